@@ -0,0 +1,128 @@
+//! Parses a decimal string into individual parts.
+
+use core::prelude::*;
+
+use dec2flt::Error;
+
+/// Parsed unsigned finite non-zero decimal value `0.d[0..n-1] * 10^exp`, such that:
+///
+/// - The digits `d[0..n-1]` are given as a concatenation of `integral` and `fractional`,
+///   so that they can be borrowed from the input without copying.
+///
+/// - Both `d[0]` and `d[n-1]` are non-zero. `integral` is empty when the integral part
+///   of the input is zero, and `fractional` is empty when the fractional part is zero.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Decimal<'a> {
+    /// Leading significant digits from the integral part of the input, in ASCII.
+    pub integral: &'a [u8],
+    /// Remaining significant digits from the fractional part of the input, in ASCII.
+    pub fractional: &'a [u8],
+    /// The decimal exponent, such that the first digit is scaled by `10^(exp-1)`.
+    pub exp: i64,
+}
+
+/// Parsed unsigned value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FullDecimal<'a> {
+    /// Not-a-number.
+    Nan,
+    /// Infinities, either positive or negative.
+    Infinite,
+    /// Zero, either positive or negative.
+    Zero,
+    /// Finite non-zero numbers with further parsed fields.
+    Finite(Decimal<'a>),
+}
+
+/// The limit of the explicit exponent. Anything beyond this is either zero or infinity,
+/// and it is small enough so that no further computation with the exponent overflows.
+const EXP_LIMIT: i64 = 1 << 48;
+
+fn eq_ignore_case(s: &[u8], lower: &[u8]) -> bool {
+    s.len() == lower.len() && s.iter().zip(lower.iter()).all(|(&a, &b)| (a | 0x20) == b)
+}
+
+fn is_digit(c: u8) -> bool {
+    b'0' <= c && c <= b'9'
+}
+
+/// Returns a sign (true when negative) and `FullDecimal` value from given string.
+/// See `dec2flt::parse` for the accepted syntax.
+pub fn parse_decimal<'a>(s: &'a [u8]) -> Result<(/*negative?*/ bool, FullDecimal<'a>), Error> {
+    let (negative, s) = match s.first() {
+        Some(&b'-') => (true, &s[1..]),
+        Some(&b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if s.is_empty() { return Err(Error::Empty); }
+
+    if eq_ignore_case(s, b"inf") || eq_ignore_case(s, b"infinity") {
+        return Ok((negative, FullDecimal::Infinite));
+    }
+    if eq_ignore_case(s, b"nan") {
+        return Ok((negative, FullDecimal::Nan));
+    }
+
+    // <integral> [. <fractional>] [(e|E) [+|-] <exponent>]
+    let intlen = s.iter().position(|&c| !is_digit(c)).unwrap_or(s.len());
+    let (integral, s) = s.split_at(intlen);
+    let (fractional, s) = if s.first() == Some(&b'.') {
+        let s = &s[1..];
+        let fraclen = s.iter().position(|&c| !is_digit(c)).unwrap_or(s.len());
+        s.split_at(fraclen)
+    } else {
+        (&s[..0], s)
+    };
+    if integral.is_empty() && fractional.is_empty() { return Err(Error::Invalid); }
+
+    let exp = match s.first() {
+        None => 0,
+        Some(&b'e') | Some(&b'E') => {
+            let (expneg, s) = match s.get(1) {
+                Some(&b'-') => (true, &s[2..]),
+                Some(&b'+') => (false, &s[2..]),
+                _ => (false, &s[1..]),
+            };
+            if s.is_empty() { return Err(Error::Invalid); }
+            let mut exp: i64 = 0;
+            for &c in s {
+                if !is_digit(c) { return Err(Error::Invalid); }
+                if exp < EXP_LIMIT { exp = exp * 10 + (c - b'0') as i64; }
+            }
+            if expneg { -exp } else { exp }
+        }
+        Some(_) => return Err(Error::Invalid),
+    };
+
+    // strip leading zeroes from the integral part
+    let integral = match integral.iter().position(|&c| c != b'0') {
+        Some(i) => &integral[i..],
+        None => &integral[..0],
+    };
+    // strip trailing zeroes from the fractional part
+    let fractional = match fractional.iter().rposition(|&c| c != b'0') {
+        Some(i) => &fractional[..i+1],
+        None => &fractional[..0],
+    };
+
+    let decimal = if !integral.is_empty() {
+        let exp = exp + integral.len() as i64;
+        // the trailing zeroes of the integral part are not significant without the fraction
+        let integral = if fractional.is_empty() {
+            let last = integral.iter().rposition(|&c| c != b'0').unwrap();
+            &integral[..last+1]
+        } else {
+            integral
+        };
+        Decimal { integral: integral, fractional: fractional, exp: exp }
+    } else {
+        // skip leading zeroes in the fractional part, adjusting the exponent
+        let zeroes = match fractional.iter().position(|&c| c != b'0') {
+            Some(i) => i,
+            None => return Ok((negative, FullDecimal::Zero)),
+        };
+        Decimal { integral: integral, fractional: &fractional[zeroes..],
+                  exp: exp - zeroes as i64 }
+    };
+    Ok((negative, FullDecimal::Finite(decimal)))
+}
@@ -0,0 +1,134 @@
+//! Encodes a rounded value into a floating-point value.
+
+use core::prelude::*;
+use core::mem;
+use core::num::Float;
+
+use flt2dec::DecodableFloat;
+
+/// The binary floating-point format to be rounded to.
+///
+/// Every finite value of the format is `mant * 2^exp` with an integer `0 <= mant < 2^sig_bits`
+/// and `min_exp <= exp <= max_exp`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Format {
+    /// The number of significand bits, including the implicit leading bit if any.
+    pub sig_bits: u32,
+    /// The exponent of subnormals, that is, `2^min_exp` is the smallest positive value.
+    pub min_exp: i16,
+    /// The exponent of the largest binade, that is, `(2^sig_bits - 1) * 2^max_exp`
+    /// is the largest finite value.
+    pub max_exp: i16,
+}
+
+impl Format {
+    /// Returns an `Unpacked` value representing the infinity.
+    pub fn infinity(&self) -> Unpacked {
+        Unpacked { mant: 1 << (self.sig_bits - 1), exp: self.max_exp + 1 }
+    }
+}
+
+/// Rounded unsigned value `mant * 2^exp`, such that:
+///
+/// - It is zero when `mant` is zero (and `exp` is ignored).
+///
+/// - Otherwise `mant < 2^sig_bits` and `exp >= min_exp`. In addition,
+///   `mant >= 2^(sig_bits-1)` unless it is subnormal (`exp == min_exp`).
+///
+/// - It is the infinity when `exp > max_exp`.
+///   The canonical infinity is given by `Format::infinity`,
+///   so that its predecessor is the largest finite value.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Unpacked {
+    /// The integer mantissa.
+    pub mant: u64,
+    /// The exponent in base 2.
+    pub exp: i16,
+}
+
+/// Correctly rounds `(m + eps) * 2^e` into given format, where `eps` is zero
+/// if `inexact` is false and otherwise some unknown value between 0 and 1 (exclusive).
+/// `m` should have more bits than the mantissa when `inexact` is true.
+pub fn round_exact(m: u128, e: i32, inexact: bool, format: &Format) -> Unpacked {
+    debug_assert!(m > 0);
+    let nbits = 128 - m.leading_zeros() as i32;
+    let mut exp = e + nbits - format.sig_bits as i32;
+    if exp < format.min_exp as i32 { exp = format.min_exp as i32; }
+
+    let mut mant = if exp <= e {
+        debug_assert!(!inexact);
+        (m << (e - exp) as u32) as u64
+    } else if exp - e > 128 {
+        0 // far below the halfway point to the smallest subnormal
+    } else {
+        let shift = (exp - e) as u32;
+        let (mant, rem) = if shift == 128 { (0, m) } else { (m >> shift, m & ((1 << shift) - 1)) };
+        let half = 1 << (shift - 1);
+        if rem > half || (rem == half && (inexact || mant & 1 != 0)) { mant as u64 + 1 }
+        else { mant as u64 }
+    };
+    if mant == 1 << format.sig_bits {
+        mant >>= 1;
+        exp += 1;
+    }
+    if exp > format.max_exp as i32 { return format.infinity(); }
+    Unpacked { mant: mant, exp: exp as i16 }
+}
+
+/// A floating point type which can be `encode`d. This is an inverse of `decode`.
+pub trait EncodableFloat: DecodableFloat {
+    /// Returns the description of the format.
+    fn format() -> Format;
+    /// Makes a value from given sign (true when negative) and `Unpacked` value.
+    fn encode(negative: bool, v: Unpacked) -> Self;
+    /// Returns a (quiet) not-a-number.
+    fn nan() -> Self;
+}
+
+impl EncodableFloat for f32 {
+    fn format() -> Format {
+        Format { sig_bits: 24, min_exp: -149, max_exp: 104 }
+    }
+
+    fn encode(negative: bool, v: Unpacked) -> f32 {
+        let bits = if v.mant == 0 {
+            0
+        } else if v.exp > 104 {
+            0x7f80_0000
+        } else if v.mant < 1 << 23 {
+            debug_assert!(v.exp == -149);
+            v.mant as u32
+        } else {
+            debug_assert!(v.mant < 1 << 24 && v.exp >= -149);
+            ((v.exp + 150) as u32) << 23 | (v.mant as u32 & 0x7f_ffff)
+        };
+        let sign = if negative { 0x8000_0000 } else { 0 };
+        unsafe { mem::transmute(sign | bits) }
+    }
+
+    fn nan() -> f32 { Float::nan() }
+}
+
+impl EncodableFloat for f64 {
+    fn format() -> Format {
+        Format { sig_bits: 53, min_exp: -1074, max_exp: 971 }
+    }
+
+    fn encode(negative: bool, v: Unpacked) -> f64 {
+        let bits = if v.mant == 0 {
+            0
+        } else if v.exp > 971 {
+            0x7ff0_0000_0000_0000
+        } else if v.mant < 1 << 52 {
+            debug_assert!(v.exp == -1074);
+            v.mant
+        } else {
+            debug_assert!(v.mant < 1 << 53 && v.exp >= -1074);
+            ((v.exp + 1075) as u64) << 52 | (v.mant & 0xf_ffff_ffff_ffff)
+        };
+        let sign = if negative { 0x8000_0000_0000_0000 } else { 0 };
+        unsafe { mem::transmute(sign | bits) }
+    }
+
+    fn nan() -> f64 { Float::nan() }
+}
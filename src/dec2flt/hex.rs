@@ -0,0 +1,115 @@
+//! Parses a hexadecimal floating point literal, the inverse of `flt2dec::to_hex_str`.
+
+use core::prelude::*;
+
+use dec2flt::Error;
+use dec2flt::encoder::{round_exact, EncodableFloat, Unpacked};
+
+/// The limit of the explicit binary exponent. Anything beyond this is either zero or infinity,
+/// and it is small enough so that no further computation with the exponent overflows.
+const EXP_LIMIT: i64 = 1 << 24;
+
+fn eq_ignore_case(s: &[u8], lower: &[u8]) -> bool {
+    s.len() == lower.len() && s.iter().zip(lower.iter()).all(|(&a, &b)| (a | 0x20) == b)
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    if b'0' <= c && c <= b'9' {
+        Some(c - b'0')
+    } else if b'a' <= (c | 0x20) && (c | 0x20) <= b'f' {
+        Some((c | 0x20) - b'a' + 10)
+    } else {
+        None
+    }
+}
+
+/// Parses the longest prefix of `s` which is a hexadecimal floating point literal
+/// into the value of the type `T`. Returns the value and the number of bytes consumed.
+///
+/// The literal consists of an optional sign (`+` or `-`) followed by either
+/// `0x` or `0X`, hexadecimal digits with an optional hexadecimal point, and
+/// an optional binary exponent (`p` or `P`, an optional sign and decimal digits),
+/// as in C99 (`0x1.8p-3`, `0X.Cp2`, `0x10`). `inf`, `infinity` and `nan` (case-insensitive)
+/// are also accepted but only as a whole string. The exponent is not consumed when
+/// no digit follows it, so `0x1p` parses as `0x1` with the trailing `p` left over.
+///
+/// The result is correctly rounded (round-half-to-even) no matter how many digits are given,
+/// and it may be a subnormal or zero. `Error::Overflow` is returned when the rounded result
+/// exceeds the largest finite value of `T`.
+pub fn parse_hex<T: EncodableFloat>(s: &[u8]) -> Result<(T, usize), Error> {
+    let (negative, start) = match s.first() {
+        Some(&b'-') => (true, 1),
+        Some(&b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let s = &s[start..];
+    if s.is_empty() { return Err(Error::Empty); }
+
+    if eq_ignore_case(s, b"inf") || eq_ignore_case(s, b"infinity") {
+        let format = <T as EncodableFloat>::format();
+        return Ok((<T as EncodableFloat>::encode(negative, format.infinity()), start + s.len()));
+    }
+    if eq_ignore_case(s, b"nan") {
+        return Ok((<T as EncodableFloat>::nan(), start + s.len()));
+    }
+
+    if s.len() < 2 || s[0] != b'0' || (s[1] | 0x20) != b'x' { return Err(Error::Invalid); }
+    let mut i = 2;
+
+    // the significand is `(mant + eps) * 2^exp` where `0 <= eps < 1` is nonzero iff `inexact`.
+    // we keep at most 32 digits (128 bits) of `mant`, which is more than enough for rounding.
+    let mut mant: u128 = 0;
+    let mut exp: i64 = 0;
+    let mut inexact = false;
+    let mut ndigits = 0;
+    let mut seen_point = false;
+    while i < s.len() {
+        if s[i] == b'.' && !seen_point {
+            seen_point = true;
+        } else if let Some(d) = hex_digit(s[i]) {
+            ndigits += 1;
+            if mant >> 124 == 0 {
+                mant = mant << 4 | d as u128;
+                if seen_point { exp -= 4; }
+            } else {
+                if d != 0 { inexact = true; }
+                if !seen_point { exp += 4; }
+            }
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    if ndigits == 0 { return Err(Error::Invalid); }
+
+    if i < s.len() && (s[i] | 0x20) == b'p' {
+        let (expneg, j) = match s.get(i + 1) {
+            Some(&b'-') => (true, i + 2),
+            Some(&b'+') => (false, i + 2),
+            _ => (false, i + 1),
+        };
+        let explen = s[j..].iter().position(|&c| !(b'0' <= c && c <= b'9'))
+                               .unwrap_or(s.len() - j);
+        if explen > 0 {
+            let mut pexp: i64 = 0;
+            for &c in &s[j..j + explen] {
+                if pexp < EXP_LIMIT { pexp = pexp * 10 + (c - b'0') as i64; }
+            }
+            exp += if expneg { -pexp } else { pexp };
+            i = j + explen;
+        }
+    }
+
+    let v = if mant == 0 {
+        Unpacked { mant: 0, exp: 0 }
+    } else {
+        // `mant < 2^124`, so any exponent beyond the limit gives zero or infinity anyway
+        let exp = if exp < -EXP_LIMIT { -EXP_LIMIT } else if exp > EXP_LIMIT { EXP_LIMIT }
+                  else { exp };
+        let format = <T as EncodableFloat>::format();
+        let v = round_exact(mant, exp as i32, inexact, &format);
+        if v.exp > format.max_exp { return Err(Error::Overflow); }
+        v
+    };
+    Ok((<T as EncodableFloat>::encode(negative, v), start + i))
+}
@@ -0,0 +1,97 @@
+/*!
+
+Decimal to floating-point number conversion routines.
+
+# Problem statement
+
+We are given the decimal number `V = 0.d[0..n-1] * 10^k` parsed from a string,
+and would like to get the floating-point number `v` nearest to `V`.
+When `V` is exactly halfway between two adjacent floating-point numbers,
+the one with the even mantissa should be returned (round-half-to-even).
+`V` may be arbitrarily long, as in the exact decimal expansion of the halfway point.
+
+The result should be correctly rounded for every target format, including subnormals
+(which have fewer significant bits than normal numbers) and the overflow to infinity
+(when `V` is no less than the halfway point between the maximum value and `2^(emax+1)`).
+In particular `f32` should be rounded from `V` directly and not through `f64`,
+as the double rounding may give a different result.
+
+# Implementation overview
+
+This is the other half of `flt2dec`, and the structure closely mirrors it.
+The input string is parsed into a `Decimal` (see the `decimal` module), and a strategy
+rounds it into an `Unpacked` value for the given `Format` (see the `encoder` module),
+which is then assembled into the actual floating-point value.
+
+Each strategy exposes one public function:
+
+- `decimal_to_float(decimal, format)` returns the correctly rounded `Unpacked` value.
+
+Strategies which cannot always decide the result additionally expose
+`decimal_to_float_opt`, which returns `None` in that case (just like Grisu).
+
+- `bigcomp` makes an approximation with Grisu's cached powers of ten and then corrects it
+  by comparing the input against the halfway points with bignums from `flt2dec`.
+  It is exact but slow, and is meant to be the fallback of faster strategies.
+
+- `lemire` tries Clinger's fast path and then the Eisel-Lemire algorithm,
+  which handle almost every input without bignums, and falls back to `bigcomp`.
+
+The `parse` function glues all of them together, handling the sign, infinities and NaN.
+
+Hexadecimal literals like `0x1.8p-3` are separately handled by `hex::parse_hex`.
+They do not need any strategy, since the binary exponent can be applied as is.
+
+*/
+
+use core::prelude::*;
+
+pub use self::decimal::{parse_decimal, Decimal, FullDecimal};
+pub use self::encoder::{EncodableFloat, Format, Unpacked};
+
+pub mod decimal;
+pub mod encoder;
+pub mod hex;
+
+/// Decimal-to-float algorithms.
+pub mod strategy {
+    pub mod bigcomp;
+    pub mod lemire;
+}
+
+#[cfg(test)] mod tests;
+
+/// An error from parsing a decimal string.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The input (after the sign, if any) is empty.
+    Empty,
+    /// The input is not a valid decimal number.
+    Invalid,
+    /// The result is too large for the target type. Only returned by `hex::parse_hex`;
+    /// `parse` returns an infinity instead.
+    Overflow,
+}
+
+/// Parses given string into the floating-point value of the type `T`
+/// with given decimal-to-float function `decimal_to_float`.
+///
+/// The string consists of an optional sign (`+` or `-`), followed by either
+/// a decimal number in the usual or exponential form (`12`, `1.`, `.5`, `1.2e-3`)
+/// or one of `inf`, `infinity` and `nan` (case-insensitive).
+/// Any leading or trailing whitespace is not allowed. NaN is always positive.
+///
+/// You probably would want `strategy::lemire::decimal_to_float` for this.
+pub fn parse<T, F>(mut decimal_to_float: F, s: &[u8]) -> Result<T, Error>
+        where T: EncodableFloat, F: FnMut(&Decimal, &Format) -> Unpacked {
+    let format = <T as EncodableFloat>::format();
+    let (negative, full_decimal) = try!(parse_decimal(s));
+    Ok(match full_decimal {
+        FullDecimal::Nan => <T as EncodableFloat>::nan(),
+        FullDecimal::Infinite => <T as EncodableFloat>::encode(negative, format.infinity()),
+        FullDecimal::Zero => <T as EncodableFloat>::encode(negative, Unpacked { mant: 0, exp: 0 }),
+        FullDecimal::Finite(ref decimal) => {
+            <T as EncodableFloat>::encode(negative, decimal_to_float(decimal, &format))
+        }
+    })
+}
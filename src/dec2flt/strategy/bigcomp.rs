@@ -0,0 +1,173 @@
+/*!
+The exact decimal-to-float conversion with big integer comparisons.
+
+It first approximates the input with the first 19 digits and Grisu's cached powers of ten,
+which is off by at most one unit in the last place. The approximation is then corrected
+by exactly comparing the input against the halfway points to the adjacent numbers,
+in the manner similar to David Gay's "bigcomp" in `dtoa.c`: the decimal expansion of
+the halfway point is generated digit by digit with Dragon's bignum arithmetic and
+compared with the input digits, so the input can be arbitrarily long.
+*/
+
+use core::prelude::*;
+use core::cmp::Ordering;
+
+use dec2flt::{Decimal, Format, Unpacked};
+use flt2dec::estimator::estimate_scaling_factor;
+use flt2dec::bignum::Big32x36 as Big;
+use flt2dec::strategy::dragon::mul_pow10;
+use flt2dec::strategy::grisu::CACHED_POW10;
+
+// FIXME(#22540) const ref to static array seems to ICE
+static POW10: [u64; 8] = [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000];
+
+/// Returns `(f, e)` such that `2^63 <= f < 2^64` and `x * y` is approximately `f * 2^e`,
+/// where `x = xf * 2^xe` and `y = yf * 2^ye` are similarly normalized.
+/// The result is truncated, so the relative error increases by less than `2^-63`.
+fn mul((xf, xe): (u64, i32), (yf, ye): (u64, i32)) -> (u64, i32) {
+    let p = xf as u128 * yf as u128; // 2^126 <= p < 2^128
+    if p >> 127 == 0 {
+        ((p >> 63) as u64, xe + ye + 63)
+    } else {
+        ((p >> 64) as u64, xe + ye + 64)
+    }
+}
+
+/// Returns a normalized `(f, e)` such that `v = f * 2^e`.
+fn normalize(v: u64) -> (u64, i32) {
+    debug_assert!(v > 0);
+    let shift = v.leading_zeros();
+    (v << shift, -(shift as i32))
+}
+
+/// Returns a normalized `(f, e)` such that `10^k` is approximately `f * 2^e`.
+/// The relative error is less than `2^-61`.
+fn pow10(k: i32) -> (u64, i32) {
+    let (_, _, firstk) = CACHED_POW10[0];
+    if k < firstk as i32 {
+        let (f, e, _) = CACHED_POW10[0];
+        return mul((f, e as i32), pow10(k - firstk as i32));
+    }
+    let (f, e, cachedk) = CACHED_POW10[((k - firstk as i32) / 8) as usize];
+    mul((f, e as i32), normalize(POW10[(k - cachedk as i32) as usize]))
+}
+
+/// Rounds `f * 2^e` (with `2^63 <= f < 2^64`) to the nearest value in given format.
+fn round((f, e): (u64, i32), format: &Format) -> Unpacked {
+    let mut exp = e + 64 - format.sig_bits as i32;
+    if exp < format.min_exp as i32 { exp = format.min_exp as i32; }
+    if exp > format.max_exp as i32 { return format.infinity(); }
+
+    let shift = (exp - e) as u32; // at least 64 - sig_bits
+    let mut mant = if shift < 64 { f >> shift } else { 0 };
+    if shift <= 64 && (f >> (shift - 1)) & 1 != 0 { mant += 1; }
+    if mant == 1 << format.sig_bits {
+        mant >>= 1;
+        exp += 1;
+        if exp > format.max_exp as i32 { return format.infinity(); }
+    }
+    Unpacked { mant: mant, exp: exp as i16 }
+}
+
+/// Returns the next larger value of `v` in given format. `v` should be finite.
+fn next(v: &Unpacked, format: &Format) -> Unpacked {
+    if v.mant + 1 == 1 << format.sig_bits {
+        Unpacked { mant: 1 << (format.sig_bits - 1), exp: v.exp + 1 }
+    } else if v.mant == 0 {
+        Unpacked { mant: 1, exp: format.min_exp }
+    } else {
+        Unpacked { mant: v.mant + 1, exp: v.exp }
+    }
+}
+
+/// Returns the next smaller value of `v` in given format. `v` should be positive.
+fn prev(v: &Unpacked, format: &Format) -> Unpacked {
+    if v.mant == 1 << (format.sig_bits - 1) && v.exp > format.min_exp {
+        Unpacked { mant: (1 << format.sig_bits) - 1, exp: v.exp - 1 }
+    } else {
+        Unpacked { mant: v.mant - 1, exp: v.exp }
+    }
+}
+
+/// Compares `d` with `(2 * v.mant + 1) * 2^(v.exp - 1)`, the halfway point between
+/// a finite value `v` and its successor.
+fn compare_with_halfway(d: &Decimal, v: &Unpacked) -> Ordering {
+    let mant = 2 * v.mant + 1;
+    let exp = v.exp - 1;
+    let dexp = d.exp as i16;
+
+    // 10^(k-1) < mant * 2^exp <= 10^(k+1), and 10^(dexp-1) <= d < 10^dexp
+    let k = estimate_scaling_factor(mant as u128, exp);
+    if k - 1 >= dexp { return Ordering::Less; }
+    if k + 1 < dexp - 1 { return Ordering::Greater; }
+
+    // now `mant * 2^exp / 10^dexp = num / den` is between 10^-3 and 10^2,
+    // so both fit in `Big` for every supported format.
+    let mut num = Big::from_u64(mant);
+    let mut den = Big::from_small(1);
+    if exp >= 0 { num.mul_pow2(exp as usize); } else { den.mul_pow2(-exp as usize); }
+    if dexp >= 0 { mul_pow10(&mut den, dexp as usize); } else { mul_pow10(&mut num, -dexp as usize); }
+    if num >= den { return Ordering::Less; }
+
+    // generate decimal digits of the halfway point and compare them one by one.
+    // invariant: `num < den`.
+    let mut den2 = den.clone(); den2.mul_pow2(1);
+    let mut den4 = den.clone(); den4.mul_pow2(2);
+    let mut den8 = den.clone(); den8.mul_pow2(3);
+    for &c in d.integral.iter().chain(d.fractional.iter()) {
+        num.mul_small(10);
+        let mut digit = 0;
+        if num >= den8 { num.sub(&den8); digit += 8; }
+        if num >= den4 { num.sub(&den4); digit += 4; }
+        if num >= den2 { num.sub(&den2); digit += 2; }
+        if num >= den  { num.sub(&den);  digit += 1; }
+        debug_assert!(num < den);
+        match (c - b'0').cmp(&digit) {
+            Ordering::Equal => {}
+            ord => return ord,
+        }
+    }
+
+    // all digits of `d` are consumed; the halfway point is larger if it has more digits
+    if num.is_zero() { Ordering::Equal } else { Ordering::Less }
+}
+
+/// The decimal-to-float implementation for bigcomp.
+pub fn decimal_to_float(d: &Decimal, format: &Format) -> Unpacked {
+    // 10^(d.exp-1) <= d < 10^d.exp. they give enough margin to the estimation below.
+    // 302/1000 is slightly larger than log_10 2.
+    let maxbits = (format.max_exp as i64 + format.sig_bits as i64) * 302 / 1000;
+    let minbits = (format.min_exp as i64 - 1) * 302 / 1000;
+    if d.exp - 1 > maxbits + 1 { return format.infinity(); } // d >= 10^(maxbits+1)
+    if d.exp <= minbits - 1 { return Unpacked { mant: 0, exp: format.min_exp }; }
+
+    // the approximation from the first 19 digits, which may be truncated
+    let mut w = 0;
+    let mut ndigits = 0;
+    for &c in d.integral.iter().chain(d.fractional.iter()).take(19) {
+        w = w * 10 + (c - b'0') as u64;
+        ndigits += 1;
+    }
+    let approx = mul(normalize(w), pow10(d.exp as i32 - ndigits));
+    let mut v = round(approx, format);
+
+    // go down while `d` is below the halfway point to the predecessor (or at the halfway
+    // point and the predecessor is even), then go up in the same way.
+    // the approximation is at most one ulp off, so this usually needs two comparisons.
+    while v.mant > 0 {
+        let p = prev(&v, format);
+        match compare_with_halfway(d, &p) {
+            Ordering::Less => v = p,
+            Ordering::Equal if v.mant & 1 != 0 => v = p,
+            _ => break,
+        }
+    }
+    while v.exp <= format.max_exp {
+        match compare_with_halfway(d, &v) {
+            Ordering::Greater => v = next(&v, format),
+            Ordering::Equal if v.mant & 1 != 0 => v = next(&v, format),
+            _ => break,
+        }
+    }
+    v
+}
@@ -0,0 +1,171 @@
+use std::prelude::v1::*;
+use std::{str, mem, fmt};
+use rand;
+use rand::distributions::{IndependentSample, Range};
+
+use dec2flt::{EncodableFloat, Error};
+use dec2flt::hex::parse_hex;
+use flt2dec::{to_hex_str, Part, Sign};
+
+fn check<T: EncodableFloat + fmt::Debug>(s: &str, expected: T, expectedlen: usize) {
+    let (actual, len): (T, usize) = parse_hex(s.as_bytes()).unwrap();
+    assert!((actual.integer_decode(), len) == (expected.integer_decode(), expectedlen),
+            "parse_hex mismatch for {:?}: actual {:?}, expected {:?}",
+            s, (actual, len), (expected, expectedlen));
+}
+
+macro_rules! check_f32 {
+    ($s:expr => $bits:expr) => (check_f32!($s => $bits, $s.len()));
+    ($s:expr => $bits:expr, $len:expr) => ({
+        let expected: f32 = unsafe { mem::transmute($bits as u32) };
+        check($s, expected, $len);
+    })
+}
+
+macro_rules! check_f64 {
+    ($s:expr => $bits:expr) => (check_f64!($s => $bits, $s.len()));
+    ($s:expr => $bits:expr, $len:expr) => ({
+        let expected: f64 = unsafe { mem::transmute($bits as u64) };
+        check($s, expected, $len);
+    })
+}
+
+#[test]
+fn parse_hex_error_test() {
+    assert_eq!(parse_hex::<f64>(b""), Err(Error::Empty));
+    assert_eq!(parse_hex::<f64>(b"+"), Err(Error::Empty));
+    assert_eq!(parse_hex::<f64>(b"-"), Err(Error::Empty));
+    assert_eq!(parse_hex::<f64>(b"0"), Err(Error::Invalid));
+    assert_eq!(parse_hex::<f64>(b"1.5"), Err(Error::Invalid));
+    assert_eq!(parse_hex::<f64>(b"0x"), Err(Error::Invalid));
+    assert_eq!(parse_hex::<f64>(b"0x."), Err(Error::Invalid));
+    assert_eq!(parse_hex::<f64>(b"0xp1"), Err(Error::Invalid));
+    assert_eq!(parse_hex::<f64>(b" 0x1"), Err(Error::Invalid));
+    assert_eq!(parse_hex::<f64>(b"--0x1"), Err(Error::Invalid));
+    assert_eq!(parse_hex::<f64>(b"infinite"), Err(Error::Invalid));
+
+    assert_eq!(parse_hex::<f64>(b"0x1p1024"), Err(Error::Overflow));
+    assert_eq!(parse_hex::<f64>(b"-0x1.fffffffffffff8p+1023"), Err(Error::Overflow));
+    assert_eq!(parse_hex::<f64>(b"0x1p99999999999999999999"), Err(Error::Overflow));
+    assert_eq!(parse_hex::<f32>(b"0x1p128"), Err(Error::Overflow));
+    assert_eq!(parse_hex::<f32>(b"0x1.ffffffp127"), Err(Error::Overflow));
+}
+
+#[test]
+fn f32_parse_hex_test() {
+    check_f32!("0x0p+0" => 0);
+    check_f32!("-0x0" => 0x8000_0000);
+    check_f32!("0x1p+0" => 0x3f80_0000);
+    check_f32!("0X1.8P-1" => 0x3f40_0000);
+    check_f32!("+0x.cp2" => 0x4040_0000);
+    check_f32!("0x1.99999ap-4" => 0x3dcc_cccd);
+    check_f32!("0x0.000002p-126" => 1);
+    check_f32!("0x0.fffffep-126" => 0x007f_ffff);
+    check_f32!("0x1p-126" => 0x0080_0000);
+    check_f32!("0x1.fffffep+127" => 0x7f7f_ffff);
+    check_f32!("0x1.fffffefffffffffffffffp+127" => 0x7f7f_ffff);
+    check_f32!("inf" => 0x7f80_0000);
+    check_f32!("-Infinity" => 0xff80_0000);
+
+    // rounding, ties to even
+    check_f32!("0x1.000001p0" => 0x3f80_0000);
+    check_f32!("0x1.000003p0" => 0x3f80_0002);
+    check_f32!("0x1.0000010000000000000000000000000000001p0" => 0x3f80_0001);
+    check_f32!("0x1p-150" => 0);
+    check_f32!("0x1.000000000000000000000000000001p-150" => 1);
+    check_f32!("0x3p-150" => 2);
+    check_f32!("0x1.fffffep-127" => 0x0080_0000);
+}
+
+#[test]
+fn f64_parse_hex_test() {
+    check_f64!("0x1p+0" => 0x3ff0_0000_0000_0000);
+    check_f64!("-0x1.999999999999ap-4" => 0xbfb9_9999_9999_999a);
+    check_f64!("0x00000000001.0000000000000000000" => 0x3ff0_0000_0000_0000);
+    check_f64!("0x10000000000000000000000000000000000000000p-160" => 0x3ff0_0000_0000_0000);
+    check_f64!("0x.000000000000000000000000000000000000001p+156" => 0x3ff0_0000_0000_0000);
+    check_f64!("0x1.fffffffffffffp+1023" => 0x7fef_ffff_ffff_ffff);
+    check_f64!("0x1.fffffffffffff7ffp+1023" => 0x7fef_ffff_ffff_ffff);
+    check_f64!("0x1p-1022" => 0x0010_0000_0000_0000);
+    check_f64!("0x0.fffffffffffffp-1022" => 0x000f_ffff_ffff_ffff);
+    check_f64!("0x0.0000000000001p-1022" => 1);
+    check_f64!("0x1p-1074" => 1);
+    check_f64!("0x1p-1075" => 0);
+    check_f64!("0x1.0000000000001p-1075" => 1);
+    check_f64!("0x1.8p-1074" => 2);
+    check_f64!("0x1p-99999999999999999999" => 0);
+    check_f64!("0x0p+99999999999999999999" => 0);
+    check_f64!("0xe.0370a1f0a0094p-1027" => 0x0007_01b8_50f8_5005);
+
+    // the longest valid prefix is consumed
+    check_f64!("0x1p" => 0x3ff0_0000_0000_0000, 3);
+    check_f64!("0x1p+" => 0x3ff0_0000_0000_0000, 3);
+    check_f64!("0x1.8p+3xyz" => 0x4028_0000_0000_0000, 8);
+    check_f64!("0x1.8.3" => 0x3ff8_0000_0000_0000, 5);
+    check_f64!("0x1g" => 0x3ff0_0000_0000_0000, 3);
+
+    let (nan, len): (f64, usize) = parse_hex(b"NaN").unwrap();
+    assert!(nan.is_nan() && len == 3);
+}
+
+// `to_hex_str` followed by `parse_hex` should be an identity for every finite value.
+fn iterate_roundtrip<T, V>(func: &str, n: usize, mut v: V)
+        where T: EncodableFloat + fmt::Debug, V: FnMut(usize) -> T {
+    let mut npassed = 0;
+    for i in 0..n {
+        if (i & 0xfffff) == 0 {
+            println!("in progress, {:x}/{:x} (passed={} failed={})",
+                     i, n, npassed, i - npassed);
+        }
+
+        let x = v(i);
+        let mut buf = [0; 32];
+        let mut parts = [Part::Zero(0); 7];
+        let mut s = [0; 64];
+        let formatted = to_hex_str(x, Sign::MinusRaw, None, i % 2 == 0, &mut buf, &mut parts);
+        let len = formatted.write(&mut s).unwrap();
+        match parse_hex::<T>(&s[..len]) {
+            Ok((y, ylen)) if ylen == len && y.integer_decode() == x.integer_decode() => {
+                npassed += 1;
+            }
+            r => {
+                println!("{} failed, {:x}/{:x}: {:?} => {:?}, expected {:?}",
+                         func, i, n, str::from_utf8(&s[..len]).unwrap(), r, x);
+            }
+        }
+    }
+    println!("{}: done, passed={} failed={}", func, npassed, n - npassed);
+    assert!(npassed == n, "{}: {} out of {} values returns an incorrect value!",
+            func, n - npassed, n);
+}
+
+#[test]
+fn f32_random_roundtrip_test() {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f32_range = Range::new(0x0000_0000u32, 0x7f80_0000);
+    iterate_roundtrip("f32_random_roundtrip_test", 10_000, |i| {
+        let x: u32 = f32_range.ind_sample(&mut rng);
+        let x: f32 = unsafe { mem::transmute(x | (i as u32 & 1) << 31) };
+        x
+    });
+}
+
+#[test]
+fn f64_random_roundtrip_test() {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0000u64, 0x7ff0_0000_0000_0000);
+    iterate_roundtrip("f64_random_roundtrip_test", 10_000, |i| {
+        let x: u64 = f64_range.ind_sample(&mut rng);
+        let x: f64 = unsafe { mem::transmute(x | (i as u64 & 1) << 63) };
+        x
+    });
+}
+
+#[test] #[ignore] // it is too expensive
+fn f32_exhaustive_roundtrip_test() {
+    // iterate from 0x0000_0000 to 0x7f7f_ffff, i.e. all finite ranges
+    iterate_roundtrip("f32_exhaustive_roundtrip_test", 0x7f80_0000, |i| {
+        let x: f32 = unsafe { mem::transmute(i as u32) };
+        x
+    });
+}
@@ -0,0 +1,384 @@
+use std::prelude::v1::*;
+use std::{str, mem, fmt};
+use rand;
+use rand::distributions::{IndependentSample, Range};
+
+use dec2flt::{parse, parse_decimal, Decimal, FullDecimal, Format, Unpacked, EncodableFloat};
+use dec2flt::Error;
+use flt2dec::{decode, FullDecoded, Decoded, DecodableFloat, MAX_SIG_DIGITS};
+use flt2dec::strategy::dragon::{format_shortest, format_exact};
+
+pub use test::Bencher;
+
+mod hex;
+mod strategy {
+    mod bigcomp;
+    mod lemire;
+}
+
+fn decimal<'a>(integral: &'a [u8], fractional: &'a [u8], exp: i64) -> FullDecimal<'a> {
+    FullDecimal::Finite(Decimal { integral: integral, fractional: fractional, exp: exp })
+}
+
+#[test]
+fn parse_decimal_test() {
+    assert_eq!(parse_decimal(b""), Err(Error::Empty));
+    assert_eq!(parse_decimal(b"+"), Err(Error::Empty));
+    assert_eq!(parse_decimal(b"-"), Err(Error::Empty));
+    assert_eq!(parse_decimal(b"."), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"e5"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b".e5"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"1e"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"1e+"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"1e-x"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"1.2.3"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b" 1"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"1 "), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"--1"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"infinite"), Err(Error::Invalid));
+    assert_eq!(parse_decimal(b"nan0"), Err(Error::Invalid));
+
+    assert_eq!(parse_decimal(b"inf"), Ok((false, FullDecimal::Infinite)));
+    assert_eq!(parse_decimal(b"-Infinity"), Ok((true, FullDecimal::Infinite)));
+    assert_eq!(parse_decimal(b"+INF"), Ok((false, FullDecimal::Infinite)));
+    assert_eq!(parse_decimal(b"NaN"), Ok((false, FullDecimal::Nan)));
+    assert_eq!(parse_decimal(b"-nan"), Ok((true, FullDecimal::Nan)));
+
+    assert_eq!(parse_decimal(b"0"), Ok((false, FullDecimal::Zero)));
+    assert_eq!(parse_decimal(b"-0"), Ok((true, FullDecimal::Zero)));
+    assert_eq!(parse_decimal(b"000.000e999"), Ok((false, FullDecimal::Zero)));
+    assert_eq!(parse_decimal(b".0"), Ok((false, FullDecimal::Zero)));
+    assert_eq!(parse_decimal(b"0."), Ok((false, FullDecimal::Zero)));
+
+    assert_eq!(parse_decimal(b"1"), Ok((false, decimal(b"1", b"", 1))));
+    assert_eq!(parse_decimal(b"-1."), Ok((true, decimal(b"1", b"", 1))));
+    assert_eq!(parse_decimal(b"+.1"), Ok((false, decimal(b"", b"1", 0))));
+    assert_eq!(parse_decimal(b"00120"), Ok((false, decimal(b"12", b"", 3))));
+    assert_eq!(parse_decimal(b"120.0"), Ok((false, decimal(b"12", b"", 3))));
+    assert_eq!(parse_decimal(b"120.05"), Ok((false, decimal(b"120", b"05", 3))));
+    assert_eq!(parse_decimal(b"0.00250"), Ok((false, decimal(b"", b"25", -2))));
+    assert_eq!(parse_decimal(b"1.5e3"), Ok((false, decimal(b"1", b"5", 4))));
+    assert_eq!(parse_decimal(b"1.5E+3"), Ok((false, decimal(b"1", b"5", 4))));
+    assert_eq!(parse_decimal(b"15e-3"), Ok((false, decimal(b"15", b"", -1))));
+    assert_eq!(parse_decimal(b".015e-0003"), Ok((false, decimal(b"", b"15", -4))));
+
+    // exponents are saturated but still give the correct zero or infinity
+    match parse_decimal(b"1e99999999999999999999999999") {
+        Ok((false, FullDecimal::Finite(Decimal { exp, .. }))) => assert!(exp > 1 << 40),
+        r => panic!("unexpected result {:?}", r),
+    }
+    match parse_decimal(b"1e-99999999999999999999999999") {
+        Ok((false, FullDecimal::Finite(Decimal { exp, .. }))) => assert!(exp < -(1 << 40)),
+        r => panic!("unexpected result {:?}", r),
+    }
+}
+
+fn check<T, F>(f: F, s: &str, expected: T) where T: EncodableFloat + fmt::Debug, F: FnMut(&Decimal, &Format) -> Unpacked {
+    let actual: T = parse(f, s.as_bytes()).unwrap();
+    assert!(actual.integer_decode() == expected.integer_decode(),
+            "parse mismatch for {:?}: actual {:?}, expected {:?}", s, actual, expected);
+}
+
+macro_rules! check_f32 {
+    ($f:ident($s:expr) => $bits:expr) => ({
+        let expected: f32 = unsafe { mem::transmute($bits as u32) };
+        check(&mut $f, $s, expected);
+    })
+}
+
+macro_rules! check_f64 {
+    ($f:ident($s:expr) => $bits:expr) => ({
+        let expected: f64 = unsafe { mem::transmute($bits as u64) };
+        check(&mut $f, $s, expected);
+    })
+}
+
+pub fn f32_sanity_test<F>(mut f: F) where F: FnMut(&Decimal, &Format) -> Unpacked {
+    check_f32!(f("1") => 0x3f80_0000);
+    check_f32!(f("-1") => 0xbf80_0000);
+    check_f32!(f("-0.0") => 0x8000_0000);
+    check_f32!(f("0.1") => 0x3dcc_cccd);
+    check_f32!(f("3.141592") => 0x4049_0fd8);
+    check_f32!(f("16777216") => 0x4b80_0000);
+    check_f32!(f("16777217") => 0x4b80_0000); // tie, to even
+    check_f32!(f("16777218") => 0x4b80_0001);
+    check_f32!(f("16777219") => 0x4b80_0002); // tie, to even
+    check_f32!(f("16777217.000000000000000000000000001") => 0x4b80_0001);
+
+    // the input is slightly below the halfway point between 1 and 1 + 2^-23,
+    // but its nearest `f64` is exactly at the halfway point.
+    // the double rounding would give 0x3f80_0002 here.
+    check_f32!(f("1.000000178813934326171874") => 0x3f80_0001);
+    check_f32!(f("1.000000178813934326171875") => 0x3f80_0002); // tie, to even
+    check_f32!(f("1.000000059604644775390625") => 0x3f80_0000); // tie, to even
+    check_f32!(f("1.000000059604644775390626") => 0x3f80_0001);
+
+    // maximum, minimum and overflow
+    check_f32!(f("3.4028234663852886e38") => 0x7f7f_ffff);
+    check_f32!(f("3.4028235677973366e38") => 0x7f7f_ffff); // just below the halfway
+    check_f32!(f("3.4028235677973367e38") => 0x7f80_0000);
+    check_f32!(f("1e39") => 0x7f80_0000);
+    check_f32!(f("1.1754942e-38") => 0x007f_ffff);
+    check_f32!(f("1.17549435e-38") => 0x0080_0000);
+    check_f32!(f("1e-45") => 0x0000_0001);
+    check_f32!(f("7.006492321624085e-46") => 0x0000_0000); // just below the halfway
+    check_f32!(f("7.006492321624086e-46") => 0x0000_0001);
+    check_f32!(f("1e-46") => 0x0000_0000);
+}
+
+pub fn f64_sanity_test<F>(mut f: F) where F: FnMut(&Decimal, &Format) -> Unpacked {
+    check_f64!(f("1") => 0x3ff0_0000_0000_0000);
+    check_f64!(f("-2.5") => 0xc004_0000_0000_0000);
+    check_f64!(f("-0") => 0x8000_0000_0000_0000);
+    check_f64!(f("0.1") => 0x3fb9_9999_9999_999a);
+    check_f64!(f("0.3") => 0x3fd3_3333_3333_3333);
+    check_f64!(f("3.141592") => 0x4009_21fa_fc8b_007a);
+    check_f64!(f("1e23") => 0x44b5_2d02_c7e1_4af6);
+    check_f64!(f("8.41e21") => 0x447c_7e83_209e_90b2);
+    check_f64!(f("9007199254740992") => 0x4340_0000_0000_0000);
+    check_f64!(f("9007199254740993") => 0x4340_0000_0000_0000); // tie, to even
+    check_f64!(f("9007199254740993.0000000000000000000000000000001") => 0x4340_0000_0000_0001);
+    check_f64!(f("9007199254740995") => 0x4340_0000_0000_0002); // tie, to even
+    check_f64!(f("123456789012345678901234567890") => 0x45f8_ee90_ff6c_373e);
+    check_f64!(f("0.000000000000000000000000000001") => 0x39b4_484b_feeb_c2a0);
+
+    // maximum, minimum and overflow
+    check_f64!(f("1.7976931348623157e308") => 0x7fef_ffff_ffff_ffff);
+    check_f64!(f("1.7976931348623158e308") => 0x7fef_ffff_ffff_ffff);
+    check_f64!(f("1.797693134862315807e308") => 0x7fef_ffff_ffff_ffff); // just below the halfway
+    check_f64!(f("1.797693134862315808e308") => 0x7ff0_0000_0000_0000);
+    check_f64!(f("1e309") => 0x7ff0_0000_0000_0000);
+    check_f64!(f("2.2250738585072011e-308") => 0x000f_ffff_ffff_ffff);
+    check_f64!(f("2.2250738585072012e-308") => 0x0010_0000_0000_0000);
+    check_f64!(f("4.9406564584124654e-324") => 0x0000_0000_0000_0001);
+    check_f64!(f("2.4703282292062327e-324") => 0x0000_0000_0000_0000); // just below the halfway
+    check_f64!(f("2.4703282292062328e-324") => 0x0000_0000_0000_0001);
+    check_f64!(f("1e-400") => 0x0000_0000_0000_0000);
+    check_f64!(f("1e-99999999999999999999") => 0x0000_0000_0000_0000);
+    check_f64!(f("-1e99999999999999999999") => 0xfff0_0000_0000_0000);
+}
+
+pub fn more_sanity_test<F>(mut f: F) where F: FnMut(&Decimal, &Format) -> Unpacked {
+    // inputs with a lot of digits
+    let mut s = "0.".to_string();
+    for _ in 0..1000 { s.push('0'); }
+    s.push_str("1e1000");
+    check_f64!(f(&s) => 0x3fb9_9999_9999_999a);
+    let mut s = "0.1".to_string();
+    for _ in 0..1000 { s.push('0'); }
+    s.push_str("1");
+    check_f64!(f(&s) => 0x3fb9_9999_9999_999a);
+    // the exact halfway point between the largest subnormal and the smallest normal number
+    let halfway = concat!("2.225073858507201136057409796709131975934819546351645648023426109724822222021076",
+                          "94551652952390813508791414915891303962110687008643869459464552765720740782062174",
+                          "33799881410632673292535522868813721490129811224514518898490572223072852551331557",
+                          "55015914397476397983411801999323962548289017107081850690630666655994938275772572",
+                          "01576306269066333264756530000924588831643303777979186961204949739037782970490505",
+                          "10806099407302629371289589500035837999672072543043602840788957717961509455167482",
+                          "43471030702609144621572289880258182545180325707018860872113128079512233426288368",
+                          "62232150377566662250398253433597456888442390026549819838548794829220689472168983",
+                          "10996983658468140228542433306603398508864458040010349339704275671864433837704860",
+                          "3786162277173854562306587467901408672332763671875");
+    check_f64!(f(&format!("{}e-308", halfway)) => 0x0010_0000_0000_0000); // tie, to even
+    check_f64!(f(&format!("{}4999e-308", &halfway[..halfway.len()-1])) => 0x000f_ffff_ffff_ffff);
+    check_f64!(f(&format!("{}0001e-308", halfway)) => 0x0010_0000_0000_0000);
+    check_f32!(f("7.00649232162408535461864791644958065640130970938257885878534141944895541342930300743319094181060791015625e-46") => 0x0000_0000); // tie, to even
+    check_f32!(f("7.006492321624085354618647916449580656401309709382578858785341419448955413429303007433190941810607910156250001e-46") => 0x0000_0001);
+
+    // NaN, infinities and invalid strings
+    let nan: f64 = parse(&mut f, b"nan").unwrap();
+    assert!(nan != nan);
+    let nan: f32 = parse(&mut f, b"-NaN").unwrap();
+    assert!(nan != nan);
+    check_f64!(f("inf") => 0x7ff0_0000_0000_0000);
+    check_f64!(f("-infinity") => 0xfff0_0000_0000_0000);
+    check_f32!(f("+Inf") => 0x7f80_0000);
+    assert_eq!(parse::<f64, _>(&mut f, b""), Err(Error::Empty));
+    assert_eq!(parse::<f32, _>(&mut f, b"1x"), Err(Error::Invalid));
+}
+
+fn iterate<T, F, V>(func: &str, n: usize, mut f: F, mut v: V)
+        where T: EncodableFloat + fmt::Debug, F: FnMut(&Decimal, &Format) -> Unpacked,
+              V: FnMut(usize, &mut [u8]) -> (String, T) {
+    let mut npassed = 0;
+    let mut buf = [0; 1024];
+    for i in 0..n {
+        if (i & 0xfffff) == 0 {
+            println!("in progress, {:x}/{:x} (passed={} failed={})",
+                     i, n, npassed, i - npassed);
+        }
+
+        let (s, expected) = v(i, &mut buf);
+        let actual: T = parse(&mut f, s.as_bytes()).unwrap();
+        if actual.integer_decode() == expected.integer_decode() {
+            npassed += 1;
+        } else {
+            println!("{} failed, {:x}/{:x}: {:?} => {:?}, expected {:?}",
+                     func, i, n, s, actual, expected);
+        }
+    }
+    println!("{}: done, passed={} failed={}", func, npassed, n - npassed);
+    assert!(npassed == n, "{}: {} out of {} values returns an incorrect value!",
+            func, n - npassed, n);
+}
+
+fn decode_finite<T: DecodableFloat>(v: T) -> Decoded {
+    match decode(v).1 {
+        FullDecoded::Finite(decoded) => decoded,
+        full_decoded => panic!("expected finite, got {:?} instead", full_decoded)
+    }
+}
+
+fn digits_to_string(digits: &[u8], suffix: &str, exp: i16) -> String {
+    format!("0.{}{}e{}", str::from_utf8(digits).unwrap(), suffix, exp)
+}
+
+/// Returns the exact decimal expansion of `mant * 2^exp`.
+fn exact_digits(mant: u128, exp: i16, buf: &mut [u8]) -> (usize, i16) {
+    let d = Decoded { mant: mant, minus: 1, plus: 1, exp: exp, inclusive: true };
+    let (len, exp) = format_exact(&d, buf, i16::MIN);
+    let len = buf[..len].iter().rposition(|&c| c != b'0').unwrap() + 1;
+    (len, exp)
+}
+
+/// Makes the `i`-th string for given finite value `x` of the type `T`,
+/// which is parsed back to `x` only when the parser is correctly rounded.
+fn make_string<T: EncodableFloat>(x: T, i: usize, buf: &mut [u8]) -> (String, T) {
+    // it is far smaller than the distance between the halfway point and `x`
+    const EPSILON: &'static str = "00000000000000000001";
+
+    let decoded = decode_finite(x);
+    // `decoded.inclusive` is always true for subnormals, whose mantissa is scaled
+    let (mant, _, _) = x.integer_decode();
+    let even = if mant < decoded.mant { mant & 1 == 0 } else { mant & 2 == 0 };
+    let s = match i % 4 {
+        // the shortest representation
+        0 => {
+            let (len, exp) = format_shortest(&decoded, &mut buf[..MAX_SIG_DIGITS]);
+            digits_to_string(&buf[..len], "", exp)
+        }
+        // 17 digits, which are enough to identify any `f64`
+        1 => {
+            let (len, exp) = format_exact(&decoded, &mut buf[..17], i16::MIN);
+            digits_to_string(&buf[..len], "", exp)
+        }
+        // the halfway point to the predecessor, or slightly above if it is not rounded to `x`
+        2 => {
+            let (len, exp) = exact_digits(decoded.mant - decoded.minus, decoded.exp, buf);
+            let suffix = if even { "" } else { EPSILON };
+            digits_to_string(&buf[..len], suffix, exp)
+        }
+        // the halfway point to the successor, or slightly below if it is not rounded to `x`
+        _ => {
+            let (len, exp) = exact_digits(decoded.mant + decoded.plus, decoded.exp, buf);
+            if even {
+                digits_to_string(&buf[..len], "", exp)
+            } else {
+                buf[len-1] -= 1; // the last digit is never zero
+                digits_to_string(&buf[..len], "99999999999999999999", exp)
+            }
+        }
+    };
+    (s, x)
+}
+
+pub fn f32_random_test<F>(f: F, n: usize) where F: FnMut(&Decimal, &Format) -> Unpacked {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f32_range = Range::new(0x0000_0001u32, 0x7f80_0000);
+    iterate("f32_random_test", n, f, |i, buf| {
+        let x: f32 = unsafe { mem::transmute(f32_range.ind_sample(&mut rng)) };
+        make_string(x, i, buf)
+    });
+}
+
+pub fn f64_random_test<F>(f: F, n: usize) where F: FnMut(&Decimal, &Format) -> Unpacked {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0001u64, 0x7ff0_0000_0000_0000);
+    iterate("f64_random_test", n, f, |i, buf| {
+        let x: f64 = unsafe { mem::transmute(f64_range.ind_sample(&mut rng)) };
+        make_string(x, i, buf)
+    });
+}
+
+pub fn f32_exhaustive_test<F>(f: F) where F: FnMut(&Decimal, &Format) -> Unpacked {
+    // iterate from 0x0000_0001 to 0x7f7f_ffff, i.e. all finite ranges,
+    // each with all four kinds of strings.
+    iterate("f32_exhaustive_test", 0x7f7f_ffff * 4, f, |i, buf| {
+        let x: f32 = unsafe { mem::transmute((i / 4) as u32 + 1) };
+        make_string(x, i, buf)
+    });
+}
+
+fn iterate_equivalence<T, F, G, V>(func: &str, n: usize, mut f: F, mut g: G, mut v: V)
+        -> (usize, usize)
+        where T: EncodableFloat, F: FnMut(&Decimal, &Format) -> Option<Unpacked>,
+              G: FnMut(&Decimal, &Format) -> Unpacked,
+              V: FnMut(usize, &mut [u8]) -> (String, T) {
+    let format = <T as EncodableFloat>::format();
+    let mut npassed = 0; // f(x) = Some(g(x))
+    let mut nignored = 0; // f(x) = None
+    let mut buf = [0; 1024];
+
+    for i in 0..n {
+        if (i & 0xfffff) == 0 {
+            println!("in progress, {:x}/{:x} (ignored={} passed={} failed={})",
+                     i, n, nignored, npassed, i - nignored - npassed);
+        }
+
+        let (s, _) = v(i, &mut buf);
+        let decimal = match parse_decimal(s.as_bytes()) {
+            Ok((_, FullDecimal::Finite(decimal))) => decimal,
+            r => panic!("expected finite, got {:?} instead", r),
+        };
+        if let Some(v1) = f(&decimal, &format) {
+            let v2 = g(&decimal, &format);
+            if v1 == v2 {
+                npassed += 1;
+            } else {
+                println!("equivalence test failed, {:x}/{:x}: {:?} f(i)={:?} g(i)={:?}",
+                         i, n, s, v1, v2);
+            }
+        } else {
+            nignored += 1;
+        }
+    }
+    println!("{}: done, ignored={} passed={} failed={}",
+             func, nignored, npassed, n - nignored - npassed);
+    assert!(nignored + npassed == n,
+            "{}: {} out of {} values returns an incorrect value!",
+            func, n - nignored - npassed, n);
+    (npassed, nignored)
+}
+
+pub fn f32_random_equivalence_test<F, G>(f: F, g: G, n: usize)
+        where F: FnMut(&Decimal, &Format) -> Option<Unpacked>,
+              G: FnMut(&Decimal, &Format) -> Unpacked {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f32_range = Range::new(0x0000_0001u32, 0x7f80_0000);
+    iterate_equivalence("f32_random_equivalence_test", n, f, g, |i, buf| {
+        let x: f32 = unsafe { mem::transmute(f32_range.ind_sample(&mut rng)) };
+        make_string(x, i, buf)
+    });
+}
+
+pub fn f64_random_equivalence_test<F, G>(f: F, g: G, n: usize)
+        where F: FnMut(&Decimal, &Format) -> Option<Unpacked>,
+              G: FnMut(&Decimal, &Format) -> Unpacked {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0001u64, 0x7ff0_0000_0000_0000);
+    iterate_equivalence("f64_random_equivalence_test", n, f, g, |i, buf| {
+        let x: f64 = unsafe { mem::transmute(f64_range.ind_sample(&mut rng)) };
+        make_string(x, i, buf)
+    });
+}
+
+pub fn f32_exhaustive_equivalence_test<F, G>(f: F, g: G)
+        where F: FnMut(&Decimal, &Format) -> Option<Unpacked>,
+              G: FnMut(&Decimal, &Format) -> Unpacked {
+    // iterate from 0x0000_0001 to 0x7f7f_ffff, i.e. all finite ranges,
+    // each with all four kinds of strings.
+    iterate_equivalence("f32_exhaustive_equivalence_test", 0x7f7f_ffff * 4, f, g, |i, buf| {
+        let x: f32 = unsafe { mem::transmute((i / 4) as u32 + 1) };
+        make_string(x, i, buf)
+    });
+}
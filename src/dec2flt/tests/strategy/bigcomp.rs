@@ -0,0 +1,51 @@
+use std::prelude::v1::*;
+use super::super::*;
+use dec2flt::*;
+use dec2flt::strategy::bigcomp::*;
+
+#[test]
+fn sanity_test() {
+    f32_sanity_test(decimal_to_float);
+    f64_sanity_test(decimal_to_float);
+    more_sanity_test(decimal_to_float);
+}
+
+#[test]
+fn f32_random_halfway_test() {
+    f32_random_test(decimal_to_float, 10_000);
+}
+
+#[test]
+fn f64_random_halfway_test() {
+    f64_random_test(decimal_to_float, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn f32_exhaustive_halfway_test() {
+    f32_exhaustive_test(decimal_to_float);
+}
+
+#[bench]
+fn bench_short(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"3.141592"));
+}
+
+#[bench]
+fn bench_long(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"3.14159265358979323846264338327950288"));
+}
+
+#[bench]
+fn bench_halfway(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"9007199254740993"));
+}
+
+#[bench]
+fn bench_big(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"1.7976931348623157e308"));
+}
+
+#[bench]
+fn bench_small(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"4.9406564584124654e-324"));
+}
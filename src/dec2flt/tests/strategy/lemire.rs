@@ -0,0 +1,95 @@
+use std::prelude::v1::*;
+use super::super::*;
+use dec2flt::*;
+use dec2flt::strategy::lemire::*;
+
+#[test]
+fn test_pow5_128() {
+    assert_eq!(POW5_128.len() as i32, POW5_128_LAST_Q - POW5_128_FIRST_Q + 1);
+    // every entry is normalized, and exact for small non-negative powers
+    for &(hi, _) in POW5_128.iter() { assert!(hi >> 63 == 1); }
+    let mut pow5 = 1u64;
+    for q in 0..28 {
+        let (hi, lo) = POW5_128[(q - POW5_128_FIRST_Q) as usize];
+        assert_eq!((hi, lo), (pow5 << pow5.leading_zeros(), 0));
+        pow5 = pow5.wrapping_mul(5);
+    }
+}
+
+#[test]
+fn subnormal_format_test() {
+    // the subnormal rounding is only proven for `f32` and `f64`
+    let d = Decimal { integral: b"1", fractional: b"", exp: -319 };
+    assert!(decimal_to_float_opt(&d, &<f64 as EncodableFloat>::format()).is_some());
+    let format = Format { sig_bits: 60, min_exp: -1100, max_exp: 971 };
+    assert_eq!(decimal_to_float_opt(&d, &format), None);
+    assert!(decimal_to_float(&d, &format).mant > 0);
+}
+
+#[test]
+fn sanity_test() {
+    f32_sanity_test(decimal_to_float);
+    f64_sanity_test(decimal_to_float);
+    more_sanity_test(decimal_to_float);
+}
+
+#[test]
+fn f32_random_halfway_test() {
+    f32_random_test(decimal_to_float, 10_000);
+}
+
+#[test]
+fn f64_random_halfway_test() {
+    f64_random_test(decimal_to_float, 10_000);
+}
+
+#[test]
+fn f32_random_equivalence_test() {
+    use dec2flt::strategy::bigcomp::decimal_to_float as fallback;
+    super::super::f32_random_equivalence_test(decimal_to_float_opt, fallback, 10_000);
+}
+
+#[test]
+fn f64_random_equivalence_test() {
+    use dec2flt::strategy::bigcomp::decimal_to_float as fallback;
+    super::super::f64_random_equivalence_test(decimal_to_float_opt, fallback, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn f32_exhaustive_equivalence_test() {
+    // it is hard to directly test the correctness for every input, but we can at least test
+    // if two different algorithms agree to each other for all kinds of strings from `f32`.
+    use dec2flt::strategy::bigcomp::decimal_to_float as fallback;
+    super::super::f32_exhaustive_equivalence_test(decimal_to_float_opt, fallback);
+}
+
+#[test] #[ignore] // it is too expensive
+fn f64_hard_random_equivalence_test() {
+    use dec2flt::strategy::bigcomp::decimal_to_float as fallback;
+    super::super::f64_random_equivalence_test(decimal_to_float_opt, fallback, 100_000_000);
+}
+
+#[bench]
+fn bench_short(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"3.141592"));
+}
+
+#[bench]
+fn bench_long(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"3.14159265358979323846264338327950288"));
+}
+
+#[bench]
+fn bench_halfway(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"9007199254740993"));
+}
+
+#[bench]
+fn bench_big(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"1.7976931348623157e308"));
+}
+
+#[bench]
+fn bench_small(b: &mut Bencher) {
+    b.iter(|| parse::<f64, _>(decimal_to_float, b"4.9406564584124654e-324"));
+}
@@ -0,0 +1,63 @@
+use core::prelude::*;
+
+// every strategy provides a subset of the following functions:
+//
+// - `dec_to_uN(s) -> Result<uN, Error>` parses a non-empty string of decimal digits.
+//   leading zeroes are allowed and no sign is accepted.
+// - `dec_to_iN(s) -> Result<iN, Error>` is same but accepts an optional sign (`+` or `-`).
+// - `digits_to_uN(d) -> Result<uN, Error>` is a fixed-width counterpart of `dec_to_uN`
+//   that inverts `int2dec::uN_to_digits`. it can still overflow as `DigitsN` has
+//   more than enough digits (e.g. `99999999999999999999` in `Digits64`).
+//
+// when the input contains an invalid digit and also overflows, either error can be returned.
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// The input (after the sign, if any) is empty.
+    Empty,
+    /// The input contains a byte other than `0` through `9`.
+    InvalidDigit,
+    /// The resulting integer does not fit in the target type.
+    Overflow,
+}
+
+macro_rules! signed_from_dec {
+    ($($ifn:ident($it:ident) => $ufn:ident($ut:ty);)*) => ($(
+        pub fn $ifn(s: &[u8]) -> Result<$it, Error> {
+            let (negative, s) = match s.first() {
+                Some(&b'-') => (true, &s[1..]),
+                Some(&b'+') => (false, &s[1..]),
+                _ => (false, s),
+            };
+            let n = try!($ufn(s));
+            if negative {
+                // the magnitude of `$it::MIN` is one more than `$it::MAX`
+                if n > ::core::$it::MAX as $ut + 1 { return Err(Error::Overflow); }
+                Ok((!n).wrapping_add(1) as $it)
+            } else {
+                if n > ::core::$it::MAX as $ut { return Err(Error::Overflow); }
+                Ok(n as $it)
+            }
+        })*
+    )
+}
+
+pub mod strategy {
+    pub mod naive;
+    pub mod table;
+    pub mod swar;
+
+    pub mod best {
+        pub use super::table::{dec_to_u64, dec_to_u32, dec_to_u16, dec_to_u8};
+        pub use super::table::{dec_to_i64, dec_to_i32, dec_to_i16, dec_to_i8};
+        pub use super::table::digits_to_u32;
+
+        // SWAR only pays off with many digits known in advance, and needs fast 64-bit multiplication.
+        #[cfg(target_arch = "x86")] pub use super::table::digits_to_u64;
+        #[cfg(not(target_arch = "x86"))] pub use super::swar::digits_to_u64;
+    }
+}
+
+pub use self::strategy::best;
+
+#[cfg(test)] mod tests;
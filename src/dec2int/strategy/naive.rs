@@ -0,0 +1,75 @@
+use core::prelude::*;
+
+use int2dec::{Digits64, Digits32};
+use dec2int::Error;
+
+pub fn dec_to_u64(s: &[u8]) -> Result<u64, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u64 = 0;
+    for &c in s {
+        let d = c.wrapping_sub(b'0');
+        if d > 9 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(10).and_then(|n| n.checked_add(d as u64)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u32(s: &[u8]) -> Result<u32, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u32 = 0;
+    for &c in s {
+        let d = c.wrapping_sub(b'0');
+        if d > 9 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(10).and_then(|n| n.checked_add(d as u32)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u16(s: &[u8]) -> Result<u16, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u16 = 0;
+    for &c in s {
+        let d = c.wrapping_sub(b'0');
+        if d > 9 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(10).and_then(|n| n.checked_add(d as u16)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u8(s: &[u8]) -> Result<u8, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u8 = 0;
+    for &c in s {
+        let d = c.wrapping_sub(b'0');
+        if d > 9 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(10).and_then(|n| n.checked_add(d as u8)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+    }
+    Ok(n)
+}
+
+pub fn digits_to_u64(d: &Digits64) -> Result<u64, Error> {
+    dec_to_u64(d)
+}
+
+pub fn digits_to_u32(d: &Digits32) -> Result<u32, Error> {
+    dec_to_u32(d)
+}
+
+signed_from_dec! {
+    dec_to_i64(i64) => dec_to_u64(u64);
+    dec_to_i32(i32) => dec_to_u32(u32);
+    dec_to_i16(i16) => dec_to_u16(u16);
+    dec_to_i8(i8) => dec_to_u8(u8);
+}
@@ -0,0 +1,93 @@
+use core::prelude::*;
+use core::u32;
+
+use int2dec::{Digits64, Digits32};
+use dec2int::Error;
+
+// parses exactly 8 digits at once ("SIMD within a register").
+// the first digit goes to the lowest byte, so every step below combines adjacent lanes
+// `(hi, lo)` into `hi * 10^k + lo` and the lane width doubles: 8 -> 16 -> 32 -> 64 bits.
+#[inline]
+fn eight(s: &[u8]) -> Result<u64, Error> {
+    assert!(s.len() == 8);
+    let x = (s[0] as u64)       | (s[1] as u64) <<  8 | (s[2] as u64) << 16 | (s[3] as u64) << 24 |
+            (s[4] as u64) << 32 | (s[5] as u64) << 40 | (s[6] as u64) << 48 | (s[7] as u64) << 56;
+
+    // each byte should be 0x30..0x39, i.e. both it and the byte plus 6 have 3 as an upper nibble.
+    // the addition can carry to the next byte only when the current byte is already invalid.
+    let hi = x & 0xf0f0f0f0_f0f0f0f0;
+    let hi6 = x.wrapping_add(0x06060606_06060606) & 0xf0f0f0f0_f0f0f0f0;
+    if hi | (hi6 >> 4) != 0x33333333_33333333 { return Err(Error::InvalidDigit); }
+
+    let x = x - 0x30303030_30303030;
+    let x = (x * 10 + (x >> 8)) & 0x00ff00ff_00ff00ff;
+    let x = (x * 100 + (x >> 16)) & 0x0000ffff_0000ffff;
+    let x = (x * 10000 + (x >> 32)) & 0x00000000_ffffffff;
+    Ok(x)
+}
+
+// parses less than 8 digits, which cannot overflow u64. an empty input results in 0.
+#[inline]
+fn small(s: &[u8]) -> Result<u64, Error> {
+    debug_assert!(s.len() < 8);
+    let mut n = 0;
+    for &c in s {
+        let d = c.wrapping_sub(b'0');
+        if d > 9 { return Err(Error::InvalidDigit); }
+        n = n * 10 + d as u64;
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u64(s: &[u8]) -> Result<u64, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let head = s.len() % 8;
+    let mut n = try!(small(&s[..head]));
+    for chunk in s[head..].chunks(8) {
+        let d = try!(eight(chunk));
+        n = match n.checked_mul(1_0000_0000).and_then(|n| n.checked_add(d)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u32(s: &[u8]) -> Result<u32, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let head = s.len() % 8;
+    let mut n = try!(small(&s[..head]));
+    for chunk in s[head..].chunks(8) {
+        let d = try!(eight(chunk));
+        // n <= 2^32 - 1 so n * 10^8 + d < 2^64
+        n = n * 1_0000_0000 + d;
+        if n > u32::MAX as u64 { return Err(Error::Overflow); }
+    }
+    Ok(n as u32)
+}
+
+// 1844 6744 0737 0955 1615
+pub fn digits_to_u64(d: &Digits64) -> Result<u64, Error> {
+    let d0 = try!(small(&d[..4]));
+    let d1 = try!(eight(&d[4..12]));
+    let d2 = try!(eight(&d[12..]));
+    // d0 * 10^8 + d1 < 10^12 cannot overflow, but the final one can
+    match (d0 * 1_0000_0000 + d1).checked_mul(1_0000_0000).and_then(|n| n.checked_add(d2)) {
+        Some(n) => Ok(n),
+        None => Err(Error::Overflow),
+    }
+}
+
+// 42 9496 7295
+pub fn digits_to_u32(d: &Digits32) -> Result<u32, Error> {
+    let d0 = try!(small(&d[..2]));
+    let d1 = try!(eight(&d[2..]));
+    let n = d0 * 1_0000_0000 + d1;
+    if n > u32::MAX as u64 { return Err(Error::Overflow); }
+    Ok(n as u32)
+}
+
+signed_from_dec! {
+    dec_to_i64(i64) => dec_to_u64(u64);
+    dec_to_i32(i32) => dec_to_u32(u32);
+}
@@ -0,0 +1,147 @@
+use core::prelude::*;
+
+use int2dec::{Digits64, Digits32};
+use dec2int::Error;
+
+// `TENS[c] + ONES[d]` is a two-digit number `cd` if both `c` and `d` are digits,
+// or at least 0x100 otherwise. this avoids checking each digit separately.
+static TENS: [u16; 256] = [
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x000, 0x00a, 0x014, 0x01e, 0x028, 0x032, 0x03c, 0x046, 0x050, 0x05a, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+];
+
+static ONES: [u16; 256] = [
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x000, 0x001, 0x002, 0x003, 0x004, 0x005, 0x006, 0x007, 0x008, 0x009, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+    0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100, 0x100,
+];
+
+pub fn dec_to_u64(s: &[u8]) -> Result<u64, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u64 = 0;
+    let mut i = 0;
+    if s.len() % 2 == 1 {
+        let d = ONES[s[0] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = d as u64;
+        i = 1;
+    }
+    while i < s.len() {
+        let d = TENS[s[i] as usize] + ONES[s[i+1] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(100).and_then(|n| n.checked_add(d as u64)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+        i += 2;
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u32(s: &[u8]) -> Result<u32, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u32 = 0;
+    let mut i = 0;
+    if s.len() % 2 == 1 {
+        let d = ONES[s[0] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = d as u32;
+        i = 1;
+    }
+    while i < s.len() {
+        let d = TENS[s[i] as usize] + ONES[s[i+1] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(100).and_then(|n| n.checked_add(d as u32)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+        i += 2;
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u16(s: &[u8]) -> Result<u16, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u16 = 0;
+    let mut i = 0;
+    if s.len() % 2 == 1 {
+        let d = ONES[s[0] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = d as u16;
+        i = 1;
+    }
+    while i < s.len() {
+        let d = TENS[s[i] as usize] + ONES[s[i+1] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(100).and_then(|n| n.checked_add(d as u16)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+        i += 2;
+    }
+    Ok(n)
+}
+
+pub fn dec_to_u8(s: &[u8]) -> Result<u8, Error> {
+    if s.is_empty() { return Err(Error::Empty); }
+    let mut n: u8 = 0;
+    let mut i = 0;
+    if s.len() % 2 == 1 {
+        let d = ONES[s[0] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = d as u8;
+        i = 1;
+    }
+    while i < s.len() {
+        let d = TENS[s[i] as usize] + ONES[s[i+1] as usize];
+        if d >= 0x100 { return Err(Error::InvalidDigit); }
+        n = match n.checked_mul(100).and_then(|n| n.checked_add(d as u8)) {
+            Some(n) => n,
+            None => return Err(Error::Overflow),
+        };
+        i += 2;
+    }
+    Ok(n)
+}
+
+pub fn digits_to_u64(d: &Digits64) -> Result<u64, Error> {
+    dec_to_u64(d)
+}
+
+pub fn digits_to_u32(d: &Digits32) -> Result<u32, Error> {
+    dec_to_u32(d)
+}
+
+signed_from_dec! {
+    dec_to_i64(i64) => dec_to_u64(u64);
+    dec_to_i32(i32) => dec_to_u32(u32);
+    dec_to_i16(i16) => dec_to_u16(u16);
+    dec_to_i8(i8) => dec_to_u8(u8);
+}
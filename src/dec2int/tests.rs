@@ -0,0 +1,330 @@
+use std::prelude::v1::*;
+use std::{str, fmt};
+use std::ops::{Add, Shr};
+use std::num::One;
+use std::num::wrapping::OverflowingOps;
+use std::slice::bytes;
+use test;
+
+use int2dec::{Digits64, Digits32};
+use dec2int::Error;
+
+pub use test::Bencher;
+
+// "18446744073709551615" -> "18446744073709551616".
+// the maximum of any integral type never ends with 9, so this is easy.
+fn succ(s: &str) -> String {
+    let mut s = s.to_string().into_bytes();
+    let last = s.len() - 1;
+    assert!(s[last] != b'9');
+    s[last] += 1;
+    String::from_utf8(s).unwrap()
+}
+
+static INVALID_BYTES: &'static [u8] = b"/:a \x00\xff\xb0\xb9";
+
+macro_rules! unsigned_sanity_test {
+    ($name:ident: $t:ident) => (
+        pub fn $name<F: FnMut(&[u8]) -> Result<$t, Error>>(mut f: F) {
+            use std::$t;
+
+            assert_eq!(f(b""), Err(Error::Empty));
+            assert_eq!(f(b"0"), Ok(0));
+            assert_eq!(f(b"1"), Ok(1));
+            assert_eq!(f(b"12"), Ok(12));
+            assert_eq!(f(b"123"), Ok(123));
+            assert_eq!(f(b"0000000000000000000000000000000000000000"), Ok(0));
+            assert_eq!(f(b"0000000000000000000000000000000000000042"), Ok(42));
+            assert_eq!(f(b"+1"), Err(Error::InvalidDigit));
+            assert_eq!(f(b"-1"), Err(Error::InvalidDigit));
+            assert_eq!(f(b"-0"), Err(Error::InvalidDigit));
+
+            let max = $t::MAX.to_string();
+            assert_eq!(f(max.as_bytes()), Ok($t::MAX));
+            assert_eq!(f(format!("0000000000{}", max).as_bytes()), Ok($t::MAX));
+            assert_eq!(f(succ(&max).as_bytes()), Err(Error::Overflow));
+            assert_eq!(f(format!("{}0", max).as_bytes()), Err(Error::Overflow));
+            assert_eq!(f(&vec![b'9'; max.len()]), Err(Error::Overflow));
+            assert_eq!(f(&vec![b'9'; 40]), Err(Error::Overflow));
+
+            // every invalid byte at every position, for every length that does not overflow
+            for len in 1..max.len() {
+                for i in 0..len {
+                    for &c in INVALID_BYTES {
+                        let mut s = vec![b'1'; len];
+                        s[i] = c;
+                        assert_eq!(f(&s), Err(Error::InvalidDigit));
+                    }
+                }
+            }
+
+            // all powers of 10 and their neighbors
+            let mut n: $t = 1;
+            loop {
+                for &m in &[n - 1, n, n + 1] {
+                    assert_eq!(f(m.to_string().as_bytes()), Ok(m));
+                }
+                match n.checked_mul(10) {
+                    Some(n_) => { n = n_; }
+                    None => { break; }
+                }
+            }
+
+            let mut n: $t = 1;
+            for _ in 0..(::std::mem::size_of::<$t>() * 8) {
+                assert_eq!(f(n.to_string().as_bytes()), Ok(n));
+                assert_eq!(f((!n).to_string().as_bytes()), Ok(!n));
+                n = n.wrapping_mul(3);
+            }
+        }
+    )
+}
+
+macro_rules! signed_sanity_test {
+    ($name:ident: $t:ident) => (
+        pub fn $name<F: FnMut(&[u8]) -> Result<$t, Error>>(mut f: F) {
+            use std::$t;
+
+            assert_eq!(f(b""), Err(Error::Empty));
+            assert_eq!(f(b"-"), Err(Error::Empty));
+            assert_eq!(f(b"+"), Err(Error::Empty));
+            assert_eq!(f(b"0"), Ok(0));
+            assert_eq!(f(b"-0"), Ok(0));
+            assert_eq!(f(b"+0"), Ok(0));
+            assert_eq!(f(b"42"), Ok(42));
+            assert_eq!(f(b"+42"), Ok(42));
+            assert_eq!(f(b"-42"), Ok(-42));
+            assert_eq!(f(b"-0000000000000000000000000000000000000042"), Ok(-42));
+            assert_eq!(f(b"--1"), Err(Error::InvalidDigit));
+            assert_eq!(f(b"+-1"), Err(Error::InvalidDigit));
+            assert_eq!(f(b" 1"), Err(Error::InvalidDigit));
+            assert_eq!(f(b"1-"), Err(Error::InvalidDigit));
+
+            let max = $t::MAX.to_string();
+            let min = $t::MIN.to_string();
+            assert_eq!(f(max.as_bytes()), Ok($t::MAX));
+            assert_eq!(f(format!("+{}", max).as_bytes()), Ok($t::MAX));
+            assert_eq!(f(min.as_bytes()), Ok($t::MIN));
+            assert_eq!(f(succ(&max).as_bytes()), Err(Error::Overflow));
+            assert_eq!(f(format!("+{}", succ(&max)).as_bytes()), Err(Error::Overflow));
+            assert_eq!(f(succ(&min).as_bytes()), Err(Error::Overflow));
+            assert_eq!(f(format!("-{}", succ(&succ(&max))).as_bytes()), Err(Error::Overflow));
+
+            let mut n: $t = 1;
+            for _ in 0..(::std::mem::size_of::<$t>() * 8) {
+                for &m in &[n, !n] {
+                    assert_eq!(f(m.to_string().as_bytes()), Ok(m));
+                }
+                n = n.wrapping_mul(3);
+            }
+        }
+    )
+}
+
+macro_rules! digits_sanity_test {
+    ($name:ident: $t:ident, $Digits:ty, $to_digits:path) => (
+        pub fn $name<F: FnMut(&$Digits) -> Result<$t, Error>>(mut f: F) {
+            use std::$t;
+
+            assert_eq!(f(&$to_digits(0)), Ok(0));
+            assert_eq!(f(&$to_digits(1)), Ok(1));
+            assert_eq!(f(&$to_digits($t::MAX)), Ok($t::MAX));
+
+            let mut n: $t = 1;
+            for _ in 0..(::std::mem::size_of::<$t>() * 8) {
+                assert_eq!(f(&$to_digits(n)), Ok(n));
+                assert_eq!(f(&$to_digits(!n)), Ok(!n));
+                n = n.wrapping_mul(3);
+            }
+
+            let max = $t::MAX.to_string();
+            let mut d = $to_digits(0);
+            bytes::copy_memory(succ(&max).as_bytes(), &mut d);
+            assert_eq!(f(&d), Err(Error::Overflow));
+            for c in d.iter_mut() { *c = b'9'; }
+            assert_eq!(f(&d), Err(Error::Overflow));
+
+            for i in 0..d.len() {
+                for &c in INVALID_BYTES {
+                    let mut d = $to_digits(1);
+                    d[i] = c;
+                    assert_eq!(f(&d), Err(Error::InvalidDigit));
+                }
+            }
+        }
+    )
+}
+
+unsigned_sanity_test!(u64_sanity_test: u64);
+unsigned_sanity_test!(u32_sanity_test: u32);
+unsigned_sanity_test!(u16_sanity_test: u16);
+unsigned_sanity_test!(u8_sanity_test: u8);
+signed_sanity_test!(i64_sanity_test: i64);
+signed_sanity_test!(i32_sanity_test: i32);
+signed_sanity_test!(i16_sanity_test: i16);
+signed_sanity_test!(i8_sanity_test: i8);
+digits_sanity_test!(u64_digits_sanity_test: u64, Digits64, ::int2dec::strategy::naive::u64_to_digits);
+digits_sanity_test!(u32_digits_sanity_test: u32, Digits32, ::int2dec::strategy::naive::u32_to_digits);
+
+// the same set of integers as `int2dec::tests::rotating_bench`, formatted in advance
+pub fn rotating_inputs<I>() -> Vec<I>
+        where I: Copy + One + Add<I,Output=I> + Shr<usize,Output=I> + OverflowingOps {
+    // XXX ugh.
+    let _1: I = One::one();
+    let _3 = _1 + _1 + _1;
+    let _4 = _1 + _1 + _1 + _1;
+
+    let mut inputs = Vec::new();
+    let mut n = _4;
+    for _ in 0..64 {
+        inputs.push(n);
+        n = n.overflowing_add(n >> 2).0;
+    }
+    let mut n = _1;
+    for _ in 0..64 {
+        inputs.push(n);
+        n = n.overflowing_mul(_3).0;
+    }
+    inputs
+}
+
+#[inline(always)]
+pub fn parsing_bench<I, F>(mut f: F, b: &mut Bencher)
+        where I: Copy + One + Add<I,Output=I> + Shr<usize,Output=I> + OverflowingOps + fmt::Display,
+              F: FnMut(&[u8]) -> Result<I, Error> {
+    let inputs: Vec<String> = rotating_inputs::<I>().iter().map(|n| n.to_string()).collect();
+    b.iter(|| {
+        for s in &inputs {
+            test::black_box(f(s.as_bytes()));
+        }
+    });
+}
+
+// per-strategy tests
+macro_rules! per_strategy {
+    ($strategy:ident: $($t:tt)*) => (
+        mod $strategy {
+            use super::super::*;
+            use dec2int::strategy::$strategy::*;
+
+            #[test] fn sanity_test() { per_strategy_sanity_test!($($t)*) }
+            per_strategy_bench!($($t)*);
+        }
+    )
+}
+
+macro_rules! per_strategy_sanity_test {
+    () => ({});
+    (u64 $($t:tt)*) => ({ u64_sanity_test(dec_to_u64); per_strategy_sanity_test!($($t)*) });
+    (u32 $($t:tt)*) => ({ u32_sanity_test(dec_to_u32); per_strategy_sanity_test!($($t)*) });
+    (u16 $($t:tt)*) => ({ u16_sanity_test(dec_to_u16); per_strategy_sanity_test!($($t)*) });
+    (u8 $($t:tt)*) => ({ u8_sanity_test(dec_to_u8); per_strategy_sanity_test!($($t)*) });
+    (i64 $($t:tt)*) => ({ i64_sanity_test(dec_to_i64); per_strategy_sanity_test!($($t)*) });
+    (i32 $($t:tt)*) => ({ i32_sanity_test(dec_to_i32); per_strategy_sanity_test!($($t)*) });
+    (i16 $($t:tt)*) => ({ i16_sanity_test(dec_to_i16); per_strategy_sanity_test!($($t)*) });
+    (i8 $($t:tt)*) => ({ i8_sanity_test(dec_to_i8); per_strategy_sanity_test!($($t)*) });
+    (u64_digits $($t:tt)*) => ({
+        u64_digits_sanity_test(digits_to_u64); per_strategy_sanity_test!($($t)*)
+    });
+    (u32_digits $($t:tt)*) => ({
+        u32_digits_sanity_test(digits_to_u32); per_strategy_sanity_test!($($t)*)
+    });
+}
+
+macro_rules! per_strategy_bench {
+    () => ();
+    (u64 $($t:tt)*) => (
+        #[bench] fn bench_u64(b: &mut Bencher) { parsing_bench(dec_to_u64, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (u32 $($t:tt)*) => (
+        #[bench] fn bench_u32(b: &mut Bencher) { parsing_bench(dec_to_u32, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (u16 $($t:tt)*) => (
+        #[bench] fn bench_u16(b: &mut Bencher) { parsing_bench(dec_to_u16, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (u8 $($t:tt)*) => (
+        #[bench] fn bench_u8(b: &mut Bencher) { parsing_bench(dec_to_u8, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i64 $($t:tt)*) => (
+        #[bench] fn bench_i64(b: &mut Bencher) { parsing_bench(dec_to_i64, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i32 $($t:tt)*) => (
+        #[bench] fn bench_i32(b: &mut Bencher) { parsing_bench(dec_to_i32, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i16 $($t:tt)*) => (
+        #[bench] fn bench_i16(b: &mut Bencher) { parsing_bench(dec_to_i16, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i8 $($t:tt)*) => (
+        #[bench] fn bench_i8(b: &mut Bencher) { parsing_bench(dec_to_i8, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (u64_digits $($t:tt)*) => (
+        #[bench] fn bench_u64_digits(b: &mut Bencher) {
+            let inputs: Vec<_> = rotating_inputs::<u64>().into_iter()
+                                     .map(::int2dec::best::u64_to_digits).collect();
+            b.iter(|| {
+                for d in &inputs {
+                    test::black_box(digits_to_u64(d));
+                }
+            });
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u32_digits $($t:tt)*) => (
+        #[bench] fn bench_u32_digits(b: &mut Bencher) {
+            let inputs: Vec<_> = rotating_inputs::<u32>().into_iter()
+                                     .map(::int2dec::best::u32_to_digits).collect();
+            b.iter(|| {
+                for d in &inputs {
+                    test::black_box(digits_to_u32(d));
+                }
+            });
+        }
+        per_strategy_bench!($($t)*);
+    );
+}
+
+mod strategy {
+    per_strategy!(naive: u64 u32 u16 u8  i64 i32 i16 i8  u64_digits u32_digits);
+    per_strategy!(table: u64 u32 u16 u8  i64 i32 i16 i8  u64_digits u32_digits);
+    per_strategy!(swar:  u64 u32         i64 i32         u64_digits u32_digits);
+
+    mod best {
+        use super::super::*;
+        use dec2int::strategy::best::*;
+        #[test] fn sanity_test() {
+            per_strategy_sanity_test!(u64 u32 u16 u8 i64 i32 i16 i8 u64_digits u32_digits)
+        }
+        per_strategy_bench!(u64 u32 u16 u8 i64 i32 i16 i8 u64_digits u32_digits);
+    }
+}
+
+macro_rules! make_bench {
+    ($t:ty, $conv:ident: $system:ident vs $best:ident) => (
+        #[bench]
+        fn $system(b: &mut test::Bencher) {
+            parsing_bench(|s| Ok(str::from_utf8(s).unwrap().parse::<$t>().unwrap()), b)
+        }
+
+        #[bench]
+        fn $best(b: &mut test::Bencher) {
+            parsing_bench(::dec2int::best::$conv, b)
+        }
+    )
+}
+
+make_bench!(u64, dec_to_u64: bench_u64_system vs bench_u64_best);
+make_bench!(u32, dec_to_u32: bench_u32_system vs bench_u32_best);
+make_bench!(u16, dec_to_u16: bench_u16_system vs bench_u16_best);
+make_bench!(u8, dec_to_u8: bench_u8_system vs bench_u8_best);
+make_bench!(i64, dec_to_i64: bench_i64_system vs bench_i64_best);
+make_bench!(i32, dec_to_i32: bench_i32_system vs bench_i32_best);
+make_bench!(i16, dec_to_i16: bench_i16_system vs bench_i16_best);
+make_bench!(i8, dec_to_i8: bench_i8_system vs bench_i8_best);
@@ -0,0 +1,78 @@
+//! Densely packed decimal (DPD) encoding of the trailing significand.
+//!
+//! Every group of three decimal digits is packed into a 10-bit "declet".
+//! Digits from 0 to 7 are stored in three bits as is, and the `v` bit (bit 3) and
+//! the following bits tell which digits are 8 or 9 and thus only need one bit.
+//! The resulting declets for `000` to `079` coincide with the binary coded decimal.
+//!
+//! 24 out of 1024 declets are non-canonical, that is, never produced by `pack_declet`.
+//! They are still decoded to the valid three digits as IEEE 754 requires.
+
+use core::prelude::*;
+
+/// Unpacks a declet `pqr stu v wxy` into an integer between 0 and 999.
+/// Only the lowest 10 bits are used.
+pub fn unpack_declet(d: u16) -> u16 {
+    let pqr = (d >> 7) & 7;
+    let stu = (d >> 4) & 7;
+    let wxy = d & 7;
+    let (r, u, y) = (pqr & 1, stu & 1, wxy & 1);
+
+    let (d1, d2, d3) = if d & 0b1000 == 0 {
+        (pqr, stu, wxy)
+    } else {
+        match (d >> 1) & 3 { // wx
+            0b00 => (pqr, stu, 8 + y),
+            0b01 => (pqr, 8 + u, (stu & 6) | y),
+            0b10 => (8 + r, stu, (pqr & 6) | y),
+            _ => match (d >> 5) & 3 { // st
+                0b00 => (8 + r, 8 + u, (pqr & 6) | y),
+                0b01 => (8 + r, (pqr & 6) | u, 8 + y),
+                0b10 => (pqr, 8 + u, 8 + y),
+                _ => (8 + r, 8 + u, 8 + y),
+            },
+        }
+    };
+    d1 * 100 + d2 * 10 + d3
+}
+
+/// Packs an integer between 0 and 999 into a canonical declet.
+pub fn pack_declet(n: u16) -> u16 {
+    debug_assert!(n < 1000);
+    let (d1, d2, d3) = (n / 100, n / 10 % 10, n % 10);
+    let (d, h, m) = (d1 & 1, d2 & 1, d3 & 1);
+
+    // the table is indexed by `aei`, the highest bits of three digits
+    match (d1 >> 3, d2 >> 3, d3 >> 3) {
+        (0, 0, 0) => (d1 << 7) | (d2 << 4) | d3,
+        (0, 0, _) => (d1 << 7) | (d2 << 4) | 0b1000 | m,
+        (0, _, 0) => (d1 << 7) | ((d3 & 6) << 4) | (h << 4) | 0b1010 | m,
+        (0, _, _) => (d1 << 7) | (0b100 << 4) | (h << 4) | 0b1110 | m,
+        (_, 0, 0) => ((d3 & 6) << 7) | (d << 7) | (d2 << 4) | 0b1100 | m,
+        (_, 0, _) => ((d2 & 6) << 7) | (d << 7) | (0b010 << 4) | (h << 4) | 0b1110 | m,
+        (_, _, 0) => ((d3 & 6) << 7) | (d << 7) | (h << 4) | 0b1110 | m,
+        (_, _, _) => (d << 7) | (0b110 << 4) | (h << 4) | 0b1110 | m,
+    }
+}
+
+/// Unpacks `ndeclets` declets from the lowest bits of `bits` into an integer
+/// less than `10^(3*ndeclets)`. The lowest declet holds the lowest three digits.
+pub fn unpack(bits: u128, ndeclets: u32) -> u128 {
+    let mut n = 0;
+    for i in (0..ndeclets).rev() {
+        n = n * 1000 + unpack_declet((bits >> (10 * i)) as u16 & 0x3ff) as u128;
+    }
+    n
+}
+
+/// Packs an integer less than `10^(3*ndeclets)` into `ndeclets` declets.
+/// This is the inverse of `unpack` for canonical declets.
+pub fn pack(mut n: u128, ndeclets: u32) -> u128 {
+    let mut bits = 0;
+    for i in 0..ndeclets {
+        bits |= (pack_declet((n % 1000) as u16) as u128) << (10 * i);
+        n /= 1000;
+    }
+    debug_assert_eq!(n, 0);
+    bits
+}
@@ -0,0 +1,664 @@
+/*!
+
+IEEE 754-2008 decimal floating-point types and their conversions from and to strings.
+
+# Representation
+
+A finite decimal floating-point value is `(-1)^s * c * 10^q` with an integer coefficient
+`0 <= c < 10^p` and an exponent `qmin <= q <= qmax`. Unlike binary formats the coefficient
+is not normalized, so a value generally has multiple representations: `1.5` can be
+`15 * 10^-1`, `150 * 10^-2` and so on. Such a set is called a *cohort*, and the exponent
+of each member is its *quantum*. The quantum is significant (`1.50` is not `1.5`),
+and is preserved by the decoding, the encoding and the parsing.
+
+IEEE 754-2008 defines three interchange formats:
+
+Format      | `p` | `qmin` | `qmax` | Bits of `w` | Bits of `t`
+----------- | ---:| ------:| ------:| -----------:| -----------:
+decimal32   |   7 |   -101 |     90 |           6 |          20
+decimal64   |  16 |   -398 |    369 |           8 |          50
+decimal128  |  34 |  -6176 |   6111 |          12 |         110
+
+Each of them is encoded as a sign bit, a `w+5`-bit combination field `G` and
+a `t`-bit trailing significand field `T`. The first five bits of `G` distinguish
+NaNs (`11111`, where the sixth bit tells a signaling NaN) and infinities (`11110`).
+Otherwise `G` contains the biased exponent `q - qmin` and the leading bits or digit
+of the coefficient, and `T` contains the rest of the coefficient in one of two ways:
+
+- The binary integer decimal (BID) encoding stores the coefficient as a binary integer.
+  The coefficient is `T` prefixed by three bits from `G`, or `100` and one more bit
+  if the first two bits of `G` are `11`. Coefficients no less than `10^p` are
+  non-canonical and are decoded as zero.
+
+- The densely packed decimal (DPD) encoding stores the leading digit of the coefficient
+  in `G` and the remaining `p-1` digits in `T`, three digits per 10-bit declet
+  (see the `dpd` module).
+
+Both encodings are provided for every format as a newtype over its bit pattern,
+so there are six types from `Bid32` to `Dpd128`. They do not support any arithmetic.
+
+# Conversions
+
+`decode` and `encode` convert between these types and the `FullDecoded` value.
+`parse` reads a decimal string, rounding it to the precision of the type (ties to even).
+
+The formatting functions mirror those in `flt2dec` and produce the same `Formatted` value.
+No digit-generation strategy is needed, since the coefficient is already in decimal:
+
+- `to_shortest_str` and `to_shortest_exp_str` print the shortest representation
+  of the value, i.e. ignore the quantum.
+
+- `to_exact_exp_str` and `to_exact_fixed_str` round to given number of digits
+  (ties to even). When the number of digits is not given, they print exactly
+  the digits of the coefficient, i.e. preserve the quantum.
+
+- `to_sci_str` is the to-scientific-string conversion recommended by IEEE 754-2008
+  and the General Decimal Arithmetic specification, which is the only form
+  that can always tell every member of the cohort apart.
+
+*/
+
+use core::prelude::*;
+
+use dec2flt::Error;
+use flt2dec::{Part, Formatted, Sign, digits_to_dec_str, digits_to_exp_str};
+
+pub use self::parse::parse_decoded;
+
+pub mod dpd;
+pub mod parse;
+
+#[cfg(test)] mod tests;
+
+/// The maximum number of digits in the coefficient, i.e. `p` for decimal128.
+/// This is also the minimum size of the buffer for the formatting functions.
+pub const MAX_SIG_DIGITS: usize = 34;
+
+/// The encoding of the coefficient.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    /// Binary integer decimal.
+    Bid,
+    /// Densely packed decimal.
+    Dpd,
+}
+
+/// The decimal floating-point format to be decoded or encoded.
+///
+/// Every finite value of the format is `coeff * 10^exp` with an integer
+/// `0 <= coeff < 10^digits` and `min_exp <= exp <= max_exp`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Format {
+    /// The total number of bits, which is 32, 64 or 128.
+    pub width: u32,
+    /// The number of coefficient digits, which is 7, 16 or 34.
+    pub digits: u32,
+    /// The smallest quantum, that is, `10^min_exp` is the smallest positive value.
+    pub min_exp: i16,
+    /// The largest quantum, that is, `(10^digits - 1) * 10^max_exp`
+    /// is the largest finite value.
+    pub max_exp: i16,
+    /// The encoding of the coefficient.
+    pub encoding: Encoding,
+}
+
+impl Format {
+    /// Returns the number of bits in the combination field minus 5 (`w` in IEEE 754).
+    pub fn ecbits(&self) -> u32 {
+        self.width / 16 + 4
+    }
+
+    /// Returns the number of bits in the trailing significand field (`t` in IEEE 754).
+    pub fn tbits(&self) -> u32 {
+        self.width / 16 * 15 - 10
+    }
+}
+
+/// Decoded unsigned finite value `coeff * 10^exp`.
+///
+/// Zeroes are also finite values; `coeff` is zero but `exp` is still their quantum.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Decoded {
+    /// The integer coefficient.
+    pub coeff: u128,
+    /// The exponent in base 10.
+    pub exp: i16,
+}
+
+/// Decoded unsigned value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FullDecoded {
+    /// Not-a-number, either quiet or signaling, with an integer payload less than
+    /// `10^(digits-1)`. The non-canonical payload is decoded as zero.
+    Nan { signaling: bool, payload: u128 },
+    /// Infinities, either positive or negative.
+    Infinite,
+    /// Finite numbers including zeroes.
+    Finite(Decoded),
+}
+
+/// A decimal floating-point type which can be `decode`d and `encode`d.
+pub trait DecimalFloat: Copy {
+    /// Returns the format of this type.
+    fn format() -> Format;
+    /// Returns the bit pattern of given value, zero-extended to `u128`.
+    fn to_bits(self) -> u128;
+    /// Makes a value from the lowest `width` bits of given bit pattern.
+    fn from_bits(bits: u128) -> Self;
+}
+
+macro_rules! define_decimal {
+    ($(#[$attr:meta])* struct $name:ident($ty:ty): width=$width:expr, digits=$digits:expr,
+     min_exp=$min_exp:expr, max_exp=$max_exp:expr, encoding=$encoding:ident) => (
+        $(#[$attr])*
+        ///
+        /// The equality is bitwise, so the members of the same cohort are not equal,
+        /// nor are the positive and negative zeroes. NaNs can equal to each other.
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub struct $name(pub $ty);
+
+        impl DecimalFloat for $name {
+            fn format() -> Format {
+                Format { width: $width, digits: $digits, min_exp: $min_exp, max_exp: $max_exp,
+                         encoding: Encoding::$encoding }
+            }
+            fn to_bits(self) -> u128 { self.0 as u128 }
+            fn from_bits(bits: u128) -> $name { $name(bits as $ty) }
+        }
+    )
+}
+
+define_decimal! {
+    /// IEEE 754-2008 decimal32 number in the BID encoding.
+    struct Bid32(u32): width=32, digits=7, min_exp=-101, max_exp=90, encoding=Bid
+}
+define_decimal! {
+    /// IEEE 754-2008 decimal64 number in the BID encoding.
+    struct Bid64(u64): width=64, digits=16, min_exp=-398, max_exp=369, encoding=Bid
+}
+define_decimal! {
+    /// IEEE 754-2008 decimal128 number in the BID encoding.
+    struct Bid128(u128): width=128, digits=34, min_exp=-6176, max_exp=6111, encoding=Bid
+}
+define_decimal! {
+    /// IEEE 754-2008 decimal32 number in the DPD encoding.
+    struct Dpd32(u32): width=32, digits=7, min_exp=-101, max_exp=90, encoding=Dpd
+}
+define_decimal! {
+    /// IEEE 754-2008 decimal64 number in the DPD encoding.
+    struct Dpd64(u64): width=64, digits=16, min_exp=-398, max_exp=369, encoding=Dpd
+}
+define_decimal! {
+    /// IEEE 754-2008 decimal128 number in the DPD encoding.
+    struct Dpd128(u128): width=128, digits=34, min_exp=-6176, max_exp=6111, encoding=Dpd
+}
+
+/// Returns `10^n`. `n` should be no more than 38.
+#[doc(hidden)]
+pub fn pow10(n: u32) -> u128 {
+    let mut v = 1;
+    for _ in 0..n { v *= 10; }
+    v
+}
+
+/// Returns a sign (true when negative) and `FullDecoded` value from given decimal
+/// floating-point number.
+pub fn decode<T: DecimalFloat>(v: T) -> (/*negative?*/ bool, FullDecoded) {
+    let format = <T as DecimalFloat>::format();
+    let w = format.ecbits();
+    let t = format.tbits();
+    let bits = v.to_bits();
+
+    let negative = (bits >> (format.width - 1)) & 1 != 0;
+    let comb = (bits >> t) as u32 & ((1 << (w + 5)) - 1);
+    let trailing = bits & ((1 << t) - 1);
+    let trailing = match format.encoding {
+        Encoding::Bid => trailing,
+        Encoding::Dpd => dpd::unpack(trailing, t / 10),
+    };
+
+    let decoded = match comb >> w {
+        0b11111 => {
+            let payload = if trailing < pow10(format.digits - 1) { trailing } else { 0 };
+            FullDecoded::Nan { signaling: (comb >> (w - 1)) & 1 != 0, payload: payload }
+        }
+        0b11110 => FullDecoded::Infinite,
+        top => {
+            let (biased, coeff) = match format.encoding {
+                // [ee...e][ccc] [t...t] or [11][ee...e][c] [t...t] for `100c t...t`
+                Encoding::Bid if top >> 3 != 0b11 => {
+                    (comb >> 3, ((comb & 7) as u128) << t | trailing)
+                }
+                Encoding::Bid => {
+                    ((comb >> 1) & ((1 << (w + 2)) - 1), ((8 | (comb & 1)) as u128) << t | trailing)
+                }
+                // [ee][ddd][e...e] or [11][ee][d][e...e] for the leading digit `100d`
+                Encoding::Dpd => {
+                    let (etop, lead) = if top >> 3 != 0b11 { (top >> 3, top & 7) }
+                                       else { ((top >> 1) & 3, 8 | (top & 1)) };
+                    ((etop << w) | (comb & ((1 << w) - 1)),
+                     lead as u128 * pow10(format.digits - 1) + trailing)
+                }
+            };
+            let coeff = if coeff < pow10(format.digits) { coeff } else { 0 };
+            let exp = (biased as i32 + format.min_exp as i32) as i16;
+            FullDecoded::Finite(Decoded { coeff: coeff, exp: exp })
+        }
+    };
+    (negative, decoded)
+}
+
+/// Encodes a sign (true when negative) and `FullDecoded` value into the decimal
+/// floating-point number. Returns `None` if the value is not representable,
+/// i.e. the coefficient, the exponent or the payload is out of the range.
+/// The result is always canonical.
+pub fn encode<T: DecimalFloat>(negative: bool, v: &FullDecoded) -> Option<T> {
+    let format = <T as DecimalFloat>::format();
+    let w = format.ecbits();
+    let t = format.tbits();
+
+    let (comb, trailing) = match *v {
+        FullDecoded::Nan { signaling, payload } => {
+            if payload >= pow10(format.digits - 1) { return None; }
+            let trailing = match format.encoding {
+                Encoding::Bid => payload,
+                Encoding::Dpd => dpd::pack(payload, t / 10),
+            };
+            ((0b11111 << w) | ((signaling as u32) << (w - 1)), trailing)
+        }
+        FullDecoded::Infinite => (0b11110 << w, 0),
+        FullDecoded::Finite(Decoded { coeff, exp }) => {
+            if coeff >= pow10(format.digits) { return None; }
+            if exp < format.min_exp || exp > format.max_exp { return None; }
+            let biased = (exp as i32 - format.min_exp as i32) as u32;
+            match format.encoding {
+                Encoding::Bid if coeff >> (t + 3) == 0 => {
+                    ((biased << 3) | (coeff >> t) as u32, coeff & ((1 << t) - 1))
+                }
+                Encoding::Bid => {
+                    ((0b11 << (w + 3)) | (biased << 1) | ((coeff >> t) as u32 & 1),
+                     coeff & ((1 << t) - 1))
+                }
+                Encoding::Dpd => {
+                    let scale = pow10(format.digits - 1);
+                    let lead = (coeff / scale) as u32;
+                    let etop = biased >> w;
+                    let top = if lead < 8 { (etop << 3) | lead }
+                              else { 0b11000 | (etop << 1) | (lead & 1) };
+                    ((top << w) | (biased & ((1 << w) - 1)), dpd::pack(coeff % scale, t / 10))
+                }
+            }
+        }
+    };
+
+    let sign = (negative as u128) << (format.width - 1);
+    Some(<T as DecimalFloat>::from_bits(sign | (comb as u128) << t | trailing))
+}
+
+/// Parses given string into the decimal floating-point value of the type `T`.
+///
+/// The string consists of an optional sign (`+` or `-`), followed by either
+/// a decimal number in the usual or exponential form (`12`, `1.`, `.5`, `1.2e-3`),
+/// `inf` or `infinity`, or `nan` or `snan` optionally followed by the decimal payload
+/// (`nan123`). The keywords are case-insensitive. Any leading or trailing whitespace
+/// is not allowed. The sign is kept even for NaNs.
+///
+/// Every digit, including trailing zeroes, contributes to the coefficient;
+/// `1.50` results in `150 * 10^-2`. If there are more than `digits` significant digits,
+/// or the exponent is below `min_exp`, the coefficient is correctly rounded (ties to even)
+/// which may result in zero. If the exponent is above `max_exp`, the coefficient is
+/// padded with zeroes when possible, or otherwise the result is an infinity.
+/// Zeroes are clamped to the valid exponent range.
+pub fn parse<T: DecimalFloat>(s: &[u8]) -> Result<T, Error> {
+    let format = <T as DecimalFloat>::format();
+    let (negative, decoded) = try!(parse_decoded(s, &format));
+    Ok(encode(negative, &decoded).unwrap())
+}
+
+/// Returns the static byte string corresponding to the sign to be formatted.
+/// It can be either `b""`, `b"+"` or `b"-"`.
+fn determine_sign(sign: Sign, decoded: &FullDecoded, negative: bool) -> &'static [u8] {
+    match (*decoded, sign) {
+        (FullDecoded::Nan { .. }, _) => b"",
+        (FullDecoded::Finite(Decoded { coeff: 0, .. }), Sign::Minus) => b"",
+        (FullDecoded::Finite(Decoded { coeff: 0, .. }), Sign::MinusRaw) =>
+            if negative { b"-" } else { b"" },
+        (FullDecoded::Finite(Decoded { coeff: 0, .. }), Sign::MinusPlus) => b"+",
+        (FullDecoded::Finite(Decoded { coeff: 0, .. }), Sign::MinusPlusRaw) =>
+            if negative { b"-" } else { b"+" },
+        (_, Sign::Minus) | (_, Sign::MinusRaw) => if negative { b"-" } else { b"" },
+        (_, Sign::MinusPlus) | (_, Sign::MinusPlusRaw) => if negative { b"-" } else { b"+" },
+    }
+}
+
+/// Writes the decimal digits of `n` to the buffer and returns the number of digits.
+/// `n` should be non-zero.
+fn write_digits(mut n: u128, buf: &mut [u8]) -> usize {
+    debug_assert!(n > 0);
+    let mut len = 0;
+    while n > 0 {
+        buf[len] = b'0' + (n % 10) as u8;
+        n /= 10;
+        len += 1;
+    }
+    buf[..len].reverse();
+    len
+}
+
+/// Rounds `coeff * 10^exp` to the multiple of `10^limit` (ties to even).
+/// Returns the new coefficient and exponent, which is at least `limit`.
+fn round_to_limit(coeff: u128, exp: i32, limit: i32) -> (u128, i32) {
+    if exp >= limit { return (coeff, exp); }
+    if limit - exp > 38 { return (0, limit); } // the coefficient is less than `10^34`
+    let scale = pow10((limit - exp) as u32);
+    let (q, r) = (coeff / scale, coeff % scale);
+    if r * 2 > scale || (r * 2 == scale && q & 1 != 0) { (q + 1, limit) } else { (q, limit) }
+}
+
+/// Formats NaNs and infinities. NaNs are followed by their payload if it is non-zero.
+fn special_to_parts<'a>(decoded: &FullDecoded, upper: bool,
+                        buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> &'a [Part<'a>] {
+    match *decoded {
+        FullDecoded::Nan { signaling, payload } => {
+            let nan: &[u8] = match (signaling, upper) {
+                (false, false) => b"nan", (false, true) => b"NAN",
+                (true, false) => b"snan", (true, true) => b"SNAN",
+            };
+            parts[0] = Part::Copy(nan);
+            if payload > 0 {
+                let len = write_digits(payload, buf);
+                parts[1] = Part::Copy(&buf[..len]);
+                &parts[..2]
+            } else {
+                &parts[..1]
+            }
+        }
+        FullDecoded::Infinite => {
+            parts[0] = Part::Copy(if upper { b"INF" } else { b"inf" });
+            &parts[..1]
+        }
+        FullDecoded::Finite(..) => unreachable!(),
+    }
+}
+
+/// Formats given decimal floating point number into the decimal form with at least
+/// given number of fractional digits, like `flt2dec::to_shortest_str`.
+/// The trailing zeroes of the coefficient are not printed unless requested
+/// by `frac_digits`, so the quantum is not preserved. `upper` is only used to
+/// determine the case of non-finite values, i.e. `inf`, `nan` and `snan`.
+///
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// There should be at least 4 parts available, due to the worst case like
+/// `[0.][0000][45][0000]` with `frac_digits = 10`.
+pub fn to_shortest_str<'a, T>(v: T, sign: Sign, frac_digits: usize, upper: bool,
+                              buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecimalFloat {
+    assert!(parts.len() >= 4);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Finite(Decoded { coeff: 0, .. }) => {
+            if frac_digits > 0 { // [0.][0000]
+                parts[0] = Part::Copy(b"0.");
+                parts[1] = Part::Zero(frac_digits);
+                Formatted { sign: sign, parts: &parts[..2] }
+            } else {
+                parts[0] = Part::Copy(b"0");
+                Formatted { sign: sign, parts: &parts[..1] }
+            }
+        }
+        FullDecoded::Finite(ref decoded) => {
+            let len = write_digits(decoded.coeff, buf);
+            let exp = (decoded.exp as i32 + len as i32) as i16;
+            let len = buf[..len].iter().rposition(|&c| c != b'0').unwrap() + 1;
+            Formatted { sign: sign,
+                        parts: digits_to_dec_str(&buf[..len], exp, frac_digits, parts) }
+        }
+        ref decoded => {
+            Formatted { sign: sign, parts: special_to_parts(decoded, upper, buf, parts) }
+        }
+    }
+}
+
+/// Formats given decimal floating point number into the decimal form or
+/// the exponential form, depending on the resulting exponent,
+/// like `flt2dec::to_shortest_exp_str`. The trailing zeroes of the coefficient
+/// are not printed, so the quantum is not preserved. `upper` is used to determine
+/// the case of non-finite values (`inf`, `nan` and `snan`) or the case of
+/// the exponent prefix (`e` or `E`).
+///
+/// The `dec_bounds` is a tuple `(lo, hi)` such that the number is formatted
+/// as decimal only when `10^lo <= V < 10^hi`.
+///
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// There should be at least 6 parts available, due to the worst case like
+/// `[1][.][2345][e][-][67]`.
+pub fn to_shortest_exp_str<'a, T>(v: T, sign: Sign, dec_bounds: (i16, i16), upper: bool,
+                                  buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecimalFloat {
+    assert!(parts.len() >= 6);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+    assert!(dec_bounds.0 <= dec_bounds.1);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Finite(Decoded { coeff: 0, .. }) => {
+            parts[0] = if dec_bounds.0 <= 0 && 0 < dec_bounds.1 {
+                Part::Copy(b"0")
+            } else {
+                Part::Copy(if upper { b"0E0" } else { b"0e0" })
+            };
+            Formatted { sign: sign, parts: &parts[..1] }
+        }
+        FullDecoded::Finite(ref decoded) => {
+            let len = write_digits(decoded.coeff, buf);
+            let exp = (decoded.exp as i32 + len as i32) as i16;
+            let len = buf[..len].iter().rposition(|&c| c != b'0').unwrap() + 1;
+            let vis_exp = exp as i32 - 1;
+            let parts = if dec_bounds.0 as i32 <= vis_exp && vis_exp < dec_bounds.1 as i32 {
+                digits_to_dec_str(&buf[..len], exp, 0, parts)
+            } else {
+                digits_to_exp_str(&buf[..len], exp, 0, upper, parts)
+            };
+            Formatted { sign: sign, parts: parts }
+        }
+        ref decoded => {
+            Formatted { sign: sign, parts: special_to_parts(decoded, upper, buf, parts) }
+        }
+    }
+}
+
+/// Formats given decimal floating point number into the exponential form,
+/// like `flt2dec::to_exact_exp_str`. `upper` is used to determine the case of
+/// non-finite values (`inf`, `nan` and `snan`) or the case of the exponent prefix.
+///
+/// When `ndigits` is `None`, every digit of the coefficient is printed including
+/// trailing zeroes, so `1.50` prints as `1.50e0` and zeroes print as `0e<exp>`.
+/// Otherwise the value is correctly rounded (ties to even) to *exactly* given number
+/// of significant digits, padded by zeroes if needed.
+///
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// There should be at least 6 parts available, due to the worst case like
+/// `[1][.][2345][e][-][67]`.
+pub fn to_exact_exp_str<'a, T>(v: T, sign: Sign, ndigits: Option<usize>, upper: bool,
+                               buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecimalFloat {
+    assert!(parts.len() >= 6);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+    assert!(ndigits != Some(0));
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match (full_decoded, ndigits) {
+        (FullDecoded::Finite(Decoded { coeff: 0, exp }), None) => { // [0][e][-][67]
+            parts[0] = Part::Copy(b"0");
+            let exp = exp as i32;
+            if exp < 0 {
+                parts[1] = Part::Copy(if upper { b"E-" } else { b"e-" });
+                parts[2] = Part::Num(-exp as u16);
+            } else {
+                parts[1] = Part::Copy(if upper { b"E" } else { b"e" });
+                parts[2] = Part::Num(exp as u16);
+            }
+            Formatted { sign: sign, parts: &parts[..3] }
+        }
+        (FullDecoded::Finite(Decoded { coeff: 0, .. }), Some(ndigits)) => {
+            if ndigits > 1 { // [0.][0000][e0]
+                parts[0] = Part::Copy(b"0.");
+                parts[1] = Part::Zero(ndigits - 1);
+                parts[2] = Part::Copy(if upper { b"E0" } else { b"e0" });
+                Formatted { sign: sign, parts: &parts[..3] }
+            } else {
+                parts[0] = Part::Copy(if upper { b"0E0" } else { b"0e0" });
+                Formatted { sign: sign, parts: &parts[..1] }
+            }
+        }
+        (FullDecoded::Finite(ref decoded), ndigits) => {
+            let len = write_digits(decoded.coeff, buf);
+            let exp = decoded.exp as i32 + len as i32;
+            match ndigits {
+                Some(ndigits) if ndigits < len => {
+                    // the rounding may carry into the next digit, leaving one more zero
+                    // to be truncated (e.g. `9999` to `1000` with `ndigits = 3`).
+                    let limit = exp - ndigits as i32;
+                    let (coeff, newexp) = round_to_limit(decoded.coeff, decoded.exp as i32, limit);
+                    let newlen = write_digits(coeff, buf);
+                    let exp = (newexp + newlen as i32) as i16;
+                    let parts = digits_to_exp_str(&buf[..ndigits], exp, ndigits, upper, parts);
+                    Formatted { sign: sign, parts: parts }
+                }
+                _ => {
+                    let min_ndigits = ndigits.unwrap_or(0);
+                    Formatted { sign: sign,
+                                parts: digits_to_exp_str(&buf[..len], exp as i16, min_ndigits,
+                                                         upper, parts) }
+                }
+            }
+        }
+        (ref decoded, _) => Formatted { sign: sign,
+                                        parts: special_to_parts(decoded, upper, buf, parts) },
+    }
+}
+
+/// Formats given decimal floating point number into the decimal form,
+/// like `flt2dec::to_exact_fixed_str`. `upper` is only used to determine the case of
+/// non-finite values, i.e. `inf`, `nan` and `snan`.
+///
+/// When `frac_digits` is `None`, the number of fractional digits is determined by
+/// the quantum, so `1.50` prints as `1.50` and `1.5e3` (`15 * 10^2`) as `1500`.
+/// Otherwise the value is correctly rounded (ties to even) to *exactly* given number
+/// of fractional digits, padded by zeroes if needed.
+///
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// There should be at least 4 parts available, due to the worst case like
+/// `[0.][0000][45][0000]` with `frac_digits = Some(10)`.
+pub fn to_exact_fixed_str<'a, T>(v: T, sign: Sign, frac_digits: Option<usize>, upper: bool,
+                                 buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecimalFloat {
+    assert!(parts.len() >= 4);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Finite(ref decoded) => {
+            let frac_digits = match frac_digits {
+                Some(frac_digits) => frac_digits,
+                None => if decoded.exp < 0 { -(decoded.exp as i32) as usize } else { 0 },
+            };
+            // `frac_digits` can be ridiculously large, but then there is nothing to round.
+            let limit = if frac_digits < 0x8000 { -(frac_digits as i32) } else { -0x8000 };
+            let (coeff, exp) = round_to_limit(decoded.coeff, decoded.exp as i32, limit);
+            if coeff == 0 {
+                if frac_digits > 0 { // [0.][0000]
+                    parts[0] = Part::Copy(b"0.");
+                    parts[1] = Part::Zero(frac_digits);
+                    Formatted { sign: sign, parts: &parts[..2] }
+                } else {
+                    parts[0] = Part::Copy(b"0");
+                    Formatted { sign: sign, parts: &parts[..1] }
+                }
+            } else {
+                let len = write_digits(coeff, buf);
+                let exp = (exp + len as i32) as i16;
+                Formatted { sign: sign,
+                            parts: digits_to_dec_str(&buf[..len], exp, frac_digits, parts) }
+            }
+        }
+        ref decoded => {
+            Formatted { sign: sign, parts: special_to_parts(decoded, upper, buf, parts) }
+        }
+    }
+}
+
+/// Formats given decimal floating point number with the to-scientific-string
+/// conversion, which preserves the quantum. `upper` is used to determine the case of
+/// non-finite values (`inf`, `nan` and `snan`) or the case of the exponent prefix.
+///
+/// Let `n` be the number of digits in the coefficient (1 for zeroes) and
+/// `adj = exp + n - 1` be the adjusted exponent. When `exp <= 0` and `adj >= -6`,
+/// the coefficient is printed in the decimal form with `-exp` fractional digits
+/// (`1.50`, `0.000123`, `0.00`). Otherwise it is printed in the exponential form with
+/// the adjusted exponent, which always has a sign (`1.50E+5`, `1.23E-7`, `0E+3`).
+///
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// There should be at least 5 parts available, due to the worst case like
+/// `[1][.][2345][E-][67]`.
+pub fn to_sci_str<'a, T>(v: T, sign: Sign, upper: bool,
+                         buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecimalFloat {
+    assert!(parts.len() >= 5);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+    match full_decoded {
+        FullDecoded::Finite(ref decoded) => {
+            let len = if decoded.coeff == 0 { buf[0] = b'0'; 1 }
+                      else { write_digits(decoded.coeff, buf) };
+            let buf = &buf[..len];
+            let exp = decoded.exp as i32;
+            let adj = exp + len as i32 - 1;
+
+            let n = if exp <= 0 && adj >= -6 {
+                let frac = -exp as usize;
+                if frac == 0 { // [1234]
+                    parts[0] = Part::Copy(buf);
+                    1
+                } else if frac < len { // [12][.][34]
+                    parts[0] = Part::Copy(&buf[..len - frac]);
+                    parts[1] = Part::Copy(b".");
+                    parts[2] = Part::Copy(&buf[len - frac..]);
+                    3
+                } else { // [0.][000][1234]
+                    parts[0] = Part::Copy(b"0.");
+                    parts[1] = Part::Zero(frac - len);
+                    parts[2] = Part::Copy(buf);
+                    3
+                }
+            } else { // [1][.][234][E+][5]
+                let mut n = 0;
+                parts[n] = Part::Copy(&buf[..1]);
+                n += 1;
+                if len > 1 {
+                    parts[n] = Part::Copy(b".");
+                    parts[n + 1] = Part::Copy(&buf[1..]);
+                    n += 2;
+                }
+                parts[n] = Part::Copy(match (adj < 0, upper) {
+                    (false, false) => b"e+", (false, true) => b"E+",
+                    (true, false) => b"e-", (true, true) => b"E-",
+                });
+                parts[n + 1] = Part::Num((if adj < 0 { -adj } else { adj }) as u16);
+                n + 2
+            };
+            Formatted { sign: sign, parts: &parts[..n] }
+        }
+        ref decoded => {
+            Formatted { sign: sign, parts: special_to_parts(decoded, upper, buf, parts) }
+        }
+    }
+}
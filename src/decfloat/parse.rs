@@ -0,0 +1,159 @@
+//! Parses a decimal string into a decimal floating-point value.
+
+use core::prelude::*;
+
+use dec2flt::Error;
+use decfloat::{Format, Decoded, FullDecoded, pow10};
+
+/// The limit of the explicit exponent. Anything beyond this is either zero or infinity,
+/// and it is small enough so that no further computation with the exponent overflows.
+const EXP_LIMIT: i64 = 1 << 48;
+
+fn eq_ignore_case(s: &[u8], lower: &[u8]) -> bool {
+    s.len() == lower.len() && s.iter().zip(lower.iter()).all(|(&a, &b)| (a | 0x20) == b)
+}
+
+fn is_digit(c: u8) -> bool {
+    b'0' <= c && c <= b'9'
+}
+
+/// Returns the value of given ASCII digits, which should fit in `u128`.
+fn digits_to_u128(digits: &[u8]) -> u128 {
+    digits.iter().fold(0, |n, &c| n * 10 + (c - b'0') as u128)
+}
+
+/// Returns a sign (true when negative) and `FullDecoded` value from given string,
+/// rounded to given format. See `decfloat::parse` for the accepted syntax.
+pub fn parse_decoded(s: &[u8], format: &Format)
+        -> Result<(/*negative?*/ bool, FullDecoded), Error> {
+    let (negative, s) = match s.first() {
+        Some(&b'-') => (true, &s[1..]),
+        Some(&b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    if s.is_empty() { return Err(Error::Empty); }
+
+    if eq_ignore_case(s, b"inf") || eq_ignore_case(s, b"infinity") {
+        return Ok((negative, FullDecoded::Infinite));
+    }
+
+    // (nan | snan) [<payload>]
+    let nan = if s.len() >= 3 && eq_ignore_case(&s[..3], b"nan") {
+        Some((false, &s[3..]))
+    } else if s.len() >= 4 && eq_ignore_case(&s[..4], b"snan") {
+        Some((true, &s[4..]))
+    } else {
+        None
+    };
+    if let Some((signaling, payload)) = nan {
+        if !payload.iter().all(|&c| is_digit(c)) { return Err(Error::Invalid); }
+        let payload = match payload.iter().position(|&c| c != b'0') {
+            Some(i) => &payload[i..],
+            None => &payload[..0],
+        };
+        // the payload should be a valid trailing significand
+        if payload.len() >= format.digits as usize { return Err(Error::Invalid); }
+        let payload = digits_to_u128(payload);
+        return Ok((negative, FullDecoded::Nan { signaling: signaling, payload: payload }));
+    }
+
+    // <integral> [. <fractional>] [(e|E) [+|-] <exponent>]
+    let intlen = s.iter().position(|&c| !is_digit(c)).unwrap_or(s.len());
+    let (integral, s) = s.split_at(intlen);
+    let (fractional, s) = if s.first() == Some(&b'.') {
+        let s = &s[1..];
+        let fraclen = s.iter().position(|&c| !is_digit(c)).unwrap_or(s.len());
+        s.split_at(fraclen)
+    } else {
+        (&s[..0], s)
+    };
+    if integral.is_empty() && fractional.is_empty() { return Err(Error::Invalid); }
+
+    let exp = match s.first() {
+        None => 0,
+        Some(&b'e') | Some(&b'E') => {
+            let (expneg, s) = match s.get(1) {
+                Some(&b'-') => (true, &s[2..]),
+                Some(&b'+') => (false, &s[2..]),
+                _ => (false, &s[1..]),
+            };
+            if s.is_empty() { return Err(Error::Invalid); }
+            let mut exp: i64 = 0;
+            for &c in s {
+                if !is_digit(c) { return Err(Error::Invalid); }
+                if exp < EXP_LIMIT { exp = exp * 10 + (c - b'0') as i64; }
+            }
+            if expneg { -exp } else { exp }
+        }
+        Some(_) => return Err(Error::Invalid),
+    };
+
+    // unlike `dec2flt`, trailing zeroes are significant as they determine the quantum.
+    // the coefficient is `integral` followed by `fractional`, and scaled by `10^exp`.
+    let exp = exp - fractional.len() as i64;
+    let intzeroes = integral.iter().position(|&c| c != b'0');
+    let (integral, fractional) = match intzeroes {
+        Some(i) => (&integral[i..], fractional),
+        None => {
+            let i = fractional.iter().position(|&c| c != b'0').unwrap_or(fractional.len());
+            (&integral[..0], &fractional[i..])
+        }
+    };
+    let ndigits = integral.len() + fractional.len();
+    let digit = |i: usize| if i < integral.len() { integral[i] }
+                           else { fractional[i - integral.len()] };
+
+    let min_exp = format.min_exp as i64;
+    let max_exp = format.max_exp as i64;
+    let p = format.digits as usize;
+
+    if ndigits == 0 {
+        // zeroes only: clamp the exponent to the valid range
+        let exp = if exp < min_exp { min_exp } else if exp > max_exp { max_exp } else { exp };
+        return Ok((negative, FullDecoded::Finite(Decoded { coeff: 0, exp: exp as i16 })));
+    }
+
+    // drop the excess digits, either beyond the precision or below the smallest quantum
+    let mut drop = 0;
+    if ndigits > p { drop = (ndigits - p) as i64; }
+    if exp + drop < min_exp { drop = min_exp - exp; }
+
+    let (mut coeff, mut exp) = if drop > 0 {
+        let (coeff, first, sticky) = if drop < ndigits as i64 {
+            let keep = ndigits - drop as usize;
+            let coeff = (0..keep).fold(0, |n, i| n * 10 + (digit(i) - b'0') as u128);
+            (coeff, digit(keep), (keep + 1..ndigits).any(|i| digit(i) != b'0'))
+        } else if drop == ndigits as i64 {
+            (0, digit(0), (1..ndigits).any(|i| digit(i) != b'0'))
+        } else {
+            (0, b'0', true) // far below the halfway point to the smallest quantum
+        };
+        let coeff = if first > b'5' || (first == b'5' && (sticky || coeff & 1 != 0)) {
+            coeff + 1
+        } else {
+            coeff
+        };
+        if coeff == pow10(p as u32) {
+            // the rounding has been carried, e.g. 99999995 to 1000000 * 10^2
+            (coeff / 10, exp + drop + 1)
+        } else {
+            (coeff, exp + drop)
+        }
+    } else {
+        ((0..ndigits).fold(0, |n, i| n * 10 + (digit(i) - b'0') as u128), exp)
+    };
+
+    if exp > max_exp {
+        // the coefficient can be padded with zeroes if there is enough room
+        if coeff == 0 {
+            exp = max_exp;
+        } else if exp - max_exp < p as i64 &&
+                  coeff < pow10(p as u32 - (exp - max_exp) as u32) {
+            coeff *= pow10((exp - max_exp) as u32);
+            exp = max_exp;
+        } else {
+            return Ok((negative, FullDecoded::Infinite));
+        }
+    }
+    Ok((negative, FullDecoded::Finite(Decoded { coeff: coeff, exp: exp as i16 })))
+}
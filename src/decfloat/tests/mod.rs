@@ -0,0 +1,513 @@
+use std::prelude::v1::*;
+use std::{fmt, iter, u32, u64};
+use rand;
+use rand::distributions::{IndependentSample, Range};
+
+use dec2flt::Error;
+use flt2dec::{Part, Formatted, Sign};
+use decfloat::{decode, encode, parse, parse_decoded, DecimalFloat, Decoded, FullDecoded};
+use decfloat::{Bid32, Bid64, Bid128, Dpd32, Dpd64, Dpd128};
+use decfloat::{to_shortest_str, to_shortest_exp_str, to_exact_exp_str, to_exact_fixed_str};
+use decfloat::to_sci_str;
+use decfloat::dpd::{pack_declet, unpack_declet};
+
+fn finite(coeff: u128, exp: i16) -> FullDecoded {
+    FullDecoded::Finite(Decoded { coeff: coeff, exp: exp })
+}
+
+fn nan(signaling: bool, payload: u128) -> FullDecoded {
+    FullDecoded::Nan { signaling: signaling, payload: payload }
+}
+
+fn to_string_with_parts<F>(mut f: F) -> String
+        where F: for<'a> FnMut(&'a mut [u8], &'a mut [Part<'a>]) -> Formatted<'a> {
+    let mut buf = [0; 64];
+    let mut parts = [Part::Zero(0); 16];
+    let formatted = f(&mut buf, &mut parts);
+    let mut ret = vec![0; formatted.len()];
+    assert_eq!(formatted.write(&mut ret), Some(ret.len()));
+    String::from_utf8(ret).unwrap()
+}
+
+fn zeroes(n: usize) -> String {
+    iter::repeat('0').take(n).collect()
+}
+
+fn to_sci_string<T: DecimalFloat>(v: T) -> String {
+    to_string_with_parts(|buf, parts| to_sci_str(v, Sign::MinusRaw, true, buf, parts))
+}
+
+#[test]
+fn test_dpd_declets() {
+    assert_eq!(pack_declet(0), 0x000);
+    assert_eq!(pack_declet(79), 0x079);
+    assert_eq!(pack_declet(80), 0x00a);
+    assert_eq!(pack_declet(999), 0x0ff);
+    assert_eq!(unpack_declet(0x0ff), 999);
+    assert_eq!(unpack_declet(0x3fe), 998); // non-canonical, `0x0fe` is canonical
+
+    for n in 0..1000 {
+        assert_eq!(unpack_declet(pack_declet(n)), n);
+    }
+    let mut noncanonical = 0;
+    for d in 0..1024 {
+        let n = unpack_declet(d);
+        assert!(n < 1000);
+        if pack_declet(n) != d { noncanonical += 1; }
+    }
+    assert_eq!(noncanonical, 24);
+}
+
+fn check_encoding<T>(s: &str, bits: u128) where T: DecimalFloat + PartialEq + fmt::Debug {
+    let v = <T as DecimalFloat>::from_bits(bits);
+    assert_eq!(parse::<T>(s.as_bytes()), Ok(v));
+    assert_eq!(to_sci_string(v), s);
+    let (negative, decoded) = decode(v);
+    assert_eq!(encode::<T>(negative, &decoded), Some(v));
+}
+
+// the BID encodings agree with GCC's `_Decimal32` and others on x86-64.
+#[test]
+fn test_decimal32_encodings() {
+    const VECTORS: &'static [(&'static str, u32, u32)] = &[
+        ("0", 0x32800000, 0x22500000),
+        ("-0", 0xb2800000, 0xa2500000),
+        ("0.00", 0x31800000, 0x22300000),
+        ("0E+3", 0x34000000, 0x22800000),
+        ("1", 0x32800001, 0x22500001),
+        ("-1", 0xb2800001, 0xa2500001),
+        ("1.50", 0x31800096, 0x223000d0),
+        ("0.1", 0x32000001, 0x22400001),
+        ("-123.456", 0xb101e240, 0xa2228e56),
+        ("1234567", 0x3292d687, 0x2654d2e7),
+        ("9999999", 0x6cb8967f, 0x6e53fcff),
+        ("8000000", 0x32fa1200, 0x6a500000),
+        ("9.999999E+96", 0x77f8967f, 0x77f3fcff),
+        ("1E-101", 0x00000001, 0x00000001),
+        ("-7E-101", 0x80000007, 0x80000007),
+        ("1E+90", 0x5f800001, 0x43f00001),
+        ("1.000000E-95", 0x000f4240, 0x04000000),
+    ];
+    for &(s, bid, dpd) in VECTORS {
+        check_encoding::<Bid32>(s, bid as u128);
+        check_encoding::<Dpd32>(s, dpd as u128);
+    }
+}
+
+#[test]
+fn test_decimal64_encodings() {
+    const VECTORS: &'static [(&'static str, u64, u64)] = &[
+        ("0", 0x31c00000_00000000, 0x22380000_00000000),
+        ("-0", 0xb1c00000_00000000, 0xa2380000_00000000),
+        ("0.00", 0x31800000_00000000, 0x22300000_00000000),
+        ("0E+3", 0x32200000_00000000, 0x22440000_00000000),
+        ("1", 0x31c00000_00000001, 0x22380000_00000001),
+        ("1.50", 0x31800000_00000096, 0x22300000_000000d0),
+        ("0.1", 0x31a00000_00000001, 0x22340000_00000001),
+        ("-123.456", 0xb1600000_0001e240, 0xa22c0000_00028e56),
+        ("9.999999E+96", 0x3d000000_0098967f, 0x23a00000_0093fcff),
+        ("1.000000E-95", 0x25200000_000f4240, 0x20a40000_00100000),
+        ("1234567890123456", 0x31c462d5_3c8abac0, 0x263934b9_c1e28e56),
+        ("9999999999999999", 0x6c7386f2_6fc0ffff, 0x6e38ff3f_cff3fcff),
+        ("9.999999999999999E+384", 0x77fb86f2_6fc0ffff, 0x77fcff3f_cff3fcff),
+        ("1E-398", 0x00000000_00000001, 0x00000000_00000001),
+        ("1E+369", 0x5fe00000_00000001, 0x43fc0000_00000001),
+    ];
+    for &(s, bid, dpd) in VECTORS {
+        check_encoding::<Bid64>(s, bid as u128);
+        check_encoding::<Dpd64>(s, dpd as u128);
+    }
+}
+
+#[test]
+fn test_decimal128_encodings() {
+    const VECTORS: &'static [(&'static str, u128, u128)] = &[
+        ("0", 0x30400000_00000000_00000000_00000000, 0x22080000_00000000_00000000_00000000),
+        ("-0", 0xb0400000_00000000_00000000_00000000, 0xa2080000_00000000_00000000_00000000),
+        ("0.00", 0x303c0000_00000000_00000000_00000000, 0x22078000_00000000_00000000_00000000),
+        ("0E+3", 0x30460000_00000000_00000000_00000000, 0x2208c000_00000000_00000000_00000000),
+        ("1", 0x30400000_00000000_00000000_00000001, 0x22080000_00000000_00000000_00000001),
+        ("1.50", 0x303c0000_00000000_00000000_00000096, 0x22078000_00000000_00000000_000000d0),
+        ("-123.456", 0xb03a0000_00000000_00000000_0001e240, 0xa2074000_00000000_00000000_00028e56),
+        ("9.999999999999999E+384",
+         0x33220000_00000000_002386f2_6fc0ffff, 0x22644000_00000000_0024ff3f_cff3fcff),
+        ("1234567890123456789012345678901234",
+         0x30403cde_6fff9732_de825cd0_7e96aff2, 0x2608134b_9c1e28e5_6f3c1271_77823534),
+        ("9.999999999999999999999999999999999E+6144",
+         0x5fffed09_bead87c0_378d8e63_ffffffff, 0x77ffcff3_fcff3fcf_f3fcff3f_cff3fcff),
+        ("1E-6176", 0x00000000_00000000_00000000_00000001, 0x00000000_00000000_00000000_00000001),
+        ("-8.765432109876543210987654321098765E-6000",
+         0x811fb02b_4eadf08d_590b3193_8169500d, 0xe023fe58_c889df2c_34438fd5_1a117be5),
+        ("3.14159265358979323846264338327950",
+         0x30000f7d_3f558f10_c5dfd556_e3e5298e, 0x22001943_6565763b_f68f4c59_1b869cdc),
+    ];
+    for &(s, bid, dpd) in VECTORS {
+        check_encoding::<Bid128>(s, bid);
+        check_encoding::<Dpd128>(s, dpd);
+    }
+}
+
+#[test]
+fn test_decode_specials() {
+    assert_eq!(decode(Bid32(0x78000000)), (false, FullDecoded::Infinite));
+    assert_eq!(decode(Dpd32(0xf8000000)), (true, FullDecoded::Infinite));
+    assert_eq!(decode(Bid64(0x7c000000_00000000)), (false, nan(false, 0)));
+    assert_eq!(decode(Dpd64(0xfe000000_00000000)), (true, nan(true, 0)));
+    assert_eq!(decode(Bid128(0x7c00 << 112)), (false, nan(false, 0)));
+    assert_eq!(decode(Dpd128(0x7e00 << 112)), (false, nan(true, 0)));
+
+    // the remaining bits of the combination field are ignored
+    assert_eq!(decode(Bid32(0x7bffffff)), (false, FullDecoded::Infinite));
+    assert_eq!(decode(Bid32(0x7dffffff)), (false, nan(false, 0)));
+    assert_eq!(decode(Dpd32(0x7c0000d0)), (false, nan(false, 150)));
+    assert_eq!(decode(Bid32(0x7c0f423f)), (false, nan(false, 999999)));
+
+    // non-canonical payloads and coefficients are zero
+    assert_eq!(decode(Bid32(0x7c0f4240)), (false, nan(false, 0)));
+    assert_eq!(decode(Bid32(0x6cbfffff)), (false, finite(0, 0)));
+    assert_eq!(decode(Bid64(0x6c73ffff_ffffffff)), (false, finite(0, 0)));
+    assert_eq!(decode(Bid128(0x6fffffff_ffffffff_ffffffff_ffffffff)), (false, finite(0, 2015)));
+    // while non-canonical declets are just decoded
+    assert_eq!(decode(Dpd32(0x225003fe)), (false, finite(998, 0)));
+    assert_eq!(encode::<Dpd32>(false, &finite(998, 0)), Some(Dpd32(0x225000fe)));
+}
+
+#[test]
+fn test_encode_out_of_range() {
+    assert_eq!(encode::<Bid32>(false, &finite(9999999, 90)), Some(Bid32(0x77f8967f)));
+    assert_eq!(encode::<Bid32>(false, &finite(10000000, 0)), None);
+    assert_eq!(encode::<Bid32>(false, &finite(1, 91)), None);
+    assert_eq!(encode::<Bid32>(false, &finite(1, -102)), None);
+    assert_eq!(encode::<Dpd32>(false, &finite(10000000, 0)), None);
+    assert_eq!(encode::<Dpd64>(true, &finite(0, 370)), None);
+    assert_eq!(encode::<Dpd128>(true, &finite(0, -6177)), None);
+
+    assert_eq!(encode::<Bid32>(false, &nan(false, 999999)), Some(Bid32(0x7c0f423f)));
+    assert_eq!(encode::<Dpd32>(true, &nan(true, 999999)), Some(Dpd32(0xfe03fcff)));
+    assert_eq!(encode::<Bid32>(false, &nan(false, 1000000)), None);
+    assert_eq!(encode::<Dpd32>(false, &nan(false, 1000000)), None);
+    assert_eq!(encode::<Bid64>(false, &FullDecoded::Infinite), Some(Bid64(0x78000000_00000000)));
+}
+
+fn check_roundtrip<T, U>(v: T) where T: DecimalFloat + PartialEq + fmt::Debug,
+                                     U: DecimalFloat + PartialEq + fmt::Debug {
+    let (negative, decoded) = decode(v);
+    let canonical = encode::<T>(negative, &decoded).unwrap();
+    assert_eq!(decode(canonical), (negative, decoded));
+    // the other encoding should represent the same value
+    let other = encode::<U>(negative, &decoded).unwrap();
+    assert_eq!(decode(other), (negative, decoded));
+    assert_eq!(encode::<T>(negative, &decode(other).1), Some(canonical));
+}
+
+#[test]
+fn test_random_roundtrip() {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let u32_range = Range::new(0, u32::MAX);
+    let u64_range = Range::new(0, u64::MAX);
+    for _ in 0..10000 {
+        let x: u32 = u32_range.ind_sample(&mut rng);
+        check_roundtrip::<Bid32, Dpd32>(Bid32(x));
+        check_roundtrip::<Dpd32, Bid32>(Dpd32(x));
+        let x: u64 = u64_range.ind_sample(&mut rng);
+        check_roundtrip::<Bid64, Dpd64>(Bid64(x));
+        check_roundtrip::<Dpd64, Bid64>(Dpd64(x));
+        let y: u64 = u64_range.ind_sample(&mut rng);
+        let x = (x as u128) << 64 | y as u128;
+        check_roundtrip::<Bid128, Dpd128>(Bid128(x));
+        check_roundtrip::<Dpd128, Bid128>(Dpd128(x));
+    }
+}
+
+#[test]
+fn test_parse_decoded() {
+    let format = <Bid32 as DecimalFloat>::format();
+    let parse = |s: &str| parse_decoded(s.as_bytes(), &format);
+
+    assert_eq!(parse(""), Err(Error::Empty));
+    assert_eq!(parse("-"), Err(Error::Empty));
+    assert_eq!(parse("."), Err(Error::Invalid));
+    assert_eq!(parse("1e"), Err(Error::Invalid));
+    assert_eq!(parse("1.2.3"), Err(Error::Invalid));
+    assert_eq!(parse(" 1"), Err(Error::Invalid));
+    assert_eq!(parse("infinite"), Err(Error::Invalid));
+    assert_eq!(parse("nan-1"), Err(Error::Invalid));
+    assert_eq!(parse("nan1234567"), Err(Error::Invalid));
+    assert_eq!(parse("snan1e3"), Err(Error::Invalid));
+
+    assert_eq!(parse("Inf"), Ok((false, FullDecoded::Infinite)));
+    assert_eq!(parse("-infinity"), Ok((true, FullDecoded::Infinite)));
+    assert_eq!(parse("NaN"), Ok((false, nan(false, 0))));
+    assert_eq!(parse("-sNaN"), Ok((true, nan(true, 0))));
+    assert_eq!(parse("nan000123"), Ok((false, nan(false, 123))));
+    assert_eq!(parse("snan0000000999999"), Ok((false, nan(true, 999999))));
+
+    assert_eq!(parse("0"), Ok((false, finite(0, 0))));
+    assert_eq!(parse("-0.000"), Ok((true, finite(0, -3))));
+    assert_eq!(parse("000.e5"), Ok((false, finite(0, 5))));
+    assert_eq!(parse("0e-999999999999999999"), Ok((false, finite(0, -101))));
+    assert_eq!(parse("0e+999999999999999999"), Ok((false, finite(0, 90))));
+    assert_eq!(parse("1.50"), Ok((false, finite(150, -2))));
+    assert_eq!(parse("001.5e1"), Ok((false, finite(15, 0))));
+    assert_eq!(parse(".001500"), Ok((false, finite(1500, -6))));
+    assert_eq!(parse("1500."), Ok((false, finite(1500, 0))));
+
+    // rounding to the precision
+    assert_eq!(parse("1234567.5"), Ok((false, finite(1234568, 0))));
+    assert_eq!(parse("1234568.5"), Ok((false, finite(1234568, 0))));
+    assert_eq!(parse("1234568.50000000000000000001"), Ok((false, finite(1234569, 0))));
+    assert_eq!(parse("9999999.5"), Ok((false, finite(1000000, 1))));
+    assert_eq!(parse("12345670000"), Ok((false, finite(1234567, 4))));
+
+    // rounding to the smallest quantum
+    assert_eq!(parse("0.5e-101"), Ok((false, finite(0, -101))));
+    assert_eq!(parse("0.50000001e-101"), Ok((false, finite(1, -101))));
+    assert_eq!(parse("1.5e-101"), Ok((false, finite(2, -101))));
+    assert_eq!(parse("2.5e-101"), Ok((false, finite(2, -101))));
+    assert_eq!(parse("-1234.5e-102"), Ok((true, finite(123, -101))));
+    assert_eq!(parse("1e-999999999999999999"), Ok((false, finite(0, -101))));
+
+    // clamping and overflow
+    assert_eq!(parse("1e96"), Ok((false, finite(1000000, 90))));
+    assert_eq!(parse("1.2e96"), Ok((false, finite(1200000, 90))));
+    assert_eq!(parse("9.999999e96"), Ok((false, finite(9999999, 90))));
+    assert_eq!(parse("9.9999994e96"), Ok((false, finite(9999999, 90))));
+    assert_eq!(parse("-9.9999995e96"), Ok((true, FullDecoded::Infinite)));
+    assert_eq!(parse("1e97"), Ok((false, FullDecoded::Infinite)));
+    assert_eq!(parse("1e999999999999999999"), Ok((false, FullDecoded::Infinite)));
+}
+
+// the expected results are from Python's `decimal` module with the contexts
+// equivalent to decimal32, decimal64 and decimal128 (`clamp=1`).
+#[test]
+fn test_parse_against_python() {
+    fn check<T, U>(vectors: &[(&str, &str)]) where T: DecimalFloat + PartialEq + fmt::Debug,
+                                                   U: DecimalFloat + PartialEq + fmt::Debug {
+        for &(input, expected) in vectors {
+            let v = parse::<T>(input.as_bytes()).unwrap();
+            assert_eq!(to_sci_string(v), expected);
+            let w = parse::<U>(input.as_bytes()).unwrap();
+            assert_eq!(decode(v), decode(w));
+        }
+    }
+
+    check::<Bid32, Dpd32>(&[
+        ("-0309", "-309"),
+        ("-00.860", "-0.860"),
+        ("-04264.8244E+97", "-INF"),
+        ("0004.95e13", "4.95E+13"),
+        (".51e-14", "5.1E-15"),
+        ("0.08e-106", "0E-101"),
+        ("-6129.70", "-6129.70"),
+        ("-00094e-10", "-9.4E-9"),
+        ("-19.79365e-96", "-1.979365E-95"),
+        ("067433141e-19", "6.743314E-12"),
+        ("070403.7e-20", "7.04037E-16"),
+        ("-48434482e-8", "-0.4843448"),
+        ("065.e-107", "0E-101"),
+        ("-9.0850180E+92", "-9.085018E+92"),
+        ("-9988.55038E+85", "-9.988550E+88"),
+        ("-5e-2", "-0.05"),
+        ("643e-94", "6.43E-92"),
+        (".482704e12", "4.82704E+11"),
+        ("3629768.0", "3629768"),
+        ("-139742.02948393e8", "-1.397420E+13"),
+        ("-338.44500e-97", "-3.384450E-95"),
+        ("220.89845E+95", "INF"),
+        ("000752e-13", "7.52E-11"),
+        ("-08e-6", "-0.000008"),
+        ("-98425681.e8", "-9.842568E+15"),
+        ("-90763419e-105", "-9.076E-98"),
+        ("-007350E+92", "-7.35000E+95"),
+        ("001873236e13", "1.873236E+19"),
+    ]);
+
+    check::<Bid64, Dpd64>(&[
+        ("-240071853135622e-402", "-2.4007185314E-388"),
+        ("-43E+382", "-4.30000000000000E+383"),
+        ("-0332254213097945e11", "-3.32254213097945E+25"),
+        (".0832E+377", "8.320000E+375"),
+        ("09508190048661568e-394", "9.508190048661568E-379"),
+        ("-096.5451455414554e-398", "-9.7E-397"),
+        ("57e2", "5.7E+3"),
+        ("-004289784799666375", "-4289784799666375"),
+        ("7.e2", "7E+2"),
+        ("235308405101698292E+364", "2.353084051016983E+381"),
+        (".1e-400", "0E-398"),
+        ("000434304412926584881", "4.343044129265849E+17"),
+        ("-410644612947489e-18", "-0.000410644612947489"),
+        ("1E+386", "INF"),
+        ("-81376487348836271", "-8.137648734883627E+16"),
+        ("04837350.220E+365", "4.837350220E+371"),
+        ("-.75964068228488552E+383", "-7.596406822848855E+382"),
+        ("-8.783022992045050e-384", "-8.78302299204505E-384"),
+        (".414e-393", "4.14E-394"),
+        ("71818900074202405000.", "7.181890007420240E+19"),
+        ("7955370.22415277878E+364", "7.955370224152779E+370"),
+        ("00475731005642321.E+373", "INF"),
+        ("-12014140382752727e-405", "-1.201414038E-389"),
+        ("-501964682367000462810368", "-5.019646823670005E+23"),
+    ]);
+
+    check::<Bid128, Dpd128>(&[
+        ("-00008173708611.0299631381535685408767506e-3", "-8173708.611029963138153568540876751"),
+        ("145613473722315724254241915104093e-6168", "1.45613473722315724254241915104093E-6136"),
+        ("-7917522126410673979666445.316258772e-8", "-79175221264106739.79666445316258772"),
+        ("-9964447.44288771852048004051376189166", "-9964447.442887718520480040513761892"),
+        ("085337869416295229646097455608495702E+6121", "INF"),
+        ("070712100590825268561118420582588719e9", "7.071210059082526856111842058258872E+43"),
+        ("-76.84462868558107644171076251500e-18", "-7.684462868558107644171076251500E-17"),
+        ("005794945418945.066573632862638645709576454", "5794945418945.066573632862638645710"),
+        ("-244493085704484976764800000240235360e9", "-2.444930857044849767648000002402354E+44"),
+        (".970E+6112", "9.70E+6111"),
+        ("16.66259185719e-1", "1.666259185719"),
+        ("-262.2234798140390637944495637092941E+6128", "-2.622234798140390637944495637092941E+6130"),
+        ("-01e-6163", "-1E-6163"),
+        ("25e-5", "0.00025"),
+        ("-2500.00e-6179", "-2E-6176"),
+        ("-7374.909e-6165", "-7.374909E-6162"),
+        ("5225074834635096744201228538450135000", "5.225074834635096744201228538450135E+36"),
+        ("675000.00e-8", "0.0067500000"),
+        ("36064149674924987019082847385489542", "3.606414967492498701908284738548954E+34"),
+        (".87E+6130", "8.700000000000000000E+6129"),
+        ("0149634551.68329358372704487199425243E+6117",
+         "1.496345516832935837270448719942524E+6125"),
+    ]);
+}
+
+#[test]
+fn test_to_sci_str() {
+    fn to_string<T: DecimalFloat>(v: T, sign: Sign, upper: bool) -> String {
+        to_string_with_parts(|buf, parts| to_sci_str(v, sign, upper, buf, parts))
+    }
+    fn p(s: &str) -> Bid64 { parse(s.as_bytes()).unwrap() }
+
+    assert_eq!(to_string(p("0"), Sign::Minus, false), "0");
+    assert_eq!(to_string(p("-0"), Sign::Minus, false), "0");
+    assert_eq!(to_string(p("-0"), Sign::MinusRaw, false), "-0");
+    assert_eq!(to_string(p("0"), Sign::MinusPlus, false), "+0");
+    assert_eq!(to_string(p("-0.00"), Sign::MinusPlusRaw, false), "-0.00");
+    assert_eq!(to_string(p("0e-7"), Sign::Minus, true), "0E-7");
+    assert_eq!(to_string(p("0e3"), Sign::Minus, false), "0e+3");
+    assert_eq!(to_string(p("1"), Sign::MinusPlus, false), "+1");
+    assert_eq!(to_string(p("-1.50"), Sign::Minus, false), "-1.50");
+    assert_eq!(to_string(p("1.5e3"), Sign::Minus, true), "1.5E+3");
+    assert_eq!(to_string(p("1500"), Sign::Minus, true), "1500");
+    assert_eq!(to_string(p("0.000123"), Sign::Minus, true), "0.000123");
+    assert_eq!(to_string(p("0.0000001"), Sign::Minus, true), "1E-7");
+    assert_eq!(to_string(p("0.00000123"), Sign::Minus, true), "0.00000123");
+    assert_eq!(to_string(p("0.000000123"), Sign::Minus, true), "1.23E-7");
+    assert_eq!(to_string(p("123.456e-400"), Sign::Minus, true), "1E-398");
+
+    assert_eq!(to_string(p("inf"), Sign::MinusPlus, false), "+inf");
+    assert_eq!(to_string(p("-inf"), Sign::Minus, true), "-INF");
+    assert_eq!(to_string(p("-nan"), Sign::MinusPlus, false), "nan");
+    assert_eq!(to_string(p("snan"), Sign::Minus, true), "SNAN");
+    assert_eq!(to_string(p("nan0123"), Sign::Minus, false), "nan123");
+    assert_eq!(to_string(p("snan999999999999999"), Sign::Minus, false), "snan999999999999999");
+}
+
+#[test]
+fn test_to_shortest_str() {
+    fn to_string<T: DecimalFloat>(v: T, sign: Sign, frac_digits: usize) -> String {
+        to_string_with_parts(|buf, parts| to_shortest_str(v, sign, frac_digits, false, buf, parts))
+    }
+    fn p(s: &str) -> Bid32 { parse(s.as_bytes()).unwrap() }
+
+    assert_eq!(to_string(p("0.00"), Sign::Minus, 0), "0");
+    assert_eq!(to_string(p("-0e5"), Sign::MinusRaw, 0), "-0");
+    assert_eq!(to_string(p("0"), Sign::MinusPlus, 3), "+0.000");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, 0), "1.5");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, 3), "1.500");
+    assert_eq!(to_string(p("-1.5e3"), Sign::Minus, 0), "-1500");
+    assert_eq!(to_string(p("1.5e3"), Sign::Minus, 1), "1500.0");
+    assert_eq!(to_string(p("0.000100"), Sign::Minus, 0), "0.0001");
+    assert_eq!(to_string(p("1e-101"), Sign::Minus, 0),
+               format!("0.{}1", zeroes(100)));
+    assert_eq!(to_string(p("9.999999e96"), Sign::Minus, 0),
+               format!("9999999{}", zeroes(90)));
+    assert_eq!(to_string(p("inf"), Sign::Minus, 3), "inf");
+    assert_eq!(to_string(p("nan5"), Sign::Minus, 3), "nan5");
+}
+
+#[test]
+fn test_to_shortest_exp_str() {
+    fn to_string<T: DecimalFloat>(v: T, sign: Sign, dec_bounds: (i16, i16), upper: bool) -> String {
+        to_string_with_parts(|buf, parts| to_shortest_exp_str(v, sign, dec_bounds, upper,
+                                                               buf, parts))
+    }
+    fn p(s: &str) -> Dpd64 { parse(s.as_bytes()).unwrap() }
+
+    assert_eq!(to_string(p("0.00"), Sign::Minus, (-4, 16), false), "0");
+    assert_eq!(to_string(p("-0"), Sign::MinusRaw, (0, 0), true), "-0E0");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, (-4, 16), false), "1.5");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, (0, 0), false), "1.5e0");
+    assert_eq!(to_string(p("15e2"), Sign::Minus, (-4, 16), false), "1500");
+    assert_eq!(to_string(p("15e2"), Sign::Minus, (-4, 3), true), "1.5E3");
+    assert_eq!(to_string(p("0.000100"), Sign::Minus, (-4, 16), false), "0.0001");
+    assert_eq!(to_string(p("0.0000100"), Sign::Minus, (-4, 16), false), "1e-5");
+    assert_eq!(to_string(p("-1234567890123456e-398"), Sign::Minus, (-4, 16), false),
+               "-1.234567890123456e-383");
+    assert_eq!(to_string(p("-inf"), Sign::Minus, (-4, 16), true), "-INF");
+}
+
+#[test]
+fn test_to_exact_exp_str() {
+    fn to_string<T: DecimalFloat>(v: T, sign: Sign, ndigits: Option<usize>) -> String {
+        to_string_with_parts(|buf, parts| to_exact_exp_str(v, sign, ndigits, false, buf, parts))
+    }
+    fn p(s: &str) -> Dpd128 { parse(s.as_bytes()).unwrap() }
+
+    assert_eq!(to_string(p("0"), Sign::Minus, None), "0e0");
+    assert_eq!(to_string(p("-0.00"), Sign::MinusRaw, None), "-0e-2");
+    assert_eq!(to_string(p("0e3"), Sign::Minus, None), "0e3");
+    assert_eq!(to_string(p("0.000"), Sign::Minus, Some(1)), "0e0");
+    assert_eq!(to_string(p("0.000"), Sign::Minus, Some(3)), "0.00e0");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, None), "1.50e0");
+    assert_eq!(to_string(p("1.5e3"), Sign::Minus, None), "1.5e3");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, Some(1)), "2e0");
+    assert_eq!(to_string(p("2.50"), Sign::Minus, Some(1)), "2e0");
+    assert_eq!(to_string(p("2.51"), Sign::Minus, Some(1)), "3e0");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, Some(5)), "1.5000e0");
+    assert_eq!(to_string(p("123.456"), Sign::Minus, Some(3)), "1.23e2");
+    assert_eq!(to_string(p("0.0001234"), Sign::Minus, Some(3)), "1.23e-4");
+    assert_eq!(to_string(p("-9.995"), Sign::Minus, Some(3)), "-1.00e1");
+    assert_eq!(to_string(p("9999999999999999999999999999999999"), Sign::Minus, Some(33)),
+               "1.00000000000000000000000000000000e34");
+    assert_eq!(to_string(p("1e-6176"), Sign::Minus, Some(2)), "1.0e-6176");
+    assert_eq!(to_string(p("snan12"), Sign::Minus, Some(2)), "snan12");
+}
+
+#[test]
+fn test_to_exact_fixed_str() {
+    fn to_string<T: DecimalFloat>(v: T, sign: Sign, frac_digits: Option<usize>) -> String {
+        to_string_with_parts(|buf, parts| to_exact_fixed_str(v, sign, frac_digits, false,
+                                                              buf, parts))
+    }
+    fn p(s: &str) -> Bid128 { parse(s.as_bytes()).unwrap() }
+
+    assert_eq!(to_string(p("0"), Sign::Minus, None), "0");
+    assert_eq!(to_string(p("-0.00"), Sign::MinusRaw, None), "-0.00");
+    assert_eq!(to_string(p("0e3"), Sign::Minus, None), "0");
+    assert_eq!(to_string(p("0.000"), Sign::MinusPlus, Some(2)), "+0.00");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, None), "1.50");
+    assert_eq!(to_string(p("1.5e3"), Sign::Minus, None), "1500");
+    assert_eq!(to_string(p("0.000100"), Sign::Minus, None), "0.000100");
+    assert_eq!(to_string(p("1.50"), Sign::Minus, Some(0)), "2");
+    assert_eq!(to_string(p("2.50"), Sign::Minus, Some(0)), "2");
+    assert_eq!(to_string(p("1.5e3"), Sign::Minus, Some(2)), "1500.00");
+    assert_eq!(to_string(p("123.456"), Sign::Minus, Some(2)), "123.46");
+    assert_eq!(to_string(p("-0.0001234"), Sign::Minus, Some(2)), "-0.00");
+    assert_eq!(to_string(p("-0.0001234"), Sign::Minus, Some(4)), "-0.0001");
+    assert_eq!(to_string(p("9.995"), Sign::Minus, Some(2)), "10.00");
+    assert_eq!(to_string(p("0.5"), Sign::Minus, Some(0)), "0");
+    assert_eq!(to_string(p("0.51"), Sign::Minus, Some(0)), "1");
+    assert_eq!(to_string(p("1e-6176"), Sign::Minus, Some(6176)),
+               format!("0.{}1", zeroes(6175)));
+    assert_eq!(to_string(p("1e-6176"), Sign::Minus, Some(6175)),
+               format!("0.{}", zeroes(6175)));
+    assert_eq!(to_string(p("5e-6176"), Sign::Minus, Some(0x10000)),
+               format!("0.{}5{}", zeroes(6175), zeroes(0x10000 - 6176)));
+    assert_eq!(to_string(p("1e6111"), Sign::Minus, None), format!("1{}", zeroes(6111)));
+    assert_eq!(to_string(p("inf"), Sign::Minus, None), "inf");
+}
@@ -0,0 +1,174 @@
+/*!
+
+Runtime selection among interchangeable strategies.
+
+A `Dispatch` holds a fixed list of named candidate functions and remembers which one is
+in use. The choice is made on the first use from the CPU features (see `Features`),
+can be refined by timing every candidate on the current machine (`Dispatch::calibrate`),
+and can be overridden by the application at any time (`Dispatch::select`).
+The state is a single atomic word, so each call costs one relaxed load and an indirect call.
+
+Every candidate is compiled for the target as usual, so the detected features only guide
+the choice and never enable the code the target does not support.
+
+*/
+
+use core::prelude::*;
+use core::atomic::{AtomicUsize, Ordering};
+
+/// CPU features relevant to the choice of strategies.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Features {
+    /// SSE2 is available (x86 only).
+    pub sse2: bool,
+    /// AVX2 is available (x86 only).
+    pub avx2: bool,
+    /// BMI2 is available (x86 only). Used as a hint for a fast 64-bit multiplier.
+    pub bmi2: bool,
+    /// LZCNT is available (x86 only).
+    pub lzcnt: bool,
+}
+
+/// No features at all.
+pub const NO_FEATURES: Features = Features { sse2: false, avx2: false, bmi2: false, lzcnt: false };
+
+impl Features {
+    /// Detects the features of the current CPU.
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    pub fn detect() -> Features {
+        #[cfg(target_arch = "x86")] use core::arch::x86::__cpuid_count;
+        #[cfg(target_arch = "x86_64")] use core::arch::x86_64::__cpuid_count;
+
+        let mut features = NO_FEATURES;
+        unsafe {
+            let max_leaf = __cpuid_count(0, 0).eax;
+            if max_leaf >= 1 {
+                features.sse2 = __cpuid_count(1, 0).edx & (1 << 26) != 0;
+            }
+            if max_leaf >= 7 {
+                let ebx = __cpuid_count(7, 0).ebx;
+                features.avx2 = ebx & (1 << 5) != 0;
+                features.bmi2 = ebx & (1 << 8) != 0;
+            }
+            if __cpuid_count(0x8000_0000, 0).eax >= 0x8000_0001 {
+                features.lzcnt = __cpuid_count(0x8000_0001, 0).ecx & (1 << 5) != 0;
+            }
+        }
+        features
+    }
+
+    /// Detects the features of the current CPU.
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    pub fn detect() -> Features {
+        NO_FEATURES
+    }
+}
+
+/// A runtime choice among named candidate functions of the type `F`.
+///
+/// Use `dispatch!` to define one; the fields are public only for that macro.
+pub struct Dispatch<F: 'static> {
+    /// Candidate functions and their names.
+    pub candidates: &'static [(&'static str, F)],
+    /// Returns the name of the default candidate for given CPU features.
+    pub default: fn(&Features) -> &'static str,
+    /// Zero if undecided, otherwise one plus the index to `candidates`.
+    pub choice: AtomicUsize,
+}
+
+impl<F: Copy> Dispatch<F> {
+    /// Returns the chosen function, choosing the default one if not yet decided.
+    #[inline]
+    pub fn get(&self) -> F {
+        match self.choice.load(Ordering::Relaxed) {
+            0 => self.decide(),
+            choice => self.candidates[choice - 1].1,
+        }
+    }
+
+    #[inline(never)]
+    fn decide(&self) -> F {
+        let name = (self.default)(&Features::detect());
+        let i = self.position(name).expect("the default strategy is not a candidate");
+        // the concurrent `select` or `calibrate` takes precedence over the default
+        self.choice.compare_and_swap(0, i + 1, Ordering::Relaxed);
+        self.get()
+    }
+
+    fn position(&self, name: &str) -> Option<usize> {
+        self.candidates.iter().position(|&(name_, _)| name_ == name)
+    }
+
+    /// Returns the name of the chosen function, choosing the default one if not yet decided.
+    pub fn name(&self) -> &'static str {
+        self.get();
+        self.candidates[self.choice.load(Ordering::Relaxed) - 1].0
+    }
+
+    /// Overrides the choice with the candidate of given name.
+    /// Returns false and keeps the current choice if there is no such candidate.
+    pub fn select(&self, name: &str) -> bool {
+        match self.position(name) {
+            Some(i) => { self.choice.store(i + 1, Ordering::Relaxed); true }
+            None => false,
+        }
+    }
+
+    /// Forgets the current choice, so that the default is chosen again on the next use.
+    pub fn reset(&self) {
+        self.choice.store(0, Ordering::Relaxed);
+    }
+
+    /// Times every candidate and chooses the fastest one. Returns its name.
+    ///
+    /// `run` should call given function with a representative workload, and `clock`
+    /// should return the current time in any monotonically increasing unit.
+    /// Each candidate is measured several times in turn and the minimum is taken.
+    pub fn calibrate<C, R>(&self, clock: &mut C, run: &mut R) -> &'static str
+            where C: FnMut() -> u64, R: FnMut(F) {
+        const ROUNDS: usize = 5;
+
+        let mut elapsed = [!0u64; 32];
+        assert!(self.candidates.len() <= elapsed.len());
+        for _ in 0..ROUNDS {
+            for (i, &(_, f)) in self.candidates.iter().enumerate() {
+                let start = clock();
+                run(f);
+                let end = clock();
+                if elapsed[i] > end.wrapping_sub(start) {
+                    elapsed[i] = end.wrapping_sub(start);
+                }
+            }
+        }
+
+        let mut best = 0;
+        for i in 1..self.candidates.len() {
+            if elapsed[i] < elapsed[best] { best = i; }
+        }
+        self.choice.store(best + 1, Ordering::Relaxed);
+        self.candidates[best].0
+    }
+}
+
+/// Defines a public `Dispatch` static.
+///
+/// ```ignore
+/// dispatch! {
+///     pub static FOO: fn(u32) -> u32 = { "a" => a::foo, "b" => b::foo } default foo_default;
+/// }
+/// ```
+macro_rules! dispatch {
+    ($(
+        $(#[$attr:meta])*
+        pub static $NAME:ident: $F:ty = { $($name:expr => $f:path),+ } default $default:expr;
+    )*) => (
+        $(
+            $(#[$attr])*
+            pub static $NAME: ::dispatch::Dispatch<$F> = ::dispatch::Dispatch {
+                candidates: &[$(($name, $f as $F)),+],
+                default: $default,
+                choice: ::core::atomic::ATOMIC_USIZE_INIT,
+            };
+        )*
+    )
+}
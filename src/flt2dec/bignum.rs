@@ -5,9 +5,12 @@
 //! and will take at most 152 bytes of stack memory. This is (barely) enough
 //! for handling all possible finite `f64` values.
 //!
-//! In principle it is possible to have multiple bignum types for different
-//! inputs, but we don't do so to avoid the code bloat. Each bignum is still
-//! tracked for the actual usages, so it normally doesn't matter.
+//! Binary128 needs a much larger bignum, `Big32x520`, which is limited by
+//! 32 × 520 = 16,640 bits and takes 2,088 bytes of stack memory. We avoid
+//! using it for narrower types, since it is costly to copy. Algorithms can be
+//! written generically with the `Bignum` trait and instantiated with both.
+//! Each bignum is still tracked for the actual usages, so the size of
+//! the bignum only matters when it is copied or compared for equality.
 
 #![macro_use]
 
@@ -81,6 +84,24 @@ impl_full_ops! {
 //  u64: add(intrinsics::u64_add_with_overflow), mul/div(u128); // see RFC #521 for enabling this.
 }
 
+/// Operations common to all bignum types defined by `define_bignum!`.
+/// See the inherent methods of `Big32x36` for the description.
+pub trait Bignum: Clone + Ord {
+    /// The digit type.
+    type Digit;
+
+    fn from_small(v: Self::Digit) -> Self;
+    fn from_u128(v: u128) -> Self;
+    fn from_digits(v: &[Self::Digit]) -> Self;
+    fn is_zero(&self) -> bool;
+    fn add<'a>(&'a mut self, other: &Self) -> &'a mut Self;
+    fn sub<'a>(&'a mut self, other: &Self) -> &'a mut Self;
+    fn mul_small<'a>(&'a mut self, other: Self::Digit) -> &'a mut Self;
+    fn mul_pow2<'a>(&'a mut self, bits: usize) -> &'a mut Self;
+    fn mul_digits<'a>(&'a mut self, other: &[Self::Digit]) -> &'a mut Self;
+    fn div_rem_small<'a>(&'a mut self, other: Self::Digit) -> (&'a mut Self, Self::Digit);
+}
+
 macro_rules! define_bignum {
     ($name:ident: type=$ty:ty, n=$n:expr) => (
         /// Stack-allocated arbitrary-precision (up to certain limit) integer.
@@ -124,6 +145,31 @@ macro_rules! define_bignum {
                 $name { size: sz, base: base }
             }
 
+            /// Makes a bignum from `u128` value.
+            pub fn from_u128(mut v: u128) -> $name {
+                use core::mem;
+
+                let mut base = [0; $n];
+                let mut sz = 0;
+                while v > 0 {
+                    base[sz] = v as $ty;
+                    v >>= mem::size_of::<$ty>() * 8;
+                    sz += 1;
+                }
+                $name { size: sz, base: base }
+            }
+
+            /// Makes a bignum from digits, the least significant first.
+            /// Panics if there are more digits than the bignum can hold.
+            pub fn from_digits(v: &[$ty]) -> $name {
+                let mut base = [0; $n];
+                assert!(v.len() <= $n);
+                for (a, &b) in base.iter_mut().zip(v.iter()) {
+                    *a = b;
+                }
+                $name { size: v.len(), base: base }
+            }
+
             /// Returns true if the bignum is zero.
             pub fn is_zero(&self) -> bool {
                 self.base[..self.size].iter().all(|&v| v == 0)
@@ -285,6 +331,29 @@ macro_rules! define_bignum {
             }
         }
 
+        impl ::flt2dec::bignum::Bignum for $name {
+            type Digit = $ty;
+
+            fn from_small(v: $ty) -> $name { $name::from_small(v) }
+            fn from_u128(v: u128) -> $name { $name::from_u128(v) }
+            fn from_digits(v: &[$ty]) -> $name { $name::from_digits(v) }
+            fn is_zero(&self) -> bool { $name::is_zero(self) }
+            fn add<'a>(&'a mut self, other: &$name) -> &'a mut $name { $name::add(self, other) }
+            fn sub<'a>(&'a mut self, other: &$name) -> &'a mut $name { $name::sub(self, other) }
+            fn mul_small<'a>(&'a mut self, other: $ty) -> &'a mut $name {
+                $name::mul_small(self, other)
+            }
+            fn mul_pow2<'a>(&'a mut self, bits: usize) -> &'a mut $name {
+                $name::mul_pow2(self, bits)
+            }
+            fn mul_digits<'a>(&'a mut self, other: &[$ty]) -> &'a mut $name {
+                $name::mul_digits(self, other)
+            }
+            fn div_rem_small<'a>(&'a mut self, other: $ty) -> (&'a mut $name, $ty) {
+                $name::div_rem_small(self, other)
+            }
+        }
+
         impl ::core::cmp::PartialEq for $name {
             fn eq(&self, other: &$name) -> bool { self.base[..] == other.base[..] }
         }
@@ -333,8 +402,9 @@ macro_rules! define_bignum {
     )
 }
 
-/// The digit type for `Big32x36`.
+/// The digit type for `Big32x36` and `Big32x520`.
 pub type Digit32 = u32;
 
 define_bignum!(Big32x36: type=Digit32, n=36);
+define_bignum!(Big32x520: type=Digit32, n=520);
 
@@ -12,14 +12,17 @@ use core::num::{Float, FpCategory};
 /// - Any number from `(mant - minus) * 2^exp` to `(mant + plus) * 2^exp` will
 ///   round to the original value. The range is inclusive only when
 ///   `inclusive` is true.
+///
+/// The fields are wide enough for binary128, which has 113 bits of mantissa
+/// (plus two bits for the error range) and `exp` down to -16496.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Decoded {
     /// The scaled mantissa.
-    pub mant: u64,
+    pub mant: u128,
     /// The lower error range.
-    pub minus: u64,
+    pub minus: u128,
     /// The upper error range.
-    pub plus: u64,
+    pub plus: u128,
     /// The shared exponent in base 2.
     pub exp: i16,
     /// True when the error range is inclusive.
@@ -42,22 +45,40 @@ pub enum FullDecoded {
 }
 
 /// A floating point type which can be `decode`d.
-pub trait DecodableFloat: Float + Copy {
+///
+/// This does not require `Float`, so that types without native arithmetic
+/// (e.g. `half::F16`) can be also decoded.
+pub trait DecodableFloat: Copy {
     /// Returns `x * 2^exp`. Almost same to `std::{f32,f64}::ldexp`.
     /// This is used for testing.
     fn ldexpi(f: i64, exp: isize) -> Self;
     /// The minimum positive normalized value.
     fn min_pos_norm_value() -> Self;
+    /// Returns the mantissa, base 2 exponent and sign. Same to `Float::integer_decode`,
+    /// except that the mantissa is widened to `u128`.
+    fn integer_decode(self) -> (u128, i16, i8);
+    /// Returns the floating point category. Same to `Float::classify`.
+    fn classify(self) -> FpCategory;
 }
 
 impl DecodableFloat for f32 {
     fn ldexpi(f: i64, exp: isize) -> Self { f as Self * (exp as Self).exp2() }
     fn min_pos_norm_value() -> Self { f32::MIN_POSITIVE }
+    fn integer_decode(self) -> (u128, i16, i8) {
+        let (mant, exp, sign) = Float::integer_decode(self);
+        (mant as u128, exp, sign)
+    }
+    fn classify(self) -> FpCategory { Float::classify(self) }
 }
 
 impl DecodableFloat for f64 {
     fn ldexpi(f: i64, exp: isize) -> Self { f as Self * (exp as Self).exp2() }
     fn min_pos_norm_value() -> Self { f64::MIN_POSITIVE }
+    fn integer_decode(self) -> (u128, i16, i8) {
+        let (mant, exp, sign) = Float::integer_decode(self);
+        (mant as u128, exp, sign)
+    }
+    fn classify(self) -> FpCategory { Float::classify(self) }
 }
 
 /// Returns a sign (true when negative) and `FullDecoded` value
@@ -71,7 +92,7 @@ pub fn decode<T: DecodableFloat>(v: T) -> (/*negative?*/ bool, FullDecoded) {
         FpCategory::Zero => FullDecoded::Zero,
         FpCategory::Subnormal => {
             // neighbors: (mant - 2, exp) -- (mant, exp) -- (mant + 2, exp)
-            // Float::integer_decode always preserves the exponent,
+            // DecodableFloat::integer_decode always preserves the exponent,
             // so the mantissa is scaled for subnormals.
             FullDecoded::Finite(Decoded { mant: mant, minus: 1, plus: 1,
                                           exp: exp, inclusive: even })
@@ -93,3 +114,14 @@ pub fn decode<T: DecodableFloat>(v: T) -> (/*negative?*/ bool, FullDecoded) {
     (sign < 0, decoded)
 }
 
+/// A value which can be decoded to `FullDecoded`, which is all the formatting functions
+/// (except for `to_hex_str`) need. Unlike `DecodableFloat`, the format may be only known
+/// at the runtime (e.g. `minifloat::Minifloat`). Every `DecodableFloat` is `Decodable`.
+pub trait Decodable: Copy {
+    /// Returns a sign (true when negative) and `FullDecoded` value. Same to `decode`.
+    fn decode(self) -> (/*negative?*/ bool, FullDecoded);
+}
+
+impl<T: DecodableFloat> Decodable for T {
+    fn decode(self) -> (bool, FullDecoded) { decode(self) }
+}
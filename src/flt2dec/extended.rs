@@ -0,0 +1,261 @@
+//! Extended precision floating point types which can be `decode`d.
+//!
+//! They are mostly found in the legacy binary data: the x87 80-bit extended precision
+//! (C's `long double` in x86) and the "double-double" which represents a number as
+//! an unevaluated sum of two `f64`s (C's `long double` in PowerPC).
+//! Both are represented as bit patterns without any arithmetic, just like `quad::F128`,
+//! and can be fed to `decode` and therefore to `strategy::dragon`.
+
+use core::prelude::*;
+
+use core::mem;
+use core::num::{Float, FpCategory};
+
+use flt2dec::decoder::DecodableFloat;
+
+/// x87 80-bit extended precision number, with 64 bits of mantissa (including
+/// the explicit integer bit) and 15 bits of exponent. The first field contains the sign
+/// and exponent bits, and the second field is the mantissa.
+///
+/// Unlike other IEEE 754 formats, the integer bit is explicit and the encodings
+/// with a wrong integer bit are possible. They are handled as the x87 FPU (since 80387) does:
+///
+/// - Pseudo-denormals (the zero exponent with the integer bit set) are valid and equal to
+///   the normal numbers with the exponent of 1. They are classified as normal.
+/// - Unnormals (a non-zero exponent with the integer bit unset), pseudo-infinities and
+///   pseudo-NaNs (the maximum exponent with the integer bit unset) are all NaNs.
+///
+/// The equality is bitwise, so `F80(0, 0) != F80(0x8000, 0)` and NaNs can equal to each other.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct F80(pub u16, pub u64);
+
+/// The number of mantissa bits besides the integer bit.
+const F80_MBITS: usize = 63;
+/// The exponent field for infinities and NaNs.
+const F80_EMAX: u16 = 0x7fff;
+/// The exponent bias.
+const F80_BIAS: i32 = 16383;
+/// The exponent of the unit in the last place of subnormals and minimum normals.
+const F80_MINEXP: i32 = 1 - F80_BIAS - F80_MBITS as i32;
+
+impl F80 {
+    /// Makes a value from the sign (true when negative) and `mant * 2^exp`,
+    /// rounded to the nearest with ties to even. `mant` should be less than `2^127`.
+    fn from_parts(negative: bool, mant: u128, exp: i32) -> F80 {
+        let sign = if negative { 0x8000 } else { 0 };
+        if mant == 0 { return F80(sign, 0); }
+        debug_assert!(mant < 1 << 127);
+
+        // `mant * 2^exp` is rounded to `q * 2^qexp` where `q` has 64 bits
+        // (or less for subnormals, whose `qexp` is fixed to that of minimum normals).
+        let nbits = 128 - mant.leading_zeros() as i32;
+        let qexp = if exp + nbits - 1 - (F80_MBITS as i32) < F80_MINEXP { F80_MINEXP }
+                   else { exp + nbits - 1 - F80_MBITS as i32 };
+        if qexp - F80_MINEXP >= F80_EMAX as i32 {
+            return F80(sign | F80_EMAX, 1 << F80_MBITS); // overflows to infinity
+        }
+
+        let q = if qexp <= exp {
+            mant << (exp - qexp) as usize
+        } else if qexp - exp > nbits {
+            0 // `v < 2^(qexp-1)`, which is less than the half of the minimum subnormal
+        } else {
+            let shift = (qexp - exp) as usize;
+            let q = mant >> shift;
+            let rem = mant & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q }
+        };
+
+        // the integer bit is explicit, so `q` rounded up to the next binade is
+        // renormalized (subnormals become minimum normals with no renormalization).
+        let (field, q) = if q >> (F80_MBITS + 1) != 0 {
+            (qexp - F80_MINEXP + 2, q >> 1)
+        } else if q >> F80_MBITS != 0 {
+            (qexp - F80_MINEXP + 1, q)
+        } else {
+            (0, q)
+        };
+        if field >= F80_EMAX as i32 {
+            F80(sign | F80_EMAX, 1 << F80_MBITS) // overflows to infinity
+        } else {
+            F80(sign | field as u16, q as u64)
+        }
+    }
+}
+
+impl DecodableFloat for F80 {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        let mant = if f < 0 { (-(f as i128)) as u128 } else { f as u128 };
+        F80::from_parts(f < 0, mant, exp as i32)
+    }
+    fn min_pos_norm_value() -> Self { F80(1, 1 << F80_MBITS) }
+    fn integer_decode(self) -> (u128, i16, i8) {
+        let sign = if self.0 >> 15 == 0 { 1 } else { -1 };
+        let exp = (self.0 & F80_EMAX) as i32;
+        let mant = self.1 as u128;
+        if exp == 0 && mant >> F80_MBITS == 0 {
+            // the mantissa of subnormals is scaled by 2 (as `f32` and `f64` do)
+            (mant << 1, (F80_MINEXP - 1) as i16, sign)
+        } else if exp == 0 {
+            // pseudo-denormals share the exponent with subnormals and minimum normals
+            (mant, F80_MINEXP as i16, sign)
+        } else {
+            (mant, (exp - F80_BIAS - F80_MBITS as i32) as i16, sign)
+        }
+    }
+    fn classify(self) -> FpCategory {
+        let exp = self.0 & F80_EMAX;
+        let integer = self.1 >> F80_MBITS != 0;
+        let frac = self.1 & ((1 << F80_MBITS) - 1);
+        match (exp, integer, frac) {
+            (0, false, 0) => FpCategory::Zero,
+            (0, false, _) => FpCategory::Subnormal,
+            (0, true, _) => FpCategory::Normal, // pseudo-denormals
+            (_, false, _) => FpCategory::Nan, // unnormals, pseudo-infinities and pseudo-NaNs
+            (F80_EMAX, true, 0) => FpCategory::Infinite,
+            (F80_EMAX, true, _) => FpCategory::Nan,
+            (_, true, _) => FpCategory::Normal,
+        }
+    }
+}
+
+/// Double-double number, which is an unevaluated sum of two `f64`s: the first field
+/// `hi` and the second field `lo`. The pair should be normalized so that
+/// `hi` equals to the sum `hi + lo` rounded to `f64`, i.e. `|lo| <= ulp(hi) / 2`
+/// (or `ulp(hi) / 4` when `hi` is a power of two and `lo` has the opposite sign).
+/// Non-normalized pairs and pairs with a non-finite `lo` are classified as NaN,
+/// except for infinities which are determined by `hi` alone.
+///
+/// Since the sum can have an arbitrary number of bits, it is decoded as if
+/// it were a binary floating point with 107 bits of mantissa (53 bits of both parts,
+/// and one bit for the sign of `lo`) and the exponent range of `f64`.
+/// In particular, the sum is rounded to 107 bits with ties to even when `lo` is
+/// so small that it has bits below that, which is not possible from the exact
+/// double-double arithmetic but still valid as a normalized pair. (glibc truncates
+/// them to 106 bits instead.) The numbers below `2^-968` have less bits of mantissa,
+/// since `lo` is limited by the minimum subnormal `f64`, and are classified as subnormal.
+///
+/// The equality is bitwise, so `DoubleDouble(0.0, 0.0) != DoubleDouble(-0.0, 0.0)` and
+/// pairs with the same sum may differ.
+#[derive(Copy, Clone, Debug)]
+pub struct DoubleDouble(pub f64, pub f64);
+
+/// The number of mantissa bits besides the leading bit.
+const DD_MBITS: usize = 106;
+/// The exponent of the unit in the last place of subnormals and minimum normals.
+const DD_MINEXP: i32 = -1074;
+
+/// Returns the mantissa and exponent of finite `f64`, not scaled for subnormals.
+fn f64_parts(v: f64) -> (u64, i32) {
+    let bits: u64 = unsafe { mem::transmute(v) };
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    let mant = bits & ((1 << 52) - 1);
+    if exp == 0 { (mant, -1074) } else { (mant | (1 << 52), exp - 1075) }
+}
+
+impl DoubleDouble {
+    /// Returns the sum rounded to `mant * 2^exp`, where `mant` has 107 bits or less
+    /// and `exp` is at least the minimum exponent. `hi` should be finite and non-zero,
+    /// and the pair should be normalized.
+    fn sum_parts(self) -> (u128, i32) {
+        let DoubleDouble(hi, lo) = self;
+        let (hmant, hexp) = f64_parts(hi);
+        let (lmant, lexp) = if lo == 0.0 { (0, hexp) } else { f64_parts(lo) };
+        let opposite = (hi < 0.0) != (lo < 0.0) && lmant != 0;
+
+        // the sum is below `hi` when `hi` is a power of two and `lo` has the opposite sign
+        let hbits = 64 - hmant.leading_zeros() as i32;
+        let top = if opposite && hmant.count_ones() == 1 { hexp + hbits - 2 }
+                  else { hexp + hbits - 1 };
+        let exp = if top - (DD_MBITS as i32) < DD_MINEXP { DD_MINEXP }
+                  else { top - DD_MBITS as i32 };
+
+        // `hi` always fits in the unit of `exp`, but `lo` may be rounded
+        let hmant = (hmant as u128) << (hexp - exp) as usize;
+        let lmant = if lexp >= exp {
+            (lmant as u128) << (lexp - exp) as usize
+        } else if exp - lexp > 64 {
+            0 // `|lo| < 2^(exp-1)`, which rounds to zero
+        } else {
+            let shift = (exp - lexp) as usize;
+            let q = lmant as u128 >> shift;
+            let rem = lmant as u128 & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            // `hmant` is even, so the parity of the sum is that of `q`
+            if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q }
+        };
+
+        let mant = if opposite { hmant - lmant } else { hmant + lmant };
+        if mant >> (DD_MBITS + 1) != 0 {
+            // `lo` has been rounded to zero for `hi` being a power of two
+            (mant >> 1, exp + 1)
+        } else {
+            (mant, exp)
+        }
+    }
+
+    /// Returns true if the finite and non-zero pair is normalized.
+    fn is_normalized(self) -> bool {
+        let DoubleDouble(hi, lo) = self;
+        if lo == 0.0 { return true; }
+        if lo.is_nan() || lo.is_infinite() { return false; }
+
+        // `|lo| <= 2^(hexp-1)` where `2^hexp` is the unit in the last place of `hi`.
+        // if `hi` is a power of two and `lo` has the opposite sign, the unit below `hi`
+        // is halved and so is the bound; the tie then rounds to `hi` with an even mantissa.
+        let (hmant, hexp) = f64_parts(hi);
+        let (lmant, lexp) = f64_parts(lo);
+        let bound = if hmant == 1 << 52 && (hi < 0.0) != (lo < 0.0) { hexp - 2 } else { hexp - 1 };
+        let ltop = lexp + 63 - lmant.leading_zeros() as i32;
+        ltop < bound || (ltop == bound && lmant.count_ones() == 1)
+    }
+}
+
+impl PartialEq for DoubleDouble {
+    fn eq(&self, other: &DoubleDouble) -> bool {
+        let bits = |v: f64| -> u64 { unsafe { mem::transmute(v) } };
+        bits(self.0) == bits(other.0) && bits(self.1) == bits(other.1)
+    }
+}
+
+impl DecodableFloat for DoubleDouble {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        // `f` is split into the nearest `f64` and its (exactly representable) remainder
+        let hi = f as f64;
+        let lo = (f as i128 - hi as i128) as f64;
+        let scale = <f64 as DecodableFloat>::ldexpi(1, exp);
+        DoubleDouble(hi * scale, lo * scale)
+    }
+    fn min_pos_norm_value() -> Self {
+        // `2^-968` is the minimum power of two whose unit in the 107th bit is `2^-1074`
+        DoubleDouble(<f64 as DecodableFloat>::ldexpi(1, -968), 0.0)
+    }
+    fn integer_decode(self) -> (u128, i16, i8) {
+        let sign = if self.0.is_sign_negative() { -1 } else { 1 };
+        match self.classify() {
+            FpCategory::Nan | FpCategory::Infinite | FpCategory::Zero => (0, 0, sign),
+            FpCategory::Subnormal => {
+                // the mantissa of subnormals is scaled by 2 (as `f32` and `f64` do)
+                let (mant, exp) = self.sum_parts();
+                (mant << 1, (exp - 1) as i16, sign)
+            }
+            FpCategory::Normal => {
+                let (mant, exp) = self.sum_parts();
+                (mant, exp as i16, sign)
+            }
+        }
+    }
+    fn classify(self) -> FpCategory {
+        let DoubleDouble(hi, lo) = self;
+        if hi.is_nan() { return FpCategory::Nan; }
+        if hi.is_infinite() { return FpCategory::Infinite; }
+        if hi == 0.0 {
+            return if lo == 0.0 { FpCategory::Zero } else { FpCategory::Nan };
+        }
+        if !self.is_normalized() { return FpCategory::Nan; }
+
+        let (mant, _) = self.sum_parts();
+        if mant >> DD_MBITS != 0 { FpCategory::Normal } else { FpCategory::Subnormal }
+    }
+}
@@ -0,0 +1,140 @@
+//! 16-bit floating point types which can be `decode`d.
+//!
+//! Rust has no native 16-bit floating point types, so they are represented as
+//! newtypes over their bit patterns. They only support the conversions from and to `f64`,
+//! which are enough for feeding them to `decode` and therefore to any strategy.
+
+use core::prelude::*;
+
+use core::mem;
+use core::num::FpCategory;
+
+use flt2dec::decoder::DecodableFloat;
+
+/// IEEE 754 binary16 ("half precision") number, with 11 bits of mantissa
+/// (including the implicit bit) and 5 bits of exponent.
+///
+/// The equality is bitwise, so `F16(0x0000) != F16(0x8000)` and NaNs can equal to each other.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct F16(pub u16);
+
+/// Brain floating point ("bfloat16") number, which is the upper half of `f32`
+/// and has 8 bits of mantissa (including the implicit bit) and 8 bits of exponent.
+///
+/// The equality is bitwise, so `Bf16(0x0000) != Bf16(0x8000)` and NaNs can equal to each other.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bf16(pub u16);
+
+/// Splits a 16-bit pattern with `mbits` explicit mantissa bits and `ebits` exponent bits.
+/// The results follow `Float::integer_decode` and `Float::classify` for other types.
+fn decode_bits(bits: u16, mbits: usize, ebits: usize) -> ((u128, i16, i8), FpCategory) {
+    let sign = if bits >> 15 == 0 { 1 } else { -1 };
+    let emax = (1 << ebits) - 1;
+    let bias = (1 << (ebits - 1)) - 1;
+    let exp = (bits >> mbits) as i16 & emax;
+    let mant = (bits & ((1 << mbits) - 1)) as u128;
+
+    // the exponent of the unit in the last place of subnormals and minimum normals
+    let minexp = 1 - bias - mbits as i16;
+    if exp == 0 {
+        // the mantissa of subnormals is scaled by 2, so that the exponent is one less
+        // than that of minimum normals (as `f32` and `f64` do)
+        let category = if mant == 0 { FpCategory::Zero } else { FpCategory::Subnormal };
+        ((mant << 1, minexp - 1, sign), category)
+    } else if exp == emax {
+        let category = if mant == 0 { FpCategory::Infinite } else { FpCategory::Nan };
+        ((mant | (1 << mbits), exp - bias - mbits as i16, sign), category)
+    } else {
+        ((mant | (1 << mbits), exp - bias - mbits as i16, sign), FpCategory::Normal)
+    }
+}
+
+/// Converts `v` into a 16-bit pattern with `mbits` explicit mantissa bits and
+/// `ebits` exponent bits, rounded to the nearest with ties to even.
+/// Any NaN is converted to the quiet NaN with the same sign.
+fn encode_bits(v: f64, mbits: usize, ebits: usize) -> u16 {
+    let bits: u64 = unsafe { mem::transmute(v) };
+    let sign = ((bits >> 63) as u16) << 15;
+    let vexp = ((bits >> 52) & 0x7ff) as i32;
+    let vmant = bits & ((1 << 52) - 1);
+
+    let emax = (1 << ebits) - 1;
+    let bias = (1 << (ebits - 1)) - 1;
+    if vexp == 0x7ff {
+        let quiet = if vmant == 0 { 0 } else { 1 << (mbits - 1) };
+        return sign | (emax << mbits) | quiet;
+    }
+    if vexp == 0 {
+        // zeroes and `f64` subnormals are way below the half of any subnormal here
+        return sign;
+    }
+
+    // `v = mant * 2^exp`, which is rounded to `q * 2^qexp` where `q` has `mbits + 1` bits
+    // (or less for subnormals, whose `qexp` is fixed to that of minimum normals).
+    let mant = vmant | (1 << 52);
+    let exp = vexp - 1075;
+    let minqexp = 1 - bias - mbits as i32;
+    let qexp = if vexp - 1023 - (mbits as i32) < minqexp { minqexp }
+               else { vexp - 1023 - mbits as i32 };
+    let shift = (qexp - exp) as usize; // at least `52 - mbits`
+    let q = if shift >= 64 {
+        0 // `v < 2^(qexp-1)`, which is less than the half of the minimum subnormal
+    } else {
+        let q = mant >> shift;
+        let rem = mant & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q }
+    };
+
+    // `q` overflows to the exponent field when rounded up to the next binade,
+    // and subnormals naturally become minimum normals in that case.
+    let rounded = (((qexp - minqexp) as u64) << mbits) + q;
+    if rounded >= (emax as u64) << mbits {
+        sign | (emax << mbits) // overflows to infinity
+    } else {
+        sign | rounded as u16
+    }
+}
+
+/// Converts a 16-bit pattern to `f64`, which is always exact.
+fn bits_to_f64(bits: u16, mbits: usize, ebits: usize) -> f64 {
+    let ((mant, exp, sign), category) = decode_bits(bits, mbits, ebits);
+    let v = match category {
+        FpCategory::Nan => 0.0 / 0.0,
+        FpCategory::Infinite => 1.0 / 0.0,
+        _ => <f64 as DecodableFloat>::ldexpi(mant as i64, exp as isize),
+    };
+    if sign < 0 { -v } else { v }
+}
+
+impl F16 {
+    /// Converts `f64` to the nearest `F16`, with ties to even.
+    pub fn from_f64(v: f64) -> F16 { F16(encode_bits(v, 10, 5)) }
+    /// Converts `F16` to `f64` without any loss.
+    pub fn to_f64(self) -> f64 { bits_to_f64(self.0, 10, 5) }
+}
+
+impl Bf16 {
+    /// Converts `f64` to the nearest `Bf16`, with ties to even.
+    pub fn from_f64(v: f64) -> Bf16 { Bf16(encode_bits(v, 7, 8)) }
+    /// Converts `Bf16` to `f64` without any loss.
+    pub fn to_f64(self) -> f64 { bits_to_f64(self.0, 7, 8) }
+}
+
+impl DecodableFloat for F16 {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        F16::from_f64(<f64 as DecodableFloat>::ldexpi(f, exp))
+    }
+    fn min_pos_norm_value() -> Self { F16(0x0400) }
+    fn integer_decode(self) -> (u128, i16, i8) { decode_bits(self.0, 10, 5).0 }
+    fn classify(self) -> FpCategory { decode_bits(self.0, 10, 5).1 }
+}
+
+impl DecodableFloat for Bf16 {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        Bf16::from_f64(<f64 as DecodableFloat>::ldexpi(f, exp))
+    }
+    fn min_pos_norm_value() -> Self { Bf16(0x0080) }
+    fn integer_decode(self) -> (u128, i16, i8) { decode_bits(self.0, 7, 8).0 }
+    fn classify(self) -> FpCategory { decode_bits(self.0, 7, 8).1 }
+}
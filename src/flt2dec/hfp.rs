@@ -0,0 +1,181 @@
+//! IBM hexadecimal floating point (HFP) types which can be formatted.
+//!
+//! They are found in the legacy binary data from System/360 and its successors.
+//! HFP numbers have a sign bit, a 7-bit characteristic `c` (the base-16 exponent biased by 64)
+//! and a fraction `F` of 6, 14 or 28 hexadecimal digits, whose value is `0.F * 16^(c - 64)`.
+//! There are no hidden bits, infinities nor NaNs, and the fraction may be unnormalized
+//! (i.e. the leading digit may be zero). The types are represented as bit patterns without
+//! any arithmetic, in the big-endian order the hardware uses.
+//!
+//! Since the distance to adjacent values changes by a factor of 16 at powers of 16,
+//! they cannot be `decode`d and implement `Decodable` instead. The resulting `Decoded` can be
+//! fed to `strategy::dragon` (and `strategy::grisu`, which falls back to Dragon as needed),
+//! and the types can be given to the formatting functions except for `to_hex_str`.
+//! The shortest mode needs `MAX_SIG_DIGITS_F80` bytes of buffer for `Hfp64`
+//! and `MAX_SIG_DIGITS_F128` bytes for `Hfp128`.
+//!
+//! The hardware truncates the results, but the shortest mode assumes the conversion rounded to
+//! the nearest with ties to even (as `from_f64` does), so that the error range is symmetric
+//! and inclusive for even fractions.
+
+use core::prelude::*;
+
+use core::num::FpCategory;
+
+use flt2dec::decoder::{Decodable, DecodableFloat, Decoded, FullDecoded};
+
+/// HFP short number, with a fraction of 6 hexadecimal digits (24 bits).
+/// The range is about `5.4e-79` (or `5.1e-85` unnormalized) to `7.2e75`.
+///
+/// The equality is bitwise, so values with different (unnormalized) fractions
+/// differ from each other.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hfp32(pub u32);
+
+/// HFP long number, with a fraction of 14 hexadecimal digits (56 bits).
+/// The range is same to `Hfp32`, except that the minimum unnormalized value is about `1.2e-94`.
+///
+/// The equality is bitwise, as `Hfp32`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hfp64(pub u64);
+
+/// HFP extended number, with a fraction of 28 hexadecimal digits (112 bits).
+/// The first field contains the sign, the characteristic and the upper 14 digits
+/// of the fraction, and the lower 56 bits of the second field are the lower 14 digits.
+/// The upper 8 bits of the second field are ignored, and set to the sign and
+/// the characteristic minus 14 (modulo 128) by `from_f64`, as the hardware does.
+/// The range is same to `Hfp32`, except that the minimum unnormalized value is about `1.7e-111`.
+///
+/// The equality is bitwise, as `Hfp32`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Hfp128(pub u64, pub u64);
+
+/// The mask for 14 hexadecimal digits.
+const MASK56: u64 = (1 << 56) - 1;
+
+/// Returns a sign (true when negative) and `FullDecoded` value from the sign,
+/// the characteristic and the fraction of `digits` hexadecimal digits.
+fn decode_parts(negative: bool, c: u32, frac: u128, digits: u32) -> (bool, FullDecoded) {
+    if frac == 0 { return (negative, FullDecoded::Zero); }
+
+    // normalize the fraction as long as the characteristic allows,
+    // so that any fraction with the same value is decoded to the same `Decoded`.
+    let lead = 1 << (4 * (digits - 1));
+    let mut c = c as i32;
+    let mut frac = frac;
+    while frac < lead && c > 0 {
+        frac <<= 4;
+        c -= 1;
+    }
+
+    let exp = 4 * (c - 64 - digits as i32);
+    let even = frac & 1 == 0;
+    let decoded = if frac == lead && c > 0 {
+        // neighbors: (maxfrac, exp - 4) -- (lead, exp) -- (lead + 1, exp)
+        // where maxfrac = lead * 16 - 1
+        Decoded { mant: frac << 5, minus: 1, plus: 16, exp: (exp - 5) as i16, inclusive: even }
+    } else {
+        // neighbors: (frac - 1, exp) -- (frac, exp) -- (frac + 1, exp)
+        // this includes the unnormalized fractions with the zero characteristic.
+        Decoded { mant: frac << 1, minus: 1, plus: 1, exp: (exp - 1) as i16, inclusive: even }
+    };
+    (negative, FullDecoded::Finite(decoded))
+}
+
+/// Returns the characteristic and the fraction of `digits` hexadecimal digits nearest to
+/// finite `v`, with ties to even, and the sign (true when negative). The result is normalized
+/// unless it is too small. There are no infinities, so too large values saturate to
+/// the maximum. Returns `None` for NaNs and infinities.
+fn encode_f64(v: f64, digits: u32) -> Option<(bool, u32, u128)> {
+    let (mant, exp, sign) = DecodableFloat::integer_decode(v);
+    match DecodableFloat::classify(v) {
+        FpCategory::Nan | FpCategory::Infinite => return None,
+        FpCategory::Zero => return Some((sign < 0, 0, 0)),
+        _ => {}
+    }
+    let exp = exp as i32;
+
+    // `v` is in `[16^(c - 65), 16^(c - 64))`, unless the characteristic is out of range
+    let nbits = 128 - mant.leading_zeros() as i32;
+    let c = ((exp + nbits - 1) >> 2) + 65;
+    let c = if c < 0 { 0 } else { c };
+    let max = (sign < 0, 127, (1 << (4 * digits)) - 1);
+    if c > 127 { return Some(max); }
+
+    // `mant * 2^exp` is rounded to `q * 16^(c - 64 - digits)` where `q` has `digits` digits
+    let qexp = 4 * (c - 64 - digits as i32);
+    let q = if qexp <= exp {
+        mant << (exp - qexp) as usize
+    } else if qexp - exp > nbits {
+        0 // `v < 2^(qexp-1)`, which is less than the half of the minimum unnormalized value
+    } else {
+        let shift = (qexp - exp) as usize;
+        let q = mant >> shift;
+        let rem = mant & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q }
+    };
+
+    if q == 0 {
+        Some((sign < 0, 0, 0))
+    } else if q >> (4 * digits) != 0 {
+        // rounded up to the next power of 16
+        if c == 127 { Some(max) } else { Some((sign < 0, c as u32 + 1, q >> 4)) }
+    } else {
+        Some((sign < 0, c as u32, q))
+    }
+}
+
+impl Hfp32 {
+    /// Converts `f64` to the nearest `Hfp32`, with ties to even. Finite values beyond
+    /// the range saturate to the maximum. Returns `None` for NaNs and infinities.
+    pub fn from_f64(v: f64) -> Option<Hfp32> {
+        encode_f64(v, 6).map(|(negative, c, frac)| {
+            Hfp32((negative as u32) << 31 | c << 24 | frac as u32)
+        })
+    }
+}
+
+impl Hfp64 {
+    /// Converts `f64` to the nearest `Hfp64`. See `Hfp32::from_f64`.
+    /// This is exact for any `f64` in the normalized range.
+    pub fn from_f64(v: f64) -> Option<Hfp64> {
+        encode_f64(v, 14).map(|(negative, c, frac)| {
+            Hfp64((negative as u64) << 63 | (c as u64) << 56 | frac as u64)
+        })
+    }
+}
+
+impl Hfp128 {
+    /// Converts `f64` to the nearest `Hfp128`. See `Hfp32::from_f64`.
+    /// This is exact for any `f64` in the normalized range.
+    pub fn from_f64(v: f64) -> Option<Hfp128> {
+        encode_f64(v, 28).map(|(negative, c, frac)| {
+            let sign = (negative as u64) << 63;
+            let hi = sign | (c as u64) << 56 | (frac >> 56) as u64;
+            // the zero is the true zero in both halves
+            let lo = if frac == 0 { sign }
+                     else { sign | ((c as u64 + 128 - 14) & 0x7f) << 56 | frac as u64 & MASK56 };
+            Hfp128(hi, lo)
+        })
+    }
+}
+
+impl Decodable for Hfp32 {
+    fn decode(self) -> (bool, FullDecoded) {
+        decode_parts(self.0 >> 31 != 0, (self.0 >> 24) & 0x7f, (self.0 & 0xffffff) as u128, 6)
+    }
+}
+
+impl Decodable for Hfp64 {
+    fn decode(self) -> (bool, FullDecoded) {
+        decode_parts(self.0 >> 63 != 0, (self.0 >> 56) as u32 & 0x7f, (self.0 & MASK56) as u128, 14)
+    }
+}
+
+impl Decodable for Hfp128 {
+    fn decode(self) -> (bool, FullDecoded) {
+        let frac = ((self.0 & MASK56) as u128) << 56 | (self.1 & MASK56) as u128;
+        decode_parts(self.0 >> 63 != 0, (self.0 >> 56) as u32 & 0x7f, frac, 28)
+    }
+}
@@ -0,0 +1,174 @@
+//! Small floating point formats described at the runtime, which can be decoded.
+//!
+//! Machine learning accelerators use a number of 8-bit or narrower formats ("minifloats"),
+//! which differ in the number of exponent and mantissa bits, the bias and how (or whether)
+//! they encode infinities and NaNs. `FloatFormat` describes such a format, and `decode_bits`
+//! decodes a bit pattern of that format into `FullDecoded`, which is directly fed to
+//! any strategy. `Minifloat` pairs a bit pattern with its format so that it can be given
+//! to the formatting functions as well (except for `to_hex_str`).
+//!
+//! The formats are not limited to 8 bits, but should have at most 11 bits of exponent
+//! and at most 32 bits in total, and the bias should keep every value within the range
+//! of `f64` (see `FloatFormat::new`), so that every value is exactly representable as `f64`.
+//! `MAX_SIG_DIGITS` bytes of buffer are enough for the shortest mode then.
+
+use core::prelude::*;
+
+use flt2dec::decoder::{Decodable, Decoded, FullDecoded};
+
+/// How infinities and NaNs are encoded, if any.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Specials {
+    /// As in IEEE 754, the maximum biased exponent is reserved; the pattern with
+    /// the zero mantissa is an infinity and others are NaNs.
+    Ieee,
+    /// There are no infinities, and only the pattern with all exponent and mantissa bits set
+    /// is NaN. Other patterns with the maximum biased exponent are normal numbers.
+    /// This is `FN` ("finite and NaN") in the names like `E4M3FN`.
+    AllOnesNan,
+    /// There are no infinities nor negative zero, and the pattern of the negative zero
+    /// is the only NaN. This is `FNUZ` ("finite and NaN, unsigned zero") in the names
+    /// like `E4M3FNUZ`.
+    NegativeZeroNan,
+    /// There are no infinities nor NaNs, and every pattern is a finite number.
+    Finite,
+}
+
+/// A binary floating point format with a sign bit, `ebits` bits of biased exponent and
+/// `mbits` bits of mantissa (without the implicit bit), in this order from the highest bit.
+///
+/// Biased exponents from 1 to the maximum are normal numbers `1.m * 2^(e - bias)`, and
+/// the biased exponent 0 is for subnormals `0.m * 2^(1 - bias)`, unless reserved by `specials`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FloatFormat {
+    /// The number of exponent bits, from 1 to 11.
+    pub ebits: u32,
+    /// The number of explicit mantissa bits, which can be zero.
+    pub mbits: u32,
+    /// The exponent bias.
+    pub bias: i16,
+    /// The encoding of infinities and NaNs.
+    pub specials: Specials,
+}
+
+/// FP8 E5M2, which is the upper half of binary16 and has the IEEE 754 infinities and NaNs.
+pub const E5M2: FloatFormat =
+    FloatFormat { ebits: 5, mbits: 2, bias: 15, specials: Specials::Ieee };
+
+/// FP8 E5M2FNUZ, with the bias of 16 and only one NaN at `0x80`.
+pub const E5M2FNUZ: FloatFormat =
+    FloatFormat { ebits: 5, mbits: 2, bias: 16, specials: Specials::NegativeZeroNan };
+
+/// FP8 E4M3 in the IEEE 754 style, which has the infinities and NaNs and
+/// thus the maximum value of 240.
+pub const E4M3: FloatFormat =
+    FloatFormat { ebits: 4, mbits: 3, bias: 7, specials: Specials::Ieee };
+
+/// FP8 E4M3FN as in the OCP 8-bit floating point specification, which has no infinities
+/// and NaNs only at `0x7f` and `0xff`, extending the maximum value to 448.
+pub const E4M3FN: FloatFormat =
+    FloatFormat { ebits: 4, mbits: 3, bias: 7, specials: Specials::AllOnesNan };
+
+/// FP8 E4M3FNUZ, with the bias of 8 and only one NaN at `0x80`.
+pub const E4M3FNUZ: FloatFormat =
+    FloatFormat { ebits: 4, mbits: 3, bias: 8, specials: Specials::NegativeZeroNan };
+
+/// FP6 E3M2 as in the OCP microscaling (MX) specification, which has no infinities nor NaNs.
+pub const E3M2: FloatFormat =
+    FloatFormat { ebits: 3, mbits: 2, bias: 3, specials: Specials::Finite };
+
+/// FP6 E2M3 as in the OCP microscaling (MX) specification, which has no infinities nor NaNs.
+pub const E2M3: FloatFormat =
+    FloatFormat { ebits: 2, mbits: 3, bias: 1, specials: Specials::Finite };
+
+/// FP4 E2M1 as in the OCP microscaling (MX) specification, which has no infinities nor NaNs.
+pub const E2M1: FloatFormat =
+    FloatFormat { ebits: 2, mbits: 1, bias: 1, specials: Specials::Finite };
+
+impl FloatFormat {
+    /// Makes a new format, or panics if the format is not supported.
+    /// `ebits` should be from 1 to 11, `ebits + mbits` should be at most 31, and `bias`
+    /// should put the minimum subnormal at or above `2^-1074` and the largest binade
+    /// at or below `2^1023`, just like `f64`.
+    pub fn new(ebits: u32, mbits: u32, bias: i16, specials: Specials) -> FloatFormat {
+        let format = FloatFormat { ebits: ebits, mbits: mbits, bias: bias, specials: specials };
+        assert!(format.is_valid());
+        format
+    }
+
+    /// Returns true if the format is supported, i.e. `FloatFormat::new` would accept it.
+    pub fn is_valid(&self) -> bool {
+        if self.ebits < 1 || self.ebits > 11 || self.ebits + self.mbits > 31 { return false; }
+        // compared in `i32`, as the exponents can overflow `i16` for extreme biases
+        let bias = self.bias as i32;
+        let emax = (1 << self.ebits) - 1;
+        1 - bias - self.mbits as i32 >= -1074 && emax - bias <= 1023
+    }
+
+    /// Returns the total number of bits, including the sign bit.
+    pub fn width(&self) -> u32 {
+        1 + self.ebits + self.mbits
+    }
+}
+
+/// Returns a sign (true when negative) and `FullDecoded` value from the lowest bits
+/// of `bits` in given format. The higher bits are ignored.
+pub fn decode_bits(format: &FloatFormat, bits: u32) -> (/*negative?*/ bool, FullDecoded) {
+    assert!(format.is_valid());
+    let (ebits, mbits) = (format.ebits, format.mbits);
+
+    let negative = (bits >> (ebits + mbits)) & 1 != 0;
+    let emax = (1 << ebits) - 1;
+    let exp = (bits >> mbits) & emax;
+    let mant = bits & ((1 << mbits) - 1);
+
+    let special = match format.specials {
+        Specials::Ieee => exp == emax,
+        Specials::AllOnesNan => exp == emax && mant == (1 << mbits) - 1,
+        Specials::NegativeZeroNan => negative && exp == 0 && mant == 0,
+        Specials::Finite => false,
+    };
+    if special {
+        let infinite = format.specials == Specials::Ieee && mant == 0;
+        return (negative, if infinite { FullDecoded::Infinite } else { FullDecoded::Nan });
+    }
+
+    // the exponent of the unit in the last place of subnormals and minimum normals,
+    // which fits in `i16` as the format is valid
+    let minexp = 1 - format.bias - mbits as i16;
+    let decoded = if exp == 0 {
+        if mant == 0 {
+            FullDecoded::Zero
+        } else {
+            // neighbors: (mant - 2, exp) -- (mant, exp) -- (mant + 2, exp)
+            // the mantissa is scaled and thus always even, just like `decode`.
+            FullDecoded::Finite(Decoded { mant: (mant as u128) << 1, minus: 1, plus: 1,
+                                          exp: minexp - 1, inclusive: true })
+        }
+    } else {
+        let m = (mant | (1 << mbits)) as u128;
+        let e = minexp + exp as i16 - 1;
+        // the implicit bit is odd when `mbits` is zero
+        let even = mbits > 0 && mant & 1 == 0;
+        if mant == 0 {
+            // neighbors: (maxmant, exp - 1) -- (minnormmant, exp) -- (minnormmant + 1, exp)
+            // this is slightly narrower than needed for the minimum normal, as `decode` does.
+            FullDecoded::Finite(Decoded { mant: m << 2, minus: 1, plus: 2,
+                                          exp: e - 2, inclusive: even })
+        } else {
+            // neighbors: (mant - 1, exp) -- (mant, exp) -- (mant + 1, exp)
+            FullDecoded::Finite(Decoded { mant: m << 1, minus: 1, plus: 1,
+                                          exp: e - 1, inclusive: even })
+        }
+    };
+    (negative, decoded)
+}
+
+/// A bit pattern paired with its format, which can be given to the formatting functions.
+/// The equality is bitwise.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Minifloat<'a>(pub &'a FloatFormat, pub u32);
+
+impl<'a> Decodable for Minifloat<'a> {
+    fn decode(self) -> (bool, FullDecoded) { decode_bits(self.0, self.1) }
+}
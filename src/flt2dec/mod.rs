@@ -76,14 +76,28 @@ extensively describes all necessary justifications and many proofs for them.
 Both implementations expose two public functions:
 
 - `format_shortest(decoded, buf)`, which always needs at least
-  `MAX_SIG_DIGITS` digits of buffer. Implements the shortest mode.
+  `MAX_SIG_DIGITS` digits of buffer (more for types more precise than `f64`).
+  Implements the shortest mode.
 
 - `format_exact(decoded, buf, limit)`, which accepts as small as
   one digit of buffer. Implements exact and fixed modes.
 
 They try to fill the `u8` buffer with digits and returns the number of digits
 written and the exponent `k`. They are total for all finite `f32` and `f64`
-inputs (Grisu internally falls back to Dragon if necessary).
+inputs (Grisu internally falls back to Dragon if necessary). Dragon and Grisu
+also accept 16-bit floating point types in the `half` module, which are
+decoded to their own (much wider) intervals, and the binary128 type in
+the `quad` module, which needs a larger buffer of `MAX_SIG_DIGITS_F128` digits
+in the shortest mode and internally uses a much larger bignum. The same goes for
+the x87 80-bit and double-double types in the `extended` module.
+Small formats only known at the runtime (e.g. 8-bit formats like `E4M3FN`) can be
+described by `minifloat::FloatFormat` and decoded by `minifloat::decode_bits`.
+Legacy non-IEEE types are also supported: VAX F, D and G floating types in the `vax` module,
+which are `DecodableFloat`, and IBM hexadecimal floating point types in the `hfp` module,
+which are only `Decodable`. Their 56-bit types need a longer buffer in the shortest mode,
+`MAX_SIG_DIGITS_VAXD` digits for `VaxD` and `MAX_SIG_DIGITS_F80` digits for `Hfp64`.
+Arbitrary-precision binary numbers, which are too wide for `Decoded`, can be directly given
+to `strategy::dragon::format_shortest_limbs` and `format_exact_limbs` as a slice of digits.
 
 The rendered digits are formatted into the actual string form with
 four functions:
@@ -101,11 +115,16 @@ four functions:
 - `to_exact_fixed_str` prints the fixed representation with *exactly*
   given number of fractional digits.
 
+There is also `to_hex_str`, which prints the hexadecimal exponential form
+such as `0x1.8p-3` (C's `%a`). It does not need any digit-generation algorithm,
+since every binary floating point number has a finite hexadecimal representation.
+
 They all return a slice of preallocated `Part` array, which corresponds to
 the individual part of strings: a fixed string, a part of rendered digits,
 a number of zeroes or a small (`u16`) number. The caller is expected to
 provide a large enough buffer and `Part` array, and to assemble the final
-string from resulting `Part`s itself.
+string from resulting `Part`s itself. The decimal forms can be further
+grouped with thousands separators by `Formatted::group`.
 
 All algorithms and formatting functions are accompanied by extensive tests
 in the `tests` module. It also shows how to use individual functions.
@@ -114,18 +133,35 @@ in the `tests` module. It also shows how to use individual functions.
 
 use core::prelude::*;
 use core::i16;
-use core::num::Float;
+use core::num::FpCategory;
 use core::slice::bytes;
-pub use self::decoder::{decode, DecodableFloat, FullDecoded, Decoded};
+pub use self::decoder::{decode, Decodable, DecodableFloat, FullDecoded, Decoded};
+pub use grouping::Grouping;
+use grouping::is_boundary;
 
 pub mod estimator;
 pub mod bignum;
 pub mod decoder;
+pub mod half;
+pub mod quad;
+pub mod extended;
+pub mod minifloat;
+pub mod hfp;
+pub mod vax;
 
 /// Digit-generation algorithms.
 pub mod strategy {
     pub mod dragon;
     pub mod grisu;
+    pub mod grisu2;
+    pub mod errol3;
+    pub mod ryu;
+    pub mod ryu_printf;
+    pub mod schubfach;
+    pub mod dragonbox;
+    pub mod runtime;
+
+    mod binary;
 }
 
 #[cfg(test)] mod tests;
@@ -137,6 +173,54 @@ pub mod strategy {
 /// The exact formula is `ceil(# bits in mantissa * log_10 2 + 1)`.
 pub const MAX_SIG_DIGITS: usize = 17;
 
+/// Same to `MAX_SIG_DIGITS` but for `f32`, which has 24 bits of mantissa.
+///
+/// Any shortest result for `f32` fits in this, but the strategies may still require
+/// the buffer of at least `MAX_SIG_DIGITS` bytes.
+pub const MAX_SIG_DIGITS_F32: usize = 9;
+
+/// Same to `MAX_SIG_DIGITS_F32` but for `half::F16`, which has 11 bits of mantissa.
+pub const MAX_SIG_DIGITS_F16: usize = 5;
+
+/// Same to `MAX_SIG_DIGITS_F32` but for `half::Bf16`, which has 8 bits of mantissa.
+pub const MAX_SIG_DIGITS_BF16: usize = 4;
+
+/// Same to `MAX_SIG_DIGITS` but for `quad::F128`, which has 113 bits of mantissa.
+///
+/// Unlike narrower types, the buffer for `quad::F128` (and types in `extended`) should be
+/// at least this long in the shortest mode. Only `strategy::dragon` (and `strategy::grisu`,
+/// which falls back to Dragon) supports them.
+pub const MAX_SIG_DIGITS_F128: usize = 36;
+
+/// Same to `MAX_SIG_DIGITS_F32` but for `extended::F80`, which has 64 bits of mantissa.
+///
+/// The buffer should be still at least `MAX_SIG_DIGITS_F128` bytes long in the shortest mode.
+pub const MAX_SIG_DIGITS_F80: usize = 21;
+
+/// Same to `MAX_SIG_DIGITS_F80` but for `extended::DoubleDouble`, which is decoded to
+/// 107 bits of mantissa.
+pub const MAX_SIG_DIGITS_DD: usize = 34;
+
+/// Same to `MAX_SIG_DIGITS` but for `vax::VaxD`, which has 56 bits of mantissa.
+///
+/// Unlike `f32` and other narrower types, the buffer for `vax::VaxD` should be
+/// at least this long in the shortest mode.
+pub const MAX_SIG_DIGITS_VAXD: usize = 18;
+
+/// Returns the minimum size of buffer the shortest mode needs for `d`.
+/// This is `MAX_SIG_DIGITS` unless `d` is more precise than `f64` (e.g. from `vax::VaxD`).
+#[doc(hidden)]
+pub fn shortest_buf_len(d: &Decoded) -> usize {
+    // the precision is counted from the larger half of the interval to its upper bound,
+    // so that the asymmetric interval at powers of two has the same precision as others.
+    // for `f64` they are at most `2^54 + 2` and at least `1` (or `2` respectively).
+    let high = d.mant.checked_add(d.plus).unwrap_or(!0);
+    let ulp = if d.minus > d.plus { d.minus } else { d.plus };
+    let prec = ulp.leading_zeros().saturating_sub(high.leading_zeros());
+    let len = strategy::dragon::max_sig_digits(prec);
+    if len > MAX_SIG_DIGITS { len } else { MAX_SIG_DIGITS }
+}
+
 /// When `d[..n]` contains decimal digits, increase the last digit and propagate carry.
 /// Returns a next digit when it causes the length change.
 #[doc(hidden)]
@@ -243,6 +327,133 @@ impl<'a> Formatted<'a> {
         }
         Some(written)
     }
+
+    /// Returns the exact number of parts required by `group` with given grouping.
+    pub fn grouped_nparts(&self, grouping: &Grouping<'a>) -> usize {
+        let mut nparts = 0;
+        each_grouped_part(self.parts, grouping, |_| nparts += 1);
+        nparts
+    }
+
+    /// Applies the digit grouping to the formatted parts. The result is stored to
+    /// the supplied parts array, which should be at least `grouped_nparts` long.
+    ///
+    /// Digits before the decimal point are grouped with `grouping.groups`, and
+    /// digits after it are grouped with `grouping.frac_groups`. The decimal point
+    /// itself is replaced with `grouping.decimal_mark`. Anything after the digits
+    /// (e.g. the exponent or non-finite values) is copied verbatim.
+    pub fn group(&self, grouping: &Grouping<'a>, parts: &'a mut [Part<'a>]) -> Formatted<'a> {
+        let mut n = 0;
+        each_grouped_part(self.parts, grouping, |part| {
+            parts[n] = part;
+            n += 1;
+        });
+        Formatted { sign: self.sign, parts: &parts[..n] }
+    }
+}
+
+/// The current position while grouping parts. See `each_grouped_part`.
+#[derive(Copy, Clone)]
+enum GroupingPos {
+    /// Before the decimal point, with given number of integral digits yet to be emitted.
+    Int(usize),
+    /// After the decimal point, with given number of fractional digits already emitted.
+    Frac(usize),
+    /// After all digits; everything is copied verbatim.
+    Verbatim,
+}
+
+/// Calls `f` with each part of the grouped result of `parts`.
+fn each_grouped_part<'a, F>(parts: &[Part<'a>], grouping: &Grouping<'a>, mut f: F)
+        where F: FnMut(Part<'a>) {
+    // counts integral digits first, as the integral part is grouped from the right
+    let mut nint = 0;
+    for &part in parts {
+        match part {
+            Part::Zero(nzeroes) => { nint += nzeroes; }
+            Part::Num(_) => { break; }
+            Part::Copy(buf) => match buf.iter().position(|&c| !is_digit(c)) {
+                Some(i) => { nint += i; break; }
+                None => { nint += buf.len(); }
+            },
+        }
+    }
+
+    let mut pos = GroupingPos::Int(nint);
+    for &part in parts {
+        if let GroupingPos::Verbatim = pos {
+            f(part);
+            continue;
+        }
+        match part {
+            Part::Zero(nzeroes) => {
+                group_digits(grouping, &mut pos, nzeroes, |i, j| Part::Zero(j - i), &mut f);
+            }
+            Part::Num(_) => {
+                pos = GroupingPos::Verbatim;
+                f(part);
+            }
+            Part::Copy(mut buf) => loop {
+                let i = buf.iter().position(|&c| !is_digit(c)).unwrap_or(buf.len());
+                let digits = &buf[..i];
+                group_digits(grouping, &mut pos, i, |i, j| Part::Copy(&digits[i..j]), &mut f);
+                if i == buf.len() { break; }
+                match (buf[i], pos) {
+                    (b'.', GroupingPos::Int(_)) => {
+                        f(Part::Copy(grouping.decimal_mark));
+                        pos = GroupingPos::Frac(0);
+                        buf = &buf[i+1..];
+                    }
+                    _ => {
+                        f(Part::Copy(&buf[i..]));
+                        pos = GroupingPos::Verbatim;
+                        break;
+                    }
+                }
+            },
+        }
+    }
+}
+
+fn is_digit(c: u8) -> bool {
+    b'0' <= c && c <= b'9'
+}
+
+/// Emits a run of `len` digits at `pos` with separators inserted. Parts for digits
+/// `i..j` in the run are made with `sub(i, j)`. Only used by `each_grouped_part`.
+fn group_digits<'a, S, F>(grouping: &Grouping<'a>, pos: &mut GroupingPos, len: usize,
+                          sub: S, f: &mut F)
+        where S: Fn(usize, usize) -> Part<'a>, F: FnMut(Part<'a>) {
+    let mut i = 0;
+    match *pos {
+        GroupingPos::Int(nint) => {
+            // the separator goes *after* a digit with given number of digits to the right
+            while i < len {
+                let mut j = i + 1;
+                while j < len && !is_boundary(grouping.groups, nint - j) { j += 1; }
+                f(sub(i, j));
+                if is_boundary(grouping.groups, nint - j) {
+                    f(Part::Copy(grouping.separator));
+                }
+                i = j;
+            }
+            *pos = GroupingPos::Int(nint - len);
+        }
+        GroupingPos::Frac(nfrac) => {
+            // the separator goes *before* a digit with given number of digits to the left
+            while i < len {
+                if is_boundary(grouping.frac_groups, nfrac + i) {
+                    f(Part::Copy(grouping.separator));
+                }
+                let mut j = i + 1;
+                while j < len && !is_boundary(grouping.frac_groups, nfrac + j) { j += 1; }
+                f(sub(i, j));
+                i = j;
+            }
+            *pos = GroupingPos::Frac(nfrac + len);
+        }
+        GroupingPos::Verbatim => unreachable!(),
+    }
 }
 
 /// Formats given decimal digits `0.<...buf...> * 10^exp` into the decimal form
@@ -253,8 +464,9 @@ impl<'a> Formatted<'a> {
 /// it will be ignored and full digits will be printed. It is only used to print
 /// additional zeroes after rendered digits. Thus `frac_digits` of 0 means that
 /// it will only print given digits and nothing else.
-fn digits_to_dec_str<'a>(buf: &'a [u8], exp: i16, frac_digits: usize,
-                         parts: &'a mut [Part<'a>]) -> &'a [Part<'a>] {
+#[doc(hidden)]
+pub fn digits_to_dec_str<'a>(buf: &'a [u8], exp: i16, frac_digits: usize,
+                             parts: &'a mut [Part<'a>]) -> &'a [Part<'a>] {
     assert!(!buf.is_empty());
     assert!(buf[0] > b'0');
     assert!(parts.len() >= 4);
@@ -321,8 +533,9 @@ fn digits_to_dec_str<'a>(buf: &'a [u8], exp: i16, frac_digits: usize,
 /// it will be ignored and full digits will be printed. It is only used to print
 /// additional zeroes after rendered digits. Thus `min_digits` of 0 means that
 /// it will only print given digits and nothing else.
-fn digits_to_exp_str<'a>(buf: &'a [u8], exp: i16, min_ndigits: usize, upper: bool,
-                         parts: &'a mut [Part<'a>]) -> &'a [Part<'a>] {
+#[doc(hidden)]
+pub fn digits_to_exp_str<'a>(buf: &'a [u8], exp: i16, min_ndigits: usize, upper: bool,
+                             parts: &'a mut [Part<'a>]) -> &'a [Part<'a>] {
     assert!(!buf.is_empty());
     assert!(buf[0] > b'0');
     assert!(parts.len() >= 6);
@@ -396,17 +609,19 @@ fn determine_sign(sign: Sign, decoded: &FullDecoded, negative: bool) -> &'static
 /// additional zeroes after rendered digits. Thus `frac_digits` of 0 means that
 /// it will only print given digits and nothing else.
 ///
-/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long
+/// (`MAX_SIG_DIGITS_F128` bytes for `quad::F128` and types in `extended`,
+/// and `MAX_SIG_DIGITS_VAXD` bytes for `vax::VaxD`).
 /// There should be at least 5 parts available, due to the worst case like
 /// `[+][0.][0000][45][0000]` with `frac_digits = 10`.
 pub fn to_shortest_str<'a, T, F>(mut format_shortest: F, v: T,
                                  sign: Sign, frac_digits: usize, upper: bool,
                                  buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
-        where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+        where T: Decodable, F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
     assert!(parts.len() >= 4);
     assert!(buf.len() >= MAX_SIG_DIGITS);
 
-    let (negative, full_decoded) = decode(v);
+    let (negative, full_decoded) = v.decode();
     let sign = determine_sign(sign, &full_decoded, negative);
     match full_decoded {
         FullDecoded::Nan => {
@@ -428,6 +643,7 @@ pub fn to_shortest_str<'a, T, F>(mut format_shortest: F, v: T,
             }
         }
         FullDecoded::Finite(ref decoded) => {
+            assert!(buf.len() >= shortest_buf_len(decoded));
             let (len, exp) = format_shortest(decoded, buf);
             Formatted { sign: sign,
                         parts: digits_to_dec_str(&buf[..len], exp, frac_digits, parts) }
@@ -451,18 +667,20 @@ pub fn to_shortest_str<'a, T, F>(mut format_shortest: F, v: T,
 /// instead of the actual `v`! Thus any printed exponent in the exponential form
 /// cannot be in this range, avoiding any confusion.
 ///
-/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long.
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long
+/// (`MAX_SIG_DIGITS_F128` bytes for `quad::F128` and types in `extended`,
+/// and `MAX_SIG_DIGITS_VAXD` bytes for `vax::VaxD`).
 /// There should be at least 7 parts available, due to the worst case like
 /// `[+][1][.][2345][e][-][67]`.
 pub fn to_shortest_exp_str<'a, T, F>(mut format_shortest: F, v: T,
                                      sign: Sign, dec_bounds: (i16, i16), upper: bool,
                                      buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
-        where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+        where T: Decodable, F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
     assert!(parts.len() >= 6);
     assert!(buf.len() >= MAX_SIG_DIGITS);
     assert!(dec_bounds.0 <= dec_bounds.1);
 
-    let (negative, full_decoded) = decode(v);
+    let (negative, full_decoded) = v.decode();
     let sign = determine_sign(sign, &full_decoded, negative);
     match full_decoded {
         FullDecoded::Nan => {
@@ -482,6 +700,7 @@ pub fn to_shortest_exp_str<'a, T, F>(mut format_shortest: F, v: T,
             Formatted { sign: sign, parts: &parts[..1] }
         }
         FullDecoded::Finite(ref decoded) => {
+            assert!(buf.len() >= shortest_buf_len(decoded));
             let (len, exp) = format_shortest(decoded, buf);
             let vis_exp = exp as i32 - 1;
             let parts = if dec_bounds.0 as i32 <= vis_exp && vis_exp < dec_bounds.1 as i32 {
@@ -495,7 +714,7 @@ pub fn to_shortest_exp_str<'a, T, F>(mut format_shortest: F, v: T,
 }
 
 /// Returns rather crude approximation (upper bound) for the maximum buffer size
-/// calculated from the given decoded exponent.
+/// calculated from the given decoded mantissa and exponent.
 ///
 /// The exact limit is:
 ///
@@ -505,7 +724,8 @@ pub fn to_shortest_exp_str<'a, T, F>(mut format_shortest: F, v: T,
 /// `ceil(log_10 (x^exp * (2^64 - 1)))` is less than `ceil(log_10 (2^64 - 1)) +
 /// ceil(exp * log_10 x)`, which is in turn less than `20 + (1 + exp * log_10 x)`.
 /// We use the facts that `log_10 2 < 5/16` and `log_10 5 < 12/16`, which is
-/// enough for our purposes.
+/// enough for our purposes. The mantissa of `2^64` or more (e.g. from `quad::F128`
+/// and `extended::F80`) replaces `2^64 - 1` with `2^128 - 1`, so the constant is 39 instead.
 ///
 /// Why do we need this? `format_exact` functions will fill the entire buffer
 /// unless limited by the last digit restriction, but it is possible that
@@ -514,8 +734,10 @@ pub fn to_shortest_exp_str<'a, T, F>(mut format_shortest: F, v: T,
 /// allocate all the buffer beforehand. Consequently, for any given arguments,
 /// 826 bytes of buffer should be sufficient for `f64`. Compare this with
 /// the actual number for the worst case: 770 bytes (when `exp = -1074`).
-fn estimate_max_buf_len(exp: i16) -> usize {
-    21 + ((if exp < 0 { -12 } else { 5 } * exp as i32) as usize >> 4)
+/// For `quad::F128` it is 12,412 bytes, while the worst case is 11,563 bytes.
+fn estimate_max_buf_len(mant: u128, exp: i16) -> usize {
+    let base = if mant < 1 << 64 { 21 } else { 40 };
+    base + ((if exp < 0 { -12 } else { 5 } * exp as i32) as usize >> 4)
 }
 
 /// Formats given floating point number into the exponential form with
@@ -530,17 +752,18 @@ fn estimate_max_buf_len(exp: i16) -> usize {
 ///
 /// The byte buffer should be at least `ndigits` bytes long unless `ndigits` is
 /// so large that only the fixed number of digits will be ever written.
-/// (The tipping point for `f64` is about 800, so 1000 bytes should be enough.)
+/// (The tipping point for `f64` is about 800, so 1000 bytes should be enough.
+/// It is about 12,400 for `quad::F128`.)
 /// There should be at least 7 parts available, due to the worst case like
 /// `[+][1][.][2345][e][-][67]`.
 pub fn to_exact_exp_str<'a, T, F>(mut format_exact: F, v: T,
                                   sign: Sign, ndigits: usize, upper: bool,
                                   buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
-        where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+        where T: Decodable, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
     assert!(parts.len() >= 6);
     assert!(ndigits > 0);
 
-    let (negative, full_decoded) = decode(v);
+    let (negative, full_decoded) = v.decode();
     let sign = determine_sign(sign, &full_decoded, negative);
     match full_decoded {
         FullDecoded::Nan => {
@@ -563,7 +786,7 @@ pub fn to_exact_exp_str<'a, T, F>(mut format_exact: F, v: T,
             }
         }
         FullDecoded::Finite(ref decoded) => {
-            let maxlen = estimate_max_buf_len(decoded.exp);
+            let maxlen = estimate_max_buf_len(decoded.mant, decoded.exp);
             assert!(buf.len() >= ndigits || buf.len() >= maxlen);
 
             let trunc = if ndigits < maxlen { ndigits } else { maxlen };
@@ -586,16 +809,17 @@ pub fn to_exact_exp_str<'a, T, F>(mut format_exact: F, v: T,
 ///
 /// The byte buffer should be enough for the output unless `frac_digits` is
 /// so large that only the fixed number of digits will be ever written.
-/// (The tipping point for `f64` is about 800, and 1000 bytes should be enough.)
+/// (The tipping point for `f64` is about 800, and 1000 bytes should be enough.
+/// It is about 12,400 for `quad::F128`.)
 /// There should be at least 5 parts available, due to the worst case like
 /// `[+][0.][0000][45][0000]` with `frac_digits = 10`.
 pub fn to_exact_fixed_str<'a, T, F>(mut format_exact: F, v: T,
                                     sign: Sign, frac_digits: usize, upper: bool,
                                     buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
-        where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+        where T: Decodable, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
     assert!(parts.len() >= 4);
 
-    let (negative, full_decoded) = decode(v);
+    let (negative, full_decoded) = v.decode();
     let sign = determine_sign(sign, &full_decoded, negative);
     match full_decoded {
         FullDecoded::Nan => {
@@ -617,7 +841,7 @@ pub fn to_exact_fixed_str<'a, T, F>(mut format_exact: F, v: T,
             }
         }
         FullDecoded::Finite(ref decoded) => {
-            let maxlen = estimate_max_buf_len(decoded.exp);
+            let maxlen = estimate_max_buf_len(decoded.mant, decoded.exp);
             assert!(buf.len() >= maxlen);
 
             // it *is* possible that `frac_digits` is ridiculously large.
@@ -646,3 +870,117 @@ pub fn to_exact_fixed_str<'a, T, F>(mut format_exact: F, v: T,
     }
 }
 
+
+/// Formats given floating point number into the hexadecimal exponential form,
+/// i.e. `0x1.8p-3`, as C's `%a` conversion does. The result is stored to
+/// the supplied parts array while utilizing given byte buffer as a scratch.
+/// `upper` is used to determine the case of the prefix (`0x` or `0X`),
+/// hexadecimal digits, the exponent prefix (`p` or `P`) and non-finite values.
+/// The returned `Formatted` keeps the sign (which can be an empty string if no sign
+/// is rendered) in its `sign` field, and its parts start with the `0x` prefix,
+/// or consist of a single `nan` or `inf` for non-finite values.
+///
+/// When `frac_digits` is `None`, all hexadecimal digits required to represent `v`
+/// exactly are printed. Otherwise the mantissa is correctly rounded (ties to even)
+/// to *exactly* given number of fractional digits, padded by zeroes if needed.
+///
+/// The output follows glibc for the choice of the leading digit: it is `1` for
+/// normal numbers and `0` for subnormals, which share the minimum normal exponent.
+/// The rounding can carry into the leading digit, which is then printed as is
+/// (so `1.999` rounds to `0x2.0p+0` with one fractional digit).
+/// Zero is printed as `0x0p+0`.
+///
+/// The byte buffer should be at least `MAX_SIG_DIGITS` bytes long,
+/// and `MAX_SIG_DIGITS_F128` bytes for `quad::F128` and types in `extended`.
+/// There should be at least 7 parts available, due to the worst case like
+/// `[0x][1][.][8000000000000][00][p-][3]` with `frac_digits = Some(15)`.
+pub fn to_hex_str<'a, T>(v: T, sign: Sign, frac_digits: Option<usize>, upper: bool,
+                         buf: &'a mut [u8], parts: &'a mut [Part<'a>]) -> Formatted<'a>
+        where T: DecodableFloat {
+    assert!(parts.len() >= 7);
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    let (negative, full_decoded) = decode(v);
+    let sign = determine_sign(sign, &full_decoded, negative);
+
+    // the value is `(lead + frac / 2^nbits) * 2^exp`, where `lead` is either 0 or 1
+    let (lead, frac, nbits, exp) = match full_decoded {
+        FullDecoded::Nan => {
+            parts[0] = Part::Copy(if upper { b"NAN" } else { b"nan" });
+            return Formatted { sign: sign, parts: &parts[..1] };
+        }
+        FullDecoded::Infinite => {
+            parts[0] = Part::Copy(if upper { b"INF" } else { b"inf" });
+            return Formatted { sign: sign, parts: &parts[..1] };
+        }
+        FullDecoded::Zero => (0, 0, 0, 0),
+        FullDecoded::Finite(_) => {
+            // the minimum normal value is `2^nbits * 2^minexp`, so that `nbits` is
+            // the number of explicit mantissa bits and `nbits + minexp` is its exponent.
+            let (mant, exp, _) = v.integer_decode();
+            let (minmant, minexp, _) = <T as DecodableFloat>::min_pos_norm_value().integer_decode();
+            let nbits = minmant.trailing_zeros() as usize;
+            if v.classify() == FpCategory::Subnormal {
+                // `Float::integer_decode` may scale the mantissa of subnormals (see `decode`)
+                let frac = if exp < minexp { mant >> (minexp - exp) as usize }
+                           else { mant << (exp - minexp) as usize };
+                (0, frac, nbits, minexp as i32 + nbits as i32)
+            } else {
+                (1, mant - minmant, nbits, exp as i32 + nbits as i32)
+            }
+        }
+    };
+
+    // align the fraction to the hexadecimal digits
+    let nhex = (nbits + 3) / 4;
+    assert!(buf.len() > nhex);
+    let frac = frac << (nhex * 4 - nbits);
+
+    // `(lead, frac)` is rounded to `(lead, frac[..ndigits])`
+    let (lead, frac, ndigits, nzeroes) = match frac_digits {
+        None => {
+            let mut ndigits = nhex;
+            while ndigits > 0 && (frac >> ((nhex - ndigits) * 4)) & 0xf == 0 { ndigits -= 1; }
+            (lead, frac >> ((nhex - ndigits) * 4), ndigits, 0)
+        }
+        Some(frac_digits) if frac_digits >= nhex => (lead, frac, nhex, frac_digits - nhex),
+        Some(frac_digits) => {
+            let shift = (nhex - frac_digits) * 4;
+            let whole = (lead << (nhex * 4)) | frac;
+            let mut rounded = whole >> shift;
+            let rem = whole & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            if rem > half || (rem == half && rounded & 1 != 0) { rounded += 1; }
+            (rounded >> (frac_digits * 4), rounded & ((1 << (frac_digits * 4)) - 1),
+             frac_digits, 0)
+        }
+    };
+
+    let digits: &[u8] = if upper { b"0123456789ABCDEF" } else { b"0123456789abcdef" };
+    buf[0] = digits[lead as usize];
+    for i in 0..ndigits {
+        buf[ndigits - i] = digits[((frac >> (i * 4)) & 0xf) as usize];
+    }
+
+    let mut n = 0;
+    parts[n] = Part::Copy(if upper { b"0X" } else { b"0x" });
+    parts[n + 1] = Part::Copy(&buf[..1]);
+    n += 2;
+    if ndigits + nzeroes > 0 {
+        parts[n] = Part::Copy(b".");
+        parts[n + 1] = Part::Copy(&buf[1..ndigits + 1]);
+        n += 2;
+        if nzeroes > 0 {
+            parts[n] = Part::Zero(nzeroes);
+            n += 1;
+        }
+    }
+    if exp < 0 {
+        parts[n] = Part::Copy(if upper { b"P-" } else { b"p-" });
+        parts[n + 1] = Part::Num(-exp as u16);
+    } else {
+        parts[n] = Part::Copy(if upper { b"P+" } else { b"p+" });
+        parts[n + 1] = Part::Num(exp as u16);
+    }
+    Formatted { sign: sign, parts: &parts[..n + 2] }
+}
@@ -0,0 +1,111 @@
+//! 128-bit floating point type which can be `decode`d.
+//!
+//! Rust has no native binary128 type, so it is represented as a newtype over its bit pattern
+//! just like the `half` types. It only supports the exact conversion from `f64`,
+//! which is enough for feeding it to `decode` and therefore to `strategy::dragon`.
+//! Other strategies are limited to `f64` and narrower types.
+
+use core::prelude::*;
+
+use core::num::FpCategory;
+
+use flt2dec::decoder::DecodableFloat;
+
+/// IEEE 754 binary128 ("quadruple precision") number, with 113 bits of mantissa
+/// (including the implicit bit) and 15 bits of exponent.
+///
+/// The equality is bitwise, so `F128(0) != F128(1 << 127)` and NaNs can equal to each other.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct F128(pub u128);
+
+/// The number of explicit mantissa bits.
+const MBITS: usize = 112;
+/// The exponent field for infinities and NaNs.
+const EMAX: u128 = 0x7fff;
+/// The exponent bias.
+const BIAS: i32 = 16383;
+/// The exponent of the unit in the last place of subnormals and minimum normals.
+const MINEXP: i32 = 1 - BIAS - MBITS as i32;
+
+impl F128 {
+    /// Makes a value from the sign (true when negative) and `mant * 2^exp`,
+    /// rounded to the nearest with ties to even. `mant` should be less than `2^127`.
+    fn from_parts(negative: bool, mant: u128, exp: i32) -> F128 {
+        let sign = if negative { 1 << 127 } else { 0 };
+        if mant == 0 { return F128(sign); }
+        debug_assert!(mant < 1 << 127);
+
+        // `mant * 2^exp` is rounded to `q * 2^qexp` where `q` has 113 bits
+        // (or less for subnormals, whose `qexp` is fixed to that of minimum normals).
+        let nbits = 128 - mant.leading_zeros() as i32;
+        let qexp = if exp + nbits - 1 - (MBITS as i32) < MINEXP { MINEXP }
+                   else { exp + nbits - 1 - MBITS as i32 };
+        if qexp - MINEXP >= EMAX as i32 {
+            return F128(sign | (EMAX << MBITS)); // overflows to infinity
+        }
+
+        let q = if qexp <= exp {
+            mant << (exp - qexp) as usize
+        } else if qexp - exp > nbits {
+            0 // `v < 2^(qexp-1)`, which is less than the half of the minimum subnormal
+        } else {
+            let shift = (qexp - exp) as usize;
+            let q = mant >> shift;
+            let rem = mant & ((1 << shift) - 1);
+            let half = 1 << (shift - 1);
+            if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q }
+        };
+
+        // `q` overflows to the exponent field when rounded up to the next binade,
+        // and subnormals naturally become minimum normals in that case.
+        let rounded = (((qexp - MINEXP) as u128) << MBITS) + q;
+        if rounded >= EMAX << MBITS {
+            F128(sign | (EMAX << MBITS)) // overflows to infinity
+        } else {
+            F128(sign | rounded)
+        }
+    }
+
+    /// Converts `f64` to `F128` without any loss.
+    pub fn from_f64(v: f64) -> F128 {
+        let (mant, exp, sign) = DecodableFloat::integer_decode(v);
+        let negative = sign < 0;
+        match DecodableFloat::classify(v) {
+            FpCategory::Nan => {
+                F128(if negative { 1 << 127 } else { 0 } | (EMAX << MBITS) | (1 << (MBITS - 1)))
+            }
+            FpCategory::Infinite => F128(if negative { 1 << 127 } else { 0 } | (EMAX << MBITS)),
+            _ => F128::from_parts(negative, mant, exp as i32),
+        }
+    }
+}
+
+impl DecodableFloat for F128 {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        let mant = if f < 0 { (-(f as i128)) as u128 } else { f as u128 };
+        F128::from_parts(f < 0, mant, exp as i32)
+    }
+    fn min_pos_norm_value() -> Self { F128(1 << MBITS) }
+    fn integer_decode(self) -> (u128, i16, i8) {
+        let sign = if self.0 >> 127 == 0 { 1 } else { -1 };
+        let exp = ((self.0 >> MBITS) & EMAX) as i32;
+        let mant = self.0 & ((1 << MBITS) - 1);
+        if exp == 0 {
+            // the mantissa of subnormals is scaled by 2 (as `f32` and `f64` do)
+            (mant << 1, (MINEXP - 1) as i16, sign)
+        } else {
+            (mant | (1 << MBITS), (exp - BIAS - MBITS as i32) as i16, sign)
+        }
+    }
+    fn classify(self) -> FpCategory {
+        let exp = (self.0 >> MBITS) & EMAX;
+        let mant = self.0 & ((1 << MBITS) - 1);
+        match (exp, mant) {
+            (0, 0) => FpCategory::Zero,
+            (0, _) => FpCategory::Subnormal,
+            (EMAX, 0) => FpCategory::Infinite,
+            (EMAX, _) => FpCategory::Nan,
+            _ => FpCategory::Normal,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+/*!
+Helpers for the shortest mode strategies whose tables only cover `f32` and `f64`.
+
+Ryu, Schubfach and Dragonbox all finish with a `u64` decimal significand,
+and Schubfach and Dragonbox also need the original mantissa and exponent of the input.
+*/
+
+use core::prelude::*;
+
+use flt2dec::Decoded;
+
+/// The binary floating point type which `Decoded` was recovered from.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Binary {
+    F32,
+    F64,
+}
+
+/// Recovers `v = c * 2^q` from the decoder output, where `c` is the original mantissa.
+/// Returns the type of `v`, `c`, `q` and whether `v` is a power of two with an asymmetric
+/// rounding interval, or `None` if `d` does not come from `f32` or `f64`.
+///
+/// `f32` values are identified from their narrow mantissas; `f64` subnormals have
+/// a narrow mantissa as well, but their exponent is too small for `f32`.
+/// Anything else (e.g. a wider mantissa) does not fit either type exactly.
+pub fn recover(d: &Decoded) -> Option<(Binary, u64, i32, bool)> {
+    if d.mant >= 1 << 55 || d.minus != 1 || (d.plus != 1 && d.plus != 2) {
+        return None;
+    }
+
+    let asym = d.plus == 2;
+    let (c, q) = if asym {
+        ((d.mant >> 2) as u64, d.exp as i32 + 2)
+    } else {
+        ((d.mant >> 1) as u64, d.exp as i32 + 1)
+    };
+    if is_decoded_from(c, q, asym, 23, -149, 104) {
+        Some((Binary::F32, c, q, asym))
+    } else if is_decoded_from(c, q, asym, 52, -1074, 971) {
+        Some((Binary::F64, c, q, asym))
+    } else {
+        None
+    }
+}
+
+/// Returns true if `c * 2^q` is decoded from the binary floating point type with `mbits`
+/// explicit mantissa bits and the exponent range from `qmin` to `qmax` (as `2^q`
+/// of subnormals and the largest binade respectively), where `asym` is set for powers of two.
+fn is_decoded_from(c: u64, q: i32, asym: bool, mbits: usize, qmin: i32, qmax: i32) -> bool {
+    let hidden = 1 << mbits;
+    if asym {
+        c == hidden && qmin <= q && q <= qmax
+    } else if c < hidden {
+        q == qmin
+    } else {
+        c < hidden << 1 && qmin <= q && q <= qmax
+    }
+}
+
+/// Renders `f * 10^k` to `buf` without trailing zeroes, and returns the number of digits
+/// and the exponent as `format_shortest` does. `f` should be positive.
+pub fn render(mut f: u64, mut k: i32, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    debug_assert!(f > 0);
+    while f % 10 == 0 {
+        f /= 10;
+        k += 1;
+    }
+    let mut len = 1;
+    while len < 20 && f >= POW10[len] { len += 1; }
+    for c in buf[..len].iter_mut().rev() {
+        *c = b'0' + (f % 10) as u8;
+        f /= 10;
+    }
+    (len, (k + len as i32) as i16)
+}
+
+// FIXME(#22540) const ref to static array seems to ICE
+static POW10: [u64; 20] = [
+    1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000, 1000000000,
+    10000000000, 100000000000, 1000000000000, 10000000000000, 100000000000000,
+    1000000000000000, 10000000000000000, 100000000000000000, 1000000000000000000,
+    10000000000000000000,
+];
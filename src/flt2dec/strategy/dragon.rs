@@ -7,12 +7,14 @@ Almost direct (but slightly optimized) Rust translation of Figure 3 of [1].
 
 use core::prelude::*;
 use core::num::Float;
+use core::{cmp, mem, i16, i32};
 use core::cmp::Ordering;
 
-use flt2dec::{Decoded, MAX_SIG_DIGITS, round_up};
+use flt2dec::{Decoded, shortest_buf_len, round_up};
 use flt2dec::estimator::estimate_scaling_factor;
+use flt2dec::bignum::Bignum;
 use flt2dec::bignum::Digit32 as Digit;
-use flt2dec::bignum::Big32x36 as Big;
+use flt2dec::bignum::{Big32x36, Big32x520};
 
 // FIXME(#22540) const ref to static array seems to ICE
 static POW10: [Digit; 10] = [1, 10, 100, 1000, 10000, 100000,
@@ -31,10 +33,21 @@ static POW10TO256: [Digit; 27] =
     [0, 0, 0, 0, 0, 0, 0, 0, 0x982e7c01, 0xbed3875b, 0xd8d99f72, 0x12152f87, 0x6bde50c6,
      0xcf4a6e70, 0xd595d80f, 0x26b2716e, 0xadc666b0, 0x1d153624, 0x3c42d35a, 0x63ff540e,
      0xcc5573c0, 0x65f9ef17, 0x55bc28f2, 0x80dcc7f7, 0xf46eeddc, 0x5fdcefce, 0x553f7];
+static POW10TO512: [Digit; 54] =
+    [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xfc6cf801, 0x77f27267, 0x8f9546dc,
+     0x5d96976f, 0xb83a8a97, 0xc31e1ad9, 0x46c40513, 0x94e65747, 0xc88976c1, 0x4475b579,
+     0x28f8733b, 0xaa1da1bf, 0x703ed321, 0x1e25cfea, 0xb21a2f22, 0xbc51fb2e, 0x96e14f5d,
+     0xbfa3edac, 0x329c57ae, 0xe7fc7153, 0xc3fc0695, 0x85a91924, 0xf95f635e, 0xb2908ee0,
+     0x93abade4, 0x1366732a, 0x9449775c, 0x69be5b0e, 0x7343afac, 0xb099bc81, 0x45a71d46,
+     0xa2699748, 0x8cb07303, 0x8a0b1f13, 0x8cab8a97, 0xc1d238d9, 0x633415d4, 0x1c];
 
 #[doc(hidden)]
-pub fn mul_pow10<'a>(x: &'a mut Big, n: usize) -> &'a mut Big {
-    debug_assert!(n < 512);
+pub fn mul_pow10<'a, Big: Bignum<Digit=Digit>>(x: &'a mut Big, mut n: usize) -> &'a mut Big {
+    // only happens for binary128 and other types with the wide exponent
+    while n >= 512 {
+        x.mul_digits(&POW10TO512);
+        n -= 512;
+    }
     if n &   7 != 0 { x.mul_small(POW10[n & 7]); }
     if n &   8 != 0 { x.mul_small(POW10[8]); }
     if n &  16 != 0 { x.mul_digits(&POW10TO16); }
@@ -45,7 +58,7 @@ pub fn mul_pow10<'a>(x: &'a mut Big, n: usize) -> &'a mut Big {
     x
 }
 
-fn div_2pow10<'a>(x: &'a mut Big, mut n: usize) -> &'a mut Big {
+fn div_2pow10<'a, Big: Bignum<Digit=Digit>>(x: &'a mut Big, mut n: usize) -> &'a mut Big {
     let largest = POW10.len() - 1;
     while n > largest {
         x.div_rem_small(POW10[largest]);
@@ -56,8 +69,8 @@ fn div_2pow10<'a>(x: &'a mut Big, mut n: usize) -> &'a mut Big {
 }
 
 // only usable when `x < 16 * scale`; `scaleN` should be `scale.mul_small(N)`
-fn div_rem_upto_16<'a>(x: &'a mut Big, scale: &Big,
-                       scale2: &Big, scale4: &Big, scale8: &Big) -> (u8, &'a mut Big) {
+fn div_rem_upto_16<'a, Big: Bignum<Digit=Digit>>(x: &'a mut Big, scale: &Big, scale2: &Big,
+                                                 scale4: &Big, scale8: &Big) -> (u8, &'a mut Big) {
     let mut d = 0;
     if *x >= *scale8 { x.sub(scale8); d += 8; }
     if *x >= *scale4 { x.sub(scale4); d += 4; }
@@ -67,8 +80,28 @@ fn div_rem_upto_16<'a>(x: &'a mut Big, scale: &Big,
     (d, x)
 }
 
+/// Returns true if `Big32x36` is enough for formatting `d`, i.e. `d` is no wider than `f64`
+/// with some margin. Otherwise `Big32x520` should be used, which is costly to copy.
+fn fits_in_narrow(d: &Decoded) -> bool {
+    d.mant.checked_add(d.plus).map_or(false, |high| high < 1 << 64) &&
+        -1100 <= d.exp && d.exp <= 1000
+}
+
 /// The shortest mode implementation for Dragon.
+///
+/// The buffer should be at least `shortest_buf_len(d)` bytes long, which is `MAX_SIG_DIGITS`
+/// for `f64` and narrower types, and at most `MAX_SIG_DIGITS_F128` for `quad::F128`.
 pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    assert!(buf.len() >= shortest_buf_len(d));
+    if fits_in_narrow(d) {
+        format_shortest_with::<Big32x36>(d, buf)
+    } else {
+        format_shortest_with::<Big32x520>(d, buf)
+    }
+}
+
+fn format_shortest_with<Big>(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16)
+        where Big: Bignum<Digit=Digit> {
     // the number `v` to format is known to be:
     // - equal to `mant * 2^exp`;
     // - preceded by `(mant - 2 * minus) * 2^exp` in the original type; and
@@ -86,29 +119,35 @@ pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp
     assert!(d.plus > 0);
     assert!(d.mant.checked_add(d.plus).is_some());
     assert!(d.mant.checked_sub(d.minus).is_some());
-    assert!(buf.len() >= MAX_SIG_DIGITS);
-
-    // `a.cmp(&b) < rounding` is `if d.inclusive {a <= b} else {a < b}`
-    let rounding = if d.inclusive {Ordering::Greater} else {Ordering::Equal};
 
     // estimate `k_0` from original inputs satisfying `10^(k_0-1) < high <= 10^(k_0+1)`.
     // the tight bound `k` satisfying `10^(k-1) < high <= 10^k` is calculated later.
-    let mut k = estimate_scaling_factor(d.mant + d.plus, d.exp);
+    let k = estimate_scaling_factor(d.mant + d.plus, d.exp);
+
+    format_shortest_bignum(Big::from_u128(d.mant), Big::from_u128(d.minus),
+                           Big::from_u128(d.plus), d.exp as i32, d.inclusive, k, buf)
+}
+
+/// The shortest mode implementation for `{mant, minus, plus} * 2^exp` given as bignums,
+/// where `k` is the estimated `k_0` for `high`.
+fn format_shortest_bignum<Big>(mut mant: Big, mut minus: Big, mut plus: Big, exp: i32,
+                               inclusive: bool, mut k: i16,
+                               buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16)
+        where Big: Bignum<Digit=Digit> {
+    // `a.cmp(&b) < rounding` is `if inclusive {a <= b} else {a < b}`
+    let rounding = if inclusive {Ordering::Greater} else {Ordering::Equal};
 
     // convert `{mant, plus, minus} * 2^exp` into the fractional form so that:
     // - `v = mant / scale`
     // - `low = (mant - minus) / scale`
     // - `high = (mant + plus) / scale`
-    let mut mant = Big::from_u64(d.mant);
-    let mut minus = Big::from_u64(d.minus);
-    let mut plus = Big::from_u64(d.plus);
     let mut scale = Big::from_small(1);
-    if d.exp < 0 {
-        scale.mul_pow2(-d.exp as usize);
+    if exp < 0 {
+        scale.mul_pow2(-exp as usize);
     } else {
-        mant.mul_pow2(d.exp as usize);
-        minus.mul_pow2(d.exp as usize);
-        plus.mul_pow2(d.exp as usize);
+        mant.mul_pow2(exp as usize);
+        minus.mul_pow2(exp as usize);
+        plus.mul_pow2(exp as usize);
     }
 
     // divide `mant` by `10^k`. now `scale / 10 < mant + plus <= scale * 10`.
@@ -219,6 +258,16 @@ pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp
 
 /// The exact and fixed mode implementation for Dragon.
 pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usize, /*exp*/ i16) {
+    if fits_in_narrow(d) {
+        format_exact_with::<Big32x36>(d, buf, limit)
+    } else {
+        format_exact_with::<Big32x520>(d, buf, limit)
+    }
+}
+
+fn format_exact_with<Big>(d: &Decoded, buf: &mut [u8],
+                          limit: i16) -> (/*#digits*/ usize, /*exp*/ i16)
+        where Big: Bignum<Digit=Digit> {
     assert!(d.mant > 0);
     assert!(d.minus > 0);
     assert!(d.plus > 0);
@@ -226,15 +275,22 @@ pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usi
     assert!(d.mant.checked_sub(d.minus).is_some());
 
     // estimate `k_0` from original inputs satisfying `10^(k_0-1) < v <= 10^(k_0+1)`.
-    let mut k = estimate_scaling_factor(d.mant, d.exp);
+    let k = estimate_scaling_factor(d.mant, d.exp);
+
+    format_exact_bignum(Big::from_u128(d.mant), d.exp as i32, k, buf, limit)
+}
 
+/// The exact and fixed mode implementation for `mant * 2^exp` given as a bignum,
+/// where `k` is the estimated `k_0` for `v`.
+fn format_exact_bignum<Big>(mut mant: Big, exp: i32, mut k: i16, buf: &mut [u8],
+                            limit: i16) -> (/*#digits*/ usize, /*exp*/ i16)
+        where Big: Bignum<Digit=Digit> {
     // `v = mant / scale`.
-    let mut mant = Big::from_u64(d.mant);
     let mut scale = Big::from_small(1);
-    if d.exp < 0 {
-        scale.mul_pow2(-d.exp as usize);
+    if exp < 0 {
+        scale.mul_pow2(-exp as usize);
     } else {
-        mant.mul_pow2(d.exp as usize);
+        mant.mul_pow2(exp as usize);
     }
 
     // divide `mant` by `10^k`. now `scale / 10 < mant <= scale * 10`.
@@ -319,3 +375,85 @@ pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usi
     (len, k)
 }
 
+/// Returns the number of significant digits the shortest mode can produce for `prec` bits of
+/// precision, i.e. `floor(prec * log_10 2) + 2`. This equals to `MAX_SIG_DIGITS` for 53 bits
+/// and `MAX_SIG_DIGITS_F128` for 113 bits.
+pub fn max_sig_digits(prec: u32) -> usize {
+    // 1292913986 = floor(2^32 * log_10 2)
+    ((prec as u64 * 1292913986) >> 32) as usize + 2
+}
+
+/// Returns the number of bits in the mantissa given as `Digit`s and the number of its
+/// trailing zero bits, after checking that the mantissa is non-zero and fits in `prec` bits.
+fn limbs_bits(mant: &[Digit], prec: u32) -> (/*nbits*/ i64, /*zeros*/ i64) {
+    let digitbits = mem::size_of::<Digit>() as i64 * 8;
+    assert!(mant.iter().any(|&v| v != 0));
+    let top = mant.iter().rposition(|&v| v != 0).unwrap();
+    let bottom = mant.iter().position(|&v| v != 0).unwrap();
+    let nbits = top as i64 * digitbits + digitbits - mant[top].leading_zeros() as i64;
+    let zeros = bottom as i64 * digitbits + mant[bottom].trailing_zeros() as i64;
+    assert!(prec > 0 && nbits - zeros <= prec as i64);
+    (nbits, zeros)
+}
+
+/// The shortest mode implementation for arbitrary-precision binary floating point numbers
+/// (like MPFR's), whose value is `mant * 2^exp` and representable values have `prec` bits
+/// of precision. `mant` is given as `Digit`s with the least significant one first, and should be
+/// non-zero and fit in `prec` bits, but may be unnormalized or padded with zero digits.
+/// The exponent range is assumed to be unbounded, so the neighbors are always `prec` bits long.
+///
+/// The buffer should be at least `max_sig_digits(prec)` bytes long. All computation is done
+/// with `Big` on the stack, which should be able to hold about `prec + abs(log_2 v) + 64` bits;
+/// `Big32x36` suffices for `f64`-like values and `Big32x520` for `binary128`-like values,
+/// and more digits can be given to `define_bignum!` for larger ones. This panics when `Big`
+/// overflows or the decimal exponent doesn't fit in `i16`.
+pub fn format_shortest_limbs<Big>(mant: &[Digit], exp: i32, prec: u32,
+                                  buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16)
+        where Big: Bignum<Digit=Digit> {
+    assert!(buf.len() >= max_sig_digits(prec));
+    let (nbits, zeros) = limbs_bits(mant, prec);
+
+    // the value is `m * 2^ulp` where `m` is exactly `prec` bits long,
+    // so `2^(top-1) <= v < high < 2^top`.
+    let ulp = exp as i64 + nbits - prec as i64;
+    let top = ulp + prec as i64;
+    assert!(i16::MIN as i64 <= top && top <= i16::MAX as i64);
+
+    // neighbors: (m - 1, ulp) -- (m, ulp) -- (m + 1, ulp)
+    // or, when `m` is a power of two: (2m - 1, ulp - 1) -- (m, ulp) -- (m + 1, ulp)
+    // the last bit of `m` is at `ulp - exp` bits of `mant`, which is odd only when
+    // it is also the lowest bit set (as `prec` bits should be enough for `mant`).
+    let pow2 = nbits - zeros == 1;
+    let odd = ulp - exp as i64 == zeros;
+
+    // scale everything to `2^base` so that the half (or quarter) of `2^ulp` is an integer
+    let base = cmp::min(exp as i64, ulp) - 2;
+    assert!(base >= i32::MIN as i64);
+    let mut m = Big::from_digits(mant);
+    m.mul_pow2((exp as i64 - base) as usize);
+    let mut minus = Big::from_small(1);
+    minus.mul_pow2((ulp - base - if pow2 {2} else {1}) as usize);
+    let mut plus = Big::from_small(1);
+    plus.mul_pow2((ulp - base - 1) as usize);
+
+    // `high` is between `2^(top-1)` and `2^top`, so is the estimate for `2^top`.
+    let k = estimate_scaling_factor(1, top as i16);
+
+    format_shortest_bignum(m, minus, plus, base as i32, !odd, k, buf)
+}
+
+/// The exact and fixed mode implementation for arbitrary-precision binary floating point
+/// numbers. The arguments and the bignum `Big` are same to `format_shortest_limbs`,
+/// except that the buffer can be as small as one digit.
+pub fn format_exact_limbs<Big>(mant: &[Digit], exp: i32, prec: u32, buf: &mut [u8],
+                               limit: i16) -> (/*#digits*/ usize, /*exp*/ i16)
+        where Big: Bignum<Digit=Digit> {
+    let (nbits, zeros) = limbs_bits(mant, prec);
+
+    // `2^(top-1) < v <= 2^top`, so the estimate for `2^top` is also that for `v`.
+    let top = exp as i64 + if nbits - zeros == 1 {nbits - 1} else {nbits};
+    assert!(i16::MIN as i64 <= top && top <= i16::MAX as i64);
+    let k = estimate_scaling_factor(1, top as i16);
+
+    format_exact_bignum(Big::from_digits(mant), exp, k, buf, limit)
+}
@@ -0,0 +1,842 @@
+/*!
+Rust adaptation of Errol3 algorithm described in [1], for the shortest mode only.
+It uses about 10KB of precomputed table.
+
+Errol scales `v` and its rounding interval by a power of ten with the double-double
+arithmetic (i.e. a pair of `f64` representing their unevaluated sum, with about 106 bits
+of precision), and generates digits until both bounds of the interval diverge.
+The last digit is chosen from the midpoint of the bounds. Integers below `2^128`
+are separately handled with the 128-bit integer arithmetic, where the double-double
+arithmetic is known to be less accurate.
+
+The original Errol3 also looks up a table of enumerated inputs known to give suboptimal
+results. We omit that table, so the result can be occasionally neither shortest nor closest.
+It is included as a reference point for the benchmarks, and should not be used otherwise.
+
+[1] Marc Andrysco, Ranjit Jhala and Sorin Lerner. 2016. Printing floating-point numbers:
+    a faster, always correct method. SIGPLAN Not. 51, 1 (January 2016), 555-567.
+*/
+
+use core::prelude::*;
+use core::mem;
+
+use flt2dec::{Decoded, MAX_SIG_DIGITS};
+use flt2dec::decoder::DecodableFloat;
+
+/*
+# the following Python code generates this table:
+from fractions import Fraction
+for p in xrange(-290, 309):
+    x = Fraction(10)**p
+    hi = float(x)
+    lo = float(x - Fraction(hi))
+    print '    (%.17e, %.17e), // 10^%d' % (hi, lo, p)
+*/
+// FIXME(#22540) const ref to static array seems to ICE
+#[doc(hidden)]
+pub static POW10: [(f64, f64); 599] = [ // (val, off)
+    (1.00000000000000007e-290, -6.91278685996254792e-307), // 10^-290
+    (1.00000000000000001e-289, -1.21659778218411207e-306), // 10^-289
+    (1.00000000000000006e-288, -5.77354904440686091e-305), // 10^-288
+    (1.00000000000000002e-287, -2.12798803462866176e-304), // 10^-287
+    (1.00000000000000005e-286, -5.04443684245122084e-303), // 10^-286
+    (1.00000000000000007e-285, -7.37759588870926778e-302), // 10^-285
+    (1.00000000000000004e-284, -3.64454141469639268e-301), // 10^-284
+    (9.99999999999999947e-284, 5.31478932293450848e-300), // 10^-283
+    (1.00000000000000002e-282, -1.85267526717021238e-299), // 10^-282
+    (1.00000000000000002e-281, -1.85267526717021227e-298), // 10^-281
+    (9.99999999999999957e-281, 4.26356118305248306e-297), // 10^-280
+    (1.00000000000000006e-279, -5.52241713730382947e-296), // 10^-279
+    (9.99999999999999938e-279, 6.22075684712374584e-295), // 10^-278
+    (9.99999999999999969e-278, 3.08924378460972552e-294), // 10^-277
+    (1.00000000000000009e-276, -9.43680846544635462e-293), // 10^-276
+    (9.99999999999999934e-276, 6.59653841462542783e-292), // 10^-275
+    (9.99999999999999966e-275, 3.38986903861107174e-291), // 10^-274
+    (1.00000000000000009e-273, -9.43680846544635475e-290), // 10^-273
+    (9.99999999999999930e-273, 6.98133873974715047e-289), // 10^-272
+    (9.99999999999999963e-272, 3.69770929870844947e-288), // 10^-271
+    (1.00000000000000004e-270, -4.18300135978443292e-287), // 10^-270
+    (9.99999999999999958e-270, 4.22309000927464163e-286), // 10^-269
+    (9.99999999999999958e-269, 4.22309000927464163e-285), // 10^-268
+    (9.99999999999999985e-268, 1.53314077117573807e-284), // 10^-267
+    (9.99999999999999985e-267, 1.53314077117573789e-283), // 10^-266
+    (9.99999999999999985e-266, 1.53314077117573794e-282), // 10^-265
+    (1.00000000000000001e-264, -1.22136724863753965e-281), // 10^-264
+    (1.00000000000000001e-263, -1.22136724863753949e-280), // 10^-263
+    (1.00000000000000001e-262, -1.22136724863753954e-279), // 10^-262
+    (9.99999999999999984e-262, 1.59924896365125655e-278), // 10^-261
+    (9.99999999999999961e-261, 3.85574193348229365e-277), // 10^-260
+    (1.00000000000000007e-259, -6.97542432170668421e-276), // 10^-259
+    (9.99999999999999954e-259, 4.57781968382822540e-275), // 10^-258
+    (9.99999999999999977e-258, 2.26717088272124367e-274), // 10^-257
+    (9.99999999999999977e-257, 2.26717088272124367e-273), // 10^-256
+    (1.00000000000000001e-255, -6.90459582695693191e-273), // 10^-255
+    (9.99999999999999912e-255, 8.77395790663850484e-271), // 10^-254
+    (1.00000000000000006e-253, -6.36911007629621188e-270), // 10^-253
+    (9.99999999999999943e-253, 5.74534431005156116e-269), // 10^-252
+    (1.00000000000000002e-251, -1.52332832175710266e-268), // 10^-251
+    (1.00000000000000005e-250, -5.39995372538838998e-267), // 10^-250
+    (1.00000000000000005e-249, -5.39995372538839015e-266), // 10^-249
+    (9.99999999999999980e-249, 2.04316704958368174e-265), // 10^-248
+    (1.00000000000000002e-247, -1.92649736373475642e-264), // 10^-247
+    (9.99999999999999956e-247, 4.42496569757474468e-263), // 10^-246
+    (9.99999999999999930e-246, 6.96555092209854475e-262), // 10^-245
+    (9.99999999999999930e-245, 6.96555092209854497e-261), // 10^-244
+    (9.99999999999999995e-244, 4.61652747317615984e-261), // 10^-243
+    (9.99999999999999969e-243, 3.06321201722998756e-259), // 10^-242
+    (9.99999999999999969e-242, 3.06321201722998756e-258), // 10^-241
+    (9.99999999999999969e-241, 3.06321201722998784e-257), // 10^-240
+    (1.00000000000000008e-239, -7.59277475233108644e-256), // 10^-239
+    (9.99999999999999991e-239, 9.32014663317772806e-256), // 10^-238
+    (9.99999999999999991e-238, 9.32014663317772830e-255), // 10^-237
+    (1.00000000000000005e-236, -4.52385056269749766e-253), // 10^-236
+    (9.99999999999999958e-236, 4.20553379892693470e-252), // 10^-235
+    (9.99999999999999958e-235, 4.20553379892693489e-251), // 10^-234
+    (9.99999999999999958e-234, 4.20553379892693489e-250), // 10^-233
+    (1.00000000000000002e-232, -2.49863339080062894e-249), // 10^-232
+    (9.99999999999999989e-232, 1.07692244372073830e-248), // 10^-231
+    (1.00000000000000005e-230, -4.64396689151344976e-247), // 10^-230
+    (1.00000000000000007e-229, -6.93232262560712467e-246), // 10^-229
+    (1.00000000000000003e-228, -3.27095345105724461e-245), // 10^-228
+    (9.99999999999999945e-228, 5.51633256786246842e-244), // 10^-227
+    (9.99999999999999921e-227, 7.85960883957439101e-243), // 10^-226
+    (9.99999999999999959e-226, 4.11036680483531404e-242), // 10^-225
+    (1.00000000000000002e-224, -1.88842045074720978e-241), // 10^-224
+    (9.99999999999999971e-224, 2.91060935371880914e-240), // 10^-223
+    (1.00000000000000005e-222, -4.76783833342682124e-239), // 10^-222
+    (1.00000000000000002e-221, -1.69645925856856905e-238), // 10^-221
+    (9.99999999999999992e-221, 7.60644001318032844e-238), // 10^-220
+    (1.00000000000000003e-219, -3.17072121450052986e-236), // 10^-219
+    (1.00000000000000003e-218, -3.17072121450053012e-235), // 10^-218
+    (1.00000000000000008e-217, -8.20286869074829024e-234), // 10^-217
+    (1.00000000000000004e-216, -4.17715070975008237e-233), // 10^-216
+    (1.00000000000000004e-215, -4.17715070975008183e-232), // 10^-215
+    (9.99999999999999913e-215, 8.70514682944418493e-231), // 10^-214
+    (9.99999999999999954e-214, 4.58281161690201916e-230), // 10^-213
+    (9.99999999999999954e-213, 4.58281161690201897e-229), // 10^-212
+    (1.00000000000000009e-211, -8.60866106323290990e-228), // 10^-211
+    (1.00000000000000004e-210, -4.38738980558973261e-227), // 10^-210
+    (1.00000000000000004e-209, -4.38738980558973261e-226), // 10^-209
+    (1.00000000000000010e-208, -9.79061701537299909e-225), // 10^-208
+    (9.99999999999999925e-208, 7.49971005593345507e-224), // 10^-207
+    (1.00000000000000003e-206, -2.87448618685041781e-223), // 10^-206
+    (1.00000000000000000e-205, -1.08033855441385084e-223), // 10^-205
+    (1.00000000000000000e-204, -1.08033855441385096e-222), // 10^-204
+    (1.00000000000000004e-203, -3.64909283964494707e-220), // 10^-203
+    (1.00000000000000004e-202, -3.64909283964494707e-219), // 10^-202
+    (9.99999999999999946e-202, 5.41601815991617117e-218), // 10^-201
+    (9.99999999999999982e-201, 1.78997376009172408e-217), // 10^-200
+    (9.99999999999999982e-200, 1.78997376009172420e-216), // 10^-199
+    (9.99999999999999912e-199, 8.75197900775466242e-215), // 10^-198
+    (9.99999999999999987e-198, 1.32584007691419478e-214), // 10^-197
+    (1.00000000000000005e-196, -4.61507106775817984e-213), // 10^-196
+    (1.00000000000000009e-195, -9.36779998349607913e-212), // 10^-195
+    (1.00000000000000002e-194, -1.76343371831543984e-211), // 10^-194
+    (1.00000000000000005e-193, -4.80518022438769564e-210), // 10^-193
+    (1.00000000000000010e-192, -9.67197463410330506e-209), // 10^-192
+    (1.00000000000000002e-191, -1.88510357855833020e-208), // 10^-191
+    (1.00000000000000002e-190, -1.88510357855833012e-207), // 10^-190
+    (1.00000000000000007e-189, -6.86870105410711402e-206), // 10^-189
+    (9.99999999999999949e-189, 5.09193288720996702e-205), // 10^-188
+    (1.00000000000000001e-187, -1.28707188149247603e-204), // 10^-187
+    (9.99999999999999911e-187, 8.91933574843143348e-203), // 10^-186
+    (9.99999999999999992e-186, 7.54209644492305705e-203), // 10^-185
+    (1.00000000000000006e-184, -5.77789123865899602e-201), // 10^-184
+    (1.00000000000000001e-183, -5.52210532137954676e-201), // 10^-183
+    (1.00000000000000005e-182, -4.73275509735478787e-199), // 10^-182
+    (1.00000000000000005e-181, -4.73275509735478805e-198), // 10^-181
+    (1.00000000000000002e-180, -2.05720657561601466e-197), // 10^-180
+    (1.00000000000000002e-179, -2.05720657561601466e-196), // 10^-179
+    (9.99999999999999952e-179, 4.79219764003524474e-195), // 10^-178
+    (9.99999999999999952e-178, 4.79219764003524489e-194), // 10^-177
+    (9.99999999999999996e-177, 4.08578942018438815e-194), // 10^-176
+    (9.99999999999999996e-176, 4.08578942018438815e-193), // 10^-175
+    (9.99999999999999996e-175, 4.08578942018438795e-192), // 10^-174
+    (1.00000000000000004e-173, -4.08024660475077068e-190), // 10^-173
+    (1.00000000000000004e-172, -4.08024660475077058e-189), // 10^-172
+    (9.99999999999999983e-172, 1.66545009511381749e-188), // 10^-171
+    (9.99999999999999983e-171, 1.66545009511381742e-187), // 10^-170
+    (1.00000000000000002e-169, -2.01179579279951889e-186), // 10^-169
+    (1.00000000000000005e-168, -4.95359250313018814e-185), // 10^-168
+    (1.00000000000000000e-167, -2.46717766601117433e-185), // 10^-167
+    (1.00000000000000004e-166, -4.01221755582437364e-183), // 10^-166
+    (1.00000000000000001e-165, -9.99817724445768659e-183), // 10^-165
+    (9.99999999999999962e-165, 3.82002200575999954e-181), // 10^-164
+    (9.99999999999999923e-164, 7.67589378992461370e-180), // 10^-163
+    (9.99999999999999954e-163, 4.59119636259292220e-179), // 10^-162
+    (1.00000000000000003e-161, -2.81207746300313739e-178), // 10^-161
+    (9.99999999999999989e-161, 1.13633524398142772e-177), // 10^-160
+    (9.99999999999999989e-160, 1.13633524398142768e-176), // 10^-159
+    (1.00000000000000006e-158, -6.44461715342893749e-175), // 10^-158
+    (9.99999999999999943e-158, 5.68490668242764678e-174), // 10^-157
+    (1.00000000000000004e-156, -4.01871238625762099e-173), // 10^-156
+    (1.00000000000000001e-155, -1.43108063460821597e-172), // 10^-155
+    (9.99999999999999973e-155, 2.70913016803083150e-171), // 10^-154
+    (1.00000000000000004e-153, -3.91520711619164454e-170), // 10^-153
+    (1.00000000000000007e-152, -6.56494202988063499e-169), // 10^-152
+    (9.99999999999999938e-152, 6.15378555582651942e-168), // 10^-151
+    (1.00000000000000001e-150, -6.29535823217296424e-168), // 10^-150
+    (9.99999999999999979e-150, 2.08379272840022986e-166), // 10^-149
+    (9.99999999999999936e-149, 6.42511841098827176e-165), // 10^-148
+    (9.99999999999999970e-148, 2.95205786491783838e-164), // 10^-147
+    (1.00000000000000003e-146, -2.60483900879485548e-163), // 10^-146
+    (9.99999999999999915e-146, 8.50895473863053144e-162), // 10^-145
+    (9.99999999999999950e-145, 4.95254073945440767e-161), // 10^-144
+    (9.99999999999999950e-144, 4.95254073945440782e-160), // 10^-143
+    (1.00000000000000004e-142, -4.15187909843646929e-159), // 10^-142
+    (1.00000000000000004e-141, -4.15187909843646909e-158), // 10^-141
+    (9.99999999999999983e-141, 1.67494959781369210e-157), // 10^-140
+    (1.00000000000000003e-139, -2.98651335918643731e-156), // 10^-139
+    (1.00000000000000007e-138, -6.71568372478654016e-155), // 10^-138
+    (9.99999999999999978e-138, 2.23432515265370777e-154), // 10^-137
+    (1.00000000000000000e-136, -1.52343881330358558e-154), // 10^-136
+    (1.00000000000000004e-135, -3.97101433570486475e-152), // 10^-135
+    (1.00000000000000004e-134, -3.97101433570486458e-151), // 10^-134
+    (1.00000000000000006e-133, -6.41496342650454805e-150), // 10^-133
+    (9.99999999999999986e-133, 1.40567366405443993e-149), // 10^-132
+    (9.99999999999999986e-132, 1.40567366405443989e-148), // 10^-131
+    (1.00000000000000009e-130, -8.60474181186106438e-147), // 10^-130
+    (9.99999999999999926e-130, 7.41192294960374301e-146), // 10^-129
+    (1.00000000000000005e-128, -5.40140885956810326e-145), // 10^-128
+    (1.00000000000000003e-127, -2.83874249773373394e-144), // 10^-127
+    (9.99999999999999946e-127, 5.36178986013624700e-143), // 10^-126
+    (1.00000000000000001e-125, -1.19863602615973793e-142), // 10^-125
+    (9.99999999999999933e-125, 6.67387503739544380e-141), // 10^-124
+    (1.00000000000000006e-123, -5.92214266429284691e-140), // 10^-123
+    (1.00000000000000006e-122, -5.92214266429284747e-139), // 10^-122
+    (9.99999999999999979e-122, 2.13930866478765933e-138), // 10^-121
+    (9.99999999999999979e-121, 2.13930866478765945e-137), // 10^-120
+    (1.00000000000000001e-119, -1.30024390228669004e-136), // 10^-119
+    (9.99999999999999985e-119, 1.45139815137278951e-135), // 10^-118
+    (1.00000000000000003e-117, -2.95122913448237777e-134), // 10^-117
+    (9.99999999999999994e-117, 5.70872694201756056e-134), // 10^-116
+    (1.00000000000000005e-115, -5.06449023169285817e-132), // 10^-115
+    (1.00000000000000005e-114, -5.06449023169285842e-131), // 10^-114
+    (9.99999999999999979e-114, 2.14877431345224786e-130), // 10^-113
+    (9.99999999999999950e-113, 5.03408013151029021e-129), // 10^-112
+    (1.00000000000000009e-111, -8.81538779516831371e-128), // 10^-111
+    (1.00000000000000005e-110, -5.12219634805401858e-127), // 10^-110
+    (9.99999999999999992e-110, 7.86909967328851991e-127), // 10^-109
+    (1.00000000000000004e-108, -3.94037508497744479e-125), // 10^-108
+    (1.00000000000000000e-107, -1.58547043132407392e-125), // 10^-107
+    (9.99999999999999941e-107, 5.89237782381965219e-123), // 10^-106
+    (9.99999999999999965e-106, 3.47200787703882841e-122), // 10^-105
+    (9.99999999999999927e-105, 7.34459979188814700e-121), // 10^-104
+    (9.99999999999999958e-104, 4.24652626000869221e-120), // 10^-103
+    (9.99999999999999933e-103, 6.72498508551225632e-119), // 10^-102
+    (1.00000000000000005e-101, -5.17161727690484963e-118), // 10^-101
+    (1.00000000000000002e-100, -1.99918998026028828e-117), // 10^-100
+    (1.00000000000000002e-99, -1.99918998026028828e-116), // 10^-99
+    (9.99999999999999939e-99, 6.12222389914978884e-115), // 10^-98
+    (1.00000000000000004e-97, -3.62347275614230400e-114), // 10^-97
+    (9.99999999999999906e-97, 9.37078945091381974e-113), // 10^-96
+    (9.99999999999999989e-96, 1.05446163839790082e-112), // 10^-95
+    (9.99999999999999956e-95, 4.38099276340426890e-111), // 10^-94
+    (9.99999999999999903e-94, 9.70344256341445730e-110), // 10^-93
+    (9.99999999999999988e-93, 1.18752288339815538e-109), // 10^-92
+    (1.00000000000000002e-91, -2.21884498860836524e-108), // 10^-91
+    (9.99999999999999995e-91, 5.06249308996851372e-108), // 10^-90
+    (1.00000000000000004e-89, -3.85390156717149493e-106), // 10^-89
+    (9.99999999999999934e-89, 6.61046053563253657e-105), // 10^-88
+    (1.00000000000000002e-87, -1.76102914661068887e-104), // 10^-87
+    (1.00000000000000008e-86, -8.45822089240526835e-103), // 10^-86
+    (9.99999999999999977e-86, 2.25728590086605922e-102), // 10^-85
+    (1.00000000000000003e-84, -3.45765105554531593e-101), // 10^-84
+    (1.00000000000000003e-83, -3.45765105554531568e-100), // 10^-83
+    (9.99999999999999961e-83, 3.85746824866124441e-99), // 10^-82
+    (9.99999999999999961e-82, 3.85746824866124425e-98), // 10^-81
+    (9.99999999999999961e-81, 3.85746824866124399e-97), // 10^-80
+    (9.99999999999999999e-80, 1.12127164907485586e-97), // 10^-79
+    (9.99999999999999999e-79, 1.12127164907485576e-96), // 10^-78
+    (9.99999999999999927e-78, 7.30318204571470170e-94), // 10^-77
+    (9.99999999999999927e-77, 7.30318204571470234e-93), // 10^-76
+    (9.99999999999999958e-76, 4.23499862990362341e-92), // 10^-75
+    (9.99999999999999958e-75, 4.23499862990362314e-91), // 10^-74
+    (9.99999999999999997e-74, 3.07723857665441897e-91), // 10^-73
+    (9.99999999999999966e-73, 3.44954367545598656e-89), // 10^-72
+    (9.99999999999999915e-72, 8.47645538392085911e-88), // 10^-71
+    (9.99999999999999996e-71, 4.33396650377063649e-88), // 10^-70
+    (9.99999999999999963e-70, 3.65062014379458191e-86), // 10^-69
+    (1.00000000000000007e-68, -6.64449503514147592e-85), // 10^-68
+    (9.99999999999999943e-68, 5.70964317958179325e-84), // 10^-67
+    (9.99999999999999976e-67, 2.41520632232225493e-83), // 10^-66
+    (9.99999999999999923e-66, 7.68630529393751632e-82), // 10^-65
+    (9.99999999999999965e-65, 3.46942611664530703e-81), // 10^-64
+    (1.00000000000000007e-63, -6.65108390885599517e-80), // 10^-63
+    (1.00000000000000004e-62, -3.95228123538898138e-79), // 10^-62
+    (1.00000000000000004e-61, -3.95228123538898138e-78), // 10^-61
+    (9.99999999999999970e-61, 2.95665360868657432e-77), // 10^-60
+    (1.00000000000000003e-59, -2.57049426657386993e-76), // 10^-59
+    (1.00000000000000003e-58, -2.57049426657386999e-75), // 10^-58
+    (9.99999999999999955e-58, 4.50425501375949885e-74), // 10^-57
+    (1.00000000000000004e-56, -3.98544412264054368e-73), // 10^-56
+    (9.99999999999999995e-56, 5.42395416772812315e-73), // 10^-55
+    (1.00000000000000003e-54, -3.07987621475787282e-71), // 10^-54
+    (1.00000000000000003e-53, -3.07987621475787234e-70), // 10^-53
+    (1.00000000000000001e-52, -7.61622370578234229e-70), // 10^-52
+    (1.00000000000000001e-51, -7.61622370578234332e-69), // 10^-51
+    (1.00000000000000001e-50, -7.61622370578234229e-68), // 10^-50
+    (9.99999999999999936e-50, 6.36005343874161463e-66), // 10^-49
+    (9.99999999999999974e-49, 2.56182634043769535e-65), // 10^-48
+    (9.99999999999999974e-48, 2.56182634043769526e-64), // 10^-47
+    (1.00000000000000002e-46, -2.29990434539132177e-63), // 10^-46
+    (9.99999999999999984e-46, 1.58948020327189196e-62), // 10^-45
+    (9.99999999999999953e-45, 4.70098784220246282e-61), // 10^-44
+    (1.00000000000000008e-43, -7.74504271351982115e-60), // 10^-43
+    (1.00000000000000004e-42, -3.76231293568868979e-59), // 10^-42
+    (1.00000000000000001e-41, -5.76129113423785417e-59), // 10^-41
+    (9.99999999999999929e-41, 7.07071206001198558e-57), // 10^-40
+    (9.99999999999999929e-40, 7.07071206001198513e-56), // 10^-39
+    (9.99999999999999962e-39, 3.80805982601272359e-55), // 10^-38
+    (1.00000000000000007e-37, -6.63242732278491580e-54), // 10^-37
+    (9.99999999999999941e-37, 5.89615725577225153e-53), // 10^-36
+    (1.00000000000000001e-35, -7.85754519458238051e-53), // 10^-35
+    (9.99999999999999928e-35, 7.23253961081834850e-51), // 10^-34
+    (1.00000000000000006e-33, -5.59673099762419060e-50), // 10^-33
+    (1.00000000000000006e-32, -5.59673099762419022e-49), // 10^-32
+    (1.00000000000000008e-31, -8.33364206075859796e-48), // 10^-31
+    (1.00000000000000008e-30, -8.33364206075859893e-47), // 10^-30
+    (9.99999999999999943e-30, 5.67934258248957217e-46), // 10^-29
+    (9.99999999999999971e-29, 2.87674565383993787e-45), // 10^-28
+    (1.00000000000000004e-27, -3.84948697491918407e-44), // 10^-27
+    (1.00000000000000004e-26, -3.84948697491918417e-43), // 10^-26
+    (1.00000000000000004e-25, -3.84948697491918369e-42), // 10^-25
+    (9.99999999999999924e-25, 7.62995004482971775e-41), // 10^-24
+    (9.99999999999999960e-24, 3.95653019851006929e-40), // 10^-23
+    (1.00000000000000005e-22, -4.85967743265708718e-39), // 10^-22
+    (9.99999999999999908e-22, 9.24625477721036252e-38), // 10^-21
+    (9.99999999999999945e-21, 5.48467285457904291e-37), // 10^-20
+    (9.99999999999999975e-20, 2.47540731647398689e-36), // 10^-19
+    (1.00000000000000007e-18, -7.15424240546219257e-35), // 10^-18
+    (1.00000000000000007e-17, -7.15424240546219214e-34), // 10^-17
+    (9.99999999999999979e-17, 2.09022132759653978e-33), // 10^-16
+    (1.00000000000000008e-15, -7.77053998766610758e-32), // 10^-15
+    (9.99999999999999999e-15, 1.18069064544010129e-32), // 10^-14
+    (1.00000000000000003e-13, -3.03737455634003710e-30), // 10^-13
+    (9.99999999999999980e-13, 2.01133523707443852e-29), // 10^-12
+    (9.99999999999999939e-12, 6.05030307180601908e-28), // 10^-11
+    (1.00000000000000004e-10, -3.64321973154977434e-27), // 10^-10
+    (1.00000000000000006e-09, -6.22815914577798525e-26), // 10^-9
+    (1.00000000000000002e-08, -2.09225608301284711e-25), // 10^-8
+    (9.99999999999999955e-08, 4.52518881741137392e-24), // 10^-7
+    (9.99999999999999955e-07, 4.52518881741137407e-23), // 10^-6
+    (1.00000000000000008e-05, -8.18030539140313055e-22), // 10^-5
+    (1.00000000000000005e-04, -4.79217360238592994e-21), // 10^-4
+    (1.00000000000000002e-03, -2.08166817117216856e-20), // 10^-3
+    (1.00000000000000002e-02, -2.08166817117216844e-19), // 10^-2
+    (1.00000000000000006e-01, -5.55111512312578301e-18), // 10^-1
+    (1.00000000000000000e+00, 0.00000000000000000e+00), // 10^0
+    (1.00000000000000000e+01, 0.00000000000000000e+00), // 10^1
+    (1.00000000000000000e+02, 0.00000000000000000e+00), // 10^2
+    (1.00000000000000000e+03, 0.00000000000000000e+00), // 10^3
+    (1.00000000000000000e+04, 0.00000000000000000e+00), // 10^4
+    (1.00000000000000000e+05, 0.00000000000000000e+00), // 10^5
+    (1.00000000000000000e+06, 0.00000000000000000e+00), // 10^6
+    (1.00000000000000000e+07, 0.00000000000000000e+00), // 10^7
+    (1.00000000000000000e+08, 0.00000000000000000e+00), // 10^8
+    (1.00000000000000000e+09, 0.00000000000000000e+00), // 10^9
+    (1.00000000000000000e+10, 0.00000000000000000e+00), // 10^10
+    (1.00000000000000000e+11, 0.00000000000000000e+00), // 10^11
+    (1.00000000000000000e+12, 0.00000000000000000e+00), // 10^12
+    (1.00000000000000000e+13, 0.00000000000000000e+00), // 10^13
+    (1.00000000000000000e+14, 0.00000000000000000e+00), // 10^14
+    (1.00000000000000000e+15, 0.00000000000000000e+00), // 10^15
+    (1.00000000000000000e+16, 0.00000000000000000e+00), // 10^16
+    (1.00000000000000000e+17, 0.00000000000000000e+00), // 10^17
+    (1.00000000000000000e+18, 0.00000000000000000e+00), // 10^18
+    (1.00000000000000000e+19, 0.00000000000000000e+00), // 10^19
+    (1.00000000000000000e+20, 0.00000000000000000e+00), // 10^20
+    (1.00000000000000000e+21, 0.00000000000000000e+00), // 10^21
+    (1.00000000000000000e+22, 0.00000000000000000e+00), // 10^22
+    (9.99999999999999916e+22, 8.38860800000000000e+06), // 10^23
+    (9.99999999999999983e+23, 1.67772160000000000e+07), // 10^24
+    (1.00000000000000009e+25, -9.05969664000000000e+08), // 10^25
+    (1.00000000000000005e+26, -4.76472934400000000e+09), // 10^26
+    (1.00000000000000001e+27, -1.32875550720000000e+10), // 10^27
+    (9.99999999999999958e+27, 4.16880263168000000e+11), // 10^28
+    (9.99999999999999914e+28, 8.56684914278400000e+12), // 10^29
+    (1.00000000000000002e+30, -1.98846248386560000e+13), // 10^30
+    (9.99999999999999964e+30, 3.64103705034752000e+14), // 10^31
+    (1.00000000000000005e+32, -5.36616220439347200e+15), // 10^32
+    (9.99999999999999946e+32, 5.44247690129571840e+16), // 10^33
+    (9.99999999999999946e+33, 5.44247690129571840e+17), // 10^34
+    (9.99999999999999969e+34, 3.13663389208202445e+18), // 10^35
+    (1.00000000000000004e+36, -4.24206373740179620e+19), // 10^36
+    (9.99999999999999954e+36, 4.61237341797878858e+20), // 10^37
+    (9.99999999999999977e+37, 2.25119017654396597e+21), // 10^38
+    (9.99999999999999940e+38, 6.02908336283968214e+22), // 10^39
+    (1.00000000000000003e+40, -3.03786028427003667e+23), // 10^40
+    (1.00000000000000001e+41, -6.20008645040778319e+23), // 10^41
+    (1.00000000000000004e+42, -4.48857126780759168e+25), // 10^42
+    (1.00000000000000001e+43, -1.39372116959414099e+26), // 10^43
+    (1.00000000000000009e+44, -8.82136140530642264e+27), // 10^44
+    (9.99999999999999930e+44, 7.02427109754644488e+28), // 10^45
+    (9.99999999999999993e+45, 6.86018096405297871e+28), // 10^46
+    (1.00000000000000004e+47, -4.38458430450761988e+30), // 10^47
+    (1.00000000000000004e+48, -4.38458430450761976e+31), // 10^48
+    (9.99999999999999946e+48, 5.35097230524518240e+32), // 10^49
+    (1.00000000000000008e+50, -7.62976984109188739e+33), // 10^50
+    (9.99999999999999993e+50, 6.77905132563837160e+33), // 10^51
+    (9.99999999999999993e+51, 6.77905132563837229e+34), // 10^52
+    (9.99999999999999993e+52, 6.77905132563837266e+35), // 10^53
+    (1.00000000000000008e+54, -7.82915404045962462e+37), // 10^54
+    (1.00000000000000001e+55, -1.02350670204085516e+38), // 10^55
+    (1.00000000000000009e+56, -9.19028350814337858e+39), // 10^56
+    (1.00000000000000005e+57, -4.83466921155536625e+40), // 10^57
+    (9.99999999999999944e+57, 5.61880510025586393e+41), // 10^58
+    (9.99999999999999972e+58, 2.83121195043953603e+42), // 10^59
+    (9.99999999999999949e+59, 5.06128647029259847e+43), // 10^60
+    (9.99999999999999949e+60, 5.06128647029259827e+44), // 10^61
+    (1.00000000000000004e+62, -3.50219968594316133e+45), // 10^62
+    (1.00000000000000006e+63, -5.78579599427269727e+46), // 10^63
+    (1.00000000000000002e+64, -2.13204190094543956e+47), // 10^64
+    (9.99999999999999992e+64, 7.90961373716366191e+47), // 10^65
+    (9.99999999999999945e+65, 5.46776661317525511e+49), // 10^66
+    (9.99999999999999983e+66, 1.72632242160814405e+50), // 10^67
+    (9.99999999999999953e+67, 4.71947777486183290e+51), // 10^68
+    (1.00000000000000007e+69, -7.25314363815292314e+52), // 10^69
+    (1.00000000000000007e+70, -7.25314363815292314e+53), // 10^70
+    (1.00000000000000004e+71, -4.18815255642114560e+54), // 10^71
+    (9.99999999999999944e+71, 5.61981890512054296e+55), // 10^72
+    (9.99999999999999983e+72, 1.69663032050386748e+56), // 10^73
+    (9.99999999999999952e+73, 4.83518118819720752e+57), // 10^74
+    (9.99999999999999927e+74, 7.34602188235188052e+58), // 10^75
+    (1.00000000000000005e+76, -4.70601344959054722e+59), // 10^76
+    (9.99999999999999983e+76, 1.72173872744541406e+60), // 10^77
+    (1.00000000000000001e+78, -8.49362143368970307e+60), // 10^78
+    (9.99999999999999967e+78, 3.26439924993404463e+62), // 10^79
+    (1.00000000000000000e+80, -2.66098647083672745e+61), // 10^80
+    (9.99999999999999921e+80, 7.87181201043342123e+64), // 10^81
+    (9.99999999999999963e+81, 3.65932034369113447e+65), // 10^82
+    (1.00000000000000003e+83, -3.08066632309652576e+66), // 10^83
+    (1.00000000000000006e+84, -5.77666098981158944e+67), // 10^84
+    (1.00000000000000001e+85, -1.46306952306748731e+68), // 10^85
+    (1.00000000000000001e+86, -1.46306952306748727e+69), // 10^86
+    (9.99999999999999959e+86, 4.05832755436496392e+70), // 10^87
+    (9.99999999999999959e+87, 4.05832755436496367e+71), // 10^88
+    (9.99999999999999995e+88, 5.24633424808195111e+71), // 10^89
+    (9.99999999999999966e+89, 3.35158872845360988e+73), // 10^90
+    (1.00000000000000008e+91, -7.95623248612804970e+74), // 10^91
+    (1.00000000000000004e+92, -4.33772969746191900e+75), // 10^92
+    (1.00000000000000004e+93, -4.33772969746191868e+76), // 10^93
+    (1.00000000000000002e+94, -2.02188791271559464e+77), // 10^94
+    (1.00000000000000002e+95, -2.02188791271559474e+78), // 10^95
+    (1.00000000000000005e+96, -4.98616539719088951e+79), // 10^96
+    (1.00000000000000007e+97, -7.35758738477112453e+80), // 10^97
+    (9.99999999999999998e+97, 2.30962975485629203e+80), // 10^98
+    (9.99999999999999967e+98, 3.26638311958833116e+82), // 10^99
+    (1.00000000000000002e+100, -1.59028911097599179e+83), // 10^100
+    (9.99999999999999977e+100, 2.29504867347546613e+84), // 10^101
+    (9.99999999999999977e+101, 2.29504867347546622e+85), // 10^102
+    (1.00000000000000000e+103, -1.91567508573466886e+85), // 10^103
+    (1.00000000000000000e+104, -1.91567508573466866e+86), // 10^104
+    (9.99999999999999938e+104, 6.17416991747180233e+88), // 10^105
+    (1.00000000000000009e+106, -9.10359990503684360e+89), // 10^106
+    (9.99999999999999969e+106, 3.11861595297007291e+90), // 10^107
+    (1.00000000000000003e+108, -3.39989917130028274e+91), // 10^108
+    (9.99999999999999982e+108, 1.81491292811600193e+92), // 10^109
+    (1.00000000000000002e+110, -2.35693675141702558e+93), // 10^110
+    (9.99999999999999957e+110, 4.31802273583581824e+94), // 10^111
+    (9.99999999999999930e+111, 6.98800653073695585e+95), // 10^112
+    (1.00000000000000002e+113, -1.55594161294668425e+96), // 10^113
+    (1.00000000000000002e+114, -1.55594161294668429e+97), // 10^114
+    (1.00000000000000002e+115, -1.55594161294668433e+98), // 10^115
+    (1.00000000000000002e+116, -1.55594161294668433e+99), // 10^116
+    (1.00000000000000005e+117, -5.05554277259950356e+100), // 10^117
+    (9.99999999999999967e+117, 3.34350001056726223e+101), // 10^118
+    (9.99999999999999944e+118, 5.58324475274506669e+102), // 10^119
+    (9.99999999999999980e+119, 1.99965316526057976e+103), // 10^120
+    (1.00000000000000004e+121, -3.73409337471459878e+104), // 10^121
+    (1.00000000000000001e+122, -1.44059475872452740e+105), // 10^122
+    (9.99999999999999978e+122, 2.22900302685958712e+106), // 10^123
+    (9.99999999999999948e+123, 5.16468125532687849e+107), // 10^124
+    (9.99999999999999925e+124, 7.51322383810071211e+108), // 10^125
+    (9.99999999999999925e+125, 7.51322383810071170e+109), // 10^126
+    (9.99999999999999955e+126, 4.50708933215020550e+110), // 10^127
+    (1.00000000000000008e+128, -7.51744869165182036e+111), // 10^128
+    (9.99999999999999998e+128, 1.78255643581475852e+111), // 10^129
+    (1.00000000000000006e+130, -5.97830782460516127e+113), // 10^130
+    (9.99999999999999912e+130, 8.79744449904276788e+114), // 10^131
+    (9.99999999999999991e+131, 9.17043259763872369e+114), // 10^132
+    (1.00000000000000002e+133, -2.23511723594768608e+116), // 10^133
+    (9.99999999999999921e+133, 7.85179635032930095e+117), // 10^134
+    (9.99999999999999962e+134, 3.81703091581850606e+118), // 10^135
+    (1.00000000000000006e+136, -5.86640612700740107e+119), // 10^136
+    (1.00000000000000003e+137, -3.28415624892049248e+120), // 10^137
+    (1.00000000000000003e+138, -3.28415624892049271e+121), // 10^138
+    (1.00000000000000003e+139, -3.28415624892049252e+122), // 10^139
+    (1.00000000000000006e+140, -5.92838012408148721e+123), // 10^140
+    (1.00000000000000002e+141, -1.69762192382389594e+124), // 10^141
+    (1.00000000000000005e+142, -5.08222848402996910e+125), // 10^142
+    (1.00000000000000002e+143, -2.37454323586511054e+126), // 10^143
+    (1.00000000000000002e+144, -2.37454323586511060e+127), // 10^144
+    (9.99999999999999989e+144, 1.09129388178590798e+128), // 10^145
+    (9.99999999999999934e+145, 6.63663327002753727e+129), // 10^146
+    (9.99999999999999978e+146, 2.20036175943423399e+130), // 10^147
+    (1.00000000000000005e+148, -4.89767265751505220e+131), // 10^148
+    (1.00000000000000005e+149, -4.89767265751505204e+132), // 10^149
+    (9.99999999999999981e+149, 1.91644038275626243e+133), // 10^150
+    (1.00000000000000002e+151, -1.71775323872177192e+134), // 10^151
+    (1.00000000000000005e+152, -4.62510813590419952e+135), // 10^152
+    (1.00000000000000000e+153, 2.66596995876846262e+134), // 10^153
+    (1.00000000000000004e+154, -3.69475456880582265e+137), // 10^154
+    (1.00000000000000001e+155, -7.17623154091016826e+137), // 10^155
+    (9.99999999999999983e+155, 1.66408197768082775e+139), // 10^156
+    (9.99999999999999983e+156, 1.66408197768082786e+140), // 10^157
+    (9.99999999999999953e+157, 4.71266454634878877e+141), // 10^158
+    (9.99999999999999928e+158, 7.15153060128315756e+142), // 10^159
+    (1.00000000000000001e+160, -6.52840774506822693e+142), // 10^160
+    (1.00000000000000004e+161, -3.77458932482281490e+144), // 10^161
+    (9.99999999999999938e+161, 6.21500603618836010e+145), // 10^162
+    (9.99999999999999938e+162, 6.21500603618836010e+146), // 10^163
+    (1.00000000000000000e+164, -1.78334994858791835e+146), // 10^164
+    (9.99999999999999899e+164, 1.00510106548166510e+149), // 10^165
+    (9.99999999999999940e+165, 5.95927239494647460e+149), // 10^166
+    (1.00000000000000004e+167, -3.86089942874195119e+150), // 10^167
+    (9.99999999999999934e+167, 6.61395051652570265e+151), // 10^168
+    (9.99999999999999934e+168, 6.61395051652570288e+152), // 10^169
+    (1.00000000000000003e+170, -3.44190543093124494e+153), // 10^170
+    (9.99999999999999954e+170, 4.60277932703431317e+154), // 10^171
+    (1.00000000000000008e+172, -8.26871628571058052e+155), // 10^172
+    (1.00000000000000001e+173, -1.40391862557997062e+156), // 10^173
+    (1.00000000000000007e+174, -6.89575675368445839e+157), // 10^174
+    (9.99999999999999937e+174, 6.28465475376631275e+158), // 10^175
+    (1.00000000000000001e+176, -7.44898050207431986e+158), // 10^176
+    (1.00000000000000001e+177, -7.44898050207432064e+159), // 10^177
+    (1.00000000000000005e+178, -5.24381184475062820e+161), // 10^178
+    (9.99999999999999980e+178, 1.95445022651848602e+162), // 10^179
+    (1.00000000000000001e+180, -9.24854601989159829e+162), // 10^180
+    (9.99999999999999917e+180, 8.28892084923530659e+164), // 10^181
+    (1.00000000000000006e+182, -6.45311987272383932e+165), // 10^182
+    (9.99999999999999947e+182, 5.34051270484347724e+166), // 10^183
+    (1.00000000000000002e+184, -1.73566684169691292e+167), // 10^184
+    (9.99999999999999980e+184, 2.03829558312462853e+168), // 10^185
+    (9.99999999999999980e+185, 2.03829558312462836e+169), // 10^186
+    (9.99999999999999907e+186, 9.28430343878198823e+170), // 10^187
+    (1.00000000000000002e+188, -2.30930913026978702e+171), // 10^188
+    (1.00000000000000002e+189, -2.30930913026978710e+172), // 10^189
+    (1.00000000000000007e+190, -7.25591715973187799e+173), // 10^190
+    (1.00000000000000007e+191, -7.25591715973187755e+174), // 10^191
+    (1.00000000000000004e+192, -4.09008802087613969e+175), // 10^192
+    (1.00000000000000007e+193, -6.62275133196073068e+176), // 10^193
+    (9.99999999999999945e+193, 5.53403256124530382e+177), // 10^194
+    (9.99999999999999977e+194, 2.29222352305702808e+178), // 10^195
+    (9.99999999999999951e+195, 4.88567075360764896e+179), // 10^196
+    (9.99999999999999951e+196, 4.88567075360764896e+180), // 10^197
+    (1.00000000000000002e+198, -1.75355415660194014e+181), // 10^198
+    (1.00000000000000010e+199, -9.72062404885344669e+182), // 10^199
+    (9.99999999999999970e+199, 3.02668777874896368e+183), // 10^200
+    (1.00000000000000004e+201, -3.77187852930565500e+184), // 10^201
+    (9.99999999999999902e+201, 9.82525408680358303e+185), // 10^202
+    (9.99999999999999989e+202, 1.12308921249367064e+186), // 10^203
+    (9.99999999999999989e+203, 1.12308921249367064e+187), // 10^204
+    (1.00000000000000002e+205, -1.66160354728550136e+188), // 10^205
+    (1.00000000000000004e+206, -3.88935775510883899e+189), // 10^206
+    (1.00000000000000004e+207, -3.88935775510883899e+190), // 10^207
+    (9.99999999999999982e+207, 1.81369301691890519e+191), // 10^208
+    (1.00000000000000007e+209, -7.31118821832548563e+192), // 10^209
+    (9.99999999999999927e+209, 7.28862175806553907e+193), // 10^210
+    (9.99999999999999956e+210, 4.36865976278733478e+194), // 10^211
+    (9.99999999999999910e+211, 9.04059895523246204e+195), // 10^212
+    (9.99999999999999984e+212, 1.56549624732025780e+196), // 10^213
+    (9.99999999999999954e+213, 4.55553733048513975e+197), // 10^214
+    (9.99999999999999907e+214, 9.33960306354895019e+198), // 10^215
+    (1.00000000000000002e+216, -2.14215469580419594e+199), // 10^216
+    (9.99999999999999960e+216, 3.98144944251748237e+200), // 10^217
+    (1.00000000000000008e+218, -8.26575883412587413e+201), // 10^218
+    (9.99999999999999965e+218, 3.49156111145174815e+202), // 10^219
+    (9.99999999999999996e+219, 3.56275792631048902e+202), // 10^220
+    (1.00000000000000005e+221, -4.66018071748206954e+204), // 10^221
+    (1.00000000000000005e+222, -4.66018071748207010e+205), // 10^222
+    (1.00000000000000005e+223, -4.66018071748206957e+206), // 10^223
+    (9.99999999999999970e+223, 3.04509648205168069e+207), // 10^224
+    (9.99999999999999928e+224, 7.15457765513634726e+208), // 10^225
+    (9.99999999999999961e+225, 3.86699271666861382e+209), // 10^226
+    (1.00000000000000009e+227, -9.28334703720231995e+210), // 10^227
+    (9.99999999999999925e+227, 7.54908784775247528e+211), // 10^228
+    (9.99999999999999992e+228, 8.16113893770557186e+211), // 10^229
+    (1.00000000000000010e+230, -9.95664443260051194e+213), // 10^230
+    (1.00000000000000006e+231, -5.64754110205208389e+214), // 10^231
+    (1.00000000000000006e+232, -5.64754110205208408e+215), // 10^232
+    (9.99999999999999974e+232, 2.62593729260089672e+216), // 10^233
+    (1.00000000000000002e+234, -1.78658451788069312e+217), // 10^234
+    (1.00000000000000005e+235, -5.31660196626596470e+218), // 10^235
+    (1.00000000000000005e+236, -5.31660196626596486e+219), // 10^236
+    (9.99999999999999940e+236, 5.97945386856690463e+220), // 10^237
+    (1.00000000000000005e+238, -4.86475973287265036e+221), // 10^238
+    (9.99999999999999991e+238, 9.18820854561779396e+221), // 10^239
+    (1.00000000000000001e+240, -1.39461138041199247e+223), // 10^240
+    (1.00000000000000005e+241, -5.09610295637002695e+224), // 10^241
+    (1.00000000000000005e+242, -5.09610295637002744e+225), // 10^242
+    (1.00000000000000007e+243, -7.46505756498316974e+226), // 10^243
+    (1.00000000000000007e+244, -7.46505756498316953e+227), // 10^244
+    (1.00000000000000004e+245, -4.43279566595834773e+228), // 10^245
+    (1.00000000000000007e+246, -6.85860518517820530e+229), // 10^246
+    (9.99999999999999952e+246, 4.78528050707711192e+230), // 10^247
+    (1.00000000000000005e+248, -4.52982804672714186e+231), // 10^248
+    (9.99999999999999921e+248, 7.89031669167852948e+232), // 10^249
+    (9.99999999999999921e+249, 7.89031669167853015e+233), // 10^250
+    (1.00000000000000005e+251, -4.82791152044887798e+234), // 10^251
+    (1.00000000000000010e+252, -9.91520280529984060e+235), // 10^252
+    (9.99999999999999936e+252, 6.36412930622324113e+236), // 10^253
+    (9.99999999999999936e+253, 6.36412930622324077e+237), // 10^254
+    (9.99999999999999988e+254, 1.15474303053585462e+238), // 10^255
+    (1.00000000000000003e+256, -3.01276599001405422e+239), // 10^256
+    (1.00000000000000003e+257, -3.01276599001405422e+240), // 10^257
+    (1.00000000000000006e+258, -5.67997176316599622e+241), // 10^258
+    (9.99999999999999929e+258, 7.12261594796332398e+242), // 10^259
+    (1.00000000000000007e+260, -6.53347761057461738e+243), // 10^260
+    (9.99999999999999929e+260, 7.12261594796332387e+244), // 10^261
+    (1.00000000000000002e+262, -1.61728392950095824e+245), // 10^262
+    (1.00000000000000002e+263, -1.61728392950095839e+246), // 10^263
+    (1.00000000000000004e+264, -4.41405189028952897e+247), // 10^264
+    (1.00000000000000007e+265, -6.65146625892038544e+248), // 10^265
+    (1.00000000000000003e+266, -3.07160326911101489e+249), // 10^266
+    (9.99999999999999973e+266, 2.65617751458397719e+250), // 10^267
+    (9.99999999999999973e+267, 2.65617751458397738e+251), // 10^268
+    (1.00000000000000005e+269, -4.67538188854561289e+252), // 10^269
+    (1.00000000000000005e+270, -4.67538188854561273e+253), // 10^270
+    (9.99999999999999953e+270, 4.70901414746026230e+254), // 10^271
+    (1.00000000000000007e+272, -6.55226109574678805e+255), // 10^272
+    (9.99999999999999945e+272, 5.45976583034073282e+256), // 10^273
+    (9.99999999999999921e+273, 7.86217121555823649e+257), // 10^274
+    (9.99999999999999960e+274, 4.01832259921023040e+258), // 10^275
+    (1.00000000000000005e+276, -5.20691408002498541e+259), // 10^276
+    (1.00000000000000000e+277, -2.86787851099537237e+259), // 10^277
+    (9.99999999999999964e+277, 3.64931313204082150e+261), // 10^278
+    (1.00000000000000006e+279, -5.79732922749603923e+262), // 10^279
+    (1.00000000000000003e+280, -3.27822459828620996e+263), // 10^280
+    (1.00000000000000003e+281, -3.27822459828620974e+264), // 10^281
+    (1.00000000000000003e+282, -3.27822459828620965e+265), // 10^282
+    (9.99999999999999955e+282, 4.46046482264638673e+266), // 10^283
+    (1.00000000000000008e+284, -7.92143825084576759e+267), // 10^284
+    (9.99999999999999980e+284, 1.98408420794795578e+268), // 10^285
+    (1.00000000000000003e+286, -3.29886110340869661e+269), // 10^286
+    (1.00000000000000008e+287, -7.52521735249401870e+270), // 10^287
+    (1.00000000000000001e+288, -7.63047353957503547e+270), // 10^288
+    (1.00000000000000006e+289, -6.17278335278671567e+272), // 10^289
+    (1.00000000000000006e+290, -6.17278335278671567e+273), // 10^290
+    (9.99999999999999958e+290, 4.21390976496537161e+274), // 10^291
+    (1.00000000000000001e+292, -1.32565989783574161e+275), // 10^292
+    (9.99999999999999925e+292, 7.53765156264603993e+276), // 10^293
+    (1.00000000000000007e+294, -6.64364677412481029e+277), // 10^294
+    (9.99999999999999981e+294, 1.86513222793769961e+278), // 10^295
+    (9.99999999999999981e+295, 1.86513222793769961e+279), // 10^296
+    (1.00000000000000002e+297, -1.76528014627563795e+280), // 10^297
+    (9.99999999999999960e+297, 4.04337965246570226e+281), // 10^298
+    (1.00000000000000005e+299, -5.25047602552044175e+282), // 10^299
+    (1.00000000000000005e+300, -5.25047602552044196e+283), // 10^300
+    (1.00000000000000005e+301, -5.25047602552044229e+284), // 10^301
+    (1.00000000000000008e+302, -7.62970307908489505e+285), // 10^302
+    (1.00000000000000000e+303, -1.61765076786456445e+284), // 10^303
+    (9.99999999999999939e+303, 6.07464474944635357e+287), // 10^304
+    (9.99999999999999939e+304, 6.07464474944635397e+288), // 10^305
+    (1.00000000000000002e+306, -1.72160645967364551e+289), // 10^306
+    (9.99999999999999986e+306, 1.39689402397435424e+290), // 10^307
+    (1.00000000000000001e+308, -1.09790636294404549e+291), // 10^308
+];
+
+#[doc(hidden)] pub const POW10_FIRST: i32 = -290;
+#[doc(hidden)] pub const POW10_LAST: i32 = 308;
+
+// the relative error allowed to the scaled rounding interval, which is a few ulps.
+const EPSILON: f64 = 1.0e-15;
+
+/// A double-double number, representing the unevaluated sum `val + off` where `|off|` is
+/// no more than the half ulp of `val`.
+#[derive(Copy, Clone, Debug)]
+#[doc(hidden)]
+pub struct Hp {
+    pub val: f64,
+    pub off: f64,
+}
+
+impl Hp {
+    /// Restores the invariant that `off` is no more than the half ulp of `val`.
+    pub fn normalize(&mut self) {
+        let val = self.val;
+        self.val += self.off;
+        self.off += val - self.val;
+    }
+
+    /// Multiplies itself by 10.
+    pub fn mul10(&mut self) {
+        let val = self.val;
+        self.val *= 10.0;
+        self.off *= 10.0;
+        // `val * 8` and `val * 2` are exact, so this is the rounding error of `val * 10`.
+        let err = self.val - val * 8.0 - val * 2.0;
+        self.off -= err;
+        self.normalize();
+    }
+
+    /// Divides itself by 10.
+    pub fn div10(&mut self) {
+        let val = self.val;
+        self.val /= 10.0;
+        self.off /= 10.0;
+        let rem = val - self.val * 8.0 - self.val * 2.0;
+        self.off += rem / 10.0;
+        self.normalize();
+    }
+
+    /// Returns the product of itself and `x`, where the error from `val * x` is exactly
+    /// calculated with the Dekker's algorithm.
+    pub fn mul(&self, x: f64) -> Hp {
+        let (hi, lo) = split(self.val);
+        let (hi2, lo2) = split(x);
+        let p = self.val * x;
+        let e = ((hi * hi2 - p) + lo * hi2 + hi * lo2) + lo * lo2;
+        Hp { val: p, off: self.off * x + e }
+    }
+
+    /// Returns the integral digit of itself, which should be in `[0, 10)`.
+    /// `val` can be an integer while the actual value is slightly less than that.
+    fn digit(&self) -> u8 {
+        let d = self.val as u8;
+        if self.val == d as f64 && self.off < 0.0 { d - 1 } else { d }
+    }
+}
+
+/// Splits `x` into the higher 26 bits and the remaining bits, so that
+/// the product of any two halves is exact.
+fn split(x: f64) -> (f64, f64) {
+    let bits: u64 = unsafe { mem::transmute(x) };
+    let hi: f64 = unsafe { mem::transmute(bits & !((1 << 27) - 1)) };
+    (hi, x - hi)
+}
+
+/// Renders `n` into `buf` without trailing zeroes and returns the number of digits
+/// and the exponent `k`, where `n * 10^p` is the value to be rendered.
+fn render_u128(buf: &mut [u8], mut n: u128, mut p: i32) -> (usize, i16) {
+    debug_assert!(n > 0);
+    while n % 10 == 0 {
+        n /= 10;
+        p += 1;
+    }
+    let mut len = 0;
+    let mut m = n;
+    while m > 0 {
+        m /= 10;
+        len += 1;
+    }
+    for c in buf[..len].iter_mut().rev() {
+        *c = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+    (len, (p + len as i32) as i16)
+}
+
+/// The integer path. It finds the largest `10^p` whose multiple is in the rounding interval,
+/// and returns the multiple closest to `v` (the exact tie is rounded up).
+fn format_int(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    let shift = d.exp as usize;
+    let mid = d.mant << shift;
+    let mut low = (d.mant - d.minus) << shift;
+    let mut high = (d.mant + d.plus) << shift;
+    if !d.inclusive {
+        low += 1;
+        high -= 1;
+    }
+
+    let mut p = 0;
+    let mut pow: u128 = 1;
+    loop {
+        let next = match pow.checked_mul(10) { Some(next) => next, None => break };
+        if high / next * next < low { break; }
+        pow = next;
+        p += 1;
+    }
+
+    let lowest = (low + pow - 1) / pow;
+    let highest = high / pow;
+    let rem = mid % pow;
+    let mut n = mid / pow + if rem >= pow - rem { 1 } else { 0 };
+    if n < lowest { n = lowest; }
+    if n > highest { n = highest; }
+    render_u128(buf, n, p)
+}
+
+/// The shortest mode implementation for Errol3.
+///
+/// The result is always within the rounding interval, but may be neither the shortest
+/// nor the closest representation.
+pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    assert!(d.mant > 0);
+    assert!(d.minus > 0);
+    assert!(d.plus > 0);
+    assert!(d.mant.checked_add(d.plus).is_some());
+    assert!(d.mant.checked_sub(d.minus).is_some());
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+
+    // integers below `2^128` are handled exactly.
+    let nbits = 128 - (d.mant + d.plus).leading_zeros() as i32;
+    if d.exp >= 0 && nbits + d.exp as i32 <= 128 {
+        return format_int(d, buf);
+    }
+
+    // `v` should be exactly representable in `f64`.
+    let tz = d.mant.trailing_zeros();
+    let val = <f64 as DecodableFloat>::ldexpi((d.mant >> tz as usize) as i64,
+                                              (d.exp as i32 + tz as i32) as isize);
+
+    // scale `v` by a power of ten, so that the result is roughly within `[1, 10)`.
+    // the power of ten is limited so that the table is accurate, and is corrected later.
+    let e2 = d.exp as i32 + 127 - d.mant.leading_zeros() as i32; // `2^e2 <= v < 2^(e2+1)`
+    let p = -((e2 as f64 * 0.30103) as i32);
+    let p = if p < POW10_FIRST { POW10_FIRST } else if p > POW10_LAST { POW10_LAST } else { p };
+    let (pval, poff) = POW10[(p - POW10_FIRST) as usize];
+    let mut mid = Hp { val: pval, off: poff }.mul(val);
+    let mut exp = -p;
+
+    // normalize `mid` into `[1, 10)`, so that `v = mid * 10^exp`.
+    while mid.val > 10.0 || (mid.val == 10.0 && mid.off >= 0.0) {
+        exp += 1;
+        mid.div10();
+    }
+    while mid.val < 1.0 || (mid.val == 1.0 && mid.off < 0.0) {
+        exp -= 1;
+        mid.mul10();
+    }
+
+    // calculate the scaled bounds from `mid`, as the distances to them may not be
+    // representable in `f64` (`2^-1075` for the smallest normal `f64`). they are slightly
+    // narrowed for the rounding error, and can't be included even when `d.inclusive`.
+    let unit = mid.val / d.mant as f64 / (1.0 + EPSILON); // scaled `2^d.exp`
+    let mut high = Hp { val: mid.val, off: mid.off + d.plus as f64 * unit };
+    let mut low = Hp { val: mid.val, off: mid.off - d.minus as f64 * unit };
+    high.normalize();
+    low.normalize();
+
+    // the upper bound can be out of `[1, 10)` after the scaling.
+    while high.val > 10.0 || (high.val == 10.0 && high.off >= 0.0) {
+        exp += 1;
+        high.div10();
+        low.div10();
+    }
+    while high.val < 1.0 || (high.val == 1.0 && high.off < 0.0) {
+        exp -= 1;
+        high.mul10();
+        low.mul10();
+    }
+
+    // generate digits until both bounds diverge.
+    let mut i = 0;
+    while high.val != 0.0 || high.off != 0.0 {
+        let hdigit = high.digit();
+        let ldigit = low.digit();
+        if hdigit != ldigit { break; }
+        buf[i] = b'0' + hdigit;
+        i += 1;
+        high.val -= hdigit as f64;
+        low.val -= ldigit as f64;
+        high.mul10();
+        low.mul10();
+    }
+
+    // the last digit is the rounded midpoint of the bounds.
+    let last = ((high.val + low.val) / 2.0 + 0.5) as u8;
+    buf[i] = b'0' + last;
+    i += 1;
+    (i, (exp + 1) as i16)
+}
@@ -9,7 +9,7 @@ Rust adaptation of Grisu3 algorithm described in [1]. It uses about
 use core::prelude::*;
 use core::num::Float;
 
-use flt2dec::{Decoded, MAX_SIG_DIGITS, round_up};
+use flt2dec::{Decoded, shortest_buf_len, round_up};
 
 /// A custom 64-bit floating point type, representing `f * 2^e`.
 #[derive(Copy, Clone, Debug)]
@@ -23,7 +23,7 @@ pub struct Fp {
 
 impl Fp {
     /// Returns a correctly rounded product of itself and `other`.
-    fn mul(&self, other: &Fp) -> Fp {
+    pub fn mul(&self, other: &Fp) -> Fp {
         const MASK: u64 = 0xffffffff;
         let a = self.f >> 32;
         let b = self.f & MASK;
@@ -40,7 +40,7 @@ impl Fp {
     }
 
     /// Normalizes itself so that the resulting mantissa is at least `2^63`.
-    fn normalize(&self) -> Fp {
+    pub fn normalize(&self) -> Fp {
         let mut f = self.f;
         let mut e = self.e;
         if f >> (64 - 32) == 0 { f <<= 32; e -= 32; }
@@ -55,7 +55,7 @@ impl Fp {
 
     /// Normalizes itself to have the shared exponent.
     /// It can only decrease the exponent (and thus increase the mantissa).
-    fn normalize_to(&self, e: i16) -> Fp {
+    pub fn normalize_to(&self, e: i16) -> Fp {
         let edelta = self.e - e;
         assert!(edelta >= 0);
         let edelta = edelta as usize;
@@ -166,6 +166,11 @@ pub static CACHED_POW10: [(u64, i16, i16); 81] = [ // (f, e, k)
 #[doc(hidden)] pub const CACHED_POW10_FIRST_E: i16 = -1087;
 #[doc(hidden)] pub const CACHED_POW10_LAST_E: i16 = 1039;
 
+// the range of the exponent of normalized `Fp` for finite `f64` values.
+// `cached_power(ALPHA - e - 64, GAMMA - e - 64)` is known to be valid for these `e`.
+#[doc(hidden)] pub const MIN_NORMALIZED_E: i16 = -1137;
+#[doc(hidden)] pub const MAX_NORMALIZED_E: i16 = 960;
+
 #[doc(hidden)]
 pub fn cached_power(alpha: i16, gamma: i16) -> (i16, Fp) {
     let offset = CACHED_POW10_FIRST_E as i32;
@@ -205,6 +210,8 @@ pub fn max_pow10_no_more_than(x: u32) -> (u8, u32) {
 /// The shortest mode implementation for Grisu.
 ///
 /// It returns `None` when it would return an inexact representation otherwise.
+/// The buffer should be at least `shortest_buf_len(d)` bytes long,
+/// which is `MAX_SIG_DIGITS` unless `d` is more precise than `f64`.
 pub fn format_shortest_opt(d: &Decoded,
                            buf: &mut [u8]) -> Option<(/*#digits*/ usize, /*exp*/ i16)> {
     assert!(d.mant > 0);
@@ -212,13 +219,18 @@ pub fn format_shortest_opt(d: &Decoded,
     assert!(d.plus > 0);
     assert!(d.mant.checked_add(d.plus).is_some());
     assert!(d.mant.checked_sub(d.minus).is_some());
-    assert!(buf.len() >= MAX_SIG_DIGITS);
-    assert!(d.mant + d.plus < (1 << 61)); // we need at least three bits of additional precision
+    assert!(buf.len() >= shortest_buf_len(d));
+
+    // we need at least three bits of additional precision, and the cached powers of 10
+    // should cover the normalized exponent. both hold for `f32` and `f64` but not
+    // for wider types like `quad::F128`, which should be handled by the fallback.
+    if d.mant + d.plus >= (1 << 61) { return None; }
 
     // start with the normalized values with the shared exponent
-    let plus = Fp { f: d.mant + d.plus, e: d.exp }.normalize();
-    let minus = Fp { f: d.mant - d.minus, e: d.exp }.normalize_to(plus.e);
-    let v = Fp { f: d.mant, e: d.exp }.normalize_to(plus.e);
+    let plus = Fp { f: (d.mant + d.plus) as u64, e: d.exp }.normalize();
+    if plus.e < MIN_NORMALIZED_E || plus.e > MAX_NORMALIZED_E { return None; }
+    let minus = Fp { f: (d.mant - d.minus) as u64, e: d.exp }.normalize_to(plus.e);
+    let v = Fp { f: d.mant as u64, e: d.exp }.normalize_to(plus.e);
 
     // find any `cached = 10^minusk` such that `ALPHA <= minusk + plus.e + 64 <= GAMMA`.
     // since `plus` is normalized, this means `2^(62 + ALPHA) <= plus * cached < 2^(64 + GAMMA)`;
@@ -486,11 +498,14 @@ pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp
 pub fn format_exact_opt(d: &Decoded, buf: &mut [u8], limit: i16)
                                 -> Option<(/*#digits*/ usize, /*exp*/ i16)> {
     assert!(d.mant > 0);
-    assert!(d.mant < (1 << 61)); // we need at least three bits of additional precision
     assert!(!buf.is_empty());
 
+    // we need at least three bits of additional precision (see `format_shortest_opt`).
+    if d.mant >= (1 << 61) { return None; }
+
     // normalize and scale `v`.
-    let v = Fp { f: d.mant, e: d.exp }.normalize();
+    let v = Fp { f: d.mant as u64, e: d.exp }.normalize();
+    if v.e < MIN_NORMALIZED_E || v.e > MAX_NORMALIZED_E { return None; }
     let (minusk, cached) = cached_power(ALPHA - v.e - 64, GAMMA - v.e - 64);
     let v = v.mul(&cached);
 
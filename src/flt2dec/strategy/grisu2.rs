@@ -0,0 +1,128 @@
+/*!
+Rust adaptation of Grisu2 algorithm described in [1], for the shortest mode only.
+It shares the custom floating point type and the precomputed table with Grisu3.
+
+Grisu2 is the predecessor of Grisu3, which always produces a correct (i.e. round-tripping)
+representation but not necessarily the shortest or the closest one, and never gives up.
+It is included as a reference point for the benchmarks, and should not be used otherwise.
+
+[1] Florian Loitsch. 2010. Printing floating-point numbers quickly and
+    accurately with integers. SIGPLAN Not. 45, 6 (June 2010), 233-243.
+*/
+
+use core::prelude::*;
+
+use flt2dec::{Decoded, MAX_SIG_DIGITS};
+use flt2dec::strategy::grisu::{Fp, ALPHA, GAMMA, cached_power, max_pow10_no_more_than};
+
+/// The shortest mode implementation for Grisu2.
+///
+/// The result is always within the rounding interval, but may be neither the shortest
+/// nor the closest representation.
+pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    assert!(d.mant > 0);
+    assert!(d.minus > 0);
+    assert!(d.plus > 0);
+    assert!(d.mant.checked_add(d.plus).is_some());
+    assert!(d.mant.checked_sub(d.minus).is_some());
+    assert!(buf.len() >= MAX_SIG_DIGITS);
+    assert!(d.mant + d.plus < (1 << 61)); // we need at least three bits of additional precision
+
+    // start with the normalized values with the shared exponent, and scale them
+    // exactly as Grisu3 does. see `grisu::format_shortest_opt` for the details.
+    let plus = Fp { f: (d.mant + d.plus) as u64, e: d.exp }.normalize();
+    let minus = Fp { f: (d.mant - d.minus) as u64, e: d.exp }.normalize_to(plus.e);
+    let v = Fp { f: d.mant as u64, e: d.exp }.normalize_to(plus.e);
+    let (minusk, cached) = cached_power(ALPHA - plus.e - 64, GAMMA - plus.e - 64);
+    let plus = plus.mul(&cached);
+    let minus = minus.mul(&cached);
+    let v = v.mul(&cached);
+    debug_assert_eq!(plus.e, minus.e);
+    debug_assert_eq!(plus.e, v.e);
+
+    // unlike Grisu3, we only work with the conservative interval `(minus0, plus0)`,
+    // so that any representation inside is guaranteed to be correct.
+    // the resulting representation is shortest in that interval, which can be narrower
+    // than the actual interval by 1 ulp on each side.
+    let plus0 = plus.f - 1;
+    let minus0 = minus.f + 1;
+    let e = -plus.e as usize; // shared exponent
+
+    let plus0int = (plus0 >> e) as u32;
+    let plus0frac = plus0 & ((1 << e) - 1);
+    let (max_kappa, max_ten_kappa) = max_pow10_no_more_than(plus0int);
+
+    let mut i = 0;
+    let exp = max_kappa as i16 - minusk + 1;
+    let delta0 = plus0 - minus0;
+    let delta0frac = delta0 & ((1 << e) - 1);
+
+    // render integral parts, stopping as soon as the remainder is within the interval.
+    let mut ten_kappa = max_ten_kappa; // 10^kappa
+    let mut remainder = plus0int; // digits yet to be rendered
+    loop {
+        let q = remainder / ten_kappa;
+        let r = remainder % ten_kappa;
+        debug_assert!(q < 10);
+        buf[i] = b'0' + q as u8;
+        i += 1;
+
+        let plus0rem = ((r as u64) << e) + plus0frac; // == (plus0 % 10^kappa) * 2^e
+        if plus0rem < delta0 {
+            let ten_kappa = (ten_kappa as u64) << e; // scale 10^kappa back to the shared exponent
+            round(&mut buf[..i], plus0rem, delta0, plus0 - v.f, ten_kappa);
+            return (i, exp);
+        }
+
+        if i > max_kappa as usize {
+            debug_assert_eq!(ten_kappa, 1);
+            break;
+        }
+        ten_kappa /= 10;
+        remainder = r;
+    }
+
+    // render fractional parts with repeated multiplications.
+    let mut remainder = plus0frac;
+    let mut threshold = delta0frac;
+    let mut ulp = 1;
+    loop {
+        remainder *= 10; // won't overflow, `2^e * 10 < 2^64`
+        threshold *= 10;
+        ulp *= 10;
+
+        let q = remainder >> e;
+        let r = remainder & ((1 << e) - 1);
+        debug_assert!(q < 10);
+        buf[i] = b'0' + q as u8;
+        i += 1;
+
+        if r < threshold {
+            round(&mut buf[..i], r, threshold, (plus0 - v.f) * ulp, 1 << e);
+            return (i, exp);
+        }
+        remainder = r;
+    }
+
+    // decrease the last digit while it gets closer to the approximated `v`.
+    // this is the "rounding" phase of Grisu3 without the "weeding" phase, so the result
+    // may not be the closest one due to the error in `v` (at most 1 ulp).
+    //
+    // all arguments here are scaled by the common (but implicit) value `k`, so that:
+    // - `remainder = (plus0 % 10^kappa) * k`
+    // - `threshold = (plus0 - minus0) * k` (and also, `remainder < threshold`)
+    // - `plus0v = (plus0 - v) * k`
+    // - `ten_kappa = 10^kappa * k`
+    fn round(buf: &mut [u8], remainder: u64, threshold: u64, plus0v: u64, ten_kappa: u64) {
+        let last = buf.last_mut().unwrap();
+        let mut plus0w = remainder; // plus0w(n) = plus0 - w(n)
+        while plus0w < plus0v &&
+              threshold - plus0w >= ten_kappa &&
+              (plus0w + ten_kappa < plus0v ||
+               plus0v - plus0w >= plus0w + ten_kappa - plus0v) {
+            *last -= 1;
+            debug_assert!(*last > b'0'); // the shortest repr cannot end with `0`
+            plus0w += ten_kappa;
+        }
+    }
+}
@@ -0,0 +1,94 @@
+/*!
+Runtime dispatch among other strategies.
+
+The default choice is Grisu with Dragon fallback, which is the fastest on every machine
+measured so far. Applications can override it with `Dispatch::select` (e.g. to use Dragon
+for a guaranteed worst-case behavior), or time every candidate with `calibrate`.
+*/
+
+use core::prelude::*;
+use core::i16;
+use core::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+
+use dispatch::Features;
+use flt2dec::{decode, Decoded, FullDecoded, MAX_SIG_DIGITS};
+use flt2dec::strategy::{dragon, grisu, ryu, ryu_printf, schubfach, dragonbox};
+
+dispatch! {
+    /// Candidates for `format_shortest`.
+    pub static FORMAT_SHORTEST: fn(&Decoded, &mut [u8]) -> (usize, i16) = {
+        "grisu" => grisu::format_shortest,
+        "dragon" => dragon::format_shortest,
+        "ryu" => ryu::format_shortest,
+        "schubfach" => schubfach::format_shortest,
+        "dragonbox" => dragonbox::format_shortest
+    } default grisu_default;
+
+    /// Candidates for `format_exact`.
+    pub static FORMAT_EXACT: fn(&Decoded, &mut [u8], i16) -> (usize, i16) = {
+        "grisu" => grisu::format_exact,
+        "dragon" => dragon::format_exact,
+        "ryu_printf" => ryu_printf::format_exact
+    } default grisu_default;
+}
+
+fn grisu_default(_: &Features) -> &'static str {
+    "grisu"
+}
+
+/// The shortest mode implementation with the runtime choice of strategies.
+#[inline]
+pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (/*#digits*/ usize, /*exp*/ i16) {
+    (FORMAT_SHORTEST.get())(d, buf)
+}
+
+/// The exact and fixed mode implementation with the runtime choice of strategies.
+#[inline]
+pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usize, /*exp*/ i16) {
+    (FORMAT_EXACT.get())(d, buf, limit)
+}
+
+// keeps the calibration workload from being optimized out.
+static SINK: AtomicUsize = ATOMIC_USIZE_INIT;
+
+// the number of passes over the inputs per measurement.
+const PASSES: usize = 4;
+
+/// Times every candidate and chooses the fastest ones.
+///
+/// `clock` should return the current time in any monotonically increasing unit,
+/// preferably with a sub-microsecond resolution. This takes some milliseconds.
+pub fn calibrate<C: FnMut() -> u64>(clock: &mut C) {
+    // a mix of short and long representations, and small and large exponents.
+    let mut inputs = [Decoded { mant: 1, minus: 1, plus: 1, exp: 0, inclusive: true }; 64];
+    let mut v = 0.1f64;
+    for input in inputs.iter_mut() {
+        if let (_, FullDecoded::Finite(d)) = decode(v) { *input = d; }
+        v *= -7.3e5;
+        if v > 1e300 || v < -1e300 { v = 1.0 / v; }
+    }
+
+    FORMAT_SHORTEST.calibrate(clock, &mut |f: fn(&Decoded, &mut [u8]) -> (usize, i16)| {
+        let mut acc = 0;
+        let mut buf = [0; MAX_SIG_DIGITS];
+        for _ in 0..PASSES {
+            for d in &inputs[..] { acc ^= f(d, &mut buf).0; }
+        }
+        SINK.store(acc, Ordering::Relaxed);
+    });
+
+    FORMAT_EXACT.calibrate(clock, &mut |f: fn(&Decoded, &mut [u8], i16) -> (usize, i16)| {
+        let mut acc = 0;
+        let mut buf = [0; 17];
+        for _ in 0..PASSES {
+            for d in &inputs[..] { acc ^= f(d, &mut buf, i16::MIN).0; }
+        }
+        SINK.store(acc, Ordering::Relaxed);
+    });
+}
+
+/// Forgets every choice, so that the defaults are chosen again on the next use.
+pub fn reset() {
+    FORMAT_SHORTEST.reset();
+    FORMAT_EXACT.reset();
+}
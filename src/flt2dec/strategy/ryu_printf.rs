@@ -0,0 +1,4620 @@
+/*!
+Rust adaptation of the Ryu printf algorithm described in [1], for the exact and fixed mode.
+It uses about 105KB of precomputed table, and never needs a fallback for `f32` and `f64`.
+The tables only cover their mantissas and exponents, so wider inputs are handed to Dragon.
+
+The number `m * 2^e` is split into blocks of nine decimal digits aligned to the decimal point,
+and each block is calculated independently with a single multiplication by
+a truncated 192-bit approximation of `2^e / 10^9i` (for the integral part) or
+`10^9(i+1) / 2^-e` (for the fractional part), followed by a modulo by `10^9`.
+Therefore digits can be rendered starting from any block without the bignum arithmetic,
+and the rounding is decided from the next digit and the exact position of the last nonzero digit.
+The table precision is chosen so that the truncation never changes a block
+for every odd mantissa below `2^53`, which covers every finite `f64` and `f32`.
+
+[1] Ulf Adams. 2019. Ryu revisited: printf floating point conversion.
+    Proc. ACM Program. Lang. 3, OOPSLA (Oct. 2019), Article 169.
+*/
+
+use core::prelude::*;
+use core::cmp;
+
+use flt2dec::{Decoded, round_up};
+
+/*
+# the following Python code generates these tables:
+MOD = 10**9 << 136
+offsets = [0]; table = []
+for idx in xrange(65):
+    for i in xrange((len(str(2**(16*idx + 53) - 1)) + 8) // 9):
+        table.append(-(-2**(16*idx + 120) // 10**(9*i)) % MOD)
+    offsets.append(len(table))
+minblocks = []; offsets2 = [0]; table2 = []
+for idx in xrange(68):
+    mb = 0
+    while 10**(9*mb + 9) << 53 <= 2**(16*idx): mb += 1
+    minblocks.append(mb)
+    for i in xrange(mb, (16*idx + 23) // 9):
+        table2.append(-(-(10**(9*i + 9) << 120) // 2**(16*idx)) % MOD)
+    offsets2.append(len(table2))
+print offsets, minblocks, offsets2
+for c in table + table2:
+    print '    (%#012x, %#018x, %#018x),' % (c >> 128, (c >> 64) & (2**64 - 1), c & (2**64 - 1))
+*/
+// FIXME(#22540) const ref to static array seems to ICE
+#[doc(hidden)]
+pub static POW10_OFFSET: [u16; 66] = [
+    0, 2, 5, 8, 12, 16, 21, 26, 32, 39, 46, 54, 62, 71, 80, 90,
+    100, 111, 122, 134, 146, 159, 173, 187, 202, 217, 233, 249, 266, 283, 301, 319,
+    338, 357, 377, 397, 418, 440, 462, 485, 508, 532, 556, 581, 606, 632, 658, 685,
+    712, 740, 768, 797, 827, 857, 888, 919, 951, 983, 1016, 1049, 1083, 1117, 1152, 1187,
+    1223, 1260,
+];
+
+// FIXME(#22540) const ref to static array seems to ICE
+#[doc(hidden)]
+pub static POW10_SPLIT: [(u64, u64, u64); 1260] = [ // (hi, mid, lo)
+    (0x0000000000, 0x0100000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000044b82fa, 0x09b5a52cb98b4055),
+    (0x0000000100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000044b82fa09b5, 0xa52cb98b405447c5),
+    (0x0000000000, 0x0000000000001272, 0x5dd1d243aba0e760),
+    (0x0001000000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x044b82fa09b5a52c, 0xb98b405447c4a982),
+    (0x0000000000, 0x0000000012725dd1, 0xd243aba0e75fe646),
+    (0x1194d80000, 0x0000000000000000, 0x0000000000000000),
+    (0x000000044b, 0x82fa09b5a52cb98b, 0x405447c4a98187ef),
+    (0x0000000000, 0x000012725dd1d243, 0xaba0e75fe645cc49),
+    (0x0000000000, 0x0000000000004f3a, 0x68dbc8f03f243bb0),
+    (0x3a376c0000, 0x0000000000000000, 0x0000000000000000),
+    (0x00044b82fa, 0x09b5a52cb98b4054, 0x47c4a98187eebb23),
+    (0x0000000000, 0x12725dd1d243aba0, 0xe75fe645cc4873fa),
+    (0x0000000000, 0x000000004f3a68db, 0xc8f03f243baf5133),
+    (0x2a4ae60000, 0x0000000000000000, 0x0000000000000000),
+    (0x1aa0c609b5, 0xa52cb98b405447c4, 0xa98187eebb22f009),
+    (0x0000001272, 0x5dd1d243aba0e75f, 0xe645cc4873f9e65b),
+    (0x0000000000, 0x00004f3a68dbc8f0, 0x3f243baf513267ab),
+    (0x0000000000, 0x0000000000015448, 0x4932d2e725a5bbcb),
+    (0x0a69ce0000, 0x0000000000000000, 0x0000000000000000),
+    (0x30da53a52c, 0xb98b405447c4a981, 0x87eebb22f008d5d7),
+    (0x0012725dd1, 0xd243aba0e75fe645, 0xcc4873f9e65afe69),
+    (0x0000000000, 0x4f3a68dbc8f03f24, 0x3baf513267aa9a3f),
+    (0x0000000000, 0x0000000154484932, 0xd2e725a5bbca17a4),
+    (0x206c060000, 0x0000000000000000, 0x0000000000000000),
+    (0x0fc178b98b, 0x405447c4a98187ee, 0xbb22f008d5d64f9d),
+    (0x0d997bd243, 0xaba0e75fe645cc48, 0x73f9e65afe688c93),
+    (0x0000004f3a, 0x68dbc8f03f243baf, 0x513267aa9a3ee525),
+    (0x0000000000, 0x000154484932d2e7, 0x25a5bbca17a3aba2),
+    (0x0000000000, 0x000000000005b580, 0x6b4ddaae4689eb04),
+    (0x139f800000, 0x0000000000000000, 0x0000000000000000),
+    (0x25769d4054, 0x47c4a98187eebb22, 0xf008d5d64f9c394b),
+    (0x332c33aba0, 0xe75fe645cc4873f9, 0xe65afe688c928e20),
+    (0x004f3a68db, 0xc8f03f243baf5132, 0x67aa9a3ee524f8e1),
+    (0x0000000001, 0x54484932d2e725a5, 0xbbca17a3aba173d4),
+    (0x0000000000, 0x00000005b5806b4d, 0xdaae4689eb03dcbf),
+    (0x0000000000, 0x0000000000000018, 0x851a0b548ea3c996),
+    (0x2dc9fa0000, 0x0000000000000000, 0x0000000000000000),
+    (0x16540e47c4, 0xa98187eebb22f008, 0xd5d64f9c394ae922),
+    (0x37efd0e75f, 0xe645cc4873f9e65a, 0xfe688c928e1f2196),
+    (0x10d493c8f0, 0x3f243baf513267aa, 0x9a3ee524f8e02891),
+    (0x0000015448, 0x4932d2e725a5bbca, 0x17a3aba173d3d5fd),
+    (0x0000000000, 0x0005b5806b4ddaae, 0x4689eb03dcbe2fe9),
+    (0x0000000000, 0x000000000018851a, 0x0b548ea3c99552fd),
+    (0x1e28a60000, 0x0000000000000000, 0x0000000000000000),
+    (0x104048a981, 0x87eebb22f008d5d6, 0x4f9c394ae9213016),
+    (0x086fa9e645, 0xcc4873f9e65afe68, 0x8c928e1f2195818b),
+    (0x0bd5563f24, 0x3baf513267aa9a3e, 0xe524f8e0289064e4),
+    (0x0154484932, 0xd2e725a5bbca17a3, 0xaba173d3d5fc130d),
+    (0x0000000005, 0xb5806b4ddaae4689, 0xeb03dcbe2fe86913),
+    (0x0000000000, 0x00000018851a0b54, 0x8ea3c99552fc2988),
+    (0x0000000000, 0x0000000000000069, 0x4ff258c744320748),
+    (0x37957a0000, 0x0000000000000000, 0x0000000000000000),
+    (0x10de8987ee, 0xbb22f008d5d64f9c, 0x394ae92130153561),
+    (0x28d1b7cc48, 0x73f9e65afe688c92, 0x8e1f2195818ae780),
+    (0x35d1703baf, 0x513267aa9a3ee524, 0xf8e0289064e3cffb),
+    (0x1de660d2e7, 0x25a5bbca17a3aba1, 0x73d3d5fc130c23b8),
+    (0x000005b580, 0x6b4ddaae4689eb03, 0xdcbe2fe869126827),
+    (0x0000000000, 0x0018851a0b548ea3, 0xc99552fc298784d8),
+    (0x0000000000, 0x0000000000694ff2, 0x58c7443207477640),
+    (0x0822720000, 0x0000000000000000, 0x0000000000000000),
+    (0x3a2f30bb22, 0xf008d5d64f9c394a, 0xe9213015356022f0),
+    (0x0bbc5e73f9, 0xe65afe688c928e1f, 0x2195818ae77f3c37),
+    (0x26967d5132, 0x67aa9a3ee524f8e0, 0x289064e3cffa15ac),
+    (0x12207925a5, 0xbbca17a3aba173d3, 0xd5fc130c23b7aa2e),
+    (0x05b5806b4d, 0xdaae4689eb03dcbe, 0x2fe8691268269aa8),
+    (0x0000000018, 0x851a0b548ea3c995, 0x52fc298784d710d3),
+    (0x0000000000, 0x000000694ff258c7, 0x44320747763f868d),
+    (0x0000000000, 0x00000000000001c4, 0x5016d841baa4644c),
+    (0x020ea00000, 0x0000000000000000, 0x0000000000000000),
+    (0x152fa6f008, 0xd5d64f9c394ae921, 0x3015356022ef3217),
+    (0x27b8b3e65a, 0xfe688c928e1f2195, 0x818ae77f3c36a08d),
+    (0x2f0da467aa, 0x9a3ee524f8e02890, 0x64e3cffa15ab8bba),
+    (0x2893a1bbca, 0x17a3aba173d3d5fc, 0x130c23b7aa2da19c),
+    (0x06105bdaae, 0x4689eb03dcbe2fe8, 0x691268269aa7acc6),
+    (0x000018851a, 0x0b548ea3c99552fc, 0x298784d710d2d086),
+    (0x0000000000, 0x00694ff258c74432, 0x0747763f868cd01e),
+    (0x0000000000, 0x0000000001c45016, 0xd841baa4644b8db5),
+    (0x31e5ee0000, 0x0000000000000000, 0x0000000000000000),
+    (0x18579cd5d6, 0x4f9c394ae9213015, 0x356022ef3216417a),
+    (0x0ea4d6fe68, 0x8c928e1f2195818a, 0xe77f3c36a08cce4f),
+    (0x261b849a3e, 0xe524f8e0289064e3, 0xcffa15ab8bb9ccc3),
+    (0x201c8e17a3, 0xaba173d3d5fc130c, 0x23b7aa2da19b9a3d),
+    (0x13b0004689, 0xeb03dcbe2fe86912, 0x68269aa7acc51b40),
+    (0x18851a0b54, 0x8ea3c99552fc2987, 0x84d710d2d085b92b),
+    (0x0000000069, 0x4ff258c744320747, 0x763f868cd01d569b),
+    (0x0000000000, 0x000001c45016d841, 0xbaa4644b8db4c788),
+    (0x0000000000, 0x0000000000000796, 0xab3c855a0e1517d8),
+    (0x245faa0000, 0x0000000000000000, 0x0000000000000000),
+    (0x221f5e4f9c, 0x394ae92130153560, 0x22ef32164179b6c0),
+    (0x089bb68c92, 0x8e1f2195818ae77f, 0x3c36a08cce4e0a37),
+    (0x259750e524, 0xf8e0289064e3cffa, 0x15ab8bb9ccc2933c),
+    (0x00e635aba1, 0x73d3d5fc130c23b7, 0xaa2da19b9a3cab82),
+    (0x26227deb03, 0xdcbe2fe869126826, 0x9aa7acc51b3fd35c),
+    (0x386cfe8ea3, 0xc99552fc298784d7, 0x10d2d085b92a647f),
+    (0x0000694ff2, 0x58c744320747763f, 0x868cd01d569a53f5),
+    (0x0000000000, 0x01c45016d841baa4, 0x644b8db4c7871bc4),
+    (0x0000000000, 0x000000000796ab3c, 0x855a0e1517d71395),
+    (0x1171060000, 0x0000000000000000, 0x0000000000000000),
+    (0x073f90394a, 0xe9213015356022ef, 0x32164179b6bf082d),
+    (0x3430e28e1f, 0x2195818ae77f3c36, 0xa08cce4e0a366281),
+    (0x315266f8e0, 0x289064e3cffa15ab, 0x8bb9ccc2933b76b5),
+    (0x2c480973d3, 0xd5fc130c23b7aa2d, 0xa19b9a3cab811d57),
+    (0x22c469dcbe, 0x2fe8691268269aa7, 0xacc51b3fd35b883f),
+    (0x2e6533c995, 0x52fc298784d710d2, 0xd085b92a647eb512),
+    (0x2db52858c7, 0x44320747763f868c, 0xd01d569a53f4e888),
+    (0x00000001c4, 0x5016d841baa4644b, 0x8db4c7871bc3602d),
+    (0x0000000000, 0x00000796ab3c855a, 0x0e1517d71394ca12),
+    (0x0000000000, 0x0000000000002097, 0xb309321cde0be3b6),
+    (0x07ba260000, 0x0000000000000000, 0x0000000000000000),
+    (0x22cf40e921, 0x3015356022ef3216, 0x4179b6bf082ce3fe),
+    (0x219e8f2195, 0x818ae77f3c36a08c, 0xce4e0a36628033a5),
+    (0x3ab0ce2890, 0x64e3cffa15ab8bb9, 0xccc2933b76b4fa42),
+    (0x361cbdd5fc, 0x130c23b7aa2da19b, 0x9a3cab811d56fa9d),
+    (0x3ab60a2fe8, 0x691268269aa7acc5, 0x1b3fd35b883ed9c6),
+    (0x0ba5ed52fc, 0x298784d710d2d085, 0xb92a647eb5110568),
+    (0x0551a74432, 0x0747763f868cd01d, 0x569a53f4e887a6ab),
+    (0x0001c45016, 0xd841baa4644b8db4, 0xc7871bc3602cbb46),
+    (0x0000000000, 0x0796ab3c855a0e15, 0x17d71394ca11fdcf),
+    (0x0000000000, 0x000000002097b309, 0x321cde0be3b5001a),
+    (0x04f0200000, 0x0000000000000000, 0x0000000000000000),
+    (0x2057573015, 0x356022ef32164179, 0xb6bf082ce3fd84c0),
+    (0x350aad818a, 0xe77f3c36a08cce4e, 0x0a36628033a40be8),
+    (0x02d39264e3, 0xcffa15ab8bb9ccc2, 0x933b76b4fa414024),
+    (0x044922130c, 0x23b7aa2da19b9a3c, 0xab811d56fa9c85a6),
+    (0x1f8d8e6912, 0x68269aa7acc51b3f, 0xd35b883ed9c580d8),
+    (0x10a3762987, 0x84d710d2d085b92a, 0x647eb51105677ca6),
+    (0x0f4dc20747, 0x763f868cd01d569a, 0x53f4e887a6aafea9),
+    (0x231490d841, 0xbaa4644b8db4c787, 0x1bc3602cbb453d3a),
+    (0x0000000796, 0xab3c855a0e1517d7, 0x1394ca11fdce19b5),
+    (0x0000000000, 0x00002097b309321c, 0xde0be3b50019a304),
+    (0x0000000000, 0x0000000000008bfb, 0xea76c619ef3657ec),
+    (0x16622e0000, 0x0000000000000000, 0x0000000000000000),
+    (0x1d9fcf3560, 0x22ef32164179b6bf, 0x082ce3fd84bf5bba),
+    (0x3a3a34e77f, 0x3c36a08cce4e0a36, 0x628033a40be73648),
+    (0x2ac345cffa, 0x15ab8bb9ccc2933b, 0x76b4fa41402348ec),
+    (0x08fcfc23b7, 0xaa2da19b9a3cab81, 0x1d56fa9c85a535e0),
+    (0x3212ea6826, 0x9aa7acc51b3fd35b, 0x883ed9c580d75888),
+    (0x0eca6b84d7, 0x10d2d085b92a647e, 0xb51105677ca5ca8f),
+    (0x264683763f, 0x868cd01d569a53f4, 0xe887a6aafea8cb98),
+    (0x0e1753baa4, 0x644b8db4c7871bc3, 0x602cbb453d393824),
+    (0x000796ab3c, 0x855a0e1517d71394, 0xca11fdce19b4aed7),
+    (0x0000000000, 0x2097b309321cde0b, 0xe3b50019a3030a33),
+    (0x0000000000, 0x000000008bfbea76, 0xc619ef3657eb4edc),
+    (0x37b12c0000, 0x0000000000000000, 0x0000000000000000),
+    (0x0cb80822ef, 0x32164179b6bf082c, 0xe3fd84bf5bb9d3e6),
+    (0x27f0ed3c36, 0xa08cce4e0a366280, 0x33a40be73647459e),
+    (0x0a9fd615ab, 0x8bb9ccc2933b76b4, 0xfa41402348ebc591),
+    (0x270a33aa2d, 0xa19b9a3cab811d56, 0xfa9c85a535df608f),
+    (0x3115869aa7, 0xacc51b3fd35b883e, 0xd9c580d75887ffe6),
+    (0x20c91b10d2, 0xd085b92a647eb511, 0x05677ca5ca8e7681),
+    (0x119757868c, 0xd01d569a53f4e887, 0xa6aafea8cb971a7d),
+    (0x14f7b2644b, 0x8db4c7871bc3602c, 0xbb453d39382309ec),
+    (0x2351fc855a, 0x0e1517d71394ca11, 0xfdce19b4aed6bf45),
+    (0x0000002097, 0xb309321cde0be3b5, 0x0019a3030a3231c0),
+    (0x0000000000, 0x00008bfbea76c619, 0xef3657eb4edb3c56),
+    (0x0000000000, 0x000000000002593a, 0x163246e89954e9a9),
+    (0x052e8c0000, 0x0000000000000000, 0x0000000000000000),
+    (0x20c8af3216, 0x4179b6bf082ce3fd, 0x84bf5bb9d3e589a1),
+    (0x2b4288a08c, 0xce4e0a36628033a4, 0x0be73647459d41ef),
+    (0x243aa18bb9, 0xccc2933b76b4fa41, 0x402348ebc590999c),
+    (0x2de475a19b, 0x9a3cab811d56fa9c, 0x85a535df608eed90),
+    (0x1f2287acc5, 0x1b3fd35b883ed9c5, 0x80d75887ffe5cab7),
+    (0x06d4b2d085, 0xb92a647eb5110567, 0x7ca5ca8e7680a354),
+    (0x2a174ad01d, 0x569a53f4e887a6aa, 0xfea8cb971a7c381e),
+    (0x04e93f8db4, 0xc7871bc3602cbb45, 0x3d39382309eb172f),
+    (0x02241c0e15, 0x17d71394ca11fdce, 0x19b4aed6bf442c49),
+    (0x002097b309, 0x321cde0be3b50019, 0xa3030a3231bf90b0),
+    (0x0000000000, 0x8bfbea76c619ef36, 0x57eb4edb3c55b65b),
+    (0x0000000000, 0x00000002593a1632, 0x46e89954e9a81fe4),
+    (0x0000000000, 0x000000000000000a, 0x163ff802a3426a8d),
+    (0x1c56b60000, 0x0000000000000000, 0x0000000000000000),
+    (0x122b8a4179, 0xb6bf082ce3fd84bf, 0x5bb9d3e589a0ece4),
+    (0x2d4d94ce4e, 0x0a36628033a40be7, 0x3647459d41eefc20),
+    (0x0e2035ccc2, 0x933b76b4fa414023, 0x48ebc590999b3443),
+    (0x0edc4d9a3c, 0xab811d56fa9c85a5, 0x35df608eed8f8e22),
+    (0x09decb1b3f, 0xd35b883ed9c580d7, 0x5887ffe5cab6b936),
+    (0x23eea9b92a, 0x647eb51105677ca5, 0xca8e7680a3538142),
+    (0x1f7517569a, 0x53f4e887a6aafea8, 0xcb971a7c381d07a6),
+    (0x32138ec787, 0x1bc3602cbb453d39, 0x382309eb172e3e61),
+    (0x06b8a117d7, 0x1394ca11fdce19b4, 0xaed6bf442c480fbe),
+    (0x3aa75b321c, 0xde0be3b50019a303, 0x0a3231bf90aff414),
+    (0x0000008bfb, 0xea76c619ef3657eb, 0x4edb3c55b65a883e),
+    (0x0000000000, 0x0002593a163246e8, 0x9954e9a81fe35444),
+    (0x0000000000, 0x00000000000a163f, 0xf802a3426a8ca07d),
+    (0x2c7a640000, 0x0000000000000000, 0x0000000000000000),
+    (0x0ca995b6bf, 0x082ce3fd84bf5bb9, 0xd3e589a0ece3dd4f),
+    (0x0dd8500a36, 0x628033a40be73647, 0x459d41eefc1fa7ae),
+    (0x1e0fd4933b, 0x76b4fa41402348eb, 0xc590999b34429ee6),
+    (0x154fbeab81, 0x1d56fa9c85a535df, 0x608eed8f8e21f320),
+    (0x1d8057d35b, 0x883ed9c580d75887, 0xffe5cab6b9358329),
+    (0x3905ac647e, 0xb51105677ca5ca8e, 0x7680a3538141b711),
+    (0x2d7d4c53f4, 0xe887a6aafea8cb97, 0x1a7c381d07a5741f),
+    (0x22a4891bc3, 0x602cbb453d393823, 0x09eb172e3e605ad2),
+    (0x02c4ab1394, 0xca11fdce19b4aed6, 0xbf442c480fbddfa1),
+    (0x1ba778de0b, 0xe3b50019a3030a32, 0x31bf90aff4138edc),
+    (0x008bfbea76, 0xc619ef3657eb4edb, 0x3c55b65a883d7eab),
+    (0x0000000002, 0x593a163246e89954, 0xe9a81fe35443e1c0),
+    (0x0000000000, 0x0000000a163ff802, 0xa3426a8ca07c2dcc),
+    (0x0000000000, 0x000000000000002b, 0x52adc44bace4a762),
+    (0x0a73b00000, 0x0000000000000000, 0x0000000000000000),
+    (0x1dd96b082c, 0xe3fd84bf5bb9d3e5, 0x89a0ece3dd4e6909),
+    (0x2a231a6280, 0x33a40be73647459d, 0x41eefc1fa7ade5d7),
+    (0x14405976b4, 0xfa41402348ebc590, 0x999b34429ee53c15),
+    (0x0ea2311d56, 0xfa9c85a535df608e, 0xed8f8e21f31f1482),
+    (0x03f689883e, 0xd9c580d75887ffe5, 0xcab6b9358328ea6b),
+    (0x1f956eb511, 0x05677ca5ca8e7680, 0xa3538141b7104103),
+    (0x0a6a34e887, 0xa6aafea8cb971a7c, 0x381d07a5741ecc62),
+    (0x04305f602c, 0xbb453d39382309eb, 0x172e3e605ad1dc29),
+    (0x2a2476ca11, 0xfdce19b4aed6bf44, 0x2c480fbddfa0185d),
+    (0x0609cfe3b5, 0x0019a3030a3231bf, 0x90aff4138edbf1b6),
+    (0x0d863cc619, 0xef3657eb4edb3c55, 0xb65a883d7eaa3b8d),
+    (0x000002593a, 0x163246e89954e9a8, 0x1fe35443e1bfa421),
+    (0x0000000000, 0x000a163ff802a342, 0x6a8ca07c2dcb0cf3),
+    (0x0000000000, 0x00000000002b52ad, 0xc44bace4a761b05c),
+    (0x3b06e20000, 0x0000000000000000, 0x0000000000000000),
+    (0x2631eee3fd, 0x84bf5bb9d3e589a0, 0xece3dd4e69087102),
+    (0x0f313c33a4, 0x0be73647459d41ee, 0xfc1fa7ade5d649f3),
+    (0x247170fa41, 0x402348ebc590999b, 0x34429ee53c141ad0),
+    (0x2dfc1cfa9c, 0x85a535df608eed8f, 0x8e21f31f148122db),
+    (0x18184cd9c5, 0x80d75887ffe5cab6, 0xb9358328ea6af230),
+    (0x27d0150567, 0x7ca5ca8e7680a353, 0x8141b710410283b1),
+    (0x101ef9a6aa, 0xfea8cb971a7c381d, 0x07a5741ecc617a2b),
+    (0x2ffc8abb45, 0x3d39382309eb172e, 0x3e605ad1dc28e0ce),
+    (0x05f811fdce, 0x19b4aed6bf442c48, 0x0fbddfa0185cbe13),
+    (0x0ca71f0019, 0xa3030a3231bf90af, 0xf4138edbf1b5e3b9),
+    (0x0bb4bdef36, 0x57eb4edb3c55b65a, 0x883d7eaa3b8ca1ba),
+    (0x02593a1632, 0x46e89954e9a81fe3, 0x5443e1bfa420bc98),
+    (0x000000000a, 0x163ff802a3426a8c, 0xa07c2dcb0cf26f7b),
+    (0x0000000000, 0x0000002b52adc44b, 0xace4a761b05b2635),
+    (0x0000000000, 0x00000000000000ba, 0x121a4650e4ddeb93),
+    (0x2c8dd80000, 0x0000000000000000, 0x0000000000000000),
+    (0x35d54f84bf, 0x5bb9d3e589a0ece3, 0xdd4e690871018ebb),
+    (0x0837240be7, 0x3647459d41eefc1f, 0xa7ade5d649f2497a),
+    (0x26774f4023, 0x48ebc590999b3442, 0x9ee53c141acfdbbb),
+    (0x32239c85a5, 0x35df608eed8f8e21, 0xf31f148122da8f9e),
+    (0x2699ed80d7, 0x5887ffe5cab6b935, 0x8328ea6af22fbd8d),
+    (0x274cfb7ca5, 0xca8e7680a3538141, 0xb710410283b0a5ee),
+    (0x0d5288fea8, 0xcb971a7c381d07a5, 0x741ecc617a2aba2f),
+    (0x2a118b3d39, 0x382309eb172e3e60, 0x5ad1dc28e0cd47a3),
+    (0x00b53019b4, 0xaed6bf442c480fbd, 0xdfa0185cbe12d3fa),
+    (0x36c973a303, 0x0a3231bf90aff413, 0x8edbf1b5e3b8c588),
+    (0x3627fa57eb, 0x4edb3c55b65a883d, 0x7eaa3b8ca1b93b53),
+    (0x0ee4d646e8, 0x9954e9a81fe35443, 0xe1bfa420bc97a718),
+    (0x00000a163f, 0xf802a3426a8ca07c, 0x2dcb0cf26f7ab7b8),
+    (0x0000000000, 0x002b52adc44bace4, 0xa761b05b2634b255),
+    (0x0000000000, 0x0000000000ba121a, 0x4650e4ddeb92f34e),
+    (0x2b44320000, 0x0000000000000000, 0x0000000000000000),
+    (0x1098235bb9, 0xd3e589a0ece3dd4e, 0x690871018eba3159),
+    (0x36e5173647, 0x459d41eefc1fa7ad, 0xe5d649f24979c251),
+    (0x34025148eb, 0xc590999b34429ee5, 0x3c141acfdbbafc05),
+    (0x1faa3535df, 0x608eed8f8e21f31f, 0x148122da8f9d4d83),
+    (0x212b735887, 0xffe5cab6b9358328, 0xea6af22fbd8cc194),
+    (0x232e77ca8e, 0x7680a3538141b710, 0x410283b0a5ed881c),
+    (0x082878cb97, 0x1a7c381d07a5741e, 0xcc617a2aba2edb85),
+    (0x31ffed3823, 0x09eb172e3e605ad1, 0xdc28e0cd47a24e22),
+    (0x0bafd0aed6, 0xbf442c480fbddfa0, 0x185cbe12d3f98416),
+    (0x330b770a32, 0x31bf90aff4138edb, 0xf1b5e3b8c5870899),
+    (0x13c5314edb, 0x3c55b65a883d7eaa, 0x3b8ca1b93b52a16f),
+    (0x009d389954, 0xe9a81fe35443e1bf, 0xa420bc97a717a7b1),
+    (0x0a163ff802, 0xa3426a8ca07c2dcb, 0x0cf26f7ab7b7ea44),
+    (0x000000002b, 0x52adc44bace4a761, 0xb05b2634b254f189),
+    (0x0000000000, 0x000000ba121a4650, 0xe4ddeb92f34d6262),
+    (0x0000000000, 0x000000000000031f, 0x2ae9b9f14e0b23fa),
+    (0x3571820000, 0x0000000000000000, 0x0000000000000000),
+    (0x249d47d3e5, 0x89a0ece3dd4e6908, 0x71018eba31588166),
+    (0x21aad5459d, 0x41eefc1fa7ade5d6, 0x49f24979c250a8b3),
+    (0x21472bc590, 0x999b34429ee53c14, 0x1acfdbbafc04f170),
+    (0x3580a9608e, 0xed8f8e21f31f1481, 0x22da8f9d4d82a4cb),
+    (0x1df36bffe5, 0xcab6b9358328ea6a, 0xf22fbd8cc193ba96),
+    (0x1cec0a7680, 0xa3538141b7104102, 0x83b0a5ed881bebd9),
+    (0x36bc7d1a7c, 0x381d07a5741ecc61, 0x7a2aba2edb84dc61),
+    (0x0fdf6d09eb, 0x172e3e605ad1dc28, 0xe0cd47a24e213ac5),
+    (0x2c9a2cbf44, 0x2c480fbddfa0185c, 0xbe12d3f984156eba),
+    (0x13f4da31bf, 0x90aff4138edbf1b5, 0xe3b8c587089854db),
+    (0x2725013c55, 0xb65a883d7eaa3b8c, 0xa1b93b52a16e782a),
+    (0x0f76b6e9a8, 0x1fe35443e1bfa420, 0xbc97a717a7b08e4d),
+    (0x28754ea342, 0x6a8ca07c2dcb0cf2, 0x6f7ab7b7ea43b86e),
+    (0x00002b52ad, 0xc44bace4a761b05b, 0x2634b254f1883939),
+    (0x0000000000, 0x00ba121a4650e4dd, 0xeb92f34d62616ce5),
+    (0x0000000000, 0x00000000031f2ae9, 0xb9f14e0b23f99295),
+    (0x2577e60000, 0x0000000000000000, 0x0000000000000000),
+    (0x37a49b89a0, 0xece3dd4e69087101, 0x8eba315881653427),
+    (0x2422e341ee, 0xfc1fa7ade5d649f2, 0x4979c250a8b22feb),
+    (0x216c8e999b, 0x34429ee53c141acf, 0xdbbafc04f16f2b1b),
+    (0x2a8b2aed8f, 0x8e21f31f148122da, 0x8f9d4d82a4cae9f8),
+    (0x137147cab6, 0xb9358328ea6af22f, 0xbd8cc193ba95aa4f),
+    (0x06c250a353, 0x8141b710410283b0, 0xa5ed881bebd84b5a),
+    (0x0ac456381d, 0x07a5741ecc617a2a, 0xba2edb84dc600205),
+    (0x10c733172e, 0x3e605ad1dc28e0cd, 0x47a24e213ac47d9a),
+    (0x28f7a42c48, 0x0fbddfa0185cbe12, 0xd3f984156eb9a53f),
+    (0x15142390af, 0xf4138edbf1b5e3b8, 0xc587089854da2251),
+    (0x34eddfb65a, 0x883d7eaa3b8ca1b9, 0x3b52a16e78299289),
+    (0x20be041fe3, 0x5443e1bfa420bc97, 0xa717a7b08e4cdce6),
+    (0x119e9a6a8c, 0xa07c2dcb0cf26f7a, 0xb7b7ea43b86daa11),
+    (0x2b52adc44b, 0xace4a761b05b2634, 0xb254f18839386d37),
+    (0x00000000ba, 0x121a4650e4ddeb92, 0xf34d62616ce41322),
+    (0x0000000000, 0x0000031f2ae9b9f1, 0x4e0b23f99294bba6),
+    (0x0000000000, 0x0000000000000d68, 0x6619ba27255a2c81),
+    (0x350dc80000, 0x0000000000000000, 0x0000000000000000),
+    (0x075ec8ece3, 0xdd4e690871018eba, 0x3158816534261126),
+    (0x0f82e6fc1f, 0xa7ade5d649f24979, 0xc250a8b22feaa6be),
+    (0x07e78f3442, 0x9ee53c141acfdbba, 0xfc04f16f2b1a15ac),
+    (0x0c75958e21, 0xf31f148122da8f9d, 0x4d82a4cae9f7b11b),
+    (0x0f4cecb935, 0x8328ea6af22fbd8c, 0xc193ba95aa4e8740),
+    (0x2685cd8141, 0xb710410283b0a5ed, 0x881bebd84b59f310),
+    (0x166f3107a5, 0x741ecc617a2aba2e, 0xdb84dc60020421b6),
+    (0x2c35583e60, 0x5ad1dc28e0cd47a2, 0x4e213ac47d9900ff),
+    (0x048dc00fbd, 0xdfa0185cbe12d3f9, 0x84156eb9a53e3833),
+    (0x0e515ff413, 0x8edbf1b5e3b8c587, 0x089854da2250ffa9),
+    (0x0fce12883d, 0x7eaa3b8ca1b93b52, 0xa16e782992882873),
+    (0x1ce9a35443, 0xe1bfa420bc97a717, 0xa7b08e4cdce5b08c),
+    (0x353cd4a07c, 0x2dcb0cf26f7ab7b7, 0xea43b86daa102472),
+    (0x061e17ace4, 0xa761b05b2634b254, 0xf18839386d363b24),
+    (0x0000ba121a, 0x4650e4ddeb92f34d, 0x62616ce41321a019),
+    (0x0000000000, 0x031f2ae9b9f14e0b, 0x23f99294bba5ae40),
+    (0x0000000000, 0x000000000d686619, 0xba27255a2c80a538),
+    (0x12419e0000, 0x0000000000000000, 0x0000000000000000),
+    (0x287d1ddd4e, 0x690871018eba3158, 0x8165342611258611),
+    (0x355f73a7ad, 0xe5d649f24979c250, 0xa8b22feaa6bdcdac),
+    (0x073c849ee5, 0x3c141acfdbbafc04, 0xf16f2b1a15abafe4),
+    (0x0986c3f31f, 0x148122da8f9d4d82, 0xa4cae9f7b11ad589),
+    (0x03c8cf8328, 0xea6af22fbd8cc193, 0xba95aa4e873f4ed2),
+    (0x072bb9b710, 0x410283b0a5ed881b, 0xebd84b59f30f134f),
+    (0x30dca1741e, 0xcc617a2aba2edb84, 0xdc60020421b52eb2),
+    (0x25466a5ad1, 0xdc28e0cd47a24e21, 0x3ac47d9900fe9c7f),
+    (0x3335b1dfa0, 0x185cbe12d3f98415, 0x6eb9a53e383249c8),
+    (0x27a2a78edb, 0xf1b5e3b8c5870898, 0x54da2250ffa8f276),
+    (0x2c9eb37eaa, 0x3b8ca1b93b52a16e, 0x7829928828726654),
+    (0x2f46c1e1bf, 0xa420bc97a717a7b0, 0x8e4cdce5b08b69b5),
+    (0x16beb62dcb, 0x0cf26f7ab7b7ea43, 0xb86daa102471b0cf),
+    (0x12d5a8a761, 0xb05b2634b254f188, 0x39386d363b23fee4),
+    (0x0741bc4650, 0xe4ddeb92f34d6261, 0x6ce41321a0183e11),
+    (0x000000031f, 0x2ae9b9f14e0b23f9, 0x9294bba5ae3f0330),
+    (0x0000000000, 0x00000d686619ba27, 0x255a2c80a537b0f0),
+    (0x0000000000, 0x0000000000003996, 0x0a6cc11ac2be832e),
+    (0x01f7260000, 0x0000000000000000, 0x0000000000000000),
+    (0x31e49c6908, 0x71018eba31588165, 0x3426112586103eac),
+    (0x04ae55e5d6, 0x49f24979c250a8b2, 0x2feaa6bdcdabb8a9),
+    (0x1e111d3c14, 0x1acfdbbafc04f16f, 0x2b1a15abafe33ddb),
+    (0x18e67b1481, 0x22da8f9d4d82a4ca, 0xe9f7b11ad5887e24),
+    (0x0095deea6a, 0xf22fbd8cc193ba95, 0xaa4e873f4ed1ac9b),
+    (0x16b2184102, 0x83b0a5ed881bebd8, 0x4b59f30f134e2fa5),
+    (0x098486cc61, 0x7a2aba2edb84dc60, 0x020421b52eb172d9),
+    (0x1597e1dc28, 0xe0cd47a24e213ac4, 0x7d9900fe9c7e3562),
+    (0x2a5976185c, 0xbe12d3f984156eb9, 0xa53e383249c75632),
+    (0x20be4df1b5, 0xe3b8c587089854da, 0x2250ffa8f27564ef),
+    (0x079f423b8c, 0xa1b93b52a16e7829, 0x9288287266533380),
+    (0x387247a420, 0xbc97a717a7b08e4c, 0xdce5b08b69b4592a),
+    (0x1538eb0cf2, 0x6f7ab7b7ea43b86d, 0xaa102471b0ce9f66),
+    (0x33ab79b05b, 0x2634b254f1883938, 0x6d363b23fee36616),
+    (0x366b2ce4dd, 0xeb92f34d62616ce4, 0x1321a0183e10583d),
+    (0x00031f2ae9, 0xb9f14e0b23f99294, 0xbba5ae3f032fad21),
+    (0x0000000000, 0x0d686619ba27255a, 0x2c80a537b0efefec),
+    (0x0000000000, 0x0000000039960a6c, 0xc11ac2be832d2969),
+    (0x005cd60000, 0x0000000000000000, 0x0000000000000000),
+    (0x38696e7101, 0x8eba315881653426, 0x112586103eab5040),
+    (0x30655249f2, 0x4979c250a8b22fea, 0xa6bdcdabb8a8f616),
+    (0x32e3401acf, 0xdbbafc04f16f2b1a, 0x15abafe33dda325d),
+    (0x0b1d8d22da, 0x8f9d4d82a4cae9f7, 0xb11ad5887e232c41),
+    (0x29210cf22f, 0xbd8cc193ba95aa4e, 0x873f4ed1ac9a47b9),
+    (0x09f2be83b0, 0xa5ed881bebd84b59, 0xf30f134e2fa4ae41),
+    (0x2fcba17a2a, 0xba2edb84dc600204, 0x21b52eb172d8d161),
+    (0x04623ce0cd, 0x47a24e213ac47d99, 0x00fe9c7e35618eb1),
+    (0x2b053ebe12, 0xd3f984156eb9a53e, 0x383249c75631c599),
+    (0x2b20abe3b8, 0xc587089854da2250, 0xffa8f27564ee62a5),
+    (0x234f34a1b9, 0x3b52a16e78299288, 0x28726653337f3560),
+    (0x1c928abc97, 0xa717a7b08e4cdce5, 0xb08b69b4592909ea),
+    (0x0c44f66f7a, 0xb7b7ea43b86daa10, 0x2471b0ce9f65e8ae),
+    (0x2236ed2634, 0xb254f18839386d36, 0x3b23fee366150b47),
+    (0x382ee3eb92, 0xf34d62616ce41321, 0xa0183e10583cd332),
+    (0x184ea7b9f1, 0x4e0b23f99294bba5, 0xae3f032fad207090),
+    (0x0000000d68, 0x6619ba27255a2c80, 0xa537b0efefebdd3b),
+    (0x0000000000, 0x000039960a6cc11a, 0xc2be832d2968c44b),
+    (0x0000000000, 0x000000000000f754, 0x9530e188c128d12c),
+    (0x2b59f40000, 0x0000000000000000, 0x0000000000000000),
+    (0x1c59678eba, 0x3158816534261125, 0x86103eab503f216d),
+    (0x2b89244979, 0xc250a8b22feaa6bd, 0xcdabb8a8f615373f),
+    (0x249ff9dbba, 0xfc04f16f2b1a15ab, 0xafe33dda325c3a53),
+    (0x20c5b88f9d, 0x4d82a4cae9f7b11a, 0xd5887e232c4082ba),
+    (0x374dfdbd8c, 0xc193ba95aa4e873f, 0x4ed1ac9a47b8e308),
+    (0x0ae8eca5ed, 0x881bebd84b59f30f, 0x134e2fa4ae40ae6a),
+    (0x25ca24ba2e, 0xdb84dc60020421b5, 0x2eb172d8d1601cb5),
+    (0x3a184f47a2, 0x4e213ac47d9900fe, 0x9c7e35618eb04ef4),
+    (0x1f70a0d3f9, 0x84156eb9a53e3832, 0x49c75631c5982b14),
+    (0x133d2ac587, 0x089854da2250ffa8, 0xf27564ee62a46e67),
+    (0x0581f33b52, 0xa16e782992882872, 0x6653337f355f140f),
+    (0x2ad231a717, 0xa7b08e4cdce5b08b, 0x69b4592909e9ae01),
+    (0x13c706b7b7, 0xea43b86daa102471, 0xb0ce9f65e8ad6934),
+    (0x120476b254, 0xf18839386d363b23, 0xfee366150b46715c),
+    (0x029806f34d, 0x62616ce41321a018, 0x3e10583cd33148db),
+    (0x05fd754e0b, 0x23f99294bba5ae3f, 0x032fad20708f579d),
+    (0x000d686619, 0xba27255a2c80a537, 0xb0efefebdd3a7f74),
+    (0x0000000000, 0x39960a6cc11ac2be, 0x832d2968c44a9445),
+    (0x0000000000, 0x00000000f7549530, 0xe188c128d12bee5a),
+    (0x149b560000, 0x0000000000000000, 0x0000000000000000),
+    (0x12c7a63158, 0x8165342611258610, 0x3eab503f216cd0fe),
+    (0x0126a1c250, 0xa8b22feaa6bdcdab, 0xb8a8f615373e3962),
+    (0x1e6af8fc04, 0xf16f2b1a15abafe3, 0x3dda325c3a52e281),
+    (0x2265534d82, 0xa4cae9f7b11ad588, 0x7e232c4082b91525),
+    (0x06803cc193, 0xba95aa4e873f4ed1, 0xac9a47b8e3076f40),
+    (0x1eef1f881b, 0xebd84b59f30f134e, 0x2fa4ae40ae69892c),
+    (0x07bca2db84, 0xdc60020421b52eb1, 0x72d8d1601cb4642c),
+    (0x04fd7a4e21, 0x3ac47d9900fe9c7e, 0x35618eb04ef3dee9),
+    (0x2377a98415, 0x6eb9a53e383249c7, 0x5631c5982b13d7ea),
+    (0x19b87d0898, 0x54da2250ffa8f275, 0x64ee62a46e66747f),
+    (0x291b8ca16e, 0x7829928828726653, 0x337f355f140e4f03),
+    (0x0fc473a7b0, 0x8e4cdce5b08b69b4, 0x592909e9ae00a388),
+    (0x1fb78dea43, 0xb86daa102471b0ce, 0x9f65e8ad6933b9a6),
+    (0x16af00f188, 0x39386d363b23fee3, 0x66150b46715bb234),
+    (0x3a1baf6261, 0x6ce41321a0183e10, 0x583cd33148da6149),
+    (0x091d4723f9, 0x9294bba5ae3f032f, 0xad20708f579c45aa),
+    (0x22ef1fba27, 0x255a2c80a537b0ef, 0xefebdd3a7f737777),
+    (0x0000003996, 0x0a6cc11ac2be832d, 0x2968c44a9444a8ee),
+    (0x0000000000, 0x0000f7549530e188, 0xc128d12bee59e68f),
+    (0x0000000000, 0x0000000000042646, 0xa6fe9631f9d94f67),
+    (0x1790360000, 0x0000000000000000, 0x0000000000000000),
+    (0x297cc88165, 0x3426112586103eab, 0x503f216cd0fd77d5),
+    (0x19e226a8b2, 0x2feaa6bdcdabb8a8, 0xf615373e3961af3a),
+    (0x2f3e9cf16f, 0x2b1a15abafe33dda, 0x325c3a52e28042a9),
+    (0x22d8bea4ca, 0xe9f7b11ad5887e23, 0x2c4082b91524bcec),
+    (0x325c25ba95, 0xaa4e873f4ed1ac9a, 0x47b8e3076f3fdf88),
+    (0x165a83ebd8, 0x4b59f30f134e2fa4, 0xae40ae69892bcdda),
+    (0x2dbfc0dc60, 0x020421b52eb172d8, 0xd1601cb4642bbf28),
+    (0x2b39553ac4, 0x7d9900fe9c7e3561, 0x8eb04ef3dee8863e),
+    (0x32c9cd6eb9, 0xa53e383249c75631, 0xc5982b13d7e94ad4),
+    (0x09ade854da, 0x2250ffa8f27564ee, 0x62a46e66747ee530),
+    (0x11e5627829, 0x928828726653337f, 0x355f140e4f02a6e2),
+    (0x1988808e4c, 0xdce5b08b69b45929, 0x09e9ae00a38761fa),
+    (0x0d2349b86d, 0xaa102471b0ce9f65, 0xe8ad6933b9a5b136),
+    (0x351a503938, 0x6d363b23fee36615, 0x0b46715bb2337397),
+    (0x22a12d6ce4, 0x1321a0183e10583c, 0xd33148da61480e1c),
+    (0x14fec79294, 0xbba5ae3f032fad20, 0x708f579c45a9861a),
+    (0x195243255a, 0x2c80a537b0efefeb, 0xdd3a7f737776be8b),
+    (0x0039960a6c, 0xc11ac2be832d2968, 0xc44a9444a8ed586d),
+    (0x0000000000, 0xf7549530e188c128, 0xd12bee59e68ef47d),
+    (0x0000000000, 0x000000042646a6fe, 0x9631f9d94f66cfa1),
+    (0x0000000000, 0x0000000000000011, 0xd270cc51055ea7cb),
+    (0x08dcf80000, 0x0000000000000000, 0x0000000000000000),
+    (0x36a24f3426, 0x112586103eab503f, 0x216cd0fd77d43bc6),
+    (0x0612c42fea, 0xa6bdcdabb8a8f615, 0x373e3961af39d458),
+    (0x39ab952b1a, 0x15abafe33dda325c, 0x3a52e28042a8de27),
+    (0x1248a6e9f7, 0xb11ad5887e232c40, 0x82b91524bceb6312),
+    (0x14f167aa4e, 0x873f4ed1ac9a47b8, 0xe3076f3fdf87720f),
+    (0x00f6444b59, 0xf30f134e2fa4ae40, 0xae69892bcdd91f49),
+    (0x239fbe0204, 0x21b52eb172d8d160, 0x1cb4642bbf272a6f),
+    (0x0e7c927d99, 0x00fe9c7e35618eb0, 0x4ef3dee8863d720c),
+    (0x12dbe5a53e, 0x383249c75631c598, 0x2b13d7e94ad3a2f1),
+    (0x1fb3a62250, 0xffa8f27564ee62a4, 0x6e66747ee52f1050),
+    (0x357a919288, 0x28726653337f355f, 0x140e4f02a6e182da),
+    (0x03c228dce5, 0xb08b69b4592909e9, 0xae00a38761f9afbb),
+    (0x0ca06baa10, 0x2471b0ce9f65e8ad, 0x6933b9a5b135596b),
+    (0x07d43a6d36, 0x3b23fee366150b46, 0x715bb23373961aab),
+    (0x2693561321, 0xa0183e10583cd331, 0x48da61480e1b914c),
+    (0x1df3dcbba5, 0xae3f032fad20708f, 0x579c45a98619cbb7),
+    (0x063b102c80, 0xa537b0efefebdd3a, 0x7f737776be8aa47f),
+    (0x13b186c11a, 0xc2be832d2968c44a, 0x9444a8ed586c72c9),
+    (0x000000f754, 0x9530e188c128d12b, 0xee59e68ef47ce570),
+    (0x0000000000, 0x00042646a6fe9631, 0xf9d94f66cfa00210),
+    (0x0000000000, 0x000000000011d270, 0xcc51055ea7ca8fd7),
+    (0x08bc960000, 0x0000000000000000, 0x0000000000000000),
+    (0x2c312a1125, 0x86103eab503f216c, 0xd0fd77d43bc5c2b2),
+    (0x27f958a6bd, 0xcdabb8a8f615373e, 0x3961af39d4573798),
+    (0x063f7015ab, 0xafe33dda325c3a52, 0xe28042a8de262f94),
+    (0x0ebd71b11a, 0xd5887e232c4082b9, 0x1524bceb63110e05),
+    (0x038290873f, 0x4ed1ac9a47b8e307, 0x6f3fdf87720e1bed),
+    (0x2a2f4ff30f, 0x134e2fa4ae40ae69, 0x892bcdd91f48989e),
+    (0x3b480421b5, 0x2eb172d8d1601cb4, 0x642bbf272a6e1a9e),
+    (0x0bb56900fe, 0x9c7e35618eb04ef3, 0xdee8863d720b8781),
+    (0x2756083832, 0x49c75631c5982b13, 0xd7e94ad3a2f0dc52),
+    (0x1aa2c0ffa8, 0xf27564ee62a46e66, 0x747ee52f104f9831),
+    (0x2075a82872, 0x6653337f355f140e, 0x4f02a6e182d9fad2),
+    (0x1a787db08b, 0x69b4592909e9ae00, 0xa38761f9afba3d8e),
+    (0x0861822471, 0xb0ce9f65e8ad6933, 0xb9a5b135596a4bd6),
+    (0x05a4f63b23, 0xfee366150b46715b, 0xb23373961aaa21f2),
+    (0x0eabd5a018, 0x3e10583cd33148da, 0x61480e1b914b0c21),
+    (0x0cf773ae3f, 0x032fad20708f579c, 0x45a98619cbb6e76d),
+    (0x2c5b6ca537, 0xb0efefebdd3a7f73, 0x7776be8aa47e9438),
+    (0x0b6188c2be, 0x832d2968c44a9444, 0xa8ed586c72c820e6),
+    (0x00f7549530, 0xe188c128d12bee59, 0xe68ef47ce56fd516),
+    (0x0000000004, 0x2646a6fe9631f9d9, 0x4f66cfa0020f039c),
+    (0x0000000000, 0x00000011d270cc51, 0x055ea7ca8fd68f6f),
+    (0x0000000000, 0x000000000000004c, 0x8b888296c5f9e2bb),
+    (0x03c8440000, 0x0000000000000000, 0x0000000000000000),
+    (0x27fb638610, 0x3eab503f216cd0fd, 0x77d43bc5c2b12ed1),
+    (0x34e8a7cdab, 0xb8a8f615373e3961, 0xaf39d4573797bd4b),
+    (0x1fc799afe3, 0x3dda325c3a52e280, 0x42a8de262f93dca5),
+    (0x30d18ed588, 0x7e232c4082b91524, 0xbceb63110e043f11),
+    (0x1234414ed1, 0xac9a47b8e3076f3f, 0xdf87720e1bec494d),
+    (0x2950c3134e, 0x2fa4ae40ae69892b, 0xcdd91f48989dbdca),
+    (0x1d629d2eb1, 0x72d8d1601cb4642b, 0xbf272a6e1a9d3725),
+    (0x2e69649c7e, 0x35618eb04ef3dee8, 0x863d720b87803e55),
+    (0x1b553e49c7, 0x5631c5982b13d7e9, 0x4ad3a2f0dc51d033),
+    (0x135f2cf275, 0x64ee62a46e66747e, 0xe52f104f9830f070),
+    (0x29fd986653, 0x337f355f140e4f02, 0xa6e182d9fad10fc9),
+    (0x301bab69b4, 0x592909e9ae00a387, 0x61f9afba3d8d245a),
+    (0x34f205b0ce, 0x9f65e8ad6933b9a5, 0xb135596a4bd572ee),
+    (0x03ce37fee3, 0x66150b46715bb233, 0x73961aaa21f19d71),
+    (0x0b19ba3e10, 0x583cd33148da6148, 0x0e1b914b0c20efdd),
+    (0x37dd5f032f, 0xad20708f579c45a9, 0x8619cbb6e76c9969),
+    (0x0a83d9b0ef, 0xefebdd3a7f737776, 0xbe8aa47e943758d0),
+    (0x1fd734832d, 0x2968c44a9444a8ed, 0x586c72c820e5b373),
+    (0x107334e188, 0xc128d12bee59e68e, 0xf47ce56fd515c9ed),
+    (0x0000042646, 0xa6fe9631f9d94f66, 0xcfa0020f039bad4d),
+    (0x0000000000, 0x0011d270cc51055e, 0xa7ca8fd68f6e505e),
+    (0x0000000000, 0x00000000004c8b88, 0x8296c5f9e2ba8def),
+    (0x27e3140000, 0x0000000000000000, 0x0000000000000000),
+    (0x2756e03eab, 0x503f216cd0fd77d4, 0x3bc5c2b12ed066d0),
+    (0x32cd89b8a8, 0xf615373e3961af39, 0xd4573797bd4a732d),
+    (0x0830773dda, 0x325c3a52e28042a8, 0xde262f93dca414c7),
+    (0x23ebd07e23, 0x2c4082b91524bceb, 0x63110e043f10e8c2),
+    (0x2657bbac9a, 0x47b8e3076f3fdf87, 0x720e1bec494c01fc),
+    (0x327b5a2fa4, 0xae40ae69892bcdd9, 0x1f48989dbdc9a450),
+    (0x16b6df72d8, 0xd1601cb4642bbf27, 0x2a6e1a9d3724538b),
+    (0x0b96a23561, 0x8eb04ef3dee8863d, 0x720b87803e544002),
+    (0x3780df5631, 0xc5982b13d7e94ad3, 0xa2f0dc51d032e441),
+    (0x1d9e3764ee, 0x62a46e66747ee52f, 0x104f9830f06f1d55),
+    (0x098e19337f, 0x355f140e4f02a6e1, 0x82d9fad10fc83114),
+    (0x17ba3e5929, 0x09e9ae00a38761f9, 0xafba3d8d2459fff8),
+    (0x04e6529f65, 0xe8ad6933b9a5b135, 0x596a4bd572ed41b4),
+    (0x0e29736615, 0x0b46715bb2337396, 0x1aaa21f19d708a6f),
+    (0x078d8e583c, 0xd33148da61480e1b, 0x914b0c20efdca4a7),
+    (0x36ea99ad20, 0x708f579c45a98619, 0xcbb6e76c996871e6),
+    (0x186495efeb, 0xdd3a7f737776be8a, 0xa47e943758cf6eec),
+    (0x0980e32968, 0xc44a9444a8ed586c, 0x72c820e5b3727874),
+    (0x3b468cc128, 0xd12bee59e68ef47c, 0xe56fd515c9ec542e),
+    (0x042646a6fe, 0x9631f9d94f66cfa0, 0x020f039bad4cfbcb),
+    (0x0000000011, 0xd270cc51055ea7ca, 0x8fd68f6e505dd389),
+    (0x0000000000, 0x0000004c8b888296, 0xc5f9e2ba8dee8a97),
+    (0x0000000000, 0x0000000000000148, 0xc22ca71a1bd6f0a6),
+    (0x0eb2e00000, 0x0000000000000000, 0x0000000000000000),
+    (0x04f08f503f, 0x216cd0fd77d43bc5, 0xc2b12ed066cf05d1),
+    (0x157934f615, 0x373e3961af39d457, 0x3797bd4a732c0ee5),
+    (0x0f0522325c, 0x3a52e28042a8de26, 0x2f93dca414c699b8),
+    (0x2b0c1d2c40, 0x82b91524bceb6311, 0x0e043f10e8c11944),
+    (0x0f0f4e47b8, 0xe3076f3fdf87720e, 0x1bec494c01fbff9a),
+    (0x1660baae40, 0xae69892bcdd91f48, 0x989dbdc9a44f8e44),
+    (0x290cccd160, 0x1cb4642bbf272a6e, 0x1a9d3724538a14da),
+    (0x236def8eb0, 0x4ef3dee8863d720b, 0x87803e5440010475),
+    (0x2648ddc598, 0x2b13d7e94ad3a2f0, 0xdc51d032e440212f),
+    (0x16231c62a4, 0x6e66747ee52f104f, 0x9830f06f1d54944a),
+    (0x366865355f, 0x140e4f02a6e182d9, 0xfad10fc8311352d1),
+    (0x28601909e9, 0xae00a38761f9afba, 0x3d8d2459fff79b95),
+    (0x1066b7e8ad, 0x6933b9a5b135596a, 0x4bd572ed41b3ab13),
+    (0x0b79970b46, 0x715bb23373961aaa, 0x21f19d708a6e17bc),
+    (0x215fdcd331, 0x48da61480e1b914b, 0x0c20efdca4a6e67b),
+    (0x0d9ebe708f, 0x579c45a98619cbb6, 0xe76c996871e5fe69),
+    (0x115d43dd3a, 0x7f737776be8aa47e, 0x943758cf6eeb28e3),
+    (0x0a3a7ec44a, 0x9444a8ed586c72c8, 0x20e5b372787342f4),
+    (0x0ba2ccd12b, 0xee59e68ef47ce56f, 0xd515c9ec542dec0a),
+    (0x1a434a9631, 0xf9d94f66cfa0020f, 0x039bad4cfbca189d),
+    (0x000011d270, 0xcc51055ea7ca8fd6, 0x8f6e505dd3883605),
+    (0x0000000000, 0x004c8b888296c5f9, 0xe2ba8dee8a96a68f),
+    (0x0000000000, 0x000000000148c22c, 0xa71a1bd6f0a5b37e),
+    (0x1955f60000, 0x0000000000000000, 0x0000000000000000),
+    (0x0e7cd9216c, 0xd0fd77d43bc5c2b1, 0x2ed066cf05d0e92b),
+    (0x134c51373e, 0x3961af39d4573797, 0xbd4a732c0ee4919d),
+    (0x2b17c83a52, 0xe28042a8de262f93, 0xdca414c699b76a7f),
+    (0x01bb2282b9, 0x1524bceb63110e04, 0x3f10e8c1194353eb),
+    (0x18926ce307, 0x6f3fdf87720e1bec, 0x494c01fbff9933af),
+    (0x2a0028ae69, 0x892bcdd91f48989d, 0xbdc9a44f8e43e9c3),
+    (0x38c7d41cb4, 0x642bbf272a6e1a9d, 0x3724538a14d99369),
+    (0x049ec24ef3, 0xdee8863d720b8780, 0x3e5440010474794f),
+    (0x17dacc2b13, 0xd7e94ad3a2f0dc51, 0xd032e440212e5f24),
+    (0x0354dc6e66, 0x747ee52f104f9830, 0xf06f1d5494498adc),
+    (0x002613140e, 0x4f02a6e182d9fad1, 0x0fc8311352d07bf6),
+    (0x0c0b0fae00, 0xa38761f9afba3d8d, 0x2459fff79b947dc6),
+    (0x1559836933, 0xb9a5b135596a4bd5, 0x72ed41b3ab12bda4),
+    (0x32d876715b, 0xb23373961aaa21f1, 0x9d708a6e17bb0ef8),
+    (0x24628b48da, 0x61480e1b914b0c20, 0xefdca4a6e67a5af7),
+    (0x1ae259579c, 0x45a98619cbb6e76c, 0x996871e5fe68a8ff),
+    (0x0ffc727f73, 0x7776be8aa47e9437, 0x58cf6eeb28e2e695),
+    (0x14ee7e9444, 0xa8ed586c72c820e5, 0xb372787342f3e342),
+    (0x3298b1ee59, 0xe68ef47ce56fd515, 0xc9ec542dec09bc14),
+    (0x12dd39f9d9, 0x4f66cfa0020f039b, 0xad4cfbca189c739c),
+    (0x11d270cc51, 0x055ea7ca8fd68f6e, 0x505dd388360461c3),
+    (0x000000004c, 0x8b888296c5f9e2ba, 0x8dee8a96a68e2551),
+    (0x0000000000, 0x00000148c22ca71a, 0x1bd6f0a5b37d0be1),
+    (0x0000000000, 0x0000000000000584, 0x01c96621a4ef65ed),
+    (0x3b03e00000, 0x0000000000000000, 0x0000000000000000),
+    (0x16be72d0fd, 0x77d43bc5c2b12ed0, 0x66cf05d0e92aeb2a),
+    (0x1ddcea3961, 0xaf39d4573797bd4a, 0x732c0ee4919c857a),
+    (0x088dc0e280, 0x42a8de262f93dca4, 0x14c699b76a7efdb1),
+    (0x0edf231524, 0xbceb63110e043f10, 0xe8c1194353ea47e3),
+    (0x0a32ed6f3f, 0xdf87720e1bec494c, 0x01fbff9933ae18f8),
+    (0x0a2f81892b, 0xcdd91f48989dbdc9, 0xa44f8e43e9c257d1),
+    (0x362388642b, 0xbf272a6e1a9d3724, 0x538a14d99368ff15),
+    (0x36514ddee8, 0x863d720b87803e54, 0x40010474794efa04),
+    (0x1d8b8bd7e9, 0x4ad3a2f0dc51d032, 0xe440212e5f238f29),
+    (0x009e10747e, 0xe52f104f9830f06f, 0x1d5494498adb6ad5),
+    (0x1f85704f02, 0xa6e182d9fad10fc8, 0x311352d07bf52b5c),
+    (0x269406a387, 0x61f9afba3d8d2459, 0xfff79b947dc5675e),
+    (0x0bfabfb9a5, 0xb135596a4bd572ed, 0x41b3ab12bda3c0ce),
+    (0x10c6d1b233, 0x73961aaa21f19d70, 0x8a6e17bb0ef7993e),
+    (0x2778686148, 0x0e1b914b0c20efdc, 0xa4a6e67a5af691ea),
+    (0x1ba5b645a9, 0x8619cbb6e76c9968, 0x71e5fe68a8fe824b),
+    (0x3742e37776, 0xbe8aa47e943758cf, 0x6eeb28e2e6949834),
+    (0x2148c8a8ed, 0x586c72c820e5b372, 0x787342f3e3412013),
+    (0x17f003e68e, 0xf47ce56fd515c9ec, 0x542dec09bc13453d),
+    (0x1609e74f66, 0xcfa0020f039bad4c, 0xfbca189c739be1d0),
+    (0x1fc8a3055e, 0xa7ca8fd68f6e505d, 0xd388360461c2842a),
+    (0x00004c8b88, 0x8296c5f9e2ba8dee, 0x8a96a68e2550b653),
+    (0x0000000000, 0x0148c22ca71a1bd6, 0xf0a5b37d0be0e9cd),
+    (0x0000000000, 0x00000000058401c9, 0x6621a4ef65ec6bcb),
+    (0x316a1a0000, 0x0000000000000000, 0x0000000000000000),
+    (0x0d76e777d4, 0x3bc5c2b12ed066cf, 0x05d0e92aeb29f3df),
+    (0x27514daf39, 0xd4573797bd4a732c, 0x0ee4919c8579f95e),
+    (0x36ce2842a8, 0xde262f93dca414c6, 0x99b76a7efdb0d4de),
+    (0x1f892ebceb, 0x63110e043f10e8c1, 0x194353ea47e2f859),
+    (0x328d7ddf87, 0x720e1bec494c01fb, 0xff9933ae18f70b4d),
+    (0x091e75cdd9, 0x1f48989dbdc9a44f, 0x8e43e9c257d063ed),
+    (0x0e4e6fbf27, 0x2a6e1a9d3724538a, 0x14d99368ff140a8c),
+    (0x314684863d, 0x720b87803e544001, 0x0474794efa03ba1b),
+    (0x0af5374ad3, 0xa2f0dc51d032e440, 0x212e5f238f285722),
+    (0x348182e52f, 0x104f9830f06f1d54, 0x94498adb6ad47de7),
+    (0x3a2278a6e1, 0x82d9fad10fc83113, 0x52d07bf52b5b263f),
+    (0x0c6bdd61f9, 0xafba3d8d2459fff7, 0x9b947dc5675d75e9),
+    (0x22f2e7b135, 0x596a4bd572ed41b3, 0xab12bda3c0cd3935),
+    (0x066b277396, 0x1aaa21f19d708a6e, 0x17bb0ef7993d8ddd),
+    (0x06018c0e1b, 0x914b0c20efdca4a6, 0xe67a5af691e91ca7),
+    (0x2047bd8619, 0xcbb6e76c996871e5, 0xfe68a8fe824a6e21),
+    (0x194006be8a, 0xa47e943758cf6eeb, 0x28e2e69498336c52),
+    (0x1d1465586c, 0x72c820e5b3727873, 0x42f3e34120124f3b),
+    (0x254138f47c, 0xe56fd515c9ec542d, 0xec09bc13453ce19e),
+    (0x2f29a0cfa0, 0x020f039bad4cfbca, 0x189c739be1cfcfc5),
+    (0x231acaa7ca, 0x8fd68f6e505dd388, 0x360461c284290fbb),
+    (0x10f0be8296, 0xc5f9e2ba8dee8a96, 0xa68e2550b652834c),
+    (0x0000000148, 0xc22ca71a1bd6f0a5, 0xb37d0be0e9cc11ae),
+    (0x0000000000, 0x0000058401c96621, 0xa4ef65ec6bca6cb6),
+    (0x0000000000, 0x00000000000017b0, 0x8617a104ee462a19),
+    (0x2e0b720000, 0x0000000000000000, 0x0000000000000000),
+    (0x144e8c3bc5, 0xc2b12ed066cf05d0, 0xe92aeb29f3de227a),
+    (0x08e40dd457, 0x3797bd4a732c0ee4, 0x919c8579f95dff5c),
+    (0x03f88ade26, 0x2f93dca414c699b7, 0x6a7efdb0d4dd06bd),
+    (0x0348f76311, 0x0e043f10e8c11943, 0x53ea47e2f858f3ef),
+    (0x10e711720e, 0x1bec494c01fbff99, 0x33ae18f70b4c27bf),
+    (0x19a2b51f48, 0x989dbdc9a44f8e43, 0xe9c257d063ecb50b),
+    (0x02af332a6e, 0x1a9d3724538a14d9, 0x9368ff140a8ba6fb),
+    (0x381469720b, 0x87803e5440010474, 0x794efa03ba1a7ace),
+    (0x128833a2f0, 0xdc51d032e440212e, 0x5f238f2857218b37),
+    (0x32c09b104f, 0x9830f06f1d549449, 0x8adb6ad47de6a0e0),
+    (0x2b5ccb82d9, 0xfad10fc8311352d0, 0x7bf52b5b263edc95),
+    (0x18bfbfafba, 0x3d8d2459fff79b94, 0x7dc5675d75e81d07),
+    (0x279cb1596a, 0x4bd572ed41b3ab12, 0xbda3c0cd39345161),
+    (0x11792c1aaa, 0x21f19d708a6e17bb, 0x0ef7993d8ddc9cdd),
+    (0x2a95ed914b, 0x0c20efdca4a6e67a, 0x5af691e91ca6b7b9),
+    (0x1d78b1cbb6, 0xe76c996871e5fe68, 0xa8fe824a6e20ef83),
+    (0x2e9896a47e, 0x943758cf6eeb28e2, 0xe69498336c517461),
+    (0x1a09ba72c8, 0x20e5b372787342f3, 0xe34120124f3a8445),
+    (0x037ee8e56f, 0xd515c9ec542dec09, 0xbc13453ce19dc829),
+    (0x31f4ca020f, 0x039bad4cfbca189c, 0x739be1cfcfc49802),
+    (0x3a2e588fd6, 0x8f6e505dd3883604, 0x61c284290fbaa9b4),
+    (0x0a6582c5f9, 0xe2ba8dee8a96a68e, 0x2550b652834b9c88),
+    (0x000148c22c, 0xa71a1bd6f0a5b37d, 0x0be0e9cc11adcb2d),
+    (0x0000000000, 0x058401c96621a4ef, 0x65ec6bca6cb5567e),
+    (0x0000000000, 0x0000000017b08617, 0xa104ee462a18dff0),
+    (0x2940ec0000, 0x0000000000000000, 0x0000000000000000),
+    (0x23545fc2b1, 0x2ed066cf05d0e92a, 0xeb29f3de22796b6b),
+    (0x226d413797, 0xbd4a732c0ee4919c, 0x8579f95dff5bd979),
+    (0x00fd1a2f93, 0xdca414c699b76a7e, 0xfdb0d4dd06bcd248),
+    (0x3703c30e04, 0x3f10e8c1194353ea, 0x47e2f858f3ee38c3),
+    (0x24ba1e1bec, 0x494c01fbff9933ae, 0x18f70b4c27bec88a),
+    (0x249ac4989d, 0xbdc9a44f8e43e9c2, 0x57d063ecb50a3dfb),
+    (0x1ddbe81a9d, 0x3724538a14d99368, 0xff140a8ba6fa9115),
+    (0x130c738780, 0x3e5440010474794e, 0xfa03ba1a7acd1f79),
+    (0x0b6500dc51, 0xd032e440212e5f23, 0x8f2857218b364c56),
+    (0x30ad0b9830, 0xf06f1d5494498adb, 0x6ad47de6a0dfd2a6),
+    (0x20dcb7fad1, 0x0fc8311352d07bf5, 0x2b5b263edc946203),
+    (0x31b28c3d8d, 0x2459fff79b947dc5, 0x675d75e81d06aaa9),
+    (0x3841604bd5, 0x72ed41b3ab12bda3, 0xc0cd393451606f71),
+    (0x07ab3221f1, 0x9d708a6e17bb0ef7, 0x993d8ddc9cdc5a94),
+    (0x1949050c20, 0xefdca4a6e67a5af6, 0x91e91ca6b7b856cc),
+    (0x0ce2eee76c, 0x996871e5fe68a8fe, 0x824a6e20ef825c2e),
+    (0x357b3e9437, 0x58cf6eeb28e2e694, 0x98336c5174601c24),
+    (0x0512b620e5, 0xb372787342f3e341, 0x20124f3a84440d16),
+    (0x243f11d515, 0xc9ec542dec09bc13, 0x453ce19dc828cadb),
+    (0x19af39039b, 0xad4cfbca189c739b, 0xe1cfcfc498015ecf),
+    (0x2b6f828f6e, 0x505dd388360461c2, 0x84290fbaa9b3caa9),
+    (0x061433e2ba, 0x8dee8a96a68e2550, 0xb652834b9c87897a),
+    (0x1ebc3aa71a, 0x1bd6f0a5b37d0be0, 0xe9cc11adcb2c1361),
+    (0x0000000584, 0x01c96621a4ef65ec, 0x6bca6cb5567d9ff1),
+    (0x0000000000, 0x000017b08617a104, 0xee462a18dfef0551),
+    (0x0000000000, 0x00000000000065be, 0xee6ed136d13454cb),
+    (0x3085b40000, 0x0000000000000000, 0x0000000000000000),
+    (0x11558f2ed0, 0x66cf05d0e92aeb29, 0xf3de22796b6aa0f8),
+    (0x2633ffbd4a, 0x732c0ee4919c8579, 0xf95dff5bd978c889),
+    (0x03efdddca4, 0x14c699b76a7efdb0, 0xd4dd06bcd247a209),
+    (0x11b26a3f10, 0xe8c1194353ea47e2, 0xf858f3ee38c2dc1d),
+    (0x2ef2ca494c, 0x01fbff9933ae18f7, 0x0b4c27bec889e08d),
+    (0x087537bdc9, 0xa44f8e43e9c257d0, 0x63ecb50a3dfabeeb),
+    (0x139db13724, 0x538a14d99368ff14, 0x0a8ba6fa91147a8d),
+    (0x0bd9603e54, 0x40010474794efa03, 0xba1a7acd1f78e7c3),
+    (0x19def1d032, 0xe440212e5f238f28, 0x57218b364c558b52),
+    (0x2e9c5af06f, 0x1d5494498adb6ad4, 0x7de6a0dfd2a5d15a),
+    (0x14f4690fc8, 0x311352d07bf52b5b, 0x263edc9462028ae3),
+    (0x373d992459, 0xfff79b947dc5675d, 0x75e81d06aaa8f435),
+    (0x1a33f372ed, 0x41b3ab12bda3c0cd, 0x393451606f70bbc7),
+    (0x335f5b9d70, 0x8a6e17bb0ef7993d, 0x8ddc9cdc5a9357f5),
+    (0x185166efdc, 0xa4a6e67a5af691e9, 0x1ca6b7b856cbb135),
+    (0x304bfc9968, 0x71e5fe68a8fe824a, 0x6e20ef825c2d264d),
+    (0x1aa6f958cf, 0x6eeb28e2e6949833, 0x6c5174601c23750d),
+    (0x37064bb372, 0x787342f3e3412012, 0x4f3a84440d153559),
+    (0x11ec93c9ec, 0x542dec09bc13453c, 0xe19dc828cada40b2),
+    (0x15d87bad4c, 0xfbca189c739be1cf, 0xcfc498015eced44e),
+    (0x378a2c505d, 0xd388360461c28429, 0x0fbaa9b3caa86b86),
+    (0x320b6c8dee, 0x8a96a68e2550b652, 0x834b9c8789798f9f),
+    (0x2ee4501bd6, 0xf0a5b37d0be0e9cc, 0x11adcb2c136039f2),
+    (0x00058401c9, 0x6621a4ef65ec6bca, 0x6cb5567d9ff09d2f),
+    (0x0000000000, 0x17b08617a104ee46, 0x2a18dfef0550706b),
+    (0x0000000000, 0x0000000065beee6e, 0xd136d13454ca17af),
+    (0x3033840000, 0x0000000000000000, 0x0000000000000000),
+    (0x0a421266cf, 0x05d0e92aeb29f3de, 0x22796b6aa0f775b2),
+    (0x2e3d82732c, 0x0ee4919c8579f95d, 0xff5bd978c88897ed),
+    (0x2cf59414c6, 0x99b76a7efdb0d4dd, 0x06bcd247a2080c0e),
+    (0x3aac46e8c1, 0x194353ea47e2f858, 0xf3ee38c2dc1cde4b),
+    (0x1285e401fb, 0xff9933ae18f70b4c, 0x27bec889e08c5bbf),
+    (0x20264ba44f, 0x8e43e9c257d063ec, 0xb50a3dfabeeaa810),
+    (0x003ca4538a, 0x14d99368ff140a8b, 0xa6fa91147a8c6ba6),
+    (0x0eee6c4001, 0x0474794efa03ba1a, 0x7acd1f78e7c228d4),
+    (0x13b150e440, 0x212e5f238f285721, 0x8b364c558b51a26c),
+    (0x047fc51d54, 0x94498adb6ad47de6, 0xa0dfd2a5d1590b33),
+    (0x39a6923113, 0x52d07bf52b5b263e, 0xdc9462028ae2ca54),
+    (0x29d50ffff7, 0x9b947dc5675d75e8, 0x1d06aaa8f434c5bb),
+    (0x19a20941b3, 0xab12bda3c0cd3934, 0x51606f70bbc686cd),
+    (0x26dc088a6e, 0x17bb0ef7993d8ddc, 0x9cdc5a9357f41f2d),
+    (0x358cb2a4a6, 0xe67a5af691e91ca6, 0xb7b856cbb1343144),
+    (0x36c09c71e5, 0xfe68a8fe824a6e20, 0xef825c2d264caf7d),
+    (0x1ad61f6eeb, 0x28e2e69498336c51, 0x74601c23750ce1eb),
+    (0x0ab12a7873, 0x42f3e34120124f3a, 0x84440d15355804c4),
+    (0x2f0dde542d, 0xec09bc13453ce19d, 0xc828cada40b18bd9),
+    (0x1fb6cefbca, 0x189c739be1cfcfc4, 0x98015eced44d9217),
+    (0x231379d388, 0x360461c284290fba, 0xa9b3caa86b859ebf),
+    (0x26948e8a96, 0xa68e2550b652834b, 0x9c8789798f9e45f5),
+    (0x07d55af0a5, 0xb37d0be0e9cc11ad, 0xcb2c136039f1e6fb),
+    (0x2919a36621, 0xa4ef65ec6bca6cb5, 0x567d9ff09d2e4358),
+    (0x00000017b0, 0x8617a104ee462a18, 0xdfef0550706a6d68),
+    (0x0000000000, 0x000065beee6ed136, 0xd13454ca17aee7bf),
+    (0x0000000000, 0x000000000001b4fe, 0xb7eb212cd0915e74),
+    (0x30a40e0000, 0x0000000000000000, 0x0000000000000000),
+    (0x3537c305d0, 0xe92aeb29f3de2279, 0x6b6aa0f775b1a1bd),
+    (0x2ab4720ee4, 0x919c8579f95dff5b, 0xd978c88897ec498a),
+    (0x0fad0c99b7, 0x6a7efdb0d4dd06bc, 0xd247a2080c0dcf0a),
+    (0x23ab8b1943, 0x53ea47e2f858f3ee, 0x38c2dc1cde4a5666),
+    (0x0fcfefff99, 0x33ae18f70b4c27be, 0xc889e08c5bbe8f90),
+    (0x32a8878e43, 0xe9c257d063ecb50a, 0x3dfabeeaa80fb408),
+    (0x1b1e6214d9, 0x9368ff140a8ba6fa, 0x91147a8c6ba5b331),
+    (0x0acbf70474, 0x794efa03ba1a7acd, 0x1f78e7c228d3603c),
+    (0x111f78212e, 0x5f238f2857218b36, 0x4c558b51a26b8a71),
+    (0x308aa09449, 0x8adb6ad47de6a0df, 0xd2a5d1590b321b85),
+    (0x2292c552d0, 0x7bf52b5b263edc94, 0x62028ae2ca531d16),
+    (0x045d099b94, 0x7dc5675d75e81d06, 0xaaa8f434c5ba62b0),
+    (0x2b8d8dab12, 0xbda3c0cd39345160, 0x6f70bbc686ccdb94),
+    (0x1c7cf217bb, 0x0ef7993d8ddc9cdc, 0x5a9357f41f2ceafb),
+    (0x185e3ae67a, 0x5af691e91ca6b7b8, 0x56cbb13431435d8a),
+    (0x34d455fe68, 0xa8fe824a6e20ef82, 0x5c2d264caf7c2b90),
+    (0x38c8d728e2, 0xe69498336c517460, 0x1c23750ce1eaa8ae),
+    (0x02443b42f3, 0xe34120124f3a8444, 0x0d15355804c3daff),
+    (0x246d3dec09, 0xbc13453ce19dc828, 0xcada40b18bd8e3e6),
+    (0x01052e189c, 0x739be1cfcfc49801, 0x5eced44d921667e4),
+    (0x21188c3604, 0x61c284290fbaa9b3, 0xcaa86b859ebe7b7a),
+    (0x1d1d58a68e, 0x2550b652834b9c87, 0x89798f9e45f4ee8f),
+    (0x37bd3db37d, 0x0be0e9cc11adcb2c, 0x136039f1e6fa8507),
+    (0x058065a4ef, 0x65ec6bca6cb5567d, 0x9ff09d2e4357b221),
+    (0x0017b08617, 0xa104ee462a18dfef, 0x0550706a6d675e0a),
+    (0x0000000000, 0x65beee6ed136d134, 0x54ca17aee7befe85),
+    (0x0000000000, 0x00000001b4feb7eb, 0x212cd0915e7348eb),
+    (0x09fe260000, 0x0000000000000000, 0x0000000000000000),
+    (0x247166e92a, 0xeb29f3de22796b6a, 0xa0f775b1a1bced76),
+    (0x1d9140919c, 0x8579f95dff5bd978, 0xc88897ec4989e071),
+    (0x368c396a7e, 0xfdb0d4dd06bcd247, 0xa2080c0dcf095686),
+    (0x28890553ea, 0x47e2f858f3ee38c2, 0xdc1cde4a56659b29),
+    (0x2d3fbf33ae, 0x18f70b4c27bec889, 0xe08c5bbe8f8fce1e),
+    (0x187245e9c2, 0x57d063ecb50a3dfa, 0xbeeaa80fb4073e32),
+    (0x12635f9368, 0xff140a8ba6fa9114, 0x7a8c6ba5b3303b42),
+    (0x08477e794e, 0xfa03ba1a7acd1f78, 0xe7c228d3603b9fe0),
+    (0x33164a5f23, 0x8f2857218b364c55, 0x8b51a26b8a70e8da),
+    (0x39153b8adb, 0x6ad47de6a0dfd2a5, 0xd1590b321b848672),
+    (0x2df62e7bf5, 0x2b5b263edc946202, 0x8ae2ca531d158c15),
+    (0x2620727dc5, 0x675d75e81d06aaa8, 0xf434c5ba62af1a90),
+    (0x39a606bda3, 0xc0cd393451606f70, 0xbbc686ccdb93b045),
+    (0x3968b70ef7, 0x993d8ddc9cdc5a93, 0x57f41f2ceafa7c8d),
+    (0x3b41ea5af6, 0x91e91ca6b7b856cb, 0xb13431435d898941),
+    (0x2298eca8fe, 0x824a6e20ef825c2d, 0x264caf7c2b8f3b92),
+    (0x0f29c4e694, 0x98336c5174601c23, 0x750ce1eaa8adcb3c),
+    (0x047c9be341, 0x20124f3a84440d15, 0x355804c3dafe461a),
+    (0x244b4bbc13, 0x453ce19dc828cada, 0x40b18bd8e3e50962),
+    (0x2d4a12739b, 0xe1cfcfc498015ece, 0xd44d921667e33d81),
+    (0x12cad261c2, 0x84290fbaa9b3caa8, 0x6b859ebe7b797369),
+    (0x345de02550, 0xb652834b9c878979, 0x8f9e45f4ee8e8586),
+    (0x370bcb0be0, 0xe9cc11adcb2c1360, 0x39f1e6fa8506d41a),
+    (0x0125e565ec, 0x6bca6cb5567d9ff0, 0x9d2e4357b220c209),
+    (0x2c7465a104, 0xee462a18dfef0550, 0x706a6d675e093f43),
+    (0x00000065be, 0xee6ed136d13454ca, 0x17aee7befe84d32e),
+    (0x0000000000, 0x0001b4feb7eb212c, 0xd0915e7348eaa0d6),
+    (0x0000000000, 0x00000000000754e1, 0x13b91f745e5a32f1),
+    (0x09c4920000, 0x0000000000000000, 0x0000000000000000),
+    (0x1c6638eb29, 0xf3de22796b6aa0f7, 0x75b1a1bced757da0),
+    (0x292bfa8579, 0xf95dff5bd978c888, 0x97ec4989e0700e09),
+    (0x34d878fdb0, 0xd4dd06bcd247a208, 0x0c0dcf095685f1a5),
+    (0x3984fa47e2, 0xf858f3ee38c2dc1c, 0xde4a56659b28583f),
+    (0x30850818f7, 0x0b4c27bec889e08c, 0x5bbe8f8fce1dd3cf),
+    (0x04aa8c57d0, 0x63ecb50a3dfabeea, 0xa80fb4073e319aac),
+    (0x00de24ff14, 0x0a8ba6fa91147a8c, 0x6ba5b3303b411637),
+    (0x096478fa03, 0xba1a7acd1f78e7c2, 0x28d3603b9fdf5e07),
+    (0x31797f8f28, 0x57218b364c558b51, 0xa26b8a70e8d9dae2),
+    (0x1538ed6ad4, 0x7de6a0dfd2a5d159, 0x0b321b848671397a),
+    (0x15c2af2b5b, 0x263edc9462028ae2, 0xca531d158c14fd37),
+    (0x2fc845675d, 0x75e81d06aaa8f434, 0xc5ba62af1a8f0d56),
+    (0x0e54e9c0cd, 0x393451606f70bbc6, 0x86ccdb93b0449d99),
+    (0x36468d993d, 0x8ddc9cdc5a9357f4, 0x1f2ceafa7c8c15d5),
+    (0x11546291e9, 0x1ca6b7b856cbb134, 0x31435d89894056ac),
+    (0x0bf90e824a, 0x6e20ef825c2d264c, 0xaf7c2b8f3b916869),
+    (0x0443549833, 0x6c5174601c23750c, 0xe1eaa8adcb3bc89a),
+    (0x0e2ccf2012, 0x4f3a84440d153558, 0x04c3dafe46196432),
+    (0x306289453c, 0xe19dc828cada40b1, 0x8bd8e3e509619311),
+    (0x098f73e1cf, 0xcfc498015eced44d, 0x921667e33d806012),
+    (0x1336268429, 0x0fbaa9b3caa86b85, 0x9ebe7b797368c6b3),
+    (0x2f8376b652, 0x834b9c8789798f9e, 0x45f4ee8e85853851),
+    (0x2f2172e9cc, 0x11adcb2c136039f1, 0xe6fa8506d419d324),
+    (0x1056206bca, 0x6cb5567d9ff09d2e, 0x4357b220c2087472),
+    (0x19cd38ee46, 0x2a18dfef0550706a, 0x6d675e093f429439),
+    (0x0065beee6e, 0xd136d13454ca17ae, 0xe7befe84d32da8f2),
+    (0x0000000001, 0xb4feb7eb212cd091, 0x5e7348eaa0d5133e),
+    (0x0000000000, 0x0000000754e113b9, 0x1f745e5a32f0ad4c),
+    (0x0000000000, 0x000000000000001f, 0x7d228322baf5244a),
+    (0x33b8420000, 0x0000000000000000, 0x0000000000000000),
+    (0x15e2aff3de, 0x22796b6aa0f775b1, 0xa1bced757d9f56e2),
+    (0x337631f95d, 0xff5bd978c88897ec, 0x4989e0700e08f749),
+    (0x14b600d4dd, 0x06bcd247a2080c0d, 0xcf095685f1a41e8c),
+    (0x11bb34f858, 0xf3ee38c2dc1cde4a, 0x56659b28583e904e),
+    (0x371cd90b4c, 0x27bec889e08c5bbe, 0x8f8fce1dd3cec609),
+    (0x2083ec63ec, 0xb50a3dfabeeaa80f, 0xb4073e319aabe8d1),
+    (0x062a500a8b, 0xa6fa91147a8c6ba5, 0xb3303b41163656b1),
+    (0x07cf5dba1a, 0x7acd1f78e7c228d3, 0x603b9fdf5e06289c),
+    (0x35b2865721, 0x8b364c558b51a26b, 0x8a70e8d9dae1da3d),
+    (0x0ea2d87de6, 0xa0dfd2a5d1590b32, 0x1b84867139797707),
+    (0x360b09263e, 0xdc9462028ae2ca53, 0x1d158c14fd36d76f),
+    (0x0c2e6375e8, 0x1d06aaa8f434c5ba, 0x62af1a8f0d554245),
+    (0x335d8b3934, 0x51606f70bbc686cc, 0xdb93b0449d988cbf),
+    (0x26d1258ddc, 0x9cdc5a9357f41f2c, 0xeafa7c8c15d4b24d),
+    (0x07ab1d1ca6, 0xb7b856cbb1343143, 0x5d89894056abbe3e),
+    (0x12f7126e20, 0xef825c2d264caf7c, 0x2b8f3b9168681b16),
+    (0x0d9fdd6c51, 0x74601c23750ce1ea, 0xa8adcb3bc8998a7e),
+    (0x24bc884f3a, 0x84440d15355804c3, 0xdafe46196431d576),
+    (0x2dc5e6e19d, 0xc828cada40b18bd8, 0xe3e5096193108b4d),
+    (0x2b75f9cfc4, 0x98015eced44d9216, 0x67e33d80601123e0),
+    (0x119acb0fba, 0xa9b3caa86b859ebe, 0x7b797368c6b20fa3),
+    (0x2876e8834b, 0x9c8789798f9e45f4, 0xee8e8585385014d5),
+    (0x2a389411ad, 0xcb2c136039f1e6fa, 0x8506d419d3234870),
+    (0x05caa66cb5, 0x567d9ff09d2e4357, 0xb220c20874714a11),
+    (0x0cc35c2a18, 0xdfef0550706a6d67, 0x5e093f429438a930),
+    (0x3b4e66d136, 0xd13454ca17aee7be, 0xfe84d32da8f13373),
+    (0x000001b4fe, 0xb7eb212cd0915e73, 0x48eaa0d5133d4606),
+    (0x0000000000, 0x000754e113b91f74, 0x5e5a32f0ad4bce0f),
+    (0x0000000000, 0x00000000001f7d22, 0x8322baf524497e40),
+    (0x1c452c0000, 0x0000000000000000, 0x0000000000000000),
+    (0x1562a82279, 0x6b6aa0f775b1a1bc, 0xed757d9f56e1a7eb),
+    (0x2bf6a1ff5b, 0xd978c88897ec4989, 0xe0700e08f74849d5),
+    (0x37771f06bc, 0xd247a2080c0dcf09, 0x5685f1a41e8ba048),
+    (0x2c6b92f3ee, 0x38c2dc1cde4a5665, 0x9b28583e904d1bbe),
+    (0x02627a27be, 0xc889e08c5bbe8f8f, 0xce1dd3cec608ecf8),
+    (0x3a56f0b50a, 0x3dfabeeaa80fb407, 0x3e319aabe8d0d25e),
+    (0x2fc247a6fa, 0x91147a8c6ba5b330, 0x3b41163656b012e0),
+    (0x0ca46c7acd, 0x1f78e7c228d3603b, 0x9fdf5e06289b559a),
+    (0x341ce18b36, 0x4c558b51a26b8a70, 0xe8d9dae1da3c3261),
+    (0x228c4ea0df, 0xd2a5d1590b321b84, 0x8671397977061f98),
+    (0x018d5cdc94, 0x62028ae2ca531d15, 0x8c14fd36d76e05a6),
+    (0x1673fe1d06, 0xaaa8f434c5ba62af, 0x1a8f0d554244c5a8),
+    (0x334e1c5160, 0x6f70bbc686ccdb93, 0xb0449d988cbef908),
+    (0x2aeb769cdc, 0x5a9357f41f2ceafa, 0x7c8c15d4b24c0db4),
+    (0x1e5a10b7b8, 0x56cbb13431435d89, 0x894056abbe3d4d6c),
+    (0x166098ef82, 0x5c2d264caf7c2b8f, 0x3b9168681b15434c),
+    (0x0fd8297460, 0x1c23750ce1eaa8ad, 0xcb3bc8998a7d960c),
+    (0x097f6a8444, 0x0d15355804c3dafe, 0x46196431d57561de),
+    (0x0051adc828, 0xcada40b18bd8e3e5, 0x096193108b4c1c2f),
+    (0x29dc6a9801, 0x5eced44d921667e3, 0x3d80601123df543c),
+    (0x1f8ea2a9b3, 0xcaa86b859ebe7b79, 0x7368c6b20fa2933a),
+    (0x0a431d9c87, 0x89798f9e45f4ee8e, 0x8585385014d4d9e2),
+    (0x1d3fd1cb2c, 0x136039f1e6fa8506, 0xd419d323486ff678),
+    (0x37a6bf567d, 0x9ff09d2e4357b220, 0xc20874714a10d2c5),
+    (0x0c2f2edfef, 0x0550706a6d675e09, 0x3f429438a92f9937),
+    (0x36bed0d134, 0x54ca17aee7befe84, 0xd32da8f1337226df),
+    (0x01b4feb7eb, 0x212cd0915e7348ea, 0xa0d5133d46052999),
+    (0x0000000007, 0x54e113b91f745e5a, 0x32f0ad4bce0e56e1),
+    (0x0000000000, 0x0000001f7d228322, 0xbaf524497e3ff3e1),
+    (0x0000000000, 0x0000000000000087, 0x3e4f75e2224e685b),
+    (0x18d3920000, 0x0000000000000000, 0x0000000000000000),
+    (0x1c1f3f6b6a, 0xa0f775b1a1bced75, 0x7d9f56e1a7ea032e),
+    (0x10ada7d978, 0xc88897ec4989e070, 0x0e08f74849d41e7a),
+    (0x2d5e8cd247, 0xa2080c0dcf095685, 0xf1a41e8ba0470d54),
+    (0x201a1e38c2, 0xdc1cde4a56659b28, 0x583e904d1bbd2234),
+    (0x3a619cc889, 0xe08c5bbe8f8fce1d, 0xd3cec608ecf73267),
+    (0x04c4a03dfa, 0xbeeaa80fb4073e31, 0x9aabe8d0d25d15e8),
+    (0x1c26849114, 0x7a8c6ba5b3303b41, 0x163656b012dffc72),
+    (0x13ead51f78, 0xe7c228d3603b9fdf, 0x5e06289b559911a4),
+    (0x269b824c55, 0x8b51a26b8a70e8d9, 0xdae1da3c3260fdde),
+    (0x00978bd2a5, 0xd1590b321b848671, 0x397977061f97ee42),
+    (0x2756706202, 0x8ae2ca531d158c14, 0xfd36d76e05a5fa73),
+    (0x1a3f70aaa8, 0xf434c5ba62af1a8f, 0x0d554244c5a7ce33),
+    (0x224e5c6f70, 0xbbc686ccdb93b044, 0x9d988cbef907a59d),
+    (0x2f6d005a93, 0x57f41f2ceafa7c8c, 0x15d4b24c0db33059),
+    (0x0a832056cb, 0xb13431435d898940, 0x56abbe3d4d6b6667),
+    (0x08416a5c2d, 0x264caf7c2b8f3b91, 0x68681b15434bdb26),
+    (0x04f01e1c23, 0x750ce1eaa8adcb3b, 0xc8998a7d960bec89),
+    (0x32d0e00d15, 0x355804c3dafe4619, 0x6431d57561dd7df4),
+    (0x3027fccada, 0x40b18bd8e3e50961, 0x93108b4c1c2ef72f),
+    (0x27369d5ece, 0xd44d921667e33d80, 0x601123df543b3d9a),
+    (0x1c4d99caa8, 0x6b859ebe7b797368, 0xc6b20fa29339ad96),
+    (0x1667898979, 0x8f9e45f4ee8e8585, 0x385014d4d9e1cb6d),
+    (0x3805b61360, 0x39f1e6fa8506d419, 0xd323486ff677328d),
+    (0x12ba8b9ff0, 0x9d2e4357b220c208, 0x74714a10d2c43481),
+    (0x2f0da70550, 0x706a6d675e093f42, 0x9438a92f993683cf),
+    (0x0a6f2a54ca, 0x17aee7befe84d32d, 0xa8f1337226de2f1f),
+    (0x3467a3212c, 0xd0915e7348eaa0d5, 0x133d46052998526a),
+    (0x00000754e1, 0x13b91f745e5a32f0, 0xad4bce0e56e05068),
+    (0x0000000000, 0x001f7d228322baf5, 0x24497e3ff3e00c58),
+    (0x0000000000, 0x0000000000873e4f, 0x75e2224e685a7745),
+    (0x399dc00000, 0x0000000000000000, 0x0000000000000000),
+    (0x1fcd9aa0f7, 0x75b1a1bced757d9f, 0x56e1a7ea032d4ad6),
+    (0x01dfa4c888, 0x97ec4989e0700e08, 0xf74849d41e794511),
+    (0x06b8afa208, 0x0c0dcf095685f1a4, 0x1e8ba0470d53578e),
+    (0x20ae02dc1c, 0xde4a56659b28583e, 0x904d1bbd2233430f),
+    (0x36a29de08c, 0x5bbe8f8fce1dd3ce, 0xc608ecf73266fbbd),
+    (0x20b1b6beea, 0xa80fb4073e319aab, 0xe8d0d25d15e74387),
+    (0x2d34ce7a8c, 0x6ba5b3303b411636, 0x56b012dffc71c668),
+    (0x1301cae7c2, 0x28d3603b9fdf5e06, 0x289b559911a38070),
+    (0x14bb6b8b51, 0xa26b8a70e8d9dae1, 0xda3c3260fddda8f6),
+    (0x34cdc1d159, 0x0b321b8486713979, 0x77061f97ee4109cb),
+    (0x0c497a8ae2, 0xca531d158c14fd36, 0xd76e05a5fa72f687),
+    (0x2e391af434, 0xc5ba62af1a8f0d55, 0x4244c5a7ce323746),
+    (0x38d6cabbc6, 0x86ccdb93b0449d98, 0x8cbef907a59cb0ae),
+    (0x0c26e957f4, 0x1f2ceafa7c8c15d4, 0xb24c0db33058f04a),
+    (0x11dacfb134, 0x31435d89894056ab, 0xbe3d4d6b6666dd5f),
+    (0x02ffdb264c, 0xaf7c2b8f3b916868, 0x1b15434bdb2563d4),
+    (0x147e51750c, 0xe1eaa8adcb3bc899, 0x8a7d960bec88f427),
+    (0x2956953558, 0x04c3dafe46196431, 0xd57561dd7df33154),
+    (0x120f9240b1, 0x8bd8e3e509619310, 0x8b4c1c2ef72e1eb9),
+    (0x1f1c60d44d, 0x921667e33d806011, 0x23df543b3d9935dc),
+    (0x24d9d26b85, 0x9ebe7b797368c6b2, 0x0fa29339ad953ed8),
+    (0x38527f8f9e, 0x45f4ee8e85853850, 0x14d4d9e1cb6ca21c),
+    (0x0ac57e39f1, 0xe6fa8506d419d323, 0x486ff677328c887d),
+    (0x18c7909d2e, 0x4357b220c2087471, 0x4a10d2c4348044e4),
+    (0x28b92a706a, 0x6d675e093f429438, 0xa92f993683ce2e92),
+    (0x21d8aa17ae, 0xe7befe84d32da8f1, 0x337226de2f1e19b8),
+    (0x2b1a2ed091, 0x5e7348eaa0d5133d, 0x460529985269ccc6),
+    (0x0754e113b9, 0x1f745e5a32f0ad4b, 0xce0e56e050674140),
+    (0x000000001f, 0x7d228322baf52449, 0x7e3ff3e00c57550f),
+    (0x0000000000, 0x000000873e4f75e2, 0x224e685a7744a6e9),
+    (0x0000000000, 0x0000000000000244, 0xddb0db666656f88d),
+    (0x295bae0000, 0x0000000000000000, 0x0000000000000000),
+    (0x3703d175b1, 0xa1bced757d9f56e1, 0xa7ea032d4ad5ab07),
+    (0x03371097ec, 0x4989e0700e08f748, 0x49d41e7945106308),
+    (0x114edc0c0d, 0xcf095685f1a41e8b, 0xa0470d53578dd4e8),
+    (0x2c5e4ede4a, 0x56659b28583e904d, 0x1bbd2233430e2aec),
+    (0x03a7fc5bbe, 0x8f8fce1dd3cec608, 0xecf73266fbbc7a4a),
+    (0x26947ca80f, 0xb4073e319aabe8d0, 0xd25d15e74386ea22),
+    (0x3136fc6ba5, 0xb3303b41163656b0, 0x12dffc71c667f233),
+    (0x1909ee28d3, 0x603b9fdf5e06289b, 0x559911a3806f8f26),
+    (0x0baaa3a26b, 0x8a70e8d9dae1da3c, 0x3260fddda8f54475),
+    (0x1359f50b32, 0x1b84867139797706, 0x1f97ee4109cafbcd),
+    (0x2b6570ca53, 0x1d158c14fd36d76e, 0x05a5fa72f686272f),
+    (0x2fb278c5ba, 0x62af1a8f0d554244, 0xc5a7ce323745e0c2),
+    (0x0a755086cc, 0xdb93b0449d988cbe, 0xf907a59cb0ad0ff8),
+    (0x0faf4a1f2c, 0xeafa7c8c15d4b24c, 0x0db33058f0496bcc),
+    (0x1ce91e3143, 0x5d89894056abbe3d, 0x4d6b6666dd5ed301),
+    (0x36a2c2af7c, 0x2b8f3b9168681b15, 0x434bdb2563d3ef40),
+    (0x2d99e4e1ea, 0xa8adcb3bc8998a7d, 0x960bec88f426b2d6),
+    (0x327faa04c3, 0xdafe46196431d575, 0x61dd7df33153fc0e),
+    (0x05377d8bd8, 0xe3e5096193108b4c, 0x1c2ef72e1eb8ec53),
+    (0x2c59a19216, 0x67e33d80601123df, 0x543b3d9935dbdf18),
+    (0x3907139ebe, 0x7b797368c6b20fa2, 0x9339ad953ed7880f),
+    (0x3a542245f4, 0xee8e8585385014d4, 0xd9e1cb6ca21bf25d),
+    (0x146b13e6fa, 0x8506d419d323486f, 0xf677328c887cf566),
+    (0x18112c4357, 0xb220c20874714a10, 0xd2c4348044e3fbda),
+    (0x2c78246d67, 0x5e093f429438a92f, 0x993683ce2e915c7a),
+    (0x1ad782e7be, 0xfe84d32da8f13372, 0x26de2f1e19b782b1),
+    (0x1b181b5e73, 0x48eaa0d5133d4605, 0x29985269ccc580ff),
+    (0x0809171f74, 0x5e5a32f0ad4bce0e, 0x56e05067413f5609),
+    (0x00001f7d22, 0x8322baf524497e3f, 0xf3e00c57550e0c52),
+    (0x0000000000, 0x00873e4f75e2224e, 0x685a7744a6e804a3),
+    (0x0000000000, 0x000000000244ddb0, 0xdb666656f88c4021),
+    (0x2bfcf60000, 0x0000000000000000, 0x0000000000000000),
+    (0x201a17a1bc, 0xed757d9f56e1a7ea, 0x032d4ad5ab06983a),
+    (0x022c964989, 0xe0700e08f74849d4, 0x1e7945106307c35c),
+    (0x17a831cf09, 0x5685f1a41e8ba047, 0x0d53578dd4e72fb8),
+    (0x217b745665, 0x9b28583e904d1bbd, 0x2233430e2aebcb3f),
+    (0x01afb68f8f, 0xce1dd3cec608ecf7, 0x3266fbbc7a49aa4d),
+    (0x0b3ad1b407, 0x3e319aabe8d0d25d, 0x15e74386ea215683),
+    (0x0de1e5b330, 0x3b41163656b012df, 0xfc71c667f2321b3d),
+    (0x1a49ef603b, 0x9fdf5e06289b5599, 0x11a3806f8f25791d),
+    (0x1edb1d8a70, 0xe8d9dae1da3c3260, 0xfddda8f544743ee2),
+    (0x0504501b84, 0x8671397977061f97, 0xee4109cafbcc06af),
+    (0x28c4ff1d15, 0x8c14fd36d76e05a5, 0xfa72f686272e0dc5),
+    (0x2262ec62af, 0x1a8f0d554244c5a7, 0xce323745e0c19af4),
+    (0x3a5228db93, 0xb0449d988cbef907, 0xa59cb0ad0ff78e1f),
+    (0x2005caeafa, 0x7c8c15d4b24c0db3, 0x3058f0496bcb8b9a),
+    (0x2159555d89, 0x894056abbe3d4d6b, 0x6666dd5ed300cd47),
+    (0x2876ec2b8f, 0x3b9168681b15434b, 0xdb2563d3ef3fd56d),
+    (0x3b33e6a8ad, 0xcb3bc8998a7d960b, 0xec88f426b2d5cc61),
+    (0x3553a5dafe, 0x46196431d57561dd, 0x7df33153fc0d46a4),
+    (0x34e892e3e5, 0x096193108b4c1c2e, 0xf72e1eb8ec52ee7f),
+    (0x1c470867e3, 0x3d80601123df543b, 0x3d9935dbdf1788d1),
+    (0x212ef27b79, 0x7368c6b20fa29339, 0xad953ed7880e4da9),
+    (0x019702ee8e, 0x8585385014d4d9e1, 0xcb6ca21bf25cd7e0),
+    (0x07a0868506, 0xd419d323486ff677, 0x328c887cf565951f),
+    (0x02272bb220, 0xc20874714a10d2c4, 0x348044e3fbd98a69),
+    (0x1eecfb5e09, 0x3f429438a92f9936, 0x83ce2e915c793cb6),
+    (0x36a0eefe84, 0xd32da8f1337226de, 0x2f1e19b782b0dd81),
+    (0x15004748ea, 0xa0d5133d46052998, 0x5269ccc580fe85bc),
+    (0x0816165e5a, 0x32f0ad4bce0e56e0, 0x5067413f5608f96f),
+    (0x1f7d228322, 0xbaf524497e3ff3e0, 0x0c57550e0c51663c),
+    (0x0000000087, 0x3e4f75e2224e685a, 0x7744a6e804a291cd),
+    (0x0000000000, 0x00000244ddb0db66, 0x6656f88c402026e8),
+    (0x0000000000, 0x00000000000009be, 0xcce62836ac5774ef),
+    (0x1b5afe0000, 0x0000000000000000, 0x0000000000000000),
+    (0x1a16fced75, 0x7d9f56e1a7ea032d, 0x4ad5ab069839610f),
+    (0x1f2fade070, 0x0e08f74849d41e79, 0x45106307c35b386c),
+    (0x0964bb5685, 0xf1a41e8ba0470d53, 0x578dd4e72fb75fc1),
+    (0x06f1d99b28, 0x583e904d1bbd2233, 0x430e2aebcb3ef5d3),
+    (0x0b8ca3ce1d, 0xd3cec608ecf73266, 0xfbbc7a49aa4c5975),
+    (0x0f27793e31, 0x9aabe8d0d25d15e7, 0x4386ea215682bc3a),
+    (0x3401ba3b41, 0x163656b012dffc71, 0xc667f2321b3cb6bd),
+    (0x32b92b9fdf, 0x5e06289b559911a3, 0x806f8f25791c4c68),
+    (0x1a5cb4e8d9, 0xdae1da3c3260fddd, 0xa8f544743ee1a847),
+    (0x04e30c8671, 0x397977061f97ee41, 0x09cafbcc06aeadff),
+    (0x214e918c14, 0xfd36d76e05a5fa72, 0xf686272e0dc4d294),
+    (0x0ff9cf1a8f, 0x0d554244c5a7ce32, 0x3745e0c19af3bbd8),
+    (0x209dbbb044, 0x9d988cbef907a59c, 0xb0ad0ff78e1e947b),
+    (0x0efae07c8c, 0x15d4b24c0db33058, 0xf0496bcb8b99eb44),
+    (0x21dafb8940, 0x56abbe3d4d6b6666, 0xdd5ed300cd46bbbf),
+    (0x0deb613b91, 0x68681b15434bdb25, 0x63d3ef3fd56cc3fd),
+    (0x05e971cb3b, 0xc8998a7d960bec88, 0xf426b2d5cc60b566),
+    (0x16b7e44619, 0x6431d57561dd7df3, 0x3153fc0d46a3b494),
+    (0x1de3c30961, 0x93108b4c1c2ef72e, 0x1eb8ec52ee7e2b40),
+    (0x1865253d80, 0x601123df543b3d99, 0x35dbdf1788d07d9c),
+    (0x1f04877368, 0xc6b20fa29339ad95, 0x3ed7880e4da8a673),
+    (0x0603468585, 0x385014d4d9e1cb6c, 0xa21bf25cd7df13a3),
+    (0x01f7f2d419, 0xd323486ff677328c, 0x887cf565951ebfc9),
+    (0x0f806ac208, 0x74714a10d2c43480, 0x44e3fbd98a685448),
+    (0x0aa18b3f42, 0x9438a92f993683ce, 0x2e915c793cb55ec6),
+    (0x319c44d32d, 0xa8f1337226de2f1e, 0x19b782b0dd803f1f),
+    (0x380976a0d5, 0x133d460529985269, 0xccc580fe85bb90a5),
+    (0x39139632f0, 0xad4bce0e56e05067, 0x413f5608f96e19cd),
+    (0x128036baf5, 0x24497e3ff3e00c57, 0x550e0c51663b0c5b),
+    (0x0000873e4f, 0x75e2224e685a7744, 0xa6e804a291cc35ee),
+    (0x0000000000, 0x0244ddb0db666656, 0xf88c402026e7087f),
+    (0x0000000000, 0x0000000009becce6, 0x2836ac5774ee3680),
+    (0x25195e0000, 0x0000000000000000, 0x0000000000000000),
+    (0x0216697d9f, 0x56e1a7ea032d4ad5, 0xab069839610e7c8a),
+    (0x2636460e08, 0xf74849d41e794510, 0x6307c35b386b12a4),
+    (0x0e9115f1a4, 0x1e8ba0470d53578d, 0xd4e72fb75fc0f1d3),
+    (0x3024aa583e, 0x904d1bbd2233430e, 0x2aebcb3ef5d2ff36),
+    (0x280699d3ce, 0xc608ecf73266fbbc, 0x7a49aa4c597403c9),
+    (0x0ca6d59aab, 0xe8d0d25d15e74386, 0xea215682bc399cb9),
+    (0x016f151636, 0x56b012dffc71c667, 0xf2321b3cb6bcc241),
+    (0x3495db5e06, 0x289b559911a3806f, 0x8f25791c4c6755dc),
+    (0x1c47dfdae1, 0xda3c3260fddda8f5, 0x44743ee1a8463d17),
+    (0x0cc4cf3979, 0x77061f97ee4109ca, 0xfbcc06aeadfee2c0),
+    (0x13d9d2fd36, 0xd76e05a5fa72f686, 0x272e0dc4d293b91a),
+    (0x2384ad0d55, 0x4244c5a7ce323745, 0xe0c19af3bbd7cacb),
+    (0x3185b29d98, 0x8cbef907a59cb0ad, 0x0ff78e1e947a2cb0),
+    (0x27fcb015d4, 0xb24c0db33058f049, 0x6bcb8b99eb439718),
+    (0x183d3056ab, 0xbe3d4d6b6666dd5e, 0xd300cd46bbbe0500),
+    (0x23bfc16868, 0x1b15434bdb2563d3, 0xef3fd56cc3fc0476),
+    (0x0b9a53c899, 0x8a7d960bec88f426, 0xb2d5cc60b5658bbe),
+    (0x03da1b6431, 0xd57561dd7df33153, 0xfc0d46a3b49382d8),
+    (0x03fda19310, 0x8b4c1c2ef72e1eb8, 0xec52ee7e2b3fcc84),
+    (0x2975446011, 0x23df543b3d9935db, 0xdf1788d07d9b96ed),
+    (0x12a538c6b2, 0x0fa29339ad953ed7, 0x880e4da8a6724000),
+    (0x0837073850, 0x14d4d9e1cb6ca21b, 0xf25cd7df13a29519),
+    (0x1a6091d323, 0x486ff677328c887c, 0xf565951ebfc881d8),
+    (0x0d31407471, 0x4a10d2c4348044e3, 0xfbd98a685447daa8),
+    (0x3828b29438, 0xa92f993683ce2e91, 0x5c793cb55ec511b3),
+    (0x0e442fa8f1, 0x337226de2f1e19b7, 0x82b0dd803f1eaf7d),
+    (0x11a653133d, 0x460529985269ccc5, 0x80fe85bb90a43f33),
+    (0x111c88ad4b, 0xce0e56e05067413f, 0x5608f96e19cc7b17),
+    (0x34a6a32449, 0x7e3ff3e00c57550e, 0x0c51663b0c5ae5f8),
+    (0x1008bb75e2, 0x224e685a7744a6e8, 0x04a291cc35eddfd0),
+    (0x0000000244, 0xddb0db666656f88c, 0x402026e7087e866f),
+    (0x0000000000, 0x000009becce62836, 0xac5774ee367f9431),
+    (0x0000000000, 0x00000000000029db, 0x1608ce3b49886bcb),
+    (0x348a240000, 0x0000000000000000, 0x0000000000000000),
+    (0x10d4b956e1, 0xa7ea032d4ad5ab06, 0x9839610e7c895746),
+    (0x20825cf748, 0x49d41e7945106307, 0xc35b386b12a37dd4),
+    (0x11f4041e8b, 0xa0470d53578dd4e7, 0x2fb75fc0f1d2752a),
+    (0x021402904d, 0x1bbd2233430e2aeb, 0xcb3ef5d2ff35a10c),
+    (0x309ebec608, 0xecf73266fbbc7a49, 0xaa4c597403c835cf),
+    (0x28fecfe8d0, 0xd25d15e74386ea21, 0x5682bc399cb8e416),
+    (0x242aa656b0, 0x12dffc71c667f232, 0x1b3cb6bcc240d017),
+    (0x0e0402289b, 0x559911a3806f8f25, 0x791c4c6755db3d34),
+    (0x016cfbda3c, 0x3260fddda8f54474, 0x3ee1a8463d16404b),
+    (0x199dd37706, 0x1f97ee4109cafbcc, 0x06aeadfee2bf7e87),
+    (0x100322d76e, 0x05a5fa72f686272e, 0x0dc4d293b919082d),
+    (0x2c76dd4244, 0xc5a7ce323745e0c1, 0x9af3bbd7caca0481),
+    (0x11b5248cbe, 0xf907a59cb0ad0ff7, 0x8e1e947a2caf8230),
+    (0x0e45e8b24c, 0x0db33058f0496bcb, 0x8b99eb4397173df2),
+    (0x04f35dbe3d, 0x4d6b6666dd5ed300, 0xcd46bbbe04ffe34e),
+    (0x1d3d841b15, 0x434bdb2563d3ef3f, 0xd56cc3fc0475fcbb),
+    (0x1b54878a7d, 0x960bec88f426b2d5, 0xcc60b5658bbd1598),
+    (0x11b883d575, 0x61dd7df33153fc0d, 0x46a3b49382d73b5c),
+    (0x33ff728b4c, 0x1c2ef72e1eb8ec52, 0xee7e2b3fcc83a0a1),
+    (0x25da3b23df, 0x543b3d9935dbdf17, 0x88d07d9b96ecf15a),
+    (0x318eea0fa2, 0x9339ad953ed7880e, 0x4da8a6723fff505f),
+    (0x1a118014d4, 0xd9e1cb6ca21bf25c, 0xd7df13a295189c30),
+    (0x03eabf486f, 0xf677328c887cf565, 0x951ebfc881d7e2fb),
+    (0x0b15174a10, 0xd2c4348044e3fbd9, 0x8a685447daa72178),
+    (0x1a93faa92f, 0x993683ce2e915c79, 0x3cb55ec511b2b120),
+    (0x0133b53372, 0x26de2f1e19b782b0, 0xdd803f1eaf7c0fed),
+    (0x0356b14605, 0x29985269ccc580fe, 0x85bb90a43f3274ba),
+    (0x0ee3dfce0e, 0x56e05067413f5608, 0xf96e19cc7b1670e5),
+    (0x1241757e3f, 0xf3e00c57550e0c51, 0x663b0c5ae5f77b82),
+    (0x292d80224e, 0x685a7744a6e804a2, 0x91cc35eddfcf0997),
+    (0x000244ddb0, 0xdb666656f88c4020, 0x26e7087e866eaa71),
+    (0x0000000000, 0x09becce62836ac57, 0x74ee367f9430aec4),
+    (0x0000000000, 0x0000000029db1608, 0xce3b49886bcabcaf),
+    (0x367c3a0000, 0x0000000000000000, 0x0000000000000000),
+    (0x316347a7ea, 0x032d4ad5ab069839, 0x610e7c895745fbd2),
+    (0x108b0849d4, 0x1e7945106307c35b, 0x386b12a37dd3b17c),
+    (0x2c093da047, 0x0d53578dd4e72fb7, 0x5fc0f1d27529582b),
+    (0x398e151bbd, 0x2233430e2aebcb3e, 0xf5d2ff35a10beccb),
+    (0x15ac54ecf7, 0x3266fbbc7a49aa4c, 0x597403c835cec08e),
+    (0x34269cd25d, 0x15e74386ea215682, 0xbc399cb8e41556ab),
+    (0x23a39e12df, 0xfc71c667f2321b3c, 0xb6bcc240d016f615),
+    (0x1695275599, 0x11a3806f8f25791c, 0x4c6755db3d33d7f8),
+    (0x235fc63260, 0xfddda8f544743ee1, 0xa8463d16404a362c),
+    (0x26a5141f97, 0xee4109cafbcc06ae, 0xadfee2bf7e869d62),
+    (0x2711fc05a5, 0xfa72f686272e0dc4, 0xd293b919082c552d),
+    (0x01c7cac5a7, 0xce323745e0c19af3, 0xbbd7caca04807a9c),
+    (0x29b87cf907, 0xa59cb0ad0ff78e1e, 0x947a2caf822faf35),
+    (0x19018a0db3, 0x3058f0496bcb8b99, 0xeb4397173df121a5),
+    (0x11a95f4d6b, 0x6666dd5ed300cd46, 0xbbbe04ffe34dd889),
+    (0x02c6b9434b, 0xdb2563d3ef3fd56c, 0xc3fc0475fcba1873),
+    (0x3391f3960b, 0xec88f426b2d5cc60, 0xb5658bbd15972099),
+    (0x0aef5d61dd, 0x7df33153fc0d46a3, 0xb49382d73b5b2759),
+    (0x0dcb041c2e, 0xf72e1eb8ec52ee7e, 0x2b3fcc83a0a0a28e),
+    (0x0d6de1543b, 0x3d9935dbdf1788d0, 0x7d9b96ecf15998a2),
+    (0x3492689339, 0xad953ed7880e4da8, 0xa6723fff505e84fe),
+    (0x1bc0b8d9e1, 0xcb6ca21bf25cd7df, 0x13a295189c2f3155),
+    (0x2daebbf677, 0x328c887cf565951e, 0xbfc881d7e2fa5436),
+    (0x0cb156d2c4, 0x348044e3fbd98a68, 0x5447daa7217750f8),
+    (0x33bb339936, 0x83ce2e915c793cb5, 0x5ec511b2b11fb126),
+    (0x23771826de, 0x2f1e19b782b0dd80, 0x3f1eaf7c0fece459),
+    (0x343a292998, 0x5269ccc580fe85bb, 0x90a43f3274b915e2),
+    (0x342a5056e0, 0x5067413f5608f96e, 0x19cc7b1670e45a0a),
+    (0x15102ff3e0, 0x0c57550e0c51663b, 0x0c5ae5f77b81c16c),
+    (0x17d780685a, 0x7744a6e804a291cc, 0x35eddfcf0996d779),
+    (0x2c6c96db66, 0x6656f88c402026e7, 0x087e866eaa70e7b5),
+    (0x00000009be, 0xcce62836ac5774ee, 0x367f9430aec32bf0),
+    (0x0000000000, 0x000029db1608ce3b, 0x49886bcabcae02be),
+    (0x0000000000, 0x000000000000b3c4, 0xf1ba87bc86968f49),
+    (0x0a8ba20000, 0x0000000000000000, 0x0000000000000000),
+    (0x1c3c3e032d, 0x4ad5ab069839610e, 0x7c895745fbd18be9),
+    (0x1367921e79, 0x45106307c35b386b, 0x12a37dd3b17bcf2f),
+    (0x0bef730d53, 0x578dd4e72fb75fc0, 0xf1d27529582aa77b),
+    (0x17fa492233, 0x430e2aebcb3ef5d2, 0xff35a10becca36ff),
+    (0x35d8653266, 0xfbbc7a49aa4c5974, 0x03c835cec08db159),
+    (0x1a7d8515e7, 0x4386ea215682bc39, 0x9cb8e41556aa411c),
+    (0x261175fc71, 0xc667f2321b3cb6bc, 0xc240d016f6149185),
+    (0x339bf711a3, 0x806f8f25791c4c67, 0x55db3d33d7f74520),
+    (0x0f2494fddd, 0xa8f544743ee1a846, 0x3d16404a362bcc6a),
+    (0x1ac453ee41, 0x09cafbcc06aeadfe, 0xe2bf7e869d610340),
+    (0x0bb119fa72, 0xf686272e0dc4d293, 0xb919082c552c95b0),
+    (0x247b75ce32, 0x3745e0c19af3bbd7, 0xcaca04807a9bc215),
+    (0x14b527a59c, 0xb0ad0ff78e1e947a, 0x2caf822faf340cc7),
+    (0x17f3373058, 0xf0496bcb8b99eb43, 0x97173df121a4e393),
+    (0x08b49d6666, 0xdd5ed300cd46bbbe, 0x04ffe34dd888ed66),
+    (0x1fe313db25, 0x63d3ef3fd56cc3fc, 0x0475fcba18724ae5),
+    (0x389f69ec88, 0xf426b2d5cc60b565, 0x8bbd159720986dd4),
+    (0x0abcf77df3, 0x3153fc0d46a3b493, 0x82d73b5b2758e63f),
+    (0x23ac0cf72e, 0x1eb8ec52ee7e2b3f, 0xcc83a0a0a28ddc38),
+    (0x22bfb93d99, 0x35dbdf1788d07d9b, 0x96ecf15998a10a10),
+    (0x194b0bad95, 0x3ed7880e4da8a672, 0x3fff505e84fd38c7),
+    (0x20b86dcb6c, 0xa21bf25cd7df13a2, 0x95189c2f31542ee2),
+    (0x1ddd6f328c, 0x887cf565951ebfc8, 0x81d7e2fa5435eb9c),
+    (0x3001663480, 0x44e3fbd98a685447, 0xdaa7217750f77623),
+    (0x07022083ce, 0x2e915c793cb55ec5, 0x11b2b11fb1252436),
+    (0x18a22a2f1e, 0x19b782b0dd803f1e, 0xaf7c0fece458897b),
+    (0x180a785269, 0xccc580fe85bb90a4, 0x3f3274b915e10155),
+    (0x1455685067, 0x413f5608f96e19cc, 0x7b1670e45a0927cc),
+    (0x0ffbfa0c57, 0x550e0c51663b0c5a, 0xe5f77b81c16b2ed8),
+    (0x143fde7744, 0xa6e804a291cc35ed, 0xdfcf0996d778cb29),
+    (0x35966e6656, 0xf88c402026e7087e, 0x866eaa70e7b457f6),
+    (0x0009becce6, 0x2836ac5774ee367f, 0x9430aec32befa783),
+    (0x0000000000, 0x29db1608ce3b4988, 0x6bcabcae02bdbc94),
+    (0x0000000000, 0x00000000b3c4f1ba, 0x87bc86968f48a48a),
+    (0x31bf9c0000, 0x0000000000000000, 0x0000000000000000),
+    (0x03d0bb4ad5, 0xab069839610e7c89, 0x5745fbd18be819f5),
+    (0x2105d34510, 0x6307c35b386b12a3, 0x7dd3b17bcf2e3f01),
+    (0x034c75578d, 0xd4e72fb75fc0f1d2, 0x7529582aa77abcbd),
+    (0x2be225430e, 0x2aebcb3ef5d2ff35, 0xa10becca36feb44b),
+    (0x1f6988fbbc, 0x7a49aa4c597403c8, 0x35cec08db158ab72),
+    (0x1833ab4386, 0xea215682bc399cb8, 0xe41556aa411b1983),
+    (0x19f971c667, 0xf2321b3cb6bcc240, 0xd016f6149184a517),
+    (0x391b13806f, 0x8f25791c4c6755db, 0x3d33d7f7451f88f9),
+    (0x2f42c3a8f5, 0x44743ee1a8463d16, 0x404a362bcc69830a),
+    (0x1f3c2509ca, 0xfbcc06aeadfee2bf, 0x7e869d61033fd137),
+    (0x10450cf686, 0x272e0dc4d293b919, 0x082c552c95af4069),
+    (0x284b523745, 0xe0c19af3bbd7caca, 0x04807a9bc2144d53),
+    (0x11183cb0ad, 0x0ff78e1e947a2caf, 0x822faf340cc66497),
+    (0x1613f6f049, 0x6bcb8b99eb439717, 0x3df121a4e3922189),
+    (0x315848dd5e, 0xd300cd46bbbe04ff, 0xe34dd888ed650270),
+    (0x09029d63d3, 0xef3fd56cc3fc0475, 0xfcba18724ae445f2),
+    (0x138ddef426, 0xb2d5cc60b5658bbd, 0x159720986dd388d5),
+    (0x2b0e473153, 0xfc0d46a3b49382d7, 0x3b5b2758e63ecc8b),
+    (0x33315c1eb8, 0xec52ee7e2b3fcc83, 0xa0a0a28ddc374b28),
+    (0x322ead35db, 0xdf1788d07d9b96ec, 0xf15998a10a0f3eaf),
+    (0x0681c13ed7, 0x880e4da8a6723fff, 0x505e84fd38c6031a),
+    (0x1d181ca21b, 0xf25cd7df13a29518, 0x9c2f31542ee12970),
+    (0x3514e4887c, 0xf565951ebfc881d7, 0xe2fa5435eb9bd33a),
+    (0x21d83444e3, 0xfbd98a685447daa7, 0x217750f77622b6b9),
+    (0x2aba142e91, 0x5c793cb55ec511b2, 0xb11fb12524350f18),
+    (0x2dfc2619b7, 0x82b0dd803f1eaf7c, 0x0fece458897a0e13),
+    (0x0ebf1fccc5, 0x80fe85bb90a43f32, 0x74b915e10154d174),
+    (0x034555413f, 0x5608f96e19cc7b16, 0x70e45a0927cb97e2),
+    (0x36055b550e, 0x0c51663b0c5ae5f7, 0x7b81c16b2ed736b9),
+    (0x20a794a6e8, 0x04a291cc35eddfcf, 0x0996d778cb280d1e),
+    (0x0cbac6f88c, 0x402026e7087e866e, 0xaa70e7b457f5b30a),
+    (0x33028c2836, 0xac5774ee367f9430, 0xaec32befa7825303),
+    (0x00000029db, 0x1608ce3b49886bca, 0xbcae02bdbc939960),
+    (0x0000000000, 0x0000b3c4f1ba87bc, 0x86968f48a4899878),
+    (0x0000000000, 0x000000000003041a, 0x7353aafa27e2667c),
+    (0x0189120000, 0x0000000000000000, 0x0000000000000000),
+    (0x01ceb7ab06, 0x9839610e7c895745, 0xfbd18be819f40bc5),
+    (0x35d3c86307, 0xc35b386b12a37dd3, 0xb17bcf2e3f00aca4),
+    (0x36e669d4e7, 0x2fb75fc0f1d27529, 0x582aa77abcbc6c14),
+    (0x1126ca2aeb, 0xcb3ef5d2ff35a10b, 0xecca36feb44ab76b),
+    (0x07c3187a49, 0xaa4c597403c835ce, 0xc08db158ab71507e),
+    (0x0baa92ea21, 0x5682bc399cb8e415, 0x56aa411b1982045e),
+    (0x08b991f232, 0x1b3cb6bcc240d016, 0xf6149184a516e640),
+    (0x1b10c78f25, 0x791c4c6755db3d33, 0xd7f7451f88f8931f),
+    (0x2f80e74474, 0x3ee1a8463d16404a, 0x362bcc6983097de8),
+    (0x0ab904fbcc, 0x06aeadfee2bf7e86, 0x9d61033fd1369e43),
+    (0x2d13c6272e, 0x0dc4d293b919082c, 0x552c95af4068109b),
+    (0x39a36fe0c1, 0x9af3bbd7caca0480, 0x7a9bc2144d52a8fe),
+    (0x2f643f0ff7, 0x8e1e947a2caf822f, 0xaf340cc664969414),
+    (0x002fcb6bcb, 0x8b99eb4397173df1, 0x21a4e3922188fb47),
+    (0x0edcc8d300, 0xcd46bbbe04ffe34d, 0xd888ed65026f9a18),
+    (0x31c75fef3f, 0xd56cc3fc0475fcba, 0x18724ae445f1cf26),
+    (0x03174eb2d5, 0xcc60b5658bbd1597, 0x20986dd388d4e554),
+    (0x134f1bfc0d, 0x46a3b49382d73b5b, 0x2758e63ecc8a1fdb),
+    (0x057ac2ec52, 0xee7e2b3fcc83a0a0, 0xa28ddc374b274f64),
+    (0x03548bdf17, 0x88d07d9b96ecf159, 0x98a10a0f3eae1a24),
+    (0x159de3880e, 0x4da8a6723fff505e, 0x84fd38c60319bb29),
+    (0x17a6c9f25c, 0xd7df13a295189c2f, 0x31542ee1296f34db),
+    (0x32a66ef565, 0x951ebfc881d7e2fa, 0x5435eb9bd3391dbc),
+    (0x1c3a4bfbd9, 0x8a685447daa72177, 0x50f77622b6b8e28a),
+    (0x292dfd5c79, 0x3cb55ec511b2b11f, 0xb12524350f17edea),
+    (0x3b42b782b0, 0xdd803f1eaf7c0fec, 0xe458897a0e12f1b5),
+    (0x0216e980fe, 0x85bb90a43f3274b9, 0x15e10154d1737862),
+    (0x12f3c75608, 0xf96e19cc7b1670e4, 0x5a0927cb97e196eb),
+    (0x25d9e60c51, 0x663b0c5ae5f77b81, 0xc16b2ed736b810e3),
+    (0x077c6804a2, 0x91cc35eddfcf0996, 0xd778cb280d1d08cc),
+    (0x145cd44020, 0x26e7087e866eaa70, 0xe7b457f5b309a0f2),
+    (0x020cdaac57, 0x74ee367f9430aec3, 0x2befa78253027daa),
+    (0x0029db1608, 0xce3b49886bcabcae, 0x02bdbc93995f23d4),
+    (0x0000000000, 0xb3c4f1ba87bc8696, 0x8f48a4899877186d),
+    (0x0000000000, 0x00000003041a7353, 0xaafa27e2667b02ee),
+    (0x0000000000, 0x000000000000000c, 0xf42894a5dce35ea6),
+];
+
+// FIXME(#22540) const ref to static array seems to ICE
+#[doc(hidden)]
+pub static MIN_BLOCK_2: [u8; 68] = [
+    0, 0, 0, 0, 0, 0, 1, 1, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 10, 10,
+    11, 11, 12, 12, 13, 13, 14, 14, 15, 15, 16, 16, 17, 18, 18, 19, 19, 20, 20, 21, 21, 22, 22, 23,
+    23, 24, 24, 25, 26, 26, 27, 27, 28, 28, 29, 29, 30, 30, 31, 31, 32, 33, 33, 34,
+];
+
+// FIXME(#22540) const ref to static array seems to ICE
+#[doc(hidden)]
+pub static POW10_OFFSET_2: [u16; 69] = [
+    0, 2, 6, 12, 19, 28, 39, 51, 65, 79, 94, 111, 129, 148,
+    168, 190, 213, 238, 263, 290, 318, 348, 378, 409, 442, 476, 512, 548,
+    586, 625, 666, 707, 750, 794, 840, 887, 935, 984, 1034, 1086, 1138, 1192,
+    1247, 1304, 1362, 1421, 1481, 1543, 1606, 1670, 1735, 1802, 1870, 1939, 2009, 2080,
+    2153, 2227, 2302, 2378, 2456, 2535, 2616, 2697, 2780, 2864, 2949, 3035, 3122,
+];
+
+// FIXME(#22540) const ref to static array seems to ICE
+#[doc(hidden)]
+pub static POW10_SPLIT_2: [(u64, u64, u64); 3122] = [ // (hi, mid, lo)
+    (0x00003b9aca, 0x0000000000000000, 0x0000000000000000),
+    (0x2f08236400, 0x0000000000000000, 0x0000000000000000),
+    (0x000000003b, 0x9aca000000000000, 0x0000000000000000),
+    (0x0de0b6b3a7, 0x6400000000000000, 0x0000000000000000),
+    (0x26f9423ce8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x003b9aca00000000, 0x0000000000000000),
+    (0x00000de0b6, 0xb3a7640000000000, 0x0000000000000000),
+    (0x2a89dfd080, 0x3ce8000000000000, 0x0000000000000000),
+    (0x1ddb934b9f, 0x1000000000000000, 0x0000000000000000),
+    (0x2508dd22a0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000003b9aca0000, 0x0000000000000000),
+    (0x000000000d, 0xe0b6b3a764000000, 0x0000000000000000),
+    (0x033b2e3c9f, 0xd0803ce800000000, 0x0000000000000000),
+    (0x2535af15b3, 0x4b9f100000000000, 0x0000000000000000),
+    (0x29bed6a00b, 0x22a0000000000000, 0x0000000000000000),
+    (0x0297b67d92, 0x4000000000000000, 0x0000000000000000),
+    (0x220d2de680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000003b9aca, 0x0000000000000000),
+    (0x0000000000, 0x000de0b6b3a76400, 0x0000000000000000),
+    (0x0000033b2e, 0x3c9fd0803ce80000, 0x0000000000000000),
+    (0x0ac3edc907, 0x15b34b9f10000000, 0x0000000000000000),
+    (0x01a648f768, 0xa00b22a000000000, 0x0000000000000000),
+    (0x185c2565f6, 0x7d92400000000000, 0x0000000000000000),
+    (0x3963fabdcf, 0xe680000000000000, 0x0000000000000000),
+    (0x3067d434e1, 0x0000000000000000, 0x0000000000000000),
+    (0x34630b8a00, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000000003b, 0x9aca000000000000),
+    (0x0000000000, 0x0000000de0b6b3a7, 0x6400000000000000),
+    (0x0000000003, 0x3b2e3c9fd0803ce8, 0x0000000000000000),
+    (0x00c097ce7b, 0xc90715b34b9f1000, 0x0000000000000000),
+    (0x1cd22d3ce2, 0xf768a00b22a00000, 0x0000000000000000),
+    (0x34d841090b, 0x65f67d9240000000, 0x0000000000000000),
+    (0x02a76423ac, 0xbdcfe68000000000, 0x0000000000000000),
+    (0x28383166f6, 0x34e1000000000000, 0x0000000000000000),
+    (0x39530df013, 0x8a00000000000000, 0x0000000000000000),
+    (0x048c9e6ee4, 0x0000000000000000, 0x0000000000000000),
+    (0x3515dbe800, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000de0b6, 0xb3a7640000000000),
+    (0x0000000000, 0x00033b2e3c9fd080, 0x3ce8000000000000),
+    (0x000000c097, 0xce7bc90715b34b9f, 0x1000000000000000),
+    (0x23586086b9, 0x3ce2f768a00b22a0, 0x0000000000000000),
+    (0x2b21091999, 0x090b65f67d924000, 0x0000000000000000),
+    (0x23a19dd27e, 0x23acbdcfe6800000, 0x0000000000000000),
+    (0x1d5e7dcdcf, 0x66f634e100000000, 0x0000000000000000),
+    (0x304a225589, 0xf0138a0000000000, 0x0000000000000000),
+    (0x201dbbc3fe, 0x6ee4000000000000, 0x0000000000000000),
+    (0x3b3d6604a7, 0xe800000000000000, 0x0000000000000000),
+    (0x2717fe0d10, 0x0000000000000000, 0x0000000000000000),
+    (0x03b9aca000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000000000d, 0xe0b6b3a764000000),
+    (0x0000000000, 0x000000033b2e3c9f, 0xd0803ce800000000),
+    (0x0000000000, 0xc097ce7bc90715b3, 0x4b9f100000000000),
+    (0x002cd76fe0, 0x86b93ce2f768a00b, 0x22a0000000000000),
+    (0x3446cee6c5, 0x1999090b65f67d92, 0x4000000000000000),
+    (0x2de4133157, 0xd27e23acbdcfe680, 0x0000000000000000),
+    (0x14729cfe31, 0xcdcf66f634e10000, 0x0000000000000000),
+    (0x0b988be7e4, 0x5589f0138a000000, 0x0000000000000000),
+    (0x3529c66acd, 0xc3fe6ee400000000, 0x0000000000000000),
+    (0x2fe895e744, 0x04a7e80000000000, 0x0000000000000000),
+    (0x0fd6332b26, 0x0d10000000000000, 0x0000000000000000),
+    (0x08dc0491ee, 0xa000000000000000, 0x0000000000000000),
+    (0x378f288a40, 0x0000000000000000, 0x0000000000000000),
+    (0x0ee6b28000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000033b2e, 0x3c9fd0803ce80000),
+    (0x0000000000, 0x0000c097ce7bc907, 0x15b34b9f10000000),
+    (0x0000002cd7, 0x6fe086b93ce2f768, 0xa00b22a000000000),
+    (0x32290c0a64, 0xe6c51999090b65f6, 0x7d92400000000000),
+    (0x177e31dd8f, 0x3157d27e23acbdcf, 0xe680000000000000),
+    (0x2156f3e946, 0xfe31cdcf66f634e1, 0x0000000000000000),
+    (0x10878268f7, 0xe7e45589f0138a00, 0x0000000000000000),
+    (0x39b856bc0c, 0x6acdc3fe6ee40000, 0x0000000000000000),
+    (0x02e41f7839, 0xe74404a7e8000000, 0x0000000000000000),
+    (0x0d7b4f7c79, 0x2b260d1000000000, 0x0000000000000000),
+    (0x1c363555f2, 0x91eea00000000000, 0x0000000000000000),
+    (0x387a4d2f92, 0x8a40000000000000, 0x0000000000000000),
+    (0x221e778b96, 0x8000000000000000, 0x0000000000000000),
+    (0x230a7fc100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000c097ce7b, 0xc90715b34b9f1000),
+    (0x0000000000, 0x2cd76fe086b93ce2, 0xf768a00b22a00000),
+    (0x000a70c3c4, 0x0a64e6c51999090b, 0x65f67d9240000000),
+    (0x2da4ee3215, 0xdd8f3157d27e23ac, 0xbdcfe68000000000),
+    (0x0517488955, 0xe946fe31cdcf66f6, 0x34e1000000000000),
+    (0x1400b57b5c, 0x68f7e7e45589f013, 0x8a00000000000000),
+    (0x1584113266, 0xbc0c6acdc3fe6ee4, 0x0000000000000000),
+    (0x17eb750c77, 0x7839e74404a7e800, 0x0000000000000000),
+    (0x1bd0f1efff, 0x7c792b260d100000, 0x0000000000000000),
+    (0x3b7c2a660d, 0x55f291eea0000000, 0x0000000000000000),
+    (0x031adf215f, 0x2f928a4000000000, 0x0000000000000000),
+    (0x1629847f8d, 0x8b96800000000000, 0x0000000000000000),
+    (0x20f4c1582d, 0xc100000000000000, 0x0000000000000000),
+    (0x0aa725344a, 0x0000000000000000, 0x0000000000000000),
+    (0x113abe6400, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000000c097, 0xce7bc90715b34ba0),
+    (0x0000000000, 0x00002cd76fe086b9, 0x3ce2f768a00b22a0),
+    (0x0000000a70, 0xc3c40a64e6c51999, 0x090b65f67d924000),
+    (0x1a414ceccc, 0x3215dd8f3157d27e, 0x23acbdcfe6800000),
+    (0x2f8b024abc, 0x8955e946fe31cdcf, 0x66f634e100000000),
+    (0x2be5a62ecf, 0x7b5c68f7e7e45589, 0xf0138a0000000000),
+    (0x304ee2391f, 0x3266bc0c6acdc3fe, 0x6ee4000000000000),
+    (0x07437a9ce9, 0x0c777839e74404a7, 0xe800000000000000),
+    (0x3642c8ec69, 0xefff7c792b260d10, 0x0000000000000000),
+    (0x18aa5dd8c0, 0x660d55f291eea000, 0x0000000000000000),
+    (0x2ccbda475b, 0x215f2f928a400000, 0x0000000000000000),
+    (0x1537caeb8e, 0x7f8d8b9680000000, 0x0000000000000000),
+    (0x212d8ecaf7, 0x582dc10000000000, 0x0000000000000000),
+    (0x3996e0c295, 0x344a000000000000, 0x0000000000000000),
+    (0x22bd443dc6, 0x6400000000000000, 0x0000000000000000),
+    (0x2e3100b2e8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000002cd76fe0, 0x86b93ce2f768a00c),
+    (0x0000000000, 0x0a70c3c40a64e6c5, 0x1999090b65f67d93),
+    (0x00026e4d30, 0xeccc3215dd8f3157, 0xd27e23acbdcfe680),
+    (0x0b64281d3a, 0x4abc8955e946fe31, 0xcdcf66f634e10000),
+    (0x0d9278680a, 0x2ecf7b5c68f7e7e4, 0x5589f0138a000000),
+    (0x025ef2032e, 0x391f3266bc0c6acd, 0xc3fe6ee400000000),
+    (0x0ac31d0674, 0x9ce90c777839e744, 0x04a7e80000000000),
+    (0x1b26ac1de0, 0xec69efff7c792b26, 0x0d10000000000000),
+    (0x345e7c1c95, 0xd8c0660d55f291ee, 0xa000000000000000),
+    (0x22e38ef84a, 0x475b215f2f928a40, 0x0000000000000000),
+    (0x114b5b89d0, 0xeb8e7f8d8b968000, 0x0000000000000000),
+    (0x30a49c64fc, 0xcaf7582dc1000000, 0x0000000000000000),
+    (0x3adba09244, 0xc295344a00000000, 0x0000000000000000),
+    (0x10026bb258, 0x3dc6640000000000, 0x0000000000000000),
+    (0x148b978322, 0xb2e8000000000000, 0x0000000000000000),
+    (0x08143678bb, 0x1000000000000000, 0x0000000000000000),
+    (0x2b8dcb3aa0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000002cd7, 0x6fe086b93ce2f769),
+    (0x0000000000, 0x00000a70c3c40a64, 0xe6c51999090b65f7),
+    (0x000000026e, 0x4d30eccc3215dd8f, 0x3157d27e23acbdd0),
+    (0x19ae7bbeea, 0x1d3a4abc8955e946, 0xfe31cdcf66f634e1),
+    (0x36824abf5a, 0x680a2ecf7b5c68f7, 0xe7e45589f0138a00),
+    (0x150ca44502, 0x032e391f3266bc0c, 0x6acdc3fe6ee40000),
+    (0x0077f3277b, 0x06749ce90c777839, 0xe74404a7e8000000),
+    (0x1ca4dfd568, 0x1de0ec69efff7c79, 0x2b260d1000000000),
+    (0x183dd6f75e, 0x1c95d8c0660d55f2, 0x91eea00000000000),
+    (0x15e97dfda6, 0xf84a475b215f2f92, 0x8a40000000000000),
+    (0x26e02e3b0b, 0x89d0eb8e7f8d8b96, 0x8000000000000000),
+    (0x02afbd28b6, 0x64fccaf7582dc100, 0x0000000000000000),
+    (0x2a778eee88, 0x9244c295344a0000, 0x0000000000000000),
+    (0x1fcc49999f, 0xb2583dc664000000, 0x0000000000000000),
+    (0x252ea9a20b, 0x8322b2e800000000, 0x0000000000000000),
+    (0x02ae2ef794, 0x78bb100000000000, 0x0000000000000000),
+    (0x229198e47b, 0x3aa0000000000000, 0x0000000000000000),
+    (0x1cb1056082, 0x4000000000000000, 0x0000000000000000),
+    (0x1e53814680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000a70c3c4, 0x0a64e6c51999090c),
+    (0x0000000000, 0x026e4d30eccc3215, 0xdd8f3157d27e23ad),
+    (0x000090e40f, 0xbeea1d3a4abc8955, 0xe946fe31cdcf66f7),
+    (0x03aa853a36, 0xbf5a680a2ecf7b5c, 0x68f7e7e45589f014),
+    (0x0cbf34255a, 0x4502032e391f3266, 0xbc0c6acdc3fe6ee4),
+    (0x15047c3465, 0x277b06749ce90c77, 0x7839e74404a7e800),
+    (0x178d42eba5, 0xd5681de0ec69efff, 0x7c792b260d100000),
+    (0x269c7439e8, 0xf75e1c95d8c0660d, 0x55f291eea0000000),
+    (0x363ddf5263, 0xfda6f84a475b215f, 0x2f928a4000000000),
+    (0x1747eaf7d0, 0x3b0b89d0eb8e7f8d, 0x8b96800000000000),
+    (0x307b837c4b, 0x28b664fccaf7582d, 0xc100000000000000),
+    (0x117fd3d51e, 0xee889244c295344a, 0x0000000000000000),
+    (0x0733ad6015, 0x999fb2583dc66400, 0x0000000000000000),
+    (0x0507774363, 0xa20b8322b2e80000, 0x0000000000000000),
+    (0x173296c002, 0xf79478bb10000000, 0x0000000000000000),
+    (0x00b0da7e7e, 0xe47b3aa000000000, 0x0000000000000000),
+    (0x1d8b61f8ed, 0x6082400000000000, 0x0000000000000000),
+    (0x3744c56141, 0x4680000000000000, 0x0000000000000000),
+    (0x0f32b76aa1, 0x0000000000000000, 0x0000000000000000),
+    (0x257c590a00, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000a70, 0xc3c40a64e6c5199a),
+    (0x0000000000, 0x0000026e4d30eccc, 0x3215dd8f3157d27f),
+    (0x0000000090, 0xe40fbeea1d3a4abc, 0x8955e946fe31cdd0),
+    (0x21bc2b266d, 0x3a36bf5a680a2ecf, 0x7b5c68f7e7e4558a),
+    (0x196e75d2f8, 0x255a4502032e391f, 0x3266bc0c6acdc3ff),
+    (0x39c6a613ae, 0x3465277b06749ce9, 0x0c777839e74404a8),
+    (0x288f684a4a, 0xeba5d5681de0ec69, 0xefff7c792b260d10),
+    (0x11719c17e2, 0x39e8f75e1c95d8c0, 0x660d55f291eea000),
+    (0x34ac2208d9, 0x5263fda6f84a475b, 0x215f2f928a400000),
+    (0x329964169e, 0xf7d03b0b89d0eb8e, 0x7f8d8b9680000000),
+    (0x25033b8069, 0x7c4b28b664fccaf7, 0x582dc10000000000),
+    (0x188f6d53ab, 0xd51eee889244c295, 0x344a000000000000),
+    (0x280203ebbf, 0x6015999fb2583dc6, 0x6400000000000000),
+    (0x2c8edbc937, 0x4363a20b8322b2e8, 0x0000000000000000),
+    (0x0cddf21b74, 0xc002f79478bb1000, 0x0000000000000000),
+    (0x1b2ed8505a, 0x7e7ee47b3aa00000, 0x0000000000000000),
+    (0x1511debecd, 0xf8ed608240000000, 0x0000000000000000),
+    (0x2ff4e8fa75, 0x6141468000000000, 0x0000000000000000),
+    (0x1b54632b41, 0x6aa1000000000000, 0x0000000000000000),
+    (0x0f3b20ddfd, 0x0a00000000000000, 0x0000000000000000),
+    (0x3aea4dade4, 0x0000000000000000, 0x0000000000000000),
+    (0x3515dbe800, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000026e4d30, 0xeccc3215dd8f3158),
+    (0x0000000000, 0x0090e40fbeea1d3a, 0x4abc8955e946fe32),
+    (0x000021bc2b, 0x266d3a36bf5a680a, 0x2ecf7b5c68f7e7e5),
+    (0x0a0bf25671, 0xd2f8255a4502032e, 0x391f3266bc0c6ace),
+    (0x1a8071ea5a, 0x13ae3465277b0674, 0x9ce90c777839e745),
+    (0x14f9001066, 0x4a4aeba5d5681de0, 0xec69efff7c792b27),
+    (0x17d0f8ac00, 0x17e239e8f75e1c95, 0xd8c0660d55f291ef),
+    (0x00058f9448, 0x08d95263fda6f84a, 0x475b215f2f928a40),
+    (0x10c5983fb4, 0x169ef7d03b0b89d0, 0xeb8e7f8d8b968000),
+    (0x29ee1a5897, 0x80697c4b28b664fc, 0xcaf7582dc1000000),
+    (0x2346331a6d, 0x53abd51eee889244, 0xc295344a00000000),
+    (0x1974633381, 0xebbf6015999fb258, 0x3dc6640000000000),
+    (0x1e3fe37049, 0xc9374363a20b8322, 0xb2e8000000000000),
+    (0x112dfd008c, 0x1b74c002f79478bb, 0x1000000000000000),
+    (0x209f0afa26, 0x505a7e7ee47b3aa0, 0x0000000000000000),
+    (0x08ebaf6cfe, 0xbecdf8ed60824000, 0x0000000000000000),
+    (0x3b500142d4, 0xfa75614146800000, 0x0000000000000000),
+    (0x31967fc5a7, 0x2b416aa100000000, 0x0000000000000000),
+    (0x26ec0c010e, 0xddfd0a0000000000, 0x0000000000000000),
+    (0x03762696af, 0xade4000000000000, 0x0000000000000000),
+    (0x28e74cc65d, 0xe800000000000000, 0x0000000000000000),
+    (0x15dd3fa910, 0x0000000000000000, 0x0000000000000000),
+    (0x03b9aca000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000000026e, 0x4d30eccc3215dd90),
+    (0x0000000000, 0x00000090e40fbeea, 0x1d3a4abc8955e947),
+    (0x0000000021, 0xbc2b266d3a36bf5a, 0x680a2ecf7b5c68f8),
+    (0x07dac3c24a, 0x5671d2f8255a4502, 0x032e391f3266bc0d),
+    (0x114edee44d, 0xea5a13ae3465277b, 0x06749ce90c77783a),
+    (0x12241f37a4, 0x10664a4aeba5d568, 0x1de0ec69efff7c7a),
+    (0x2632fae598, 0xac0017e239e8f75e, 0x1c95d8c0660d55f3),
+    (0x238bf3f547, 0x944808d95263fda6, 0xf84a475b215f2f93),
+    (0x10aa744860, 0x3fb4169ef7d03b0b, 0x89d0eb8e7f8d8b97),
+    (0x1668e0c5d0, 0x589780697c4b28b6, 0x64fccaf7582dc100),
+    (0x3082649ba3, 0x1a6d53abd51eee88, 0x9244c295344a0000),
+    (0x25f9b5cae7, 0x3381ebbf6015999f, 0xb2583dc664000000),
+    (0x35d4aa5c21, 0x7049c9374363a20b, 0x8322b2e800000000),
+    (0x07c918f05d, 0x008c1b74c002f794, 0x78bb100000000000),
+    (0x15a75c010a, 0xfa26505a7e7ee47b, 0x3aa0000000000000),
+    (0x028e49f8f3, 0x6cfebecdf8ed6082, 0x4000000000000000),
+    (0x38ad4e5b39, 0x42d4fa7561414680, 0x0000000000000000),
+    (0x0d55067893, 0xc5a72b416aa10000, 0x0000000000000000),
+    (0x2267e7077e, 0x010eddfd0a000000, 0x0000000000000000),
+    (0x1d566e7cf0, 0x96afade400000000, 0x0000000000000000),
+    (0x380432f9a8, 0xc65de80000000000, 0x0000000000000000),
+    (0x274bc4257b, 0xa910000000000000, 0x0000000000000000),
+    (0x1ccabbf706, 0xa000000000000000, 0x0000000000000000),
+    (0x018ae17a40, 0x0000000000000000, 0x0000000000000000),
+    (0x0ee6b28000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000090e40f, 0xbeea1d3a4abc8956),
+    (0x0000000000, 0x0021bc2b266d3a36, 0xbf5a680a2ecf7b5d),
+    (0x000007dac3, 0xc24a5671d2f8255a, 0x4502032e391f3267),
+    (0x2d942479f2, 0xe44dea5a13ae3465, 0x277b06749ce90c78),
+    (0x388d7af407, 0x37a410664a4aeba5, 0xd5681de0ec69f000),
+    (0x01ae2ffa60, 0xe598ac0017e239e8, 0xf75e1c95d8c0660e),
+    (0x168f80c2a5, 0xf547944808d95263, 0xfda6f84a475b2160),
+    (0x26a3dfffc6, 0x48603fb4169ef7d0, 0x3b0b89d0eb8e7f8e),
+    (0x2e2a922db0, 0xc5d0589780697c4b, 0x28b664fccaf7582e),
+    (0x2928797fd6, 0x9ba31a6d53abd51e, 0xee889244c295344a),
+    (0x31f7a18e03, 0xcae73381ebbf6015, 0x999fb2583dc66400),
+    (0x00e20e560e, 0x5c217049c9374363, 0xa20b8322b2e80000),
+    (0x0357ea75b0, 0xf05d008c1b74c002, 0xf79478bb10000000),
+    (0x293261a4bc, 0x010afa26505a7e7e, 0xe47b3aa000000000),
+    (0x2bc5ea8113, 0xf8f36cfebecdf8ed, 0x6082400000000000),
+    (0x04a6739efe, 0x5b3942d4fa756141, 0x4680000000000000),
+    (0x3b38d1c6d4, 0x7893c5a72b416aa1, 0x0000000000000000),
+    (0x3178423e97, 0x077e010eddfd0a00, 0x0000000000000000),
+    (0x232a0bb7f4, 0x7cf096afade40000, 0x0000000000000000),
+    (0x38ec9f860a, 0xf9a8c65de8000000, 0x0000000000000000),
+    (0x028e2cbe3e, 0x257ba91000000000, 0x0000000000000000),
+    (0x0e783715ed, 0xf706a00000000000, 0x0000000000000000),
+    (0x3767d0e5c7, 0x7a40000000000000, 0x0000000000000000),
+    (0x2e71c9b0f6, 0x8000000000000000, 0x0000000000000000),
+    (0x39648b8100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000090, 0xe40fbeea1d3a4abd),
+    (0x0000000000, 0x00000021bc2b266d, 0x3a36bf5a680a2ed0),
+    (0x0000000007, 0xdac3c24a5671d2f8, 0x255a4502032e3920),
+    (0x01d42aea28, 0x79f2e44dea5a13ae, 0x3465277b06749cea),
+    (0x096c9446f4, 0xf40737a410664a4a, 0xeba5d5681de0ec6a),
+    (0x390859beb7, 0xfa60e598ac0017e2, 0x39e8f75e1c95d8c1),
+    (0x2ad5f21ec4, 0xc2a5f547944808d9, 0x5263fda6f84a475c),
+    (0x2dcfd498f3, 0xffc648603fb4169e, 0xf7d03b0b89d0eb8f),
+    (0x38cf7b17c8, 0x2db0c5d058978069, 0x7c4b28b664fccaf8),
+    (0x2e9b912dfb, 0x7fd69ba31a6d53ab, 0xd51eee889244c296),
+    (0x3a8e87cfd7, 0x8e03cae73381ebbf, 0x6015999fb2583dc7),
+    (0x323010641a, 0x560e5c217049c937, 0x4363a20b8322b2e8),
+    (0x0621c1dbc6, 0x75b0f05d008c1b74, 0xc002f79478bb1000),
+    (0x2e351f2cb3, 0xa4bc010afa26505a, 0x7e7ee47b3aa00000),
+    (0x29d3962d52, 0x8113f8f36cfebecd, 0xf8ed608240000000),
+    (0x1335a25a3d, 0x9efe5b3942d4fa75, 0x6141468000000000),
+    (0x0e58e6e37d, 0xc6d47893c5a72b41, 0x6aa1000000000000),
+    (0x1d48dfd27e, 0x3e97077e010eddfd, 0x0a00000000000000),
+    (0x1d64c212f7, 0xb7f47cf096afade4, 0x0000000000000000),
+    (0x39ad2d7905, 0x860af9a8c65de800, 0x0000000000000000),
+    (0x01493b85e8, 0xbe3e257ba9100000, 0x0000000000000000),
+    (0x3630926a23, 0x15edf706a0000000, 0x0000000000000000),
+    (0x082b44b862, 0xe5c77a4000000000, 0x0000000000000000),
+    (0x1706c13c7b, 0xb0f6800000000000, 0x0000000000000000),
+    (0x1ccc92dd6b, 0x8100000000000000, 0x0000000000000000),
+    (0x1907bb6dca, 0x0000000000000000, 0x0000000000000000),
+    (0x2f08236400, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000021bc2b, 0x266d3a36bf5a680b),
+    (0x0000000000, 0x0007dac3c24a5671, 0xd2f8255a4502032f),
+    (0x000001d42a, 0xea2879f2e44dea5a, 0x13ae3465277b0675),
+    (0x09fdea0d38, 0x46f4f40737a41066, 0x4a4aeba5d5681de1),
+    (0x0d1a618b95, 0xbeb7fa60e598ac00, 0x17e239e8f75e1c96),
+    (0x22dd7f49de, 0x1ec4c2a5f5479448, 0x08d95263fda6f84b),
+    (0x33b7651f80, 0x98f3ffc648603fb4, 0x169ef7d03b0b89d1),
+    (0x1df101b76b, 0x17c82db0c5d05897, 0x80697c4b28b664fd),
+    (0x18ef3bf1b7, 0x2dfb7fd69ba31a6d, 0x53abd51eee889245),
+    (0x2aa65b2a09, 0xcfd78e03cae73381, 0xebbf6015999fb259),
+    (0x0248d57366, 0x641a560e5c217049, 0xc9374363a20b8323),
+    (0x17d6fb14a9, 0xdbc675b0f05d008c, 0x1b74c002f79478bc),
+    (0x278c5af9ed, 0x2cb3a4bc010afa26, 0x505a7e7ee47b3aa0),
+    (0x3738b5704e, 0x2d528113f8f36cfe, 0xbecdf8ed60824000),
+    (0x1233b6f724, 0x5a3d9efe5b3942d4, 0xfa75614146800000),
+    (0x0876c72bea, 0xe37dc6d47893c5a7, 0x2b416aa100000000),
+    (0x36b0742dfd, 0xd27e3e97077e010e, 0xddfd0a0000000000),
+    (0x3b18fc0076, 0x12f7b7f47cf096af, 0xade4000000000000),
+    (0x1b7dc3ab61, 0x7905860af9a8c65d, 0xe800000000000000),
+    (0x16b1d3fcb5, 0x85e8be3e257ba910, 0x0000000000000000),
+    (0x2a439e6d84, 0x6a2315edf706a000, 0x0000000000000000),
+    (0x1ed4866ae8, 0xb862e5c77a400000, 0x0000000000000000),
+    (0x362f3557f1, 0x3c7bb0f680000000, 0x0000000000000000),
+    (0x382acd3dea, 0xdd6b810000000000, 0x0000000000000000),
+    (0x36af0a4c1d, 0x6dca000000000000, 0x0000000000000000),
+    (0x06da18d225, 0x6400000000000000, 0x0000000000000000),
+    (0x08b4a7a8e8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000021, 0xbc2b266d3a36bf5b),
+    (0x0000000000, 0x00000007dac3c24a, 0x5671d2f8255a4503),
+    (0x0000000001, 0xd42aea2879f2e44d, 0xea5a13ae3465277c),
+    (0x006d00f732, 0x0d3846f4f40737a4, 0x10664a4aeba5d569),
+    (0x0ba74f6aa3, 0x8b95beb7fa60e598, 0xac0017e239e8f75f),
+    (0x26140e872d, 0x49de1ec4c2a5f547, 0x944808d95263fda7),
+    (0x0a8b685cff, 0x1f8098f3ffc64860, 0x3fb4169ef7d03b0c),
+    (0x3b6684e577, 0xb76b17c82db0c5d0, 0x589780697c4b28b7),
+    (0x1bdfa87ba1, 0xf1b72dfb7fd69ba3, 0x1a6d53abd51eee89),
+    (0x25b4a06885, 0x2a09cfd78e03cae7, 0x3381ebbf601599a0),
+    (0x1f01349ff9, 0x7366641a560e5c21, 0x7049c9374363a20c),
+    (0x3a146cd9b9, 0x14a9dbc675b0f05d, 0x008c1b74c002f795),
+    (0x2b17ab9e22, 0xf9ed2cb3a4bc010a, 0xfa26505a7e7ee47c),
+    (0x0824bf9b2f, 0x704e2d528113f8f3, 0x6cfebecdf8ed6083),
+    (0x0b0b910216, 0xf7245a3d9efe5b39, 0x42d4fa7561414680),
+    (0x0558d82bad, 0x2beae37dc6d47893, 0xc5a72b416aa10000),
+    (0x2851d43262, 0x2dfdd27e3e97077e, 0x010eddfd0a000000),
+    (0x16dbf6a27e, 0x007612f7b7f47cf0, 0x96afade400000000),
+    (0x1d564ae9c3, 0xab617905860af9a8, 0xc65de80000000000),
+    (0x2d8ecef4c1, 0xfcb585e8be3e257b, 0xa910000000000000),
+    (0x2d2a88ec76, 0x6d846a2315edf706, 0xa000000000000000),
+    (0x1b92d8d888, 0x6ae8b862e5c77a40, 0x0000000000000000),
+    (0x1fc31f96d9, 0x57f13c7bb0f68000, 0x0000000000000000),
+    (0x329aaeff73, 0x3deadd6b81000000, 0x0000000000000000),
+    (0x1ad4f34f2c, 0x4c1d6dca00000000, 0x0000000000000000),
+    (0x0a50538610, 0xd225640000000000, 0x0000000000000000),
+    (0x03ea9a4e5b, 0xa8e8000000000000, 0x0000000000000000),
+    (0x15575966d7, 0x1000000000000000, 0x0000000000000000),
+    (0x3212b952a0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000007dac3, 0xc24a5671d2f8255b),
+    (0x0000000000, 0x0001d42aea2879f2, 0xe44dea5a13ae3466),
+    (0x0000006d00, 0xf7320d3846f4f407, 0x37a410664a4aeba6),
+    (0x00398e0035, 0x6aa38b95beb7fa60, 0xe598ac0017e239e9),
+    (0x0c6fdffdb2, 0x872d49de1ec4c2a5, 0xf547944808d95264),
+    (0x2991199fee, 0x5cff1f8098f3ffc6, 0x48603fb4169ef7d1),
+    (0x377f8ed31c, 0xe577b76b17c82db0, 0xc5d0589780697c4c),
+    (0x06ba5b6e5a, 0x7ba1f1b72dfb7fd6, 0x9ba31a6d53abd51f),
+    (0x15113417ae, 0x68852a09cfd78e03, 0xcae73381ebbf6016),
+    (0x289b8b2f44, 0x9ff97366641a560e, 0x5c217049c9374364),
+    (0x0ffa5cdfe2, 0xd9b914a9dbc675b0, 0xf05d008c1b74c003),
+    (0x34d157a0e5, 0x9e22f9ed2cb3a4bc, 0x010afa26505a7e7f),
+    (0x3576485f6b, 0x9b2f704e2d528113, 0xf8f36cfebecdf8ee),
+    (0x190dd431df, 0x0216f7245a3d9efe, 0x5b3942d4fa756142),
+    (0x33ec52846c, 0x2bad2beae37dc6d4, 0x7893c5a72b416aa1),
+    (0x192f7889c2, 0x32622dfdd27e3e97, 0x077e010eddfd0a00),
+    (0x2d37082b6a, 0xa27e007612f7b7f4, 0x7cf096afade40000),
+    (0x18d3ecee1e, 0xe9c3ab617905860a, 0xf9a8c65de8000000),
+    (0x0732911ca8, 0xf4c1fcb585e8be3e, 0x257ba91000000000),
+    (0x2756914310, 0xec766d846a2315ed, 0xf706a00000000000),
+    (0x03f0bae510, 0xd8886ae8b862e5c7, 0x7a40000000000000),
+    (0x03ec16fd8f, 0x96d957f13c7bb0f6, 0x8000000000000000),
+    (0x216e96230a, 0xff733deadd6b8100, 0x0000000000000000),
+    (0x028f85e829, 0x4f2c4c1d6dca0000, 0x0000000000000000),
+    (0x099e396ea9, 0x8610d22564000000, 0x0000000000000000),
+    (0x2778664a56, 0x4e5ba8e800000000, 0x0000000000000000),
+    (0x14183e5ce5, 0x66d7100000000000, 0x0000000000000000),
+    (0x3569687135, 0x52a0000000000000, 0x0000000000000000),
+    (0x0c6a48a772, 0x4000000000000000, 0x0000000000000000),
+    (0x1a99d4a680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000001d42aea28, 0x79f2e44dea5a13af),
+    (0x0000000000, 0x6d00f7320d3846f4, 0xf40737a410664a4b),
+    (0x0019612190, 0x00356aa38b95beb7, 0xfa60e598ac0017e3),
+    (0x21871e0fdf, 0xfdb2872d49de1ec4, 0xc2a5f547944808da),
+    (0x3426e780bb, 0x9fee5cff1f8098f3, 0xffc648603fb4169f),
+    (0x2baf4e3104, 0xd31ce577b76b17c8, 0x2db0c5d05897806a),
+    (0x011f9276d9, 0x6e5a7ba1f1b72dfb, 0x7fd69ba31a6d53ac),
+    (0x329fe6ce00, 0x17ae68852a09cfd7, 0x8e03cae73381ebc0),
+    (0x00058383b1, 0x2f449ff97366641a, 0x560e5c217049c938),
+    (0x2941070f72, 0xdfe2d9b914a9dbc6, 0x75b0f05d008c1b75),
+    (0x1abf0e9b4d, 0xa0e59e22f9ed2cb3, 0xa4bc010afa26505b),
+    (0x121304f688, 0x5f6b9b2f704e2d52, 0x8113f8f36cfebece),
+    (0x1fc072ceca, 0x31df0216f7245a3d, 0x9efe5b3942d4fa76),
+    (0x2f13bff0fc, 0x846c2bad2beae37d, 0xc6d47893c5a72b42),
+    (0x3acb33d7a0, 0x89c232622dfdd27e, 0x3e97077e010eddfe),
+    (0x2560d14d22, 0x2b6aa27e007612f7, 0xb7f47cf096afade4),
+    (0x07f4aaa7da, 0xee1ee9c3ab617905, 0x860af9a8c65de800),
+    (0x32f9411e5d, 0x1ca8f4c1fcb585e8, 0xbe3e257ba9100000),
+    (0x15ade7a6a9, 0x4310ec766d846a23, 0x15edf706a0000000),
+    (0x2768cccd98, 0xe510d8886ae8b862, 0xe5c77a4000000000),
+    (0x23993d52c8, 0xfd8f96d957f13c7b, 0xb0f6800000000000),
+    (0x2ecbf73838, 0x230aff733deadd6b, 0x8100000000000000),
+    (0x0d1204e923, 0xe8294f2c4c1d6dca, 0x0000000000000000),
+    (0x085c378349, 0x6ea98610d2256400, 0x0000000000000000),
+    (0x1118e79532, 0x4a564e5ba8e80000, 0x0000000000000000),
+    (0x0bb58a4aa2, 0x5ce566d710000000, 0x0000000000000000),
+    (0x25cd94de00, 0x713552a000000000, 0x0000000000000000),
+    (0x001a5bbd72, 0xa772400000000000, 0x0000000000000000),
+    (0x1ab1ea879a, 0xa680000000000000, 0x0000000000000000),
+    (0x2401e1b061, 0x0000000000000000, 0x0000000000000000),
+    (0x1695a68a00, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000001d42a, 0xea2879f2e44dea5b),
+    (0x0000000000, 0x00006d00f7320d38, 0x46f4f40737a41067),
+    (0x0000001961, 0x219000356aa38b95, 0xbeb7fa60e598ac01),
+    (0x169d770528, 0x0fdffdb2872d49de, 0x1ec4c2a5f5479449),
+    (0x0953e1c8bd, 0x80bb9fee5cff1f80, 0x98f3ffc648603fb5),
+    (0x2c1f40364e, 0x3104d31ce577b76b, 0x17c82db0c5d05898),
+    (0x1234934c3c, 0x76d96e5a7ba1f1b7, 0x2dfb7fd69ba31a6e),
+    (0x0e13f35102, 0xce0017ae68852a09, 0xcfd78e03cae73382),
+    (0x00a72c280f, 0x83b12f449ff97366, 0x641a560e5c21704a),
+    (0x039cbb4c65, 0x0f72dfe2d9b914a9, 0xdbc675b0f05d008d),
+    (0x1787aa82e4, 0x9b4da0e59e22f9ed, 0x2cb3a4bc010afa27),
+    (0x353a04b352, 0xf6885f6b9b2f704e, 0x2d528113f8f36cff),
+    (0x1350fb328e, 0xceca31df0216f724, 0x5a3d9efe5b3942d5),
+    (0x214001ae4b, 0xf0fc846c2bad2bea, 0xe37dc6d47893c5a8),
+    (0x11ae751693, 0xd7a089c232622dfd, 0xd27e3e97077e010f),
+    (0x226c165e77, 0x4d222b6aa27e0076, 0x12f7b7f47cf096b0),
+    (0x1bc6e9696c, 0xa7daee1ee9c3ab61, 0x7905860af9a8c65e),
+    (0x194c622b07, 0x1e5d1ca8f4c1fcb5, 0x85e8be3e257ba910),
+    (0x01a84d5793, 0xa6a94310ec766d84, 0x6a2315edf706a000),
+    (0x2260afc5c8, 0xcd98e510d8886ae8, 0xb862e5c77a400000),
+    (0x2ec0cc5cff, 0x52c8fd8f96d957f1, 0x3c7bb0f680000000),
+    (0x3b727596ab, 0x3838230aff733dea, 0xdd6b810000000000),
+    (0x27dd7bdc34, 0xe923e8294f2c4c1d, 0x6dca000000000000),
+    (0x0c51b94611, 0x83496ea98610d225, 0x6400000000000000),
+    (0x0413d8b845, 0x95324a564e5ba8e8, 0x0000000000000000),
+    (0x1033753f1c, 0x4aa25ce566d71000, 0x0000000000000000),
+    (0x06964ea3f8, 0xde00713552a00000, 0x0000000000000000),
+    (0x39f1a40587, 0xbd72a77240000000, 0x0000000000000000),
+    (0x1f9abc7f0c, 0x879aa68000000000, 0x0000000000000000),
+    (0x02ead41a67, 0xb061000000000000, 0x0000000000000000),
+    (0x1824584686, 0x8a00000000000000, 0x0000000000000000),
+    (0x1f53272ce4, 0x0000000000000000, 0x0000000000000000),
+    (0x3515dbe800, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000006d00f732, 0x0d3846f4f40737a5),
+    (0x0000000000, 0x1961219000356aa3, 0x8b95beb7fa60e599),
+    (0x0005e8bb31, 0x05280fdffdb2872d, 0x49de1ec4c2a5f548),
+    (0x0b69d403d3, 0xc8bd80bb9fee5cff, 0x1f8098f3ffc64861),
+    (0x314f518b10, 0x364e3104d31ce577, 0xb76b17c82db0c5d1),
+    (0x03c6517b2f, 0x4c3c76d96e5a7ba1, 0xf1b72dfb7fd69ba4),
+    (0x0b032b1deb, 0x5102ce0017ae6885, 0x2a09cfd78e03cae8),
+    (0x36c9f40f16, 0x280f83b12f449ff9, 0x7366641a560e5c22),
+    (0x0528a1284b, 0x4c650f72dfe2d9b9, 0x14a9dbc675b0f05e),
+    (0x118822a9a2, 0x82e49b4da0e59e22, 0xf9ed2cb3a4bc010b),
+    (0x25d66da898, 0xb352f6885f6b9b2f, 0x704e2d528113f8f4),
+    (0x238da87c39, 0x328ececa31df0216, 0xf7245a3d9efe5b3a),
+    (0x0d513c7575, 0xae4bf0fc846c2bad, 0x2beae37dc6d47894),
+    (0x1b665335c1, 0x1693d7a089c23262, 0x2dfdd27e3e97077f),
+    (0x2cf4f40372, 0x5e774d222b6aa27e, 0x007612f7b7f47cf1),
+    (0x1aa0ec9115, 0x696ca7daee1ee9c3, 0xab617905860af9a9),
+    (0x04fc3e5b3c, 0x2b071e5d1ca8f4c1, 0xfcb585e8be3e257c),
+    (0x0e024c003b, 0x5793a6a94310ec76, 0x6d846a2315edf707),
+    (0x0dd1108955, 0xc5c8cd98e510d888, 0x6ae8b862e5c77a40),
+    (0x13f871f03e, 0x5cff52c8fd8f96d9, 0x57f13c7bb0f68000),
+    (0x0e8523ff0d, 0x96ab3838230aff73, 0x3deadd6b81000000),
+    (0x0329f0d1d7, 0xdc34e923e8294f2c, 0x4c1d6dca00000000),
+    (0x324244fd51, 0x461183496ea98610, 0xd225640000000000),
+    (0x12ec4a5114, 0xb84595324a564e5b, 0xa8e8000000000000),
+    (0x04d2ff3ca5, 0x3f1c4aa25ce566d7, 0x1000000000000000),
+    (0x267975e004, 0xa3f8de00713552a0, 0x0000000000000000),
+    (0x011498a842, 0x0587bd72a7724000, 0x0000000000000000),
+    (0x0f5f31b4ae, 0x7f0c879aa6800000, 0x0000000000000000),
+    (0x28a0ca010a, 0x1a67b06100000000, 0x0000000000000000),
+    (0x025a31c0dc, 0x46868a0000000000, 0x0000000000000000),
+    (0x3349713e63, 0x2ce4000000000000, 0x0000000000000000),
+    (0x17174fce93, 0xe800000000000000, 0x0000000000000000),
+    (0x226fe64510, 0x0000000000000000, 0x0000000000000000),
+    (0x03b9aca000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000006d00, 0xf7320d3846f4f408),
+    (0x0000000000, 0x0000196121900035, 0x6aa38b95beb7fa61),
+    (0x00000005e8, 0xbb3105280fdffdb2, 0x872d49de1ec4c2a6),
+    (0x362fdc8b62, 0x03d3c8bd80bb9fee, 0x5cff1f8098f3ffc7),
+    (0x16d22573af, 0x8b10364e3104d31c, 0xe577b76b17c82db1),
+    (0x28df30e7ff, 0x7b2f4c3c76d96e5a, 0x7ba1f1b72dfb7fd7),
+    (0x3b7bdd9839, 0x1deb5102ce0017ae, 0x68852a09cfd78e04),
+    (0x0d4c6e4cd6, 0x0f16280f83b12f44, 0x9ff97366641a560f),
+    (0x31d6e81677, 0x284b4c650f72dfe2, 0xd9b914a9dbc675b1),
+    (0x1bbe559db2, 0xa9a282e49b4da0e5, 0x9e22f9ed2cb3a4bd),
+    (0x29991f79c7, 0xa898b352f6885f6b, 0x9b2f704e2d528114),
+    (0x2e7c942838, 0x7c39328ececa31df, 0x0216f7245a3d9eff),
+    (0x0d26c87b14, 0x7575ae4bf0fc846c, 0x2bad2beae37dc6d5),
+    (0x04c370eca5, 0x35c11693d7a089c2, 0x32622dfdd27e3e98),
+    (0x26774832c6, 0x03725e774d222b6a, 0xa27e007612f7b7f5),
+    (0x2e1a85ad4a, 0x9115696ca7daee1e, 0xe9c3ab617905860b),
+    (0x115c860ca8, 0x5b3c2b071e5d1ca8, 0xf4c1fcb585e8be3f),
+    (0x2732d2981a, 0x003b5793a6a94310, 0xec766d846a2315ee),
+    (0x060dc65510, 0x8955c5c8cd98e510, 0xd8886ae8b862e5c8),
+    (0x03d9a66e8b, 0xf03e5cff52c8fd8f, 0x96d957f13c7bb0f7),
+    (0x2094fb5083, 0xff0d96ab3838230a, 0xff733deadd6b8100),
+    (0x1ebb97b726, 0xd1d7dc34e923e829, 0x4f2c4c1d6dca0000),
+    (0x0909d59d2e, 0xfd51461183496ea9, 0x8610d22564000000),
+    (0x0af0cb31ec, 0x5114b84595324a56, 0x4e5ba8e800000000),
+    (0x37059304e9, 0x3ca53f1c4aa25ce5, 0x66d7100000000000),
+    (0x364e009acd, 0xe004a3f8de007135, 0x52a0000000000000),
+    (0x2fef1c4758, 0xa8420587bd72a772, 0x4000000000000000),
+    (0x14a46263c1, 0xb4ae7f0c879aa680, 0x0000000000000000),
+    (0x2d19c3c0d2, 0x010a1a67b0610000, 0x0000000000000000),
+    (0x30e537a8fb, 0xc0dc46868a000000, 0x0000000000000000),
+    (0x3a9dab6ef1, 0x3e632ce400000000, 0x0000000000000000),
+    (0x382b3ebe3b, 0xce93e80000000000, 0x0000000000000000),
+    (0x0decc58c72, 0x4510000000000000, 0x0000000000000000),
+    (0x1a9b02661e, 0xa000000000000000, 0x0000000000000000),
+    (0x0721646a40, 0x0000000000000000, 0x0000000000000000),
+    (0x0ee6b28000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000019612190, 0x00356aa38b95beb8),
+    (0x0000000000, 0x05e8bb3105280fdf, 0xfdb2872d49de1ec5),
+    (0x00016035ce, 0x8b6203d3c8bd80bb, 0x9fee5cff1f8098f4),
+    (0x30170269d3, 0x73af8b10364e3104, 0xd31ce577b76b17c9),
+    (0x313b83e5ee, 0xe7ff7b2f4c3c76d9, 0x6e5a7ba1f1b72dfc),
+    (0x379febf423, 0x98391deb5102ce00, 0x17ae68852a09cfd8),
+    (0x08499ad25e, 0x4cd60f16280f83b1, 0x2f449ff97366641b),
+    (0x15f4b9f6e0, 0x1677284b4c650f72, 0xdfe2d9b914a9dbc7),
+    (0x342cabcbb1, 0x9db2a9a282e49b4d, 0xa0e59e22f9ed2cb4),
+    (0x295abd3101, 0x79c7a898b352f688, 0x5f6b9b2f704e2d53),
+    (0x0057f5700e, 0x28387c39328ececa, 0x31df0216f7245a3e),
+    (0x034bd46258, 0x7b147575ae4bf0fc, 0x846c2bad2beae37e),
+    (0x1499dd927e, 0xeca535c11693d7a0, 0x89c232622dfdd27f),
+    (0x1d8d489580, 0x32c603725e774d22, 0x2b6aa27e007612f8),
+    (0x1dd93755f9, 0xad4a9115696ca7da, 0xee1ee9c3ab617906),
+    (0x3a21e77108, 0x0ca85b3c2b071e5d, 0x1ca8f4c1fcb585e9),
+    (0x01dfc8c44a, 0x981a003b5793a6a9, 0x4310ec766d846a24),
+    (0x115e2859b6, 0x55108955c5c8cd98, 0xe510d8886ae8b863),
+    (0x2a73d9dab8, 0x6e8bf03e5cff52c8, 0xfd8f96d957f13c7c),
+    (0x2af0fe47c7, 0x5083ff0d96ab3838, 0x230aff733deadd6c),
+    (0x2e681220b7, 0xb726d1d7dc34e923, 0xe8294f2c4c1d6dca),
+    (0x2ac64b163b, 0x9d2efd5146118349, 0x6ea98610d2256400),
+    (0x0de1456cad, 0x31ec5114b8459532, 0x4a564e5ba8e80000),
+    (0x28533a283d, 0x04e93ca53f1c4aa2, 0x5ce566d710000000),
+    (0x0e3506db28, 0x9acde004a3f8de00, 0x713552a000000000),
+    (0x09743a9ca0, 0x4758a8420587bd72, 0xa772400000000000),
+    (0x25515ad268, 0x63c1b4ae7f0c879a, 0xa680000000000000),
+    (0x184e1c05e1, 0xc0d2010a1a67b061, 0x0000000000000000),
+    (0x348ff086b7, 0xa8fbc0dc46868a00, 0x0000000000000000),
+    (0x2ac2fe983b, 0x6ef13e632ce40000, 0x0000000000000000),
+    (0x0dd6813c0a, 0xbe3bce93e8000000, 0x0000000000000000),
+    (0x028056b2b1, 0x8c72451000000000, 0x0000000000000000),
+    (0x2956b8eb7a, 0x661ea00000000000, 0x0000000000000000),
+    (0x1c7f8b11e0, 0x6a40000000000000, 0x0000000000000000),
+    (0x34402dbe56, 0x8000000000000000, 0x0000000000000000),
+    (0x1423cd4100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000001961, 0x219000356aa38b96),
+    (0x0000000000, 0x000005e8bb310528, 0x0fdffdb2872d49df),
+    (0x0000000160, 0x35ce8b6203d3c8bd, 0x80bb9fee5cff1f81),
+    (0x166692e2d4, 0x69d373af8b10364e, 0x3104d31ce577b76c),
+    (0x3174d3005d, 0xe5eee7ff7b2f4c3c, 0x76d96e5a7ba1f1b8),
+    (0x15dcc4789d, 0xf42398391deb5102, 0xce0017ae68852a0a),
+    (0x24c6c5b422, 0xd25e4cd60f16280f, 0x83b12f449ff97367),
+    (0x081b89c26d, 0xf6e01677284b4c65, 0x0f72dfe2d9b914aa),
+    (0x199a62ecc7, 0xcbb19db2a9a282e4, 0x9b4da0e59e22f9ee),
+    (0x2e84c01eeb, 0x310179c7a898b352, 0xf6885f6b9b2f704f),
+    (0x36c280669f, 0x700e28387c39328e, 0xceca31df0216f725),
+    (0x251f3a7a34, 0x62587b147575ae4b, 0xf0fc846c2bad2beb),
+    (0x0c3256e331, 0x927eeca535c11693, 0xd7a089c232622dfe),
+    (0x0b8abc7e7c, 0x958032c603725e77, 0x4d222b6aa27e0077),
+    (0x1d01c8c8c9, 0x55f9ad4a9115696c, 0xa7daee1ee9c3ab62),
+    (0x2ee08d20f9, 0x71080ca85b3c2b07, 0x1e5d1ca8f4c1fcb6),
+    (0x3a13dfacf2, 0xc44a981a003b5793, 0xa6a94310ec766d85),
+    (0x388606d4d0, 0x59b655108955c5c8, 0xcd98e510d8886ae9),
+    (0x3082a76413, 0xdab86e8bf03e5cff, 0x52c8fd8f96d957f2),
+    (0x049f69bf02, 0x47c75083ff0d96ab, 0x3838230aff733deb),
+    (0x0087ebea18, 0x20b7b726d1d7dc34, 0xe923e8294f2c4c1e),
+    (0x059e210f8b, 0x163b9d2efd514611, 0x83496ea98610d226),
+    (0x206238dca1, 0x6cad31ec5114b845, 0x95324a564e5ba8e8),
+    (0x2595a6aa72, 0x283d04e93ca53f1c, 0x4aa25ce566d71000),
+    (0x1a944c5896, 0xdb289acde004a3f8, 0xde00713552a00000),
+    (0x231fb93b08, 0x9ca04758a8420587, 0xbd72a77240000000),
+    (0x02014df472, 0xd26863c1b4ae7f0c, 0x879aa68000000000),
+    (0x1abbeb3bd0, 0x05e1c0d2010a1a67, 0xb061000000000000),
+    (0x306f22b5e2, 0x86b7a8fbc0dc4686, 0x8a00000000000000),
+    (0x34be041cba, 0x983b6ef13e632ce4, 0x0000000000000000),
+    (0x2b71e88271, 0x3c0abe3bce93e800, 0x0000000000000000),
+    (0x1a5d4df1ae, 0xb2b18c7245100000, 0x0000000000000000),
+    (0x28acd0432c, 0xeb7a661ea0000000, 0x0000000000000000),
+    (0x0a756e4ef9, 0x11e06a4000000000, 0x0000000000000000),
+    (0x39fdb80197, 0xbe56800000000000, 0x0000000000000000),
+    (0x23549e2bb9, 0x4100000000000000, 0x0000000000000000),
+    (0x2b21fe474a, 0x0000000000000000, 0x0000000000000000),
+    (0x113abe6400, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000005e8bb31, 0x05280fdffdb2872e),
+    (0x0000000000, 0x016035ce8b6203d3, 0xc8bd80bb9fee5d00),
+    (0x000052015c, 0xe2d469d373af8b10, 0x364e3104d31ce578),
+    (0x15a070b327, 0x005de5eee7ff7b2f, 0x4c3c76d96e5a7ba2),
+    (0x0914aaa2c4, 0x789df42398391deb, 0x5102ce0017ae6886),
+    (0x2dbe97fd75, 0xb422d25e4cd60f16, 0x280f83b12f449ffa),
+    (0x1b67af4391, 0xc26df6e01677284b, 0x4c650f72dfe2d9ba),
+    (0x21eff15176, 0xecc7cbb19db2a9a2, 0x82e49b4da0e59e23),
+    (0x1bb07a52f8, 0x1eeb310179c7a898, 0xb352f6885f6b9b30),
+    (0x39c526962c, 0x669f700e28387c39, 0x328ececa31df0217),
+    (0x0a567f83b6, 0x7a3462587b147575, 0xae4bf0fc846c2bae),
+    (0x2a7c7f929a, 0xe331927eeca535c1, 0x1693d7a089c23263),
+    (0x2410034fda, 0x7e7c958032c60372, 0x5e774d222b6aa27f),
+    (0x32df433534, 0xc8c955f9ad4a9115, 0x696ca7daee1ee9c4),
+    (0x0c4a30d65d, 0x20f971080ca85b3c, 0x2b071e5d1ca8f4c2),
+    (0x15aee8cf1f, 0xacf2c44a981a003b, 0x5793a6a94310ec77),
+    (0x076002fbbe, 0xd4d059b655108955, 0xc5c8cd98e510d889),
+    (0x2c6e6e9def, 0x6413dab86e8bf03e, 0x5cff52c8fd8f96da),
+    (0x37bccfac51, 0xbf0247c75083ff0d, 0x96ab3838230aff74),
+    (0x130872eea1, 0xea1820b7b726d1d7, 0xdc34e923e8294f2d),
+    (0x25b2da24c5, 0x0f8b163b9d2efd51, 0x461183496ea98611),
+    (0x2de1bbe60e, 0xdca16cad31ec5114, 0xb84595324a564e5c),
+    (0x0375d5a73e, 0xaa72283d04e93ca5, 0x3f1c4aa25ce566d8),
+    (0x0e972c4a70, 0x5896db289acde004, 0xa3f8de00713552a0),
+    (0x1a2858b529, 0x3b089ca04758a842, 0x0587bd72a7724000),
+    (0x09998907db, 0xf472d26863c1b4ae, 0x7f0c879aa6800000),
+    (0x3336551273, 0x3bd005e1c0d2010a, 0x1a67b06100000000),
+    (0x1ad475d9b0, 0xb5e286b7a8fbc0dc, 0x46868a0000000000),
+    (0x2924c40ed6, 0x1cba983b6ef13e63, 0x2ce4000000000000),
+    (0x31da153c00, 0x82713c0abe3bce93, 0xe800000000000000),
+    (0x001e5ef7e1, 0xf1aeb2b18c724510, 0x0000000000000000),
+    (0x349b50eefa, 0x432ceb7a661ea000, 0x0000000000000000),
+    (0x3a44cd3c4c, 0x4ef911e06a400000, 0x0000000000000000),
+    (0x11c4571f44, 0x0197be5680000000, 0x0000000000000000),
+    (0x0fd57c9768, 0x2bb9410000000000, 0x0000000000000000),
+    (0x18411031ec, 0x474a000000000000, 0x0000000000000000),
+    (0x37034b60c4, 0x6400000000000000, 0x0000000000000000),
+    (0x2db9cb1ee8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000005e8, 0xbb3105280fdffdb3),
+    (0x0000000000, 0x0000016035ce8b62, 0x03d3c8bd80bb9fef),
+    (0x0000000052, 0x015ce2d469d373af, 0x8b10364e3104d31d),
+    (0x1317e5ef3a, 0xb327005de5eee7ff, 0x7b2f4c3c76d96e5b),
+    (0x0daac813e8, 0xa2c4789df4239839, 0x1deb5102ce0017af),
+    (0x362a2cc26b, 0xfd75b422d25e4cd6, 0x0f16280f83b12f45),
+    (0x1924b5cf51, 0x4391c26df6e01677, 0x284b4c650f72dfe3),
+    (0x12ebb55ccf, 0x5176ecc7cbb19db2, 0xa9a282e49b4da0e6),
+    (0x3045210064, 0x52f81eeb310179c7, 0xa898b352f6885f6c),
+    (0x175bc841da, 0x962c669f700e2838, 0x7c39328ececa31e0),
+    (0x32e4c70cdb, 0x83b67a3462587b14, 0x7575ae4bf0fc846d),
+    (0x331c157fdd, 0x929ae331927eeca5, 0x35c11693d7a089c3),
+    (0x3396c2b937, 0x4fda7e7c958032c6, 0x03725e774d222b6b),
+    (0x0ce0d90999, 0x3534c8c955f9ad4a, 0x9115696ca7daee1f),
+    (0x23abe61002, 0xd65d20f971080ca8, 0x5b3c2b071e5d1ca9),
+    (0x00a91ea7c4, 0xcf1facf2c44a981a, 0x003b5793a6a94311),
+    (0x2dd2bc3158, 0xfbbed4d059b65510, 0x8955c5c8cd98e511),
+    (0x14b7d2a27c, 0x9def6413dab86e8b, 0xf03e5cff52c8fd90),
+    (0x1d03bf82b1, 0xac51bf0247c75083, 0xff0d96ab3838230b),
+    (0x295e24b22a, 0xeea1ea1820b7b726, 0xd1d7dc34e923e82a),
+    (0x09fef4bea6, 0x24c50f8b163b9d2e, 0xfd51461183496eaa),
+    (0x26aeeea223, 0xe60edca16cad31ec, 0x5114b84595324a57),
+    (0x085bba2551, 0xa73eaa72283d04e9, 0x3ca53f1c4aa25ce6),
+    (0x1302ea7af2, 0x4a705896db289acd, 0xe004a3f8de007136),
+    (0x3869a7dabc, 0xb5293b089ca04758, 0xa8420587bd72a773),
+    (0x2befda625b, 0x07dbf472d26863c1, 0xb4ae7f0c879aa680),
+    (0x1531da3fdb, 0x12733bd005e1c0d2, 0x010a1a67b0610000),
+    (0x3301b684a9, 0xd9b0b5e286b7a8fb, 0xc0dc46868a000000),
+    (0x278bdeb3fe, 0x0ed61cba983b6ef1, 0x3e632ce400000000),
+    (0x3b2708bd21, 0x3c0082713c0abe3b, 0xce93e80000000000),
+    (0x07bcec6fb6, 0xf7e1f1aeb2b18c72, 0x4510000000000000),
+    (0x2a99c29036, 0xeefa432ceb7a661e, 0xa000000000000000),
+    (0x0cca4ac899, 0x3c4c4ef911e06a40, 0x0000000000000000),
+    (0x23ad8cc5af, 0x1f440197be568000, 0x0000000000000000),
+    (0x28c617a9f2, 0x97682bb941000000, 0x0000000000000000),
+    (0x387b938236, 0x31ec474a00000000, 0x0000000000000000),
+    (0x0c9e463ff5, 0x60c4640000000000, 0x0000000000000000),
+    (0x3921ab178b, 0x1ee8000000000000, 0x0000000000000000),
+    (0x20643dd5f3, 0x1000000000000000, 0x0000000000000000),
+    (0x3897a76aa0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000016035ce, 0x8b6203d3c8bd80bc),
+    (0x0000000000, 0x0052015ce2d469d3, 0x73af8b10364e3105),
+    (0x00001317e5, 0xef3ab327005de5ee, 0xe7ff7b2f4c3c76da),
+    (0x358929df5e, 0x13e8a2c4789df423, 0x98391deb5102ce01),
+    (0x15e778d32a, 0xc26bfd75b422d25e, 0x4cd60f16280f83b2),
+    (0x09f4a995c9, 0xcf514391c26df6e0, 0x1677284b4c650f73),
+    (0x2efccdaf0b, 0x5ccf5176ecc7cbb1, 0x9db2a9a282e49b4e),
+    (0x02a54293b9, 0x006452f81eeb3101, 0x79c7a898b352f689),
+    (0x2b12f355c8, 0x41da962c669f700e, 0x28387c39328ececb),
+    (0x2ea0430211, 0x0cdb83b67a346258, 0x7b147575ae4bf0fd),
+    (0x03f845c78d, 0x7fdd929ae331927e, 0xeca535c11693d7a1),
+    (0x20f206a2f8, 0xb9374fda7e7c9580, 0x32c603725e774d23),
+    (0x39e9136cd3, 0x09993534c8c955f9, 0xad4a9115696ca7db),
+    (0x3122d09b00, 0x1002d65d20f97108, 0x0ca85b3c2b071e5e),
+    (0x0003ba55be, 0xa7c4cf1facf2c44a, 0x981a003b5793a6aa),
+    (0x2c63f1b882, 0x3158fbbed4d059b6, 0x55108955c5c8cd99),
+    (0x1e5017ec7c, 0xa27c9def6413dab8, 0x6e8bf03e5cff52c9),
+    (0x1d04cecf93, 0x82b1ac51bf0247c7, 0x5083ff0d96ab3839),
+    (0x22584ff6b8, 0xb22aeea1ea1820b7, 0xb726d1d7dc34e924),
+    (0x2b00bccf68, 0xbea624c50f8b163b, 0x9d2efd514611834a),
+    (0x186345a0da, 0xa223e60edca16cad, 0x31ec5114b8459533),
+    (0x32e790538e, 0x2551a73eaa72283d, 0x04e93ca53f1c4aa3),
+    (0x21188c6e1c, 0x7af24a705896db28, 0x9acde004a3f8de01),
+    (0x06a18e45eb, 0xdabcb5293b089ca0, 0x4758a8420587bd73),
+    (0x36ea052dde, 0x625b07dbf472d268, 0x63c1b4ae7f0c879b),
+    (0x33c7219f2e, 0x3fdb12733bd005e1, 0xc0d2010a1a67b061),
+    (0x0ac4ae656c, 0x84a9d9b0b5e286b7, 0xa8fbc0dc46868a00),
+    (0x1944309406, 0xb3fe0ed61cba983b, 0x6ef13e632ce40000),
+    (0x018f891e46, 0xbd213c0082713c0a, 0xbe3bce93e8000000),
+    (0x10785c400e, 0x6fb6f7e1f1aeb2b1, 0x8c72451000000000),
+    (0x035c79c358, 0x9036eefa432ceb7a, 0x661ea00000000000),
+    (0x149ec94bea, 0xc8993c4c4ef911e0, 0x6a40000000000000),
+    (0x36aa313f5c, 0xc5af1f440197be56, 0x8000000000000000),
+    (0x1599a77b89, 0xa9f297682bb94100, 0x0000000000000000),
+    (0x200d67c4ed, 0x823631ec474a0000, 0x0000000000000000),
+    (0x374c9e3ada, 0x3ff560c464000000, 0x0000000000000000),
+    (0x32d0b43d61, 0x178b1ee800000000, 0x0000000000000000),
+    (0x169b21d663, 0xd5f3100000000000, 0x0000000000000000),
+    (0x173eac7fb9, 0x6aa0000000000000, 0x0000000000000000),
+    (0x2b2baf5262, 0x4000000000000000, 0x0000000000000000),
+    (0x16e0280680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000160, 0x35ce8b6203d3c8be),
+    (0x0000000000, 0x00000052015ce2d4, 0x69d373af8b10364f),
+    (0x0000000013, 0x17e5ef3ab327005d, 0xe5eee7ff7b2f4c3d),
+    (0x04720d6f4f, 0xdf5e13e8a2c4789d, 0xf42398391deb5103),
+    (0x1298c6136e, 0xd32ac26bfd75b422, 0xd25e4cd60f162810),
+    (0x19cdad5527, 0x95c9cf514391c26d, 0xf6e01677284b4c66),
+    (0x093774da5f, 0xaf0b5ccf5176ecc7, 0xcbb19db2a9a282e5),
+    (0x1647326b58, 0x93b9006452f81eeb, 0x310179c7a898b353),
+    (0x149f9a64f1, 0x55c841da962c669f, 0x700e28387c39328f),
+    (0x3830b12f55, 0x02110cdb83b67a34, 0x62587b147575ae4c),
+    (0x13cae03fd9, 0xc78d7fdd929ae331, 0x927eeca535c11694),
+    (0x32b4ab7f0c, 0xa2f8b9374fda7e7c, 0x958032c603725e78),
+    (0x02f13354e7, 0x6cd309993534c8c9, 0x55f9ad4a9115696d),
+    (0x35e202b608, 0x9b001002d65d20f9, 0x71080ca85b3c2b08),
+    (0x0200ed0c08, 0x55bea7c4cf1facf2, 0xc44a981a003b5794),
+    (0x01f0cd1903, 0xb8823158fbbed4d0, 0x59b655108955c5c9),
+    (0x00ddc5ef47, 0xec7ca27c9def6413, 0xdab86e8bf03e5d00),
+    (0x10befdbd06, 0xcf9382b1ac51bf02, 0x47c75083ff0d96ac),
+    (0x0195f53da5, 0xf6b8b22aeea1ea18, 0x20b7b726d1d7dc35),
+    (0x26a435f0d8, 0xcf68bea624c50f8b, 0x163b9d2efd514612),
+    (0x327ae4fc9b, 0xa0daa223e60edca1, 0x6cad31ec5114b846),
+    (0x243c2bf3d0, 0x538e2551a73eaa72, 0x283d04e93ca53f1d),
+    (0x308138680a, 0x6e1c7af24a705896, 0xdb289acde004a3f9),
+    (0x026daf085a, 0x45ebdabcb5293b08, 0x9ca04758a8420588),
+    (0x1504b2a677, 0x2dde625b07dbf472, 0xd26863c1b4ae7f0d),
+    (0x1bbfa1e2a3, 0x9f2e3fdb12733bd0, 0x05e1c0d2010a1a68),
+    (0x26189e8624, 0x656c84a9d9b0b5e2, 0x86b7a8fbc0dc4687),
+    (0x087961bde2, 0x9406b3fe0ed61cba, 0x983b6ef13e632ce4),
+    (0x34c11d6051, 0x1e46bd213c008271, 0x3c0abe3bce93e800),
+    (0x12e3068608, 0x400e6fb6f7e1f1ae, 0xb2b18c7245100000),
+    (0x01ebc05ef9, 0xc3589036eefa432c, 0xeb7a661ea0000000),
+    (0x3a270a00a7, 0x4beac8993c4c4ef9, 0x11e06a4000000000),
+    (0x26f3a6c95f, 0x3f5cc5af1f440197, 0xbe56800000000000),
+    (0x162d31a75d, 0x7b89a9f297682bb9, 0x4100000000000000),
+    (0x15c3fece75, 0xc4ed823631ec474a, 0x0000000000000000),
+    (0x1b6b982146, 0x3ada3ff560c46400, 0x0000000000000000),
+    (0x105a071e78, 0x3d61178b1ee80000, 0x0000000000000000),
+    (0x1bfed92d43, 0xd663d5f310000000, 0x0000000000000000),
+    (0x0fcb6d8188, 0x7fb96aa000000000, 0x0000000000000000),
+    (0x1fc7f845e5, 0x5262400000000000, 0x0000000000000000),
+    (0x3564a526dc, 0x0680000000000000, 0x0000000000000000),
+    (0x333a890621, 0x0000000000000000, 0x0000000000000000),
+    (0x07aef40a00, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000052015c, 0xe2d469d373af8b11),
+    (0x0000000000, 0x001317e5ef3ab327, 0x005de5eee7ff7b30),
+    (0x000004720d, 0x6f4fdf5e13e8a2c4, 0x789df42398391dec),
+    (0x0320c6f85c, 0x136ed32ac26bfd75, 0xb422d25e4cd60f17),
+    (0x157026e2ab, 0x552795c9cf514391, 0xc26df6e01677284c),
+    (0x27e4388a86, 0xda5faf0b5ccf5176, 0xecc7cbb19db2a9a3),
+    (0x1f65ddd336, 0x6b5893b9006452f8, 0x1eeb310179c7a899),
+    (0x0caba4ee08, 0x64f155c841da962c, 0x669f700e28387c3a),
+    (0x01f456f799, 0x2f5502110cdb83b6, 0x7a3462587b147576),
+    (0x23aa87eff6, 0x3fd9c78d7fdd929a, 0xe331927eeca535c2),
+    (0x39559be861, 0x7f0ca2f8b9374fda, 0x7e7c958032c60373),
+    (0x16b33b4569, 0x54e76cd309993534, 0xc8c955f9ad4a9116),
+    (0x188641864a, 0xb6089b001002d65d, 0x20f971080ca85b3d),
+    (0x1165207089, 0x0c0855bea7c4cf1f, 0xacf2c44a981a003c),
+    (0x1fe8a34c45, 0x1903b8823158fbbe, 0xd4d059b655108956),
+    (0x10168b6d7f, 0xef47ec7ca27c9def, 0x6413dab86e8bf03f),
+    (0x1dc9807793, 0xbd06cf9382b1ac51, 0xbf0247c75083ff0e),
+    (0x2265e4db17, 0x3da5f6b8b22aeea1, 0xea1820b7b726d1d8),
+    (0x056942ac57, 0xf0d8cf68bea624c5, 0x0f8b163b9d2efd52),
+    (0x1479ae3e44, 0xfc9ba0daa223e60e, 0xdca16cad31ec5115),
+    (0x100fee4303, 0xf3d0538e2551a73e, 0xaa72283d04e93ca6),
+    (0x00eb94ccf6, 0x680a6e1c7af24a70, 0x5896db289acde005),
+    (0x395ef76bbf, 0x085a45ebdabcb529, 0x3b089ca04758a843),
+    (0x2c7a6e9102, 0xa6772dde625b07db, 0xf472d26863c1b4af),
+    (0x009df7b29d, 0xe2a39f2e3fdb1273, 0x3bd005e1c0d2010b),
+    (0x24c2b2a0f2, 0x8624656c84a9d9b0, 0xb5e286b7a8fbc0dd),
+    (0x38778e731d, 0xbde29406b3fe0ed6, 0x1cba983b6ef13e64),
+    (0x06ecbeea3f, 0x60511e46bd213c00, 0x82713c0abe3bce94),
+    (0x0ec184a4c6, 0x8608400e6fb6f7e1, 0xf1aeb2b18c724510),
+    (0x2e38ed2d7c, 0x5ef9c3589036eefa, 0x432ceb7a661ea000),
+    (0x1cf516d536, 0x00a74beac8993c4c, 0x4ef911e06a400000),
+    (0x0c92cd8fa6, 0xc95f3f5cc5af1f44, 0x0197be5680000000),
+    (0x26d541b1cb, 0xa75d7b89a9f29768, 0x2bb9410000000000),
+    (0x2f6ab5ebc4, 0xce75c4ed823631ec, 0x474a000000000000),
+    (0x2dd294a224, 0x21463ada3ff560c4, 0x6400000000000000),
+    (0x086983b611, 0x1e783d61178b1ee8, 0x0000000000000000),
+    (0x03fc5f8c85, 0x2d43d663d5f31000, 0x0000000000000000),
+    (0x1f01f4f2ef, 0x81887fb96aa00000, 0x0000000000000000),
+    (0x37c3ab5dc2, 0x45e5526240000000, 0x0000000000000000),
+    (0x2d3b933117, 0x26dc068000000000, 0x0000000000000000),
+    (0x0563f45a85, 0x0621000000000000, 0x0000000000000000),
+    (0x1ef8d841b0, 0x0a00000000000000, 0x0000000000000000),
+    (0x28fcbeebe4, 0x0000000000000000, 0x0000000000000000),
+    (0x3515dbe800, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000052, 0x015ce2d469d373b0),
+    (0x0000000000, 0x0000001317e5ef3a, 0xb327005de5eee800),
+    (0x0000000004, 0x720d6f4fdf5e13e8, 0xa2c4789df423983a),
+    (0x0108f936ba, 0xf85c136ed32ac26b, 0xfd75b422d25e4cd7),
+    (0x2b884a27d6, 0xe2ab552795c9cf51, 0x4391c26df6e01678),
+    (0x32082b668c, 0x8a86da5faf0b5ccf, 0x5176ecc7cbb19db3),
+    (0x20b8e74ec1, 0xd3366b5893b90064, 0x52f81eeb310179c8),
+    (0x2d20df8a22, 0xee0864f155c841da, 0x962c669f700e2839),
+    (0x0821fab022, 0xf7992f5502110cdb, 0x83b67a3462587b15),
+    (0x082434d76d, 0xeff63fd9c78d7fdd, 0x929ae331927eeca6),
+    (0x1998c6da31, 0xe8617f0ca2f8b937, 0x4fda7e7c958032c7),
+    (0x0b9ebba44b, 0x456954e76cd30999, 0x3534c8c955f9ad4b),
+    (0x1186826cb3, 0x864ab6089b001002, 0xd65d20f971080ca9),
+    (0x29cc7fa8dc, 0x70890c0855bea7c4, 0xcf1facf2c44a981b),
+    (0x3353393903, 0x4c451903b8823158, 0xfbbed4d059b65511),
+    (0x00c4926883, 0x6d7fef47ec7ca27c, 0x9def6413dab86e8c),
+    (0x1e99b40f82, 0x7793bd06cf9382b1, 0xac51bf0247c75084),
+    (0x1e6071edca, 0xdb173da5f6b8b22a, 0xeea1ea1820b7b727),
+    (0x2f3b263810, 0xac57f0d8cf68bea6, 0x24c50f8b163b9d2f),
+    (0x03e1cd1966, 0x3e44fc9ba0daa223, 0xe60edca16cad31ed),
+    (0x17ce2c08da, 0x4303f3d0538e2551, 0xa73eaa72283d04ea),
+    (0x32d16a7272, 0xccf6680a6e1c7af2, 0x4a705896db289ace),
+    (0x1abaa6abef, 0x6bbf085a45ebdabc, 0xb5293b089ca04759),
+    (0x37be98c2dc, 0x9102a6772dde625b, 0x07dbf472d26863c2),
+    (0x335ac8e261, 0xb29de2a39f2e3fdb, 0x12733bd005e1c0d3),
+    (0x16bf3ced26, 0xa0f28624656c84a9, 0xd9b0b5e286b7a8fc),
+    (0x08fe7331ce, 0x731dbde29406b3fe, 0x0ed61cba983b6ef2),
+    (0x30115c017c, 0xea3f60511e46bd21, 0x3c0082713c0abe3c),
+    (0x1d15841628, 0xa4c68608400e6fb6, 0xf7e1f1aeb2b18c73),
+    (0x09768cf255, 0x2d7c5ef9c3589036, 0xeefa432ceb7a661f),
+    (0x13d4fc3c98, 0xd53600a74beac899, 0x3c4c4ef911e06a40),
+    (0x23958c4cdf, 0x8fa6c95f3f5cc5af, 0x1f440197be568000),
+    (0x340d484217, 0xb1cba75d7b89a9f2, 0x97682bb941000000),
+    (0x05844d965f, 0xebc4ce75c4ed8236, 0x31ec474a00000000),
+    (0x165555e002, 0xa22421463ada3ff5, 0x60c4640000000000),
+    (0x009cf5f157, 0xb6111e783d61178b, 0x1ee8000000000000),
+    (0x146bfeadfb, 0x8c852d43d663d5f3, 0x1000000000000000),
+    (0x3a917bb66c, 0xf2ef81887fb96aa0, 0x0000000000000000),
+    (0x195ddd4e9f, 0x5dc245e552624000, 0x0000000000000000),
+    (0x251af7ecf5, 0x311726dc06800000, 0x0000000000000000),
+    (0x391691569e, 0x5a85062100000000, 0x0000000000000000),
+    (0x24de9c0fdc, 0x41b00a0000000000, 0x0000000000000000),
+    (0x334850e208, 0xebe4000000000000, 0x0000000000000000),
+    (0x0213c27d49, 0xe800000000000000, 0x0000000000000000),
+    (0x113527e110, 0x0000000000000000, 0x0000000000000000),
+    (0x03b9aca000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000001317e5, 0xef3ab327005de5ef),
+    (0x0000000000, 0x0004720d6f4fdf5e, 0x13e8a2c4789df424),
+    (0x00000108f9, 0x36baf85c136ed32a, 0xc26bfd75b422d25f),
+    (0x3a064ca8e6, 0x27d6e2ab552795c9, 0xcf514391c26df6e1),
+    (0x35965818f1, 0x668c8a86da5faf0b, 0x5ccf5176ecc7cbb2),
+    (0x3834988f63, 0x4ec1d3366b5893b9, 0x006452f81eeb3102),
+    (0x171f32686b, 0x8a22ee0864f155c8, 0x41da962c669f700f),
+    (0x1909dc00de, 0xb022f7992f550211, 0x0cdb83b67a346259),
+    (0x33d93dbb14, 0xd76deff63fd9c78d, 0x7fdd929ae331927f),
+    (0x04da40606c, 0xda31e8617f0ca2f8, 0xb9374fda7e7c9581),
+    (0x19581aa6bf, 0xa44b456954e76cd3, 0x09993534c8c955fa),
+    (0x2c9ebd65ea, 0x6cb3864ab6089b00, 0x1002d65d20f97109),
+    (0x3694cbbdb7, 0xa8dc70890c0855be, 0xa7c4cf1facf2c44b),
+    (0x2ac2f74dc9, 0x39034c451903b882, 0x3158fbbed4d059b7),
+    (0x2ed9ced58c, 0x68836d7fef47ec7c, 0xa27c9def6413dab9),
+    (0x20b0fbf3c4, 0x0f827793bd06cf93, 0x82b1ac51bf0247c8),
+    (0x2da61f1a47, 0xedcadb173da5f6b8, 0xb22aeea1ea1820b8),
+    (0x10bf4b8e28, 0x3810ac57f0d8cf68, 0xbea624c50f8b163c),
+    (0x095d3d4dfd, 0x19663e44fc9ba0da, 0xa223e60edca16cae),
+    (0x3aede38de6, 0x08da4303f3d0538e, 0x2551a73eaa72283e),
+    (0x358f2123ba, 0x7272ccf6680a6e1c, 0x7af24a705896db29),
+    (0x2b691c6c9a, 0xabef6bbf085a45eb, 0xdabcb5293b089ca1),
+    (0x240325a786, 0xc2dc9102a6772dde, 0x625b07dbf472d269),
+    (0x1f606463dc, 0xe261b29de2a39f2e, 0x3fdb12733bd005e2),
+    (0x336dbafd90, 0xed26a0f28624656c, 0x84a9d9b0b5e286b8),
+    (0x21be48eb75, 0x31ce731dbde29406, 0xb3fe0ed61cba983c),
+    (0x1b49570406, 0x017cea3f60511e46, 0xbd213c0082713c0b),
+    (0x0165f96c4e, 0x1628a4c68608400e, 0x6fb6f7e1f1aeb2b2),
+    (0x122e524fe8, 0xf2552d7c5ef9c358, 0x9036eefa432ceb7b),
+    (0x363cb337f0, 0x3c98d53600a74bea, 0xc8993c4c4ef911e1),
+    (0x37ef393ce4, 0x4cdf8fa6c95f3f5c, 0xc5af1f440197be57),
+    (0x3527c1e940, 0x4217b1cba75d7b89, 0xa9f297682bb94100),
+    (0x0ef615ec61, 0x965febc4ce75c4ed, 0x823631ec474a0000),
+    (0x16b8a991b1, 0xe002a22421463ada, 0x3ff560c464000000),
+    (0x296a2db7b5, 0xf157b6111e783d61, 0x178b1ee800000000),
+    (0x2a5ca1f628, 0xadfb8c852d43d663, 0xd5f3100000000000),
+    (0x0978b1bbfd, 0xb66cf2ef81887fb9, 0x6aa0000000000000),
+    (0x3b12730b79, 0x4e9f5dc245e55262, 0x4000000000000000),
+    (0x1c3e77be83, 0xecf5311726dc0680, 0x0000000000000000),
+    (0x1eb76126c9, 0x569e5a8506210000, 0x0000000000000000),
+    (0x2ee0b37878, 0x0fdc41b00a000000, 0x0000000000000000),
+    (0x1bf4400a26, 0xe208ebe400000000, 0x0000000000000000),
+    (0x090d9ab616, 0x7d49e80000000000, 0x0000000000000000),
+    (0x053c7925c9, 0xe110000000000000, 0x0000000000000000),
+    (0x2f00ef5f36, 0xa000000000000000, 0x0000000000000000),
+    (0x0cb7e75a40, 0x0000000000000000, 0x0000000000000000),
+    (0x0ee6b28000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000013, 0x17e5ef3ab327005e),
+    (0x0000000000, 0x00000004720d6f4f, 0xdf5e13e8a2c4789e),
+    (0x0000000001, 0x08f936baf85c136e, 0xd32ac26bfd75b423),
+    (0x003db1a69c, 0xa8e627d6e2ab5527, 0x95c9cf514391c26e),
+    (0x2479a642e8, 0x18f1668c8a86da5f, 0xaf0b5ccf5176ecc8),
+    (0x360a15c788, 0x8f634ec1d3366b58, 0x93b9006452f81eec),
+    (0x1fcb9de608, 0x686b8a22ee0864f1, 0x55c841da962c66a0),
+    (0x01f5263bec, 0x00deb022f7992f55, 0x02110cdb83b67a35),
+    (0x36f2e6113d, 0xbb14d76deff63fd9, 0xc78d7fdd929ae332),
+    (0x0e5f710dce, 0x606cda31e8617f0c, 0xa2f8b9374fda7e7d),
+    (0x300d01efda, 0xa6bfa44b456954e7, 0x6cd309993534c8ca),
+    (0x32e8a301b9, 0x65ea6cb3864ab608, 0x9b001002d65d20fa),
+    (0x2b2a96a07d, 0xbdb7a8dc70890c08, 0x55bea7c4cf1facf3),
+    (0x1d46c0ac19, 0x4dc939034c451903, 0xb8823158fbbed4d1),
+    (0x05e43a2290, 0xd58c68836d7fef47, 0xec7ca27c9def6414),
+    (0x21b8ca1b0d, 0xf3c40f827793bd06, 0xcf9382b1ac51bf03),
+    (0x033f9dd5dd, 0x1a47edcadb173da5, 0xf6b8b22aeea1ea19),
+    (0x337abed9cf, 0x8e283810ac57f0d8, 0xcf68bea624c50f8c),
+    (0x3053428f49, 0x4dfd19663e44fc9b, 0xa0daa223e60edca2),
+    (0x11114c16a5, 0x8de608da4303f3d0, 0x538e2551a73eaa73),
+    (0x268bce0263, 0x23ba7272ccf6680a, 0x6e1c7af24a705897),
+    (0x17152db0ba, 0x6c9aabef6bbf085a, 0x45ebdabcb5293b09),
+    (0x2b67c0145d, 0xa786c2dc9102a677, 0x2dde625b07dbf473),
+    (0x15ce3cbc2a, 0x63dce261b29de2a3, 0x9f2e3fdb12733bd1),
+    (0x09dea56dd8, 0xfd90ed26a0f28624, 0x656c84a9d9b0b5e3),
+    (0x3285a427ea, 0xeb7531ce731dbde2, 0x9406b3fe0ed61cbb),
+    (0x36b24f04c5, 0x0406017cea3f6051, 0x1e46bd213c008272),
+    (0x2ddf0d4321, 0x6c4e1628a4c68608, 0x400e6fb6f7e1f1af),
+    (0x07c82b858a, 0x4fe8f2552d7c5ef9, 0xc3589036eefa432d),
+    (0x20340be509, 0x37f03c98d53600a7, 0x4beac8993c4c4efa),
+    (0x0225774a9f, 0x3ce44cdf8fa6c95f, 0x3f5cc5af1f440198),
+    (0x251350e519, 0xe9404217b1cba75d, 0x7b89a9f297682bba),
+    (0x06086c91ef, 0xec61965febc4ce75, 0xc4ed823631ec474a),
+    (0x37dc8c00e1, 0x91b1e002a2242146, 0x3ada3ff560c46400),
+    (0x3484f7a097, 0xb7b5f157b6111e78, 0x3d61178b1ee80000),
+    (0x2353132907, 0xf628adfb8c852d43, 0xd663d5f310000000),
+    (0x01da8bbccd, 0xbbfdb66cf2ef8188, 0x7fb96aa000000000),
+    (0x2fe6b8e601, 0x0b794e9f5dc245e5, 0x5262400000000000),
+    (0x003e46af25, 0xbe83ecf5311726dc, 0x0680000000000000),
+    (0x08c9bacb4d, 0x26c9569e5a850621, 0x0000000000000000),
+    (0x11f6969caf, 0x78780fdc41b00a00, 0x0000000000000000),
+    (0x28dadc98f0, 0x0a26e208ebe40000, 0x0000000000000000),
+    (0x37e37a797e, 0xb6167d49e8000000, 0x0000000000000000),
+    (0x1d8094b415, 0x25c9e11000000000, 0x0000000000000000),
+    (0x04ec7ef221, 0x5f36a00000000000, 0x0000000000000000),
+    (0x07c51f32dd, 0x5a40000000000000, 0x0000000000000000),
+    (0x3389a3b3b6, 0x8000000000000000, 0x0000000000000000),
+    (0x2a7dd90100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000004720d, 0x6f4fdf5e13e8a2c5),
+    (0x0000000000, 0x000108f936baf85c, 0x136ed32ac26bfd76),
+    (0x0000003db1, 0xa69ca8e627d6e2ab, 0x552795c9cf514392),
+    (0x295cd082a2, 0x42e818f1668c8a86, 0xda5faf0b5ccf5177),
+    (0x25c787c629, 0xc7888f634ec1d336, 0x6b5893b9006452f9),
+    (0x09ba3f78a3, 0xe608686b8a22ee08, 0x64f155c841da962d),
+    (0x26291da4a2, 0x3bec00deb022f799, 0x2f5502110cdb83b7),
+    (0x25c5e77374, 0x113dbb14d76deff6, 0x3fd9c78d7fdd929b),
+    (0x1b06272edb, 0x0dce606cda31e861, 0x7f0ca2f8b9374fdb),
+    (0x3300a1b743, 0xefdaa6bfa44b4569, 0x54e76cd309993535),
+    (0x0fd15b4939, 0x01b965ea6cb3864a, 0xb6089b001002d65e),
+    (0x0d45ddbf60, 0xa07dbdb7a8dc7089, 0x0c0855bea7c4cf20),
+    (0x167f69c500, 0xac194dc939034c45, 0x1903b8823158fbbf),
+    (0x002811e3f2, 0x2290d58c68836d7f, 0xef47ec7ca27c9df0),
+    (0x38605f3b9e, 0x1b0df3c40f827793, 0xbd06cf9382b1ac52),
+    (0x24cfd53eeb, 0xd5dd1a47edcadb17, 0x3da5f6b8b22aeea2),
+    (0x36e8e2b2d0, 0xd9cf8e283810ac57, 0xf0d8cf68bea624c6),
+    (0x30a07aa87c, 0x8f494dfd19663e44, 0xfc9ba0daa223e60f),
+    (0x1d00566022, 0x16a58de608da4303, 0xf3d0538e2551a73f),
+    (0x07efd4ad2a, 0x026323ba7272ccf6, 0x680a6e1c7af24a71),
+    (0x09c7f36ec1, 0xb0ba6c9aabef6bbf, 0x085a45ebdabcb52a),
+    (0x2d18d81ca0, 0x145da786c2dc9102, 0xa6772dde625b07dc),
+    (0x25457c2604, 0xbc2a63dce261b29d, 0xe2a39f2e3fdb1274),
+    (0x011a3ab2fd, 0x6dd8fd90ed26a0f2, 0x8624656c84a9d9b1),
+    (0x3b018d0fa6, 0x27eaeb7531ce731d, 0xbde29406b3fe0ed7),
+    (0x26afaa4315, 0x04c50406017cea3f, 0x60511e46bd213c01),
+    (0x04e4cedc35, 0x43216c4e1628a4c6, 0x8608400e6fb6f7e2),
+    (0x0c66ad1d09, 0x858a4fe8f2552d7c, 0x5ef9c3589036eefb),
+    (0x023788b8fd, 0xe50937f03c98d536, 0x00a74beac8993c4d),
+    (0x3b1d4d3e29, 0x4a9f3ce44cdf8fa6, 0xc95f3f5cc5af1f45),
+    (0x099d2a2bb4, 0xe519e9404217b1cb, 0xa75d7b89a9f29769),
+    (0x2a1e2d871e, 0x91efec61965febc4, 0xce75c4ed823631ed),
+    (0x071e1e34f6, 0x00e191b1e002a224, 0x21463ada3ff560c5),
+    (0x3946f2a0f7, 0xa097b7b5f157b611, 0x1e783d61178b1ee8),
+    (0x39a7bcf753, 0x2907f628adfb8c85, 0x2d43d663d5f31000),
+    (0x135cbd22e5, 0xbccdbbfdb66cf2ef, 0x81887fb96aa00000),
+    (0x357d6c4510, 0xe6010b794e9f5dc2, 0x45e5526240000000),
+    (0x03ef39efc2, 0xaf25be83ecf53117, 0x26dc068000000000),
+    (0x2d5414add0, 0xcb4d26c9569e5a85, 0x0621000000000000),
+    (0x309d19d4c4, 0x9caf78780fdc41b0, 0x0a00000000000000),
+    (0x2dc6fdd5f4, 0x98f00a26e208ebe4, 0x0000000000000000),
+    (0x38f324536a, 0x797eb6167d49e800, 0x0000000000000000),
+    (0x18ca614e0e, 0xb41525c9e1100000, 0x0000000000000000),
+    (0x036c64ce86, 0xf2215f36a0000000, 0x0000000000000000),
+    (0x1f6b65d413, 0x32dd5a4000000000, 0x0000000000000000),
+    (0x047854c317, 0xb3b6800000000000, 0x0000000000000000),
+    (0x0584bfdf17, 0x0100000000000000, 0x0000000000000000),
+    (0x055b23c0ca, 0x0000000000000000, 0x0000000000000000),
+    (0x2f08236400, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000108f936ba, 0xf85c136ed32ac26c),
+    (0x0000000000, 0x3db1a69ca8e627d6, 0xe2ab552795c9cf52),
+    (0x000e5d3ef2, 0x82a242e818f1668c, 0x8a86da5faf0b5cd0),
+    (0x3876bd561b, 0xc629c7888f634ec1, 0xd3366b5893b90065),
+    (0x067776c07b, 0x78a3e608686b8a22, 0xee0864f155c841db),
+    (0x1cbf75c5cd, 0xa4a23bec00deb022, 0xf7992f5502110cdc),
+    (0x2fe148b14f, 0x7374113dbb14d76d, 0xeff63fd9c78d7fde),
+    (0x127fa5e4e5, 0x2edb0dce606cda31, 0xe8617f0ca2f8b938),
+    (0x355c5f82ed, 0xb743efdaa6bfa44b, 0x456954e76cd3099a),
+    (0x3758f879c1, 0x493901b965ea6cb3, 0x864ab6089b001003),
+    (0x2d00beb377, 0xbf60a07dbdb7a8dc, 0x70890c0855bea7c5),
+    (0x1be182e21f, 0xc500ac194dc93903, 0x4c451903b8823159),
+    (0x07659cbb83, 0xe3f22290d58c6883, 0x6d7fef47ec7ca27d),
+    (0x1eb547ff7d, 0x3b9e1b0df3c40f82, 0x7793bd06cf9382b2),
+    (0x1d28761e63, 0x3eebd5dd1a47edca, 0xdb173da5f6b8b22b),
+    (0x171b8283ce, 0xb2d0d9cf8e283810, 0xac57f0d8cf68bea7),
+    (0x302030ccee, 0xa87c8f494dfd1966, 0x3e44fc9ba0daa224),
+    (0x37912260e6, 0x602216a58de608da, 0x4303f3d0538e2552),
+    (0x35a3737794, 0xad2a026323ba7272, 0xccf6680a6e1c7af3),
+    (0x229dce2a75, 0x6ec1b0ba6c9aabef, 0x6bbf085a45ebdabd),
+    (0x1b5787eda4, 0x1ca0145da786c2dc, 0x9102a6772dde625c),
+    (0x2635d39b94, 0x2604bc2a63dce261, 0xb29de2a39f2e3fdc),
+    (0x227e56dc36, 0xb2fd6dd8fd90ed26, 0xa0f28624656c84aa),
+    (0x0cbc533e01, 0x0fa627eaeb7531ce, 0x731dbde29406b3ff),
+    (0x003f3f8b80, 0x431504c50406017c, 0xea3f60511e46bd22),
+    (0x1ddd0367ac, 0xdc3543216c4e1628, 0xa4c68608400e6fb7),
+    (0x283f452445, 0x1d09858a4fe8f255, 0x2d7c5ef9c3589037),
+    (0x10177b326a, 0xb8fde50937f03c98, 0xd53600a74beac89a),
+    (0x18d92a027d, 0x3e294a9f3ce44cdf, 0x8fa6c95f3f5cc5b0),
+    (0x1d290dbc16, 0x2bb4e519e9404217, 0xb1cba75d7b89a9f3),
+    (0x05297a7a1b, 0x871e91efec61965f, 0xebc4ce75c4ed8237),
+    (0x0668c90ca4, 0x34f600e191b1e002, 0xa22421463ada3ff6),
+    (0x263b7e1ffa, 0xa0f7a097b7b5f157, 0xb6111e783d61178c),
+    (0x3a5aa3a9fc, 0xf7532907f628adfb, 0x8c852d43d663d5f4),
+    (0x3ae5f48da3, 0x22e5bccdbbfdb66c, 0xf2ef81887fb96aa0),
+    (0x25fbaeaa40, 0x4510e6010b794e9f, 0x5dc245e552624000),
+    (0x0ef6c727ab, 0xefc2af25be83ecf5, 0x311726dc06800000),
+    (0x280837c4aa, 0xadd0cb4d26c9569e, 0x5a85062100000000),
+    (0x27bd425b9b, 0xd4c49caf78780fdc, 0x41b00a0000000000),
+    (0x2448424445, 0xd5f498f00a26e208, 0xebe4000000000000),
+    (0x1042892f36, 0x536a797eb6167d49, 0xe800000000000000),
+    (0x0ca61295df, 0x4e0eb41525c9e110, 0x0000000000000000),
+    (0x33fe028bf0, 0xce86f2215f36a000, 0x0000000000000000),
+    (0x38113359f1, 0xd41332dd5a400000, 0x0000000000000000),
+    (0x384e18d19c, 0xc317b3b680000000, 0x0000000000000000),
+    (0x247fbf849d, 0xdf17010000000000, 0x0000000000000000),
+    (0x24c1df1319, 0xc0ca000000000000, 0x0000000000000000),
+    (0x05ff00d9a3, 0x6400000000000000, 0x0000000000000000),
+    (0x260ad714e8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000108f9, 0x36baf85c136ed32b),
+    (0x0000000000, 0x00003db1a69ca8e6, 0x27d6e2ab552795ca),
+    (0x0000000e5d, 0x3ef282a242e818f1, 0x668c8a86da5faf0c),
+    (0x15b5e355a9, 0x561bc629c7888f63, 0x4ec1d3366b5893ba),
+    (0x276d3bd152, 0xc07b78a3e608686b, 0x8a22ee0864f155c9),
+    (0x1344658af5, 0xc5cda4a23bec00de, 0xb022f7992f550212),
+    (0x39393150ba, 0xb14f7374113dbb14, 0xd76deff63fd9c78e),
+    (0x2b77bf494f, 0xe4e52edb0dce606c, 0xda31e8617f0ca2f9),
+    (0x129a0f8e47, 0x82edb743efdaa6bf, 0xa44b456954e76cd4),
+    (0x10a669f98c, 0x79c1493901b965ea, 0x6cb3864ab6089b01),
+    (0x20b4ffa238, 0xb377bf60a07dbdb7, 0xa8dc70890c0855bf),
+    (0x0d33a54cc0, 0xe21fc500ac194dc9, 0x39034c451903b883),
+    (0x2ce8bd8bf0, 0xbb83e3f22290d58c, 0x68836d7fef47ec7d),
+    (0x380cc626d5, 0xff7d3b9e1b0df3c4, 0x0f827793bd06cf94),
+    (0x31d34669ac, 0x1e633eebd5dd1a47, 0xedcadb173da5f6b9),
+    (0x281312f72e, 0x83ceb2d0d9cf8e28, 0x3810ac57f0d8cf69),
+    (0x0ad480a18e, 0xcceea87c8f494dfd, 0x19663e44fc9ba0db),
+    (0x213f92f61a, 0x60e6602216a58de6, 0x08da4303f3d0538f),
+    (0x0624483333, 0x7794ad2a026323ba, 0x7272ccf6680a6e1d),
+    (0x0bfbadcfb4, 0x2a756ec1b0ba6c9a, 0xabef6bbf085a45ec),
+    (0x29f2b8c4ab, 0xeda41ca0145da786, 0xc2dc9102a6772ddf),
+    (0x2807b970d5, 0x9b942604bc2a63dc, 0xe261b29de2a39f2f),
+    (0x31bc0348a4, 0xdc36b2fd6dd8fd90, 0xed26a0f28624656d),
+    (0x26626f29eb, 0x3e010fa627eaeb75, 0x31ce731dbde29407),
+    (0x36c5872a2b, 0x8b80431504c50406, 0x017cea3f60511e47),
+    (0x0a237ad6b1, 0x67acdc3543216c4e, 0x1628a4c68608400f),
+    (0x294e29308b, 0x24451d09858a4fe8, 0xf2552d7c5ef9c359),
+    (0x20657d89e3, 0x326ab8fde50937f0, 0x3c98d53600a74beb),
+    (0x34e5fe329e, 0x027d3e294a9f3ce4, 0x4cdf8fa6c95f3f5d),
+    (0x24ca1d0aa1, 0xbc162bb4e519e940, 0x4217b1cba75d7b8a),
+    (0x25a823dfd2, 0x7a1b871e91efec61, 0x965febc4ce75c4ee),
+    (0x310167e10d, 0x0ca434f600e191b1, 0xe002a22421463adb),
+    (0x0309cdbef6, 0x1ffaa0f7a097b7b5, 0xf157b6111e783d62),
+    (0x394e303519, 0xa9fcf7532907f628, 0xadfb8c852d43d664),
+    (0x05f9b1cf4e, 0x8da322e5bccdbbfd, 0xb66cf2ef81887fba),
+    (0x124a23c8f0, 0xaa404510e6010b79, 0x4e9f5dc245e55263),
+    (0x3808c120eb, 0x27abefc2af25be83, 0xecf5311726dc0680),
+    (0x36c0540afd, 0xc4aaadd0cb4d26c9, 0x569e5a8506210000),
+    (0x3b15c3e1ea, 0x5b9bd4c49caf7878, 0x0fdc41b00a000000),
+    (0x3690d0f210, 0x4445d5f498f00a26, 0xe208ebe400000000),
+    (0x03c9920031, 0x2f36536a797eb616, 0x7d49e80000000000),
+    (0x0b739ebb28, 0x95df4e0eb41525c9, 0xe110000000000000),
+    (0x097314a594, 0x8bf0ce86f2215f36, 0xa000000000000000),
+    (0x229611e4e1, 0x59f1d41332dd5a40, 0x0000000000000000),
+    (0x3477fca852, 0xd19cc317b3b68000, 0x0000000000000000),
+    (0x13486292a9, 0x849ddf1701000000, 0x0000000000000000),
+    (0x27780fec07, 0x1319c0ca00000000, 0x0000000000000000),
+    (0x01a5ae01fe, 0xd9a3640000000000, 0x0000000000000000),
+    (0x3b5640ac11, 0x14e8000000000000, 0x0000000000000000),
+    (0x03fa25860f, 0x1000000000000000, 0x0000000000000000),
+    (0x0381cb82a0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000003db1a69c, 0xa8e627d6e2ab5528),
+    (0x0000000000, 0x0e5d3ef282a242e8, 0x18f1668c8a86da60),
+    (0x0003582cef, 0x55a9561bc629c788, 0x8f634ec1d3366b59),
+    (0x37b974684d, 0xd152c07b78a3e608, 0x686b8a22ee0864f2),
+    (0x121e4b654f, 0x8af5c5cda4a23bec, 0x00deb022f7992f56),
+    (0x12851f0015, 0x50bab14f7374113d, 0xbb14d76deff63fda),
+    (0x04f67e68df, 0x494fe4e52edb0dce, 0x606cda31e8617f0d),
+    (0x33fce7b3a9, 0x8e4782edb743efda, 0xa6bfa44b456954e8),
+    (0x277a4fdc75, 0xf98c79c1493901b9, 0x65ea6cb3864ab609),
+    (0x1b77d89579, 0xa238b377bf60a07d, 0xbdb7a8dc70890c09),
+    (0x1c51eea179, 0x4cc0e21fc500ac19, 0x4dc939034c451904),
+    (0x1c3e0856b5, 0x8bf0bb83e3f22290, 0xd58c68836d7fef48),
+    (0x2a4505ea74, 0x26d5ff7d3b9e1b0d, 0xf3c40f827793bd07),
+    (0x1b0b2e5542, 0x69ac1e633eebd5dd, 0x1a47edcadb173da6),
+    (0x0f7682a3ec, 0xf72e83ceb2d0d9cf, 0x8e283810ac57f0d9),
+    (0x372c3f6566, 0xa18ecceea87c8f49, 0x4dfd19663e44fc9c),
+    (0x17e54a149c, 0xf61a60e6602216a5, 0x8de608da4303f3d1),
+    (0x248b9ffa64, 0x33337794ad2a0263, 0x23ba7272ccf6680b),
+    (0x175462b9eb, 0xcfb42a756ec1b0ba, 0x6c9aabef6bbf085b),
+    (0x36e7738a0e, 0xc4abeda41ca0145d, 0xa786c2dc9102a678),
+    (0x0370419661, 0x70d59b942604bc2a, 0x63dce261b29de2a4),
+    (0x16afebfe63, 0x48a4dc36b2fd6dd8, 0xfd90ed26a0f28625),
+    (0x171dc6093f, 0x29eb3e010fa627ea, 0xeb7531ce731dbde3),
+    (0x0eb4da45e1, 0x2a2b8b80431504c5, 0x0406017cea3f6052),
+    (0x346cdd129e, 0xd6b167acdc354321, 0x6c4e1628a4c68609),
+    (0x24fb855f05, 0x308b24451d09858a, 0x4fe8f2552d7c5efa),
+    (0x0135535d5d, 0x89e3326ab8fde509, 0x37f03c98d53600a8),
+    (0x15c7561e18, 0x329e027d3e294a9f, 0x3ce44cdf8fa6c960),
+    (0x05a24bf591, 0x0aa1bc162bb4e519, 0xe9404217b1cba75e),
+    (0x21c5261e07, 0xdfd27a1b871e91ef, 0xec61965febc4ce76),
+    (0x01d5585d5d, 0xe10d0ca434f600e1, 0x91b1e002a2242147),
+    (0x15dba17757, 0xbef61ffaa0f7a097, 0xb7b5f157b6111e79),
+    (0x146e10d61c, 0x3519a9fcf7532907, 0xf628adfb8c852d44),
+    (0x06914b1d83, 0xcf4e8da322e5bccd, 0xbbfdb66cf2ef8189),
+    (0x1eb079d179, 0xc8f0aa404510e601, 0x0b794e9f5dc245e6),
+    (0x1c5af27091, 0x20eb27abefc2af25, 0xbe83ecf5311726dd),
+    (0x21ca568394, 0x0afdc4aaadd0cb4d, 0x26c9569e5a850621),
+    (0x22780be9a7, 0xe1ea5b9bd4c49caf, 0x78780fdc41b00a00),
+    (0x271693625a, 0xf2104445d5f498f0, 0x0a26e208ebe40000),
+    (0x152cc72086, 0x00312f36536a797e, 0xb6167d49e8000000),
+    (0x1f33112f9e, 0xbb2895df4e0eb415, 0x25c9e11000000000),
+    (0x24f51c30a2, 0xa5948bf0ce86f221, 0x5f36a00000000000),
+    (0x25de812e43, 0xe4e159f1d41332dd, 0x5a40000000000000),
+    (0x0fcecd31e4, 0xa852d19cc317b3b6, 0x8000000000000000),
+    (0x353d0cc4f2, 0x92a9849ddf170100, 0x0000000000000000),
+    (0x387a78b343, 0xec071319c0ca0000, 0x0000000000000000),
+    (0x0fd07735e6, 0x01fed9a364000000, 0x0000000000000000),
+    (0x358d886d0a, 0xac1114e800000000, 0x0000000000000000),
+    (0x027c1bdddd, 0x860f100000000000, 0x0000000000000000),
+    (0x3393d6e987, 0x82a0000000000000, 0x0000000000000000),
+    (0x1f8d0a6152, 0x4000000000000000, 0x0000000000000000),
+    (0x13267b6680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000003db1, 0xa69ca8e627d6e2ac),
+    (0x0000000000, 0x00000e5d3ef282a2, 0x42e818f1668c8a87),
+    (0x0000000358, 0x2cef55a9561bc629, 0xc7888f634ec1d337),
+    (0x1487abc42c, 0x684dd152c07b78a3, 0xe608686b8a22ee09),
+    (0x0a56e3b85b, 0x654f8af5c5cda4a2, 0x3bec00deb022f79a),
+    (0x15479c64d1, 0x001550bab14f7374, 0x113dbb14d76deff7),
+    (0x30a963e07e, 0x68df494fe4e52edb, 0x0dce606cda31e862),
+    (0x1d6e9a4af7, 0xb3a98e4782edb743, 0xefdaa6bfa44b456a),
+    (0x39ac2d9b8d, 0xdc75f98c79c14939, 0x01b965ea6cb3864b),
+    (0x210795bf70, 0x9579a238b377bf60, 0xa07dbdb7a8dc708a),
+    (0x1a3685c980, 0xa1794cc0e21fc500, 0xac194dc939034c46),
+    (0x1df2fd9712, 0x56b58bf0bb83e3f2, 0x2290d58c68836d80),
+    (0x04451278e1, 0xea7426d5ff7d3b9e, 0x1b0df3c40f827794),
+    (0x3499a211d2, 0x554269ac1e633eeb, 0xd5dd1a47edcadb18),
+    (0x30f8d38f94, 0xa3ecf72e83ceb2d0, 0xd9cf8e283810ac58),
+    (0x229ba782dd, 0x6566a18ecceea87c, 0x8f494dfd19663e45),
+    (0x338c3c58fc, 0x149cf61a60e66022, 0x16a58de608da4304),
+    (0x3ab12b7b67, 0xfa6433337794ad2a, 0x026323ba7272ccf7),
+    (0x183593c3a6, 0xb9ebcfb42a756ec1, 0xb0ba6c9aabef6bc0),
+    (0x26d1a8bf6d, 0x8a0ec4abeda41ca0, 0x145da786c2dc9103),
+    (0x19810ce325, 0x966170d59b942604, 0xbc2a63dce261b29e),
+    (0x08c0629447, 0xfe6348a4dc36b2fd, 0x6dd8fd90ed26a0f3),
+    (0x10c328b832, 0x093f29eb3e010fa6, 0x27eaeb7531ce731e),
+    (0x0ba66299f4, 0x45e12a2b8b804315, 0x04c50406017cea40),
+    (0x38dfcdad4f, 0x129ed6b167acdc35, 0x43216c4e1628a4c7),
+    (0x12691a33b9, 0x5f05308b24451d09, 0x858a4fe8f2552d7d),
+    (0x2b28fba049, 0x5d5d89e3326ab8fd, 0xe50937f03c98d537),
+    (0x1114e09cb8, 0x1e18329e027d3e29, 0x4a9f3ce44cdf8fa7),
+    (0x2ade42f5f7, 0xf5910aa1bc162bb4, 0xe519e9404217b1cc),
+    (0x39bb85ce78, 0x1e07dfd27a1b871e, 0x91efec61965febc5),
+    (0x1bf78ca904, 0x5d5de10d0ca434f6, 0x00e191b1e002a225),
+    (0x0104283f03, 0x7757bef61ffaa0f7, 0xa097b7b5f157b612),
+    (0x00ce99bff6, 0xd61c3519a9fcf753, 0x2907f628adfb8c86),
+    (0x3978981227, 0x1d83cf4e8da322e5, 0xbccdbbfdb66cf2f0),
+    (0x091b73ff5b, 0xd179c8f0aa404510, 0xe6010b794e9f5dc3),
+    (0x1560cb87dc, 0x709120eb27abefc2, 0xaf25be83ecf53118),
+    (0x33533b1ab6, 0x83940afdc4aaadd0, 0xcb4d26c9569e5a86),
+    (0x2a7eae4969, 0xe9a7e1ea5b9bd4c4, 0x9caf78780fdc41b1),
+    (0x18a8e3d26d, 0x625af2104445d5f4, 0x98f00a26e208ebe4),
+    (0x1977ce701b, 0x208600312f36536a, 0x797eb6167d49e800),
+    (0x0650e5da51, 0x2f9ebb2895df4e0e, 0xb41525c9e1100000),
+    (0x12e7104a32, 0x30a2a5948bf0ce86, 0xf2215f36a0000000),
+    (0x0baf8e5861, 0x2e43e4e159f1d413, 0x32dd5a4000000000),
+    (0x16a06c2919, 0x31e4a852d19cc317, 0xb3b6800000000000),
+    (0x05ddbb97b6, 0xc4f292a9849ddf17, 0x0100000000000000),
+    (0x2a8de69920, 0xb343ec071319c0ca, 0x0000000000000000),
+    (0x079d164bb5, 0x35e601fed9a36400, 0x0000000000000000),
+    (0x2a30fd6b5a, 0x6d0aac1114e80000, 0x0000000000000000),
+    (0x150dce681d, 0xdddd860f10000000, 0x0000000000000000),
+    (0x06f4311638, 0xe98782a000000000, 0x0000000000000000),
+    (0x0d403b9ee4, 0x6152400000000000, 0x0000000000000000),
+    (0x352c84b505, 0x6680000000000000, 0x0000000000000000),
+    (0x0141e36be1, 0x0000000000000000, 0x0000000000000000),
+    (0x34630b8a00, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000e5d3ef2, 0x82a242e818f1668d),
+    (0x0000000000, 0x03582cef55a9561b, 0xc629c7888f634ec2),
+    (0x0000c75809, 0xc42c684dd152c07b, 0x78a3e608686b8a23),
+    (0x02461df38b, 0xb85b654f8af5c5cd, 0xa4a23bec00deb023),
+    (0x2087f836cc, 0x64d1001550bab14f, 0x7374113dbb14d76e),
+    (0x2f96d2184b, 0xe07e68df494fe4e5, 0x2edb0dce606cda32),
+    (0x11aa9e0d5a, 0x4af7b3a98e4782ed, 0xb743efdaa6bfa44c),
+    (0x1505df6e91, 0x9b8ddc75f98c79c1, 0x493901b965ea6cb4),
+    (0x21e6e429e3, 0xbf709579a238b377, 0xbf60a07dbdb7a8dd),
+    (0x3506d3d13b, 0xc980a1794cc0e21f, 0xc500ac194dc93904),
+    (0x0deb970997, 0x971256b58bf0bb83, 0xe3f22290d58c6884),
+    (0x234b79b838, 0x78e1ea7426d5ff7d, 0x3b9e1b0df3c40f83),
+    (0x0d26015852, 0x11d2554269ac1e63, 0x3eebd5dd1a47edcb),
+    (0x131bbaf43d, 0x8f94a3ecf72e83ce, 0xb2d0d9cf8e283811),
+    (0x0e5550347d, 0x82dd6566a18eccee, 0xa87c8f494dfd1967),
+    (0x1d390cc8d0, 0x58fc149cf61a60e6, 0x602216a58de608db),
+    (0x30827c069b, 0x7b67fa6433337794, 0xad2a026323ba7273),
+    (0x243373e2a1, 0xc3a6b9ebcfb42a75, 0x6ec1b0ba6c9aabf0),
+    (0x25a9e6c386, 0xbf6d8a0ec4abeda4, 0x1ca0145da786c2dd),
+    (0x1f5f97b9c2, 0xe325966170d59b94, 0x2604bc2a63dce262),
+    (0x2d60301580, 0x9447fe6348a4dc36, 0xb2fd6dd8fd90ed27),
+    (0x1defeb3ff0, 0xb832093f29eb3e01, 0x0fa627eaeb7531cf),
+    (0x380c004792, 0x99f445e12a2b8b80, 0x431504c50406017d),
+    (0x22221f9687, 0xad4f129ed6b167ac, 0xdc3543216c4e1629),
+    (0x1f96fa899c, 0x33b95f05308b2445, 0x1d09858a4fe8f256),
+    (0x245e5e1739, 0xa0495d5d89e3326a, 0xb8fde50937f03c99),
+    (0x0d6ac8cd20, 0x9cb81e18329e027d, 0x3e294a9f3ce44ce0),
+    (0x0797d6715a, 0xf5f7f5910aa1bc16, 0x2bb4e519e9404218),
+    (0x152dafe2d7, 0xce781e07dfd27a1b, 0x871e91efec619660),
+    (0x323f122c18, 0xa9045d5de10d0ca4, 0x34f600e191b1e003),
+    (0x05bddd2382, 0x3f037757bef61ffa, 0xa0f7a097b7b5f158),
+    (0x1e53467a4f, 0xbff6d61c3519a9fc, 0xf7532907f628adfc),
+    (0x1291764b4e, 0x12271d83cf4e8da3, 0x22e5bccdbbfdb66d),
+    (0x122d6389a7, 0xff5bd179c8f0aa40, 0x4510e6010b794ea0),
+    (0x271d6e5601, 0x87dc709120eb27ab, 0xefc2af25be83ecf6),
+    (0x005b3cbdc1, 0x1ab683940afdc4aa, 0xadd0cb4d26c9569f),
+    (0x2cf5ea8132, 0x4969e9a7e1ea5b9b, 0xd4c49caf78780fdd),
+    (0x0bb553407d, 0xd26d625af2104445, 0xd5f498f00a26e209),
+    (0x1d4b931382, 0x701b208600312f36, 0x536a797eb6167d4a),
+    (0x1e5eb49d45, 0xda512f9ebb2895df, 0x4e0eb41525c9e110),
+    (0x10438d2914, 0x4a3230a2a5948bf0, 0xce86f2215f36a000),
+    (0x04b95e35f2, 0x58612e43e4e159f1, 0xd41332dd5a400000),
+    (0x386ce6c9dc, 0x291931e4a852d19c, 0xc317b3b680000000),
+    (0x3342974015, 0x97b6c4f292a9849d, 0xdf17010000000000),
+    (0x0507056d0c, 0x9920b343ec071319, 0xc0ca000000000000),
+    (0x02eee897d0, 0x4bb535e601fed9a3, 0x6400000000000000),
+    (0x307f64aa2b, 0x6b5a6d0aac1114e8, 0x0000000000000000),
+    (0x0a1bfeae3c, 0x681ddddd860f1000, 0x0000000000000000),
+    (0x0e10852e41, 0x1638e98782a00000, 0x0000000000000000),
+    (0x0f2779d797, 0x9ee4615240000000, 0x0000000000000000),
+    (0x234d4bdb30, 0xb505668000000000, 0x0000000000000000),
+    (0x0b572b92b5, 0x6be1000000000000, 0x0000000000000000),
+    (0x2a3d8ee779, 0x8a00000000000000, 0x0000000000000000),
+    (0x1c4c4aeae4, 0x0000000000000000, 0x0000000000000000),
+    (0x3515dbe800, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000e5d, 0x3ef282a242e818f2),
+    (0x0000000000, 0x000003582cef55a9, 0x561bc629c7888f64),
+    (0x00000000c7, 0x5809c42c684dd152, 0xc07b78a3e608686c),
+    (0x2e69d2818d, 0xf38bb85b654f8af5, 0xc5cda4a23bec00df),
+    (0x210cf5b7b6, 0x36cc64d1001550ba, 0xb14f7374113dbb15),
+    (0x2a6ccdd96e, 0x184be07e68df494f, 0xe4e52edb0dce606d),
+    (0x19a22af98e, 0x0d5a4af7b3a98e47, 0x82edb743efdaa6c0),
+    (0x2112f7ee21, 0x6e919b8ddc75f98c, 0x79c1493901b965eb),
+    (0x07c8b273b0, 0x29e3bf709579a238, 0xb377bf60a07dbdb8),
+    (0x29042bb12d, 0xd13bc980a1794cc0, 0xe21fc500ac194dca),
+    (0x0aaaeccc81, 0x0997971256b58bf0, 0xbb83e3f22290d58d),
+    (0x1e0b3b8693, 0xb83878e1ea7426d5, 0xff7d3b9e1b0df3c5),
+    (0x2264c66531, 0x585211d2554269ac, 0x1e633eebd5dd1a48),
+    (0x0b7d30fb2a, 0xf43d8f94a3ecf72e, 0x83ceb2d0d9cf8e29),
+    (0x0a004301d8, 0x347d82dd6566a18e, 0xcceea87c8f494dfe),
+    (0x3256d31a14, 0xc8d058fc149cf61a, 0x60e6602216a58de7),
+    (0x04d6d9384c, 0x069b7b67fa643333, 0x7794ad2a026323bb),
+    (0x11b37dcc2f, 0xe2a1c3a6b9ebcfb4, 0x2a756ec1b0ba6c9b),
+    (0x0b262f663a, 0xc386bf6d8a0ec4ab, 0xeda41ca0145da787),
+    (0x0dae980b75, 0xb9c2e325966170d5, 0x9b942604bc2a63dd),
+    (0x1b68fe8e2a, 0x15809447fe6348a4, 0xdc36b2fd6dd8fd91),
+    (0x09cc66c67d, 0x3ff0b832093f29eb, 0x3e010fa627eaeb76),
+    (0x1d2977c5b6, 0x479299f445e12a2b, 0x8b80431504c50407),
+    (0x2a70b5ac25, 0x9687ad4f129ed6b1, 0x67acdc3543216c4f),
+    (0x08c06b7b56, 0x899c33b95f05308b, 0x24451d09858a4fe9),
+    (0x14260a1078, 0x1739a0495d5d89e3, 0x326ab8fde50937f1),
+    (0x1bf5f702ee, 0xcd209cb81e18329e, 0x027d3e294a9f3ce5),
+    (0x3799aa5798, 0x715af5f7f5910aa1, 0xbc162bb4e519e941),
+    (0x237e4c70d9, 0xe2d7ce781e07dfd2, 0x7a1b871e91efec62),
+    (0x32bb061f66, 0x2c18a9045d5de10d, 0x0ca434f600e191b2),
+    (0x17c9f0d495, 0x23823f037757bef6, 0x1ffaa0f7a097b7b6),
+    (0x22b95c0ee4, 0x7a4fbff6d61c3519, 0xa9fcf7532907f629),
+    (0x3532563dba, 0x4b4e12271d83cf4e, 0x8da322e5bccdbbfe),
+    (0x2b5fff4a91, 0x89a7ff5bd179c8f0, 0xaa404510e6010b7a),
+    (0x21e2b95d88, 0x560187dc709120eb, 0x27abefc2af25be84),
+    (0x1fbe41ab18, 0xbdc11ab683940afd, 0xc4aaadd0cb4d26ca),
+    (0x05c2b12d0c, 0x81324969e9a7e1ea, 0x5b9bd4c49caf7879),
+    (0x02e9562d1d, 0x407dd26d625af210, 0x4445d5f498f00a27),
+    (0x06cf8ce013, 0x1382701b20860031, 0x2f36536a797eb617),
+    (0x047107d9b2, 0x9d45da512f9ebb28, 0x95df4e0eb41525ca),
+    (0x29963ea56f, 0x29144a3230a2a594, 0x8bf0ce86f2215f37),
+    (0x19e1ae19b8, 0x35f258612e43e4e1, 0x59f1d41332dd5a40),
+    (0x2ae3d0a8b8, 0xc9dc291931e4a852, 0xd19cc317b3b68000),
+    (0x2b0640fb31, 0x401597b6c4f292a9, 0x849ddf1701000000),
+    (0x0b778c6385, 0x6d0c9920b343ec07, 0x1319c0ca00000000),
+    (0x1f10cec8ea, 0x97d04bb535e601fe, 0xd9a3640000000000),
+    (0x369ed5708a, 0xaa2b6b5a6d0aac11, 0x14e8000000000000),
+    (0x20490fca22, 0xae3c681ddddd860f, 0x1000000000000000),
+    (0x08132019d1, 0x2e411638e98782a0, 0x0000000000000000),
+    (0x30b423e1c5, 0xd7979ee461524000, 0x0000000000000000),
+    (0x2e104fbef1, 0xdb30b50566800000, 0x0000000000000000),
+    (0x384fc0ebf9, 0x92b56be100000000, 0x0000000000000000),
+    (0x3a1bb6fec2, 0xe7798a0000000000, 0x0000000000000000),
+    (0x2d61320c90, 0xeae4000000000000, 0x0000000000000000),
+    (0x21bdc2327f, 0xe800000000000000, 0x0000000000000000),
+    (0x1dc7ce7d10, 0x0000000000000000, 0x0000000000000000),
+    (0x03b9aca000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000003582cef, 0x55a9561bc629c789),
+    (0x0000000000, 0x00c75809c42c684d, 0xd152c07b78a3e609),
+    (0x00002e69d2, 0x818df38bb85b654f, 0x8af5c5cda4a23bed),
+    (0x310323c0bf, 0xb7b636cc64d10015, 0x50bab14f7374113e),
+    (0x2ca342c933, 0xd96e184be07e68df, 0x494fe4e52edb0dcf),
+    (0x0c12761564, 0xf98e0d5a4af7b3a9, 0x8e4782edb743efdb),
+    (0x1782918971, 0xee216e919b8ddc75, 0xf98c79c1493901ba),
+    (0x1a86c4da7e, 0x73b029e3bf709579, 0xa238b377bf60a07e),
+    (0x1d711ef8e9, 0xb12dd13bc980a179, 0x4cc0e21fc500ac1a),
+    (0x3669228a96, 0xcc810997971256b5, 0x8bf0bb83e3f22291),
+    (0x231c4fc033, 0x8693b83878e1ea74, 0x26d5ff7d3b9e1b0e),
+    (0x0bff2ba882, 0x6531585211d25542, 0x69ac1e633eebd5de),
+    (0x1e5c2a22e2, 0xfb2af43d8f94a3ec, 0xf72e83ceb2d0d9d0),
+    (0x34d9211851, 0x01d8347d82dd6566, 0xa18ecceea87c8f4a),
+    (0x12dc67db9d, 0x1a14c8d058fc149c, 0xf61a60e6602216a6),
+    (0x249400715d, 0x384c069b7b67fa64, 0x33337794ad2a0264),
+    (0x15b456f1ad, 0xcc2fe2a1c3a6b9eb, 0xcfb42a756ec1b0bb),
+    (0x2877250127, 0x663ac386bf6d8a0e, 0xc4abeda41ca0145e),
+    (0x092c622114, 0x0b75b9c2e3259661, 0x70d59b942604bc2b),
+    (0x04aac2d7ac, 0x8e2a15809447fe63, 0x48a4dc36b2fd6dd9),
+    (0x282d196072, 0xc67d3ff0b832093f, 0x29eb3e010fa627eb),
+    (0x1ab924d5b3, 0xc5b6479299f445e1, 0x2a2b8b80431504c6),
+    (0x29db43cc27, 0xac259687ad4f129e, 0xd6b167acdc354322),
+    (0x093ca98623, 0x7b56899c33b95f05, 0x308b24451d09858b),
+    (0x0842e12318, 0x10781739a0495d5d, 0x89e3326ab8fde50a),
+    (0x059a589297, 0x02eecd209cb81e18, 0x329e027d3e294aa0),
+    (0x2328fbf53e, 0x5798715af5f7f591, 0x0aa1bc162bb4e51a),
+    (0x0e83e204f2, 0x70d9e2d7ce781e07, 0xdfd27a1b871e91f0),
+    (0x3872996766, 0x1f662c18a9045d5d, 0xe10d0ca434f600e2),
+    (0x17c6fc0466, 0xd49523823f037757, 0xbef61ffaa0f7a098),
+    (0x17f12b64a4, 0x0ee47a4fbff6d61c, 0x3519a9fcf7532908),
+    (0x2632a11162, 0x3dba4b4e12271d83, 0xcf4e8da322e5bcce),
+    (0x16dfa09621, 0x4a9189a7ff5bd179, 0xc8f0aa404510e602),
+    (0x07c050ab1d, 0x5d88560187dc7091, 0x20eb27abefc2af26),
+    (0x06d64fdba3, 0xab18bdc11ab68394, 0x0afdc4aaadd0cb4e),
+    (0x261b64c59f, 0x2d0c81324969e9a7, 0xe1ea5b9bd4c49cb0),
+    (0x250fa094d8, 0x2d1d407dd26d625a, 0xf2104445d5f498f1),
+    (0x32551b750e, 0xe0131382701b2086, 0x00312f36536a797f),
+    (0x0376a2edc7, 0xd9b29d45da512f9e, 0xbb2895df4e0eb416),
+    (0x2e8802d178, 0xa56f29144a3230a2, 0xa5948bf0ce86f222),
+    (0x1c171355e0, 0x19b835f258612e43, 0xe4e159f1d41332de),
+    (0x342d6dc18a, 0xa8b8c9dc291931e4, 0xa852d19cc317b3b7),
+    (0x2048b97ed0, 0xfb31401597b6c4f2, 0x92a9849ddf170100),
+    (0x30a8405b9a, 0x63856d0c9920b343, 0xec071319c0ca0000),
+    (0x23f24970ec, 0xc8ea97d04bb535e6, 0x01fed9a364000000),
+    (0x372179c4a5, 0x708aaa2b6b5a6d0a, 0xac1114e800000000),
+    (0x2684f8336f, 0xca22ae3c681ddddd, 0x860f100000000000),
+    (0x1a072dcc84, 0x19d12e411638e987, 0x82a0000000000000),
+    (0x1ec1d2f9dd, 0xe1c5d7979ee46152, 0x4000000000000000),
+    (0x33a9317dd9, 0xbef1db30b5056680, 0x0000000000000000),
+    (0x32b2aa6bac, 0xebf992b56be10000, 0x0000000000000000),
+    (0x2842f0eb24, 0xfec2e7798a000000, 0x0000000000000000),
+    (0x089d155d9e, 0x0c90eae400000000, 0x0000000000000000),
+    (0x24cc75ab3a, 0x327fe80000000000, 0x0000000000000000),
+    (0x0d8cd3c742, 0x7d10000000000000, 0x0000000000000000),
+    (0x0f7b06624e, 0xa000000000000000, 0x0000000000000000),
+    (0x124e6a4a40, 0x0000000000000000, 0x0000000000000000),
+    (0x0ee6b28000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000358, 0x2cef55a9561bc62a),
+    (0x0000000000, 0x000000c75809c42c, 0x684dd152c07b78a4),
+    (0x000000002e, 0x69d2818df38bb85b, 0x654f8af5c5cda4a3),
+    (0x0ace73cbfd, 0xc0bfb7b636cc64d1, 0x001550bab14f7375),
+    (0x3b14da5cc2, 0xc933d96e184be07e, 0x68df494fe4e52edc),
+    (0x2d5a25af10, 0x1564f98e0d5a4af7, 0xb3a98e4782edb744),
+    (0x03bea7d523, 0x8971ee216e919b8d, 0xdc75f98c79c1493a),
+    (0x084629fade, 0xda7e73b029e3bf70, 0x9579a238b377bf61),
+    (0x33e31a6d22, 0xf8e9b12dd13bc980, 0xa1794cc0e21fc501),
+    (0x08248330d2, 0x8a96cc8109979712, 0x56b58bf0bb83e3f3),
+    (0x31053e4133, 0xc0338693b83878e1, 0xea7426d5ff7d3b9f),
+    (0x0c0c9654ab, 0xa8826531585211d2, 0x554269ac1e633eec),
+    (0x27f7a0deba, 0x22e2fb2af43d8f94, 0xa3ecf72e83ceb2d1),
+    (0x2b56962bf5, 0x185101d8347d82dd, 0x6566a18ecceea87d),
+    (0x3910ccb157, 0xdb9d1a14c8d058fc, 0x149cf61a60e66023),
+    (0x1474bca4ce, 0x715d384c069b7b67, 0xfa6433337794ad2b),
+    (0x3010f39380, 0xf1adcc2fe2a1c3a6, 0xb9ebcfb42a756ec2),
+    (0x1e05aa2f4f, 0x0127663ac386bf6d, 0x8a0ec4abeda41ca1),
+    (0x1265091d2c, 0x21140b75b9c2e325, 0x966170d59b942605),
+    (0x0a464e56cc, 0xd7ac8e2a15809447, 0xfe6348a4dc36b2fe),
+    (0x2fb19024bf, 0x6072c67d3ff0b832, 0x093f29eb3e010fa7),
+    (0x2c8ef17ae4, 0xd5b3c5b6479299f4, 0x45e12a2b8b804316),
+    (0x35479d8d55, 0xcc27ac259687ad4f, 0x129ed6b167acdc36),
+    (0x13f9eda7a1, 0x86237b56899c33b9, 0x5f05308b24451d0a),
+    (0x259b94529d, 0x231810781739a049, 0x5d5d89e3326ab8fe),
+    (0x249619a5f6, 0x929702eecd209cb8, 0x1e18329e027d3e2a),
+    (0x3968df8c2f, 0xf53e5798715af5f7, 0xf5910aa1bc162bb5),
+    (0x0b2a84bd34, 0x04f270d9e2d7ce78, 0x1e07dfd27a1b871f),
+    (0x0c1c97e5c1, 0x67661f662c18a904, 0x5d5de10d0ca434f7),
+    (0x2d07c55842, 0x0466d49523823f03, 0x7757bef61ffaa0f8),
+    (0x0f5eee7053, 0x64a40ee47a4fbff6, 0xd61c3519a9fcf754),
+    (0x136a9e2789, 0x11623dba4b4e1227, 0x1d83cf4e8da322e6),
+    (0x1fe9e2410a, 0x96214a9189a7ff5b, 0xd179c8f0aa404511),
+    (0x02770056ac, 0xab1d5d88560187dc, 0x709120eb27abefc3),
+    (0x2833d6f33d, 0xdba3ab18bdc11ab6, 0x83940afdc4aaadd1),
+    (0x0e6705a832, 0xc59f2d0c81324969, 0xe9a7e1ea5b9bd4c5),
+    (0x0bd23ea112, 0x94d82d1d407dd26d, 0x625af2104445d5f5),
+    (0x04538a05e3, 0x750ee0131382701b, 0x208600312f36536b),
+    (0x34f58252f4, 0xedc7d9b29d45da51, 0x2f9ebb2895df4e0f),
+    (0x3906e55d04, 0xd178a56f29144a32, 0x30a2a5948bf0ce87),
+    (0x011f309dfd, 0x55e019b835f25861, 0x2e43e4e159f1d414),
+    (0x3afbf8347f, 0xc18aa8b8c9dc2919, 0x31e4a852d19cc318),
+    (0x1dbeda3103, 0x7ed0fb31401597b6, 0xc4f292a9849ddf18),
+    (0x00d05735ac, 0x5b9a63856d0c9920, 0xb343ec071319c0ca),
+    (0x282153b017, 0x70ecc8ea97d04bb5, 0x35e601fed9a36400),
+    (0x057532ffd9, 0xc4a5708aaa2b6b5a, 0x6d0aac1114e80000),
+    (0x32b3fe41a0, 0x336fca22ae3c681d, 0xdddd860f10000000),
+    (0x254cb81d6b, 0xcc8419d12e411638, 0xe98782a000000000),
+    (0x19195088ca, 0xf9dde1c5d7979ee4, 0x6152400000000000),
+    (0x2f42509bab, 0x7dd9bef1db30b505, 0x6680000000000000),
+    (0x27edb2354c, 0x6bacebf992b56be1, 0x0000000000000000),
+    (0x11cb05ed5e, 0xeb24fec2e7798a00, 0x0000000000000000),
+    (0x161995e083, 0x5d9e0c90eae40000, 0x0000000000000000),
+    (0x1e960165d7, 0xab3a327fe8000000, 0x0000000000000000),
+    (0x3236dd97c1, 0xc7427d1000000000, 0x0000000000000000),
+    (0x2d1e17180c, 0x624ea00000000000, 0x0000000000000000),
+    (0x02e22507be, 0x4a40000000000000, 0x0000000000000000),
+    (0x2c4e2b9116, 0x8000000000000000, 0x0000000000000000),
+    (0x053d1ac100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000c75809, 0xc42c684dd152c07c),
+    (0x0000000000, 0x002e69d2818df38b, 0xb85b654f8af5c5ce),
+    (0x00000ace73, 0xcbfdc0bfb7b636cc, 0x64d1001550bab150),
+    (0x1af6079108, 0x5cc2c933d96e184b, 0xe07e68df494fe4e6),
+    (0x01f26f4abd, 0xaf101564f98e0d5a, 0x4af7b3a98e4782ee),
+    (0x2c2a09b0bd, 0xd5238971ee216e91, 0x9b8ddc75f98c79c2),
+    (0x2c32e7323b, 0xfadeda7e73b029e3, 0xbf709579a238b378),
+    (0x0df7159a5e, 0x6d22f8e9b12dd13b, 0xc980a1794cc0e220),
+    (0x15fc3f3885, 0x30d28a96cc810997, 0x971256b58bf0bb84),
+    (0x1f02c8fd1e, 0x4133c0338693b838, 0x78e1ea7426d5ff7e),
+    (0x070b5205e0, 0x54aba88265315852, 0x11d2554269ac1e64),
+    (0x343b2781e8, 0xdeba22e2fb2af43d, 0x8f94a3ecf72e83cf),
+    (0x363822a1c2, 0x2bf5185101d8347d, 0x82dd6566a18eccef),
+    (0x2d358924ba, 0xb157db9d1a14c8d0, 0x58fc149cf61a60e7),
+    (0x2b77c13e66, 0xa4ce715d384c069b, 0x7b67fa6433337795),
+    (0x17e60bb65b, 0x9380f1adcc2fe2a1, 0xc3a6b9ebcfb42a76),
+    (0x15525db5a8, 0x2f4f0127663ac386, 0xbf6d8a0ec4abeda5),
+    (0x272898601f, 0x1d2c21140b75b9c2, 0xe325966170d59b95),
+    (0x073e894530, 0x56ccd7ac8e2a1580, 0x9447fe6348a4dc37),
+    (0x0b413b916c, 0x24bf6072c67d3ff0, 0xb832093f29eb3e02),
+    (0x192ddb8b59, 0x7ae4d5b3c5b64792, 0x99f445e12a2b8b81),
+    (0x14d56d45e1, 0x8d55cc27ac259687, 0xad4f129ed6b167ad),
+    (0x3483f3c52f, 0xa7a186237b56899c, 0x33b95f05308b2446),
+    (0x0b1872ab5a, 0x529d231810781739, 0xa0495d5d89e3326b),
+    (0x1507a72ecd, 0xa5f6929702eecd20, 0x9cb81e18329e027e),
+    (0x2fe197ef11, 0x8c2ff53e5798715a, 0xf5f7f5910aa1bc17),
+    (0x0415eb3afc, 0xbd3404f270d9e2d7, 0xce781e07dfd27a1c),
+    (0x3ad86c3bb9, 0xe5c167661f662c18, 0xa9045d5de10d0ca5),
+    (0x2b485a7877, 0x58420466d4952382, 0x3f037757bef61ffb),
+    (0x1bc9807a5e, 0x705364a40ee47a4f, 0xbff6d61c3519a9fd),
+    (0x15fcfd4efe, 0x278911623dba4b4e, 0x12271d83cf4e8da4),
+    (0x3b2cc8eaa8, 0x410a96214a9189a7, 0xff5bd179c8f0aa41),
+    (0x272cb9544a, 0x56acab1d5d885601, 0x87dc709120eb27ac),
+    (0x114eec97b2, 0xf33ddba3ab18bdc1, 0x1ab683940afdc4ab),
+    (0x29aa42c8c3, 0xa832c59f2d0c8132, 0x4969e9a7e1ea5b9c),
+    (0x2d8e1144ce, 0xa11294d82d1d407d, 0xd26d625af2104446),
+    (0x301c0f3894, 0x05e3750ee0131382, 0x701b208600312f37),
+    (0x2276dbc374, 0x52f4edc7d9b29d45, 0xda512f9ebb2895e0),
+    (0x1b15742399, 0x5d04d178a56f2914, 0x4a3230a2a5948bf1),
+    (0x23b52b1492, 0x9dfd55e019b835f2, 0x58612e43e4e159f2),
+    (0x2223101dda, 0x347fc18aa8b8c9dc, 0x291931e4a852d19d),
+    (0x32ce0933e2, 0x31037ed0fb314015, 0x97b6c4f292a9849e),
+    (0x34aa0fc501, 0x35ac5b9a63856d0c, 0x9920b343ec07131a),
+    (0x004819f725, 0xb01770ecc8ea97d0, 0x4bb535e601fed9a4),
+    (0x08c65f1212, 0xffd9c4a5708aaa2b, 0x6b5a6d0aac1114e8),
+    (0x046c741734, 0x41a0336fca22ae3c, 0x681ddddd860f1000),
+    (0x0c2ab8a202, 0x1d6bcc8419d12e41, 0x1638e98782a00000),
+    (0x007e0f3632, 0x88caf9dde1c5d797, 0x9ee4615240000000),
+    (0x0bc414f1a0, 0x9bab7dd9bef1db30, 0xb505668000000000),
+    (0x2564fce600, 0x354c6bacebf992b5, 0x6be1000000000000),
+    (0x000c68d6d7, 0xed5eeb24fec2e779, 0x8a00000000000000),
+    (0x3246440c97, 0xe0835d9e0c90eae4, 0x0000000000000000),
+    (0x235c932cc1, 0x65d7ab3a327fe800, 0x0000000000000000),
+    (0x2d0768928f, 0x97c1c7427d100000, 0x0000000000000000),
+    (0x216ecc413d, 0x180c624ea0000000, 0x0000000000000000),
+    (0x0e397b8715, 0x07be4a4000000000, 0x0000000000000000),
+    (0x04e5801bb1, 0x9116800000000000, 0x0000000000000000),
+    (0x2957cd9384, 0xc100000000000000, 0x0000000000000000),
+    (0x1ee8bfda4a, 0x0000000000000000, 0x0000000000000000),
+    (0x113abe6400, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000000c7, 0x5809c42c684dd153),
+    (0x0000000000, 0x0000002e69d2818d, 0xf38bb85b654f8af6),
+    (0x000000000a, 0xce73cbfdc0bfb7b6, 0x36cc64d1001550bb),
+    (0x02841d6893, 0x91085cc2c933d96e, 0x184be07e68df4950),
+    (0x225ba69cd9, 0x4abdaf101564f98e, 0x0d5a4af7b3a98e48),
+    (0x32979c226d, 0xb0bdd5238971ee21, 0x6e919b8ddc75f98d),
+    (0x198a0e9fc7, 0x323bfadeda7e73b0, 0x29e3bf709579a239),
+    (0x2e61053889, 0x9a5e6d22f8e9b12d, 0xd13bc980a1794cc1),
+    (0x2009c733c3, 0x388530d28a96cc81, 0x0997971256b58bf1),
+    (0x2d7410bcf8, 0xfd1e4133c0338693, 0xb83878e1ea7426d6),
+    (0x39f8e2b4f4, 0x05e054aba8826531, 0x585211d2554269ad),
+    (0x38d0e6c919, 0x81e8deba22e2fb2a, 0xf43d8f94a3ecf72f),
+    (0x05f05cf1ec, 0xa1c22bf5185101d8, 0x347d82dd6566a18f),
+    (0x37185bc693, 0x24bab157db9d1a14, 0xc8d058fc149cf61b),
+    (0x22426f3a29, 0x3e66a4ce715d384c, 0x069b7b67fa643334),
+    (0x099a51bcf7, 0xb65b9380f1adcc2f, 0xe2a1c3a6b9ebcfb5),
+    (0x39acce43f9, 0xb5a82f4f0127663a, 0xc386bf6d8a0ec4ac),
+    (0x3a23da136a, 0x601f1d2c21140b75, 0xb9c2e325966170d6),
+    (0x18c478ee49, 0x453056ccd7ac8e2a, 0x15809447fe6348a5),
+    (0x110f3f93ad, 0x916c24bf6072c67d, 0x3ff0b832093f29ec),
+    (0x2869765c45, 0x8b597ae4d5b3c5b6, 0x479299f445e12a2c),
+    (0x10312a531b, 0x45e18d55cc27ac25, 0x9687ad4f129ed6b2),
+    (0x0659988a65, 0xc52fa7a186237b56, 0x899c33b95f05308c),
+    (0x17b1fae7e4, 0xab5a529d23181078, 0x1739a0495d5d89e4),
+    (0x353dc15495, 0x2ecda5f6929702ee, 0xcd209cb81e18329f),
+    (0x22bbfd43e3, 0xef118c2ff53e5798, 0x715af5f7f5910aa2),
+    (0x3511eab681, 0x3afcbd3404f270d9, 0xe2d7ce781e07dfd3),
+    (0x1e16bbb430, 0x3bb9e5c167661f66, 0x2c18a9045d5de10e),
+    (0x0b3aedd4e8, 0x787758420466d495, 0x23823f037757bef7),
+    (0x3620536830, 0x7a5e705364a40ee4, 0x7a4fbff6d61c351a),
+    (0x0b4983a141, 0x4efe278911623dba, 0x4b4e12271d83cf4f),
+    (0x0f34b1a054, 0xeaa8410a96214a91, 0x89a7ff5bd179c8f1),
+    (0x13c56cf15d, 0x544a56acab1d5d88, 0x560187dc709120ec),
+    (0x15badb7b34, 0x97b2f33ddba3ab18, 0xbdc11ab683940afe),
+    (0x0c3ec2ff68, 0xc8c3a832c59f2d0c, 0x81324969e9a7e1eb),
+    (0x1865a08be1, 0x44cea11294d82d1d, 0x407dd26d625af211),
+    (0x347310c3b7, 0x389405e3750ee013, 0x1382701b20860032),
+    (0x2aa8d2b90b, 0xc37452f4edc7d9b2, 0x9d45da512f9ebb29),
+    (0x02bd28ab52, 0x23995d04d178a56f, 0x29144a3230a2a595),
+    (0x131fde92c9, 0x14929dfd55e019b8, 0x35f258612e43e4e2),
+    (0x2ed152d4d8, 0x1dda347fc18aa8b8, 0xc9dc291931e4a853),
+    (0x32518dc6eb, 0x33e231037ed0fb31, 0x401597b6c4f292aa),
+    (0x36c32bee4d, 0xc50135ac5b9a6385, 0x6d0c9920b343ec08),
+    (0x121b6d278b, 0xf725b01770ecc8ea, 0x97d04bb535e601ff),
+    (0x209696cd45, 0x1212ffd9c4a5708a, 0xaa2b6b5a6d0aac12),
+    (0x1014edc0a8, 0x173441a0336fca22, 0xae3c681ddddd8610),
+    (0x2722fba2de, 0xa2021d6bcc8419d1, 0x2e411638e98782a0),
+    (0x33d5f39de3, 0x363288caf9dde1c5, 0xd7979ee461524000),
+    (0x34e6df88b0, 0xf1a09bab7dd9bef1, 0xdb30b50566800000),
+    (0x2932acfd26, 0xe600354c6bacebf9, 0x92b56be100000000),
+    (0x090e8719e4, 0xd6d7ed5eeb24fec2, 0xe7798a0000000000),
+    (0x3547e19320, 0x0c97e0835d9e0c90, 0xeae4000000000000),
+    (0x077647de0b, 0x2cc165d7ab3a327f, 0xe800000000000000),
+    (0x029a125020, 0x928f97c1c7427d10, 0x0000000000000000),
+    (0x079578f600, 0x413d180c624ea000, 0x0000000000000000),
+    (0x000f3086c5, 0x871507be4a400000, 0x0000000000000000),
+    (0x2dfd90f806, 0x1bb1911680000000, 0x0000000000000000),
+    (0x016c13671b, 0x9384c10000000000, 0x0000000000000000),
+    (0x066bac18bd, 0xda4a000000000000, 0x0000000000000000),
+    (0x2c341a2cc2, 0x6400000000000000, 0x0000000000000000),
+    (0x2d42958ae8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000002e69d2, 0x818df38bb85b6550),
+    (0x0000000000, 0x000ace73cbfdc0bf, 0xb7b636cc64d10016),
+    (0x000002841d, 0x689391085cc2c933, 0xd96e184be07e68e0),
+    (0x06d8e21fb6, 0x9cd94abdaf101564, 0xf98e0d5a4af7b3aa),
+    (0x2a849086b4, 0x226db0bdd5238971, 0xee216e919b8ddc76),
+    (0x29f0da20e2, 0x9fc7323bfadeda7e, 0x73b029e3bf70957a),
+    (0x34c3d9d87b, 0x38899a5e6d22f8e9, 0xb12dd13bc980a17a),
+    (0x1cb088f3f7, 0x33c3388530d28a96, 0xcc810997971256b6),
+    (0x398e66304e, 0xbcf8fd1e4133c033, 0x8693b83878e1ea75),
+    (0x125529313a, 0xb4f405e054aba882, 0x6531585211d25543),
+    (0x0dab336aee, 0xc91981e8deba22e2, 0xfb2af43d8f94a3ed),
+    (0x3798ba44f6, 0xf1eca1c22bf51851, 0x01d8347d82dd6567),
+    (0x397f11ec85, 0xc69324bab157db9d, 0x1a14c8d058fc149d),
+    (0x1f25a6ecab, 0x3a293e66a4ce715d, 0x384c069b7b67fa65),
+    (0x27ddef9a15, 0xbcf7b65b9380f1ad, 0xcc2fe2a1c3a6b9ec),
+    (0x050fb1eb26, 0x43f9b5a82f4f0127, 0x663ac386bf6d8a0f),
+    (0x08e8cda2b8, 0x136a601f1d2c2114, 0x0b75b9c2e3259662),
+    (0x2adbc67176, 0xee49453056ccd7ac, 0x8e2a15809447fe64),
+    (0x1bb0d4130b, 0x93ad916c24bf6072, 0xc67d3ff0b8320940),
+    (0x02b208f974, 0x5c458b597ae4d5b3, 0xc5b6479299f445e2),
+    (0x1b179f59c2, 0x531b45e18d55cc27, 0xac259687ad4f129f),
+    (0x2d3ea69d16, 0x8a65c52fa7a18623, 0x7b56899c33b95f06),
+    (0x053f867ede, 0xe7e4ab5a529d2318, 0x10781739a0495d5e),
+    (0x33e6391607, 0x54952ecda5f69297, 0x02eecd209cb81e19),
+    (0x01b4ed0c45, 0x43e3ef118c2ff53e, 0x5798715af5f7f592),
+    (0x10208706c8, 0xb6813afcbd3404f2, 0x70d9e2d7ce781e08),
+    (0x2ebb6bf257, 0xb4303bb9e5c16766, 0x1f662c18a9045d5e),
+    (0x146b8eb6f5, 0xd4e8787758420466, 0xd49523823f037758),
+    (0x393cb5a19b, 0x68307a5e705364a4, 0x0ee47a4fbff6d61d),
+    (0x242efa7993, 0xa1414efe27891162, 0x3dba4b4e12271d84),
+    (0x225f6d8bbb, 0xa054eaa8410a9621, 0x4a9189a7ff5bd17a),
+    (0x2baf6611ac, 0xf15d544a56acab1d, 0x5d88560187dc7092),
+    (0x2844322b05, 0x7b3497b2f33ddba3, 0xab18bdc11ab68395),
+    (0x0146b58fd0, 0xff68c8c3a832c59f, 0x2d0c81324969e9a8),
+    (0x30a93bb4d6, 0x8be144cea11294d8, 0x2d1d407dd26d625b),
+    (0x31f3f65abe, 0xc3b7389405e3750e, 0xe0131382701b2087),
+    (0x2c6a737cb0, 0xb90bc37452f4edc7, 0xd9b29d45da512f9f),
+    (0x2925807922, 0xab5223995d04d178, 0xa56f29144a3230a3),
+    (0x08127258cc, 0x92c914929dfd55e0, 0x19b835f258612e44),
+    (0x2fa1861086, 0xd4d81dda347fc18a, 0xa8b8c9dc291931e5),
+    (0x1f64943cd5, 0xc6eb33e231037ed0, 0xfb31401597b6c4f3),
+    (0x31c61a8d67, 0xee4dc50135ac5b9a, 0x63856d0c9920b344),
+    (0x1832c34939, 0x278bf725b01770ec, 0xc8ea97d04bb535e7),
+    (0x0d4eac255c, 0xcd451212ffd9c4a5, 0x708aaa2b6b5a6d0b),
+    (0x159b6ba0af, 0xc0a8173441a0336f, 0xca22ae3c681dddde),
+    (0x28ebab507b, 0xa2dea2021d6bcc84, 0x19d12e411638e988),
+    (0x1cc94ad7c7, 0x9de3363288caf9dd, 0xe1c5d7979ee46153),
+    (0x2e7a15dac1, 0x88b0f1a09bab7dd9, 0xbef1db30b5056680),
+    (0x2d0f85b7fc, 0xfd26e600354c6bac, 0xebf992b56be10000),
+    (0x3ae74fe029, 0x19e4d6d7ed5eeb24, 0xfec2e7798a000000),
+    (0x0991d1bf9b, 0x93200c97e0835d9e, 0x0c90eae400000000),
+    (0x2438f9a645, 0xde0b2cc165d7ab3a, 0x327fe80000000000),
+    (0x10446b473e, 0x5020928f97c1c742, 0x7d10000000000000),
+    (0x0e8224e098, 0xf600413d180c624e, 0xa000000000000000),
+    (0x239d2ebd4c, 0x86c5871507be4a40, 0x0000000000000000),
+    (0x11d154fb4c, 0xf8061bb191168000, 0x0000000000000000),
+    (0x11ebb357c3, 0x671b9384c1000000, 0x0000000000000000),
+    (0x2d7ee990f2, 0x18bdda4a00000000, 0x0000000000000000),
+    (0x385e15ab0a, 0x2cc2640000000000, 0x0000000000000000),
+    (0x025e77c14d, 0x8ae8000000000000, 0x0000000000000000),
+    (0x120de6372b, 0x1000000000000000, 0x0000000000000000),
+    (0x0a06b99aa0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000000002e, 0x69d2818df38bb85c),
+    (0x0000000000, 0x0000000ace73cbfd, 0xc0bfb7b636cc64d2),
+    (0x0000000002, 0x841d689391085cc2, 0xc933d96e184be07f),
+    (0x0095f83d0a, 0x1fb69cd94abdaf10, 0x1564f98e0d5a4af8),
+    (0x025b6e2706, 0x86b4226db0bdd523, 0x8971ee216e919b8e),
+    (0x0184fdb296, 0x20e29fc7323bfade, 0xda7e73b029e3bf71),
+    (0x22f45a7919, 0xd87b38899a5e6d22, 0xf8e9b12dd13bc981),
+    (0x06048504f8, 0xf3f733c3388530d2, 0x8a96cc8109979713),
+    (0x39f6c12c24, 0x304ebcf8fd1e4133, 0xc0338693b83878e2),
+    (0x086d03c309, 0x313ab4f405e054ab, 0xa8826531585211d3),
+    (0x0223e765dd, 0x6aeec91981e8deba, 0x22e2fb2af43d8f95),
+    (0x338d86125e, 0x44f6f1eca1c22bf5, 0x185101d8347d82de),
+    (0x15f2e4c8b9, 0xec85c69324bab157, 0xdb9d1a14c8d058fd),
+    (0x2b49edd1de, 0xecab3a293e66a4ce, 0x715d384c069b7b68),
+    (0x33e755bc27, 0x9a15bcf7b65b9380, 0xf1adcc2fe2a1c3a7),
+    (0x093874f335, 0xeb2643f9b5a82f4f, 0x0127663ac386bf6e),
+    (0x0c8dcbd23b, 0xa2b8136a601f1d2c, 0x21140b75b9c2e326),
+    (0x0de28f5d9a, 0x7176ee49453056cc, 0xd7ac8e2a15809448),
+    (0x23f58887fe, 0x130b93ad916c24bf, 0x6072c67d3ff0b833),
+    (0x3b28039b06, 0xf9745c458b597ae4, 0xd5b3c5b6479299f5),
+    (0x019fb56219, 0x59c2531b45e18d55, 0xcc27ac259687ad50),
+    (0x05e703c8e0, 0x9d168a65c52fa7a1, 0x86237b56899c33ba),
+    (0x344c03ed68, 0x7edee7e4ab5a529d, 0x231810781739a04a),
+    (0x18546c25a5, 0x160754952ecda5f6, 0x929702eecd209cb9),
+    (0x266fe53449, 0x0c4543e3ef118c2f, 0xf53e5798715af5f8),
+    (0x1101fefbff, 0x06c8b6813afcbd34, 0x04f270d9e2d7ce79),
+    (0x3b60c39227, 0xf257b4303bb9e5c1, 0x67661f662c18a905),
+    (0x094d018482, 0xb6f5d4e878775842, 0x0466d49523823f04),
+    (0x1e6f33dc51, 0xa19b68307a5e7053, 0x64a40ee47a4fbff7),
+    (0x13019a7204, 0x7993a1414efe2789, 0x11623dba4b4e1228),
+    (0x010ab9b0e7, 0x8bbba054eaa8410a, 0x96214a9189a7ff5c),
+    (0x35e9350114, 0x11acf15d544a56ac, 0xab1d5d88560187dd),
+    (0x04ac35539c, 0x2b057b3497b2f33d, 0xdba3ab18bdc11ab7),
+    (0x245c575ea3, 0x8fd0ff68c8c3a832, 0xc59f2d0c8132496a),
+    (0x26150abe11, 0xb4d68be144cea112, 0x94d82d1d407dd26e),
+    (0x041f6233fe, 0x5abec3b7389405e3, 0x750ee0131382701c),
+    (0x3b38b54177, 0x7cb0b90bc37452f4, 0xedc7d9b29d45da52),
+    (0x1bd1fc0558, 0x7922ab5223995d04, 0xd178a56f29144a33),
+    (0x149969abec, 0x58cc92c914929dfd, 0x55e019b835f25862),
+    (0x37075f0ef6, 0x1086d4d81dda347f, 0xc18aa8b8c9dc291a),
+    (0x394a972d34, 0x3cd5c6eb33e23103, 0x7ed0fb31401597b7),
+    (0x0c299b1572, 0x8d67ee4dc50135ac, 0x5b9a63856d0c9921),
+    (0x1aabda6805, 0x4939278bf725b017, 0x70ecc8ea97d04bb6),
+    (0x013b126446, 0x255ccd451212ffd9, 0xc4a5708aaa2b6b5b),
+    (0x105506369d, 0xa0afc0a8173441a0, 0x336fca22ae3c681e),
+    (0x24b3578beb, 0x507ba2dea2021d6b, 0xcc8419d12e411639),
+    (0x36c9d4966a, 0xd7c79de3363288ca, 0xf9dde1c5d7979ee5),
+    (0x18e0551dbb, 0xdac188b0f1a09bab, 0x7dd9bef1db30b506),
+    (0x2bbd006d89, 0xb7fcfd26e600354c, 0x6bacebf992b56be1),
+    (0x2010aca7b5, 0xe02919e4d6d7ed5e, 0xeb24fec2e7798a00),
+    (0x2a58a1d491, 0xbf9b93200c97e083, 0x5d9e0c90eae40000),
+    (0x21ef491faf, 0xa645de0b2cc165d7, 0xab3a327fe8000000),
+    (0x28e586b967, 0x473e5020928f97c1, 0xc7427d1000000000),
+    (0x180bddb62a, 0xe098f600413d180c, 0x624ea00000000000),
+    (0x09fbb031ee, 0xbd4c86c5871507be, 0x4a40000000000000),
+    (0x3795fae476, 0xfb4cf8061bb19116, 0x8000000000000000),
+    (0x1bb3dbcbc1, 0x57c3671b9384c100, 0x0000000000000000),
+    (0x2d0421638f, 0x90f218bdda4a0000, 0x0000000000000000),
+    (0x216d3645b5, 0xab0a2cc264000000, 0x0000000000000000),
+    (0x2a4c439565, 0xc14d8ae800000000, 0x0000000000000000),
+    (0x17b1137230, 0x372b100000000000, 0x0000000000000000),
+    (0x0b39de281f, 0x9aa0000000000000, 0x0000000000000000),
+    (0x075bbed442, 0x4000000000000000, 0x0000000000000000),
+    (0x0f6ccec680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000ace73, 0xcbfdc0bfb7b636cd),
+    (0x0000000000, 0x0002841d68939108, 0x5cc2c933d96e184c),
+    (0x00000095f8, 0x3d0a1fb69cd94abd, 0xaf101564f98e0d5b),
+    (0x39cc29ed90, 0x270686b4226db0bd, 0xd5238971ee216e92),
+    (0x219027b9c3, 0xb29620e29fc7323b, 0xfadeda7e73b029e4),
+    (0x2d907c72ce, 0x7919d87b38899a5e, 0x6d22f8e9b12dd13c),
+    (0x3012c0b9ff, 0x04f8f3f733c33885, 0x30d28a96cc810998),
+    (0x3b605797e9, 0x2c24304ebcf8fd1e, 0x4133c0338693b839),
+    (0x364a28e1bb, 0xc309313ab4f405e0, 0x54aba88265315853),
+    (0x2bb77a99c5, 0x65dd6aeec91981e8, 0xdeba22e2fb2af43e),
+    (0x2df5d51138, 0x125e44f6f1eca1c2, 0x2bf5185101d8347e),
+    (0x0d0e230518, 0xc8b9ec85c69324ba, 0xb157db9d1a14c8d1),
+    (0x05c53f27bd, 0xd1deecab3a293e66, 0xa4ce715d384c069c),
+    (0x2c3224683f, 0xbc279a15bcf7b65b, 0x9380f1adcc2fe2a2),
+    (0x0ed6e69acc, 0xf335eb2643f9b5a8, 0x2f4f0127663ac387),
+    (0x2fb7f97389, 0xd23ba2b8136a601f, 0x1d2c21140b75b9c3),
+    (0x2016c8f643, 0x5d9a7176ee494530, 0x56ccd7ac8e2a1581),
+    (0x0faf4e0eea, 0x87fe130b93ad916c, 0x24bf6072c67d3ff1),
+    (0x369b266c89, 0x9b06f9745c458b59, 0x7ae4d5b3c5b64793),
+    (0x2009ee7203, 0x621959c2531b45e1, 0x8d55cc27ac259688),
+    (0x00c9a78657, 0xc8e09d168a65c52f, 0xa7a186237b56899d),
+    (0x14705fdfd3, 0xed687edee7e4ab5a, 0x529d23181078173a),
+    (0x3157db1f6e, 0x25a5160754952ecd, 0xa5f6929702eecd21),
+    (0x19a5469b17, 0x34490c4543e3ef11, 0x8c2ff53e5798715b),
+    (0x0567149906, 0xfbff06c8b6813afc, 0xbd3404f270d9e2d8),
+    (0x01a04ce0d1, 0x9227f257b4303bb9, 0xe5c167661f662c19),
+    (0x30cb667e35, 0x8482b6f5d4e87877, 0x58420466d4952383),
+    (0x0c75e6115b, 0xdc51a19b68307a5e, 0x705364a40ee47a50),
+    (0x156351d532, 0x72047993a1414efe, 0x278911623dba4b4f),
+    (0x0bbec76cad, 0xb0e78bbba054eaa8, 0x410a96214a9189a8),
+    (0x2870cad615, 0x011411acf15d544a, 0x56acab1d5d885602),
+    (0x04e3f2d8ff, 0x539c2b057b3497b2, 0xf33ddba3ab18bdc2),
+    (0x3b72a6c1d5, 0x5ea38fd0ff68c8c3, 0xa832c59f2d0c8133),
+    (0x31add2fd36, 0xbe11b4d68be144ce, 0xa11294d82d1d407e),
+    (0x0cbee79d4e, 0x33fe5abec3b73894, 0x05e3750ee0131383),
+    (0x1235449af3, 0x41777cb0b90bc374, 0x52f4edc7d9b29d46),
+    (0x38a32bdd46, 0x05587922ab522399, 0x5d04d178a56f2915),
+    (0x104d91db5b, 0xabec58cc92c91492, 0x9dfd55e019b835f3),
+    (0x15580d3a4d, 0x0ef61086d4d81dda, 0x347fc18aa8b8c9dd),
+    (0x11f10a83a3, 0x2d343cd5c6eb33e2, 0x31037ed0fb314016),
+    (0x25fe14fd1d, 0x15728d67ee4dc501, 0x35ac5b9a63856d0d),
+    (0x06c587406c, 0x68054939278bf725, 0xb01770ecc8ea97d1),
+    (0x193d855522, 0x6446255ccd451212, 0xffd9c4a5708aaa2c),
+    (0x0801e79fee, 0x369da0afc0a81734, 0x41a0336fca22ae3d),
+    (0x37769f25f3, 0x8beb507ba2dea202, 0x1d6bcc8419d12e42),
+    (0x38b4819382, 0x966ad7c79de33632, 0x88caf9dde1c5d798),
+    (0x1e67a026b1, 0x1dbbdac188b0f1a0, 0x9bab7dd9bef1db31),
+    (0x293cf1efe2, 0x6d89b7fcfd26e600, 0x354c6bacebf992b6),
+    (0x34b8274cae, 0xa7b5e02919e4d6d7, 0xed5eeb24fec2e77a),
+    (0x28aa419e67, 0xd491bf9b93200c97, 0xe0835d9e0c90eae4),
+    (0x182cc56491, 0x1fafa645de0b2cc1, 0x65d7ab3a327fe800),
+    (0x21ca0d0dfc, 0xb967473e5020928f, 0x97c1c7427d100000),
+    (0x3ad789bfd7, 0xb62ae098f600413d, 0x180c624ea0000000),
+    (0x323969ad4c, 0x31eebd4c86c58715, 0x07be4a4000000000),
+    (0x11bd942ea4, 0xe476fb4cf8061bb1, 0x9116800000000000),
+    (0x26645af7c3, 0xcbc157c3671b9384, 0xc100000000000000),
+    (0x2d9658a04f, 0x638f90f218bdda4a, 0x0000000000000000),
+    (0x127bf29f54, 0x45b5ab0a2cc26400, 0x0000000000000000),
+    (0x139f054cb5, 0x9565c14d8ae80000, 0x0000000000000000),
+    (0x2a47399aa5, 0x7230372b10000000, 0x0000000000000000),
+    (0x26855a59d2, 0x281f9aa000000000, 0x0000000000000000),
+    (0x30ee513f4e, 0xd442400000000000, 0x0000000000000000),
+    (0x125a952816, 0xc680000000000000, 0x0000000000000000),
+    (0x054d84e1a1, 0x0000000000000000, 0x0000000000000000),
+    (0x257c590a00, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000000000a, 0xce73cbfdc0bfb7b7),
+    (0x0000000000, 0x00000002841d6893, 0x91085cc2c933d96f),
+    (0x0000000000, 0x95f83d0a1fb69cd9, 0x4abdaf101564f98f),
+    (0x0022eae3bb, 0xed90270686b4226d, 0xb0bdd5238971ee22),
+    (0x2bc1616b29, 0xb9c3b29620e29fc7, 0x323bfadeda7e73b1),
+    (0x09b70ac676, 0x72ce7919d87b3889, 0x9a5e6d22f8e9b12e),
+    (0x1b94141cb4, 0xb9ff04f8f3f733c3, 0x388530d28a96cc82),
+    (0x2a14244451, 0x97e92c24304ebcf8, 0xfd1e4133c0338694),
+    (0x12ff58814e, 0xe1bbc309313ab4f4, 0x05e054aba8826532),
+    (0x125db84f04, 0x99c565dd6aeec919, 0x81e8deba22e2fb2b),
+    (0x011238a08f, 0x1138125e44f6f1ec, 0xa1c22bf5185101d9),
+    (0x214f792b8d, 0x0518c8b9ec85c693, 0x24bab157db9d1a15),
+    (0x20d5710d31, 0x27bdd1deecab3a29, 0x3e66a4ce715d384d),
+    (0x0b71e170ea, 0x683fbc279a15bcf7, 0xb65b9380f1adcc30),
+    (0x3693c25cf6, 0x9accf335eb2643f9, 0xb5a82f4f0127663b),
+    (0x396ac8f17d, 0x7389d23ba2b8136a, 0x601f1d2c21140b76),
+    (0x1d357b4186, 0xf6435d9a7176ee49, 0x453056ccd7ac8e2b),
+    (0x1f6c5c296a, 0x0eea87fe130b93ad, 0x916c24bf6072c67e),
+    (0x18b190b632, 0x6c899b06f9745c45, 0x8b597ae4d5b3c5b7),
+    (0x0bbd80cb26, 0x7203621959c2531b, 0x45e18d55cc27ac26),
+    (0x08f385b39b, 0x8657c8e09d168a65, 0xc52fa7a186237b57),
+    (0x2435ffc41b, 0xdfd3ed687edee7e4, 0xab5a529d23181079),
+    (0x067d707bd1, 0x1f6e25a516075495, 0x2ecda5f6929702ef),
+    (0x30b0b04dbc, 0x9b1734490c4543e3, 0xef118c2ff53e5799),
+    (0x2be9c87762, 0x9906fbff06c8b681, 0x3afcbd3404f270da),
+    (0x16f4e27706, 0xe0d19227f257b430, 0x3bb9e5c167661f67),
+    (0x0199f8f826, 0x7e358482b6f5d4e8, 0x787758420466d496),
+    (0x08f65ca152, 0x115bdc51a19b6830, 0x7a5e705364a40ee5),
+    (0x131b9f5ebb, 0xd53272047993a141, 0x4efe278911623dbb),
+    (0x2bbbb516d9, 0x6cadb0e78bbba054, 0xeaa8410a96214a92),
+    (0x329f82f802, 0xd615011411acf15d, 0x544a56acab1d5d89),
+    (0x00a90ddcce, 0xd8ff539c2b057b34, 0x97b2f33ddba3ab19),
+    (0x3029149916, 0xc1d55ea38fd0ff68, 0xc8c3a832c59f2d0d),
+    (0x054c6ebc1c, 0xfd36be11b4d68be1, 0x44cea11294d82d1e),
+    (0x06bfe2d089, 0x9d4e33fe5abec3b7, 0x389405e3750ee014),
+    (0x200a763d26, 0x9af341777cb0b90b, 0xc37452f4edc7d9b3),
+    (0x08fd0dbcaf, 0xdd4605587922ab52, 0x23995d04d178a570),
+    (0x28f25503f3, 0xdb5babec58cc92c9, 0x14929dfd55e019b9),
+    (0x38c70080db, 0x3a4d0ef61086d4d8, 0x1dda347fc18aa8b9),
+    (0x330afdd0ce, 0x83a32d343cd5c6eb, 0x33e231037ed0fb32),
+    (0x301534bf72, 0xfd1d15728d67ee4d, 0xc50135ac5b9a6386),
+    (0x1ac5dcb329, 0x406c68054939278b, 0xf725b01770ecc8eb),
+    (0x099aca4a05, 0x55226446255ccd45, 0x1212ffd9c4a5708b),
+    (0x013dd858f9, 0x9fee369da0afc0a8, 0x173441a0336fca23),
+    (0x3a1ecb1415, 0x25f38beb507ba2de, 0xa2021d6bcc8419d2),
+    (0x04ec88a5b3, 0x9382966ad7c79de3, 0x363288caf9dde1c6),
+    (0x29cf93879e, 0x26b11dbbdac188b0, 0xf1a09bab7dd9bef2),
+    (0x24d28ae2ed, 0xefe26d89b7fcfd26, 0xe600354c6bacebfa),
+    (0x3766273cbd, 0x4caea7b5e02919e4, 0xd6d7ed5eeb24fec3),
+    (0x2c1321c039, 0x9e67d491bf9b9320, 0x0c97e0835d9e0c91),
+    (0x0d6a58af71, 0x64911fafa645de0b, 0x2cc165d7ab3a3280),
+    (0x1a66bd6af5, 0x0dfcb967473e5020, 0x928f97c1c7427d10),
+    (0x390e6505cb, 0xbfd7b62ae098f600, 0x413d180c624ea000),
+    (0x2f7068e41f, 0xad4c31eebd4c86c5, 0x871507be4a400000),
+    (0x076017ce16, 0x2ea4e476fb4cf806, 0x1bb1911680000000),
+    (0x052a2990a6, 0xf7c3cbc157c3671b, 0x9384c10000000000),
+    (0x26e00eeb3e, 0xa04f638f90f218bd, 0xda4a000000000000),
+    (0x0e94d02632, 0x9f5445b5ab0a2cc2, 0x6400000000000000),
+    (0x0bc954367b, 0x4cb59565c14d8ae8, 0x0000000000000000),
+    (0x1cb53b4931, 0x9aa57230372b1000, 0x0000000000000000),
+    (0x0b8ca24cde, 0x59d2281f9aa00000, 0x0000000000000000),
+    (0x33c524ea8b, 0x3f4ed44240000000, 0x0000000000000000),
+    (0x206bc9204b, 0x2816c68000000000, 0x0000000000000000),
+    (0x117faeab14, 0xe1a1000000000000, 0x0000000000000000),
+    (0x04dca04fe3, 0x0a00000000000000, 0x0000000000000000),
+    (0x34dc9529e4, 0x0000000000000000, 0x0000000000000000),
+    (0x3515dbe800, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000002841d, 0x689391085cc2c934),
+    (0x0000000000, 0x000095f83d0a1fb6, 0x9cd94abdaf101565),
+    (0x00000022ea, 0xe3bbed90270686b4, 0x226db0bdd5238972),
+    (0x36b082a67f, 0x6b29b9c3b29620e2, 0x9fc7323bfadeda7f),
+    (0x1daabd9f78, 0xc67672ce7919d87b, 0x38899a5e6d22f8ea),
+    (0x1c1ec3fc50, 0x1cb4b9ff04f8f3f7, 0x33c3388530d28a97),
+    (0x12a70e223c, 0x445197e92c24304e, 0xbcf8fd1e4133c034),
+    (0x0e082f7500, 0x814ee1bbc309313a, 0xb4f405e054aba883),
+    (0x001e1b5d82, 0x4f0499c565dd6aee, 0xc91981e8deba22e3),
+    (0x1e57006a8e, 0xa08f1138125e44f6, 0xf1eca1c22bf51852),
+    (0x21353e19b9, 0x2b8d0518c8b9ec85, 0xc69324bab157db9e),
+    (0x2b1cffcf5f, 0x0d3127bdd1deecab, 0x3a293e66a4ce715e),
+    (0x1621834423, 0x70ea683fbc279a15, 0xbcf7b65b9380f1ae),
+    (0x084073ea22, 0x5cf69accf335eb26, 0x43f9b5a82f4f0128),
+    (0x080033df60, 0xf17d7389d23ba2b8, 0x136a601f1d2c2115),
+    (0x169245ada5, 0x4186f6435d9a7176, 0xee49453056ccd7ad),
+    (0x267a05eba6, 0x296a0eea87fe130b, 0x93ad916c24bf6073),
+    (0x26b00377ea, 0xb6326c899b06f974, 0x5c458b597ae4d5b4),
+    (0x36a5e86d1c, 0xcb267203621959c2, 0x531b45e18d55cc28),
+    (0x06b43ac9b3, 0xb39b8657c8e09d16, 0x8a65c52fa7a18624),
+    (0x29d70caf3d, 0xc41bdfd3ed687ede, 0xe7e4ab5a529d2319),
+    (0x0e618b2218, 0x7bd11f6e25a51607, 0x54952ecda5f69298),
+    (0x05b356ffbe, 0x4dbc9b1734490c45, 0x43e3ef118c2ff53f),
+    (0x2c4efb648a, 0x77629906fbff06c8, 0xb6813afcbd3404f3),
+    (0x203d3cccc8, 0x7706e0d19227f257, 0xb4303bb9e5c16767),
+    (0x2eaca45dde, 0xf8267e358482b6f5, 0xd4e8787758420467),
+    (0x33ea02160c, 0xa152115bdc51a19b, 0x68307a5e705364a5),
+    (0x02f0d0eca9, 0x5ebbd53272047993, 0xa1414efe27891163),
+    (0x276f3debe1, 0x16d96cadb0e78bbb, 0xa054eaa8410a9622),
+    (0x34685d76de, 0xf802d615011411ac, 0xf15d544a56acab1e),
+    (0x33e9f9c8bd, 0xdcced8ff539c2b05, 0x7b3497b2f33ddba4),
+    (0x2c34b050ac, 0x9916c1d55ea38fd0, 0xff68c8c3a832c5a0),
+    (0x282fa48728, 0xbc1cfd36be11b4d6, 0x8be144cea11294d9),
+    (0x097bfbfc3a, 0xd0899d4e33fe5abe, 0xc3b7389405e3750f),
+    (0x0db19f9296, 0x3d269af341777cb0, 0xb90bc37452f4edc8),
+    (0x22faef3b2f, 0xbcafdd4605587922, 0xab5223995d04d179),
+    (0x0b1d59b4ad, 0x03f3db5babec58cc, 0x92c914929dfd55e1),
+    (0x284886195e, 0x80db3a4d0ef61086, 0xd4d81dda347fc18b),
+    (0x1600d69bfd, 0xd0ce83a32d343cd5, 0xc6eb33e231037ed1),
+    (0x3b18977b54, 0xbf72fd1d15728d67, 0xee4dc50135ac5b9b),
+    (0x13bb5d8a92, 0xb329406c68054939, 0x278bf725b01770ed),
+    (0x2227fe0a08, 0x4a0555226446255c, 0xcd451212ffd9c4a6),
+    (0x01ee124c3c, 0x58f99fee369da0af, 0xc0a8173441a03370),
+    (0x0e0cfeac3b, 0x141525f38beb507b, 0xa2dea2021d6bcc85),
+    (0x0dc1599250, 0xa5b39382966ad7c7, 0x9de3363288caf9de),
+    (0x12c6f3b3c5, 0x879e26b11dbbdac1, 0x88b0f1a09bab7dda),
+    (0x2dfdb0e510, 0xe2edefe26d89b7fc, 0xfd26e600354c6bad),
+    (0x03ee82ac7b, 0x3cbd4caea7b5e029, 0x19e4d6d7ed5eeb25),
+    (0x1cb1836879, 0xc0399e67d491bf9b, 0x93200c97e0835d9f),
+    (0x1c58eafbd8, 0xaf7164911fafa645, 0xde0b2cc165d7ab3b),
+    (0x327373a6d3, 0x6af50dfcb967473e, 0x5020928f97c1c743),
+    (0x31397ba409, 0x05cbbfd7b62ae098, 0xf600413d180c624f),
+    (0x0219ca905a, 0xe41fad4c31eebd4c, 0x86c5871507be4a40),
+    (0x1529883fff, 0xce162ea4e476fb4c, 0xf8061bb191168000),
+    (0x3b8f2aeeb5, 0x90a6f7c3cbc157c3, 0x671b9384c1000000),
+    (0x2a461ec3ae, 0xeb3ea04f638f90f2, 0x18bdda4a00000000),
+    (0x28b9faf83e, 0x26329f5445b5ab0a, 0x2cc2640000000000),
+    (0x0e7861af50, 0x367b4cb59565c14d, 0x8ae8000000000000),
+    (0x12ad0e7bd7, 0x49319aa57230372b, 0x1000000000000000),
+    (0x32200a563c, 0x4cde59d2281f9aa0, 0x0000000000000000),
+    (0x0e0a2d111c, 0xea8b3f4ed4424000, 0x0000000000000000),
+    (0x06bb8a006d, 0x204b2816c6800000, 0x0000000000000000),
+    (0x19686cdaee, 0xab14e1a100000000, 0x0000000000000000),
+    (0x3791bd0d8e, 0x4fe30a0000000000, 0x0000000000000000),
+    (0x212275aceb, 0x29e4000000000000, 0x0000000000000000),
+    (0x36c0d84e35, 0xe800000000000000, 0x0000000000000000),
+    (0x0c8d101910, 0x0000000000000000, 0x0000000000000000),
+    (0x03b9aca000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000095f83d0a, 0x1fb69cd94abdaf11),
+    (0x0000000000, 0x22eae3bbed902706, 0x86b4226db0bdd524),
+    (0x0008213f56, 0xa67f6b29b9c3b296, 0x20e29fc7323bfadf),
+    (0x142cc3e5b9, 0x9f78c67672ce7919, 0xd87b38899a5e6d23),
+    (0x2b37fd3c39, 0xfc501cb4b9ff04f8, 0xf3f733c3388530d3),
+    (0x0d8035ffe6, 0x223c445197e92c24, 0x304ebcf8fd1e4134),
+    (0x35950a1303, 0x7500814ee1bbc309, 0x313ab4f405e054ac),
+    (0x00ce0e3a6d, 0x5d824f0499c565dd, 0x6aeec91981e8debb),
+    (0x1976ad9462, 0x6a8ea08f1138125e, 0x44f6f1eca1c22bf6),
+    (0x16ea10a0e2, 0x19b92b8d0518c8b9, 0xec85c69324bab158),
+    (0x34a4a38eb9, 0xcf5f0d3127bdd1de, 0xecab3a293e66a4cf),
+    (0x2b432444d9, 0x442370ea683fbc27, 0x9a15bcf7b65b9381),
+    (0x329612981b, 0xea225cf69accf335, 0xeb2643f9b5a82f50),
+    (0x067fd6cad7, 0xdf60f17d7389d23b, 0xa2b8136a601f1d2d),
+    (0x3243020e3b, 0xada54186f6435d9a, 0x7176ee49453056cd),
+    (0x0de51aa287, 0xeba6296a0eea87fe, 0x130b93ad916c24c0),
+    (0x1fa57e4d71, 0x77eab6326c899b06, 0xf9745c458b597ae5),
+    (0x1a6b3ec3ce, 0x6d1ccb2672036219, 0x59c2531b45e18d56),
+    (0x300ff6283c, 0xc9b3b39b8657c8e0, 0x9d168a65c52fa7a2),
+    (0x0e273db7a6, 0xaf3dc41bdfd3ed68, 0x7edee7e4ab5a529e),
+    (0x26cf2c2da1, 0x22187bd11f6e25a5, 0x160754952ecda5f7),
+    (0x2584494c2a, 0xffbe4dbc9b173449, 0x0c4543e3ef118c30),
+    (0x0a02f0a231, 0x648a77629906fbff, 0x06c8b6813afcbd35),
+    (0x0b80095e24, 0xccc87706e0d19227, 0xf257b4303bb9e5c2),
+    (0x0891726d9c, 0x5ddef8267e358482, 0xb6f5d4e878775843),
+    (0x24682e3d64, 0x160ca152115bdc51, 0xa19b68307a5e7054),
+    (0x174d99262c, 0xeca95ebbd5327204, 0x7993a1414efe278a),
+    (0x0a75b4d975, 0xebe116d96cadb0e7, 0x8bbba054eaa8410b),
+    (0x1b74a9d1cb, 0x76def802d6150114, 0x11acf15d544a56ad),
+    (0x2f5f6b7115, 0xc8bddcced8ff539c, 0x2b057b3497b2f33e),
+    (0x05126fb480, 0x50ac9916c1d55ea3, 0x8fd0ff68c8c3a833),
+    (0x1de02d8ec4, 0x8728bc1cfd36be11, 0xb4d68be144cea113),
+    (0x2dc1fac481, 0xfc3ad0899d4e33fe, 0x5abec3b7389405e4),
+    (0x1e43b9da77, 0x92963d269af34177, 0x7cb0b90bc37452f5),
+    (0x1bd7152823, 0x3b2fbcafdd460558, 0x7922ab5223995d05),
+    (0x0833f167e7, 0xb4ad03f3db5babec, 0x58cc92c914929dfe),
+    (0x35f2bd648e, 0x195e80db3a4d0ef6, 0x1086d4d81dda3480),
+    (0x2115c42a90, 0x9bfdd0ce83a32d34, 0x3cd5c6eb33e23104),
+    (0x21ab6370e5, 0x7b54bf72fd1d1572, 0x8d67ee4dc50135ad),
+    (0x356e2dcc6b, 0x8a92b329406c6805, 0x4939278bf725b018),
+    (0x1909f606e2, 0x0a084a0555226446, 0x255ccd451212ffda),
+    (0x34a0fc4df6, 0x4c3c58f99fee369d, 0xa0afc0a8173441a1),
+    (0x39587e1cf6, 0xac3b141525f38beb, 0x507ba2dea2021d6c),
+    (0x396ed7dd11, 0x9250a5b39382966a, 0xd7c79de3363288cb),
+    (0x0417587827, 0xb3c5879e26b11dbb, 0xdac188b0f1a09bac),
+    (0x093e6ffeee, 0xe510e2edefe26d89, 0xb7fcfd26e600354d),
+    (0x379f3d3134, 0xac7b3cbd4caea7b5, 0xe02919e4d6d7ed5f),
+    (0x0c4399b93b, 0x6879c0399e67d491, 0xbf9b93200c97e084),
+    (0x0dd4ffc8fa, 0xfbd8af7164911faf, 0xa645de0b2cc165d8),
+    (0x3a6fcc7b81, 0xa6d36af50dfcb967, 0x473e5020928f97c2),
+    (0x1e2fd76277, 0xa40905cbbfd7b62a, 0xe098f600413d180d),
+    (0x1bdb252932, 0x905ae41fad4c31ee, 0xbd4c86c5871507bf),
+    (0x0bc5d7af28, 0x3fffce162ea4e476, 0xfb4cf8061bb19117),
+    (0x095f1636e0, 0xeeb590a6f7c3cbc1, 0x57c3671b9384c100),
+    (0x345f04edea, 0xc3aeeb3ea04f638f, 0x90f218bdda4a0000),
+    (0x36a90c45d8, 0xf83e26329f5445b5, 0xab0a2cc264000000),
+    (0x328466dc11, 0xaf50367b4cb59565, 0xc14d8ae800000000),
+    (0x041e18e724, 0x7bd749319aa57230, 0x372b100000000000),
+    (0x087e99e718, 0x563c4cde59d2281f, 0x9aa0000000000000),
+    (0x05aa96fa09, 0x111cea8b3f4ed442, 0x4000000000000000),
+    (0x021c6d1cf4, 0x006d204b2816c680, 0x0000000000000000),
+    (0x38cfa1f06c, 0xdaeeab14e1a10000, 0x0000000000000000),
+    (0x19584699c1, 0x0d8e4fe30a000000, 0x0000000000000000),
+    (0x2cf2da48b7, 0xaceb29e400000000, 0x0000000000000000),
+    (0x2ac3e92690, 0x4e35e80000000000, 0x0000000000000000),
+    (0x219947569c, 0x1910000000000000, 0x0000000000000000),
+    (0x245828ef66, 0xa000000000000000, 0x0000000000000000),
+    (0x17e4ed3a40, 0x0000000000000000, 0x0000000000000000),
+    (0x0ee6b28000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000095f8, 0x3d0a1fb69cd94abe),
+    (0x0000000000, 0x000022eae3bbed90, 0x270686b4226db0be),
+    (0x0000000821, 0x3f56a67f6b29b9c3, 0xb29620e29fc7323c),
+    (0x07bdb34e79, 0xe5b99f78c67672ce, 0x7919d87b38899a5f),
+    (0x1c61a628af, 0x3c39fc501cb4b9ff, 0x04f8f3f733c33886),
+    (0x28ccd5dd8d, 0xffe6223c445197e9, 0x2c24304ebcf8fd1f),
+    (0x210fd60640, 0x13037500814ee1bb, 0xc309313ab4f405e1),
+    (0x0eeb1fcb0c, 0x3a6d5d824f0499c5, 0x65dd6aeec91981e9),
+    (0x02d8dc0071, 0x94626a8ea08f1138, 0x125e44f6f1eca1c3),
+    (0x1a71df90d8, 0xa0e219b92b8d0518, 0xc8b9ec85c69324bb),
+    (0x32700fd2e3, 0x8eb9cf5f0d3127bd, 0xd1deecab3a293e67),
+    (0x34fb7c3d30, 0x44d9442370ea683f, 0xbc279a15bcf7b65c),
+    (0x0b3d0d93ba, 0x981bea225cf69acc, 0xf335eb2643f9b5a9),
+    (0x2b71e12bc6, 0xcad7df60f17d7389, 0xd23ba2b8136a6020),
+    (0x2e48f2a266, 0x0e3bada54186f643, 0x5d9a7176ee494531),
+    (0x17c2fcd826, 0xa287eba6296a0eea, 0x87fe130b93ad916d),
+    (0x08fed19552, 0x4d7177eab6326c89, 0x9b06f9745c458b5a),
+    (0x13299cae00, 0xc3ce6d1ccb267203, 0x621959c2531b45e2),
+    (0x002d96f7d4, 0x283cc9b3b39b8657, 0xc8e09d168a65c530),
+    (0x31658d9ecd, 0xb7a6af3dc41bdfd3, 0xed687edee7e4ab5b),
+    (0x2fe5b63792, 0x2da122187bd11f6e, 0x25a5160754952ece),
+    (0x2208e6edcb, 0x4c2affbe4dbc9b17, 0x34490c4543e3ef12),
+    (0x2f557a24e8, 0xa231648a77629906, 0xfbff06c8b6813afd),
+    (0x362a0a83dd, 0x5e24ccc87706e0d1, 0x9227f257b4303bba),
+    (0x338a8bc99e, 0x6d9c5ddef8267e35, 0x8482b6f5d4e87878),
+    (0x24e30dfc30, 0x3d64160ca152115b, 0xdc51a19b68307a5f),
+    (0x0b3b510fbb, 0x262ceca95ebbd532, 0x72047993a1414eff),
+    (0x2b92f4fdb0, 0xd975ebe116d96cad, 0xb0e78bbba054eaa9),
+    (0x292d0c89e3, 0xd1cb76def802d615, 0x011411acf15d544b),
+    (0x350b19dc55, 0x7115c8bddcced8ff, 0x539c2b057b3497b3),
+    (0x13e4b97799, 0xb48050ac9916c1d5, 0x5ea38fd0ff68c8c4),
+    (0x23c9897035, 0x8ec48728bc1cfd36, 0xbe11b4d68be144cf),
+    (0x0c78497006, 0xc481fc3ad0899d4e, 0x33fe5abec3b73895),
+    (0x0193618261, 0xda7792963d269af3, 0x41777cb0b90bc375),
+    (0x16c8843119, 0x28233b2fbcafdd46, 0x05587922ab52239a),
+    (0x05db761d81, 0x67e7b4ad03f3db5b, 0xabec58cc92c91493),
+    (0x1e21310403, 0x648e195e80db3a4d, 0x0ef61086d4d81ddb),
+    (0x00ca39eaac, 0x2a909bfdd0ce83a3, 0x2d343cd5c6eb33e3),
+    (0x2815e8c887, 0x70e57b54bf72fd1d, 0x15728d67ee4dc502),
+    (0x1f88e9ac8d, 0xcc6b8a92b329406c, 0x68054939278bf726),
+    (0x2103d9a4ee, 0x06e20a084a055522, 0x6446255ccd451213),
+    (0x376b820db8, 0x4df64c3c58f99fee, 0x369da0afc0a81735),
+    (0x2ae9681740, 0x1cf6ac3b141525f3, 0x8beb507ba2dea203),
+    (0x0eed70dcef, 0xdd119250a5b39382, 0x966ad7c79de33633),
+    (0x37d8fb4dba, 0x7827b3c5879e26b1, 0x1dbbdac188b0f1a1),
+    (0x2b6a70911f, 0xfeeee510e2edefe2, 0x6d89b7fcfd26e601),
+    (0x077319a9a9, 0x3134ac7b3cbd4cae, 0xa7b5e02919e4d6d8),
+    (0x2764a43e43, 0xb93b6879c0399e67, 0xd491bf9b93200c98),
+    (0x0fc4a38ef9, 0xc8fafbd8af716491, 0x1fafa645de0b2cc2),
+    (0x3a2859d79c, 0x7b81a6d36af50dfc, 0xb967473e50209290),
+    (0x246f14a6e5, 0x6277a40905cbbfd7, 0xb62ae098f600413e),
+    (0x356863ce79, 0x2932905ae41fad4c, 0x31eebd4c86c58716),
+    (0x1c35c10a31, 0xaf283fffce162ea4, 0xe476fb4cf8061bb2),
+    (0x0b9168d92c, 0x36e0eeb590a6f7c3, 0xcbc157c3671b9385),
+    (0x0a4b61bda0, 0xedeac3aeeb3ea04f, 0x638f90f218bdda4a),
+    (0x257823360e, 0x45d8f83e26329f54, 0x45b5ab0a2cc26400),
+    (0x0352ba48d8, 0xdc11af50367b4cb5, 0x9565c14d8ae80000),
+    (0x327dd793b0, 0xe7247bd749319aa5, 0x7230372b10000000),
+    (0x29303c0adf, 0xe718563c4cde59d2, 0x281f9aa000000000),
+    (0x3421a44cdc, 0xfa09111cea8b3f4e, 0xd442400000000000),
+    (0x33733cddb1, 0x1cf4006d204b2816, 0xc680000000000000),
+    (0x293cc367b9, 0xf06cdaeeab14e1a1, 0x0000000000000000),
+    (0x2b4ad66fa6, 0x99c10d8e4fe30a00, 0x0000000000000000),
+    (0x26ca2b7194, 0x48b7aceb29e40000, 0x0000000000000000),
+    (0x22866b14b9, 0x26904e35e8000000, 0x0000000000000000),
+    (0x2b1bd68d43, 0x569c191000000000, 0x0000000000000000),
+    (0x0fadad3604, 0xef66a00000000000, 0x0000000000000000),
+    (0x0126288f83, 0x3a40000000000000, 0x0000000000000000),
+    (0x1e8dc55676, 0x8000000000000000, 0x0000000000000000),
+    (0x1b97268100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000022eae3bb, 0xed90270686b4226e),
+    (0x0000000000, 0x08213f56a67f6b29, 0xb9c3b29620e29fc8),
+    (0x0001e49403, 0x4e79e5b99f78c676, 0x72ce7919d87b388a),
+    (0x00c515e932, 0x28af3c39fc501cb4, 0xb9ff04f8f3f733c4),
+    (0x0badb47065, 0xdd8dffe6223c4451, 0x97e92c24304ebcf9),
+    (0x17b7a76238, 0x064013037500814e, 0xe1bbc309313ab4f5),
+    (0x0d0b50bbdb, 0xcb0c3a6d5d824f04, 0x99c565dd6aeec91a),
+    (0x332cb1650a, 0x007194626a8ea08f, 0x1138125e44f6f1ed),
+    (0x02542655df, 0x90d8a0e219b92b8d, 0x0518c8b9ec85c694),
+    (0x340d8f77af, 0xd2e38eb9cf5f0d31, 0x27bdd1deecab3a2a),
+    (0x28efea0b30, 0x3d3044d9442370ea, 0x683fbc279a15bcf8),
+    (0x0b3b44ff2f, 0x93ba981bea225cf6, 0x9accf335eb2643fa),
+    (0x0b13d069df, 0x2bc6cad7df60f17d, 0x7389d23ba2b8136b),
+    (0x33f6073ee0, 0xa2660e3bada54186, 0xf6435d9a7176ee4a),
+    (0x344d4076d0, 0xd826a287eba6296a, 0x0eea87fe130b93ae),
+    (0x30a017b941, 0x95524d7177eab632, 0x6c899b06f9745c46),
+    (0x0f45118b2e, 0xae00c3ce6d1ccb26, 0x7203621959c2531c),
+    (0x0ade53aee2, 0xf7d4283cc9b3b39b, 0x8657c8e09d168a66),
+    (0x34d85a1273, 0x9ecdb7a6af3dc41b, 0xdfd3ed687edee7e5),
+    (0x1aeb822c62, 0x37922da122187bd1, 0x1f6e25a516075496),
+    (0x16de319e4c, 0xedcb4c2affbe4dbc, 0x9b1734490c4543e4),
+    (0x11e9519a7c, 0x24e8a231648a7762, 0x9906fbff06c8b682),
+    (0x1ce791c672, 0x83dd5e24ccc87706, 0xe0d19227f257b431),
+    (0x1aa9a1b3e9, 0xc99e6d9c5ddef826, 0x7e358482b6f5d4e9),
+    (0x366ed345a7, 0xfc303d64160ca152, 0x115bdc51a19b6831),
+    (0x271cb16029, 0x0fbb262ceca95ebb, 0xd53272047993a142),
+    (0x098f73feca, 0xfdb0d975ebe116d9, 0x6cadb0e78bbba055),
+    (0x2f43348aae, 0x89e3d1cb76def802, 0xd615011411acf15e),
+    (0x28a3502d33, 0xdc557115c8bddcce, 0xd8ff539c2b057b35),
+    (0x0c13232851, 0x7799b48050ac9916, 0xc1d55ea38fd0ff69),
+    (0x12f7d2a76f, 0x70358ec48728bc1c, 0xfd36be11b4d68be2),
+    (0x19f23dc6a9, 0x7006c481fc3ad089, 0x9d4e33fe5abec3b8),
+    (0x277344a5c1, 0x8261da7792963d26, 0x9af341777cb0b90c),
+    (0x2d0e0dad18, 0x311928233b2fbcaf, 0xdd4605587922ab53),
+    (0x05a1f16c20, 0x1d8167e7b4ad03f3, 0xdb5babec58cc92ca),
+    (0x077a37ea13, 0x0403648e195e80db, 0x3a4d0ef61086d4d9),
+    (0x046d6c3361, 0xeaac2a909bfdd0ce, 0x83a32d343cd5c6ec),
+    (0x16cc4a1c8c, 0xc88770e57b54bf72, 0xfd1d15728d67ee4e),
+    (0x20c756eeb9, 0xac8dcc6b8a92b329, 0x406c68054939278c),
+    (0x2b3b08fd91, 0xa4ee06e20a084a05, 0x55226446255ccd46),
+    (0x21e912feea, 0x0db84df64c3c58f9, 0x9fee369da0afc0a9),
+    (0x367eae69aa, 0x17401cf6ac3b1415, 0x25f38beb507ba2df),
+    (0x279a33f996, 0xdcefdd119250a5b3, 0x9382966ad7c79de4),
+    (0x2320233a93, 0x4dba7827b3c5879e, 0x26b11dbbdac188b1),
+    (0x224bfaf732, 0x911ffeeee510e2ed, 0xefe26d89b7fcfd27),
+    (0x0bc6059383, 0xa9a93134ac7b3cbd, 0x4caea7b5e02919e5),
+    (0x1ea7b5f1fe, 0x3e43b93b6879c039, 0x9e67d491bf9b9321),
+    (0x3b3213ad8f, 0x8ef9c8fafbd8af71, 0x64911fafa645de0c),
+    (0x216cc0da65, 0xd79c7b81a6d36af5, 0x0dfcb967473e5021),
+    (0x17b64520ba, 0xa6e56277a40905cb, 0xbfd7b62ae098f601),
+    (0x2b75528b5f, 0xce792932905ae41f, 0xad4c31eebd4c86c6),
+    (0x164e83ba4d, 0x0a31af283fffce16, 0x2ea4e476fb4cf807),
+    (0x11efee5f4c, 0xd92c36e0eeb590a6, 0xf7c3cbc157c3671c),
+    (0x11e484789b, 0xbda0edeac3aeeb3e, 0xa04f638f90f218be),
+    (0x2442df0d45, 0x360e45d8f83e2632, 0x9f5445b5ab0a2cc3),
+    (0x101d4e6b56, 0x48d8dc11af50367b, 0x4cb59565c14d8ae8),
+    (0x1416f5e2a7, 0x93b0e7247bd74931, 0x9aa57230372b1000),
+    (0x27045cd83a, 0x0adfe718563c4cde, 0x59d2281f9aa00000),
+    (0x0d8399f188, 0x4cdcfa09111cea8b, 0x3f4ed44240000000),
+    (0x1fbc20b734, 0xddb11cf4006d204b, 0x2816c68000000000),
+    (0x0c4f0ee525, 0x67b9f06cdaeeab14, 0xe1a1000000000000),
+    (0x08b585c41c, 0x6fa699c10d8e4fe3, 0x0a00000000000000),
+    (0x069eecffc1, 0x719448b7aceb29e4, 0x0000000000000000),
+    (0x2d0a242395, 0x14b926904e35e800, 0x0000000000000000),
+    (0x22b5eac59e, 0x8d43569c19100000, 0x0000000000000000),
+    (0x24ea6c9aef, 0x3604ef66a0000000, 0x0000000000000000),
+    (0x37b21662c4, 0x8f833a4000000000, 0x0000000000000000),
+    (0x2dc3ecac9b, 0x5676800000000000, 0x0000000000000000),
+    (0x242ad9e502, 0x8100000000000000, 0x0000000000000000),
+    (0x00953e93ca, 0x0000000000000000, 0x0000000000000000),
+    (0x2f08236400, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000022ea, 0xe3bbed90270686b5),
+    (0x0000000000, 0x000008213f56a67f, 0x6b29b9c3b29620e3),
+    (0x00000001e4, 0x94034e79e5b99f78, 0xc67672ce7919d87c),
+    (0x35385229dd, 0xe93228af3c39fc50, 0x1cb4b9ff04f8f3f8),
+    (0x33aaebf18e, 0x7065dd8dffe6223c, 0x445197e92c24304f),
+    (0x212a077c07, 0x6238064013037500, 0x814ee1bbc309313b),
+    (0x01b819d2a4, 0xbbdbcb0c3a6d5d82, 0x4f0499c565dd6aef),
+    (0x265ae6a63f, 0x650a007194626a8e, 0xa08f1138125e44f7),
+    (0x0ec29e1bd8, 0x55df90d8a0e219b9, 0x2b8d0518c8b9ec86),
+    (0x325e98e2a1, 0x77afd2e38eb9cf5f, 0x0d3127bdd1deecac),
+    (0x259836edd0, 0x0b303d3044d94423, 0x70ea683fbc279a16),
+    (0x30705f01f2, 0xff2f93ba981bea22, 0x5cf69accf335eb27),
+    (0x3893bd3706, 0x69df2bc6cad7df60, 0xf17d7389d23ba2b9),
+    (0x017e472ee1, 0x3ee0a2660e3bada5, 0x4186f6435d9a7177),
+    (0x3471af542c, 0x76d0d826a287eba6, 0x296a0eea87fe130c),
+    (0x0a5a44b133, 0xb94195524d7177ea, 0xb6326c899b06f975),
+    (0x0c0af85f0b, 0x8b2eae00c3ce6d1c, 0xcb267203621959c3),
+    (0x02b00e9801, 0xaee2f7d4283cc9b3, 0xb39b8657c8e09d17),
+    (0x006452d7a6, 0x12739ecdb7a6af3d, 0xc41bdfd3ed687edf),
+    (0x26aaaac9b6, 0x2c6237922da12218, 0x7bd11f6e25a51608),
+    (0x2a6a6114e9, 0x9e4cedcb4c2affbe, 0x4dbc9b1734490c46),
+    (0x3664bd4bfd, 0x9a7c24e8a231648a, 0x77629906fbff06c9),
+    (0x3b0bf1a715, 0xc67283dd5e24ccc8, 0x7706e0d19227f258),
+    (0x0511e6f3dd, 0xb3e9c99e6d9c5dde, 0xf8267e358482b6f6),
+    (0x339e840c11, 0x45a7fc303d64160c, 0xa152115bdc51a19c),
+    (0x04057f3f23, 0x60290fbb262ceca9, 0x5ebbd53272047994),
+    (0x083c8d3933, 0xfecafdb0d975ebe1, 0x16d96cadb0e78bbc),
+    (0x0c1b2915a0, 0x8aae89e3d1cb76de, 0xf802d615011411ad),
+    (0x2561085434, 0x2d33dc557115c8bd, 0xdcced8ff539c2b06),
+    (0x0c25f750a5, 0x28517799b48050ac, 0x9916c1d55ea38fd1),
+    (0x2674275962, 0xa76f70358ec48728, 0xbc1cfd36be11b4d7),
+    (0x16f83d4003, 0xc6a97006c481fc3a, 0xd0899d4e33fe5abf),
+    (0x00e1118980, 0xa5c18261da779296, 0x3d269af341777cb1),
+    (0x1df3fcd23f, 0xad18311928233b2f, 0xbcafdd4605587923),
+    (0x0ed364f273, 0x6c201d8167e7b4ad, 0x03f3db5babec58cd),
+    (0x1adfb5856f, 0xea130403648e195e, 0x80db3a4d0ef61087),
+    (0x1a0e9d8010, 0x3361eaac2a909bfd, 0xd0ce83a32d343cd6),
+    (0x03c5a34288, 0x1c8cc88770e57b54, 0xbf72fd1d15728d68),
+    (0x1fb0e1056e, 0xeeb9ac8dcc6b8a92, 0xb329406c6805493a),
+    (0x19d417eed4, 0xfd91a4ee06e20a08, 0x4a0555226446255d),
+    (0x3197392ab4, 0xfeea0db84df64c3c, 0x58f99fee369da0b0),
+    (0x2a24301b1a, 0x69aa17401cf6ac3b, 0x141525f38beb507c),
+    (0x0626529b0d, 0xf996dcefdd119250, 0xa5b39382966ad7c8),
+    (0x0340f8f09d, 0x3a934dba7827b3c5, 0x879e26b11dbbdac2),
+    (0x249b913fbe, 0xf732911ffeeee510, 0xe2edefe26d89b7fd),
+    (0x2c76700aeb, 0x9383a9a93134ac7b, 0x3cbd4caea7b5e02a),
+    (0x36d96ff7b3, 0xf1fe3e43b93b6879, 0xc0399e67d491bf9c),
+    (0x29e593283d, 0xad8f8ef9c8fafbd8, 0xaf7164911fafa646),
+    (0x0e5c4b2942, 0xda65d79c7b81a6d3, 0x6af50dfcb967473f),
+    (0x0f90c19a49, 0x20baa6e56277a409, 0x05cbbfd7b62ae099),
+    (0x1106c26892, 0x8b5fce792932905a, 0xe41fad4c31eebd4d),
+    (0x221eba8e31, 0xba4d0a31af283fff, 0xce162ea4e476fb4d),
+    (0x0b940110b2, 0x5f4cd92c36e0eeb5, 0x90a6f7c3cbc157c4),
+    (0x2987d0c97a, 0x789bbda0edeac3ae, 0xeb3ea04f638f90f3),
+    (0x1c83d9158f, 0x0d45360e45d8f83e, 0x26329f5445b5ab0b),
+    (0x214e8dcf90, 0x6b5648d8dc11af50, 0x367b4cb59565c14e),
+    (0x21a00f6963, 0xe2a793b0e7247bd7, 0x49319aa57230372c),
+    (0x1741a1c8b0, 0xd83a0adfe718563c, 0x4cde59d2281f9aa0),
+    (0x292cc2fe09, 0xf1884cdcfa09111c, 0xea8b3f4ed4424000),
+    (0x0250ad8e4a, 0xb734ddb11cf4006d, 0x204b2816c6800000),
+    (0x1165665974, 0xe52567b9f06cdaee, 0xab14e1a100000000),
+    (0x1b377db437, 0xc41c6fa699c10d8e, 0x4fe30a0000000000),
+    (0x0cfbea8794, 0xffc1719448b7aceb, 0x29e4000000000000),
+    (0x22b109015a, 0x239514b926904e35, 0xe800000000000000),
+    (0x14fcb3e388, 0xc59e8d43569c1910, 0x0000000000000000),
+    (0x1fd83e57de, 0x9aef3604ef66a000, 0x0000000000000000),
+    (0x33d44dfb9a, 0x62c48f833a400000, 0x0000000000000000),
+    (0x23f21c8940, 0xac9b567680000000, 0x0000000000000000),
+    (0x0f0ee2aa91, 0xe502810000000000, 0x0000000000000000),
+    (0x21f7fe75f0, 0x93ca000000000000, 0x0000000000000000),
+    (0x3803864a21, 0x6400000000000000, 0x0000000000000000),
+    (0x07c63c80e8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000008213f56, 0xa67f6b29b9c3b297),
+    (0x0000000000, 0x01e494034e79e5b9, 0x9f78c67672ce791a),
+    (0x000070d31c, 0x29dde93228af3c39, 0xfc501cb4b9ff04f9),
+    (0x068ead8d45, 0xf18e7065dd8dffe6, 0x223c445197e92c25),
+    (0x1048f65431, 0x7c07623806401303, 0x7500814ee1bbc30a),
+    (0x0b85815bf1, 0xd2a4bbdbcb0c3a6d, 0x5d824f0499c565de),
+    (0x384dc37e9a, 0xa63f650a00719462, 0x6a8ea08f1138125f),
+    (0x2401d2a59a, 0x1bd855df90d8a0e2, 0x19b92b8d0518c8ba),
+    (0x23e19935e6, 0xe2a177afd2e38eb9, 0xcf5f0d3127bdd1df),
+    (0x35c1d5ba8a, 0xedd00b303d3044d9, 0x442370ea683fbc28),
+    (0x2058cfa161, 0x01f2ff2f93ba981b, 0xea225cf69accf336),
+    (0x16961ab887, 0x370669df2bc6cad7, 0xdf60f17d7389d23c),
+    (0x1f7b7045ad, 0x2ee13ee0a2660e3b, 0xada54186f6435d9b),
+    (0x285284c3fb, 0x542c76d0d826a287, 0xeba6296a0eea87ff),
+    (0x3a845d328c, 0xb133b94195524d71, 0x77eab6326c899b07),
+    (0x20c1e888a2, 0x5f0b8b2eae00c3ce, 0x6d1ccb267203621a),
+    (0x25ce14f504, 0x9801aee2f7d4283c, 0xc9b3b39b8657c8e1),
+    (0x0111cf7442, 0xd7a612739ecdb7a6, 0xaf3dc41bdfd3ed69),
+    (0x0f901dbe50, 0xc9b62c6237922da1, 0x22187bd11f6e25a6),
+    (0x12cf5612fb, 0x14e99e4cedcb4c2a, 0xffbe4dbc9b17344a),
+    (0x3a75a28a85, 0x4bfd9a7c24e8a231, 0x648a77629906fc00),
+    (0x1f091c571f, 0xa715c67283dd5e24, 0xccc87706e0d19228),
+    (0x075ea581ac, 0xf3ddb3e9c99e6d9c, 0x5ddef8267e358483),
+    (0x2844c74442, 0x0c1145a7fc303d64, 0x160ca152115bdc52),
+    (0x0f60b75af7, 0x3f2360290fbb262c, 0xeca95ebbd5327205),
+    (0x39910c3a43, 0x3933fecafdb0d975, 0xebe116d96cadb0e8),
+    (0x0fa6d47023, 0x15a08aae89e3d1cb, 0x76def802d6150115),
+    (0x082b32b19a, 0x54342d33dc557115, 0xc8bddcced8ff539d),
+    (0x23eeb8743f, 0x50a528517799b480, 0x50ac9916c1d55ea4),
+    (0x0ebdde8947, 0x5962a76f70358ec4, 0x8728bc1cfd36be12),
+    (0x109cbdce77, 0x4003c6a97006c481, 0xfc3ad0899d4e33ff),
+    (0x1bc3db7991, 0x8980a5c18261da77, 0x92963d269af34178),
+    (0x21e2b03416, 0xd23fad1831192823, 0x3b2fbcafdd460559),
+    (0x0550412918, 0xf2736c201d8167e7, 0xb4ad03f3db5babed),
+    (0x05cef622a9, 0x856fea130403648e, 0x195e80db3a4d0ef7),
+    (0x277840d38f, 0x80103361eaac2a90, 0x9bfdd0ce83a32d35),
+    (0x21694800a3, 0x42881c8cc88770e5, 0x7b54bf72fd1d1573),
+    (0x26030c36f5, 0x056eeeb9ac8dcc6b, 0x8a92b329406c6806),
+    (0x390c672c09, 0xeed4fd91a4ee06e2, 0x0a084a0555226447),
+    (0x02500c9905, 0x2ab4feea0db84df6, 0x4c3c58f99fee369e),
+    (0x0133f77b54, 0x1b1a69aa17401cf6, 0xac3b141525f38bec),
+    (0x139519c1a0, 0x9b0df996dcefdd11, 0x9250a5b39382966b),
+    (0x2564d83946, 0xf09d3a934dba7827, 0xb3c5879e26b11dbc),
+    (0x108458f4f1, 0x3fbef732911ffeee, 0xe510e2edefe26d8a),
+    (0x382b8fb826, 0x0aeb9383a9a93134, 0xac7b3cbd4caea7b6),
+    (0x08db84e153, 0xf7b3f1fe3e43b93b, 0x6879c0399e67d492),
+    (0x138cdbbc79, 0x283dad8f8ef9c8fa, 0xfbd8af7164911fb0),
+    (0x1c358805db, 0x2942da65d79c7b81, 0xa6d36af50dfcb968),
+    (0x330706291b, 0x9a4920baa6e56277, 0xa40905cbbfd7b62b),
+    (0x066d3f7246, 0x68928b5fce792932, 0x905ae41fad4c31ef),
+    (0x1064ac3cca, 0x8e31ba4d0a31af28, 0x3fffce162ea4e477),
+    (0x2f293ed40d, 0x10b25f4cd92c36e0, 0xeeb590a6f7c3cbc2),
+    (0x030abf7670, 0xc97a789bbda0edea, 0xc3aeeb3ea04f6390),
+    (0x1a42a16c73, 0x158f0d45360e45d8, 0xf83e26329f5445b6),
+    (0x1acb8dbf1f, 0xcf906b5648d8dc11, 0xaf50367b4cb59566),
+    (0x0768123f65, 0x6963e2a793b0e724, 0x7bd749319aa57231),
+    (0x179c9b707b, 0xc8b0d83a0adfe718, 0x563c4cde59d22820),
+    (0x1cd2192892, 0xfe09f1884cdcfa09, 0x111cea8b3f4ed443),
+    (0x22396d1919, 0x8e4ab734ddb11cf4, 0x006d204b2816c680),
+    (0x05f33efb72, 0x5974e52567b9f06c, 0xdaeeab14e1a10000),
+    (0x1a9fc1fbb7, 0xb437c41c6fa699c1, 0x0d8e4fe30a000000),
+    (0x2ac59c37f2, 0x8794ffc1719448b7, 0xaceb29e400000000),
+    (0x3877e4458f, 0x015a239514b92690, 0x4e35e80000000000),
+    (0x214bc76d7d, 0xe388c59e8d43569c, 0x1910000000000000),
+    (0x1d4f8ebb5c, 0x57de9aef3604ef66, 0xa000000000000000),
+    (0x15801606f3, 0xfb9a62c48f833a40, 0x0000000000000000),
+    (0x38ce82742a, 0x8940ac9b56768000, 0x0000000000000000),
+    (0x09e75a08fc, 0xaa91e50281000000, 0x0000000000000000),
+    (0x3ad4159a22, 0x75f093ca00000000, 0x0000000000000000),
+    (0x08060495d8, 0x4a21640000000000, 0x0000000000000000),
+    (0x325bdcf4a0, 0x80e8000000000000, 0x0000000000000000),
+    (0x255ec1a947, 0x1000000000000000, 0x0000000000000000),
+    (0x108ba7b2a0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000821, 0x3f56a67f6b29b9c4),
+    (0x0000000000, 0x000001e494034e79, 0xe5b99f78c67672cf),
+    (0x0000000070, 0xd31c29dde93228af, 0x3c39fc501cb4ba00),
+    (0x1a44df832b, 0x8d45f18e7065dd8d, 0xffe6223c445197ea),
+    (0x0a23e47838, 0x54317c0762380640, 0x13037500814ee1bc),
+    (0x0d1d767fc9, 0x5bf1d2a4bbdbcb0c, 0x3a6d5d824f0499c6),
+    (0x2ee1f0ed91, 0x7e9aa63f650a0071, 0x94626a8ea08f1139),
+    (0x21e0269b3e, 0xa59a1bd855df90d8, 0xa0e219b92b8d0519),
+    (0x0e960b91cb, 0x35e6e2a177afd2e3, 0x8eb9cf5f0d3127be),
+    (0x2f504afba7, 0xba8aedd00b303d30, 0x44d9442370ea6840),
+    (0x270d689593, 0xa16101f2ff2f93ba, 0x981bea225cf69acd),
+    (0x225f74ed24, 0xb887370669df2bc6, 0xcad7df60f17d738a),
+    (0x088cbb24a0, 0x45ad2ee13ee0a266, 0x0e3bada54186f644),
+    (0x2550f74af6, 0xc3fb542c76d0d826, 0xa287eba6296a0eeb),
+    (0x39745f883b, 0x328cb133b9419552, 0x4d7177eab6326c8a),
+    (0x0dc8718b5c, 0x88a25f0b8b2eae00, 0xc3ce6d1ccb267204),
+    (0x158b70a164, 0xf5049801aee2f7d4, 0x283cc9b3b39b8658),
+    (0x1781831d21, 0x7442d7a612739ecd, 0xb7a6af3dc41bdfd4),
+    (0x07ca05bda5, 0xbe50c9b62c623792, 0x2da122187bd11f6f),
+    (0x269713e342, 0x12fb14e99e4cedcb, 0x4c2affbe4dbc9b18),
+    (0x0f62536bd6, 0x8a854bfd9a7c24e8, 0xa231648a77629907),
+    (0x31f3a55600, 0x571fa715c67283dd, 0x5e24ccc87706e0d2),
+    (0x001448f94b, 0x81acf3ddb3e9c99e, 0x6d9c5ddef8267e36),
+    (0x11948a7291, 0x44420c1145a7fc30, 0x3d64160ca152115c),
+    (0x21d290e85f, 0x5af73f2360290fbb, 0x262ceca95ebbd533),
+    (0x16339ef210, 0x3a433933fecafdb0, 0xd975ebe116d96cae),
+    (0x03c73d5898, 0x702315a08aae89e3, 0xd1cb76def802d616),
+    (0x237e03d392, 0xb19a54342d33dc55, 0x7115c8bddcced900),
+    (0x2227a12862, 0x743f50a528517799, 0xb48050ac9916c1d6),
+    (0x16ec523566, 0x89475962a76f7035, 0x8ec48728bc1cfd37),
+    (0x17dfa2eed7, 0xce774003c6a97006, 0xc481fc3ad0899d4f),
+    (0x323f11f867, 0x79918980a5c18261, 0xda7792963d269af4),
+    (0x181795522a, 0x3416d23fad183119, 0x28233b2fbcafdd47),
+    (0x09d385e549, 0x2918f2736c201d81, 0x67e7b4ad03f3db5c),
+    (0x1108b53350, 0x22a9856fea130403, 0x648e195e80db3a4e),
+    (0x12a8712714, 0xd38f80103361eaac, 0x2a909bfdd0ce83a4),
+    (0x04d959c5c6, 0x00a342881c8cc887, 0x70e57b54bf72fd1e),
+    (0x2e19de3f0c, 0x36f5056eeeb9ac8d, 0xcc6b8a92b329406d),
+    (0x02d80d2b03, 0x2c09eed4fd91a4ee, 0x06e20a084a055523),
+    (0x00bd1148c4, 0x99052ab4feea0db8, 0x4df64c3c58f99fef),
+    (0x2dc6235eb1, 0x7b541b1a69aa1740, 0x1cf6ac3b141525f4),
+    (0x2952bc9e27, 0xc1a09b0df996dcef, 0xdd119250a5b39383),
+    (0x0941a9dd22, 0x3946f09d3a934dba, 0x7827b3c5879e26b2),
+    (0x07f7e4cf52, 0xf4f13fbef732911f, 0xfeeee510e2edefe3),
+    (0x13509c6817, 0xb8260aeb9383a9a9, 0x3134ac7b3cbd4caf),
+    (0x0585c842b4, 0xe153f7b3f1fe3e43, 0xb93b6879c0399e68),
+    (0x2a1d4ca065, 0xbc79283dad8f8ef9, 0xc8fafbd8af716492),
+    (0x17aff393e0, 0x05db2942da65d79c, 0x7b81a6d36af50dfd),
+    (0x3428cdccf8, 0x291b9a4920baa6e5, 0x6277a40905cbbfd8),
+    (0x39c785e799, 0x724668928b5fce79, 0x2932905ae41fad4d),
+    (0x23ba1e0ca0, 0x3cca8e31ba4d0a31, 0xaf283fffce162ea5),
+    (0x254ee5b096, 0xd40d10b25f4cd92c, 0x36e0eeb590a6f7c4),
+    (0x231e119607, 0x7670c97a789bbda0, 0xedeac3aeeb3ea050),
+    (0x01bccf21bd, 0x6c73158f0d45360e, 0x45d8f83e26329f55),
+    (0x2c1a873ac5, 0xbf1fcf906b5648d8, 0xdc11af50367b4cb6),
+    (0x2e0a9d56c8, 0x3f656963e2a793b0, 0xe7247bd749319aa6),
+    (0x2e9fb08451, 0x707bc8b0d83a0adf, 0xe718563c4cde59d3),
+    (0x12f62a7479, 0x2892fe09f1884cdc, 0xfa09111cea8b3f4f),
+    (0x1c359be2fd, 0x19198e4ab734ddb1, 0x1cf4006d204b2817),
+    (0x3aedd1b2f8, 0xfb725974e52567b9, 0xf06cdaeeab14e1a1),
+    (0x39f87f13cf, 0xfbb7b437c41c6fa6, 0x99c10d8e4fe30a00),
+    (0x306cc4dfaa, 0x37f28794ffc17194, 0x48b7aceb29e40000),
+    (0x27a1d0dd4a, 0x458f015a239514b9, 0x26904e35e8000000),
+    (0x114af06839, 0x6d7de388c59e8d43, 0x569c191000000000),
+    (0x0d5ef53190, 0xbb5c57de9aef3604, 0xef66a00000000000),
+    (0x21b2b131a4, 0x06f3fb9a62c48f83, 0x3a40000000000000),
+    (0x2630c7d73e, 0x742a8940ac9b5676, 0x8000000000000000),
+    (0x0e8a88f6e2, 0x08fcaa91e5028100, 0x0000000000000000),
+    (0x34a0bdfe65, 0x9a2275f093ca0000, 0x0000000000000000),
+    (0x17a7f4d588, 0x95d84a2164000000, 0x0000000000000000),
+    (0x1fcd1ec36e, 0xf4a080e800000000, 0x0000000000000000),
+    (0x19d577b349, 0xa947100000000000, 0x0000000000000000),
+    (0x11268d5501, 0xb2a0000000000000, 0x0000000000000000),
+    (0x006531ab32, 0x4000000000000000, 0x0000000000000000),
+    (0x0bb3222680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000001e49403, 0x4e79e5b99f78c677),
+    (0x0000000000, 0x0070d31c29dde932, 0x28af3c39fc501cb5),
+    (0x00001a44df, 0x832b8d45f18e7065, 0xdd8dffe6223c4452),
+    (0x340a604f42, 0x783854317c076238, 0x064013037500814f),
+    (0x0f79e5c026, 0x7fc95bf1d2a4bbdb, 0xcb0c3a6d5d824f05),
+    (0x08f6baa826, 0xed917e9aa63f650a, 0x007194626a8ea090),
+    (0x09104a2928, 0x9b3ea59a1bd855df, 0x90d8a0e219b92b8e),
+    (0x097454de59, 0x91cb35e6e2a177af, 0xd2e38eb9cf5f0d32),
+    (0x14dac236b4, 0xfba7ba8aedd00b30, 0x3d3044d9442370eb),
+    (0x2a236dd976, 0x9593a16101f2ff2f, 0x93ba981bea225cf7),
+    (0x1b9c2c9306, 0xed24b887370669df, 0x2bc6cad7df60f17e),
+    (0x019cd795bd, 0x24a045ad2ee13ee0, 0xa2660e3bada54187),
+    (0x2c09ce375f, 0x4af6c3fb542c76d0, 0xd826a287eba6296b),
+    (0x162fe528c3, 0x883b328cb133b941, 0x95524d7177eab633),
+    (0x2d869fe1c1, 0x8b5c88a25f0b8b2e, 0xae00c3ce6d1ccb27),
+    (0x2d1024e11e, 0xa164f5049801aee2, 0xf7d4283cc9b3b39c),
+    (0x0721b7868d, 0x1d217442d7a61273, 0x9ecdb7a6af3dc41c),
+    (0x20db0994e7, 0xbda5be50c9b62c62, 0x37922da122187bd2),
+    (0x35f4d42435, 0xe34212fb14e99e4c, 0xedcb4c2affbe4dbd),
+    (0x0c8bf57571, 0x6bd68a854bfd9a7c, 0x24e8a231648a7763),
+    (0x1a686ed013, 0x5600571fa715c672, 0x83dd5e24ccc87707),
+    (0x0480831224, 0xf94b81acf3ddb3e9, 0xc99e6d9c5ddef827),
+    (0x089bcf8b04, 0x729144420c1145a7, 0xfc303d64160ca153),
+    (0x010917e884, 0xe85f5af73f236029, 0x0fbb262ceca95ebc),
+    (0x1ef1eaa2ae, 0xf2103a433933feca, 0xfdb0d975ebe116da),
+    (0x28bb916631, 0x5898702315a08aae, 0x89e3d1cb76def803),
+    (0x0b7d415d73, 0xd392b19a54342d33, 0xdc557115c8bddccf),
+    (0x1af7cb7a1f, 0x2862743f50a52851, 0x7799b48050ac9917),
+    (0x07412591e2, 0x356689475962a76f, 0x70358ec48728bc1d),
+    (0x34ab153f74, 0xeed7ce774003c6a9, 0x7006c481fc3ad08a),
+    (0x1b39bfaedd, 0xf86779918980a5c1, 0x8261da7792963d27),
+    (0x33ae766d45, 0x522a3416d23fad18, 0x311928233b2fbcb0),
+    (0x1023d9da39, 0xe5492918f2736c20, 0x1d8167e7b4ad03f4),
+    (0x0d7ad97967, 0x335022a9856fea13, 0x0403648e195e80dc),
+    (0x180739c4af, 0x2714d38f80103361, 0xeaac2a909bfdd0cf),
+    (0x28c7e9841f, 0xc5c600a342881c8c, 0xc88770e57b54bf73),
+    (0x0765caad50, 0x3f0c36f5056eeeb9, 0xac8dcc6b8a92b32a),
+    (0x12af0d0fc3, 0x2b032c09eed4fd91, 0xa4ee06e20a084a06),
+    (0x2d70eb9aff, 0x48c499052ab4feea, 0x0db84df64c3c58fa),
+    (0x3b702084f3, 0x5eb17b541b1a69aa, 0x17401cf6ac3b1416),
+    (0x38a9f9e6e8, 0x9e27c1a09b0df996, 0xdcefdd119250a5b4),
+    (0x362919da55, 0xdd223946f09d3a93, 0x4dba7827b3c5879f),
+    (0x13fde1aa46, 0xcf52f4f13fbef732, 0x911ffeeee510e2ee),
+    (0x107c98b5f2, 0x6817b8260aeb9383, 0xa9a93134ac7b3cbe),
+    (0x38708f5bd8, 0x42b4e153f7b3f1fe, 0x3e43b93b6879c03a),
+    (0x325a227560, 0xa065bc79283dad8f, 0x8ef9c8fafbd8af72),
+    (0x167f642e33, 0x93e005db2942da65, 0xd79c7b81a6d36af6),
+    (0x0c024448cb, 0xccf8291b9a4920ba, 0xa6e56277a40905cc),
+    (0x2f73774e7d, 0xe799724668928b5f, 0xce792932905ae420),
+    (0x1d50810864, 0x0ca03cca8e31ba4d, 0x0a31af283fffce17),
+    (0x174b67785d, 0xb096d40d10b25f4c, 0xd92c36e0eeb590a7),
+    (0x15d058eaf1, 0x96077670c97a789b, 0xbda0edeac3aeeb3f),
+    (0x383fa6992b, 0x21bd6c73158f0d45, 0x360e45d8f83e2633),
+    (0x0a0adafc91, 0x3ac5bf1fcf906b56, 0x48d8dc11af50367c),
+    (0x21d05b8661, 0x56c83f656963e2a7, 0x93b0e7247bd74932),
+    (0x16a9db298c, 0x8451707bc8b0d83a, 0x0adfe718563c4cdf),
+    (0x20b7753e52, 0x74792892fe09f188, 0x4cdcfa09111cea8c),
+    (0x1332b30d23, 0xe2fd19198e4ab734, 0xddb11cf4006d204c),
+    (0x085b033225, 0xb2f8fb725974e525, 0x67b9f06cdaeeab15),
+    (0x08c70acaf3, 0x13cffbb7b437c41c, 0x6fa699c10d8e4fe4),
+    (0x38988aa7c2, 0xdfaa37f28794ffc1, 0x719448b7aceb29e4),
+    (0x2d5f608bc6, 0xdd4a458f015a2395, 0x14b926904e35e800),
+    (0x2e4d3e2752, 0x68396d7de388c59e, 0x8d43569c19100000),
+    (0x132fd8f505, 0x3190bb5c57de9aef, 0x3604ef66a0000000),
+    (0x013590455b, 0x31a406f3fb9a62c4, 0x8f833a4000000000),
+    (0x153b949f71, 0xd73e742a8940ac9b, 0x5676800000000000),
+    (0x1a8170b42e, 0xf6e208fcaa91e502, 0x8100000000000000),
+    (0x0aef4baad9, 0xfe659a2275f093ca, 0x0000000000000000),
+    (0x32c1707660, 0xd58895d84a216400, 0x0000000000000000),
+    (0x168bc35730, 0xc36ef4a080e80000, 0x0000000000000000),
+    (0x0b5a869d55, 0xb349a94710000000, 0x0000000000000000),
+    (0x13f42373cb, 0x5501b2a000000000, 0x0000000000000000),
+    (0x2f5788f843, 0xab32400000000000, 0x0000000000000000),
+    (0x0fc15ef610, 0x2680000000000000, 0x0000000000000000),
+    (0x03c2a36761, 0x0000000000000000, 0x0000000000000000),
+    (0x1695a68a00, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000000001e4, 0x94034e79e5b99f79),
+    (0x0000000000, 0x00000070d31c29dd, 0xe93228af3c39fc51),
+    (0x000000001a, 0x44df832b8d45f18e, 0x7065dd8dffe6223d),
+    (0x061dc1ac08, 0x4f42783854317c07, 0x6238064013037501),
+    (0x01ef4a8e3b, 0xc0267fc95bf1d2a4, 0xbbdbcb0c3a6d5d83),
+    (0x0de9699c3a, 0xa826ed917e9aa63f, 0x650a007194626a8f),
+    (0x0da83868da, 0x29289b3ea59a1bd8, 0x55df90d8a0e219ba),
+    (0x32cb6542ae, 0xde5991cb35e6e2a1, 0x77afd2e38eb9cf60),
+    (0x28b6fa61ee, 0x36b4fba7ba8aedd0, 0x0b303d3044d94424),
+    (0x3776a49609, 0xd9769593a16101f2, 0xff2f93ba981bea23),
+    (0x024b12eb66, 0x9306ed24b8873706, 0x69df2bc6cad7df61),
+    (0x17e1e7fad5, 0x95bd24a045ad2ee1, 0x3ee0a2660e3bada6),
+    (0x31baa73360, 0x375f4af6c3fb542c, 0x76d0d826a287eba7),
+    (0x1666f0314b, 0x28c3883b328cb133, 0xb94195524d7177eb),
+    (0x117fd6e42f, 0xe1c18b5c88a25f0b, 0x8b2eae00c3ce6d1d),
+    (0x0b25fb31ae, 0xe11ea164f5049801, 0xaee2f7d4283cc9b4),
+    (0x28b79f7941, 0x868d1d217442d7a6, 0x12739ecdb7a6af3e),
+    (0x0f41a12ac5, 0x94e7bda5be50c9b6, 0x2c6237922da12219),
+    (0x2e00c8e39c, 0x2435e34212fb14e9, 0x9e4cedcb4c2affbf),
+    (0x245ac1685d, 0x75716bd68a854bfd, 0x9a7c24e8a231648b),
+    (0x15c29388c0, 0xd0135600571fa715, 0xc67283dd5e24ccc9),
+    (0x2ce489c4a3, 0x1224f94b81acf3dd, 0xb3e9c99e6d9c5ddf),
+    (0x25f7c81c03, 0x8b04729144420c11, 0x45a7fc303d64160d),
+    (0x00d32e72c5, 0xe884e85f5af73f23, 0x60290fbb262cecaa),
+    (0x2e1440aafa, 0xa2aef2103a433933, 0xfecafdb0d975ebe2),
+    (0x3a5b09f365, 0x66315898702315a0, 0x8aae89e3d1cb76df),
+    (0x179bdcdbbd, 0x5d73d392b19a5434, 0x2d33dc557115c8be),
+    (0x2c1709552d, 0x7a1f2862743f50a5, 0x28517799b48050ad),
+    (0x0a96a48769, 0x91e2356689475962, 0xa76f70358ec48729),
+    (0x189474317f, 0x3f74eed7ce774003, 0xc6a97006c481fc3b),
+    (0x1da0908775, 0xaeddf86779918980, 0xa5c18261da779297),
+    (0x1b667535c2, 0x6d45522a3416d23f, 0xad18311928233b30),
+    (0x2d44be1fdb, 0xda39e5492918f273, 0x6c201d8167e7b4ae),
+    (0x33303a18dd, 0x7967335022a9856f, 0xea130403648e195f),
+    (0x3390e492b3, 0xc4af2714d38f8010, 0x3361eaac2a909bfe),
+    (0x29db068891, 0x841fc5c600a34288, 0x1c8cc88770e57b55),
+    (0x21e16f9ff2, 0xad503f0c36f5056e, 0xeeb9ac8dcc6b8a93),
+    (0x3880ad3d8f, 0x0fc32b032c09eed4, 0xfd91a4ee06e20a09),
+    (0x214f2258c1, 0x9aff48c499052ab4, 0xfeea0db84df64c3d),
+    (0x2d13c8d7a4, 0x84f35eb17b541b1a, 0x69aa17401cf6ac3c),
+    (0x264e1de221, 0xe6e89e27c1a09b0d, 0xf996dcefdd119251),
+    (0x07e4b74495, 0xda55dd223946f09d, 0x3a934dba7827b3c6),
+    (0x22e3ed5fe5, 0xaa46cf52f4f13fbe, 0xf732911ffeeee511),
+    (0x35791bf8bc, 0xb5f26817b8260aeb, 0x9383a9a93134ac7c),
+    (0x2bf0093961, 0x5bd842b4e153f7b3, 0xf1fe3e43b93b687a),
+    (0x16ab08e9f0, 0x7560a065bc79283d, 0xad8f8ef9c8fafbd9),
+    (0x37fc719db6, 0x2e3393e005db2942, 0xda65d79c7b81a6d4),
+    (0x2a6acd6e90, 0x48cbccf8291b9a49, 0x20baa6e56277a40a),
+    (0x2198049c47, 0x4e7de79972466892, 0x8b5fce792932905b),
+    (0x109a34800d, 0x08640ca03cca8e31, 0xba4d0a31af284000),
+    (0x0308d063b7, 0x785db096d40d10b2, 0x5f4cd92c36e0eeb6),
+    (0x2ab7acc508, 0xeaf196077670c97a, 0x789bbda0edeac3af),
+    (0x02138a0c4a, 0x992b21bd6c73158f, 0x0d45360e45d8f83f),
+    (0x115e67f194, 0xfc913ac5bf1fcf90, 0x6b5648d8dc11af51),
+    (0x22b04af733, 0x866156c83f656963, 0xe2a793b0e7247bd8),
+    (0x0bff1fed97, 0x298c8451707bc8b0, 0xd83a0adfe718563d),
+    (0x2331f9a7cf, 0x3e5274792892fe09, 0xf1884cdcfa09111d),
+    (0x3040ac059f, 0x0d23e2fd19198e4a, 0xb734ddb11cf4006e),
+    (0x250832ad45, 0x3225b2f8fb725974, 0xe52567b9f06cdaef),
+    (0x101c65747e, 0xcaf313cffbb7b437, 0xc41c6fa699c10d8f),
+    (0x1d857027ee, 0xa7c2dfaa37f28794, 0xffc1719448b7acec),
+    (0x3790f72526, 0x8bc6dd4a458f015a, 0x239514b926904e36),
+    (0x08f98554ec, 0x275268396d7de388, 0xc59e8d43569c1910),
+    (0x36fbd9fc9e, 0xf5053190bb5c57de, 0x9aef3604ef66a000),
+    (0x25029504e2, 0x455b31a406f3fb9a, 0x62c48f833a400000),
+    (0x34aecc4806, 0x9f71d73e742a8940, 0xac9b567680000000),
+    (0x018ac060e6, 0xb42ef6e208fcaa91, 0xe502810000000000),
+    (0x35b7054153, 0xaad9fe659a2275f0, 0x93ca000000000000),
+    (0x137af70994, 0x7660d58895d84a21, 0x6400000000000000),
+    (0x22910cacdf, 0x5730c36ef4a080e8, 0x0000000000000000),
+    (0x340022eb2c, 0x9d55b349a9471000, 0x0000000000000000),
+    (0x0a633c9a05, 0x73cb5501b2a00000, 0x0000000000000000),
+    (0x0144fbd246, 0xf843ab3240000000, 0x0000000000000000),
+    (0x108620f10e, 0xf610268000000000, 0x0000000000000000),
+    (0x037bc18cbf, 0x6761000000000000, 0x0000000000000000),
+    (0x2c908e92ec, 0x8a00000000000000, 0x0000000000000000),
+    (0x3712d3a8e4, 0x0000000000000000, 0x0000000000000000),
+    (0x3515dbe800, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000070d31c, 0x29dde93228af3c3a),
+    (0x0000000000, 0x001a44df832b8d45, 0xf18e7065dd8dffe7),
+    (0x0000061dc1, 0xac084f4278385431, 0x7c07623806401304),
+    (0x2d17c03902, 0x8e3bc0267fc95bf1, 0xd2a4bbdbcb0c3a6e),
+    (0x0098535975, 0x9c3aa826ed917e9a, 0xa63f650a00719463),
+    (0x1b621e4d50, 0x68da29289b3ea59a, 0x1bd855df90d8a0e3),
+    (0x12b8c8cd75, 0x42aede5991cb35e6, 0xe2a177afd2e38eba),
+    (0x1b4d44f10e, 0x61ee36b4fba7ba8a, 0xedd00b303d3044da),
+    (0x035944292e, 0x9609d9769593a161, 0x01f2ff2f93ba981c),
+    (0x0ad8bf496e, 0xeb669306ed24b887, 0x370669df2bc6cad8),
+    (0x19d351c555, 0xfad595bd24a045ad, 0x2ee13ee0a2660e3c),
+    (0x1404cbf5eb, 0x3360375f4af6c3fb, 0x542c76d0d826a288),
+    (0x36c30dab2e, 0x314b28c3883b328c, 0xb133b94195524d72),
+    (0x0ac14a6c80, 0xe42fe1c18b5c88a2, 0x5f0b8b2eae00c3cf),
+    (0x1e028601e3, 0x31aee11ea164f504, 0x9801aee2f7d4283d),
+    (0x34e5d27649, 0x7941868d1d217442, 0xd7a612739ecdb7a7),
+    (0x111b5f051b, 0x2ac594e7bda5be50, 0xc9b62c6237922da2),
+    (0x065348b3ec, 0xe39c2435e34212fb, 0x14e99e4cedcb4c2b),
+    (0x3727b0d3df, 0x685d75716bd68a85, 0x4bfd9a7c24e8a232),
+    (0x3404229aa3, 0x88c0d0135600571f, 0xa715c67283dd5e25),
+    (0x261365bdd9, 0xc4a31224f94b81ac, 0xf3ddb3e9c99e6d9d),
+    (0x32b3fdb470, 0x1c038b0472914442, 0x0c1145a7fc303d65),
+    (0x1a1a3e2146, 0x72c5e884e85f5af7, 0x3f2360290fbb262d),
+    (0x10670c3e34, 0xaafaa2aef2103a43, 0x3933fecafdb0d976),
+    (0x0c43402d2d, 0xf365663158987023, 0x15a08aae89e3d1cc),
+    (0x0ab2e10b9a, 0xdbbd5d73d392b19a, 0x54342d33dc557116),
+    (0x240e4705d7, 0x552d7a1f2862743f, 0x50a528517799b481),
+    (0x3222d4a1b6, 0x876991e235668947, 0x5962a76f70358ec5),
+    (0x2a7f92d0fa, 0x317f3f74eed7ce77, 0x4003c6a97006c482),
+    (0x3a40af853a, 0x8775aeddf8677991, 0x8980a5c18261da78),
+    (0x0da09bcafb, 0x35c26d45522a3416, 0xd23fad1831192824),
+    (0x3a7d485e90, 0x1fdbda39e5492918, 0xf2736c201d8167e8),
+    (0x218e7c8eb0, 0x18dd7967335022a9, 0x856fea130403648f),
+    (0x290034f3d4, 0x92b3c4af2714d38f, 0x80103361eaac2a91),
+    (0x317e576a3a, 0x8891841fc5c600a3, 0x42881c8cc88770e6),
+    (0x0da0dde0bf, 0x9ff2ad503f0c36f5, 0x056eeeb9ac8dcc6c),
+    (0x2c9dba5a23, 0x3d8f0fc32b032c09, 0xeed4fd91a4ee06e3),
+    (0x08347ecf44, 0x58c19aff48c49905, 0x2ab4feea0db84df7),
+    (0x0fe9c7f138, 0xd7a484f35eb17b54, 0x1b1a69aa17401cf7),
+    (0x0d3c117dc3, 0xe221e6e89e27c1a0, 0x9b0df996dcefdd12),
+    (0x2d9b8e690b, 0x4495da55dd223946, 0xf09d3a934dba7828),
+    (0x029f9eaf95, 0x5fe5aa46cf52f4f1, 0x3fbef732911ffeef),
+    (0x22c76b7c11, 0xf8bcb5f26817b826, 0x0aeb9383a9a93135),
+    (0x042f314db9, 0x39615bd842b4e153, 0xf7b3f1fe3e43b93c),
+    (0x2b20381c02, 0xe9f07560a065bc79, 0x283dad8f8ef9c8fb),
+    (0x00adad724b, 0x9db62e3393e005db, 0x2942da65d79c7b82),
+    (0x119b1186af, 0x6e9048cbccf8291b, 0x9a4920baa6e56278),
+    (0x28d88e30d0, 0x9c474e7de7997246, 0x68928b5fce792933),
+    (0x3092270d4c, 0x800d08640ca03cca, 0x8e31ba4d0a31af29),
+    (0x11cfc465d0, 0x63b7785db096d40d, 0x10b25f4cd92c36e1),
+    (0x3084fbb3ca, 0xc508eaf196077670, 0xc97a789bbda0edeb),
+    (0x2f360394fc, 0x0c4a992b21bd6c73, 0x158f0d45360e45d9),
+    (0x3aaf3b77df, 0xf194fc913ac5bf1f, 0xcf906b5648d8dc12),
+    (0x3424155e74, 0xf733866156c83f65, 0x6963e2a793b0e725),
+    (0x1b3bb1e005, 0xed97298c8451707b, 0xc8b0d83a0adfe719),
+    (0x01615770fb, 0xa7cf3e5274792892, 0xfe09f1884cdcfa0a),
+    (0x3a97d64872, 0x059f0d23e2fd1919, 0x8e4ab734ddb11cf5),
+    (0x1a8c3d0224, 0xad453225b2f8fb72, 0x5974e52567b9f06d),
+    (0x088a1c1ee7, 0x747ecaf313cffbb7, 0xb437c41c6fa699c2),
+    (0x35e3cbeef8, 0x27eea7c2dfaa37f2, 0x8794ffc1719448b8),
+    (0x39c73fd5bd, 0x25268bc6dd4a458f, 0x015a239514b92691),
+    (0x2c09ed7ab7, 0x54ec275268396d7d, 0xe388c59e8d43569d),
+    (0x2aaf6c2c21, 0xfc9ef5053190bb5c, 0x57de9aef3604ef67),
+    (0x07e9c56b6d, 0x04e2455b31a406f3, 0xfb9a62c48f833a40),
+    (0x19620b1bf4, 0x48069f71d73e742a, 0x8940ac9b56768000),
+    (0x38e04d9b90, 0x60e6b42ef6e208fc, 0xaa91e50281000000),
+    (0x219da162c5, 0x4153aad9fe659a22, 0x75f093ca00000000),
+    (0x2ded533a41, 0x09947660d58895d8, 0x4a21640000000000),
+    (0x0f24884c26, 0xacdf5730c36ef4a0, 0x80e8000000000000),
+    (0x090139fbda, 0xeb2c9d55b349a947, 0x1000000000000000),
+    (0x32f8917eaa, 0x9a0573cb5501b2a0, 0x0000000000000000),
+    (0x27b8a6867f, 0xd246f843ab324000, 0x0000000000000000),
+    (0x1dc2bfb5d4, 0xf10ef61026800000, 0x0000000000000000),
+    (0x31944f7beb, 0x8cbf676100000000, 0x0000000000000000),
+    (0x36d7dca506, 0x92ec8a0000000000, 0x0000000000000000),
+    (0x0187d61607, 0xa8e4000000000000, 0x0000000000000000),
+    (0x01c88e306b, 0xe800000000000000, 0x0000000000000000),
+    (0x191fb6b510, 0x0000000000000000, 0x0000000000000000),
+    (0x03b9aca000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000000070, 0xd31c29dde93228b0),
+    (0x0000000000, 0x0000001a44df832b, 0x8d45f18e7065dd8e),
+    (0x0000000006, 0x1dc1ac084f427838, 0x54317c0762380641),
+    (0x016c8e5ca2, 0x39028e3bc0267fc9, 0x5bf1d2a4bbdbcb0d),
+    (0x25c539e34d, 0x59759c3aa826ed91, 0x7e9aa63f650a0072),
+    (0x120262f458, 0x4d5068da29289b3e, 0xa59a1bd855df90d9),
+    (0x148f35b78a, 0xcd7542aede5991cb, 0x35e6e2a177afd2e4),
+    (0x2051472506, 0xf10e61ee36b4fba7, 0xba8aedd00b303d31),
+    (0x019dc0cd6e, 0x292e9609d9769593, 0xa16101f2ff2f93bb),
+    (0x19a6196f19, 0x496eeb669306ed24, 0xb887370669df2bc7),
+    (0x05e336b0eb, 0xc555fad595bd24a0, 0x45ad2ee13ee0a267),
+    (0x36e509903d, 0xf5eb3360375f4af6, 0xc3fb542c76d0d827),
+    (0x0e6d24085f, 0xab2e314b28c3883b, 0x328cb133b9419553),
+    (0x16464c1c38, 0x6c80e42fe1c18b5c, 0x88a25f0b8b2eae01),
+    (0x0d231f7fbe, 0x01e331aee11ea164, 0xf5049801aee2f7d5),
+    (0x2c3d526c9c, 0x76497941868d1d21, 0x7442d7a612739ece),
+    (0x246ddd8c7b, 0x051b2ac594e7bda5, 0xbe50c9b62c623793),
+    (0x1ca48f673a, 0xb3ece39c2435e342, 0x12fb14e99e4cedcc),
+    (0x0daaf626ee, 0xd3df685d75716bd6, 0x8a854bfd9a7c24e9),
+    (0x379b3c64a0, 0x9aa388c0d0135600, 0x571fa715c67283de),
+    (0x2564bf70e9, 0xbdd9c4a31224f94b, 0x81acf3ddb3e9c99f),
+    (0x366c15d51f, 0xb4701c038b047291, 0x44420c1145a7fc31),
+    (0x0761c16646, 0x214672c5e884e85f, 0x5af73f2360290fbc),
+    (0x1054129716, 0x3e34aafaa2aef210, 0x3a433933fecafdb1),
+    (0x052dc91c2c, 0x2d2df36566315898, 0x702315a08aae89e4),
+    (0x0a491fa063, 0x0b9adbbd5d73d392, 0xb19a54342d33dc56),
+    (0x170f8fd2f5, 0x05d7552d7a1f2862, 0x743f50a52851779a),
+    (0x390c7f7ac6, 0xa1b6876991e23566, 0x89475962a76f7036),
+    (0x2e3f5f149c, 0xd0fa317f3f74eed7, 0xce774003c6a97007),
+    (0x2482fb1ccf, 0x853a8775aeddf867, 0x79918980a5c18262),
+    (0x30512e618d, 0xcafb35c26d45522a, 0x3416d23fad18311a),
+    (0x210383e2ac, 0x5e901fdbda39e549, 0x2918f2736c201d82),
+    (0x2822041ca8, 0x8eb018dd79673350, 0x22a9856fea130404),
+    (0x273ecd6c40, 0xf3d492b3c4af2714, 0xd38f80103361eaad),
+    (0x0f1f77ec15, 0x6a3a8891841fc5c6, 0x00a342881c8cc888),
+    (0x04fc6e4a81, 0xe0bf9ff2ad503f0c, 0x36f5056eeeb9ac8e),
+    (0x1e3d53d87a, 0x5a233d8f0fc32b03, 0x2c09eed4fd91a4ef),
+    (0x1c7cc0e382, 0xcf4458c19aff48c4, 0x99052ab4feea0db9),
+    (0x1e74dca71d, 0xf138d7a484f35eb1, 0x7b541b1a69aa1741),
+    (0x06f8b2d63b, 0x7dc3e221e6e89e27, 0xc1a09b0df996dcf0),
+    (0x0dd9f4be30, 0x690b4495da55dd22, 0x3946f09d3a934dbb),
+    (0x0b457afc78, 0xaf955fe5aa46cf52, 0xf4f13fbef7329120),
+    (0x1c19704781, 0x7c11f8bcb5f26817, 0xb8260aeb9383a9aa),
+    (0x1e25e2f309, 0x4db939615bd842b4, 0xe153f7b3f1fe3e44),
+    (0x022a89c8fa, 0x1c02e9f07560a065, 0xbc79283dad8f8efa),
+    (0x3a3baedfc5, 0x724b9db62e3393e0, 0x05db2942da65d79d),
+    (0x2df8b9fb05, 0x86af6e9048cbccf8, 0x291b9a4920baa6e6),
+    (0x014961d04a, 0x30d09c474e7de799, 0x724668928b5fce7a),
+    (0x11461bfc07, 0x0d4c800d08640ca0, 0x3cca8e31ba4d0a32),
+    (0x01a4543206, 0x65d063b7785db096, 0xd40d10b25f4cd92d),
+    (0x017d5552ad, 0xb3cac508eaf19607, 0x7670c97a789bbda1),
+    (0x287176f341, 0x94fc0c4a992b21bd, 0x6c73158f0d45360f),
+    (0x0f44fd7603, 0x77dff194fc913ac5, 0xbf1fcf906b5648d9),
+    (0x00ceb975fb, 0x5e74f733866156c8, 0x3f656963e2a793b1),
+    (0x3a86c21fdd, 0xe005ed97298c8451, 0x707bc8b0d83a0ae0),
+    (0x33a8c93417, 0x70fba7cf3e527479, 0x2892fe09f1884cdd),
+    (0x0575367636, 0x4872059f0d23e2fd, 0x19198e4ab734ddb2),
+    (0x0ca384b10d, 0x0224ad453225b2f8, 0xfb725974e52567ba),
+    (0x03075c01b0, 0x1ee7747ecaf313cf, 0xfbb7b437c41c6fa7),
+    (0x29019ce777, 0xeef827eea7c2dfaa, 0x37f28794ffc17195),
+    (0x1bec97950b, 0xd5bd25268bc6dd4a, 0x458f015a239514ba),
+    (0x02c16a81ff, 0x7ab754ec27526839, 0x6d7de388c59e8d44),
+    (0x3b7bc1a9b0, 0x2c21fc9ef5053190, 0xbb5c57de9aef3605),
+    (0x2904b1647d, 0x6b6d04e2455b31a4, 0x06f3fb9a62c48f84),
+    (0x1d3397b679, 0x1bf448069f71d73e, 0x742a8940ac9b5677),
+    (0x1c32abad9b, 0x9b9060e6b42ef6e2, 0x08fcaa91e5028100),
+    (0x243af0a3ef, 0x62c54153aad9fe65, 0x9a2275f093ca0000),
+    (0x37bc81c4a7, 0x3a4109947660d588, 0x95d84a2164000000),
+    (0x26ef89fc4c, 0x4c26acdf5730c36e, 0xf4a080e800000000),
+    (0x11c3aeed31, 0xfbdaeb2c9d55b349, 0xa947100000000000),
+    (0x0ba344669f, 0x7eaa9a0573cb5501, 0xb2a0000000000000),
+    (0x2522a15e12, 0x867fd246f843ab32, 0x4000000000000000),
+    (0x045032fc7b, 0xb5d4f10ef6102680, 0x0000000000000000),
+    (0x1ccdb51321, 0x7beb8cbf67610000, 0x0000000000000000),
+    (0x07cbce40ea, 0xa50692ec8a000000, 0x0000000000000000),
+    (0x36a1e8f008, 0x1607a8e400000000, 0x0000000000000000),
+    (0x01e1f765ea, 0x306be80000000000, 0x0000000000000000),
+    (0x3686c2c996, 0xb510000000000000, 0x0000000000000000),
+    (0x2316da867e, 0xa000000000000000, 0x0000000000000000),
+    (0x1d7b702a40, 0x0000000000000000, 0x0000000000000000),
+    (0x0ee6b28000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x00000000001a44df, 0x832b8d45f18e7066),
+    (0x0000000000, 0x00061dc1ac084f42, 0x783854317c076239),
+    (0x0000016c8e, 0x5ca239028e3bc026, 0x7fc95bf1d2a4bbdc),
+    (0x21256d71d1, 0xe34d59759c3aa826, 0xed917e9aa63f650b),
+    (0x30de4b2d80, 0xf4584d5068da2928, 0x9b3ea59a1bd855e0),
+    (0x1e064917bd, 0xb78acd7542aede59, 0x91cb35e6e2a177b0),
+    (0x2c2c0319ad, 0x2506f10e61ee36b4, 0xfba7ba8aedd00b31),
+    (0x2850397ef2, 0xcd6e292e9609d976, 0x9593a16101f2ff30),
+    (0x3888278ddb, 0x6f19496eeb669306, 0xed24b887370669e0),
+    (0x331748cecc, 0xb0ebc555fad595bd, 0x24a045ad2ee13ee1),
+    (0x2fa88a47e9, 0x903df5eb3360375f, 0x4af6c3fb542c76d1),
+    (0x36617758c4, 0x085fab2e314b28c3, 0x883b328cb133b942),
+    (0x2da475c49c, 0x1c386c80e42fe1c1, 0x8b5c88a25f0b8b2f),
+    (0x2458e52937, 0x7fbe01e331aee11e, 0xa164f5049801aee3),
+    (0x0cebff6d88, 0x6c9c76497941868d, 0x1d217442d7a61274),
+    (0x1fc3850b15, 0x8c7b051b2ac594e7, 0xbda5be50c9b62c63),
+    (0x050467dd07, 0x673ab3ece39c2435, 0xe34212fb14e99e4d),
+    (0x01b944783c, 0x26eed3df685d7571, 0x6bd68a854bfd9a7d),
+    (0x0e0157ed38, 0x64a09aa388c0d013, 0x5600571fa715c673),
+    (0x0d214a0ba7, 0x70e9bdd9c4a31224, 0xf94b81acf3ddb3ea),
+    (0x26fc43ea75, 0xd51fb4701c038b04, 0x729144420c1145a8),
+    (0x1b6f5d7dd3, 0x6646214672c5e884, 0xe85f5af73f23602a),
+    (0x3138647e8e, 0x97163e34aafaa2ae, 0xf2103a433933fecb),
+    (0x21330986ef, 0x1c2c2d2df3656631, 0x5898702315a08aaf),
+    (0x37ac11cd37, 0xa0630b9adbbd5d73, 0xd392b19a54342d34),
+    (0x0cf39933cf, 0xd2f505d7552d7a1f, 0x2862743f50a52852),
+    (0x3063475c33, 0x7ac6a1b6876991e2, 0x356689475962a770),
+    (0x0bfc6c41a3, 0x149cd0fa317f3f74, 0xeed7ce774003c6aa),
+    (0x25f85b38c3, 0x1ccf853a8775aedd, 0xf86779918980a5c2),
+    (0x2d6d9d1d46, 0x618dcafb35c26d45, 0x522a3416d23fad19),
+    (0x106309e60d, 0xe2ac5e901fdbda39, 0xe5492918f2736c21),
+    (0x033ba30a58, 0x1ca88eb018dd7967, 0x335022a9856fea14),
+    (0x1483e19ce5, 0x6c40f3d492b3c4af, 0x2714d38f80103362),
+    (0x356aab1eaf, 0xec156a3a8891841f, 0xc5c600a342881c8d),
+    (0x28f5c7c4a6, 0x4a81e0bf9ff2ad50, 0x3f0c36f5056eeeba),
+    (0x26b7b7f7b7, 0xd87a5a233d8f0fc3, 0x2b032c09eed4fd92),
+    (0x2ace0d7d30, 0xe382cf4458c19aff, 0x48c499052ab4feeb),
+    (0x0b61fe95fa, 0xa71df138d7a484f3, 0x5eb17b541b1a69ab),
+    (0x3a5c123678, 0xd63b7dc3e221e6e8, 0x9e27c1a09b0df997),
+    (0x1c226feed0, 0xbe30690b4495da55, 0xdd223946f09d3a94),
+    (0x309a0c4766, 0xfc78af955fe5aa46, 0xcf52f4f13fbef733),
+    (0x17fa74f448, 0x47817c11f8bcb5f2, 0x6817b8260aeb9384),
+    (0x10d42ee3e8, 0xf3094db939615bd8, 0x42b4e153f7b3f1ff),
+    (0x363cdd2847, 0xc8fa1c02e9f07560, 0xa065bc79283dad90),
+    (0x10b6b92f7e, 0xdfc5724b9db62e33, 0x93e005db2942da66),
+    (0x1d8a493aaf, 0xfb0586af6e9048cb, 0xccf8291b9a4920bb),
+    (0x28f942236f, 0xd04a30d09c474e7d, 0xe799724668928b60),
+    (0x1a089ca03b, 0xfc070d4c800d0864, 0x0ca03cca8e31ba4e),
+    (0x0df75a912c, 0x320665d063b7785d, 0xb096d40d10b25f4d),
+    (0x0a4a4070c9, 0x52adb3cac508eaf1, 0x96077670c97a789c),
+    (0x2edfc8a02a, 0xf34194fc0c4a992b, 0x21bd6c73158f0d46),
+    (0x0a000856bb, 0x760377dff194fc91, 0x3ac5bf1fcf906b57),
+    (0x2ba58bb5d5, 0x75fb5e74f7338661, 0x56c83f656963e2a8),
+    (0x31b3425714, 0x1fdde005ed97298c, 0x8451707bc8b0d83b),
+    (0x04af832f3f, 0x341770fba7cf3e52, 0x74792892fe09f189),
+    (0x0eb7389c3e, 0x76364872059f0d23, 0xe2fd19198e4ab735),
+    (0x0e8b02e8a0, 0xb10d0224ad453225, 0xb2f8fb725974e526),
+    (0x2569f74d06, 0x01b01ee7747ecaf3, 0x13cffbb7b437c41d),
+    (0x0166055866, 0xe777eef827eea7c2, 0xdfaa37f28794ffc2),
+    (0x17f59114dd, 0x950bd5bd25268bc6, 0xdd4a458f015a2396),
+    (0x3397543afc, 0x81ff7ab754ec2752, 0x68396d7de388c59f),
+    (0x3acaa3538b, 0xa9b02c21fc9ef505, 0x3190bb5c57de9af0),
+    (0x20848de20b, 0x647d6b6d04e2455b, 0x31a406f3fb9a62c5),
+    (0x02a70c587f, 0xb6791bf448069f71, 0xd73e742a8940ac9c),
+    (0x1dbc467447, 0xad9b9b9060e6b42e, 0xf6e208fcaa91e503),
+    (0x10b059db72, 0xa3ef62c54153aad9, 0xfe659a2275f093ca),
+    (0x1ab1193f1f, 0xc4a73a4109947660, 0xd58895d84a216400),
+    (0x076587e831, 0xfc4c4c26acdf5730, 0xc36ef4a080e80000),
+    (0x0ba35ecc86, 0xed31fbdaeb2c9d55, 0xb349a94710000000),
+    (0x1f6a3fac46, 0x669f7eaa9a0573cb, 0x5501b2a000000000),
+    (0x1064380b1d, 0x5e12867fd246f843, 0xab32400000000000),
+    (0x06d670085e, 0xfc7bb5d4f10ef610, 0x2680000000000000),
+    (0x161d9f588d, 0x13217beb8cbf6761, 0x0000000000000000),
+    (0x20d8b58acc, 0x40eaa50692ec8a00, 0x0000000000000000),
+    (0x2f8e764c68, 0xf0081607a8e40000, 0x0000000000000000),
+    (0x186ec50f57, 0x65ea306be8000000, 0x0000000000000000),
+    (0x1459553e74, 0xc996b51000000000, 0x0000000000000000),
+    (0x1b31132774, 0x867ea00000000000, 0x0000000000000000),
+    (0x1b2174092c, 0x2a40000000000000, 0x0000000000000000),
+    (0x0a487103d6, 0x8000000000000000, 0x0000000000000000),
+    (0x31f1324100, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000000000001a, 0x44df832b8d45f18f),
+    (0x0000000000, 0x000000061dc1ac08, 0x4f42783854317c08),
+    (0x0000000001, 0x6c8e5ca239028e3b, 0xc0267fc95bf1d2a5),
+    (0x0054e13ca5, 0x71d1e34d59759c3a, 0xa826ed917e9aa640),
+    (0x2685446375, 0x2d80f4584d5068da, 0x29289b3ea59a1bd9),
+    (0x1b48568dcb, 0x17bdb78acd7542ae, 0xde5991cb35e6e2a2),
+    (0x2f49454229, 0x19ad2506f10e61ee, 0x36b4fba7ba8aedd1),
+    (0x0991c4c7f3, 0x7ef2cd6e292e9609, 0xd9769593a16101f3),
+    (0x38b17c7593, 0x8ddb6f19496eeb66, 0x9306ed24b8873707),
+    (0x225ae9568a, 0xceccb0ebc555fad5, 0x95bd24a045ad2ee2),
+    (0x2051971b16, 0x47e9903df5eb3360, 0x375f4af6c3fb542d),
+    (0x05300bab7d, 0x58c4085fab2e314b, 0x28c3883b328cb134),
+    (0x1d2f3f7be5, 0xc49c1c386c80e42f, 0xe1c18b5c88a25f0c),
+    (0x357f3d8d8d, 0x29377fbe01e331ae, 0xe11ea164f5049802),
+    (0x20ddd9f859, 0x6d886c9c76497941, 0x868d1d217442d7a7),
+    (0x14d250e587, 0x0b158c7b051b2ac5, 0x94e7bda5be50c9b7),
+    (0x1f71353115, 0xdd07673ab3ece39c, 0x2435e34212fb14ea),
+    (0x051728eba6, 0x783c26eed3df685d, 0x75716bd68a854bfe),
+    (0x26c25d8c07, 0xed3864a09aa388c0, 0xd0135600571fa716),
+    (0x01d876f44c, 0x0ba770e9bdd9c4a3, 0x1224f94b81acf3de),
+    (0x11b4aa9af1, 0xea75d51fb4701c03, 0x8b04729144420c12),
+    (0x38534f1601, 0x7dd36646214672c5, 0xe884e85f5af73f24),
+    (0x0058e69706, 0x7e8e97163e34aafa, 0xa2aef2103a433934),
+    (0x0183181e75, 0x86ef1c2c2d2df365, 0x66315898702315a1),
+    (0x1b5d2903cd, 0xcd37a0630b9adbbd, 0x5d73d392b19a5435),
+    (0x2feabba95b, 0x33cfd2f505d7552d, 0x7a1f2862743f50a6),
+    (0x153c160785, 0x5c337ac6a1b68769, 0x91e2356689475963),
+    (0x1f0ce28f04, 0x41a3149cd0fa317f, 0x3f74eed7ce774004),
+    (0x00fdb36da5, 0x38c31ccf853a8775, 0xaeddf86779918981),
+    (0x2677fb7bcd, 0x1d46618dcafb35c2, 0x6d45522a3416d240),
+    (0x2fc1c4adeb, 0xe60de2ac5e901fdb, 0xda39e5492918f274),
+    (0x36eca7bb1f, 0x0a581ca88eb018dd, 0x7967335022a98570),
+    (0x073a2705c5, 0x9ce56c40f3d492b3, 0xc4af2714d38f8011),
+    (0x2e02a52fc3, 0x1eafec156a3a8891, 0x841fc5c600a34289),
+    (0x2d6e0cf773, 0xc4a64a81e0bf9ff2, 0xad503f0c36f5056f),
+    (0x1af451f85f, 0xf7b7d87a5a233d8f, 0x0fc32b032c09eed5),
+    (0x16581e1cf3, 0x7d30e382cf4458c1, 0x9aff48c499052ab5),
+    (0x38b113b4a0, 0x95faa71df138d7a4, 0x84f35eb17b541b1b),
+    (0x2563a9b3a4, 0x3678d63b7dc3e221, 0xe6e89e27c1a09b0e),
+    (0x263bd8310b, 0xeed0be30690b4495, 0xda55dd223946f09e),
+    (0x02c7412fd8, 0x4766fc78af955fe5, 0xaa46cf52f4f13fbf),
+    (0x325b3a587a, 0xf44847817c11f8bc, 0xb5f26817b8260aec),
+    (0x1ca0a4a0b6, 0xe3e8f3094db93961, 0x5bd842b4e153f7b4),
+    (0x2a951c19fb, 0x2847c8fa1c02e9f0, 0x7560a065bc79283e),
+    (0x3a7a24f449, 0x2f7edfc5724b9db6, 0x2e3393e005db2943),
+    (0x110a328f5f, 0x3aaffb0586af6e90, 0x48cbccf8291b9a4a),
+    (0x162c1b0106, 0x236fd04a30d09c47, 0x4e7de79972466893),
+    (0x016de0ee3a, 0xa03bfc070d4c800d, 0x08640ca03cca8e32),
+    (0x0da660799a, 0x912c320665d063b7, 0x785db096d40d10b3),
+    (0x23fcea7aaa, 0x70c952adb3cac508, 0xeaf196077670c97b),
+    (0x27af0cbc28, 0xa02af34194fc0c4a, 0x992b21bd6c731590),
+    (0x09757a4e48, 0x56bb760377dff194, 0xfc913ac5bf1fcf91),
+    (0x10d7ba7567, 0xb5d575fb5e74f733, 0x866156c83f656964),
+    (0x18259d6a14, 0x57141fdde005ed97, 0x298c8451707bc8b1),
+    (0x04bc5e1229, 0x2f3f341770fba7cf, 0x3e5274792892fe0a),
+    (0x0996ca7c4e, 0x9c3e76364872059f, 0x0d23e2fd19198e4b),
+    (0x124d8a6a1a, 0xe8a0b10d0224ad45, 0x3225b2f8fb725975),
+    (0x0643e23507, 0x4d0601b01ee7747e, 0xcaf313cffbb7b438),
+    (0x01b32a7ac7, 0x5866e777eef827ee, 0xa7c2dfaa37f28795),
+    (0x2e69e83101, 0x14dd950bd5bd2526, 0x8bc6dd4a458f015b),
+    (0x004076791c, 0x3afc81ff7ab754ec, 0x275268396d7de389),
+    (0x0692a9f467, 0x538ba9b02c21fc9e, 0xf5053190bb5c57df),
+    (0x180ebafa0b, 0xe20b647d6b6d04e2, 0x455b31a406f3fb9b),
+    (0x02c447fb60, 0x587fb6791bf44806, 0x9f71d73e742a8941),
+    (0x166ea6b1b6, 0x7447ad9b9b9060e6, 0xb42ef6e208fcaa92),
+    (0x2a7b1e6fe1, 0xdb72a3ef62c54153, 0xaad9fe659a2275f1),
+    (0x349623a5e7, 0x3f1fc4a73a410994, 0x7660d58895d84a22),
+    (0x35d75ec33d, 0xe831fc4c4c26acdf, 0x5730c36ef4a080e8),
+    (0x0e69f20c6e, 0xcc86ed31fbdaeb2c, 0x9d55b349a9471000),
+    (0x19cc218f37, 0xac46669f7eaa9a05, 0x73cb5501b2a00000),
+    (0x0cf65dc9f0, 0x0b1d5e12867fd246, 0xf843ab3240000000),
+    (0x37e3b3dd1e, 0x085efc7bb5d4f10e, 0xf610268000000000),
+    (0x06fe169fef, 0x588d13217beb8cbf, 0x6761000000000000),
+    (0x37ba20a425, 0x8acc40eaa50692ec, 0x8a00000000000000),
+    (0x08bdb0315a, 0x4c68f0081607a8e4, 0x0000000000000000),
+    (0x15063566bd, 0x0f5765ea306be800, 0x0000000000000000),
+    (0x2c04d98d2b, 0x3e74c996b5100000, 0x0000000000000000),
+    (0x0a118a9bff, 0x2774867ea0000000, 0x0000000000000000),
+    (0x3b685eec3a, 0x092c2a4000000000, 0x0000000000000000),
+    (0x0d83347d8b, 0x03d6800000000000, 0x0000000000000000),
+    (0x205df06f90, 0x4100000000000000, 0x0000000000000000),
+    (0x219633ed4a, 0x0000000000000000, 0x0000000000000000),
+    (0x113abe6400, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000061dc1, 0xac084f4278385432),
+    (0x0000000000, 0x00016c8e5ca23902, 0x8e3bc0267fc95bf2),
+    (0x00000054e1, 0x3ca571d1e34d5975, 0x9c3aa826ed917e9b),
+    (0x34712a569c, 0x63752d80f4584d50, 0x68da29289b3ea59b),
+    (0x24697b3c74, 0x8dcb17bdb78acd75, 0x42aede5991cb35e7),
+    (0x1b23271287, 0x422919ad2506f10e, 0x61ee36b4fba7ba8b),
+    (0x1f7e07ffd8, 0xc7f37ef2cd6e292e, 0x9609d9769593a162),
+    (0x3279287482, 0x75938ddb6f19496e, 0xeb669306ed24b888),
+    (0x1e5ffaad3b, 0x568aceccb0ebc555, 0xfad595bd24a045ae),
+    (0x0dd0d2df73, 0x1b1647e9903df5eb, 0x3360375f4af6c3fc),
+    (0x1accd74159, 0xab7d58c4085fab2e, 0x314b28c3883b328d),
+    (0x14e0bdce2d, 0x7be5c49c1c386c80, 0xe42fe1c18b5c88a3),
+    (0x0a970e604b, 0x8d8d29377fbe01e3, 0x31aee11ea164f505),
+    (0x11974e4d1b, 0xf8596d886c9c7649, 0x7941868d1d217443),
+    (0x0683261400, 0xe5870b158c7b051b, 0x2ac594e7bda5be51),
+    (0x003570e7e7, 0x3115dd07673ab3ec, 0xe39c2435e34212fc),
+    (0x35d419fdd2, 0xeba6783c26eed3df, 0x685d75716bd68a86),
+    (0x311bd78dcb, 0x8c07ed3864a09aa3, 0x88c0d01356005720),
+    (0x2f6458acee, 0xf44c0ba770e9bdd9, 0xc4a31224f94b81ad),
+    (0x37a2c90932, 0x9af1ea75d51fb470, 0x1c038b0472914443),
+    (0x0bc84ee4d3, 0x16017dd366462146, 0x72c5e884e85f5af8),
+    (0x3125b42442, 0x97067e8e97163e34, 0xaafaa2aef2103a44),
+    (0x0f8111e43e, 0x1e7586ef1c2c2d2d, 0xf365663158987024),
+    (0x0e76946cd5, 0x03cdcd37a0630b9a, 0xdbbd5d73d392b19b),
+    (0x3198accd19, 0xa95b33cfd2f505d7, 0x552d7a1f28627440),
+    (0x05f98c2570, 0x07855c337ac6a1b6, 0x876991e235668948),
+    (0x1a1578a868, 0x8f0441a3149cd0fa, 0x317f3f74eed7ce78),
+    (0x18582e8489, 0x6da538c31ccf853a, 0x8775aeddf8677992),
+    (0x1fff5d79fd, 0x7bcd1d46618dcafb, 0x35c26d45522a3417),
+    (0x3b04ccc2d2, 0xadebe60de2ac5e90, 0x1fdbda39e5492919),
+    (0x310d783b29, 0xbb1f0a581ca88eb0, 0x18dd7967335022aa),
+    (0x09b75ba5b5, 0x05c59ce56c40f3d4, 0x92b3c4af2714d390),
+    (0x2a25c8da97, 0x2fc31eafec156a3a, 0x8891841fc5c600a4),
+    (0x23336bff22, 0xf773c4a64a81e0bf, 0x9ff2ad503f0c36f6),
+    (0x08242c2137, 0xf85ff7b7d87a5a23, 0x3d8f0fc32b032c0a),
+    (0x0d0815b1ce, 0x1cf37d30e382cf44, 0x58c19aff48c49906),
+    (0x2ffd4c2b2b, 0xb4a095faa71df138, 0xd7a484f35eb17b55),
+    (0x0a2d0e27b1, 0xb3a43678d63b7dc3, 0xe221e6e89e27c1a1),
+    (0x295fd92116, 0x310beed0be30690b, 0x4495da55dd223947),
+    (0x052ab8c3eb, 0x2fd84766fc78af95, 0x5fe5aa46cf52f4f2),
+    (0x36c23b3450, 0x587af44847817c11, 0xf8bcb5f26817b827),
+    (0x12b4f8f614, 0xa0b6e3e8f3094db9, 0x39615bd842b4e154),
+    (0x04cd831b5c, 0x19fb2847c8fa1c02, 0xe9f07560a065bc7a),
+    (0x1571ad2fde, 0xf4492f7edfc5724b, 0x9db62e3393e005dc),
+    (0x33e91bbeba, 0x8f5f3aaffb0586af, 0x6e9048cbccf8291c),
+    (0x2b6fd866f1, 0x0106236fd04a30d0, 0x9c474e7de7997247),
+    (0x381cf532aa, 0xee3aa03bfc070d4c, 0x800d08640ca03ccb),
+    (0x27cc41b22c, 0x799a912c320665d0, 0x63b7785db096d40e),
+    (0x0a5aeade64, 0x7aaa70c952adb3ca, 0xc508eaf196077671),
+    (0x1765065b50, 0xbc28a02af34194fc, 0x0c4a992b21bd6c74),
+    (0x12cc2e41d2, 0x4e4856bb760377df, 0xf194fc913ac5bf20),
+    (0x30f733b546, 0x7567b5d575fb5e74, 0xf733866156c83f66),
+    (0x1067a91fef, 0x6a1457141fdde005, 0xed97298c8451707c),
+    (0x37be356a02, 0x12292f3f341770fb, 0xa7cf3e5274792893),
+    (0x007b700cfe, 0x7c4e9c3e76364872, 0x059f0d23e2fd191a),
+    (0x3b4085b362, 0x6a1ae8a0b10d0224, 0xad453225b2f8fb73),
+    (0x16e9f5af86, 0x35074d0601b01ee7, 0x747ecaf313cffbb8),
+    (0x1f3f5e7afc, 0x7ac75866e777eef8, 0x27eea7c2dfaa37f3),
+    (0x3ac8f5062c, 0x310114dd950bd5bd, 0x25268bc6dd4a4590),
+    (0x0a4a039920, 0x791c3afc81ff7ab7, 0x54ec275268396d7e),
+    (0x078f8bfc23, 0xf467538ba9b02c21, 0xfc9ef5053190bb5d),
+    (0x085f113542, 0xfa0be20b647d6b6d, 0x04e2455b31a406f4),
+    (0x0f9820018b, 0xfb60587fb6791bf4, 0x48069f71d73e742b),
+    (0x209792e0b4, 0xb1b67447ad9b9b90, 0x60e6b42ef6e208fd),
+    (0x2a123688c8, 0x6fe1db72a3ef62c5, 0x4153aad9fe659a23),
+    (0x2eaafa83b9, 0xa5e73f1fc4a73a41, 0x09947660d58895d9),
+    (0x2b397c9590, 0xc33de831fc4c4c26, 0xacdf5730c36ef4a1),
+    (0x21b486f1d0, 0x0c6ecc86ed31fbda, 0xeb2c9d55b349a948),
+    (0x3070a92d99, 0x8f37ac46669f7eaa, 0x9a0573cb5501b2a0),
+    (0x23c0db2733, 0xc9f00b1d5e12867f, 0xd246f843ab324000),
+    (0x0c0edaaa4d, 0xdd1e085efc7bb5d4, 0xf10ef61026800000),
+    (0x12210a6078, 0x9fef588d13217beb, 0x8cbf676100000000),
+    (0x1c15cb8d96, 0xa4258acc40eaa506, 0x92ec8a0000000000),
+    (0x2312ea4318, 0x315a4c68f0081607, 0xa8e4000000000000),
+    (0x05a20096df, 0x66bd0f5765ea306b, 0xe800000000000000),
+    (0x3403c1a755, 0x8d2b3e74c996b510, 0x0000000000000000),
+    (0x13eb4364cc, 0x9bff2774867ea000, 0x0000000000000000),
+    (0x2fa3ab18ac, 0xec3a092c2a400000, 0x0000000000000000),
+    (0x2842ffed6c, 0x7d8b03d680000000, 0x0000000000000000),
+    (0x1942882a92, 0x6f90410000000000, 0x0000000000000000),
+    (0x221840e7c9, 0xed4a000000000000, 0x0000000000000000),
+    (0x2f03c821c0, 0x6400000000000000, 0x0000000000000000),
+    (0x2ccb5ff6e8, 0x0000000000000000, 0x0000000000000000),
+    (0x3604471000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x000000016c8e5ca2, 0x39028e3bc0267fca),
+    (0x0000000000, 0x54e13ca571d1e34d, 0x59759c3aa826ed92),
+    (0x0013c33b72, 0x569c63752d80f458, 0x4d5068da29289b3f),
+    (0x1a9f185d57, 0x3c748dcb17bdb78a, 0xcd7542aede5991cc),
+    (0x144fae107f, 0x1287422919ad2506, 0xf10e61ee36b4fba8),
+    (0x1d961a963b, 0xffd8c7f37ef2cd6e, 0x292e9609d9769594),
+    (0x0df83e365e, 0x748275938ddb6f19, 0x496eeb669306ed25),
+    (0x15fdf6af82, 0xad3b568aceccb0eb, 0xc555fad595bd24a1),
+    (0x1e6cefff54, 0xdf731b1647e9903d, 0xf5eb3360375f4af7),
+    (0x13c2d0eacd, 0x4159ab7d58c4085f, 0xab2e314b28c3883c),
+    (0x2fca2af007, 0xce2d7be5c49c1c38, 0x6c80e42fe1c18b5d),
+    (0x01d13cab9a, 0x604b8d8d29377fbe, 0x01e331aee11ea165),
+    (0x23f189270e, 0x4d1bf8596d886c9c, 0x76497941868d1d22),
+    (0x03546b1de8, 0x1400e5870b158c7b, 0x051b2ac594e7bda6),
+    (0x3608ef5d38, 0xe7e73115dd07673a, 0xb3ece39c2435e343),
+    (0x0d3fdab05f, 0xfdd2eba6783c26ee, 0xd3df685d75716bd7),
+    (0x16598a0b79, 0x8dcb8c07ed3864a0, 0x9aa388c0d0135601),
+    (0x1c4d2d1f9a, 0xaceef44c0ba770e9, 0xbdd9c4a31224f94c),
+    (0x2403612681, 0x09329af1ea75d51f, 0xb4701c038b047292),
+    (0x1e0b240368, 0xe4d316017dd36646, 0x214672c5e884e860),
+    (0x186c29119c, 0x244297067e8e9716, 0x3e34aafaa2aef211),
+    (0x245ac45d79, 0xe43e1e7586ef1c2c, 0x2d2df36566315899),
+    (0x1c614dcc7c, 0x6cd503cdcd37a063, 0x0b9adbbd5d73d393),
+    (0x1cf850bde4, 0xcd19a95b33cfd2f5, 0x05d7552d7a1f2863),
+    (0x35459cd54e, 0x257007855c337ac6, 0xa1b6876991e23567),
+    (0x1231e100aa, 0xa8688f0441a3149c, 0xd0fa317f3f74eed8),
+    (0x27bc0010be, 0x84896da538c31ccf, 0x853a8775aeddf868),
+    (0x2c5bbdbb85, 0x79fd7bcd1d46618d, 0xcafb35c26d45522b),
+    (0x1f13d22046, 0xc2d2adebe60de2ac, 0x5e901fdbda39e54a),
+    (0x1079af968c, 0x3b29bb1f0a581ca8, 0x8eb018dd79673351),
+    (0x20a66cdbe9, 0xa5b505c59ce56c40, 0xf3d492b3c4af2715),
+    (0x366676c3fa, 0xda972fc31eafec15, 0x6a3a8891841fc5c7),
+    (0x3a680e476f, 0xff22f773c4a64a81, 0xe0bf9ff2ad503f0d),
+    (0x1a1384e962, 0x2137f85ff7b7d87a, 0x5a233d8f0fc32b04),
+    (0x16d8fd501f, 0xb1ce1cf37d30e382, 0xcf4458c19aff48c5),
+    (0x07612478f6, 0x2b2bb4a095faa71d, 0xf138d7a484f35eb2),
+    (0x3950cb48fc, 0x27b1b3a43678d63b, 0x7dc3e221e6e89e28),
+    (0x3ab59ccc9f, 0x2116310beed0be30, 0x690b4495da55dd23),
+    (0x250cd794c2, 0xc3eb2fd84766fc78, 0xaf955fe5aa46cf53),
+    (0x2d58eabe19, 0x3450587af4484781, 0x7c11f8bcb5f26818),
+    (0x05de4be000, 0xf614a0b6e3e8f309, 0x4db939615bd842b5),
+    (0x00394b8b9f, 0x1b5c19fb2847c8fa, 0x1c02e9f07560a066),
+    (0x250b823afb, 0x2fdef4492f7edfc5, 0x724b9db62e3393e1),
+    (0x3a7be96231, 0xbeba8f5f3aaffb05, 0x86af6e9048cbccf9),
+    (0x0b9508fbc4, 0x66f10106236fd04a, 0x30d09c474e7de79a),
+    (0x2dba7a7171, 0x32aaee3aa03bfc07, 0x0d4c800d08640ca1),
+    (0x1a5b1f31b5, 0xb22c799a912c3206, 0x65d063b7785db097),
+    (0x2a4deccd5e, 0xde647aaa70c952ad, 0xb3cac508eaf19608),
+    (0x16169dcc32, 0x5b50bc28a02af341, 0x94fc0c4a992b21be),
+    (0x0bb97e45fc, 0x41d24e4856bb7603, 0x77dff194fc913ac6),
+    (0x3abbb21c7d, 0xb5467567b5d575fb, 0x5e74f733866156c9),
+    (0x1d44c97a7b, 0x1fef6a1457141fdd, 0xe005ed97298c8452),
+    (0x1caace8aab, 0x6a0212292f3f3417, 0x70fba7cf3e52747a),
+    (0x27e9138114, 0x0cfe7c4e9c3e7636, 0x4872059f0d23e2fe),
+    (0x04ab1e49fd, 0xb3626a1ae8a0b10d, 0x0224ad453225b2f9),
+    (0x3b11bdc733, 0xaf8635074d0601b0, 0x1ee7747ecaf313d0),
+    (0x0c08b44d74, 0x7afc7ac75866e777, 0xeef827eea7c2dfab),
+    (0x1b1ec61539, 0x062c310114dd950b, 0xd5bd25268bc6dd4b),
+    (0x0d46e6e4bf, 0x9920791c3afc81ff, 0x7ab754ec2752683a),
+    (0x2c9c23c845, 0xfc23f467538ba9b0, 0x2c21fc9ef5053191),
+    (0x104b6d2fe9, 0x3542fa0be20b647d, 0x6b6d04e2455b31a5),
+    (0x364c487df2, 0x018bfb60587fb679, 0x1bf448069f71d73f),
+    (0x3858af265c, 0xe0b4b1b67447ad9b, 0x9b9060e6b42ef6e3),
+    (0x159ff21af6, 0x88c86fe1db72a3ef, 0x62c54153aad9fe66),
+    (0x396697024a, 0x83b9a5e73f1fc4a7, 0x3a4109947660d589),
+    (0x115969d2da, 0x9590c33de831fc4c, 0x4c26acdf5730c36f),
+    (0x32e4a2d018, 0xf1d00c6ecc86ed31, 0xfbdaeb2c9d55b34a),
+    (0x05ced018d3, 0x2d998f37ac46669f, 0x7eaa9a0573cb5502),
+    (0x312b32745d, 0x2733c9f00b1d5e12, 0x867fd246f843ab33),
+    (0x15b05c05a0, 0xaa4ddd1e085efc7b, 0xb5d4f10ef6102680),
+    (0x2568652b2e, 0x60789fef588d1321, 0x7beb8cbf67610000),
+    (0x0acc466d8b, 0x8d96a4258acc40ea, 0xa50692ec8a000000),
+    (0x207e03022c, 0x4318315a4c68f008, 0x1607a8e400000000),
+    (0x0a4e39dcde, 0x96df66bd0f5765ea, 0x306be80000000000),
+    (0x33d35be21d, 0xa7558d2b3e74c996, 0xb510000000000000),
+    (0x06e77ec709, 0x64cc9bff2774867e, 0xa000000000000000),
+    (0x022fe93493, 0x18acec3a092c2a40, 0x0000000000000000),
+    (0x223fa0c3ef, 0xed6c7d8b03d68000, 0x0000000000000000),
+    (0x37dcca258a, 0x2a926f9041000000, 0x0000000000000000),
+    (0x202b5a6164, 0xe7c9ed4a00000000, 0x0000000000000000),
+    (0x177e6e980e, 0x21c0640000000000, 0x0000000000000000),
+    (0x034a52cb69, 0xf6e8000000000000, 0x0000000000000000),
+    (0x18abf99c63, 0x1000000000000000, 0x0000000000000000),
+    (0x171095caa0, 0x0000000000000000, 0x0000000000000000),
+    (0x2540be4000, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000000016c8e, 0x5ca239028e3bc027),
+    (0x0000000000, 0x000054e13ca571d1, 0xe34d59759c3aa827),
+    (0x00000013c3, 0x3b72569c63752d80, 0xf4584d5068da2929),
+    (0x2d74bf29a6, 0x5d573c748dcb17bd, 0xb78acd7542aede5a),
+    (0x26bc1a8710, 0x107f1287422919ad, 0x2506f10e61ee36b5),
+    (0x03bd83e2ba, 0x963bffd8c7f37ef2, 0xcd6e292e9609d977),
+    (0x2b71716e9a, 0x365e748275938ddb, 0x6f19496eeb669307),
+    (0x23e7c62892, 0xaf82ad3b568acecc, 0xb0ebc555fad595be),
+    (0x2227247105, 0xff54df731b1647e9, 0x903df5eb33603760),
+    (0x016578e406, 0xeacd4159ab7d58c4, 0x085fab2e314b28c4),
+    (0x019c4c02ce, 0xf007ce2d7be5c49c, 0x1c386c80e42fe1c2),
+    (0x302e717a9c, 0xab9a604b8d8d2937, 0x7fbe01e331aee11f),
+    (0x247a476e77, 0x270e4d1bf8596d88, 0x6c9c76497941868e),
+    (0x1bbe0bcf31, 0x1de81400e5870b15, 0x8c7b051b2ac594e8),
+    (0x0b6f973bd1, 0x5d38e7e73115dd07, 0x673ab3ece39c2436),
+    (0x30bf13653c, 0xb05ffdd2eba6783c, 0x26eed3df685d7572),
+    (0x0e21581c6a, 0x0b798dcb8c07ed38, 0x64a09aa388c0d014),
+    (0x18b0c397db, 0x1f9aaceef44c0ba7, 0x70e9bdd9c4a31225),
+    (0x3304c68fd7, 0x268109329af1ea75, 0xd51fb4701c038b05),
+    (0x3217f6ab20, 0x0368e4d316017dd3, 0x6646214672c5e885),
+    (0x0774247c87, 0x119c244297067e8e, 0x97163e34aafaa2af),
+    (0x1f72ba282e, 0x5d79e43e1e7586ef, 0x1c2c2d2df3656632),
+    (0x0acb93e8af, 0xcc7c6cd503cdcd37, 0xa0630b9adbbd5d74),
+    (0x28ee6c6748, 0xbde4cd19a95b33cf, 0xd2f505d7552d7a20),
+    (0x10efbf5cbe, 0xd54e257007855c33, 0x7ac6a1b6876991e3),
+    (0x2c6e8be7f3, 0x00aaa8688f0441a3, 0x149cd0fa317f3f75),
+    (0x3894157a00, 0x10be84896da538c3, 0x1ccf853a8775aede),
+    (0x0003e6085d, 0xbb8579fd7bcd1d46, 0x618dcafb35c26d46),
+    (0x15d2e48760, 0x2046c2d2adebe60d, 0xe2ac5e901fdbda3a),
+    (0x16618f92ef, 0x968c3b29bb1f0a58, 0x1ca88eb018dd7968),
+    (0x37c88feec8, 0xdbe9a5b505c59ce5, 0x6c40f3d492b3c4b0),
+    (0x2ec421a144, 0xc3fada972fc31eaf, 0xec156a3a88918420),
+    (0x1002bef7ec, 0x476fff22f773c4a6, 0x4a81e0bf9ff2ad51),
+    (0x370354398a, 0xe9622137f85ff7b7, 0xd87a5a233d8f0fc4),
+    (0x2057c79ed7, 0x501fb1ce1cf37d30, 0xe382cf4458c19b00),
+    (0x3221a76644, 0x78f62b2bb4a095fa, 0xa71df138d7a484f4),
+    (0x0ff147877b, 0x48fc27b1b3a43678, 0xd63b7dc3e221e6e9),
+    (0x1cb45d4c6c, 0xcc9f2116310beed0, 0xbe30690b4495da56),
+    (0x1954f19dcf, 0x94c2c3eb2fd84766, 0xfc78af955fe5aa47),
+    (0x3054cc2bb2, 0xbe193450587af448, 0x47817c11f8bcb5f3),
+    (0x299de33437, 0xe000f614a0b6e3e8, 0xf3094db939615bd9),
+    (0x0d0269100b, 0x8b9f1b5c19fb2847, 0xc8fa1c02e9f07561),
+    (0x02b028c530, 0x3afb2fdef4492f7e, 0xdfc5724b9db62e34),
+    (0x0b3ac16dad, 0x6231beba8f5f3aaf, 0xfb0586af6e9048cc),
+    (0x285e77585c, 0xfbc466f10106236f, 0xd04a30d09c474e7e),
+    (0x15a63f1688, 0x717132aaee3aa03b, 0xfc070d4c800d0865),
+    (0x1fc4a4fe49, 0x31b5b22c799a912c, 0x320665d063b7785e),
+    (0x110ab68896, 0xcd5ede647aaa70c9, 0x52adb3cac508eaf2),
+    (0x231c83665f, 0xcc325b50bc28a02a, 0xf34194fc0c4a992c),
+    (0x164dfc0876, 0x45fc41d24e4856bb, 0x760377dff194fc92),
+    (0x1b89a49024, 0x1c7db5467567b5d5, 0x75fb5e74f7338662),
+    (0x0868669ae1, 0x7a7b1fef6a145714, 0x1fdde005ed97298d),
+    (0x347f8ff912, 0x8aab6a0212292f3f, 0x341770fba7cf3e53),
+    (0x04512b8df7, 0x81140cfe7c4e9c3e, 0x76364872059f0d24),
+    (0x39a06690e8, 0x49fdb3626a1ae8a0, 0xb10d0224ad453226),
+    (0x3615814557, 0xc733af8635074d06, 0x01b01ee7747ecaf4),
+    (0x146ffc01ba, 0x4d747afc7ac75866, 0xe777eef827eea7c3),
+    (0x2b607f7188, 0x1539062c310114dd, 0x950bd5bd25268bc7),
+    (0x1faf2c4978, 0xe4bf9920791c3afc, 0x81ff7ab754ec2753),
+    (0x1c25d1280b, 0xc845fc23f467538b, 0xa9b02c21fc9ef506),
+    (0x02be47e73d, 0x2fe93542fa0be20b, 0x647d6b6d04e2455c),
+    (0x0e3f09d95e, 0x7df2018bfb60587f, 0xb6791bf448069f72),
+    (0x1600291951, 0x265ce0b4b1b67447, 0xad9b9b9060e6b42f),
+    (0x12e4e883ee, 0x1af688c86fe1db72, 0xa3ef62c54153aada),
+    (0x37702eeb1b, 0x024a83b9a5e73f1f, 0xc4a73a4109947661),
+    (0x0649dbdcfd, 0xd2da9590c33de831, 0xfc4c4c26acdf5731),
+    (0x3b19118056, 0xd018f1d00c6ecc86, 0xed31fbdaeb2c9d56),
+    (0x1436736ef0, 0x18d32d998f37ac46, 0x669f7eaa9a0573cc),
+    (0x37e6e50e22, 0x745d2733c9f00b1d, 0x5e12867fd246f844),
+    (0x0805a6a7e4, 0x05a0aa4ddd1e085e, 0xfc7bb5d4f10ef611),
+    (0x35172b5657, 0x2b2e60789fef588d, 0x13217beb8cbf6761),
+    (0x144ba87234, 0x6d8b8d96a4258acc, 0x40eaa50692ec8a00),
+    (0x0c34f26e05, 0x022c4318315a4c68, 0xf0081607a8e40000),
+    (0x012a8775cd, 0xdcde96df66bd0f57, 0x65ea306be8000000),
+    (0x2fee609af3, 0xe21da7558d2b3e74, 0xc996b51000000000),
+    (0x38c8934bd2, 0xc70964cc9bff2774, 0x867ea00000000000),
+    (0x31135136ef, 0x349318acec3a092c, 0x2a40000000000000),
+    (0x37b1c046a8, 0xc3efed6c7d8b03d6, 0x8000000000000000),
+    (0x274b3354a8, 0x258a2a926f904100, 0x0000000000000000),
+    (0x2726521a8c, 0x6164e7c9ed4a0000, 0x0000000000000000),
+    (0x20af539cf8, 0x980e21c064000000, 0x0000000000000000),
+    (0x39e15ae242, 0xcb69f6e800000000, 0x0000000000000000),
+    (0x0f8d447e27, 0x9c63100000000000, 0x0000000000000000),
+    (0x0938fe29ad, 0xcaa0000000000000, 0x0000000000000000),
+    (0x2876c7e622, 0x4000000000000000, 0x0000000000000000),
+    (0x07f9758680, 0x0000000000000000, 0x0000000000000000),
+    (0x0000000000, 0x0000000054e13ca5, 0x71d1e34d59759c3b),
+    (0x0000000000, 0x13c33b72569c6375, 0x2d80f4584d5068db),
+    (0x000499f1bd, 0x29a65d573c748dcb, 0x17bdb78acd7542af),
+    (0x2c0af9a874, 0x8710107f12874229, 0x19ad2506f10e61ef),
+    (0x1b2195e609, 0xe2ba963bffd8c7f3, 0x7ef2cd6e292e960a),
+    (0x024d3b31c5, 0x6e9a365e74827593, 0x8ddb6f19496eeb67),
+    (0x2df7dddc92, 0x2892af82ad3b568a, 0xceccb0ebc555fad6),
+    (0x2207b98ab4, 0x7105ff54df731b16, 0x47e9903df5eb3361),
+    (0x2a0326c0a2, 0xe406eacd4159ab7d, 0x58c4085fab2e314c),
+    (0x25ed0b4c34, 0x02cef007ce2d7be5, 0xc49c1c386c80e430),
+    (0x0c1c186c05, 0x7a9cab9a604b8d8d, 0x29377fbe01e331af),
+    (0x014692308b, 0x6e77270e4d1bf859, 0x6d886c9c76497942),
+    (0x2076c3eed7, 0xcf311de81400e587, 0x0b158c7b051b2ac6),
+    (0x323f3d3eed, 0x3bd15d38e7e73115, 0xdd07673ab3ece39d),
+    (0x373c3a6da1, 0x653cb05ffdd2eba6, 0x783c26eed3df685e),
+    (0x2593eb3d0a, 0x1c6a0b798dcb8c07, 0xed3864a09aa388c1),
+    (0x025aa982db, 0x97db1f9aaceef44c, 0x0ba770e9bdd9c4a4),
+    (0x3320c61fec, 0x8fd7268109329af1, 0xea75d51fb4701c04),
+    (0x37142fc6cc, 0xab200368e4d31601, 0x7dd36646214672c6),
+    (0x2fa730d112, 0x7c87119c24429706, 0x7e8e97163e34aafb),
+    (0x044de0a092, 0x282e5d79e43e1e75, 0x86ef1c2c2d2df366),
+    (0x2207a22f23, 0xe8afcc7c6cd503cd, 0xcd37a0630b9adbbe),
+    (0x085c56d37c, 0x6748bde4cd19a95b, 0x33cfd2f505d7552e),
+    (0x1cf7060f05, 0x5cbed54e25700785, 0x5c337ac6a1b6876a),
+    (0x013f9e0123, 0xe7f300aaa8688f04, 0x41a3149cd0fa3180),
+    (0x085c2ade5b, 0x7a0010be84896da5, 0x38c31ccf853a8776),
+    (0x154c6d962a, 0x085dbb8579fd7bcd, 0x1d46618dcafb35c3),
+    (0x09c957cd34, 0x87602046c2d2adeb, 0xe60de2ac5e901fdc),
+    (0x0c3af60a15, 0x92ef968c3b29bb1f, 0x0a581ca88eb018de),
+    (0x0505e8a1c3, 0xeec8dbe9a5b505c5, 0x9ce56c40f3d492b4),
+    (0x2d9e8089ed, 0xa144c3fada972fc3, 0x1eafec156a3a8892),
+    (0x3753d95dc8, 0xf7ec476fff22f773, 0xc4a64a81e0bf9ff3),
+    (0x2ecaa72c3a, 0x398ae9622137f85f, 0xf7b7d87a5a233d90),
+    (0x0d8e7792c1, 0x9ed7501fb1ce1cf3, 0x7d30e382cf4458c2),
+    (0x2d14adf453, 0x664478f62b2bb4a0, 0x95faa71df138d7a5),
+    (0x136aff1bc3, 0x877b48fc27b1b3a4, 0x3678d63b7dc3e222),
+    (0x2d867332e3, 0x4c6ccc9f2116310b, 0xeed0be30690b4496),
+    (0x34ec0c66e9, 0x9dcf94c2c3eb2fd8, 0x4766fc78af955fe6),
+    (0x3664a01cae, 0x2bb2be193450587a, 0xf44847817c11f8bd),
+    (0x288d61e9d1, 0x3437e000f614a0b6, 0xe3e8f3094db93962),
+    (0x30b5875d71, 0x100b8b9f1b5c19fb, 0x2847c8fa1c02e9f1),
+    (0x1a530f86c8, 0xc5303afb2fdef449, 0x2f7edfc5724b9db7),
+    (0x2ebed72833, 0x6dad6231beba8f5f, 0x3aaffb0586af6e91),
+    (0x0bf95f8479, 0x585cfbc466f10106, 0x236fd04a30d09c48),
+    (0x1c40bc55af, 0x1688717132aaee3a, 0xa03bfc070d4c800e),
+    (0x28c40f2800, 0xfe4931b5b22c799a, 0x912c320665d063b8),
+    (0x003b349f22, 0x8896cd5ede647aaa, 0x70c952adb3cac509),
+    (0x080a5c2bd3, 0x665fcc325b50bc28, 0xa02af34194fc0c4b),
+    (0x31386a7878, 0x087645fc41d24e48, 0x56bb760377dff195),
+    (0x1bf2870ff4, 0x90241c7db5467567, 0xb5d575fb5e74f734),
+    (0x38f1180206, 0x9ae17a7b1fef6a14, 0x57141fdde005ed98),
+    (0x0189b05913, 0xf9128aab6a021229, 0x2f3f341770fba7d0),
+    (0x04a67adda5, 0x8df781140cfe7c4e, 0x9c3e7636487205a0),
+    (0x268bd213a8, 0x90e849fdb3626a1a, 0xe8a0b10d0224ad46),
+    (0x273f51b721, 0x4557c733af863507, 0x4d0601b01ee7747f),
+    (0x07bf19326e, 0x01ba4d747afc7ac7, 0x5866e777eef827ef),
+    (0x199ce9c749, 0x71881539062c3101, 0x14dd950bd5bd2527),
+    (0x1119929c56, 0x4978e4bf9920791c, 0x3afc81ff7ab754ed),
+    (0x14171b256b, 0x280bc845fc23f467, 0x538ba9b02c21fc9f),
+    (0x18f3055bd7, 0xe73d2fe93542fa0b, 0xe20b647d6b6d04e3),
+    (0x3244d6914f, 0xd95e7df2018bfb60, 0x587fb6791bf44807),
+    (0x1297608b63, 0x1951265ce0b4b1b6, 0x7447ad9b9b9060e7),
+    (0x1712c120a2, 0x83ee1af688c86fe1, 0xdb72a3ef62c54154),
+    (0x25d6ab798c, 0xeb1b024a83b9a5e7, 0x3f1fc4a73a410995),
+    (0x20cf63d949, 0xdcfdd2da9590c33d, 0xe831fc4c4c26ace0),
+    (0x113297b8a9, 0x8056d018f1d00c6e, 0xcc86ed31fbdaeb2d),
+    (0x277710f573, 0x6ef018d32d998f37, 0xac46669f7eaa9a06),
+    (0x1ae05d27b1, 0x0e22745d2733c9f0, 0x0b1d5e12867fd247),
+    (0x29395026b2, 0xa7e405a0aa4ddd1e, 0x085efc7bb5d4f10f),
+    (0x2998b784f1, 0x56572b2e60789fef, 0x588d13217beb8cc0),
+    (0x3830d27584, 0x72346d8b8d96a425, 0x8acc40eaa50692ed),
+    (0x1ed6674ae6, 0x6e05022c4318315a, 0x4c68f0081607a8e4),
+    (0x35a6af2953, 0x75cddcde96df66bd, 0x0f5765ea306be800),
+    (0x136e9d2ab2, 0x9af3e21da7558d2b, 0x3e74c996b5100000),
+    (0x2995b45a17, 0x4bd2c70964cc9bff, 0x2774867ea0000000),
+    (0x056c8f927f, 0x36ef349318acec3a, 0x092c2a4000000000),
+    (0x1d9e948e5c, 0x46a8c3efed6c7d8b, 0x03d6800000000000),
+    (0x157c14366f, 0x54a8258a2a926f90, 0x4100000000000000),
+    (0x19ebd3869a, 0x1a8c6164e7c9ed4a, 0x0000000000000000),
+    (0x23e14bebd7, 0x9cf8980e21c06400, 0x0000000000000000),
+    (0x32338bda72, 0xe242cb69f6e80000, 0x0000000000000000),
+    (0x1abf9c2798, 0x7e279c6310000000, 0x0000000000000000),
+    (0x238147586a, 0x29adcaa000000000, 0x0000000000000000),
+    (0x18b7cbe521, 0xe622400000000000, 0x0000000000000000),
+    (0x07e48914f1, 0x8680000000000000, 0x0000000000000000),
+    (0x383c08fd21, 0x0000000000000000, 0x0000000000000000),
+    (0x07aef40a00, 0x0000000000000000, 0x0000000000000000),
+];
+
+/// The number of additional bits in `POW10_SPLIT` and `POW10_SPLIT_2`, so that the shift amount
+/// is `POW10_ADDITIONAL_BITS` plus the remainder of the exponent modulo 16.
+#[doc(hidden)] pub const POW10_ADDITIONAL_BITS: i32 = 120;
+
+/// Returns `floor(m * mul / 2^j) % 10^9` where `mul` is a 192-bit `(hi, mid, lo)` triple
+/// and `128 <= j < 192`.
+fn mul_shift_mod1e9(m: u64, mul: (u64, u64, u64), j: i32) -> u32 {
+    debug_assert!(128 <= j && j < 192);
+    let (hi, mid, lo) = mul;
+    let b0 = m as u128 * lo as u128;
+    let b1 = m as u128 * mid as u128;
+    let b2 = m as u128 * hi as u128;
+    let s = (((b0 >> 64) + b1) >> 64) + b2; // == floor(m * mul / 2^128)
+    ((s >> (j - 128) as usize) % 1000000000) as u32
+}
+
+/// Returns the `b`-th block of nine digits of `m * 2^e`, i.e. `floor(m * 2^e / 10^9b) % 10^9`.
+/// `b` can be negative for the fractional part. `m` should be odd and below `2^53`.
+#[doc(hidden)]
+pub fn block(m: u64, e: i32, b: i32) -> u32 {
+    if b >= 0 {
+        if e >= 0 {
+            let idx = ((e + 15) / 16) as usize;
+            let p = POW10_OFFSET[idx] as usize + b as usize;
+            if p >= POW10_OFFSET[idx + 1] as usize { return 0; }
+            // `j` is between 120 and 135; shifting `m` by 8 bits makes it at least 128.
+            let j = 16 * idx as i32 + POW10_ADDITIONAL_BITS - e;
+            mul_shift_mod1e9(m << 8, POW10_SPLIT[p], j + 8)
+        } else {
+            let mut int = if e > -64 { m >> (-e) as usize } else { 0 };
+            for _ in 0..b { int /= 1000000000; }
+            (int % 1000000000) as u32
+        }
+    } else {
+        if e >= 0 { return 0; }
+        let idx = (-e / 16) as usize;
+        let i = (-b - 1) as usize;
+        let minblock = MIN_BLOCK_2[idx] as usize;
+        if i < minblock { return 0; }
+        let p = POW10_OFFSET_2[idx] as usize + (i - minblock);
+        if p >= POW10_OFFSET_2[idx + 1] as usize { return 0; }
+        let j = POW10_ADDITIONAL_BITS - e - 16 * idx as i32;
+        mul_shift_mod1e9(m << 8, POW10_SPLIT_2[p], j + 8)
+    }
+}
+
+/// The exact and fixed mode implementation for Ryu printf.
+/// `Decoded` with an odd part of the mantissa of `2^53` or more,
+/// or with an exponent beyond `f64`, is formatted by Dragon instead.
+pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (/*#digits*/ usize, /*exp*/ i16) {
+    use flt2dec::strategy::dragon::format_exact as fallback;
+
+    assert!(d.mant > 0);
+
+    // the number `v` to format is `m * 2^e`. make `m` odd so that the tables can be used;
+    // this also gives the exact position of the last nonzero digit below.
+    let tz = d.mant.trailing_zeros();
+    let m = d.mant >> tz as usize;
+    let e = d.exp as i32 + tz as i32;
+    // the tables are only precise enough for `f64` and narrower types.
+    if m >= (1 << 53) || e < -1074 || e > 1023 {
+        return fallback(d, buf, limit);
+    }
+    let m = m as u64;
+
+    // the last nonzero digit is at `10^lowest`. `v` has exactly `-e` fractional digits
+    // when `e < 0`, otherwise it has as many trailing zeroes as factors of both 2 and 5.
+    let lowest = if e < 0 {
+        e
+    } else {
+        let mut fives = 0;
+        let mut m = m;
+        while m % 5 == 0 { m /= 5; fives += 1; }
+        cmp::min(e, fives)
+    };
+
+    // find the first nonzero block, and calculate `k` so that `10^(k-1) <= v < 10^k`.
+    let mut b = if e >= 0 {
+        let idx = ((e + 15) / 16) as usize;
+        (POW10_OFFSET[idx + 1] - POW10_OFFSET[idx]) as i32 - 1
+    } else {
+        1 // the integral part is below `2^53 < 10^18`
+    };
+    let mut digits = block(m, e, b);
+    while digits == 0 {
+        b -= 1;
+        digits = block(m, e, b);
+    }
+    let mut k = 9 * b + 1;
+    while k < 9 * b + 9 && digits >= POW10[(k - 9 * b) as usize] { k += 1; }
+
+    // if we are working with the last-digit limitation, we need to shorten the buffer
+    // before the actual rendering in order to avoid double rounding.
+    // note that we have to enlarge the buffer again when rounding up happens!
+    let limit = limit as i32;
+    let mut len = if k <= limit {
+        // oops, we cannot even produce *one* digit. we still look at the first digit
+        // for rounding, since something like 9.5 can be rounded to 10.
+        0
+    } else if ((k - limit) as usize) < buf.len() {
+        (k - limit) as usize
+    } else {
+        buf.len()
+    };
+
+    // render the digits at `10^(k-1)` down to `10^(k-len)`, and the following digit
+    // at `10^(k-len-1)` for rounding. digits below `10^lowest` are all zeroes and
+    // do not need any calculation.
+    let end = k - len as i32;
+    let stop = cmp::max(end - 1, lowest);
+    let mut next = b'0';
+    let mut top = k - 1; // the highest place yet to be rendered
+    while top >= stop {
+        let mut tmp = [b'0'; 9]; // `tmp[8-t]` is the digit at `10^(9b+t)`
+        for c in tmp.iter_mut().rev() {
+            *c = b'0' + (digits % 10) as u8;
+            digits /= 10;
+        }
+        for place in (cmp::max(9 * b, stop)..top + 1).rev() {
+            let c = tmp[(9 * b + 8 - place) as usize];
+            if place >= end {
+                buf[(k - 1 - place) as usize] = c;
+            } else {
+                next = c;
+            }
+        }
+        top = 9 * b - 1;
+        b -= 1;
+        if top >= stop { digits = block(m, e, b); }
+    }
+    for place in end..cmp::min(lowest, k) {
+        buf[(k - 1 - place) as usize] = b'0';
+    }
+
+    // rounding up if we stop in the middle of digits.
+    // if the following digits are exactly 5000..., check the prior digit and try to
+    // round to even (i.e. avoid rounding up when the prior digit is even), as Dragon does.
+    if next > b'5' || (next == b'5' && (lowest < end - 1 || len == 0 || buf[len-1] & 1 == 1)) {
+        // if rounding up changes the length, the exponent should also change.
+        // but we've been requested a fixed number of digits, so do not alter the buffer...
+        if let Some(c) = round_up(buf, len) {
+            // ...unless we've been requested the fixed precision instead.
+            // we also need to check that, if the original buffer was empty,
+            // the additional digit can only be added when `k == limit` (edge case).
+            k += 1;
+            if k > limit && len < buf.len() {
+                buf[len] = c;
+                len += 1;
+            }
+        }
+    }
+
+    (len, k as i16)
+}
+
+// FIXME(#22540) const ref to static array seems to ICE
+static POW10: [u32; 9] = [1, 10, 100, 1000, 10000, 100000, 1000000, 10000000, 100000000];
@@ -8,6 +8,20 @@ fn test_from_u64_overflow() {
     Big::from_u64(0x1000000);
 }
 
+#[test]
+fn test_from_digits() {
+    assert_eq!(Big::from_digits(&[]), Big::from_small(0));
+    assert_eq!(Big::from_digits(&[0x56, 0x34, 0x12]), Big::from_u64(0x123456));
+    assert_eq!(Big::from_digits(&[0x34, 0x12, 0]), Big::from_u64(0x1234));
+    assert_eq!(*Big::from_digits(&[0xff, 0xff]).add(&Big::from_small(1)), Big::from_u64(0x10000));
+}
+
+#[test]
+#[should_panic]
+fn test_from_digits_overflow() {
+    Big::from_digits(&[0, 0, 0, 1]);
+}
+
 #[test]
 fn test_add() {
     assert_eq!(*Big::from_small(3).add(&Big::from_small(4)), Big::from_small(7));
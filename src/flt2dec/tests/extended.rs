@@ -0,0 +1,256 @@
+use std::prelude::v1::*;
+use std::{str, iter, i16, f64};
+use std::num::FpCategory;
+
+use flt2dec::{decode, DecodableFloat, FullDecoded, Decoded};
+use flt2dec::extended::{F80, DoubleDouble};
+use flt2dec::strategy::dragon::format_exact;
+use super::decode_finite;
+
+#[test]
+fn test_f80_decode() {
+    assert_eq!(decode(F80(0x0000, 0)), (false, FullDecoded::Zero));
+    assert_eq!(decode(F80(0x8000, 0)), (true, FullDecoded::Zero));
+    assert_eq!(decode(F80(0x7fff, 0x8000000000000000)), (false, FullDecoded::Infinite));
+    assert_eq!(decode(F80(0xffff, 0x8000000000000000)), (true, FullDecoded::Infinite));
+    assert_eq!(decode(F80(0x7fff, 0xc000000000000000)).1, FullDecoded::Nan);
+    assert_eq!(decode(F80(0x7fff, 0x8000000000000001)).1, FullDecoded::Nan);
+
+    // 1.0, whose predecessor is closer than its successor
+    assert_eq!(decode_finite(F80(0x3fff, 0x8000000000000000)),
+               Decoded { mant: 1 << 65, minus: 1, plus: 2, exp: -65, inclusive: true });
+    assert_eq!(decode(F80(0xbfff, 0x8000000000000000)).0, true);
+    assert_eq!(decode_finite(F80(0x3fff, 0x8000000000000001)),
+               Decoded { mant: (1 << 64) + 2, minus: 1, plus: 1, exp: -64, inclusive: false });
+    assert_eq!(decode_finite(F80(0x7ffe, 0xffffffffffffffff)),
+               Decoded { mant: (1 << 65) - 2, minus: 1, plus: 1, exp: 16319, inclusive: false });
+    assert_eq!(decode_finite(F80(0x0001, 0x8000000000000000)),
+               Decoded { mant: 1 << 65, minus: 1, plus: 2, exp: -16447, inclusive: true });
+    assert_eq!(decode_finite(F80(0x0000, 0x7fffffffffffffff)),
+               Decoded { mant: (1 << 64) - 2, minus: 1, plus: 1, exp: -16446, inclusive: true });
+    assert_eq!(decode_finite(F80(0x0000, 0x0000000000000001)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -16446, inclusive: true });
+}
+
+#[test]
+fn test_f80_invalid_encodings() {
+    // pseudo-denormals are same to the normal numbers with the exponent of 1
+    assert_eq!(F80(0x0000, 0x8000000000000001).classify(), FpCategory::Normal);
+    assert_eq!(decode(F80(0x0000, 0x8000000000000001)),
+               decode(F80(0x0001, 0x8000000000000001)));
+    assert_eq!(decode(F80(0x8000, 0xc000000000000000)),
+               decode(F80(0x8001, 0xc000000000000000)));
+
+    // unnormals, pseudo-infinities and pseudo-NaNs
+    assert_eq!(decode(F80(0x3fff, 0x0000000000000001)).1, FullDecoded::Nan);
+    assert_eq!(decode(F80(0x0001, 0x0000000000000000)).1, FullDecoded::Nan);
+    assert_eq!(decode(F80(0x7ffe, 0x7fffffffffffffff)).1, FullDecoded::Nan);
+    assert_eq!(decode(F80(0x7fff, 0x0000000000000000)).1, FullDecoded::Nan);
+    assert_eq!(decode(F80(0xffff, 0x4000000000000000)).1, FullDecoded::Nan);
+}
+
+#[test]
+fn test_f80_ldexpi() {
+    fn ldexpi(f: i64, exp: isize) -> F80 { DecodableFloat::ldexpi(f, exp) }
+
+    assert_eq!(ldexpi(0, 100), F80(0x0000, 0));
+    assert_eq!(ldexpi(1, 0), F80(0x3fff, 0x8000000000000000));
+    assert_eq!(ldexpi(-3, -1), F80(0xbfff, 0xc000000000000000));
+    assert_eq!(ldexpi(i64::max_value(), 0), F80(0x403d, 0xfffffffffffffffe));
+    assert_eq!(ldexpi(i64::min_value(), 0), F80(0xc03e, 0x8000000000000000));
+
+    // overflows and underflows, with ties to even
+    assert_eq!(ldexpi(1, 16383), F80(0x7ffe, 0x8000000000000000));
+    assert_eq!(ldexpi(1, 16384), F80(0x7fff, 0x8000000000000000));
+    assert_eq!(ldexpi(-1, 20000), F80(0xffff, 0x8000000000000000));
+    assert_eq!(ldexpi(1, -16382), F80(0x0001, 0x8000000000000000));
+    assert_eq!(ldexpi(1, -16383), F80(0x0000, 0x4000000000000000));
+    assert_eq!(ldexpi(1, -16445), F80(0x0000, 1));
+    assert_eq!(ldexpi(1, -16446), F80(0x0000, 0));
+    assert_eq!(ldexpi(3, -16446), F80(0x0000, 2));
+    assert_eq!(ldexpi(-3, -16447), F80(0x8000, 1));
+    assert_eq!(ldexpi(i64::max_value(), -16446), F80(0x0000, 0x4000000000000000));
+    assert_eq!(ldexpi(1, -20000), F80(0x0000, 0));
+}
+
+#[test]
+fn test_dd_decode() {
+    assert_eq!(decode(DoubleDouble(0.0, 0.0)), (false, FullDecoded::Zero));
+    assert_eq!(decode(DoubleDouble(-0.0, 0.0)), (true, FullDecoded::Zero));
+    assert_eq!(decode(DoubleDouble(1.0 / 0.0, 0.0)), (false, FullDecoded::Infinite));
+    assert_eq!(decode(DoubleDouble(-1.0 / 0.0, 0.0)), (true, FullDecoded::Infinite));
+    assert_eq!(decode(DoubleDouble(0.0 / 0.0, 0.0)).1, FullDecoded::Nan);
+
+    // 1.0, whose predecessor is closer than its successor
+    assert_eq!(decode_finite(DoubleDouble(1.0, 0.0)),
+               Decoded { mant: 1 << 108, minus: 1, plus: 2, exp: -108, inclusive: true });
+    assert_eq!(decode(DoubleDouble(-1.0, 0.0)).0, true);
+    assert_eq!(decode(DoubleDouble(-1.0, 1e-20)).0, true);
+    assert_eq!(decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(1, -106))),
+               Decoded { mant: (1 << 107) + 2, minus: 1, plus: 1, exp: -107, inclusive: false });
+    assert_eq!(decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(-1, -107))),
+               Decoded { mant: (1 << 108) - 2, minus: 1, plus: 1, exp: -108, inclusive: false });
+
+    // `lo` is rounded to 107 bits with ties to even
+    assert_eq!(decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(3, -107))),
+               decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(1, -105))));
+    assert_eq!(decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(1, -107))),
+               decode_finite(DoubleDouble(1.0, 0.0)));
+    assert_eq!(decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(-1, -108))),
+               decode_finite(DoubleDouble(1.0, 0.0)));
+    assert_eq!(decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(-3, -109))),
+               decode_finite(DoubleDouble(1.0, DecodableFloat::ldexpi(-1, -107))));
+
+    // numbers below `2^-968` are subnormal
+    assert_eq!(DoubleDouble(DecodableFloat::ldexpi(1, -968), 0.0).classify(),
+               FpCategory::Normal);
+    assert_eq!(DoubleDouble(DecodableFloat::ldexpi(1, -968), -5e-324).classify(),
+               FpCategory::Subnormal);
+    assert_eq!(decode_finite(DoubleDouble(DecodableFloat::ldexpi(1, -968), -5e-324)),
+               Decoded { mant: (1 << 107) - 2, minus: 1, plus: 1, exp: -1075, inclusive: true });
+    assert_eq!(decode_finite(DoubleDouble(5e-324, 0.0)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -1075, inclusive: true });
+}
+
+#[test]
+fn test_dd_non_normalized() {
+    assert_eq!(decode(DoubleDouble(0.0, 1.0)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.0, 1.0)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.0, 0.0 / 0.0)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.0, 1.0 / 0.0)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.0 / 0.0, 1.0)).1, FullDecoded::Infinite);
+
+    // `|lo| <= ulp(hi) / 2`, where the equality is allowed
+    let halfulp: f64 = DecodableFloat::ldexpi(1, -53);
+    assert!(decode(DoubleDouble(1.5, halfulp)).1 != FullDecoded::Nan);
+    assert!(decode(DoubleDouble(1.5, -halfulp)).1 != FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.5, halfulp * 1.5)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.5, -halfulp * 2.0)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(5e-324, 5e-324)).1, FullDecoded::Nan);
+
+    // the unit below a power of two is halved, so `|lo| <= ulp(hi) / 4` for the opposite sign
+    assert!(decode(DoubleDouble(1.0, halfulp)).1 != FullDecoded::Nan);
+    assert!(decode(DoubleDouble(1.0, -halfulp / 2.0)).1 != FullDecoded::Nan);
+    assert!(decode(DoubleDouble(-1.0, halfulp / 2.0)).1 != FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.0, -halfulp * 0.75)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(1.0, -halfulp)).1, FullDecoded::Nan);
+    assert_eq!(decode(DoubleDouble(-1.0, halfulp)).1, FullDecoded::Nan);
+}
+
+#[test]
+fn test_dd_ldexpi() {
+    fn ldexpi(f: i64, exp: isize) -> DoubleDouble { DecodableFloat::ldexpi(f, exp) }
+
+    assert_eq!(ldexpi(0, 100), DoubleDouble(0.0, 0.0));
+    assert_eq!(ldexpi(-3, -1), DoubleDouble(-1.5, 0.0));
+    assert_eq!(ldexpi(i64::max_value(), 0), DoubleDouble(9223372036854775808.0, -1.0));
+    assert_eq!(ldexpi((1 << 60) + 1, -60), DoubleDouble(1.0, DecodableFloat::ldexpi(1, -60)));
+    assert_eq!(decode(ldexpi(-(1 << 62) - 1, 3)),
+               decode(DoubleDouble(-36893488147419103232.0, -8.0)));
+}
+
+/// Emulates C's `printf("%.*Lg", prec, v)` for finite `v`.
+fn to_g_str(v: F80, prec: usize) -> String {
+    let prec = if prec == 0 { 1 } else { prec };
+    let (negative, full_decoded) = decode(v);
+    let mut buf = [0; 64];
+    let (len, k) = match full_decoded {
+        FullDecoded::Zero => { buf[0] = b'0'; (1, 1) }
+        FullDecoded::Finite(ref decoded) => format_exact(decoded, &mut buf[..prec], i16::MIN),
+        _ => panic!("expected finite, got {:?} instead", full_decoded),
+    };
+    let digits = str::from_utf8(&buf[..len]).unwrap();
+    let exp = k as i32 - 1;
+
+    let mut s = String::new();
+    if negative { s.push('-'); }
+    let (int, frac) = if exp < -4 || exp >= prec as i32 {
+        (digits[..1].to_string(), digits[1..].to_string())
+    } else if exp < 0 {
+        let zeroes: String = iter::repeat('0').take((-exp - 1) as usize).collect();
+        ("0".to_string(), format!("{}{}", zeroes, digits))
+    } else {
+        (digits[..exp as usize + 1].to_string(), digits[exp as usize + 1..].to_string())
+    };
+    s.push_str(&int);
+    let frac = frac.trim_right_matches('0');
+    if !frac.is_empty() {
+        s.push('.');
+        s.push_str(frac);
+    }
+    if exp < -4 || exp >= prec as i32 {
+        s.push_str(&format!("e{}{:02}", if exp < 0 { '-' } else { '+' }, exp.abs()));
+    }
+    s
+}
+
+#[test]
+fn test_f80_glibc() {
+    // generated from `printf("%Lg %.21Lg %.30Lg %.3Lg %.0Lg", v, v, v, v, v)` in glibc 2.36
+    // (glibc treats pseudo-denormals differently, so they are not included)
+    let cases: &[(F80, [&str; 5])] = &[
+        (F80(0x3ffb, 0xcccccccccccccccd),
+         ["0.1", "0.100000000000000000001", "0.100000000000000000001355252716", "0.1", "0.1"]),
+        (F80(0x3ffd, 0xaaaaaaaaaaaaaaab),
+         ["0.333333", "0.333333333333333333342", "0.333333333333333333342368351437",
+          "0.333", "0.3"]),
+        (F80(0x4000, 0xc90fdaa22168c235),
+         ["3.14159", "3.14159265358979323851", "3.14159265358979323851280895941", "3.14", "3"]),
+        (F80(0x4000, 0xadf85458a2bb4a9b),
+         ["2.71828", "2.71828182845904523543", "2.71828182845904523542816810799", "2.72", "3"]),
+        (F80(0x3ffe, 0x8000000000000000), ["0.5", "0.5", "0.5", "0.5", "0.5"]),
+        (F80(0x403f, 0x8000000000000000),
+         ["1.84467e+19", "18446744073709551616", "18446744073709551616", "1.84e+19", "2e+19"]),
+        (F80(0x403d, 0xfffffffffffffffe),
+         ["9.22337e+18", "9223372036854775807", "9223372036854775807", "9.22e+18", "9e+18"]),
+        (F80(0x400f, 0xf120000000000000), ["123456", "123456", "123456", "1.23e+05", "1e+05"]),
+        (F80(0x4013, 0x96b4380000000000),
+         ["1.23457e+06", "1234567", "1234567", "1.23e+06", "1e+06"]),
+        (F80(0x3ff1, 0xd1b71758e219652c),
+         ["0.0001", "0.000100000000000000000001", "0.000100000000000000000001143494479",
+          "0.0001", "0.0001"]),
+        (F80(0x3fee, 0xa7c5ac471b478423),
+         ["1e-05", "9.99999999999999999995e-06", "9.99999999999999999994891332537e-06",
+          "1e-05", "1e-05"]),
+        (F80(0x4002, 0x9fffff79c842fa51),
+         ["10", "9.99999950000000000037", "9.9999995000000000003673172877", "10", "1e+01"]),
+        (F80(0x73e6, 0xd1ba8323fe558c61),
+         ["1e+4000", "9.99999999999999999997e+3999", "9.9999999999999999999654638731e+3999",
+          "1e+4000", "1e+4000"]),
+        (F80(0x8c17, 0x9c3d73864f3805c0),
+         ["-1e-4000", "-9.99999999999999999987e-4001", "-9.99999999999999999987257660378e-4001",
+          "-1e-4000", "-1e-4000"]),
+        (F80(0x7ffe, 0xffffffffffffffff),
+         ["1.18973e+4932", "1.18973149535723176502e+4932",
+          "1.18973149535723176502126385303e+4932", "1.19e+4932", "1e+4932"]),
+        (F80(0x0001, 0x8000000000000000),
+         ["3.3621e-4932", "3.36210314311209350626e-4932",
+          "3.36210314311209350626267781732e-4932", "3.36e-4932", "3e-4932"]),
+        (F80(0x0000, 0x7fffffffffffffff),
+         ["3.3621e-4932", "3.3621031431120935059e-4932",
+          "3.36210314311209350589815786413e-4932", "3.36e-4932", "3e-4932"]),
+        (F80(0x0000, 0x0000000000000001),
+         ["3.6452e-4951", "3.64519953188247460253e-4951",
+          "3.64519953188247460252840593362e-4951", "3.65e-4951", "4e-4951"]),
+        (F80(0x8000, 0x0000000000000000), ["-0", "-0", "-0", "-0", "-0"]),
+    ];
+    for &(v, ref expected) in cases {
+        for (&prec, &expected) in [6, 21, 30, 3, 0].iter().zip(expected.iter()) {
+            assert_eq!((v, prec, to_g_str(v, prec)), (v, prec, expected.to_string()));
+        }
+    }
+}
+
+#[test]
+fn test_dd_dragon_consistency() {
+    // the exact mode for `DoubleDouble(x, 0.0)` should agree with that for `f64` value `x`
+    let mut buf1 = [0; 1024];
+    let mut buf2 = [0; 1024];
+    for &x in &[1.0, 0.1, 1.0 / 3.0, 3.141592, 1.0e23, f64::MAX, f64::MIN_POSITIVE, 5e-324] {
+        let d1 = decode_finite(x);
+        let d2 = decode_finite(DoubleDouble(x, 0.0));
+        assert_eq!(format_exact(&d1, &mut buf1, i16::MIN),
+                   format_exact(&d2, &mut buf2, i16::MIN));
+        assert_eq!(&buf1[..], &buf2[..]);
+    }
+}
@@ -0,0 +1,135 @@
+use std::prelude::v1::*;
+use std::num::FpCategory;
+
+use flt2dec::{decode, DecodableFloat, FullDecoded, Decoded};
+use flt2dec::{MAX_SIG_DIGITS, MAX_SIG_DIGITS_F16, MAX_SIG_DIGITS_BF16};
+use flt2dec::half::{F16, Bf16};
+use flt2dec::strategy::dragon::format_shortest;
+use super::decode_finite;
+
+#[test]
+fn test_f16_decode() {
+    assert_eq!(decode(F16(0x0000)), (false, FullDecoded::Zero));
+    assert_eq!(decode(F16(0x8000)), (true, FullDecoded::Zero));
+    assert_eq!(decode(F16(0x7c00)), (false, FullDecoded::Infinite));
+    assert_eq!(decode(F16(0xfc00)), (true, FullDecoded::Infinite));
+    assert_eq!(decode(F16(0x7e00)).1, FullDecoded::Nan);
+    assert_eq!(decode(F16(0xfc01)).1, FullDecoded::Nan);
+
+    // 1.0, whose predecessor is closer than its successor
+    assert_eq!(decode_finite(F16(0x3c00)),
+               Decoded { mant: 4096, minus: 1, plus: 2, exp: -12, inclusive: true });
+    assert_eq!(decode(F16(0xbc00)).0, true);
+    assert_eq!(decode_finite(F16(0x3c01)),
+               Decoded { mant: 2050, minus: 1, plus: 1, exp: -11, inclusive: false });
+    assert_eq!(decode_finite(F16(0x7bff)),
+               Decoded { mant: 4094, minus: 1, plus: 1, exp: 4, inclusive: false });
+    assert_eq!(decode_finite(F16(0x0400)),
+               Decoded { mant: 4096, minus: 1, plus: 2, exp: -26, inclusive: true });
+    assert_eq!(decode_finite(F16(0x03ff)),
+               Decoded { mant: 2046, minus: 1, plus: 1, exp: -25, inclusive: true });
+    assert_eq!(decode_finite(F16(0x0001)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -25, inclusive: true });
+}
+
+#[test]
+fn test_bf16_decode() {
+    assert_eq!(decode(Bf16(0x0000)), (false, FullDecoded::Zero));
+    assert_eq!(decode(Bf16(0x8000)), (true, FullDecoded::Zero));
+    assert_eq!(decode(Bf16(0x7f80)), (false, FullDecoded::Infinite));
+    assert_eq!(decode(Bf16(0xff80)), (true, FullDecoded::Infinite));
+    assert_eq!(decode(Bf16(0x7fc0)).1, FullDecoded::Nan);
+    assert_eq!(decode(Bf16(0xff81)).1, FullDecoded::Nan);
+
+    assert_eq!(decode_finite(Bf16(0x3f80)),
+               Decoded { mant: 512, minus: 1, plus: 2, exp: -9, inclusive: true });
+    assert_eq!(decode(Bf16(0xbf80)).0, true);
+    assert_eq!(decode_finite(Bf16(0x3f81)),
+               Decoded { mant: 258, minus: 1, plus: 1, exp: -8, inclusive: false });
+    assert_eq!(decode_finite(Bf16(0x7f7f)),
+               Decoded { mant: 510, minus: 1, plus: 1, exp: 119, inclusive: false });
+    assert_eq!(decode_finite(Bf16(0x0080)),
+               Decoded { mant: 512, minus: 1, plus: 2, exp: -135, inclusive: true });
+    assert_eq!(decode_finite(Bf16(0x007f)),
+               Decoded { mant: 254, minus: 1, plus: 1, exp: -134, inclusive: true });
+    assert_eq!(decode_finite(Bf16(0x0001)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -134, inclusive: true });
+}
+
+#[test]
+fn test_f16_from_f64() {
+    assert_eq!(F16::from_f64(1.0), F16(0x3c00));
+    assert_eq!(F16::from_f64(-2.0), F16(0xc000));
+    assert_eq!(F16::from_f64(0.1), F16(0x2e66));
+    assert_eq!(F16::from_f64(-0.0), F16(0x8000));
+    assert_eq!(F16::from_f64(1.0 / 0.0), F16(0x7c00));
+    assert_eq!(F16::from_f64(0.0 / 0.0).classify(), FpCategory::Nan);
+
+    // ties to even
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(2049, -11)), F16(0x3c00));
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(2051, -11)), F16(0x3c02));
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(4097, -12)), F16(0x3c00));
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(4099, -12)), F16(0x3c01));
+
+    // overflows and underflows
+    assert_eq!(F16::from_f64(65519.99), F16(0x7bff));
+    assert_eq!(F16::from_f64(65520.0), F16(0x7c00));
+    assert_eq!(F16::from_f64(1.0e300), F16(0x7c00));
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(1, -25)), F16(0x0000));
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(3, -26)), F16(0x0001));
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(-3, -25)), F16(0x8002));
+    assert_eq!(F16::from_f64(DecodableFloat::ldexpi(2047, -25)), F16(0x0400));
+    assert_eq!(F16::from_f64(1.0e-300), F16(0x0000));
+}
+
+#[test]
+fn test_bf16_from_f64() {
+    assert_eq!(Bf16::from_f64(1.0), Bf16(0x3f80));
+    assert_eq!(Bf16::from_f64(-2.0), Bf16(0xc000));
+    assert_eq!(Bf16::from_f64(0.1), Bf16(0x3dcd));
+    assert_eq!(Bf16::from_f64(-0.0), Bf16(0x8000));
+    assert_eq!(Bf16::from_f64(1.0 / 0.0), Bf16(0x7f80));
+    assert_eq!(Bf16::from_f64(0.0 / 0.0).classify(), FpCategory::Nan);
+
+    // ties to even
+    assert_eq!(Bf16::from_f64(DecodableFloat::ldexpi(257, -8)), Bf16(0x3f80));
+    assert_eq!(Bf16::from_f64(DecodableFloat::ldexpi(259, -8)), Bf16(0x3f82));
+
+    // overflows and underflows
+    assert_eq!(Bf16::from_f64(DecodableFloat::ldexpi(511, 119)), Bf16(0x7f80));
+    assert_eq!(Bf16::from_f64(DecodableFloat::ldexpi(1021, 118)), Bf16(0x7f7f));
+    assert_eq!(Bf16::from_f64(DecodableFloat::ldexpi(1, -134)), Bf16(0x0000));
+    assert_eq!(Bf16::from_f64(DecodableFloat::ldexpi(3, -135)), Bf16(0x0001));
+    assert_eq!(Bf16::from_f64(DecodableFloat::ldexpi(255, -134)), Bf16(0x0080));
+}
+
+#[test]
+fn test_f64_roundtrip() {
+    for i in 0..0x10000 {
+        let x = F16(i as u16);
+        if x.classify() == FpCategory::Nan {
+            assert_eq!(F16::from_f64(x.to_f64()).classify(), FpCategory::Nan);
+        } else {
+            assert_eq!(F16::from_f64(x.to_f64()), x);
+        }
+
+        let x = Bf16(i as u16);
+        if x.classify() == FpCategory::Nan {
+            assert_eq!(Bf16::from_f64(x.to_f64()).classify(), FpCategory::Nan);
+        } else {
+            assert_eq!(Bf16::from_f64(x.to_f64()), x);
+        }
+    }
+}
+
+#[test]
+fn test_max_sig_digits() {
+    // `MAX_SIG_DIGITS_*` should be tight, i.e. some shortest result has exactly that many digits
+    fn max_len<V: FnMut(usize) -> Decoded>(n: usize, mut v: V) -> usize {
+        let mut buf = [0; MAX_SIG_DIGITS];
+        (0..n).map(|i| format_shortest(&v(i), &mut buf).0).max().unwrap()
+    }
+
+    assert_eq!(max_len(0x7bff, |i| decode_finite(F16(i as u16 + 1))), MAX_SIG_DIGITS_F16);
+    assert_eq!(max_len(0x7f7f, |i| decode_finite(Bf16(i as u16 + 1))), MAX_SIG_DIGITS_BF16);
+}
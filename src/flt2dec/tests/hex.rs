@@ -0,0 +1,176 @@
+use std::prelude::v1::*;
+use std::{str, mem, fmt, f32, f64};
+use rand;
+use rand::distributions::{IndependentSample, Range};
+use libc;
+
+use flt2dec::{to_hex_str, DecodableFloat, Part, Sign};
+use super::to_string_with_parts;
+
+extern {
+    fn snprintf(buf: *mut libc::c_char, len: libc::size_t,
+                fmt: *const libc::c_char, ...) -> libc::c_int;
+}
+
+fn to_string<T: DecodableFloat>(v: T, sign: Sign, frac_digits: Option<usize>,
+                                upper: bool) -> String {
+    to_string_with_parts(|buf, parts| to_hex_str(v, sign, frac_digits, upper, buf, parts))
+}
+
+// `%a` of glibc, which is the reference for the choice of the leading digit.
+// `f32` values are promoted to `f64`, so only normal `f32` values can be compared.
+fn libc_to_string(v: f64, frac_digits: Option<usize>, upper: bool) -> String {
+    let mut buf = [0u8; 64];
+    let len = unsafe {
+        match (frac_digits, upper) {
+            (None, false) => snprintf(buf.as_mut_ptr() as *mut _, buf.len() as libc::size_t,
+                                      b"%a\0".as_ptr() as *const _, v),
+            (None, true) => snprintf(buf.as_mut_ptr() as *mut _, buf.len() as libc::size_t,
+                                     b"%A\0".as_ptr() as *const _, v),
+            (Some(n), false) => snprintf(buf.as_mut_ptr() as *mut _, buf.len() as libc::size_t,
+                                         b"%.*a\0".as_ptr() as *const _, n as libc::c_int, v),
+            (Some(n), true) => snprintf(buf.as_mut_ptr() as *mut _, buf.len() as libc::size_t,
+                                        b"%.*A\0".as_ptr() as *const _, n as libc::c_int, v),
+        }
+    };
+    str::from_utf8(&buf[..len as usize]).unwrap().to_string()
+}
+
+#[test]
+fn to_hex_str_test() {
+    use flt2dec::Sign::*;
+
+    assert_eq!(to_string( 0.0f64, Minus,        None, false), "0x0p+0");
+    assert_eq!(to_string( 0.0f64, MinusRaw,     None, false), "0x0p+0");
+    assert_eq!(to_string( 0.0f64, MinusPlus,    None, false), "+0x0p+0");
+    assert_eq!(to_string( 0.0f64, MinusPlusRaw, None, false), "+0x0p+0");
+    assert_eq!(to_string(-0.0f64, Minus,        None, false), "0x0p+0");
+    assert_eq!(to_string(-0.0f64, MinusRaw,     None, false), "-0x0p+0");
+    assert_eq!(to_string(-0.0f64, MinusPlus,    None, false), "+0x0p+0");
+    assert_eq!(to_string(-0.0f64, MinusPlusRaw, None, false), "-0x0p+0");
+    assert_eq!(to_string( 0.0f64, Minus,     Some(0), false), "0x0p+0");
+    assert_eq!(to_string( 0.0f64, Minus,     Some(3),  true), "0X0.000P+0");
+
+    assert_eq!(to_string( 1.0f64/0.0, Minus,        None, false), "inf");
+    assert_eq!(to_string( 1.0f64/0.0, MinusPlus,    None,  true), "+INF");
+    assert_eq!(to_string(-1.0f64/0.0, Minus,     Some(3), false), "-inf");
+    assert_eq!(to_string( 0.0f64/0.0, Minus,        None, false), "nan");
+    assert_eq!(to_string( 0.0f64/0.0, MinusPlusRaw, None,  true), "NAN");
+
+    assert_eq!(to_string( 1.0f64, Minus,        None, false), "0x1p+0");
+    assert_eq!(to_string( 1.5f64, MinusPlus,    None, false), "+0x1.8p+0");
+    assert_eq!(to_string(-0.1f64, Minus,        None, false), "-0x1.999999999999ap-4");
+    assert_eq!(to_string(-0.1f64, Minus,        None,  true), "-0X1.999999999999AP-4");
+    assert_eq!(to_string( 0.1f32, Minus,        None, false), "0x1.99999ap-4");
+    assert_eq!(to_string( 1024.0f32, Minus,     None, false), "0x1p+10");
+
+    // rounding, ties to even
+    assert_eq!(to_string(1.5f64, Minus, Some(0), false), "0x2p+0");
+    assert_eq!(to_string(0.1f64, Minus, Some(0), false), "0x2p-4");
+    assert_eq!(to_string(0.1f64, Minus, Some(1), false), "0x1.ap-4");
+    assert_eq!(to_string(0.1f64, Minus, Some(3), false), "0x1.99ap-4");
+    assert_eq!(to_string(0.1f64, Minus, Some(15), true), "0X1.999999999999A00P-4");
+    assert_eq!(to_string(f64::ldexp(0x108 as f64, -8), Minus, Some(1), false), "0x1.0p+0");
+    assert_eq!(to_string(f64::ldexp(0x118 as f64, -8), Minus, Some(1), false), "0x1.2p+0");
+    assert_eq!(to_string(f64::ldexp(0x1f8 as f64, -8), Minus, Some(1), false), "0x2.0p+0");
+    assert_eq!(to_string(f64::ldexp(0x1f8 as f64, -8), Minus, Some(2), false), "0x1.f8p+0");
+    assert_eq!(to_string(0.1f32, Minus, Some(5), false), "0x1.9999ap-4");
+    assert_eq!(to_string(0.1f32, Minus, Some(6), false), "0x1.99999ap-4");
+    assert_eq!(to_string(0.1f32, Minus, Some(8), false), "0x1.99999a00p-4");
+
+    assert_eq!(to_string(f32::MAX, Minus, None, false), "0x1.fffffep+127");
+    assert_eq!(to_string(f32::MAX, Minus, Some(2), false), "0x2.00p+127");
+    assert_eq!(to_string(f64::MAX, Minus, None, false), "0x1.fffffffffffffp+1023");
+    assert_eq!(to_string(f64::MAX, Minus, Some(3), false), "0x2.000p+1023");
+
+    // subnormals share the minimum normal exponent
+    let minf32 = f32::ldexp(1.0, -149);
+    assert_eq!(to_string(minf32, Minus, None, false), "0x0.000002p-126");
+    assert_eq!(to_string(minf32, Minus, Some(3), false), "0x0.000p-126");
+    assert_eq!(to_string(f32::MIN_POSITIVE - minf32, Minus, None, false), "0x0.fffffep-126");
+    assert_eq!(to_string(f32::MIN_POSITIVE - minf32, Minus, Some(0), false), "0x1p-126");
+    assert_eq!(to_string(f32::MIN_POSITIVE, Minus, None, false), "0x1p-126");
+
+    let minf64 = f64::ldexp(1.0, -1074);
+    assert_eq!(to_string(minf64, Minus, None, false), "0x0.0000000000001p-1022");
+    assert_eq!(to_string(minf64, Minus, Some(0), false), "0x0p-1022");
+    assert_eq!(to_string(minf64, Minus, Some(15), false), "0x0.000000000000100p-1022");
+    assert_eq!(to_string(f64::MIN_POSITIVE / 2.0, Minus, None, false), "0x0.8p-1022");
+    assert_eq!(to_string(f64::MIN_POSITIVE - minf64, Minus, Some(3), false), "0x1.000p-1022");
+    assert_eq!(to_string(f64::MIN_POSITIVE, Minus, None, false), "0x1p-1022");
+}
+
+#[test]
+fn to_hex_str_parts_test() {
+    let mut buf = [0; 32];
+    let mut parts = [Part::Zero(0); 7];
+    let formatted = to_hex_str(-1.5f64, Sign::Minus, Some(15), false, &mut buf, &mut parts);
+    assert_eq!(formatted.sign, b"-");
+    assert_eq!(formatted.parts, &[Part::Copy(b"0x"), Part::Copy(b"1"), Part::Copy(b"."),
+                                  Part::Copy(b"8000000000000"), Part::Zero(2),
+                                  Part::Copy(b"p+"), Part::Num(0)][..]);
+}
+
+// `v` returns a value to be formatted and the same value as `f64` to be passed to libc.
+fn iterate_libc<T, V>(func: &str, n: usize, mut v: V)
+        where T: DecodableFloat + fmt::Debug, V: FnMut(usize) -> (T, f64) {
+    let mut npassed = 0;
+    for i in 0..n {
+        let (x, xf64) = v(i);
+        let frac_digits = if i % 4 == 0 { None } else { Some(i % 17) };
+        let upper = i % 3 == 0;
+        let expected = libc_to_string(xf64, frac_digits, upper);
+        let actual = to_string(x, Sign::Minus, frac_digits, upper);
+        if actual == expected {
+            npassed += 1;
+        } else {
+            println!("libc equivalence test failed, {:x}/{:x}: {:?} ({:?}) actual {:?} \
+                      expected {:?}", i, n, x, frac_digits, actual, expected);
+        }
+    }
+    println!("{}: done, passed={} failed={}", func, npassed, n - npassed);
+    assert!(npassed == n, "{}: {} out of {} values returns an incorrect value!",
+            func, n - npassed, n);
+}
+
+#[test]
+fn f64_random_libc_equivalence_test() {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0001u64, 0x7ff0_0000_0000_0000);
+    iterate_libc("f64_random_libc_equivalence_test", 10_000, |_| {
+        let i: u64 = f64_range.ind_sample(&mut rng);
+        let x: f64 = unsafe {mem::transmute(i)};
+        (x, x)
+    });
+}
+
+#[test]
+fn f64_subnormal_random_libc_equivalence_test() {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0001u64, 0x0010_0000_0000_0000);
+    iterate_libc("f64_subnormal_random_libc_equivalence_test", 10_000, |_| {
+        let i: u64 = f64_range.ind_sample(&mut rng);
+        let x: f64 = unsafe {mem::transmute(i)};
+        (x, x)
+    });
+}
+
+#[test]
+fn f32_random_libc_equivalence_test() {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f32_range = Range::new(0x0080_0000u32, 0x7f80_0000);
+    iterate_libc("f32_random_libc_equivalence_test", 10_000, |_| {
+        let i: u32 = f32_range.ind_sample(&mut rng);
+        let x: f32 = unsafe {mem::transmute(i)};
+        (x, x as f64)
+    });
+}
+
+#[test] #[ignore] // it is too expensive
+fn f32_exhaustive_libc_equivalence_test() {
+    // iterate from 0x0080_0000 to 0x7f7f_ffff, i.e. all normal ranges
+    iterate_libc("f32_exhaustive_libc_equivalence_test", 0x7f00_0000, |i| {
+        let x: f32 = unsafe {mem::transmute(i as u32 + 0x0080_0000)};
+        (x, x as f64)
+    });
+}
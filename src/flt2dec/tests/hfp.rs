@@ -0,0 +1,231 @@
+use std::prelude::v1::*;
+use std::{str, i16, f64};
+use std::slice::bytes;
+use rand;
+use rand::distributions::{IndependentSample, Range};
+
+use flt2dec::{Decodable, FullDecoded, Decoded, Sign, MAX_SIG_DIGITS_F128, round_up};
+use flt2dec::{MAX_SIG_DIGITS, MAX_SIG_DIGITS_F80, shortest_buf_len};
+use flt2dec::to_shortest_str;
+use flt2dec::hfp::{Hfp32, Hfp64, Hfp128};
+use flt2dec::strategy::dragon::{format_shortest, format_exact};
+use flt2dec::strategy::grisu;
+use super::{decode_finite, check_exact, to_string_with_parts};
+
+#[test]
+fn test_hfp32_decode() {
+    assert_eq!(Hfp32(0x00000000).decode(), (false, FullDecoded::Zero));
+    assert_eq!(Hfp32(0x80000000).decode(), (true, FullDecoded::Zero));
+    assert_eq!(Hfp32(0x42000000).decode(), (false, FullDecoded::Zero)); // a dirty zero
+
+    // 1.0, whose predecessor is 16 times closer than its successor
+    assert_eq!(decode_finite(Hfp32(0x41100000)),
+               Decoded { mant: 1 << 25, minus: 1, plus: 16, exp: -25, inclusive: true });
+    assert_eq!(Hfp32(0xc1100000).decode().0, true);
+    assert_eq!(decode_finite(Hfp32(0x41100001)),
+               Decoded { mant: (1 << 21) + 2, minus: 1, plus: 1, exp: -21, inclusive: false });
+    assert_eq!(decode_finite(Hfp32(0x7fffffff)),
+               Decoded { mant: (1 << 25) - 2, minus: 1, plus: 1, exp: 227, inclusive: false });
+
+    // unnormalized fractions are decoded as if they were normalized
+    assert_eq!(decode_finite(Hfp32(0x42010000)), decode_finite(Hfp32(0x41100000)));
+    assert_eq!(decode_finite(Hfp32(0x45000001)), decode_finite(Hfp32(0x40100000)));
+
+    // the minimum normalized value has no closer predecessor,
+    // and unnormalized values with the zero characteristic are evenly spaced
+    assert_eq!(decode_finite(Hfp32(0x00100000)),
+               Decoded { mant: 1 << 21, minus: 1, plus: 1, exp: -281, inclusive: true });
+    assert_eq!(decode_finite(Hfp32(0x000fffff)),
+               Decoded { mant: (1 << 21) - 2, minus: 1, plus: 1, exp: -281, inclusive: false });
+    assert_eq!(decode_finite(Hfp32(0x00000001)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -281, inclusive: false });
+}
+
+#[test]
+fn test_hfp64_hfp128_decode() {
+    assert_eq!(Hfp64(0x8000000000000000).decode(), (true, FullDecoded::Zero));
+    assert_eq!(decode_finite(Hfp64(0x4110000000000000)),
+               Decoded { mant: 1 << 57, minus: 1, plus: 16, exp: -57, inclusive: true });
+    assert_eq!(decode_finite(Hfp64(0x0000000000000001)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -313, inclusive: false });
+
+    // the upper 8 bits of the second field are ignored
+    assert_eq!(Hfp128(0x0000000000000000, 0xff00000000000000).decode(), (false, FullDecoded::Zero));
+    assert_eq!(decode_finite(Hfp128(0x4110000000000000, 0x3300000000000000)),
+               Decoded { mant: 1 << 113, minus: 1, plus: 16, exp: -113, inclusive: true });
+    assert_eq!(decode_finite(Hfp128(0x4110000000000000, 0x0000000000000001)),
+               Decoded { mant: (1 << 109) + 2, minus: 1, plus: 1, exp: -109, inclusive: false });
+    assert_eq!(decode_finite(Hfp128(0x0000000000000000, 0x0000000000000001)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -369, inclusive: false });
+}
+
+#[test]
+fn test_hfp_from_f64() {
+    assert_eq!(Hfp32::from_f64(1.0), Some(Hfp32(0x41100000)));
+    assert_eq!(Hfp32::from_f64(-118.625), Some(Hfp32(0xc276a000)));
+    assert_eq!(Hfp32::from_f64(0.1), Some(Hfp32(0x4019999a)));
+    assert_eq!(Hfp32::from_f64(1.0 / 3.0), Some(Hfp32(0x40555555)));
+    assert_eq!(Hfp32::from_f64(0.0), Some(Hfp32(0x00000000)));
+    assert_eq!(Hfp32::from_f64(-0.0), Some(Hfp32(0x80000000)));
+    assert_eq!(Hfp32::from_f64(0.0 / 0.0), None);
+    assert_eq!(Hfp32::from_f64(1.0 / 0.0), None);
+    assert_eq!(Hfp32::from_f64(-1.0 / 0.0), None);
+
+    // ties to even, possibly to the next power of 16
+    let ulp = 1.0 / (1 << 20) as f64;
+    assert_eq!(Hfp32::from_f64(1.0 + ulp / 2.0), Some(Hfp32(0x41100000)));
+    assert_eq!(Hfp32::from_f64(1.0 + ulp * 1.5), Some(Hfp32(0x41100002)));
+    assert_eq!(Hfp32::from_f64(16.0 - ulp / 2.0), Some(Hfp32(0x42100000)));
+    assert_eq!(Hfp32::from_f64(16.0 - ulp / 4.0), Some(Hfp32(0x42100000)));
+
+    // saturates when too large, and becomes unnormalized when too small
+    assert_eq!(Hfp32::from_f64(7.2e75), Some(Hfp32(0x7ffeb0e4)));
+    assert_eq!(Hfp32::from_f64(1.0e76), Some(Hfp32(0x7fffffff)));
+    assert_eq!(Hfp32::from_f64(-1.0e300), Some(Hfp32(0xffffffff)));
+    assert_eq!(Hfp32::from_f64(5.397605346934028e-79), Some(Hfp32(0x00100000)));
+    assert_eq!(Hfp32::from_f64(1.0e-80), Some(Hfp32(0x00004be3)));
+    assert_eq!(Hfp32::from_f64(3.0e-85), Some(Hfp32(0x00000001)));
+    assert_eq!(Hfp32::from_f64(1.0e-85), Some(Hfp32(0x00000000)));
+    assert_eq!(Hfp32::from_f64(-5e-324), Some(Hfp32(0x80000000)));
+
+    assert_eq!(Hfp64::from_f64(0.1), Some(Hfp64(0x401999999999999a)));
+    assert_eq!(Hfp64::from_f64(1.0 / 3.0), Some(Hfp64(0x4055555555555554)));
+    assert_eq!(Hfp64::from_f64(1.0e-80), Some(Hfp64(0x00004be2b05d3585)));
+    assert_eq!(Hfp64::from_f64(1.0e-94), Some(Hfp64(0x0000000000000001)));
+    assert_eq!(Hfp64::from_f64(f64::MAX), Some(Hfp64(0x7fffffffffffffff)));
+
+    // the second field has the characteristic minus 14, unless the value is zero
+    assert_eq!(Hfp128::from_f64(1.0), Some(Hfp128(0x4110000000000000, 0x3300000000000000)));
+    assert_eq!(Hfp128::from_f64(-1.0), Some(Hfp128(0xc110000000000000, 0xb300000000000000)));
+    assert_eq!(Hfp128::from_f64(0.1), Some(Hfp128(0x401999999999999a, 0x3200000000000000)));
+    assert_eq!(Hfp128::from_f64(1.0e-78), Some(Hfp128(0x001da48ce468e7c7, 0x7200000000000000)));
+    assert_eq!(Hfp128::from_f64(-0.0), Some(Hfp128(0x8000000000000000, 0x8000000000000000)));
+}
+
+#[test]
+fn test_hfp_shortest() {
+    assert_eq!(shortest(Hfp32(0x41100000)), ("1".to_string(), 1));
+    assert_eq!(shortest(Hfp32(0x41100001)), ("1000001".to_string(), 1));
+    assert_eq!(shortest(Hfp32(0x40ffffff)), ("99999994".to_string(), 0));
+    assert_eq!(shortest(Hfp32(0x3f100000)), ("390625".to_string(), -2));
+    assert_eq!(shortest(Hfp32(0x4019999a)), ("1".to_string(), 0));
+    assert_eq!(shortest(Hfp32(0x7fffffff)), ("7237005".to_string(), 76));
+    assert_eq!(shortest(Hfp32(0x00100000)), ("5397605".to_string(), -78));
+    assert_eq!(shortest(Hfp32(0x00000001)), ("5".to_string(), -84));
+    assert_eq!(shortest(Hfp64(0x7fffffffffffffff)), ("72370055773322621".to_string(), 76));
+    assert_eq!(shortest(Hfp64(0x0000000000000001)), ("1".to_string(), -93));
+
+    let to_string = |v: Hfp32| {
+        to_string_with_parts(|buf, parts| to_shortest_str(format_shortest, v, Sign::Minus, 0,
+                                                          false, buf, parts))
+    };
+    assert_eq!(to_string(Hfp32(0xc276a000)), "-118.625");
+    assert_eq!(to_string(Hfp32(0x4019999a)), "0.1");
+    assert_eq!(to_string(Hfp32(0x80000000)), "0");
+}
+
+#[test]
+fn test_hfp64_shortest_buf_len() {
+    // `10 + 2^-52` has 56 bits of precision and needs 18 digits.
+    let d = match Hfp64(0x41a0000000000001).decode() {
+        (false, FullDecoded::Finite(d)) => d,
+        full_decoded => panic!("unexpected decoding: {:?}", full_decoded),
+    };
+    assert_eq!(shortest_buf_len(&d), 18);
+    let mut buf = [0; MAX_SIG_DIGITS_F80];
+    let (len, exp) = grisu::format_shortest(&d, &mut buf);
+    assert_eq!((&buf[..len], exp), (&b"100000000000000002"[..], 2));
+}
+
+#[test]
+#[should_panic]
+fn test_hfp64_grisu_shortest_short_buf() {
+    let d = match Hfp64(0x41a0000000000001).decode() {
+        (_, FullDecoded::Finite(d)) => d,
+        _ => return,
+    };
+    let mut buf = [0; MAX_SIG_DIGITS];
+    grisu::format_shortest(&d, &mut buf);
+}
+
+#[test]
+fn test_hfp_exact() {
+    macro_rules! check {
+        ($v:expr => $buf:expr, $exp:expr) => (
+            check_exact(|d, b, k| format_exact(d, b, k), $v, stringify!($v), $buf, $exp)
+        )
+    }
+
+    check!(Hfp32(0x4019999a) => b"10000002384185791015625                 ", 0);
+    check!(Hfp32(0x7fffffff) => b"7237005145973115539562949848370752848515", 76);
+    check!(Hfp32(0x00100000) => b"5397605346934027890866469914250249731947", -78);
+    check!(Hfp32(0x00000001) => b"5147557589468028918138952173471688968608", -84);
+    check!(Hfp64(0x0000000000000001) => b"1198509146801202771751897449947821201898", -93);
+    check!(Hfp128(0x7fffffffffffffff, 0x00ffffffffffffff)
+           => b"7237005577332262213973186563042992847032", 76);
+}
+
+#[test]
+fn test_hfp_dragon_consistency() {
+    // the exact mode for `Hfp64` and `Hfp128` from `x` should agree with that for `f64` value `x`
+    let mut buf1 = [0; 1024];
+    let mut buf2 = [0; 1024];
+    for &x in &[1.0, 0.1, 1.0 / 3.0, 3.141592, 1.0e23, 1.0e75, 1.0e-75, 123456789.0] {
+        let d1 = decode_finite(x);
+        for &d2 in &[decode_finite(Hfp64::from_f64(x).unwrap()),
+                     decode_finite(Hfp128::from_f64(x).unwrap())] {
+            assert_eq!(format_exact(&d1, &mut buf1, i16::MIN),
+                       format_exact(&d2, &mut buf2, i16::MIN));
+            assert_eq!(&buf1[..], &buf2[..]);
+        }
+    }
+}
+
+#[test]
+fn test_hfp32_shortest_roundtrip() {
+    // the shortest representation of `Hfp32` should be converted back to the same value,
+    // and no shorter representation (nearest to the value) should do so.
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let range = Range::new(0x0000_0001u32, 0x8000_0000);
+    for _ in 0..10_000 {
+        let v = Hfp32::from_f64(decode_hfp32(range.ind_sample(&mut rng))).unwrap();
+        let mut buf = [0; MAX_SIG_DIGITS_F128];
+        let (len, exp) = format_shortest(&decode_finite(v), &mut buf);
+        assert_eq!(Hfp32::from_f64(parse(&buf[..len], exp)), Some(v));
+
+        if len > 1 {
+            let mut shorter = [0; MAX_SIG_DIGITS_F128];
+            bytes::copy_memory(&buf[..len - 1], &mut shorter);
+            let (shorter, exp) = if buf[len - 1] >= b'5' {
+                match round_up(&mut shorter, len - 1) {
+                    Some(c) => { shorter[len - 1] = c; (&shorter[..len], exp + 1) }
+                    None => (&shorter[..len - 1], exp),
+                }
+            } else {
+                (&shorter[..len - 1], exp)
+            };
+            assert!(Hfp32::from_f64(parse(shorter, exp)) != Some(v),
+                    "{:?}: {}e{} is not the shortest",
+                    v, str::from_utf8(&buf[..len]).unwrap(), exp);
+        }
+    }
+}
+
+// returns the shortest representation of finite `v`.
+fn shortest<T: Decodable>(v: T) -> (String, i16) {
+    let mut buf = [0; MAX_SIG_DIGITS_F128];
+    let (len, exp) = format_shortest(&decode_finite(v), &mut buf);
+    (str::from_utf8(&buf[..len]).unwrap().to_string(), exp)
+}
+
+// returns the value of the positive `Hfp32` pattern as `f64`, which is exact.
+fn decode_hfp32(bits: u32) -> f64 {
+    let frac = (bits & 0xffffff) as f64;
+    let c = (bits >> 24) as i32;
+    frac * 2.0f64.powi(4 * (c - 70))
+}
+
+// returns `0.digits * 10^exp` as `f64`.
+fn parse(digits: &[u8], exp: i16) -> f64 {
+    format!("0.{}e{}", str::from_utf8(digits).unwrap(), exp).parse().unwrap()
+}
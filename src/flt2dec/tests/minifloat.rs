@@ -0,0 +1,190 @@
+use std::prelude::v1::*;
+use std::i16;
+
+use flt2dec::{decode, FullDecoded, Sign, MAX_SIG_DIGITS};
+use flt2dec::{to_shortest_str, to_exact_exp_str};
+use flt2dec::half::{F16, Bf16};
+use flt2dec::minifloat::*;
+use flt2dec::strategy::dragon::{format_shortest, format_exact};
+use super::to_string_with_parts;
+
+const BINARY16: FloatFormat =
+    FloatFormat { ebits: 5, mbits: 10, bias: 15, specials: Specials::Ieee };
+const BFLOAT16: FloatFormat =
+    FloatFormat { ebits: 8, mbits: 7, bias: 127, specials: Specials::Ieee };
+
+fn shortest(format: &FloatFormat, bits: u32) -> String {
+    to_string_with_parts(|buf, parts| to_shortest_str(format_shortest, Minifloat(format, bits),
+                                                      Sign::MinusRaw, 0, false, buf, parts))
+}
+
+fn exact(format: &FloatFormat, bits: u32, ndigits: usize) -> String {
+    to_string_with_parts(|buf, parts| to_exact_exp_str(format_exact, Minifloat(format, bits),
+                                                       Sign::Minus, ndigits, false, buf, parts))
+}
+
+// computes the value of the positive pattern independently from `decode_bits`.
+// the exponent field is not masked, so `bits + 1` of the largest pattern gives the next power.
+fn to_f64(format: &FloatFormat, bits: u32) -> f64 {
+    let exp = (bits >> format.mbits) as i32;
+    let mant = (bits & ((1 << format.mbits) - 1)) as f64;
+    let ulp = 2.0f64.powi(1 - format.bias as i32 - format.mbits as i32);
+    if exp == 0 {
+        mant * ulp
+    } else {
+        (mant + (1u32 << format.mbits) as f64) * ulp * 2.0f64.powi(exp - 1)
+    }
+}
+
+fn check_against_decode<F>(format: &FloatFormat, mut decode16: F)
+        where F: FnMut(u16) -> (bool, FullDecoded) {
+    for bits in 0..0x10000u32 {
+        let (negative, decoded) = decode_bits(format, bits);
+        let (expected_negative, expected) = decode16(bits as u16);
+        if expected == FullDecoded::Nan {
+            assert_eq!(decoded, FullDecoded::Nan);
+            continue;
+        }
+        assert_eq!(negative, expected_negative);
+        assert_eq!(decoded, expected, "bits = {:#x}", bits);
+    }
+}
+
+#[test]
+fn test_decode_bits_binary16() {
+    check_against_decode(&BINARY16, |bits| decode(F16(bits)));
+    check_against_decode(&BFLOAT16, |bits| decode(Bf16(bits)));
+}
+
+#[test]
+fn test_float_format_new() {
+    assert_eq!(FloatFormat::new(8, 7, 127, Specials::Ieee), BFLOAT16);
+    for format in super::MINIFLOAT_FORMATS.iter() {
+        assert!(format.is_valid());
+    }
+
+    // the range of `f64` is from `2^-1074` to the binade of `2^1023`
+    assert!(FloatFormat::new(11, 20, 1055, Specials::Finite).is_valid());
+    assert!(!FloatFormat { ebits: 11, mbits: 20, bias: 1056, specials: Specials::Finite }
+                .is_valid());
+    assert!(FloatFormat::new(11, 20, 1024, Specials::Finite).is_valid());
+    assert!(!FloatFormat { ebits: 11, mbits: 20, bias: 1023, specials: Specials::Finite }
+                .is_valid());
+    assert!(!FloatFormat { ebits: 12, mbits: 3, bias: 2047, specials: Specials::Ieee }.is_valid());
+    assert!(!FloatFormat { ebits: 8, mbits: 24, bias: 127, specials: Specials::Ieee }.is_valid());
+}
+
+#[test]
+#[should_panic]
+fn test_float_format_new_overflowing_bias() {
+    // `1 - bias - mbits` would overflow `i16`
+    FloatFormat::new(4, 3, i16::MAX, Specials::Ieee);
+}
+
+#[test]
+fn test_decode_bits_specials() {
+    assert_eq!(decode_bits(&E4M3, 0x78), (false, FullDecoded::Infinite));
+    assert_eq!(decode_bits(&E4M3, 0xf8), (true, FullDecoded::Infinite));
+    assert_eq!(decode_bits(&E4M3, 0x79).1, FullDecoded::Nan);
+    assert_eq!(decode_bits(&E5M2, 0x7c), (false, FullDecoded::Infinite));
+    assert_eq!(decode_bits(&E5M2, 0xfe).1, FullDecoded::Nan);
+
+    assert_eq!(decode_bits(&E4M3FN, 0x7f), (false, FullDecoded::Nan));
+    assert_eq!(decode_bits(&E4M3FN, 0xff), (true, FullDecoded::Nan));
+    assert!(match decode_bits(&E4M3FN, 0x78).1 { FullDecoded::Finite(_) => true, _ => false });
+    assert!(match decode_bits(&E4M3FN, 0x7e).1 { FullDecoded::Finite(_) => true, _ => false });
+
+    assert_eq!(decode_bits(&E4M3FNUZ, 0x00), (false, FullDecoded::Zero));
+    assert_eq!(decode_bits(&E4M3FNUZ, 0x80).1, FullDecoded::Nan);
+    assert_eq!(decode_bits(&E5M2FNUZ, 0x80).1, FullDecoded::Nan);
+    assert!(match decode_bits(&E5M2FNUZ, 0x7f).1 { FullDecoded::Finite(_) => true, _ => false });
+
+    for format in [E3M2, E2M3, E2M1].iter() {
+        assert_eq!(decode_bits(format, 0), (false, FullDecoded::Zero));
+        assert_eq!(decode_bits(format, 1 << (format.width() - 1)), (true, FullDecoded::Zero));
+        for bits in 1..1u32 << format.width() {
+            if bits == 1 << (format.width() - 1) { continue; }
+            assert!(match decode_bits(format, bits).1 { FullDecoded::Finite(_) => true,
+                                                        _ => false });
+        }
+    }
+
+    // higher bits are ignored
+    assert_eq!(decode_bits(&E2M1, 0xf7), decode_bits(&E2M1, 0x7));
+}
+
+#[test]
+fn test_minifloat_to_shortest_str() {
+    assert_eq!(shortest(&E4M3FN, 0x7e), "450");
+    assert_eq!(shortest(&E4M3FN, 0xfe), "-450");
+    assert_eq!(shortest(&E4M3FN, 0x78), "260");
+    assert_eq!(shortest(&E4M3FN, 0x7f), "nan");
+    assert_eq!(shortest(&E4M3FN, 0x01), "0.002");
+    assert_eq!(shortest(&E4M3FN, 0x80), "-0");
+    assert_eq!(shortest(&E4M3, 0x77), "240");
+    assert_eq!(shortest(&E4M3, 0x78), "inf");
+    assert_eq!(shortest(&E4M3, 0xf8), "-inf");
+    assert_eq!(shortest(&E4M3FNUZ, 0x7f), "240");
+    assert_eq!(shortest(&E4M3FNUZ, 0x80), "nan");
+    assert_eq!(shortest(&E5M2, 0x7b), "60000");
+    assert_eq!(shortest(&E5M2, 0x3e), "1.5");
+    assert_eq!(shortest(&E5M2FNUZ, 0x7f), "60000");
+    assert_eq!(shortest(&E3M2, 0x1f), "28");
+    assert_eq!(shortest(&E2M3, 0x1f), "7.5");
+
+    let e2m1: Vec<String> = (0..16).map(|bits| shortest(&E2M1, bits)).collect();
+    assert_eq!(e2m1, ["0", "0.5", "1", "1.5", "2", "3", "4", "6",
+                      "-0", "-0.5", "-1", "-1.5", "-2", "-3", "-4", "-6"]);
+}
+
+#[test]
+fn test_minifloat_to_exact_exp_str() {
+    assert_eq!(exact(&E4M3FN, 0x01, 7), "1.953125e-3");
+    assert_eq!(exact(&E4M3FN, 0x01, 3), "1.95e-3");
+    assert_eq!(exact(&E5M2FNUZ, 0x7f, 6), "5.73440e4");
+    assert_eq!(exact(&E4M3FN, 0x7e, 3), "4.48e2");
+    assert_eq!(exact(&E5M2, 0x01, 20), "1.5258789062500000000e-5");
+}
+
+#[test]
+fn test_minifloat_shortest_correctness() {
+    // the shortest representation should be closer to the original value than any neighbor,
+    // and there should be no shorter representation (checked by trying one digit less).
+    for format in super::MINIFLOAT_FORMATS.iter() {
+        for bits in 1..1u32 << (format.width() - 1) {
+            let decoded = match decode_bits(format, bits).1 {
+                FullDecoded::Finite(decoded) => decoded,
+                _ => continue,
+            };
+            let mut buf = [0; MAX_SIG_DIGITS];
+            let (len, exp) = format_shortest(&decoded, &mut buf);
+
+            let lo = to_f64(format, bits - 1);
+            let x = to_f64(format, bits);
+            let hi = to_f64(format, bits + 1);
+            let check = |digits: &[u8], exp: i16| {
+                let s = format!("0.{}e{}", String::from_utf8(digits.to_vec()).unwrap(), exp);
+                let v: f64 = s.parse().unwrap();
+                let lower_ok = if decoded.inclusive { (lo + x) / 2.0 <= v }
+                               else { (lo + x) / 2.0 < v };
+                let upper_ok = if decoded.inclusive { v <= (x + hi) / 2.0 }
+                               else { v < (x + hi) / 2.0 };
+                lower_ok && upper_ok
+            };
+            assert!(check(&buf[..len], exp), "{:?} {:#x}: {:?}e{} is out of range",
+                    format, bits, String::from_utf8(buf[..len].to_vec()).unwrap(), exp);
+
+            // no `len - 1` digits around the value should be in the range
+            if len > 1 {
+                let mut shorter = buf[..len - 1].to_vec();
+                assert!(!check(&shorter, exp), "{:?} {:#x}: too long", format, bits);
+                let mut i = shorter.len();
+                while i > 0 && shorter[i - 1] == b'9' { shorter[i - 1] = b'0'; i -= 1; }
+                if i > 0 {
+                    shorter[i - 1] += 1;
+                    assert!(!check(&shorter, exp), "{:?} {:#x}: too long", format, bits);
+                }
+            }
+        }
+    }
+}
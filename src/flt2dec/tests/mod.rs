@@ -4,28 +4,55 @@ use std::slice::bytes;
 use rand;
 use rand::distributions::{IndependentSample, Range};
 
-use flt2dec::{decode, DecodableFloat, FullDecoded, Decoded};
-use flt2dec::{MAX_SIG_DIGITS, round_up, Part, Formatted, Sign};
+use flt2dec::{Decodable, DecodableFloat, FullDecoded, Decoded};
+use flt2dec::half::{F16, Bf16};
+use flt2dec::quad::F128;
+use flt2dec::extended::{F80, DoubleDouble};
+use flt2dec::minifloat::{decode_bits, FloatFormat};
+use flt2dec::minifloat::{E5M2, E5M2FNUZ, E4M3, E4M3FN, E4M3FNUZ, E3M2, E2M3, E2M1};
+use flt2dec::{MAX_SIG_DIGITS, MAX_SIG_DIGITS_F128, round_up, Part, Formatted, Sign};
+use flt2dec::shortest_buf_len;
 use flt2dec::{to_shortest_str, to_shortest_exp_str, to_exact_exp_str, to_exact_fixed_str};
+use grouping::{self, Grouping};
 
 pub use test::Bencher;
 
 mod estimator;
 mod bignum;
+mod half;
+mod quad;
+mod extended;
+mod minifloat;
+mod hfp;
+mod vax;
+mod hex;
 mod strategy {
     mod system;
     mod libc;
     mod dragon;
     mod grisu;
+    mod grisu2;
+    mod errol3;
+    mod ryu;
+    mod ryu_printf;
+    mod schubfach;
+    mod dragonbox;
+    mod runtime;
 }
 
-pub fn decode_finite<T: DecodableFloat>(v: T) -> Decoded {
-    match decode(v).1 {
+pub fn decode_finite<T: Decodable>(v: T) -> Decoded {
+    match v.decode().1 {
         FullDecoded::Finite(decoded) => decoded,
         full_decoded => panic!("expected finite, got {:?} instead", full_decoded)
     }
 }
 
+// returns the exact buffer size strategies accept for `d`, so that they are tested
+// with `MAX_SIG_DIGITS` bytes for `f64` and narrower types.
+fn shortest_test_buf_len(d: &Decoded) -> usize {
+    if shortest_buf_len(d) > MAX_SIG_DIGITS { MAX_SIG_DIGITS_F128 } else { MAX_SIG_DIGITS }
+}
+
 macro_rules! check_shortest {
     ($f:ident($v:expr) => $buf:expr, $exp:expr) => (
         check_shortest!($f($v) => $buf, $exp;
@@ -40,8 +67,9 @@ macro_rules! check_shortest {
     );
 
     ($f:ident($v:expr) => $buf:expr, $exp:expr; $fmt:expr, $($key:ident = $val:expr),*) => ({
-        let mut buf = [b'_'; MAX_SIG_DIGITS];
-        let (len, k) = $f(&decode_finite($v), &mut buf);
+        let decoded = decode_finite($v);
+        let mut buf = [b'_'; MAX_SIG_DIGITS_F128];
+        let (len, k) = $f(&decoded, &mut buf[..shortest_test_buf_len(&decoded)]);
         assert!((&buf[..len], k) == ($buf, $exp),
                 $fmt, actual = (str::from_utf8(&buf[..len]).unwrap(), k),
                       expected = (str::from_utf8($buf).unwrap(), $exp),
@@ -50,8 +78,9 @@ macro_rules! check_shortest {
 
     ($f:ident{$($k:ident: $v:expr),+} => $buf:expr, $exp:expr;
                                          $fmt:expr, $($key:ident = $val:expr),*) => ({
-        let mut buf = [b'_'; MAX_SIG_DIGITS];
-        let (len, k) = $f(&Decoded { $($k: $v),+ }, &mut buf);
+        let decoded = Decoded { $($k: $v),+ };
+        let mut buf = [b'_'; MAX_SIG_DIGITS_F128];
+        let (len, k) = $f(&decoded, &mut buf[..shortest_test_buf_len(&decoded)]);
         assert!((&buf[..len], k) == ($buf, $exp),
                 $fmt, actual = (str::from_utf8(&buf[..len]).unwrap(), k),
                       expected = (str::from_utf8($buf).unwrap(), $exp),
@@ -82,7 +111,7 @@ macro_rules! try_fixed {
 }
 
 fn check_exact<F, T>(mut f: F, v: T, vstr: &str, expected: &[u8], expectedk: i16)
-        where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+        where T: Decodable, F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
     // use a large enough buffer
     let mut buf = [b'_'; 1024];
     let mut expected_ = [b'_'; 1024];
@@ -424,6 +453,271 @@ pub fn f64_exact_sanity_test<F>(mut f: F)
     check_exact_one!(f(8549497411294502,  -448; f64) => b"1176257830728540379990", -118);
 }
 
+pub fn f16_shortest_sanity_test<F>(mut f: F) where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // 0.09991455078125
+    // 0.0999755859375 (0.1 in the default rounding)
+    // 0.10003662109375
+    check_shortest!(f(F16(0x2e66)) => b"1", 0);
+
+    // 0.3330078125
+    // 0.333251953125 (1/3 in the default rounding)
+    // 0.33349609375
+    check_shortest!(f(F16(0x3555)) => b"3333", 0);
+
+    // 10^1 * 0.3138671875
+    // 10^1 * 0.3140625
+    // 10^1 * 0.3142578125
+    check_shortest!(f(F16(0x4248)) => b"314", 1);
+
+    // 10^5 * 0.65472
+    // 10^5 * 0.65504
+    // 10^5 * 0.65536 (would be the next value, but is an infinity)
+    check_shortest!(f(F16(0x7bff)) => b"655", 5);
+
+    // 10^-4 * 0.60975551605224609375
+    // 10^-4 * 0.6103515625
+    // 10^-4 * 0.61094760894775390625
+    check_shortest!(f(F16(0x0400)) => b"6104", -4);
+
+    // 10^-7 * 0
+    // 10^-7 * 0.59604644775390625
+    // 10^-7 * 1.1920928955078125
+    check_shortest!(f(F16(0x0001)) => b"6", -7);
+}
+
+pub fn bf16_shortest_sanity_test<F>(mut f: F) where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // 0.099609375
+    // 0.10009765625 (0.1 in the default rounding)
+    // 0.1005859375
+    check_shortest!(f(Bf16(0x3dcd)) => b"1", 0);
+
+    // 0.33203125
+    // 0.333984375 (1/3 in the default rounding)
+    // 0.3359375
+    check_shortest!(f(Bf16(0x3eab)) => b"334", 0);
+
+    // 10^1 * 0.3125
+    // 10^1 * 0.3140625
+    // 10^1 * 0.315625
+    check_shortest!(f(Bf16(0x4049)) => b"314", 1);
+
+    // 10^39 * 0.337623910929368631717566993311207522304
+    // 10^39 * 0.33895313892515354759047080037148786688
+    // 10^39 * 0.340282366920938463463374607431768211456 (would be the next value)
+    check_shortest!(f(Bf16(0x7f7f)) => b"339", 39);
+
+    // 10^-37 * 0.1166310801206488386812730783025196883460...
+    // 10^-37 * 0.1175494350822287507968736537222245677818...
+    // 10^-37 * 0.1184677900438086629124742291419294472176...
+    check_shortest!(f(Bf16(0x0080)) => b"118", -37);
+
+    // 10^-40 * 0
+    // 10^-40 * 0.9183549615799121156005754197048794357958...
+    // 10^-40 * 1.8367099231598242312011508394097588715916...
+    check_shortest!(f(Bf16(0x0001)) => b"1", -39);
+}
+
+pub fn f128_shortest_sanity_test<F>(mut f: F) where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // 10^-1 * 0.9999999999999999999999999999999999277776...
+    // 0.1000000000000000000000000000000000048148... (0.1 in the default rounding)
+    // 0.1000000000000000000000000000000000168518...
+    check_shortest!(f(F128(0x3ffb999999999999999999999999999a)) => b"1", 0);
+
+    // 0.3333333333333333333333333333333332691356...
+    // 0.3333333333333333333333333333333333172839... (1/3 in the default rounding)
+    // 0.3333333333333333333333333333333333654321...
+    check_shortest!(f(F128(0x3ffd5555555555555555555555555555)) =>
+                    b"3333333333333333333333333333333333", 0);
+
+    // 10^1 * 0.3141592653589793238462643383279502412293...
+    // 10^1 * 0.3141592653589793238462643383279502797479... (pi in the default rounding)
+    // 10^1 * 0.3141592653589793238462643383279503182665...
+    check_shortest!(f(F128(0x4000921fb54442d18469898cc51701b8)) =>
+                    b"31415926535897932384626433832795028", 1);
+
+    // 10^20 * 0.1844674407370955161599999999999999822364...
+    // 10^20 * 0.18446744073709551616 (exact)
+    // 10^20 * 0.1844674407370955161600000000000000355271...
+    check_shortest!(f(F128(0x403f0000000000000000000000000000)) => b"18446744073709551616", 20);
+
+    // 10^4933 * 0.1189731495357231765085759326628006901629...
+    // 10^4933 * 0.1189731495357231765085759326628007016196...
+    // 10^4933 * 0.1189731495357231765085759326628007130763... (would be the next value)
+    check_shortest!(f(F128(0x7ffeffffffffffffffffffffffffffff)) =>
+                    b"1189731495357231765085759326628007", 4933);
+
+    // 10^-4931 * 0.3362103143112093506262677817321751955080...
+    // 10^-4931 * 0.3362103143112093506262677817321752602598...
+    // 10^-4931 * 0.3362103143112093506262677817321753250115...
+    check_shortest!(f(F128(0x00010000000000000000000000000000)) =>
+                    b"33621031431120935062626778173217526", -4931);
+
+    // 10^-4931 * 0.3362103143112093506262677817321751307563...
+    // 10^-4931 * 0.3362103143112093506262677817321751955080...
+    // 10^-4931 * 0.3362103143112093506262677817321752602598...
+    check_shortest!(f(F128(0x0000ffffffffffffffffffffffffffff)) =>
+                    b"3362103143112093506262677817321752", -4931);
+
+    // 10^-4965 * 0
+    // 10^-4965 * 0.6475175119438025110924438958227646552499...
+    // 10^-4965 * 1.2950350238876050221848877916455293104999...
+    check_shortest!(f(F128(0x00000000000000000000000000000001)) => b"6", -4965);
+}
+
+pub fn f128_exact_sanity_test<F>(mut f: F)
+        where F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+    check_exact!(f(F128(0x3ffb999999999999999999999999999a))
+                 => b"1000000000000000000000000000000000048148", 0);
+    check_exact!(f(F128(0x3ffd5555555555555555555555555555))
+                 => b"3333333333333333333333333333333333172839", 0);
+    check_exact!(f(F128(0x4000921fb54442d18469898cc51701b8))
+                 => b"3141592653589793238462643383279502797479", 1);
+    check_exact!(f(F128(0x403f0000000000000000000000000000))
+                 => b"18446744073709551616                    ", 20);
+    check_exact!(f(F128(0x7ffeffffffffffffffffffffffffffff))
+                 => b"1189731495357231765085759326628007016196", 4933);
+    check_exact!(f(F128(0x00010000000000000000000000000000))
+                 => b"3362103143112093506262677817321752602598", -4931);
+    check_exact!(f(F128(0x00000000000000000000000000000001))
+                 => b"6475175119438025110924438958227646552499", -4965);
+}
+
+pub fn f80_shortest_sanity_test<F>(mut f: F) where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // 10^-1 * 0.9999999999999999999457898913757247782996...
+    // 0.1000000000000000000013552527156068805425... (0.1 in the default rounding)
+    // 0.1000000000000000000081315162936412832550...
+    check_shortest!(f(F80(0x3ffb, 0xcccccccccccccccd)) => b"1", 0);
+
+    // 0.3333333333333333333152632971252415927665...
+    // 0.3333333333333333333423683514373792036167... (1/3 in the default rounding)
+    // 0.3333333333333333333694734057495168144669...
+    check_shortest!(f(F80(0x3ffd, 0xaaaaaaaaaaaaaaab)) => b"33333333333333333334", 0);
+
+    // 10^1 * 0.3141592653589793238295968524909085317631...
+    // 10^1 * 0.3141592653589793238512808959406186204432... (pi in the default rounding)
+    // 10^1 * 0.3141592653589793238729649393903287091234...
+    check_shortest!(f(F80(0x4000, 0xc90fdaa22168c235)) => b"31415926535897932385", 1);
+
+    // 10^20 * 0.1844674407370955161500000000000000000000...
+    // 10^20 * 0.18446744073709551616 (exact)
+    // 10^20 * 0.1844674407370955161800000000000000000000...
+    check_shortest!(f(F80(0x403f, 0x8000000000000000)) => b"18446744073709551616", 20);
+
+    // 10^4933 * 0.1189731495357231764956768379433933279574...
+    // 10^4933 * 0.1189731495357231765021263853030970205169...
+    // 10^4933 * 0.1189731495357231765085759326628007130763... (would be the next value)
+    check_shortest!(f(F80(0x7ffe, 0xffffffffffffffff)) => b"1189731495357231765", 4933);
+
+    // 10^-4931 * 0.3362103143112093505898157864133505142345...
+    // 10^-4931 * 0.3362103143112093506262677817321752602598...
+    // 10^-4931 * 0.3362103143112093506627197770510000062850...
+    check_shortest!(f(F80(0x0001, 0x8000000000000000)) => b"33621031431120935063", -4931);
+
+    // 10^-4931 * 0.3362103143112093505533637910945257682092...
+    // 10^-4931 * 0.3362103143112093505898157864133505142345...
+    // 10^-4931 * 0.3362103143112093506262677817321752602598...
+    check_shortest!(f(F80(0x0000, 0x7fffffffffffffff)) => b"3362103143112093506", -4931);
+
+    // 10^-4950 * 0
+    // 10^-4950 * 0.3645199531882474602528405933619419816399...
+    // 10^-4950 * 0.7290399063764949205056811867238839632798...
+    check_shortest!(f(F80(0x0000, 0x0000000000000001)) => b"4", -4950);
+
+    // a pseudo-denormal, which equals to `F80(0x0001, 0x8000000000000001)`
+    // 10^-4931 * 0.3362103143112093506262677817321752602598...
+    // 10^-4931 * 0.3362103143112093506627197770510000062850...
+    // 10^-4931 * 0.3362103143112093506991717723698247523103...
+    check_shortest!(f(F80(0x0000, 0x8000000000000001)) => b"33621031431120935066", -4931);
+}
+
+pub fn f80_exact_sanity_test<F>(mut f: F)
+        where F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+    check_exact!(f(F80(0x3ffb, 0xcccccccccccccccd))
+                 => b"1000000000000000000013552527156068805425", 0);
+    check_exact!(f(F80(0x3ffd, 0xaaaaaaaaaaaaaaab))
+                 => b"3333333333333333333423683514373792036167", 0);
+    check_exact!(f(F80(0x4000, 0xc90fdaa22168c235))
+                 => b"3141592653589793238512808959406186204432", 1);
+    check_exact!(f(F80(0x403f, 0x8000000000000000))
+                 => b"18446744073709551616                    ", 20);
+    check_exact!(f(F80(0x7ffe, 0xffffffffffffffff))
+                 => b"1189731495357231765021263853030970205169", 4933);
+    check_exact!(f(F80(0x0001, 0x8000000000000000))
+                 => b"3362103143112093506262677817321752602598", -4931);
+    check_exact!(f(F80(0x0000, 0x0000000000000001))
+                 => b"3645199531882474602528405933619419816399", -4950);
+    check_exact!(f(F80(0x0000, 0x8000000000000001))
+                 => b"3362103143112093506627197770510000062850", -4931);
+}
+
+// double-doubles are decoded as if they have 107 bits of mantissa (see `DoubleDouble`),
+// so the neighbors in the following comments are not always valid double-doubles.
+pub fn dd_shortest_sanity_test<F>(mut f: F) where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // 10^-1 * 0.9999999999999999999999999999999892147923...
+    // 10^-1 * 0.9999999999999999999999999999999969185120... (0.1 in the default rounding)
+    // 0.1000000000000000000000000000000004622231...
+    check_shortest!(f(DoubleDouble(0.1, -5.551115123125783e-18)) => b"1", 0);
+
+    // 0.3333333333333333333333333333333292246827...
+    // 0.3333333333333333333333333333333323061706... (1/3 in the default rounding)
+    // 0.3333333333333333333333333333333353876586...
+    check_shortest!(f(DoubleDouble(0.3333333333333333, 1.850371707708594e-17)) =>
+                    b"333333333333333333333333333333332", 0);
+
+    // 10^1 * 0.3141592653589793238462643383279481227063...
+    // 10^1 * 0.3141592653589793238462643383279505878966... (pi in the default rounding)
+    // 10^1 * 0.3141592653589793238462643383279530530870...
+    check_shortest!(f(DoubleDouble(3.141592653589793, 1.2246467991473532e-16)) =>
+                    b"31415926535897932384626433832795", 1);
+
+    // 10^309 * 0.1797693134862315807937289714053000913381...
+    // 10^309 * 0.1797693134862315807937289714053011992520...
+    // 10^309 * 0.1797693134862315807937289714053023071660... (would be the next value)
+    check_shortest!(f(DoubleDouble(f64::MAX, 9.979201547673597e291)) =>
+                    b"179769313486231580793728971405301", 309);
+
+    // 10^-291 * 0.4008336720017945555992216102700274689224...
+    // 10^-291 * 0.4008336720017945555992216102700324095788...
+    // 10^-291 * 0.4008336720017945555992216102700373502353...
+    check_shortest!(f(DoubleDouble(DecodableFloat::ldexpi(1, -968), 0.0)) =>
+                    b"400833672001794555599221610270032", -291);
+
+    // 10^-323 * 0
+    // 10^-323 * 0.4940656458412465441765687928682213723650...
+    // 10^-323 * 0.9881312916824930883531375857364427447301...
+    check_shortest!(f(DoubleDouble(5e-324, 0.0)) => b"5", -323);
+
+    // `lo` is rounded off since it is far below 107 bits
+    // 0.9999999999999999999999999999999938370241...
+    // 10^1 * 0.1 (exact)
+    // 10^1 * 0.1000000000000000000000000000000012325951...
+    check_shortest!(f(DoubleDouble(1.0, DecodableFloat::ldexpi(1, -200))) => b"1", 1);
+
+    // 0.9999999999999999991326382620115902898182...
+    // 0.9999999999999999991326382620115964527940...
+    // 0.9999999999999999991326382620116026157698...
+    check_shortest!(f(DoubleDouble(1.0, DecodableFloat::ldexpi(-1, -60))) =>
+                    b"999999999999999999132638262011596", 0);
+}
+
+pub fn dd_exact_sanity_test<F>(mut f: F)
+        where F: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+    check_exact!(f(DoubleDouble(0.1, -5.551115123125783e-18))
+                 => b"9999999999999999999999999999999969185120", -1);
+    check_exact!(f(DoubleDouble(0.3333333333333333, 1.850371707708594e-17))
+                 => b"3333333333333333333333333333333323061706", 0);
+    check_exact!(f(DoubleDouble(3.141592653589793, 1.2246467991473532e-16))
+                 => b"3141592653589793238462643383279505878966", 1);
+    check_exact!(f(DoubleDouble(f64::MAX, 9.979201547673597e291))
+                 => b"1797693134862315807937289714053011992520", 309);
+    check_exact!(f(DoubleDouble(5e-324, 0.0))
+                 => b"4940656458412465441765687928682213723650", -323);
+    check_exact!(f(DoubleDouble(1.0, DecodableFloat::ldexpi(1, -200)))
+                 => b"1                                       ", 1);
+    check_exact!(f(DoubleDouble(1.0, DecodableFloat::ldexpi(-1, -60)))
+                 => b"9999999999999999991326382620115964527940", 0);
+}
+
 pub fn more_shortest_sanity_test<F>(mut f: F) where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
     check_shortest!(f{mant: 99_999_999_999_999_999, minus: 1, plus: 1,
                       exp: 0, inclusive: true} => b"1", 18);
@@ -494,7 +788,7 @@ pub fn f64_random_equivalence_test<F, G>(f: F, g: G, k: usize, n: usize)
     });
 }
 
-pub fn f32_exhaustive_equivalence_test<F, G>(f: F, g: G, k: usize)
+pub fn f32_exhaustive_equivalence_test<F, G>(f: F, g: G, k: usize) -> (usize, usize)
         where F: FnMut(&Decoded, &mut [u8]) -> Option<(usize, i16)>,
               G: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
     // we have only 2^23 * (2^8 - 1) - 1 = 2,139,095,039 positive finite f32 values,
@@ -503,13 +797,145 @@ pub fn f32_exhaustive_equivalence_test<F, G>(f: F, g: G, k: usize)
     // this is of course very stressful (and thus should be behind an `#[ignore]` attribute),
     // but with `-C opt-level=3 -C lto` this only takes about an hour or so.
 
-    // iterate from 0x0000_0001 to 0x7f7f_ffff, i.e. all finite ranges
-    let (npassed, nignored) = iterate("f32_exhaustive_equivalence_test",
-                                      k, 0x7f7f_ffff, f, g, |i: usize| {
+    // iterate from 0x0000_0001 to 0x7f7f_ffff, i.e. all finite ranges.
+    // returns the number of passed and ignored values, which depend on `f`.
+    iterate("f32_exhaustive_equivalence_test", k, 0x7f7f_ffff, f, g, |i: usize| {
         let x: f32 = unsafe {mem::transmute(i as u32 + 1)};
         decode_finite(x)
-    });
-    assert_eq!((npassed, nignored), (2121451881, 17643158));
+    })
+}
+
+pub fn f16_exhaustive_equivalence_test<F, G>(f: F, g: G, k: usize) -> (usize, usize)
+        where F: FnMut(&Decoded, &mut [u8]) -> Option<(usize, i16)>,
+              G: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // iterate from 0x0001 to 0x7bff, i.e. all positive finite `F16` values.
+    // unlike `f32`, this is cheap enough to be always run.
+    iterate("f16_exhaustive_equivalence_test", k, 0x7bff, f, g, |i: usize| {
+        decode_finite(F16(i as u16 + 1))
+    })
+}
+
+pub fn bf16_exhaustive_equivalence_test<F, G>(f: F, g: G, k: usize) -> (usize, usize)
+        where F: FnMut(&Decoded, &mut [u8]) -> Option<(usize, i16)>,
+              G: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // iterate from 0x0001 to 0x7f7f, i.e. all positive finite `Bf16` values.
+    iterate("bf16_exhaustive_equivalence_test", k, 0x7f7f, f, g, |i: usize| {
+        decode_finite(Bf16(i as u16 + 1))
+    })
+}
+
+// all predefined formats in `minifloat`.
+pub static MINIFLOAT_FORMATS: [FloatFormat; 8] = [
+    E5M2, E5M2FNUZ, E4M3, E4M3FN, E4M3FNUZ, E3M2, E2M3, E2M1,
+];
+
+pub fn minifloat_exhaustive_equivalence_test<F, G>(format: &FloatFormat, f: F, g: G, k: usize)
+        -> (usize, usize)
+        where F: FnMut(&Decoded, &mut [u8]) -> Option<(usize, i16)>,
+              G: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // iterate over all positive finite values, whose patterns may be interleaved with NaNs.
+    let finite: Vec<Decoded> = (1..1u32 << (format.ebits + format.mbits)).filter_map(|i| {
+        match decode_bits(format, i).1 {
+            FullDecoded::Finite(decoded) => Some(decoded),
+            _ => None,
+        }
+    }).collect();
+    iterate("minifloat_exhaustive_equivalence_test", k, finite.len(), f, g, |i| finite[i])
+}
+
+// returns true if `0.<digits> * 10^exp` is within the rounding interval of `d`,
+// i.e. it reads back to the original value. `d` should be no wider than `f64`.
+fn is_within_interval(d: &Decoded, digits: &[u8], exp: i16) -> bool {
+    use std::cmp;
+    use flt2dec::bignum::Big32x36 as Big;
+
+    // compares `v * 10^k` and `(mant -/+ minus/plus) * 2^e` as `v * 5^k * 2^(k-e)` and
+    // `mant -/+ minus/plus`, where the negative powers are moved to the other side.
+    let k = exp as i32 - digits.len() as i32;
+    let e = d.exp as i32;
+    let mut v = Big::from_small(0);
+    for &c in digits {
+        v.mul_small(10).add(&Big::from_small((c - b'0') as u32));
+    }
+    let mut low = Big::from_u128(d.mant - d.minus);
+    let mut high = Big::from_u128(d.mant + d.plus);
+    for _ in 0..cmp::max(k, 0) { v.mul_small(5); }
+    for _ in 0..cmp::max(-k, 0) { low.mul_small(5); high.mul_small(5); }
+    if k > e {
+        v.mul_pow2((k - e) as usize);
+    } else {
+        low.mul_pow2((e - k) as usize);
+        high.mul_pow2((e - k) as usize);
+    }
+
+    if d.inclusive { low <= v && v <= high } else { low < v && v < high }
+}
+
+fn count_disagreements<F, G, V>(func: &str, n: usize, mut f: F, mut g: G, mut v: V) -> usize
+        where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16),
+              G: FnMut(&Decoded, &mut [u8]) -> (usize, i16),
+              V: FnMut(usize) -> Decoded {
+    let mut nlonger = 0; // f(x) has more digits than g(x)
+    let mut nfailed = 0; // f(x) != g(x)
+
+    for i in 0..n {
+        if (i & 0xfffff) == 0 {
+            println!("in progress, {:x}/{:x} (longer={} failed={})", i, n, nlonger, nfailed);
+        }
+
+        let decoded = v(i);
+        let mut buf1 = [0; MAX_SIG_DIGITS];
+        let (len1, e1) = f(&decoded, &mut buf1);
+        // `f` may be longer than needed, but it should be still correct
+        assert!(is_within_interval(&decoded, &buf1[..len1], e1),
+                "{}: {:?} was formatted to 0.{}e{}, out of its rounding interval",
+                func, decoded, str::from_utf8(&buf1[..len1]).unwrap(), e1);
+        let mut buf2 = [0; MAX_SIG_DIGITS];
+        let (len2, e2) = g(&decoded, &mut buf2);
+        if e1 != e2 || &buf1[..len1] != &buf2[..len2] {
+            nfailed += 1;
+            if len1 > len2 { nlonger += 1; }
+        }
+    }
+
+    // this is not an error; the caller knows that `f` is not always optimal.
+    println!("{}: done, {} out of {} values ({:.4}%) disagree, {} of them are longer",
+             func, nfailed, n, nfailed as f64 * 100.0 / n as f64, nlonger);
+    nfailed
+}
+
+pub fn f32_random_disagreement_test<F, G>(f: F, g: G, n: usize) -> usize
+        where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16),
+              G: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f32_range = Range::new(0x0000_0001u32, 0x7f80_0000);
+    count_disagreements("f32_random_disagreement_test", n, f, g, |_| {
+        let i: u32 = f32_range.ind_sample(&mut rng);
+        let x: f32 = unsafe {mem::transmute(i)};
+        decode_finite(x)
+    })
+}
+
+pub fn f64_random_disagreement_test<F, G>(f: F, g: G, n: usize) -> usize
+        where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16),
+              G: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let f64_range = Range::new(0x0000_0000_0000_0001u64, 0x7ff0_0000_0000_0000);
+    count_disagreements("f64_random_disagreement_test", n, f, g, |_| {
+        let i: u64 = f64_range.ind_sample(&mut rng);
+        let x: f64 = unsafe {mem::transmute(i)};
+        decode_finite(x)
+    })
+}
+
+pub fn f32_exhaustive_disagreement_test<F, G>(f: F, g: G) -> usize
+        where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16),
+              G: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+    // see `f32_exhaustive_equivalence_test` for the cost.
+    count_disagreements("f32_exhaustive_disagreement_test", 0x7f7f_ffff, f, g, |i: usize| {
+        let x: f32 = unsafe {mem::transmute(i as u32 + 1)};
+        decode_finite(x)
+    })
 }
 
 fn to_string_with_parts<F>(mut f: F) -> String
@@ -1166,3 +1592,90 @@ pub fn to_exact_fixed_str_test<F>(mut f_: F)
                           2787392447107715776066783064379706047475337982177734375{:0>79881}", ""));
 }
 
+fn to_grouped_string_with_parts<F>(mut f: F, grouping: &Grouping<'static>) -> String
+        where F: for<'a> FnMut(&'a mut [u8], &'a mut [Part<'a>]) -> Formatted<'a> {
+    let mut buf = [0; 1024];
+    let mut parts = [Part::Zero(0); 16];
+    let mut grouped = [Part::Zero(0); 1024];
+    let formatted = f(&mut buf, &mut parts);
+    let nparts = formatted.grouped_nparts(grouping);
+    let formatted = formatted.group(grouping, &mut grouped[..nparts]);
+    assert_eq!(formatted.parts.len(), nparts);
+    let mut ret = vec![0; formatted.len()];
+    assert_eq!(formatted.write(&mut ret), Some(ret.len()));
+    String::from_utf8(ret).unwrap()
+}
+
+pub fn to_grouped_str_test<F, G>(mut f_: F, mut g_: G)
+        where F: FnMut(&Decoded, &mut [u8]) -> (usize, i16),
+              G: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+    use super::Sign::*;
+
+    fn shortest<T, F>(f: &mut F, v: T, grouping: &Grouping<'static>) -> String
+            where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+        to_grouped_string_with_parts(|buf, parts| to_shortest_str(|d,b| f(d,b), v, Minus,
+                                                                   0, false, buf, parts),
+                                     grouping)
+    }
+
+    fn shortest_exp<T, F>(f: &mut F, v: T, grouping: &Grouping<'static>) -> String
+            where T: DecodableFloat, F: FnMut(&Decoded, &mut [u8]) -> (usize, i16) {
+        to_grouped_string_with_parts(|buf, parts| to_shortest_exp_str(|d,b| f(d,b), v, Minus,
+                                                                       (-4, 16), false,
+                                                                       buf, parts),
+                                     grouping)
+    }
+
+    fn fixed<T, G>(g: &mut G, v: T, frac_digits: usize, grouping: &Grouping<'static>) -> String
+            where T: DecodableFloat, G: FnMut(&Decoded, &mut [u8], i16) -> (usize, i16) {
+        to_grouped_string_with_parts(|buf, parts| to_exact_fixed_str(|d,b,l| g(d,b,l), v, Minus,
+                                                                      frac_digits, false,
+                                                                      buf, parts),
+                                     grouping)
+    }
+
+    let f = &mut f_;
+    let g = &mut g_;
+
+    const NONE: Grouping<'static> = Grouping { groups: &[], ..grouping::COMMA };
+    const FRAC: Grouping<'static> = Grouping { frac_groups: &[3], ..grouping::COMMA };
+    const FRAC_SPACE: Grouping<'static> = Grouping { frac_groups: &[3], ..grouping::SPACE };
+    const ZERO: Grouping<'static> = Grouping { groups: &[0], ..grouping::COMMA };
+    const THREE_THEN_ZERO: Grouping<'static> =
+        Grouping { groups: &[3, 0], frac_groups: &[3, 0], ..grouping::COMMA };
+
+    assert_eq!(fixed(g, 1234567.891, 2, &grouping::COMMA), "1,234,567.89");
+    assert_eq!(fixed(g, 1234567.891, 2, &grouping::SPACE), "1 234 567,89");
+    assert_eq!(fixed(g, 1234567.891, 2, &grouping::INDIAN), "12,34,567.89");
+    assert_eq!(fixed(g, 1234567.891, 2, &NONE), "1234567.89");
+    assert_eq!(fixed(g, 1234567.891, 2, &ZERO), "1234567.89");
+    assert_eq!(fixed(g, 1234567.891, 2, &THREE_THEN_ZERO), "1234,567.89");
+    assert_eq!(fixed(g, 0.001234567, 9, &THREE_THEN_ZERO), "0.001,234567");
+    assert_eq!(fixed(g, -1234567.891, 0, &grouping::INDIAN), "-12,34,568");
+    assert_eq!(fixed(g, 123.0, 0, &grouping::COMMA), "123");
+    assert_eq!(fixed(g, 1234.0, 0, &grouping::COMMA), "1,234");
+    assert_eq!(fixed(g, 123456.0, 0, &grouping::COMMA), "123,456");
+    assert_eq!(fixed(g, 123456.0, 0, &grouping::INDIAN), "1,23,456");
+    assert_eq!(fixed(g, 0.0, 0, &grouping::COMMA), "0");
+    assert_eq!(fixed(g, 0.0, 7, &FRAC_SPACE), "0,000 000 0");
+    assert_eq!(fixed(g, 0.5, 3, &grouping::SPACE), "0,500");
+    assert_eq!(fixed(g, 0.001234567, 9, &FRAC), "0.001,234,567");
+    assert_eq!(fixed(g, 1234.5678, 4, &FRAC), "1,234.567,8");
+    assert_eq!(fixed(g, 1.0e21, 0, &grouping::COMMA), "1,000,000,000,000,000,000,000");
+    assert_eq!(fixed(g, 1.0e21, 0, &grouping::INDIAN), "1,00,00,00,00,00,00,00,00,00,000");
+    assert_eq!(fixed(g, 1.0e21, 5, &FRAC_SPACE), "1 000 000 000 000 000 000 000,000 00");
+    assert_eq!(fixed(g, 1.0/0.0, 3, &grouping::COMMA), "inf");
+    assert_eq!(fixed(g, 0.0/0.0, 3, &grouping::COMMA), "nan");
+
+    assert_eq!(shortest(f, 1234.5, &grouping::COMMA), "1,234.5");
+    assert_eq!(shortest(f, 0.1, &grouping::SPACE), "0,1");
+    assert_eq!(shortest(f, 1.0e7, &grouping::INDIAN), "1,00,00,000");
+    assert_eq!(shortest(f, 123456.789012, &FRAC), "123,456.789,012");
+    assert_eq!(shortest(f, 1.0e21, &grouping::COMMA), "1,000,000,000,000,000,000,000");
+
+    assert_eq!(shortest_exp(f, 12345.0, &grouping::COMMA), "12,345");
+    assert_eq!(shortest_exp(f, 1.2345678e20, &grouping::SPACE), "1,2345678e20");
+    assert_eq!(shortest_exp(f, 1.2345678e20, &FRAC), "1.234,567,8e20");
+    assert_eq!(shortest_exp(f, -1.2345678e-20, &FRAC_SPACE), "-1,234 567 8e-20");
+    assert_eq!(shortest_exp(f, 0.0, &grouping::SPACE), "0");
+}
@@ -0,0 +1,179 @@
+use std::prelude::v1::*;
+use std::{i16, f64};
+use std::num::FpCategory;
+
+use flt2dec::{decode, DecodableFloat, FullDecoded, Decoded, Part, Formatted};
+use flt2dec::{to_shortest_str, to_shortest_exp_str, to_exact_exp_str, to_exact_fixed_str};
+use flt2dec::to_hex_str;
+use flt2dec::quad::F128;
+use flt2dec::strategy::{dragon, grisu};
+use super::decode_finite;
+
+#[test]
+fn test_f128_decode() {
+    assert_eq!(decode(F128(0)), (false, FullDecoded::Zero));
+    assert_eq!(decode(F128(1 << 127)), (true, FullDecoded::Zero));
+    assert_eq!(decode(F128(0x7fff << 112)), (false, FullDecoded::Infinite));
+    assert_eq!(decode(F128(0xffff << 112)), (true, FullDecoded::Infinite));
+    assert_eq!(decode(F128((0x7fff << 112) | (1 << 111))).1, FullDecoded::Nan);
+    assert_eq!(decode(F128((0xffff << 112) | 1)).1, FullDecoded::Nan);
+
+    // 1.0, whose predecessor is closer than its successor
+    assert_eq!(decode_finite(F128(0x3fff << 112)),
+               Decoded { mant: 1 << 114, minus: 1, plus: 2, exp: -114, inclusive: true });
+    assert_eq!(decode(F128(0xbfff << 112)).0, true);
+    assert_eq!(decode_finite(F128((0x3fff << 112) | 1)),
+               Decoded { mant: (1 << 113) + 2, minus: 1, plus: 1, exp: -113, inclusive: false });
+    assert_eq!(decode_finite(F128(0x7ffeffffffffffffffffffffffffffff)),
+               Decoded { mant: (1 << 114) - 2, minus: 1, plus: 1, exp: 16270, inclusive: false });
+    assert_eq!(decode_finite(F128(1 << 112)),
+               Decoded { mant: 1 << 114, minus: 1, plus: 2, exp: -16496, inclusive: true });
+    assert_eq!(decode_finite(F128((1 << 112) - 1)),
+               Decoded { mant: (1 << 113) - 2, minus: 1, plus: 1, exp: -16495, inclusive: true });
+    assert_eq!(decode_finite(F128(1)),
+               Decoded { mant: 2, minus: 1, plus: 1, exp: -16495, inclusive: true });
+}
+
+#[test]
+fn test_f128_from_f64() {
+    assert_eq!(F128::from_f64(1.0), F128(0x3fff0000000000000000000000000000));
+    assert_eq!(F128::from_f64(-2.5), F128(0xc0004000000000000000000000000000));
+    assert_eq!(F128::from_f64(0.1), F128(0x3ffb999999999999a000000000000000));
+    assert_eq!(F128::from_f64(0.0), F128(0));
+    assert_eq!(F128::from_f64(-0.0), F128(1 << 127));
+    assert_eq!(F128::from_f64(1.0 / 0.0), F128(0x7fff << 112));
+    assert_eq!(F128::from_f64(-1.0 / 0.0), F128(0xffff << 112));
+    assert_eq!(F128::from_f64(0.0 / 0.0).classify(), FpCategory::Nan);
+
+    // `f64` subnormals become normal
+    assert_eq!(F128::from_f64(f64::MAX), F128(0x43fefffffffffffff000000000000000));
+    assert_eq!(F128::from_f64(f64::MIN_POSITIVE), F128(0x3c010000000000000000000000000000));
+    assert_eq!(F128::from_f64(DecodableFloat::ldexpi(1, -1074)),
+               F128(0x3bcd0000000000000000000000000000));
+}
+
+#[test]
+fn test_f128_ldexpi() {
+    fn ldexpi(f: i64, exp: isize) -> F128 { DecodableFloat::ldexpi(f, exp) }
+
+    assert_eq!(ldexpi(0, 100), F128(0));
+    assert_eq!(ldexpi(-3, -1), F128(0xbfff8000000000000000000000000000));
+    assert_eq!(ldexpi(i64::min_value(), 0), F128(0xc03e0000000000000000000000000000));
+
+    // overflows and underflows, with ties to even
+    assert_eq!(ldexpi(1, 16383), F128(0x7ffe0000000000000000000000000000));
+    assert_eq!(ldexpi(1, 16384), F128(0x7fff << 112));
+    assert_eq!(ldexpi(-1, 20000), F128(0xffff << 112));
+    assert_eq!(ldexpi(1, -16494), F128(1));
+    assert_eq!(ldexpi(1, -16495), F128(0));
+    assert_eq!(ldexpi(3, -16495), F128(2));
+    assert_eq!(ldexpi(-3, -16496), F128((1 << 127) | 1));
+    assert_eq!(ldexpi((1 << 62) + 1, -16500), F128(1 << 56));
+    assert_eq!(ldexpi(1, -20000), F128(0));
+}
+
+#[test]
+fn test_f128_grisu_fallback() {
+    // Grisu cannot handle 113-bit mantissas and should give up
+    let mut buf = [0; 1024];
+    let d = decode_finite(F128(0x7ffeffffffffffffffffffffffffffff));
+    assert_eq!(grisu::format_shortest_opt(&d, &mut buf), None);
+    assert_eq!(grisu::format_exact_opt(&d, &mut buf, i16::MIN), None);
+    let d = decode_finite(F128(0x3ffb999999999999999999999999999a));
+    assert_eq!(grisu::format_shortest_opt(&d, &mut buf), None);
+    assert_eq!(grisu::format_exact_opt(&d, &mut buf, i16::MIN), None);
+}
+
+fn to_string_with_parts<F>(mut f: F) -> String
+        where F: for<'a> FnMut(&'a mut [u8], &'a mut [Part<'a>]) -> Formatted<'a> {
+    // the exact mode needs much larger buffer for binary128
+    let mut buf = vec![0; 13000];
+    let mut parts = [Part::Zero(0); 16];
+    let formatted = f(&mut buf, &mut parts);
+    let mut ret = vec![0; formatted.len()];
+    assert_eq!(formatted.write(&mut ret), Some(ret.len()));
+    String::from_utf8(ret).unwrap()
+}
+
+#[test]
+fn test_f128_to_str() {
+    use flt2dec::Sign::*;
+    use flt2dec::strategy::dragon::{format_shortest, format_exact};
+
+    let max = F128(0x7ffeffffffffffffffffffffffffffff);
+    let pi = F128(0x4000921fb54442d18469898cc51701b8);
+    let minsub = F128(1);
+
+    assert_eq!(to_string_with_parts(|buf, parts| to_shortest_str(format_shortest, pi, Minus,
+                                                                  0, false, buf, parts)),
+               "3.1415926535897932384626433832795028");
+    assert_eq!(to_string_with_parts(|buf, parts| to_shortest_exp_str(format_shortest, max, Minus,
+                                                                      (-4, 16), false,
+                                                                      buf, parts)),
+               "1.189731495357231765085759326628007e4932");
+    assert_eq!(to_string_with_parts(|buf, parts| to_shortest_exp_str(format_shortest, minsub,
+                                                                      MinusPlus, (-4, 16), true,
+                                                                      buf, parts)),
+               "+6E-4966");
+    assert_eq!(to_string_with_parts(|buf, parts| to_exact_exp_str(format_exact, pi, Minus,
+                                                                   40, false, buf, parts)),
+               "3.141592653589793238462643383279502797479e0");
+
+    // the exact expansion of the minimum subnormal has 16,494 fractional digits,
+    // 11,530 of them significant
+    let s = to_string_with_parts(|buf, parts| to_exact_fixed_str(format_exact, minsub, Minus,
+                                                                 16500, false, buf, parts));
+    assert_eq!(s.len(), 16502);
+    assert!(s[2..4967].bytes().all(|c| c == b'0'));
+    assert_eq!(&s[4967..4977], "6475175119");
+    assert_eq!(&s[16490..16496], "515625");
+    assert_eq!(&s[16496..], "000000");
+
+    assert_eq!(to_string_with_parts(|buf, parts| to_hex_str(pi, Minus, None, false,
+                                                            buf, parts)),
+               "0x1.921fb54442d18469898cc51701b8p+1");
+    assert_eq!(to_string_with_parts(|buf, parts| to_hex_str(minsub, Minus, None, false,
+                                                            buf, parts)),
+               "0x0.0000000000000000000000000001p-16382");
+    assert_eq!(to_string_with_parts(|buf, parts| to_hex_str(max, Minus, Some(3), true,
+                                                            buf, parts)),
+               "0X2.000P+16383");
+}
+
+#[test]
+fn test_exact_fixed_buf_len() {
+    use flt2dec::Sign::*;
+    use flt2dec::strategy::dragon::format_exact;
+
+    // the wider mantissa of `F128` should not raise the buffer requirement of `f64`,
+    // which is `21 + floor(1076 * 12 / 16)` bytes for the minimum normal.
+    {
+        let mut buf = [0; 828];
+        let mut parts = [Part::Zero(0); 16];
+        let formatted = to_exact_fixed_str(format_exact, f64::MIN_POSITIVE, Minus, 2000, false,
+                                           &mut buf, &mut parts);
+        assert_eq!(formatted.len(), 2002);
+    }
+    {
+        let mut buf = [0; 828];
+        let mut parts = [Part::Zero(0); 16];
+        let formatted = to_exact_fixed_str(format_exact, 5e-324f64, Minus, 2000, false,
+                                           &mut buf, &mut parts);
+        assert_eq!(formatted.len(), 2002);
+    }
+}
+
+#[test]
+fn test_f128_dragon_consistency() {
+    // for the binary128 value `v` exactly equal to `f64` value `x`, the exact mode
+    // should agree (the shortest mode differs, since `v` has a much narrower interval)
+    let mut buf1 = [0; 1024];
+    let mut buf2 = [0; 1024];
+    for &x in &[1.0, 0.1, 1.0 / 3.0, 3.141592, 1.0e23, f64::MAX, f64::MIN_POSITIVE] {
+        let d1 = decode_finite(x);
+        let d2 = decode_finite(F128::from_f64(x));
+        assert_eq!(dragon::format_exact(&d1, &mut buf1, i16::MIN),
+                   dragon::format_exact(&d2, &mut buf2, i16::MIN));
+        assert_eq!(&buf1[..], &buf2[..]);
+    }
+}
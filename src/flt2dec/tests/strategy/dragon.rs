@@ -3,6 +3,8 @@ use std::{i16, f64};
 use super::super::*;
 use flt2dec::*;
 use flt2dec::bignum::Big32x36 as Big;
+use flt2dec::bignum::Big32x520 as WideBig;
+use flt2dec::quad::F128;
 use flt2dec::strategy::dragon::*;
 
 #[test]
@@ -14,12 +16,25 @@ fn test_mul_pow10() {
         assert_eq!(curpow10, *prevpow10.clone().mul_small(10));
         prevpow10 = curpow10;
     }
+
+    let mut prevpow10 = WideBig::from_small(1);
+    for i in 1..4966 {
+        let mut curpow10 = WideBig::from_small(1);
+        mul_pow10(&mut curpow10, i);
+        assert_eq!(curpow10, *prevpow10.clone().mul_small(10));
+        prevpow10 = curpow10;
+    }
 }
 
 #[test]
 fn shortest_sanity_test() {
     f64_shortest_sanity_test(format_shortest);
     f32_shortest_sanity_test(format_shortest);
+    f16_shortest_sanity_test(format_shortest);
+    bf16_shortest_sanity_test(format_shortest);
+    f128_shortest_sanity_test(format_shortest);
+    f80_shortest_sanity_test(format_shortest);
+    dd_shortest_sanity_test(format_shortest);
     more_shortest_sanity_test(format_shortest);
 }
 
@@ -27,6 +42,126 @@ fn shortest_sanity_test() {
 fn exact_sanity_test() {
     f64_exact_sanity_test(format_exact);
     f32_exact_sanity_test(format_exact);
+    f128_exact_sanity_test(format_exact);
+    f80_exact_sanity_test(format_exact);
+    dd_exact_sanity_test(format_exact);
+}
+
+// `d.mant` as the least significant digit first
+fn limbs(d: &Decoded) -> [u32; 4] {
+    [d.mant as u32, (d.mant >> 32) as u32, (d.mant >> 64) as u32, (d.mant >> 96) as u32]
+}
+
+#[test]
+fn shortest_limbs_equivalence_test() {
+    // the exponent range of `f64` does not matter except for subnormals,
+    // whose neighbors are (unlike `format_shortest_limbs`) narrower than 53 bits
+    f64_random_equivalence_test(|d, buf| {
+        if d.mant < 1 << 53 { return None; }
+        Some(format_shortest_limbs::<Big>(&limbs(d), d.exp as i32, 53, buf))
+    }, format_shortest, MAX_SIG_DIGITS, 10_000);
+
+    for &v in &[F128(0x3ffb999999999999999999999999999a), F128(0x3ffd5555555555555555555555555555),
+                F128(0x4000921fb54442d18469898cc51701b8), F128(0x403f0000000000000000000000000000),
+                F128(0x7ffeffffffffffffffffffffffffffff), F128(0x00010000000000000000000000000000),
+                F128(0x00010000000000000000000000000001)] {
+        let d = decode_finite(v);
+        let mut buf1 = [0; MAX_SIG_DIGITS_F128];
+        let mut buf2 = [0; MAX_SIG_DIGITS_F128];
+        let (len1, k1) = format_shortest_limbs::<WideBig>(&limbs(&d), d.exp as i32, 113, &mut buf1);
+        let (len2, k2) = format_shortest(&d, &mut buf2);
+        assert_eq!((&buf1[..len1], k1), (&buf2[..len2], k2));
+    }
+}
+
+#[test]
+fn exact_limbs_equivalence_test() {
+    for k in 1..21 {
+        f64_random_equivalence_test(|d, buf| {
+            Some(format_exact_limbs::<Big>(&limbs(d), d.exp as i32, 53, buf, i16::MIN))
+        }, |d, buf| format_exact(d, buf, i16::MIN), k, 1_000);
+    }
+}
+
+#[test]
+fn shortest_limbs_sanity_test() {
+    let mut buf = [0; 128];
+    let mut check = |mant: &[u32], exp: i32, prec: u32, expected: &[u8], expectedk: i16| {
+        let (len, k) = format_shortest_limbs::<WideBig>(mant, exp, prec, &mut buf);
+        assert_eq!((&buf[..len], k), (expected, expectedk));
+    };
+
+    // sqrt(2) in 256 bits
+    let sqrt2 = [0x83339915, 0xed17ac85, 0x893ba84c, 0x1d6f60ba,
+                 0x754abe9f, 0x597d89b3, 0xf9de6484, 0xb504f333];
+    check(&sqrt2, -255, 256,
+          b"14142135623730950488016887242096980785696718753769480731766797379907324784621", 1);
+
+    // 1/3 in 300 bits, where the last digit is not rounded up
+    let third = [0xaaaaaaaa, 0xaaaaaaaa, 0xaaaaaaaa, 0xaaaaaaaa, 0xaaaaaaaa,
+                 0xaaaaaaaa, 0xaaaaaaaa, 0xaaaaaaaa, 0xaaaaaaaa, 0xaaa];
+    check(&third, -301, 300,
+          b"33333333333333333333333333333333333333333333333333\
+            33333333333333333333333333333333333333332", 0);
+
+    // 10^100 needs 333 bits, and is exact with any more bits
+    let googol = [0, 0, 0, 0xa82e8f10, 0xaab24308, 0x8e211a7c,
+                  0xf38ace40, 0x84c4ce0b, 0x7ceb0b27, 0xad2594c3, 0x1249];
+    check(&googol, 0, 333, b"1", 101);
+    check(&googol, 0, 400, b"1", 101);
+    check(&googol[3..], 96, 333, b"1", 101);
+
+    // 2^-1000 has the asymmetric neighbors, and any representation of it gives same digits
+    check(&[1], -1000, 200, b"9332636185032188789900895447238171696170914463717080246217143", -301);
+    check(&[0, 0x100], -1040, 200,
+          b"9332636185032188789900895447238171696170914463717080246217143", -301);
+    check(&[0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xffffffff, 0xff],
+          -1200, 200, b"933263618503218878990089544723817169617091446371708024621714", -301);
+
+    // the precision of one bit
+    check(&[1], 0, 1, b"1", 1);
+    check(&[1], 10, 1, b"1", 4);
+    check(&[1], -1, 1, b"5", 0);
+}
+
+#[test]
+fn exact_limbs_sanity_test() {
+    let mut buf = [0; 100];
+    let mut check = |mant: &[u32], exp: i32, limit: i16, expected: &[u8], expectedk: i16| {
+        let (len, k) = format_exact_limbs::<WideBig>(mant, exp, 256, &mut buf[..80], limit);
+        assert_eq!((&buf[..len], k), (expected, expectedk));
+    };
+
+    let sqrt2 = [0x83339915, 0xed17ac85, 0x893ba84c, 0x1d6f60ba,
+                 0x754abe9f, 0x597d89b3, 0xf9de6484, 0xb504f333];
+    check(&sqrt2, -255, i16::MIN,
+          b"14142135623730950488016887242096980785696718753769480731766797379907324784621020", 1);
+    check(&sqrt2, -255, -10, b"14142135624", 1);
+    check(&[1], -1000, i16::MIN, b"93326361850321887899008954472381716961709144637170\
+                                   802462171433979596691097577563", -301);
+    check(&[1], -1000, -341, b"9332636185032188789900895447238171696171", -301);
+    check(&[1], -1000, -301, b"1", -300);
+}
+
+#[test]
+#[should_panic]
+fn test_shortest_limbs_too_precise() {
+    let mut buf = [0; 100];
+    format_shortest_limbs::<Big>(&[0xffffffff, 1], 0, 32, &mut buf);
+}
+
+#[test]
+#[should_panic]
+fn test_shortest_limbs_zero() {
+    let mut buf = [0; 100];
+    format_shortest_limbs::<Big>(&[0, 0], 0, 32, &mut buf);
+}
+
+#[test]
+#[should_panic]
+fn test_shortest_limbs_too_large_for_bignum() {
+    let mut buf = [0; 100];
+    format_shortest_limbs::<Big>(&[1], 2000, 53, &mut buf);
 }
 
 #[bench]
@@ -85,6 +220,20 @@ fn bench_big_exact_inf(b: &mut Bencher) {
     b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
 }
 
+#[bench]
+fn bench_f128_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(F128(0x7ffeffffffffffffffffffffffffffff));
+    let mut buf = [0; MAX_SIG_DIGITS_F128];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_f128_exact_12(b: &mut Bencher) {
+    let decoded = decode_finite(F128(0x7ffeffffffffffffffffffffffffffff));
+    let mut buf = [0; 12];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
 #[test]
 fn test_to_shortest_str() {
     to_shortest_str_test(format_shortest);
@@ -105,3 +254,8 @@ fn test_to_exact_fixed_str() {
     to_exact_fixed_str_test(format_exact);
 }
 
+#[test]
+fn test_to_grouped_str() {
+    to_grouped_str_test(format_shortest, format_exact);
+}
+
@@ -0,0 +1,122 @@
+use std::f64;
+use super::super::*;
+use flt2dec::*;
+use flt2dec::strategy::dragonbox::*;
+
+#[test]
+fn test_cache_tables() {
+    // powers of ten are exact as long as they fit, and otherwise rounded up.
+    assert_eq!(CACHE64[(0 - CACHE64_K_MIN) as usize], (1 << 63, 0));
+    assert_eq!(CACHE64[(1 - CACHE64_K_MIN) as usize], (0xa << 60, 0));
+    assert_eq!(CACHE32[(0 - CACHE32_K_MIN) as usize], 1 << 63);
+    assert_eq!(CACHE32[(1 - CACHE32_K_MIN) as usize], 0xa << 60);
+    for &(hi, _) in CACHE64.iter() { assert_eq!(hi.leading_zeros(), 0); }
+    for &c in CACHE32.iter() { assert_eq!(c.leading_zeros(), 0); }
+}
+
+#[test]
+fn test_log_approximations() {
+    // compare against the exact computation with the bit length of powers of two and ten
+    for e in 0..62 {
+        let pow2 = 1u64 << e;
+        assert_eq!(floor_log10_pow2(e), (pow2 as f64).log10().floor() as i32);
+        assert_eq!(floor_log10_pow2_minus_log10_4_over_3(e + 2),
+                   ((pow2 * 3) as f64).log10().floor() as i32);
+    }
+    let mut pow10 = 1u64;
+    for e in 0..20 {
+        assert_eq!(floor_log2_pow10(e), 63 - pow10.leading_zeros() as i32);
+        pow10 = pow10.wrapping_mul(10);
+    }
+}
+
+#[test]
+fn test_cache_bounds() {
+    // every exponent indexes the cache, with `kappa` subtracted unless it is a power of two,
+    // and the largest and smallest exponents reach both ends.
+    let ks: Vec<i32> = (-1074..972).flat_map(|e| vec![floor_log10_pow2(e) - 2,
+                                                      floor_log10_pow2_minus_log10_4_over_3(e)])
+                                   .map(|minus_k| -minus_k).collect();
+    assert_eq!(ks.iter().cloned().min(), Some(CACHE64_K_MIN));
+    assert_eq!(ks.iter().cloned().max(), Some(CACHE64_K_MIN + CACHE64.len() as i32 - 1));
+
+    let ks: Vec<i32> = (-149..105).flat_map(|e| vec![floor_log10_pow2(e) - 1,
+                                                     floor_log10_pow2_minus_log10_4_over_3(e)])
+                                  .map(|minus_k| -minus_k).collect();
+    assert_eq!(ks.iter().cloned().min(), Some(CACHE32_K_MIN));
+    assert_eq!(ks.iter().cloned().max(), Some(CACHE32_K_MIN + CACHE32.len() as i32 - 1));
+}
+
+#[test]
+fn test_shorter_interval() {
+    // powers of two have a shorter left half of the interval, which takes a separate path
+    // with the endpoints computed directly. they can be integers for small exponents
+    // (e.g. `2^54`), so both inclusive and exclusive intervals are compared against Dragon.
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+
+    let mut buf = [0; MAX_SIG_DIGITS];
+    let mut expected = [0; MAX_SIG_DIGITS];
+    let mut check = |d: Decoded| {
+        let (len, k) = format_shortest(&d, &mut buf);
+        let (expectedlen, expectedk) = fallback(&d, &mut expected);
+        assert!((&buf[..len], k) == (&expected[..expectedlen], expectedk),
+                "shortest mismatch for {:?}: actual {:?}, expected {:?}",
+                d, (&buf[..len], k), (&expected[..expectedlen], expectedk));
+    };
+    let pows = (-1074..972).map(|e| (1 << 54, e)).chain((-149..105).map(|e| (1 << 25, e)));
+    for (mant, e) in pows {
+        for &inclusive in [false, true].iter() {
+            check(Decoded { mant: mant, minus: 1, plus: 2, exp: (e - 2) as i16,
+                            inclusive: inclusive });
+        }
+    }
+}
+
+#[test]
+fn shortest_sanity_test() {
+    f64_shortest_sanity_test(format_shortest);
+    f32_shortest_sanity_test(format_shortest);
+    more_shortest_sanity_test(format_shortest);
+}
+
+#[test]
+fn shortest_random_equivalence_test() {
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 10_000);
+    f32_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f32_exhaustive_equivalence_test() {
+    // unlike Grisu, Dragonbox never gives up and every value should pass.
+
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    assert_eq!(f32_exhaustive_equivalence_test(|d, buf| Some(format_shortest(d, buf)),
+                                               fallback, MAX_SIG_DIGITS),
+               (2139095039, 0));
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f64_hard_random_equivalence_test() {
+    // this again probably has to use appropriate rustc flags.
+
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 100_000_000);
+}
+
+#[bench]
+fn bench_small_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_big_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
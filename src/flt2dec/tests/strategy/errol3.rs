@@ -0,0 +1,56 @@
+use std::f64;
+use super::super::*;
+use flt2dec::*;
+use flt2dec::strategy::errol3::*;
+
+#[test]
+fn test_pow10() {
+    // `val` is the closest `f64` to the power of ten, and `off` is that to the remainder.
+    assert_eq!(POW10[(0 - POW10_FIRST) as usize], (1.0, 0.0));
+    assert_eq!(POW10[(22 - POW10_FIRST) as usize], (1.0e22, 0.0));
+    assert_eq!(POW10[(23 - POW10_FIRST) as usize], (1.0e23, 8388608.0));
+    assert_eq!(POW10[(-1 - POW10_FIRST) as usize].1, -5.551115123125783e-18);
+    assert_eq!(POW10.len() as i32, POW10_LAST - POW10_FIRST + 1);
+}
+
+#[test]
+fn test_hp() {
+    let mut x = Hp { val: 0.1, off: -5.551115123125783e-18 }; // 0.1 to about 106 bits
+    x.mul10();
+    assert_eq!((x.val, x.off), (1.0, 0.0));
+    x.div10();
+    assert_eq!((x.val, x.off), (0.1, -5.551115123125783e-18));
+
+    // 3 * 0.1 = 0.30000000000000001665... is not exact in `f64`.
+    let y = Hp { val: 0.1, off: 0.0 }.mul(3.0);
+    assert_eq!(y.val, 0.30000000000000004);
+    assert!(y.off < 0.0);
+}
+
+#[test]
+fn shortest_random_disagreement_test() {
+    // Errol3 is not always optimal, so we only report how often it disagrees with Dragon.
+    use flt2dec::strategy::dragon::format_shortest as reference;
+    f64_random_disagreement_test(format_shortest, reference, 10_000);
+    f32_random_disagreement_test(format_shortest, reference, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f32_exhaustive_disagreement_test() {
+    use flt2dec::strategy::dragon::format_shortest as reference;
+    f32_exhaustive_disagreement_test(format_shortest, reference);
+}
+
+#[bench]
+fn bench_small_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_big_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
@@ -8,7 +8,7 @@ fn test_cached_power() {
     assert_eq!(CACHED_POW10.first().unwrap().1, CACHED_POW10_FIRST_E);
     assert_eq!(CACHED_POW10.last().unwrap().1, CACHED_POW10_LAST_E);
 
-    for e in -1137..961 { // full range for f64
+    for e in MIN_NORMALIZED_E..MAX_NORMALIZED_E+1 { // full range for f64
         let low = ALPHA - e - 64;
         let high = GAMMA - e - 64;
         let (_k, cached) = cached_power(low, high);
@@ -33,6 +33,11 @@ fn test_max_pow10_no_more_than() {
 fn shortest_sanity_test() {
     f64_shortest_sanity_test(format_shortest);
     f32_shortest_sanity_test(format_shortest);
+    f16_shortest_sanity_test(format_shortest);
+    bf16_shortest_sanity_test(format_shortest);
+    f128_shortest_sanity_test(format_shortest);
+    f80_shortest_sanity_test(format_shortest);
+    dd_shortest_sanity_test(format_shortest);
     more_shortest_sanity_test(format_shortest);
 }
 
@@ -43,6 +48,23 @@ fn shortest_random_equivalence_test() {
     f32_random_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS, 10_000);
 }
 
+#[test]
+fn shortest_f16_exhaustive_equivalence_test() {
+    // 16-bit types have so few values that we can always test all of them.
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    f16_exhaustive_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS);
+    bf16_exhaustive_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS);
+}
+
+#[test]
+fn shortest_minifloat_exhaustive_equivalence_test() {
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    for format in MINIFLOAT_FORMATS.iter() {
+        minifloat_exhaustive_equivalence_test(format, format_shortest_opt, fallback,
+                                              MAX_SIG_DIGITS);
+    }
+}
+
 #[test] #[ignore] // it is too expensive
 fn shortest_f32_exhaustive_equivalence_test() {
     // it is hard to directly test the optimality of the output, but we can at least test if
@@ -53,7 +75,8 @@ fn shortest_f32_exhaustive_equivalence_test() {
     // `done, ignored=17643158 passed=2121451881 failed=0`.
 
     use flt2dec::strategy::dragon::format_shortest as fallback;
-    f32_exhaustive_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS);
+    assert_eq!(f32_exhaustive_equivalence_test(format_shortest_opt, fallback, MAX_SIG_DIGITS),
+               (2121451881, 17643158));
 }
 
 #[test] #[ignore] // it is too expensive
@@ -69,6 +92,9 @@ fn shortest_f64_hard_random_equivalence_test() {
 fn exact_sanity_test() {
     f64_exact_sanity_test(format_exact);
     f32_exact_sanity_test(format_exact);
+    f128_exact_sanity_test(format_exact);
+    f80_exact_sanity_test(format_exact);
+    dd_exact_sanity_test(format_exact);
 }
 
 #[test]
@@ -89,6 +115,29 @@ fn exact_f64_random_equivalence_test() {
     }
 }
 
+#[test]
+fn exact_f16_exhaustive_equivalence_test() {
+    use flt2dec::strategy::dragon::format_exact as fallback;
+    for k in 1..8 {
+        f16_exhaustive_equivalence_test(|d, buf| format_exact_opt(d, buf, i16::MIN),
+                                        |d, buf| fallback(d, buf, i16::MIN), k);
+        bf16_exhaustive_equivalence_test(|d, buf| format_exact_opt(d, buf, i16::MIN),
+                                         |d, buf| fallback(d, buf, i16::MIN), k);
+    }
+}
+
+#[test]
+fn exact_minifloat_exhaustive_equivalence_test() {
+    use flt2dec::strategy::dragon::format_exact as fallback;
+    for format in MINIFLOAT_FORMATS.iter() {
+        for k in 1..8 {
+            minifloat_exhaustive_equivalence_test(format,
+                                                  |d, buf| format_exact_opt(d, buf, i16::MIN),
+                                                  |d, buf| fallback(d, buf, i16::MIN), k);
+        }
+    }
+}
+
 #[bench]
 fn bench_small_shortest(b: &mut Bencher) {
     let decoded = decode_finite(3.141592f64);
@@ -165,3 +214,8 @@ fn test_to_exact_fixed_str() {
     to_exact_fixed_str_test(format_exact);
 }
 
+#[test]
+fn test_to_grouped_str() {
+    to_grouped_str_test(format_shortest, format_exact);
+}
+
@@ -0,0 +1,32 @@
+use std::f64;
+use super::super::*;
+use flt2dec::*;
+use flt2dec::strategy::grisu2::*;
+
+#[test]
+fn shortest_random_disagreement_test() {
+    // Grisu2 is not always optimal, so we only report how often it disagrees with Dragon.
+    use flt2dec::strategy::dragon::format_shortest as reference;
+    f64_random_disagreement_test(format_shortest, reference, 10_000);
+    f32_random_disagreement_test(format_shortest, reference, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f32_exhaustive_disagreement_test() {
+    use flt2dec::strategy::dragon::format_shortest as reference;
+    f32_exhaustive_disagreement_test(format_shortest, reference);
+}
+
+#[bench]
+fn bench_small_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_big_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
@@ -0,0 +1,98 @@
+use std::{i16, f64};
+use super::super::*;
+use flt2dec::*;
+use flt2dec::strategy::runtime::*;
+
+#[test]
+fn shortest_sanity_test() {
+    f64_shortest_sanity_test(format_shortest);
+    f32_shortest_sanity_test(format_shortest);
+    more_shortest_sanity_test(format_shortest);
+}
+
+#[test]
+fn exact_sanity_test() {
+    f64_exact_sanity_test(format_exact);
+    f32_exact_sanity_test(format_exact);
+}
+
+#[test]
+fn candidates_sanity_test() {
+    // every candidate should be correct for any input, as other tests may select them
+    for &(_, f) in FORMAT_SHORTEST.candidates {
+        f64_shortest_sanity_test(f);
+        f32_shortest_sanity_test(f);
+        more_shortest_sanity_test(f);
+    }
+    for &(_, f) in FORMAT_EXACT.candidates {
+        f64_exact_sanity_test(f);
+        f32_exact_sanity_test(f);
+        f128_exact_sanity_test(f);
+    }
+}
+
+#[test]
+fn dispatch_test() {
+    use std::sync::atomic::ATOMIC_USIZE_INIT;
+    use dispatch::Dispatch;
+
+    // the global choices are shared with other tests running in parallel,
+    // so this works on private copies of them instead.
+    let shortest = Dispatch { candidates: FORMAT_SHORTEST.candidates,
+                              default: FORMAT_SHORTEST.default, choice: ATOMIC_USIZE_INIT };
+    let exact = Dispatch { candidates: FORMAT_EXACT.candidates,
+                           default: FORMAT_EXACT.default, choice: ATOMIC_USIZE_INIT };
+
+    // every candidate should be selectable
+    for &(name, f) in shortest.candidates {
+        assert!(shortest.select(name));
+        assert_eq!(shortest.name(), name);
+        assert!(shortest.get() as usize == f as usize);
+    }
+    for &(name, f) in exact.candidates {
+        assert!(exact.select(name));
+        assert_eq!(exact.name(), name);
+        assert!(exact.get() as usize == f as usize);
+    }
+    assert!(!shortest.select("no such strategy"));
+    assert_eq!(shortest.name(), shortest.candidates.last().unwrap().0);
+
+    // with a clock that never advances, the first candidate should be chosen
+    assert_eq!(shortest.calibrate(&mut || 0, &mut |_| {}), shortest.candidates[0].0);
+    assert_eq!(shortest.name(), shortest.candidates[0].0);
+    assert_eq!(exact.calibrate(&mut || 0, &mut |_| {}), exact.candidates[0].0);
+    assert_eq!(exact.name(), exact.candidates[0].0);
+
+    shortest.reset();
+    exact.reset();
+    assert_eq!(shortest.name(), "grisu");
+    assert_eq!(exact.name(), "grisu");
+}
+
+#[bench]
+fn bench_small_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_big_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_small_exact_inf(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; 1024];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
+#[bench]
+fn bench_big_exact_inf(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; 1024];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
@@ -0,0 +1,82 @@
+use std::f64;
+use super::super::*;
+use flt2dec::*;
+use flt2dec::strategy::ryu::*;
+
+#[test]
+fn test_pow5_split() {
+    assert_eq!(POW5_INV_SPLIT[0], (1 << 61, 1));
+    assert_eq!(POW5_SPLIT[0], (1 << 60, 0));
+    // every entry has exactly `POW5_BITCOUNT` (or `POW5_INV_BITCOUNT`) bits,
+    // except for the inverse of 1 which is rounded up to `2^125 + 1`.
+    for &(hi, _) in POW5_SPLIT.iter() {
+        assert_eq!(hi.leading_zeros() as i32, 128 - POW5_BITCOUNT);
+    }
+    for &(hi, _) in POW5_INV_SPLIT[1..].iter() {
+        assert_eq!(hi.leading_zeros() as i32, 128 - POW5_INV_BITCOUNT);
+    }
+}
+
+#[test]
+fn test_log_approximations() {
+    // compare against the exact computation with the bit length of powers of five
+    let mut pow5 = 1u64;
+    for e in 0..28 {
+        let nbits = 64 - pow5.leading_zeros() as i32;
+        assert_eq!(pow5bits(e), if e == 0 { 1 } else { nbits });
+        assert_eq!(log10_pow5(e), (pow5 as f64).log10().floor() as i32);
+        pow5 = pow5.wrapping_mul(5);
+    }
+    for e in 0..64 {
+        assert_eq!(log10_pow2(e), ((1u64 << e) as f64).log10().floor() as i32);
+    }
+}
+
+#[test]
+fn shortest_sanity_test() {
+    f64_shortest_sanity_test(format_shortest);
+    f32_shortest_sanity_test(format_shortest);
+    more_shortest_sanity_test(format_shortest);
+}
+
+#[test]
+fn shortest_random_equivalence_test() {
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 10_000);
+    f32_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f32_exhaustive_equivalence_test() {
+    // unlike Grisu, Ryu never gives up and every value should pass.
+
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    assert_eq!(f32_exhaustive_equivalence_test(|d, buf| Some(format_shortest(d, buf)),
+                                               fallback, MAX_SIG_DIGITS),
+               (2139095039, 0));
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f64_hard_random_equivalence_test() {
+    // this again probably has to use appropriate rustc flags.
+
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 100_000_000);
+}
+
+#[bench]
+fn bench_small_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_big_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
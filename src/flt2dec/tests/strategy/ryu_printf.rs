@@ -0,0 +1,136 @@
+use std::{i16, f64};
+use super::super::*;
+use flt2dec::*;
+use flt2dec::strategy::ryu_printf::*;
+
+#[test]
+fn test_pow10_split() {
+    assert_eq!(POW10_OFFSET[POW10_OFFSET.len() - 1] as usize, POW10_SPLIT.len());
+    assert_eq!(POW10_OFFSET_2[POW10_OFFSET_2.len() - 1] as usize, POW10_SPLIT_2.len());
+    for w in POW10_OFFSET.windows(2) { assert!(w[0] < w[1]); }
+    for w in POW10_OFFSET_2.windows(2) { assert!(w[0] < w[1]); }
+    for w in MIN_BLOCK_2.windows(2) { assert!(w[0] <= w[1]); }
+
+    // 2^120 / 10^0 and 10^9 * 2^120 / 2^0, both exact.
+    assert_eq!(POW10_SPLIT[0], (0, 1 << 56, 0));
+    assert_eq!(POW10_SPLIT_2[0], (1000000000 >> 8, 0, 0));
+    // every entry is reduced modulo `10^9 * 2^136`.
+    for &(hi, _, _) in POW10_SPLIT.iter().chain(POW10_SPLIT_2.iter()) {
+        assert!(hi < 1000000000 << 8);
+    }
+}
+
+#[test]
+fn test_block() {
+    assert_eq!(block(12345678901, 0, 0), 345678901);
+    assert_eq!(block(12345678901, 0, 1), 12);
+    assert_eq!(block(12345678901, 0, 2), 0);
+    assert_eq!(block(12345678901, 0, -1), 0);
+    assert_eq!(block(3, 40, 0), 534883328); // 3 * 2^40 = 3298534883328
+    assert_eq!(block(3, 40, 1), 3298);
+    assert_eq!(block(1, -1, 0), 0);
+    assert_eq!(block(1, -1, -1), 500000000);
+    assert_eq!(block(1, -1, -2), 0);
+    assert_eq!(block(7, -3, 0), 0); // 0.875
+    assert_eq!(block(7, -3, -1), 875000000);
+    assert_eq!(block(25, -1, 0), 12); // 12.5
+    assert_eq!(block(25, -1, -1), 500000000);
+    assert_eq!(block(1, -10, -1), 976562); // 2^-10 = 0.0009765625
+    assert_eq!(block(1, -10, -2), 500000000);
+
+    // 2^-1074 = 10^-324 * 4.940656458412465441765687928682213723650598026143247644255...
+    assert_eq!(block(1, -1074, -35), 0);
+    assert_eq!(block(1, -1074, -36), 4);
+    assert_eq!(block(1, -1074, -37), 940656458);
+    assert_eq!(block(1, -1074, -38), 412465441);
+    assert_eq!(block(1, -1074, -39), 765687928);
+
+    // f64::MAX = 179769313486231570814527423731704356798070567525844996598917476803...
+    //            ...6184124858368 (309 digits)
+    assert_eq!(block((1 << 53) - 1, 971, 34), 179);
+    assert_eq!(block((1 << 53) - 1, 971, 33), 769313486);
+    assert_eq!(block((1 << 53) - 1, 971, 32), 231570814);
+    assert_eq!(block((1 << 53) - 1, 971, 0), 124858368);
+}
+
+#[test]
+fn exact_sanity_test() {
+    f64_exact_sanity_test(format_exact);
+    f32_exact_sanity_test(format_exact);
+    f128_exact_sanity_test(format_exact);
+    f80_exact_sanity_test(format_exact);
+    dd_exact_sanity_test(format_exact);
+}
+
+#[test]
+fn exact_f32_random_equivalence_test() {
+    use flt2dec::strategy::dragon::format_exact as fallback;
+    for k in 1..21 {
+        f32_random_equivalence_test(|d, buf| Some(format_exact(d, buf, i16::MIN)),
+                                    |d, buf| fallback(d, buf, i16::MIN), k, 1_000);
+    }
+}
+
+#[test]
+fn exact_f64_random_equivalence_test() {
+    use flt2dec::strategy::dragon::format_exact as fallback;
+    for k in 1..21 {
+        f64_random_equivalence_test(|d, buf| Some(format_exact(d, buf, i16::MIN)),
+                                    |d, buf| fallback(d, buf, i16::MIN), k, 1_000);
+    }
+    // every significant digit, possibly hundreds of them.
+    f64_random_equivalence_test(|d, buf| Some(format_exact(d, buf, i16::MIN)),
+                                |d, buf| fallback(d, buf, i16::MIN), 800, 1_000);
+}
+
+#[bench]
+fn bench_small_exact_3(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; 3];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
+#[bench]
+fn bench_big_exact_3(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; 3];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
+#[bench]
+fn bench_small_exact_12(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; 12];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
+#[bench]
+fn bench_big_exact_12(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; 12];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
+#[bench]
+fn bench_small_exact_inf(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; 1024];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
+#[bench]
+fn bench_big_exact_inf(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; 1024];
+    b.iter(|| format_exact(&decoded, &mut buf, i16::MIN));
+}
+
+#[test]
+fn test_to_exact_exp_str() {
+    to_exact_exp_str_test(format_exact);
+}
+
+#[test]
+fn test_to_exact_fixed_str() {
+    to_exact_fixed_str_test(format_exact);
+}
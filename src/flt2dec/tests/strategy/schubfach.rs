@@ -0,0 +1,111 @@
+use std::f64;
+use super::super::*;
+use flt2dec::*;
+use flt2dec::strategy::schubfach::*;
+
+#[test]
+fn test_g_table() {
+    // every entry has exactly 126 bits, and 10^0 is rounded up to `2^125 + 1`.
+    assert_eq!(G[(0 - K_MIN) as usize], (1 << 62, 1));
+    for &(g1, g0) in G.iter() {
+        assert_eq!(g1.leading_zeros(), 1);
+        assert!(g0 < 1 << 63);
+    }
+}
+
+#[test]
+fn test_log_approximations() {
+    // compare against the exact computation with the bit length of powers of two and ten
+    for e in 0..62 {
+        let pow2 = 1u64 << e;
+        assert_eq!(flog10pow2(e), (pow2 as f64).log10().floor() as i32);
+        assert_eq!(flog10_three_quarters_pow2(e + 2), ((pow2 * 3) as f64).log10().floor() as i32);
+    }
+    let mut pow10 = 1u64;
+    for e in 0..20 {
+        assert_eq!(flog2pow10(e), 63 - pow10.leading_zeros() as i32);
+        pow10 = pow10.wrapping_mul(10);
+    }
+}
+
+#[test]
+fn test_g_table_bounds() {
+    // every `f64` exponent (and thus every `f32` exponent) indexes `G`, and reaches both ends.
+    let ks: Vec<i32> = (-1074..972).flat_map(|q| vec![flog10pow2(q),
+                                                      flog10_three_quarters_pow2(q)]).collect();
+    assert_eq!(ks.iter().cloned().min(), Some(K_MIN));
+    assert_eq!(ks.iter().cloned().max(), Some(K_MAX));
+    assert_eq!(G.len() as i32, K_MAX - K_MIN + 1);
+
+    // the original scales `5e-324` by 10 to get two digits, but we use `G[0]` as is.
+    let mut buf = [0; MAX_SIG_DIGITS];
+    let mut check = |d: Decoded, expected: &[u8], expectedk: i16| {
+        let (len, k) = format_shortest(&d, &mut buf);
+        assert_eq!((&buf[..len], k), (expected, expectedk));
+    };
+    check(decode_finite(5e-324f64), b"5", -323);
+    check(decode_finite(f64::MAX), b"17976931348623157", 309);
+    check(decode_finite(8.98846567431158e307f64), b"898846567431158", 308);
+}
+
+#[test]
+fn test_exact_ties() {
+    // `v` is exactly halfway between two candidates in the interval,
+    // which the original rounds to even but we round up as other strategies do.
+    let mut buf = [0; MAX_SIG_DIGITS];
+    let mut check = |d: Decoded, expected: &[u8], expectedk: i16| {
+        let (len, k) = format_shortest(&d, &mut buf);
+        assert_eq!((&buf[..len], k), (expected, expectedk));
+    };
+    check(decode_finite(1048576.25f32), b"10485763", 7);
+    check(decode_finite(562949953421312.25f64), b"5629499534213123", 15);
+}
+
+#[test]
+fn shortest_sanity_test() {
+    f64_shortest_sanity_test(format_shortest);
+    f32_shortest_sanity_test(format_shortest);
+    more_shortest_sanity_test(format_shortest);
+}
+
+#[test]
+fn shortest_random_equivalence_test() {
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 10_000);
+    f32_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 10_000);
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f32_exhaustive_equivalence_test() {
+    // unlike Grisu, Schubfach never gives up and every value should pass.
+
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    assert_eq!(f32_exhaustive_equivalence_test(|d, buf| Some(format_shortest(d, buf)),
+                                               fallback, MAX_SIG_DIGITS),
+               (2139095039, 0));
+}
+
+#[test] #[ignore] // it is too expensive
+fn shortest_f64_hard_random_equivalence_test() {
+    // this again probably has to use appropriate rustc flags.
+
+    use flt2dec::strategy::dragon::format_shortest as fallback;
+    f64_random_equivalence_test(|d, buf| Some(format_shortest(d, buf)), fallback,
+                                MAX_SIG_DIGITS, 100_000_000);
+}
+
+#[bench]
+fn bench_small_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(3.141592f64);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
+
+#[bench]
+fn bench_big_shortest(b: &mut Bencher) {
+    let decoded = decode_finite(f64::MAX);
+    let mut buf = [0; MAX_SIG_DIGITS];
+    b.iter(|| format_shortest(&decoded, &mut buf));
+}
@@ -0,0 +1,203 @@
+use std::prelude::v1::*;
+use std::{str, i16, f64};
+use std::num::FpCategory;
+use rand;
+use rand::distributions::{IndependentSample, Range};
+
+use flt2dec::{decode, DecodableFloat, FullDecoded, Decoded, shortest_buf_len};
+use flt2dec::{MAX_SIG_DIGITS, MAX_SIG_DIGITS_VAXD, MAX_SIG_DIGITS_F128};
+use flt2dec::vax::{VaxF, VaxD, VaxG};
+use flt2dec::strategy::dragon::{format_shortest, format_exact};
+use flt2dec::strategy::grisu;
+use super::{decode_finite, check_exact};
+
+#[test]
+fn test_vax_decode() {
+    assert_eq!(decode(VaxF(0x00000000)), (false, FullDecoded::Zero));
+    assert_eq!(decode(VaxF(0x00001234)), (false, FullDecoded::Zero)); // a dirty zero
+    assert_eq!(decode(VaxF(0x80000000)).1, FullDecoded::Nan); // the reserved operand
+    assert_eq!(decode(VaxF(0x80001234)).1, FullDecoded::Nan);
+    assert_eq!(decode(VaxD(0x8000000000000000)).1, FullDecoded::Nan);
+    assert_eq!(decode(VaxG(0x8000000000000000)).1, FullDecoded::Nan);
+
+    // 1.0, whose predecessor is closer than its successor
+    assert_eq!(decode_finite(VaxF(0x40800000)),
+               Decoded { mant: 1 << 25, minus: 1, plus: 2, exp: -25, inclusive: true });
+    assert_eq!(decode(VaxF(0xc0800000)).0, true);
+    assert_eq!(decode_finite(VaxF(0x40800001)),
+               Decoded { mant: (1 << 24) + 2, minus: 1, plus: 1, exp: -24, inclusive: false });
+    assert_eq!(decode_finite(VaxF(0x7fffffff)),
+               Decoded { mant: (1 << 25) - 2, minus: 1, plus: 1, exp: 102, inclusive: false });
+    assert_eq!(decode_finite(VaxF(0x00800000)),
+               Decoded { mant: 1 << 25, minus: 1, plus: 2, exp: -153, inclusive: true });
+
+    assert_eq!(decode_finite(VaxD(0x4080000000000000)),
+               Decoded { mant: 1 << 57, minus: 1, plus: 2, exp: -57, inclusive: true });
+    assert_eq!(decode_finite(VaxG(0x4010000000000000)),
+               Decoded { mant: 1 << 54, minus: 1, plus: 2, exp: -54, inclusive: true });
+    assert_eq!(decode_finite(VaxG(0x0010000000000001)),
+               Decoded { mant: (1 << 53) + 2, minus: 1, plus: 1, exp: -1077, inclusive: false });
+}
+
+#[test]
+fn test_vax_from_f64() {
+    assert_eq!(VaxF::from_f64(1.0), Some(VaxF(0x40800000)));
+    assert_eq!(VaxF::from_f64(0.1), Some(VaxF(0x3ecccccd)));
+    assert_eq!(VaxF::from_f64(-0.1), Some(VaxF(0xbecccccd)));
+    assert_eq!(VaxF::from_f64(1.0 / 3.0), Some(VaxF(0x3faaaaab)));
+    assert_eq!(VaxF::from_f64(3.141592653589793), Some(VaxF(0x41490fdb)));
+    assert_eq!(VaxD::from_f64(0.1), Some(VaxD(0x3eccccccccccccd0)));
+    assert_eq!(VaxD::from_f64(3.141592653589793), Some(VaxD(0x41490fdaa22168c0)));
+    assert_eq!(VaxG::from_f64(0.1), Some(VaxG(0x3fd999999999999a)));
+    assert_eq!(VaxG::from_f64(3.141592653589793), Some(VaxG(0x402921fb54442d18)));
+
+    // there are no negative zero, infinities nor NaNs
+    assert_eq!(VaxF::from_f64(-0.0), Some(VaxF(0x00000000)));
+    assert_eq!(VaxD::from_f64(-0.0), Some(VaxD(0x0000000000000000)));
+    assert_eq!(VaxF::from_f64(0.0 / 0.0), None);
+    assert_eq!(VaxD::from_f64(1.0 / 0.0), None);
+    assert_eq!(VaxG::from_f64(-1.0 / 0.0), None);
+
+    // saturates when too large, and rounds to zero or the minimum when too small
+    let ldexp = |x: f64, e: isize| x * <f64 as DecodableFloat>::ldexpi(1, e);
+    assert_eq!(VaxF::from_f64(1.0e39), Some(VaxF(0x7fffffff)));
+    assert_eq!(VaxF::from_f64(-1.0e39), Some(VaxF(0xffffffff)));
+    assert_eq!(VaxD::from_f64(f64::MAX), Some(VaxD(0x7fffffffffffffff)));
+    assert_eq!(VaxG::from_f64(1.0e39), Some(VaxG(0x48278287f49c4a1d)));
+    assert_eq!(VaxG::from_f64(f64::MAX), Some(VaxG(0x7fffffffffffffff)));
+    assert_eq!(VaxF::from_f64(ldexp(1.0, -128)), Some(VaxF(0x00800000)));
+    assert_eq!(VaxF::from_f64(ldexp(1.0, -129)), Some(VaxF(0x00000000)));
+    assert_eq!(VaxF::from_f64(ldexp(1.5, -129)), Some(VaxF(0x00800000)));
+    assert_eq!(VaxF::from_f64(ldexp(-1.5, -129)), Some(VaxF(0x80800000)));
+    assert_eq!(VaxF::from_f64(1.0e-40), Some(VaxF(0x00000000)));
+    assert_eq!(VaxG::from_f64(1.0e-40), Some(VaxG(0x37c16c262777579c)));
+    assert_eq!(VaxG::from_f64(ldexp(1.0, -1024)), Some(VaxG(0x0010000000000000)));
+    assert_eq!(VaxG::from_f64(5e-324), Some(VaxG(0x0000000000000000)));
+
+    // ties to even, possibly to the next binade
+    assert_eq!(VaxF::from_f64(1.0 + ldexp(1.0, -24)), Some(VaxF(0x40800000)));
+    assert_eq!(VaxF::from_f64(1.0 + ldexp(3.0, -24)), Some(VaxF(0x40800002)));
+    assert_eq!(VaxF::from_f64(2.0 - ldexp(1.0, -25)), Some(VaxF(0x41000000)));
+}
+
+#[test]
+fn test_vax_ldexpi() {
+    fn ldexpi(f: i64, exp: isize) -> VaxF { DecodableFloat::ldexpi(f, exp) }
+
+    assert_eq!(ldexpi(0, 100), VaxF(0x00000000));
+    assert_eq!(ldexpi(1, 0), VaxF(0x40800000));
+    assert_eq!(ldexpi(-3, -1), VaxF(0xc0c00000));
+    assert_eq!(ldexpi(i64::max_value(), 0), VaxF(0x60000000));
+    assert_eq!(ldexpi(1, 126), VaxF(0x7f800000));
+    assert_eq!(ldexpi(1, 127), VaxF(0x7fffffff));
+    assert_eq!(ldexpi(1, -128), VaxF(0x00800000));
+    assert_eq!(ldexpi(3, -130), VaxF(0x00800000));
+    assert_eq!(ldexpi(1, -129), VaxF(0x00000000));
+    assert_eq!(<VaxF as DecodableFloat>::min_pos_norm_value().classify(), FpCategory::Normal);
+}
+
+#[test]
+fn test_vax_bytes() {
+    assert_eq!(VaxF(0x40800000).to_bytes(), [0x80, 0x40, 0x00, 0x00]);
+    assert_eq!(VaxF(0x41490fdb).to_bytes(), [0x49, 0x41, 0xdb, 0x0f]);
+    assert_eq!(VaxF::from_bytes([0x49, 0x41, 0xdb, 0x0f]), VaxF(0x41490fdb));
+    assert_eq!(VaxD(0x41490fdaa22168c0).to_bytes(),
+               [0x49, 0x41, 0xda, 0x0f, 0x21, 0xa2, 0xc0, 0x68]);
+    assert_eq!(VaxD::from_bytes([0x49, 0x41, 0xda, 0x0f, 0x21, 0xa2, 0xc0, 0x68]),
+               VaxD(0x41490fdaa22168c0));
+    assert_eq!(VaxG(0x402921fb54442d18).to_bytes(),
+               [0x29, 0x40, 0xfb, 0x21, 0x44, 0x54, 0x18, 0x2d]);
+    assert_eq!(VaxG::from_bytes([0x29, 0x40, 0xfb, 0x21, 0x44, 0x54, 0x18, 0x2d]),
+               VaxG(0x402921fb54442d18));
+}
+
+#[test]
+fn test_vax_shortest() {
+    assert_eq!(shortest(VaxF(0x40800000)), ("1".to_string(), 1));
+    assert_eq!(shortest(VaxF(0x3ecccccd)), ("1".to_string(), 0));
+    assert_eq!(shortest(VaxF(0x7fffffff)), ("17014117".to_string(), 39));
+    assert_eq!(shortest(VaxF(0x00800000)), ("2938736".to_string(), -38));
+    assert_eq!(shortest(VaxF(0x00800001)), ("29387362".to_string(), -38));
+    assert_eq!(shortest(VaxD(0x7fffffffffffffff)), ("17014118346046923".to_string(), 39));
+    assert_eq!(shortest(VaxD(0x0080000000000000)), ("29387358770557188".to_string(), -38));
+    assert_eq!(shortest(VaxG(0x7fffffffffffffff)), ("8988465674311579".to_string(), 308));
+    assert_eq!(shortest(VaxG(0x0010000000000000)), ("5562684646268004".to_string(), -308));
+}
+
+#[test]
+fn test_vaxd_shortest_buf_len() {
+    // `1 + 2^-55` needs 18 digits, which should fit in the exactly-sized buffer.
+    let d = decode_finite(VaxD(0x4080000000000001));
+    assert_eq!(shortest_buf_len(&d), MAX_SIG_DIGITS_VAXD);
+    let mut buf = [0; MAX_SIG_DIGITS_VAXD];
+    let (len, exp) = format_shortest(&d, &mut buf);
+    assert_eq!((&buf[..len], exp), (&b"100000000000000003"[..], 1));
+    let mut buf = [0; MAX_SIG_DIGITS_VAXD];
+    let (len, exp) = grisu::format_shortest(&d, &mut buf);
+    assert_eq!((&buf[..len], exp), (&b"100000000000000003"[..], 1));
+}
+
+#[test]
+#[should_panic]
+fn test_vaxd_shortest_short_buf() {
+    let mut buf = [0; MAX_SIG_DIGITS];
+    format_shortest(&decode_finite(VaxD(0x4080000000000001)), &mut buf);
+}
+
+#[test]
+#[should_panic]
+fn test_vaxd_grisu_shortest_short_buf() {
+    let mut buf = [0; MAX_SIG_DIGITS];
+    grisu::format_shortest(&decode_finite(VaxD(0x4080000000000001)), &mut buf);
+}
+
+#[test]
+fn test_vax_exact() {
+    macro_rules! check {
+        ($v:expr => $buf:expr, $exp:expr) => (
+            check_exact(|d, b, k| format_exact(d, b, k), $v, stringify!($v), $buf, $exp)
+        )
+    }
+
+    check!(VaxF(0x7fffffff) => b"17014117331926442990585209174225846272  ", 39);
+    check!(VaxF(0x00800000) => b"2938735877055718769921841343055614194546", -38);
+    check!(VaxD(0x7fffffffffffffff) => b"17014118346046922937050406228106149888  ", 39);
+    check!(VaxG(0x7fffffffffffffff) => b"8988465674311578540726371186585217839903", 308);
+    check!(VaxG(0x0010000000000000) => b"5562684646268003457725581793331010160548", -308);
+}
+
+#[test]
+fn test_vax_dragon_consistency() {
+    // the exact mode for `VaxD` and `VaxG` from `x` should agree with that for `f64` value `x`
+    let mut buf1 = [0; 1024];
+    let mut buf2 = [0; 1024];
+    for &x in &[1.0, 0.1, 1.0 / 3.0, 3.141592, 1.0e23, 1.0e38, 1.0e-38] {
+        let d1 = decode_finite(x);
+        for &d2 in &[decode_finite(VaxD::from_f64(x).unwrap()),
+                     decode_finite(VaxG::from_f64(x).unwrap())] {
+            assert_eq!(format_exact(&d1, &mut buf1, i16::MIN),
+                       format_exact(&d2, &mut buf2, i16::MIN));
+            assert_eq!(&buf1[..], &buf2[..]);
+        }
+    }
+}
+
+#[test]
+fn test_vaxf_shortest_roundtrip() {
+    // the shortest representation of `VaxF` should be converted back to the same value.
+    let mut rng: rand::XorShiftRng = rand::Rand::rand(&mut rand::thread_rng());
+    let range = Range::new(0x0080_0000u32, 0x8000_0000);
+    for _ in 0..10_000 {
+        let v = VaxF(range.ind_sample(&mut rng));
+        let (digits, exp) = shortest(v);
+        let parsed: f64 = format!("0.{}e{}", digits, exp).parse().unwrap();
+        assert_eq!(VaxF::from_f64(parsed), Some(v));
+    }
+}
+
+// returns the shortest representation of finite `v`.
+fn shortest<T: DecodableFloat>(v: T) -> (String, i16) {
+    let mut buf = [0; MAX_SIG_DIGITS_F128];
+    let (len, exp) = format_shortest(&decode_finite(v), &mut buf);
+    (str::from_utf8(&buf[..len]).unwrap().to_string(), exp)
+}
@@ -0,0 +1,221 @@
+//! VAX floating point types which can be `decode`d.
+//!
+//! They are found in the legacy binary data from VAX (and Alpha in the VAX mode).
+//! VAX floating point numbers are binary and sign-magnitude like IEEE 754, but differ in
+//! the details: the mantissa is `0.1f` instead of `1.f` (so the exponent bias is
+//! effectively off by two), there are no subnormals, infinities nor NaNs, and
+//! the negative zero pattern is a "reserved operand" which faults when used.
+//! They are represented as bit patterns without any arithmetic, just like `quad::F128`,
+//! and can be fed to `decode` and therefore to `strategy::dragon`.
+//! The shortest mode needs `MAX_SIG_DIGITS_VAXD` bytes of buffer for `VaxD`.
+//!
+//! VAX stores them as a sequence of little-endian 16-bit words with the most significant word
+//! first, so the bytes in memory are neither little- nor big-endian. The types here hold
+//! the logical bit pattern with the sign at the highest bit; use `from_bytes` and `to_bytes`
+//! to convert from and to the memory layout.
+
+use core::prelude::*;
+
+use core::num::FpCategory;
+
+use flt2dec::decoder::DecodableFloat;
+
+/// VAX F_floating number, with 24 bits of mantissa (including the hidden bit) and
+/// 8 bits of exponent. The range is about `2.9e-39` to `1.7e38`.
+///
+/// The equality is bitwise, so zeroes with different fraction bits ("dirty zeroes")
+/// differ from each other.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VaxF(pub u32);
+
+/// VAX D_floating number, with 56 bits of mantissa (including the hidden bit) and
+/// 8 bits of exponent. The range is same to `VaxF`.
+///
+/// It is more precise than `f64`, so the shortest mode needs `MAX_SIG_DIGITS_VAXD` (18)
+/// bytes of buffer instead of `MAX_SIG_DIGITS`; e.g. `1 + 2^-55` is `1.00000000000000003`.
+///
+/// The equality is bitwise, as `VaxF`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VaxD(pub u64);
+
+/// VAX G_floating number, with 53 bits of mantissa (including the hidden bit) and
+/// 11 bits of exponent. The range is about `5.6e-309` to `9.0e307`.
+///
+/// The equality is bitwise, as `VaxF`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct VaxG(pub u64);
+
+/// Splits a `width`-bit pattern with `ebits` exponent bits.
+/// The results follow `Float::integer_decode` and `Float::classify` for other types.
+fn decode_bits(bits: u64, width: u32, ebits: u32) -> ((u128, i16, i8), FpCategory) {
+    let mbits = width - 1 - ebits;
+    let negative = bits >> (width - 1) != 0;
+    let sign = if negative { -1 } else { 1 };
+    let bias = 1 << (ebits - 1);
+    let exp = ((bits >> mbits) & ((1 << ebits) - 1)) as i32;
+    let frac = bits & ((1 << mbits) - 1);
+
+    if exp == 0 {
+        // the zero exponent is zero regardless of the fraction,
+        // or the reserved operand when the sign is set.
+        let category = if negative { FpCategory::Nan } else { FpCategory::Zero };
+        ((0, 0, sign), category)
+    } else {
+        // `0.1f * 2^(exp - bias)`, where the leading one is hidden
+        let mant = (frac | (1 << mbits)) as u128;
+        ((mant, (exp - bias - mbits as i32 - 1) as i16, sign), FpCategory::Normal)
+    }
+}
+
+/// Makes a `width`-bit pattern with `ebits` exponent bits from the sign (true when negative)
+/// and `mant * 2^exp`, rounded to the nearest with ties to even. `mant` should be less
+/// than `2^127`. There are no infinities, so too large values saturate to the maximum.
+/// Zeroes are always positive, since the negative zero is the reserved operand.
+fn encode_bits(negative: bool, mant: u128, exp: i32, width: u32, ebits: u32) -> u64 {
+    if mant == 0 { return 0; }
+    debug_assert!(mant < 1 << 127);
+
+    let mbits = width - 1 - ebits;
+    let sign = if negative { 1 << (width - 1) } else { 0 };
+    let bias = 1 << (ebits - 1);
+    let emax = (1 << ebits) - 1;
+    let max = (1 << (width - 1)) - 1; // the maximum magnitude
+
+    // `mant * 2^exp` is in `[2^(e - bias - 1), 2^(e - bias))`
+    let nbits = 128 - mant.leading_zeros() as i32;
+    let e = exp + nbits + bias;
+    if e < 1 {
+        // there are no subnormals, so this rounds to either zero or the minimum `2^-bias`.
+        // the halfway point `2^(-bias-1)` rounds to zero, whose mantissa is even.
+        let above_half = e == 0 && mant.count_ones() > 1;
+        return if above_half { sign | (1 << mbits) } else { 0 };
+    }
+    if e > emax { return sign | max; }
+
+    // `mant * 2^exp` is rounded to `q * 2^qexp` where `q` has `mbits + 1` bits
+    let qexp = e - bias - mbits as i32 - 1;
+    let q = if qexp <= exp {
+        mant << (exp - qexp) as usize
+    } else {
+        let shift = (qexp - exp) as usize;
+        let q = mant >> shift;
+        let rem = mant & ((1 << shift) - 1);
+        let half = 1 << (shift - 1);
+        if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q }
+    };
+
+    // `q` overflows to the exponent field when rounded up to the next binade
+    let rounded = ((e as u64) << mbits) + (q as u64 - (1 << mbits));
+    if rounded > max { sign | max } else { sign | rounded }
+}
+
+/// Makes a pattern from `f64`, rounded to the nearest with ties to even.
+/// Returns `None` for NaNs and infinities.
+fn encode_f64(v: f64, width: u32, ebits: u32) -> Option<u64> {
+    let (mant, exp, sign) = DecodableFloat::integer_decode(v);
+    match DecodableFloat::classify(v) {
+        FpCategory::Nan | FpCategory::Infinite => None,
+        _ => Some(encode_bits(sign < 0, mant, exp as i32, width, ebits)),
+    }
+}
+
+/// Reads a pattern from the memory layout, i.e. little-endian 16-bit words
+/// with the most significant word first.
+fn bits_from_bytes(bytes: &[u8]) -> u64 {
+    bytes.chunks(2).fold(0, |bits, word| (bits << 16) | (word[1] as u64) << 8 | word[0] as u64)
+}
+
+/// Writes a pattern to the memory layout. The reverse of `bits_from_bytes`.
+fn bits_to_bytes(bits: u64, bytes: &mut [u8]) {
+    let nwords = bytes.len() / 2;
+    for (i, word) in bytes.chunks_mut(2).enumerate() {
+        let w = bits >> (16 * (nwords - 1 - i));
+        word[0] = w as u8;
+        word[1] = (w >> 8) as u8;
+    }
+}
+
+impl VaxF {
+    /// Converts `f64` to the nearest `VaxF`, with ties to even. Finite values beyond
+    /// the range saturate to the maximum, and the negative zero becomes the positive zero.
+    /// Returns `None` for NaNs and infinities.
+    pub fn from_f64(v: f64) -> Option<VaxF> {
+        encode_f64(v, 32, 8).map(|bits| VaxF(bits as u32))
+    }
+
+    /// Reads `VaxF` from 4 bytes in the memory layout.
+    pub fn from_bytes(bytes: [u8; 4]) -> VaxF { VaxF(bits_from_bytes(&bytes) as u32) }
+
+    /// Writes `VaxF` to 4 bytes in the memory layout.
+    pub fn to_bytes(self) -> [u8; 4] {
+        let mut bytes = [0; 4];
+        bits_to_bytes(self.0 as u64, &mut bytes);
+        bytes
+    }
+}
+
+impl VaxD {
+    /// Converts `f64` to the nearest `VaxD`, with ties to even. See `VaxF::from_f64`.
+    /// This is exact for any `f64` in the range.
+    pub fn from_f64(v: f64) -> Option<VaxD> {
+        encode_f64(v, 64, 8).map(VaxD)
+    }
+
+    /// Reads `VaxD` from 8 bytes in the memory layout.
+    pub fn from_bytes(bytes: [u8; 8]) -> VaxD { VaxD(bits_from_bytes(&bytes)) }
+
+    /// Writes `VaxD` to 8 bytes in the memory layout.
+    pub fn to_bytes(self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        bits_to_bytes(self.0, &mut bytes);
+        bytes
+    }
+}
+
+impl VaxG {
+    /// Converts `f64` to the nearest `VaxG`, with ties to even. See `VaxF::from_f64`.
+    /// This is exact for any `f64` in the range.
+    pub fn from_f64(v: f64) -> Option<VaxG> {
+        encode_f64(v, 64, 11).map(VaxG)
+    }
+
+    /// Reads `VaxG` from 8 bytes in the memory layout.
+    pub fn from_bytes(bytes: [u8; 8]) -> VaxG { VaxG(bits_from_bytes(&bytes)) }
+
+    /// Writes `VaxG` to 8 bytes in the memory layout.
+    pub fn to_bytes(self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        bits_to_bytes(self.0, &mut bytes);
+        bytes
+    }
+}
+
+impl DecodableFloat for VaxF {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        let mant = if f < 0 { (-(f as i128)) as u128 } else { f as u128 };
+        VaxF(encode_bits(f < 0, mant, exp as i32, 32, 8) as u32)
+    }
+    fn min_pos_norm_value() -> Self { VaxF(1 << 23) }
+    fn integer_decode(self) -> (u128, i16, i8) { decode_bits(self.0 as u64, 32, 8).0 }
+    fn classify(self) -> FpCategory { decode_bits(self.0 as u64, 32, 8).1 }
+}
+
+impl DecodableFloat for VaxD {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        let mant = if f < 0 { (-(f as i128)) as u128 } else { f as u128 };
+        VaxD(encode_bits(f < 0, mant, exp as i32, 64, 8))
+    }
+    fn min_pos_norm_value() -> Self { VaxD(1 << 55) }
+    fn integer_decode(self) -> (u128, i16, i8) { decode_bits(self.0, 64, 8).0 }
+    fn classify(self) -> FpCategory { decode_bits(self.0, 64, 8).1 }
+}
+
+impl DecodableFloat for VaxG {
+    fn ldexpi(f: i64, exp: isize) -> Self {
+        let mant = if f < 0 { (-(f as i128)) as u128 } else { f as u128 };
+        VaxG(encode_bits(f < 0, mant, exp as i32, 64, 11))
+    }
+    fn min_pos_norm_value() -> Self { VaxG(1 << 52) }
+    fn integer_decode(self) -> (u128, i16, i8) { decode_bits(self.0, 64, 11).0 }
+    fn classify(self) -> FpCategory { decode_bits(self.0, 64, 11).1 }
+}
@@ -0,0 +1,98 @@
+/*!
+
+Digit grouping (thousands separators) shared by `int2dec` and `flt2dec`.
+
+The grouping is described by a separator, a decimal mark and the sizes of groups.
+Group sizes are counted from the decimal mark outwards, and the last size repeats
+indefinitely: `[3]` gives `1,234,567` and `[3, 2]` gives the Indian `12,34,567`.
+An empty list of group sizes disables the grouping, and a zero size stops it
+(so `[3, 0]` gives `1234,567`), much like `CHAR_MAX` in the C `lconv::grouping`.
+
+*/
+
+use core::prelude::*;
+
+/// The maximum length of `Grouping::separator` accepted by `int2dec`.
+/// This is enough for any single Unicode scalar value in UTF-8.
+/// Longer separators make the formatting fail with `fmt::Error`.
+pub const MAX_SEPARATOR_LEN: usize = 4;
+
+/// Digit grouping options.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Grouping<'a> {
+    /// A byte string inserted between groups, e.g. `b","`.
+    pub separator: &'a [u8],
+    /// A byte string replacing the decimal point, e.g. `b"."`.
+    pub decimal_mark: &'a [u8],
+    /// Group sizes of the integral part, from the decimal mark to the left.
+    /// The last size repeats. A zero size means no more separators to the left.
+    pub groups: &'a [usize],
+    /// Group sizes of the fractional part, from the decimal mark to the right.
+    /// The last size repeats. Empty if the fractional part should not be grouped,
+    /// and a zero size means no more separators to the right.
+    pub frac_groups: &'a [usize],
+}
+
+/// `1,234,567.89`
+pub const COMMA: Grouping<'static> = Grouping {
+    separator: b",", decimal_mark: b".", groups: &[3], frac_groups: &[],
+};
+
+/// `1 234 567,89`
+pub const SPACE: Grouping<'static> = Grouping {
+    separator: b" ", decimal_mark: b",", groups: &[3], frac_groups: &[],
+};
+
+/// `12,34,567.89`
+pub const INDIAN: Grouping<'static> = Grouping {
+    separator: b",", decimal_mark: b".", groups: &[3, 2], frac_groups: &[],
+};
+
+/// Returns true if the separator should be put at `n` digits away from the decimal mark.
+/// `n` is never a boundary when it is zero or `groups` is empty,
+/// nor when it is beyond the first zero size in `groups`.
+pub fn is_boundary(groups: &[usize], n: usize) -> bool {
+    if n == 0 { return false; }
+    let mut acc = 0;
+    for &g in groups {
+        if g == 0 { return false; }
+        acc += g;
+        if acc >= n { return acc == n; }
+    }
+    match groups.last() {
+        Some(&g) => (n - acc) % g == 0,
+        None => false,
+    }
+}
+
+/// Returns the number of separators in `ndigits` digits, grouped by `groups`.
+pub fn count_separators(groups: &[usize], ndigits: usize) -> usize {
+    (1..ndigits).filter(|&n| is_boundary(groups, n)).count()
+}
+
+impl<'a> Grouping<'a> {
+    /// Returns the exact length of given integral digits after the grouping.
+    pub fn grouped_len(&self, ndigits: usize) -> usize {
+        ndigits + count_separators(self.groups, ndigits) * self.separator.len()
+    }
+
+    /// Writes given integral digits into the supplied buffer with separators inserted.
+    /// Returns the number of written bytes, or `None` if the buffer is not enough.
+    /// (It may still leave partially written bytes in the buffer; do not rely on that.)
+    pub fn write_digits(&self, digits: &[u8], out: &mut [u8]) -> Option<usize> {
+        if out.len() < self.grouped_len(digits.len()) { return None; }
+
+        let mut written = 0;
+        for (i, &c) in digits.iter().enumerate() {
+            if i > 0 && is_boundary(self.groups, digits.len() - i) {
+                for &s in self.separator {
+                    out[written] = s;
+                    written += 1;
+                }
+            }
+            out[written] = c;
+            written += 1;
+        }
+        Some(written)
+    }
+}
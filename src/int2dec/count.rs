@@ -0,0 +1,74 @@
+// counts the number of decimal digits without any division.
+//
+// for `n` with `b` significant bits, `2^(b-1) <= n < 2^b`, the number of digits is
+// either `t = floor(b * log10(2))` or `t + 1`, and it is the latter iff `n >= 10^t`.
+// `floor(b * log10(2))` is approximated by `b * 1233 >> 12`, which is exact for `b <= 128`.
+// `n = 0` is treated as having one bit, and the tables start with 0 instead of 10^0
+// so that both `0` and `1` are correctly counted as one digit.
+
+static POW10_U32: [u32; 10] = [
+    0, 10, 100, 1000, 1_0000, 10_0000, 100_0000, 1000_0000, 1_0000_0000, 10_0000_0000,
+];
+
+static POW10_U64: [u64; 20] = [
+    0, 10, 100, 1000, 1_0000, 10_0000, 100_0000, 1000_0000,
+    1_0000_0000, 10_0000_0000, 100_0000_0000, 1000_0000_0000,
+    1_0000_0000_0000, 10_0000_0000_0000, 100_0000_0000_0000, 1000_0000_0000_0000,
+    1_0000_0000_0000_0000, 10_0000_0000_0000_0000, 100_0000_0000_0000_0000,
+    1000_0000_0000_0000_0000,
+];
+
+static POW10_U128: [u128; 39] = [
+    0, 10, 100, 1000, 1_0000, 10_0000, 100_0000, 1000_0000,
+    1_0000_0000, 10_0000_0000, 100_0000_0000, 1000_0000_0000,
+    1_0000_0000_0000, 10_0000_0000_0000, 100_0000_0000_0000, 1000_0000_0000_0000,
+    1_0000_0000_0000_0000, 10_0000_0000_0000_0000, 100_0000_0000_0000_0000,
+    1000_0000_0000_0000_0000,
+    1_0000_0000_0000_0000_0000, 10_0000_0000_0000_0000_0000,
+    100_0000_0000_0000_0000_0000, 1000_0000_0000_0000_0000_0000,
+    1_0000_0000_0000_0000_0000_0000, 10_0000_0000_0000_0000_0000_0000,
+    100_0000_0000_0000_0000_0000_0000, 1000_0000_0000_0000_0000_0000_0000,
+    1_0000_0000_0000_0000_0000_0000_0000, 10_0000_0000_0000_0000_0000_0000_0000,
+    100_0000_0000_0000_0000_0000_0000_0000, 1000_0000_0000_0000_0000_0000_0000_0000,
+    1_0000_0000_0000_0000_0000_0000_0000_0000, 10_0000_0000_0000_0000_0000_0000_0000_0000,
+    100_0000_0000_0000_0000_0000_0000_0000_0000, 1000_0000_0000_0000_0000_0000_0000_0000_0000,
+    1_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+    10_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+    100_0000_0000_0000_0000_0000_0000_0000_0000_0000,
+];
+
+/// Returns the number of decimal digits in `n`. Zero has one digit.
+#[inline]
+pub fn count_digits_u128(n: u128) -> usize {
+    let bits = 128 - (n | 1).leading_zeros() as usize;
+    let t = (bits * 1233) >> 12;
+    t + (n >= POW10_U128[t]) as usize
+}
+
+/// Returns the number of decimal digits in `n`. Zero has one digit.
+#[inline]
+pub fn count_digits_u64(n: u64) -> usize {
+    let bits = 64 - (n | 1).leading_zeros() as usize;
+    let t = (bits * 1233) >> 12;
+    t + (n >= POW10_U64[t]) as usize
+}
+
+/// Returns the number of decimal digits in `n`. Zero has one digit.
+#[inline]
+pub fn count_digits_u32(n: u32) -> usize {
+    let bits = 32 - (n | 1).leading_zeros() as usize;
+    let t = (bits * 1233) >> 12;
+    t + (n >= POW10_U32[t]) as usize
+}
+
+/// Returns the number of decimal digits in `n`. Zero has one digit.
+#[inline]
+pub fn count_digits_u16(n: u16) -> usize {
+    count_digits_u32(n as u32)
+}
+
+/// Returns the number of decimal digits in `n`. Zero has one digit.
+#[inline]
+pub fn count_digits_u8(n: u8) -> usize {
+    count_digits_u32(n as u32)
+}
@@ -1,3 +1,4 @@
+pub const NDIGITS128: usize = 39; // 340 2823 6692 0938 4634 6337 4607 4317 6821 1455
 pub const NDIGITS64: usize = 20; // 1844 6744 0737 0955 1615
 pub const NDIGITS32: usize = 10; // 42 9496 7295
 pub const NDIGITS16: usize = 5; // 6 5535
@@ -5,6 +6,17 @@ pub const NDIGITS8: usize = 3; // 255
 
 pub type Digit = u8;
 
+// every strategy provides a subset of the following functions:
+//
+// - `uN_to_digits(n) -> DigitsN` returns the fixed-size array of digits, zero-padded to
+//   the maximal number of digits `NDIGITSN`.
+// - `iN_to_digits(n) -> (negative, DigitsN)` is same but returns a sign separately.
+// - `uN_to_dec(n, buf) -> start` writes only significant digits to the end of `buf`
+//   (which should be at least `NDIGITSN` bytes long) and returns the starting offset,
+//   so that `buf[start..]` contains the digits. `buf[..start]` is left untouched.
+//   only early-exit strategies provide this, as others do not know when to stop.
+
+pub type Digits128 = [Digit; NDIGITS128];
 pub type Digits64 = [Digit; NDIGITS64];
 pub type Digits32 = [Digit; NDIGITS32];
 pub type Digits16 = [Digit; NDIGITS16];
@@ -18,3 +30,20 @@ pub static ONES: &'static [u8] = b"012345678901234567890123456789012345678901234
 macro_rules! tens { ($i:expr) => (TENS[$i as usize]) }
 macro_rules! ones { ($i:expr) => (ONES[$i as usize]) }
 
+// defines signed `*_to_digits` functions in terms of unsigned ones.
+// they return the absolute value and a separate sign (true when negative),
+// so that the most negative value like `i64::MIN` is handled without overflow.
+macro_rules! signed_to_digits {
+    ($($ifn:ident($it:ty) => $ufn:ident($ut:ty) -> $Digits:ty;)*) => (
+        $(
+            pub fn $ifn(n: $it) -> (/*negative?*/ bool, $Digits) {
+                if n < 0 {
+                    // `!n + 1` cannot overflow here since `n as $ut >= 2^(nbits-1)`
+                    (true, $ufn(!(n as $ut) + 1))
+                } else {
+                    (false, $ufn(n as $ut))
+                }
+            }
+        )*
+    )
+}
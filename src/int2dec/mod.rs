@@ -1,14 +1,22 @@
 use core::prelude::*;
 use core::{str, fmt};
 
+use grouping::{Grouping, MAX_SEPARATOR_LEN};
+
 pub use self::digits::Digit;
-pub use self::digits::{Digits64, Digits32, Digits16, Digits8};
-pub use self::digits::{NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+pub use self::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+pub use self::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+
+pub use self::count::{count_digits_u128, count_digits_u64, count_digits_u32};
+pub use self::count::{count_digits_u16, count_digits_u8};
 
 pub use self::strategy::best;
 
 #[macro_use] mod digits;
+mod count;
 
+// every strategy provides `u*_to_digits`, but only the early-exit ones (and `best` and
+// `runtime` built on them) provide `u*_to_dec` for the significant digits alone.
 pub mod strategy {
     pub mod naive;
     pub mod naive_earlyexit;
@@ -18,33 +26,60 @@ pub mod strategy {
     pub mod div100_u32_earlyexit;
     pub mod bcd;
     pub mod bcd_earlyexit;
+    pub mod jeaiii;
+    pub mod simd;
+    pub mod runtime;
 
     pub mod best {
+        #[cfg(target_arch = "x86")] pub use super::bcd_earlyexit::u128_to_digits;
         #[cfg(target_arch = "x86")] pub use super::bcd_earlyexit::u64_to_digits;
         #[cfg(target_arch = "x86")] pub use super::div100_earlyexit::u32_to_digits;
         #[cfg(target_arch = "x86")] pub use super::div100::u16_to_digits;
         #[cfg(target_arch = "x86")] pub use super::div100_earlyexit::u8_to_digits;
 
+        #[cfg(not(target_arch = "x86"))] pub use super::div100_u32_earlyexit::u128_to_digits;
         #[cfg(not(target_arch = "x86"))] pub use super::div100_u32_earlyexit::u64_to_digits;
         #[cfg(not(target_arch = "x86"))] pub use super::div100_earlyexit::u32_to_digits;
         #[cfg(not(target_arch = "x86"))] pub use super::naive::u16_to_digits;
         #[cfg(not(target_arch = "x86"))] pub use super::div100_u32::u8_to_digits;
+
+        #[cfg(target_arch = "x86")] pub use super::bcd_earlyexit::u128_to_dec;
+        #[cfg(target_arch = "x86")] pub use super::bcd_earlyexit::u64_to_dec;
+        #[cfg(target_arch = "x86")] pub use super::div100_earlyexit::u32_to_dec;
+        #[cfg(target_arch = "x86")] pub use super::div100_earlyexit::u16_to_dec;
+        #[cfg(target_arch = "x86")] pub use super::div100_earlyexit::u8_to_dec;
+
+        #[cfg(not(target_arch = "x86"))] pub use super::div100_u32_earlyexit::u128_to_dec;
+        #[cfg(not(target_arch = "x86"))] pub use super::div100_u32_earlyexit::u64_to_dec;
+        #[cfg(not(target_arch = "x86"))] pub use super::div100_earlyexit::u32_to_dec;
+        #[cfg(not(target_arch = "x86"))] pub use super::naive_earlyexit::u16_to_dec;
+        #[cfg(not(target_arch = "x86"))] pub use super::div100_u32_earlyexit::u8_to_dec;
+
+        use int2dec::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+
+        signed_to_digits! {
+            i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+            i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+            i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+            i16_to_digits(i16) => u16_to_digits(u16) -> Digits16;
+            i8_to_digits(i8) => u8_to_digits(u8) -> Digits8;
+        }
     }
 }
 
-#[cfg(test)] mod tests;
+#[cfg(test)] pub mod tests;
 
 pub struct UintToDecFunc<I, T>(pub I, pub fn(I) -> T);
 #[derive(Debug)] pub struct UintToDec<I>(pub I);
+#[derive(Debug)] pub struct GroupedUintToDec<'a, I>(pub I, pub &'a Grouping<'a>);
 
 macro_rules! impl_uint_to_dec {
-    ($t:ty, $Digits:ty, $default_conv:ident) => (
+    ($t:ty, $Digits:ty, $NDIGITS:expr, $count:ident, $default_conv:ident) => (
         impl fmt::Display for UintToDecFunc<$t, $Digits> {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let UintToDecFunc(num, conv) = *self;
                 let buf = conv(num);
-                let last = buf.len() - 1;
-                let start = buf[..last].iter().position(|&c| c != b'0').unwrap_or(last);
+                let start = buf.len() - $count(num);
                 f.pad_integral(true, "", unsafe {str::from_utf8_unchecked(&buf[start..])})
             }
         }
@@ -53,14 +88,85 @@ macro_rules! impl_uint_to_dec {
             #[inline]
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 let UintToDec(num) = *self;
-                UintToDecFunc(num, best::$default_conv).fmt(f)
+                let mut buf = [0; $NDIGITS];
+                let start = best::$default_conv(num, &mut buf);
+                f.pad_integral(true, "", unsafe {str::from_utf8_unchecked(&buf[start..])})
+            }
+        }
+
+        impl<'a> fmt::Display for GroupedUintToDec<'a, $t> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let GroupedUintToDec(num, grouping) = *self;
+                let mut buf = [0; $NDIGITS];
+                let start = best::$default_conv(num, &mut buf);
+                let mut grouped = [0; $NDIGITS * (1 + MAX_SEPARATOR_LEN)];
+                let digits = try!(write_grouped(grouping, &buf[start..], &mut grouped));
+                f.pad_integral(true, "", digits)
+            }
+        }
+    )
+}
+
+// `pad_integral` wants a single string, so we need a buffer large enough for separators.
+// a separator longer than `MAX_SEPARATOR_LEN` does not fit there and is an error.
+fn write_grouped<'a>(grouping: &Grouping, digits: &[u8],
+                     out: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+    if grouping.separator.len() > MAX_SEPARATOR_LEN { return Err(fmt::Error); }
+    let len = try!(grouping.write_digits(digits, out).ok_or(fmt::Error));
+    str::from_utf8(&out[..len]).map_err(|_| fmt::Error)
+}
+
+impl_uint_to_dec!(u128, Digits128, NDIGITS128, count_digits_u128, u128_to_dec);
+impl_uint_to_dec!(u64, Digits64, NDIGITS64, count_digits_u64, u64_to_dec);
+impl_uint_to_dec!(u32, Digits32, NDIGITS32, count_digits_u32, u32_to_dec);
+impl_uint_to_dec!(u16, Digits16, NDIGITS16, count_digits_u16, u16_to_dec);
+impl_uint_to_dec!(u8, Digits8, NDIGITS8, count_digits_u8, u8_to_dec);
+
+pub struct IntToDecFunc<I, T>(pub I, pub fn(I) -> (bool, T));
+#[derive(Debug)] pub struct IntToDec<I>(pub I);
+#[derive(Debug)] pub struct GroupedIntToDec<'a, I>(pub I, pub &'a Grouping<'a>);
+
+macro_rules! impl_int_to_dec {
+    ($t:ty, $Digits:ty, $ut:ty, $NDIGITS:expr, $count:ident, $default_conv:ident) => (
+        impl fmt::Display for IntToDecFunc<$t, $Digits> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let IntToDecFunc(num, conv) = *self;
+                let (negative, buf) = conv(num);
+                let abs = if num < 0 { !(num as $ut) + 1 } else { num as $ut };
+                let start = buf.len() - $count(abs);
+                f.pad_integral(!negative, "", unsafe {str::from_utf8_unchecked(&buf[start..])})
+            }
+        }
+
+        impl fmt::Display for IntToDec<$t> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let IntToDec(num) = *self;
+                // see `signed_to_digits!` for the explanation.
+                let abs = if num < 0 { !(num as $ut) + 1 } else { num as $ut };
+                let mut buf = [0; $NDIGITS];
+                let start = best::$default_conv(abs, &mut buf);
+                f.pad_integral(num >= 0, "", unsafe {str::from_utf8_unchecked(&buf[start..])})
+            }
+        }
+
+        impl<'a> fmt::Display for GroupedIntToDec<'a, $t> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let GroupedIntToDec(num, grouping) = *self;
+                let abs = if num < 0 { !(num as $ut) + 1 } else { num as $ut };
+                let mut buf = [0; $NDIGITS];
+                let start = best::$default_conv(abs, &mut buf);
+                let mut grouped = [0; $NDIGITS * (1 + MAX_SEPARATOR_LEN)];
+                let digits = try!(write_grouped(grouping, &buf[start..], &mut grouped));
+                f.pad_integral(num >= 0, "", digits)
             }
         }
     )
 }
 
-impl_uint_to_dec!(u64, Digits64, u64_to_digits);
-impl_uint_to_dec!(u32, Digits32, u32_to_digits);
-impl_uint_to_dec!(u16, Digits16, u16_to_digits);
-impl_uint_to_dec!(u8, Digits8, u8_to_digits);
+impl_int_to_dec!(i128, Digits128, u128, NDIGITS128, count_digits_u128, u128_to_dec);
+impl_int_to_dec!(i64, Digits64, u64, NDIGITS64, count_digits_u64, u64_to_dec);
+impl_int_to_dec!(i32, Digits32, u32, NDIGITS32, count_digits_u32, u32_to_dec);
+impl_int_to_dec!(i16, Digits16, u16, NDIGITS16, count_digits_u16, u16_to_dec);
+impl_int_to_dec!(i8, Digits8, u8, NDIGITS8, count_digits_u8, u8_to_dec);
 
@@ -1,9 +1,54 @@
 use num::div_rem;
 
-use int2dec::digits::{Digits64, Digits32};
-use int2dec::digits::{NDIGITS64, NDIGITS32};
+use int2dec::digits::{Digits128, Digits64, Digits32};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS32};
 use int2dec::digits::{ONES, TENS};
 
+// the same technique as `u64_to_digits` below, but with 32-bit limbs and base 10^8.
+// 2^32 = 42_94967296, 2^64 = 1844_67440737_09551616 and 2^96 = 79228_16251426_43375935_43950336.
+// every intermediate sum is less than 2^32 * 1.5 * 10^8 < 2^64.
+pub fn u128_to_digits(n: u128) -> Digits128 {
+    let mut buf: Digits128 = [0; NDIGITS128];
+
+    let n0 = (n & 0xffffffff) as u64;
+    let n1 = ((n >> 32) & 0xffffffff) as u64;
+    let n2 = ((n >> 64) & 0xffffffff) as u64;
+    let n3 = ((n >> 96) & 0xffffffff) as u64;
+
+    let (c0, d0) = div_rem(      43950336 * n3 +  9551616 * n2 + 94967296 * n1 + n0, 1_0000_0000);
+    let (c1, d1) = div_rem(c0 + 43375935 * n3 + 67440737 * n2 +       42 * n1,      1_0000_0000);
+    let (c2, d2) = div_rem(c1 + 16251426 * n3 +     1844 * n2,                       1_0000_0000);
+    let (d4, d3) = div_rem(c2 +    79228 * n3,                                       1_0000_0000);
+
+    macro_rules! quad {
+        ($d:expr, $i:expr) => ({
+            let (qq, rr) = div_rem($d, 100);
+            buf[$i  ] = tens!(qq); buf[$i+1] = ones!(qq);
+            buf[$i+2] = tens!(rr); buf[$i+3] = ones!(rr);
+        })
+    }
+
+    macro_rules! octa {
+        ($d:expr, $i:expr) => ({
+            let (hi, lo) = div_rem($d as u32, 10000);
+            quad!(hi, $i);
+            quad!(lo, $i+4);
+        })
+    }
+
+    // `d4` has at most 7 digits
+    let (hi, lo) = div_rem(d4 as u32, 10000);
+    let (q, r) = div_rem(hi, 100);
+    buf[0] = q as u8 + b'0'; buf[1] = tens!(r); buf[2] = ones!(r);
+    quad!(lo, 3);
+    octa!(d3, 7);
+    octa!(d2, 15);
+    octa!(d1, 23);
+    octa!(d0, 31);
+
+    buf
+}
+
 // http://homepage.cs.uiowa.edu/~jones/bcd/decimal.html#sixtyfour
 pub fn u64_to_digits(n: u64) -> Digits64 {
     let mut buf: Digits64 = [0; NDIGITS64];
@@ -60,3 +105,8 @@ pub fn u32_to_digits(n: u32) -> Digits32 {
     buf
 }
 
+signed_to_digits! {
+    i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+}
@@ -1,9 +1,59 @@
 use num::div_rem;
 
-use int2dec::digits::{Digits64, Digits32};
-use int2dec::digits::{NDIGITS64, NDIGITS32};
+use int2dec::count_digits_u32;
+
+use int2dec::digits::{Digits128, Digits64, Digits32};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS32};
 use int2dec::digits::{ONES, TENS};
 
+pub fn u128_to_digits(n: u128) -> Digits128 {
+    let mut buf: Digits128 = [b'0'; NDIGITS128];
+
+    let n0 = (n & 0xffffffff) as u64;
+    let n1 = ((n >> 32) & 0xffffffff) as u64;
+    let n2 = ((n >> 64) & 0xffffffff) as u64;
+    let n3 = ((n >> 96) & 0xffffffff) as u64;
+
+    macro_rules! quad {
+        ($d:expr, $i:expr) => ({
+            let (qq, rr) = div_rem($d, 100);
+            buf[$i  ] = tens!(qq); buf[$i+1] = ones!(qq);
+            buf[$i+2] = tens!(rr); buf[$i+3] = ones!(rr);
+        })
+    }
+
+    macro_rules! octa {
+        ($d:expr, $i:expr) => ({
+            let (hi, lo) = div_rem($d as u32, 10000);
+            quad!(hi, $i);
+            quad!(lo, $i+4);
+        })
+    }
+
+    // see `bcd::u128_to_digits` for the explanation.
+    if n <= 0 { return buf; }
+    let (c0, d0) = div_rem(      43950336 * n3 +  9551616 * n2 + 94967296 * n1 + n0, 1_0000_0000);
+    octa!(d0, 31);
+    if n <= 9999_9999 { return buf; }
+    let (c1, d1) = div_rem(c0 + 43375935 * n3 + 67440737 * n2 +       42 * n1,      1_0000_0000);
+    octa!(d1, 23);
+    if n <= 9999_9999_9999_9999 { return buf; }
+    let (c2, d2) = div_rem(c1 + 16251426 * n3 +     1844 * n2,                       1_0000_0000);
+    octa!(d2, 15);
+    if n <= 9999_9999_9999_9999_9999_9999 { return buf; }
+    let (d4, d3) = div_rem(c2 +    79228 * n3,                                       1_0000_0000);
+    octa!(d3, 7);
+    if n <= 9999_9999_9999_9999_9999_9999_9999_9999 { return buf; }
+
+    // `d4` has at most 7 digits
+    let (hi, lo) = div_rem(d4 as u32, 10000);
+    let (q, r) = div_rem(hi, 100);
+    buf[0] = q as u8 + b'0'; buf[1] = tens!(r); buf[2] = ones!(r);
+    quad!(lo, 3);
+
+    buf
+}
+
 // http://homepage.cs.uiowa.edu/~jones/bcd/decimal.html#sixtyfour
 pub fn u64_to_digits(n: u64) -> Digits64 {
     let mut buf: Digits64 = [b'0'; NDIGITS64];
@@ -59,3 +109,119 @@ pub fn u32_to_digits(n: u32) -> Digits32 {
     buf
 }
 
+// writes significant digits of `d < 10^8` to the end of `buf[..i]` and returns the starting offset.
+fn u32_to_dec_var(mut d: u32, buf: &mut [u8], mut i: usize) -> usize {
+    let start = i - count_digits_u32(d);
+    while i >= start + 2 {
+        let (q, r) = div_rem(d, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+        i -= 2;
+        d = q;
+    }
+    if i > start {
+        buf[start] = d as u8 + b'0';
+    }
+    start
+}
+
+pub fn u128_to_dec(n: u128, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS128);
+    let i = buf.len();
+
+    let n0 = (n & 0xffffffff) as u64;
+    let n1 = ((n >> 32) & 0xffffffff) as u64;
+    let n2 = ((n >> 64) & 0xffffffff) as u64;
+    let n3 = ((n >> 96) & 0xffffffff) as u64;
+
+    macro_rules! quad {
+        ($d:expr, $i:expr) => ({
+            let (qq, rr) = div_rem($d, 100);
+            buf[$i  ] = tens!(qq); buf[$i+1] = ones!(qq);
+            buf[$i+2] = tens!(rr); buf[$i+3] = ones!(rr);
+        })
+    }
+
+    macro_rules! octa {
+        ($d:expr, $i:expr) => ({
+            let (hi, lo) = div_rem($d as u32, 10000);
+            quad!(hi, $i);
+            quad!(lo, $i+4);
+        })
+    }
+
+    // see `bcd::u128_to_digits` for the explanation.
+    let (c0, d0) = div_rem(      43950336 * n3 +  9551616 * n2 + 94967296 * n1 + n0, 1_0000_0000);
+    if n <= 9999_9999 { return u32_to_dec_var(d0 as u32, buf, i); }
+    octa!(d0, i-8);
+    let (c1, d1) = div_rem(c0 + 43375935 * n3 + 67440737 * n2 +       42 * n1,      1_0000_0000);
+    if n <= 9999_9999_9999_9999 { return u32_to_dec_var(d1 as u32, buf, i-8); }
+    octa!(d1, i-16);
+    let (c2, d2) = div_rem(c1 + 16251426 * n3 +     1844 * n2,                       1_0000_0000);
+    if n <= 9999_9999_9999_9999_9999_9999 { return u32_to_dec_var(d2 as u32, buf, i-16); }
+    octa!(d2, i-24);
+    let (d4, d3) = div_rem(c2 +    79228 * n3,                                       1_0000_0000);
+    if n <= 9999_9999_9999_9999_9999_9999_9999_9999 { return u32_to_dec_var(d3 as u32, buf, i-24); }
+    octa!(d3, i-32);
+    u32_to_dec_var(d4 as u32, buf, i-32)
+}
+
+pub fn u64_to_dec(n: u64, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS64);
+    let i = buf.len();
+
+    let n0 = (n & 0xffff) as u32;
+    let n1 = ((n >> 16) & 0xffff) as u32;
+    let n2 = ((n >> 32) & 0xffff) as u32;
+    let n3 = ((n >> 48) & 0xffff) as u32;
+
+    macro_rules! quad {
+        ($d:expr, $i:expr) => ({
+            let (qq, rr) = div_rem($d, 100);
+            buf[$i  ] = tens!(qq); buf[$i+1] = ones!(qq);
+            buf[$i+2] = tens!(rr); buf[$i+3] = ones!(rr);
+        })
+    }
+
+    let (c0, d0) = div_rem(      656 * n3 + 7296 * n2 + 5536 * n1 + n0, 10000);
+    if n <= 9999 { return u32_to_dec_var(d0, buf, i); }
+    quad!(d0, i-4);
+    let (c1, d1) = div_rem(c0 + 7671 * n3 + 9496 * n2 +    6 * n1,      10000);
+    if n <= 9999_9999 { return u32_to_dec_var(d1, buf, i-4); }
+    quad!(d1, i-8);
+    let (c2, d2) = div_rem(c1 + 4749 * n3 +   42 * n2,                  10000);
+    if n <= 9999_9999_9999 { return u32_to_dec_var(d2, buf, i-8); }
+    quad!(d2, i-12);
+    let (d4, d3) = div_rem(c2 +  281 * n3,                              10000);
+    if n <= 9999_9999_9999_9999 { return u32_to_dec_var(d3, buf, i-12); }
+    quad!(d3, i-16);
+    u32_to_dec_var(d4, buf, i-16)
+}
+
+pub fn u32_to_dec(n: u32, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS32);
+    let i = buf.len();
+
+    let n0 = (n & 0xffff) as u32;
+    let n1 = ((n >> 16) & 0xffff) as u32;
+
+    macro_rules! quad {
+        ($d:expr, $i:expr) => ({
+            let (qq, rr) = div_rem($d, 100);
+            buf[$i  ] = tens!(qq); buf[$i+1] = ones!(qq);
+            buf[$i+2] = tens!(rr); buf[$i+3] = ones!(rr);
+        })
+    }
+
+    let (c0, d0) = div_rem(     5536 * n1 + n0, 10000);
+    if n <= 9999 { return u32_to_dec_var(d0, buf, i); }
+    quad!(d0, i-4);
+    let (d2, d1) = div_rem(c0 +    6 * n1,      10000);
+    if n <= 9999_9999 { return u32_to_dec_var(d1, buf, i-4); }
+    quad!(d1, i-8);
+    u32_to_dec_var(d2, buf, i-8)
+}
+
+signed_to_digits! {
+    i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+}
@@ -1,9 +1,37 @@
 use num::div_rem;
 
-use int2dec::digits::{Digits64, Digits32, Digits16, Digits8};
-use int2dec::digits::{NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use int2dec::{count_digits_u128, count_digits_u64, count_digits_u32};
+use int2dec::{count_digits_u16, count_digits_u8};
+
+use int2dec::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
 use int2dec::digits::{ONES, TENS};
 
+pub fn u128_to_digits(n: u128) -> Digits128 {
+    let mut buf: Digits128 = [b'0'; NDIGITS128];
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[37] = tens!(r); buf[38] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[35] = tens!(r); buf[36] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[33] = tens!(r); buf[34] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[31] = tens!(r); buf[32] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[29] = tens!(r); buf[30] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[27] = tens!(r); buf[28] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[25] = tens!(r); buf[26] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[23] = tens!(r); buf[24] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[21] = tens!(r); buf[22] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[19] = tens!(r); buf[20] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[17] = tens!(r); buf[18] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[15] = tens!(r); buf[16] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[13] = tens!(r); buf[14] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[11] = tens!(r); buf[12] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[ 9] = tens!(r); buf[10] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[ 7] = tens!(r); buf[ 8] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[ 5] = tens!(r); buf[ 6] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[ 3] = tens!(r); buf[ 4] = ones!(r);
+    if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[ 1] = tens!(r); buf[ 2] = ones!(r);
+    if n == 0 { return buf; } let r = n;                    buf[ 0] = r as u8 + b'0';
+    buf
+}
+
 pub fn u64_to_digits(n: u64) -> Digits64 {
     let mut buf: Digits64 = [b'0'; NDIGITS64];
     if n == 0 { return buf; } let (n, r) = div_rem(n, 100); buf[18] = tens!(r); buf[19] = ones!(r);
@@ -44,3 +72,85 @@ pub fn u8_to_digits(n: u8) -> Digits8 {
     buf
 }
 
+pub fn u128_to_dec(mut n: u128, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS128);
+    let start = buf.len() - count_digits_u128(n);
+    let mut i = buf.len();
+    while i >= start + 2 {
+        let (q, r) = div_rem(n, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+        i -= 2;
+        n = q;
+    }
+    if i > start {
+        buf[start] = n as u8 + b'0';
+    }
+    start
+}
+
+pub fn u64_to_dec(mut n: u64, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS64);
+    let start = buf.len() - count_digits_u64(n);
+    let mut i = buf.len();
+    while i >= start + 2 {
+        let (q, r) = div_rem(n, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+        i -= 2;
+        n = q;
+    }
+    if i > start {
+        buf[start] = n as u8 + b'0';
+    }
+    start
+}
+
+pub fn u32_to_dec(mut n: u32, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS32);
+    let start = buf.len() - count_digits_u32(n);
+    let mut i = buf.len();
+    while i >= start + 2 {
+        let (q, r) = div_rem(n, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+        i -= 2;
+        n = q;
+    }
+    if i > start {
+        buf[start] = n as u8 + b'0';
+    }
+    start
+}
+
+pub fn u16_to_dec(mut n: u16, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS16);
+    let start = buf.len() - count_digits_u16(n);
+    let mut i = buf.len();
+    while i >= start + 2 {
+        let (q, r) = div_rem(n, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+        i -= 2;
+        n = q;
+    }
+    if i > start {
+        buf[start] = n as u8 + b'0';
+    }
+    start
+}
+
+pub fn u8_to_dec(mut n: u8, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS8);
+    let start = buf.len() - count_digits_u8(n);
+    let mut i = buf.len();
+    while i >= start + 2 {
+        let (q, r) = div_rem(n, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+        i -= 2;
+        n = q;
+    }
+    if i > start {
+        buf[start] = n as u8 + b'0';
+    }
+    start
+}
+
+signed_to_digits! {
+    i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+    i16_to_digits(i16) => u16_to_digits(u16) -> Digits16;
+    i8_to_digits(i8) => u8_to_digits(u8) -> Digits8;
+}
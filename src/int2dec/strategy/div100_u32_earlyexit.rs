@@ -1,9 +1,74 @@
 use num::div_rem;
 
-use int2dec::digits::{Digits64, Digits16, Digits8};
-use int2dec::digits::{NDIGITS64, NDIGITS16, NDIGITS8};
+use int2dec::count_digits_u32;
+
+use int2dec::digits::{Digits128, Digits64, Digits16, Digits8};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS16, NDIGITS8};
 use int2dec::digits::{ONES, TENS};
 
+pub fn u128_to_digits(n: u128) -> Digits128 {
+    let mut buf: Digits128 = [b'0'; NDIGITS128];
+    if n <= 0 { return buf; }
+
+    // see `div100_u32::u128_to_digits` for the explanation.
+    let (xy, z) = div_rem(n, 1_0000_0000_0000_0000);
+
+    let (z1, z0) = div_rem(z as u64, 1_0000_0000);
+    let m = z0 as u32;
+    let (m, r) = div_rem(m, 100); buf[37] = tens!(r); buf[38] = ones!(r);
+    if n <= 99 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[35] = tens!(r); buf[36] = ones!(r);
+    if n <= 9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[33] = tens!(r); buf[34] = ones!(r);
+    if n <= 99_9999 { return buf; }
+    let r = m;                    buf[31] = tens!(r); buf[32] = ones!(r);
+    if n <= 9999_9999 { return buf; }
+
+    let m = z1 as u32;
+    let (m, r) = div_rem(m, 100); buf[29] = tens!(r); buf[30] = ones!(r);
+    if n <= 99_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[27] = tens!(r); buf[28] = ones!(r);
+    if n <= 9999_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[25] = tens!(r); buf[26] = ones!(r);
+    if n <= 99_9999_9999_9999 { return buf; }
+    let r = m;                    buf[23] = tens!(r); buf[24] = ones!(r);
+    if n <= 9999_9999_9999_9999 { return buf; }
+
+    let (x, y) = div_rem(xy, 1_0000_0000_0000_0000);
+
+    let (y1, y0) = div_rem(y as u64, 1_0000_0000);
+    let m = y0 as u32;
+    let (m, r) = div_rem(m, 100); buf[21] = tens!(r); buf[22] = ones!(r);
+    if n <= 99_9999_9999_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[19] = tens!(r); buf[20] = ones!(r);
+    if n <= 9999_9999_9999_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[17] = tens!(r); buf[18] = ones!(r);
+    if n <= 99_9999_9999_9999_9999_9999 { return buf; }
+    let r = m;                    buf[15] = tens!(r); buf[16] = ones!(r);
+    if n <= 9999_9999_9999_9999_9999_9999 { return buf; }
+
+    let m = y1 as u32;
+    let (m, r) = div_rem(m, 100); buf[13] = tens!(r); buf[14] = ones!(r);
+    if n <= 99_9999_9999_9999_9999_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[11] = tens!(r); buf[12] = ones!(r);
+    if n <= 9999_9999_9999_9999_9999_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[ 9] = tens!(r); buf[10] = ones!(r);
+    if n <= 99_9999_9999_9999_9999_9999_9999_9999 { return buf; }
+    let r = m;                    buf[ 7] = tens!(r); buf[ 8] = ones!(r);
+    if n <= 9999_9999_9999_9999_9999_9999_9999_9999 { return buf; }
+
+    let m = x as u32;
+    let (m, r) = div_rem(m, 100); buf[ 5] = tens!(r); buf[ 6] = ones!(r);
+    if n <= 99_9999_9999_9999_9999_9999_9999_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[ 3] = tens!(r); buf[ 4] = ones!(r);
+    if n <= 9999_9999_9999_9999_9999_9999_9999_9999_9999 { return buf; }
+    let (m, r) = div_rem(m, 100); buf[ 1] = tens!(r); buf[ 2] = ones!(r);
+    if n <= 99_9999_9999_9999_9999_9999_9999_9999_9999_9999 { return buf; }
+    let r = m;                    buf[ 0] = r as u8 + b'0';
+
+    buf
+}
+
 pub fn u64_to_digits(n: u64) -> Digits64 {
     let mut buf: Digits64 = [b'0'; NDIGITS64];
     if n <= 0 { return buf; }
@@ -57,3 +122,76 @@ pub fn u8_to_digits(n: u8) -> Digits8 {
     buf
 }
 
+// writes exactly 8 digits of `n < 10^8` to `buf[i-8..i]`.
+fn u32_to_dec_8(n: u32, buf: &mut [u8], i: usize) {
+    let (n, r) = div_rem(n, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+    let (n, r) = div_rem(n, 100); buf[i-4] = tens!(r); buf[i-3] = ones!(r);
+    let (n, r) = div_rem(n, 100); buf[i-6] = tens!(r); buf[i-5] = ones!(r);
+    let r = n;                    buf[i-8] = tens!(r); buf[i-7] = ones!(r);
+}
+
+// writes significant digits of `n` to the end of `buf[..i]` and returns the starting offset.
+fn u32_to_dec_var(mut n: u32, buf: &mut [u8], mut i: usize) -> usize {
+    let start = i - count_digits_u32(n);
+    while i >= start + 2 {
+        let (q, r) = div_rem(n, 100); buf[i-2] = tens!(r); buf[i-1] = ones!(r);
+        i -= 2;
+        n = q;
+    }
+    if i > start {
+        buf[start] = n as u8 + b'0';
+    }
+    start
+}
+
+// same to `u32_to_dec_var` but for `u64`.
+fn u64_to_dec_var(mut n: u64, buf: &mut [u8], mut i: usize) -> usize {
+    // peel off 8 digits until the remainder fits in `u32`; this loops at most twice.
+    while n > 0xffff_ffff {
+        let (q, r) = div_rem(n, 1_0000_0000);
+        u32_to_dec_8(r as u32, buf, i);
+        i -= 8;
+        n = q;
+    }
+    u32_to_dec_var(n as u32, buf, i)
+}
+
+pub fn u128_to_dec(mut n: u128, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS128);
+    let mut i = buf.len();
+    // peel off 16 digits until the remainder fits in `u64`; this loops at most twice.
+    while n > 0xffff_ffff_ffff_ffff {
+        let (q, r) = div_rem(n, 1_0000_0000_0000_0000);
+        let (r1, r0) = div_rem(r as u64, 1_0000_0000);
+        u32_to_dec_8(r0 as u32, buf, i);
+        u32_to_dec_8(r1 as u32, buf, i - 8);
+        i -= 16;
+        n = q;
+    }
+    u64_to_dec_var(n as u64, buf, i)
+}
+
+pub fn u64_to_dec(n: u64, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS64);
+    let i = buf.len();
+    u64_to_dec_var(n, buf, i)
+}
+
+pub fn u16_to_dec(n: u16, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS16);
+    let i = buf.len();
+    u32_to_dec_var(n as u32, buf, i)
+}
+
+pub fn u8_to_dec(n: u8, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS8);
+    let i = buf.len();
+    u32_to_dec_var(n as u32, buf, i)
+}
+
+signed_to_digits! {
+    i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i16_to_digits(i16) => u16_to_digits(u16) -> Digits16;
+    i8_to_digits(i8) => u8_to_digits(u8) -> Digits8;
+}
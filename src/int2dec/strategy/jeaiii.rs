@@ -0,0 +1,115 @@
+// the multiplication-only strategy inspired by James Edward Anhalt III's `itoa`.
+//
+// given `n < 10^(k+2)`, we compute a fixed-point approximation `y` of `n / 10^k` with
+// 32 fractional bits (16 for `u8`). the integral part of `y` is the first two digits,
+// and the next two digits are given by the integral part of `(y mod 2^32) * 100` and so on.
+// this is exact as long as `n * 2^32 / 10^k <= y < (n + 1) * 2^32 / 10^k`,
+// so that the fractional part of `y` never crosses the boundary of the next digits.
+//
+// `y` is calculated as `(n * M >> s) + b` where `M = ceil(2^(32+s) / 10^k)`.
+// the error from rounding `M` up is at most `n * (M / 2^s - 2^32 / 10^k)`, and
+// the truncation by `>> s` is compensated by `b` (unless `s` is zero). all constants
+// are chosen so that the total error is less than `2^32 / 10^k`.
+
+use int2dec::digits::{Digits64, Digits32, Digits16, Digits8};
+use int2dec::digits::{NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use int2dec::digits::{ONES, TENS};
+
+// writes two digits from the integral part of `y` to `buf[i..i+2]`, then moves to the next.
+macro_rules! next_two {
+    ($buf:ident[$i:expr], $y:ident) => ({
+        $y = ($y & 0xffffffff) * 100;
+        let d = $y >> 32;
+        $buf[$i] = tens!(d);
+        $buf[$i+1] = ones!(d);
+    })
+}
+
+// writes 8 digits of `x < 10^8` to `buf[i..i+8]`.
+// k = 6, M = ceil(2^55 / 10^6) = 36028797019, s = 23, b = 1. the error is less than 1.43.
+#[inline(always)]
+fn chunk8(x: u64, buf: &mut [u8], i: usize) {
+    let mut y = (x * 36028797019 >> 23) + 1;
+    let d = y >> 32;
+    buf[i] = tens!(d);
+    buf[i+1] = ones!(d);
+    next_two!(buf[i+2], y);
+    next_two!(buf[i+4], y);
+    next_two!(buf[i+6], y);
+}
+
+// returns `n / 10^8` for any `n < 2^64` without a division.
+// `n >> 8` is less than 2^56 and `10^8 = 2^8 * 390625`, so we use the Granlund-Montgomery
+// multiplier `ceil(2^(56+19) / 390625)` which is exact since `390625 <= 2^19`.
+#[inline(always)]
+fn div1e8(n: u64) -> u64 {
+    (((n >> 8) as u128 * 96714065569170334) >> 75) as u64
+}
+
+pub fn u64_to_digits(n: u64) -> Digits64 {
+    let mut buf: Digits64 = [0; NDIGITS64];
+
+    let q = div1e8(n);
+    let lo = n - q * 1_0000_0000;
+    let hi = div1e8(q);
+    let mid = q - hi * 1_0000_0000;
+
+    // `hi < 1845`. k = 2, M = ceil(2^32 / 100) = 42949673, s = 0. the error is less than 400.
+    let mut y = hi * 42949673;
+    let d = y >> 32;
+    buf[0] = tens!(d);
+    buf[1] = ones!(d);
+    next_two!(buf[2], y);
+
+    chunk8(mid, &mut buf, 4);
+    chunk8(lo, &mut buf, 12);
+    buf
+}
+
+pub fn u32_to_digits(n: u32) -> Digits32 {
+    let mut buf: Digits32 = [0; NDIGITS32];
+
+    // k = 8, M = ceil(2^57 / 10^8) = 1441151881, s = 25, b = 1. the error is less than 32,
+    // which is barely enough for `2^32 / 10^8 = 42.9...`.
+    let mut y = (n as u64 * 1441151881 >> 25) + 1;
+    let d = y >> 32;
+    buf[0] = tens!(d);
+    buf[1] = ones!(d);
+    next_two!(buf[2], y);
+    next_two!(buf[4], y);
+    next_two!(buf[6], y);
+    next_two!(buf[8], y);
+    buf
+}
+
+pub fn u16_to_digits(n: u16) -> Digits16 {
+    let mut buf: Digits16 = [0; NDIGITS16];
+
+    // k = 4, M = ceil(2^32 / 10^4) = 429497, s = 0. the error is less than 17721.
+    // the integral part is a single digit as `n < 7 * 10^4`.
+    let mut y = n as u64 * 429497;
+    buf[0] = (y >> 32) as u8 + b'0';
+    next_two!(buf[1], y);
+    next_two!(buf[3], y);
+    buf
+}
+
+pub fn u8_to_digits(n: u8) -> Digits8 {
+    let mut buf: Digits8 = [0; NDIGITS8];
+
+    // k = 2 with 16 fractional bits, M = ceil(2^16 / 100) = 656, s = 0.
+    // the error is less than 164, which is less than `2^16 / 100 = 655.36`.
+    let y = n as u32 * 656;
+    buf[0] = (y >> 16) as u8 + b'0';
+    let d = (y & 0xffff) * 100 >> 16;
+    buf[1] = tens!(d);
+    buf[2] = ones!(d);
+    buf
+}
+
+signed_to_digits! {
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+    i16_to_digits(i16) => u16_to_digits(u16) -> Digits16;
+    i8_to_digits(i8) => u8_to_digits(u8) -> Digits8;
+}
@@ -1,8 +1,18 @@
 use core::prelude::*;
 use num::div_rem;
 
-use int2dec::digits::{Digits64, Digits32, Digits16, Digits8};
-use int2dec::digits::{NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use int2dec::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+
+pub fn u128_to_digits(mut n: u128) -> Digits128 {
+    let mut buf: Digits128 = [0; NDIGITS128];
+    for i in (0..NDIGITS128).rev() {
+        let (q, r) = div_rem(n, 10);
+        buf[i] = r as u8 + b'0';
+        n = q;
+    }
+    buf
+}
 
 pub fn u64_to_digits(mut n: u64) -> Digits64 {
     let mut buf: Digits64 = [0; NDIGITS64];
@@ -44,3 +54,10 @@ pub fn u8_to_digits(mut n: u8) -> Digits8 {
     buf
 }
 
+signed_to_digits! {
+    i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+    i16_to_digits(i16) => u16_to_digits(u16) -> Digits16;
+    i8_to_digits(i8) => u8_to_digits(u8) -> Digits8;
+}
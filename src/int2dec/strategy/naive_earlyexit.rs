@@ -1,13 +1,25 @@
 use core::prelude::*;
 use num::div_rem;
 
-use int2dec::digits::{Digits64, Digits32, Digits16, Digits8};
-use int2dec::digits::{NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use int2dec::{count_digits_u128, count_digits_u64, count_digits_u32};
+use int2dec::{count_digits_u16, count_digits_u8};
+
+use int2dec::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+
+pub fn u128_to_digits(mut n: u128) -> Digits128 {
+    let mut buf: Digits128 = [b'0'; NDIGITS128];
+    for i in (NDIGITS128 - count_digits_u128(n)..NDIGITS128).rev() {
+        let (q, r) = div_rem(n, 10);
+        buf[i] = r as u8 + b'0';
+        n = q;
+    }
+    buf
+}
 
 pub fn u64_to_digits(mut n: u64) -> Digits64 {
     let mut buf: Digits64 = [b'0'; NDIGITS64];
-    for i in (0..NDIGITS64).rev() {
-        if n == 0 { return buf; }
+    for i in (NDIGITS64 - count_digits_u64(n)..NDIGITS64).rev() {
         let (q, r) = div_rem(n, 10);
         buf[i] = r as u8 + b'0';
         n = q;
@@ -17,8 +29,7 @@ pub fn u64_to_digits(mut n: u64) -> Digits64 {
 
 pub fn u32_to_digits(mut n: u32) -> Digits32 {
     let mut buf: Digits32 = [b'0'; NDIGITS32];
-    for i in (0..NDIGITS32).rev() {
-        if n == 0 { return buf; }
+    for i in (NDIGITS32 - count_digits_u32(n)..NDIGITS32).rev() {
         let (q, r) = div_rem(n, 10);
         buf[i] = r as u8 + b'0';
         n = q;
@@ -28,8 +39,7 @@ pub fn u32_to_digits(mut n: u32) -> Digits32 {
 
 pub fn u16_to_digits(mut n: u16) -> Digits16 {
     let mut buf: Digits16 = [b'0'; NDIGITS16];
-    for i in (0..NDIGITS16).rev() {
-        if n == 0 { return buf; }
+    for i in (NDIGITS16 - count_digits_u16(n)..NDIGITS16).rev() {
         let (q, r) = div_rem(n, 10);
         buf[i] = r as u8 + b'0';
         n = q;
@@ -39,8 +49,7 @@ pub fn u16_to_digits(mut n: u16) -> Digits16 {
 
 pub fn u8_to_digits(mut n: u8) -> Digits8 {
     let mut buf: Digits8 = [b'0'; NDIGITS8];
-    for i in (0..NDIGITS8).rev() {
-        if n == 0 { return buf; }
+    for i in (NDIGITS8 - count_digits_u8(n)..NDIGITS8).rev() {
         let (q, r) = div_rem(n, 10);
         buf[i] = r as u8 + b'0';
         n = q;
@@ -48,3 +57,65 @@ pub fn u8_to_digits(mut n: u8) -> Digits8 {
     buf
 }
 
+pub fn u128_to_dec(mut n: u128, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS128);
+    let start = buf.len() - count_digits_u128(n);
+    for i in (start..buf.len()).rev() {
+        let (q, r) = div_rem(n, 10);
+        buf[i] = r as u8 + b'0';
+        n = q;
+    }
+    start
+}
+
+pub fn u64_to_dec(mut n: u64, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS64);
+    let start = buf.len() - count_digits_u64(n);
+    for i in (start..buf.len()).rev() {
+        let (q, r) = div_rem(n, 10);
+        buf[i] = r as u8 + b'0';
+        n = q;
+    }
+    start
+}
+
+pub fn u32_to_dec(mut n: u32, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS32);
+    let start = buf.len() - count_digits_u32(n);
+    for i in (start..buf.len()).rev() {
+        let (q, r) = div_rem(n, 10);
+        buf[i] = r as u8 + b'0';
+        n = q;
+    }
+    start
+}
+
+pub fn u16_to_dec(mut n: u16, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS16);
+    let start = buf.len() - count_digits_u16(n);
+    for i in (start..buf.len()).rev() {
+        let (q, r) = div_rem(n, 10);
+        buf[i] = r as u8 + b'0';
+        n = q;
+    }
+    start
+}
+
+pub fn u8_to_dec(mut n: u8, buf: &mut [u8]) -> usize {
+    assert!(buf.len() >= NDIGITS8);
+    let start = buf.len() - count_digits_u8(n);
+    for i in (start..buf.len()).rev() {
+        let (q, r) = div_rem(n, 10);
+        buf[i] = r as u8 + b'0';
+        n = q;
+    }
+    start
+}
+
+signed_to_digits! {
+    i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+    i16_to_digits(i16) => u16_to_digits(u16) -> Digits16;
+    i8_to_digits(i8) => u8_to_digits(u8) -> Digits8;
+}
@@ -0,0 +1,249 @@
+// the runtime strategy, which dispatches to one of other strategies per function.
+//
+// the default choice is made on the first use from the CPU features. it mostly follows
+// `best` (which is tuned for the machines in the README), except that the CPUs with BMI2
+// (Haswell, Zen and later) are assumed to have a fast enough 64-bit multiplier for `jeaiii`
+// and the SIMD strategy is used whenever its SSE2 code is compiled in and can be executed
+// (its AVX2 code only serves the batch conversion). every choice can be overridden with
+// `Dispatch::select`, or refined on the current machine with `calibrate`.
+
+use core::prelude::*;
+use core::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+
+use dispatch::Features;
+use int2dec::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use super::{naive, naive_earlyexit, div100, div100_earlyexit, div100_u32, div100_u32_earlyexit};
+use super::{bcd, bcd_earlyexit, jeaiii, simd};
+
+dispatch! {
+    pub static U128_TO_DIGITS: fn(u128) -> Digits128 = {
+        "naive" => naive::u128_to_digits,
+        "naive_earlyexit" => naive_earlyexit::u128_to_digits,
+        "div100" => div100::u128_to_digits,
+        "div100_earlyexit" => div100_earlyexit::u128_to_digits,
+        "div100_u32" => div100_u32::u128_to_digits,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u128_to_digits,
+        "bcd" => bcd::u128_to_digits,
+        "bcd_earlyexit" => bcd_earlyexit::u128_to_digits
+    } default wide_default;
+
+    pub static U64_TO_DIGITS: fn(u64) -> Digits64 = {
+        "naive" => naive::u64_to_digits,
+        "naive_earlyexit" => naive_earlyexit::u64_to_digits,
+        "div100" => div100::u64_to_digits,
+        "div100_earlyexit" => div100_earlyexit::u64_to_digits,
+        "div100_u32" => div100_u32::u64_to_digits,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u64_to_digits,
+        "bcd" => bcd::u64_to_digits,
+        "bcd_earlyexit" => bcd_earlyexit::u64_to_digits,
+        "jeaiii" => jeaiii::u64_to_digits,
+        "simd" => simd::u64_to_digits
+    } default u64_default;
+
+    pub static U32_TO_DIGITS: fn(u32) -> Digits32 = {
+        "naive" => naive::u32_to_digits,
+        "naive_earlyexit" => naive_earlyexit::u32_to_digits,
+        "div100" => div100::u32_to_digits,
+        "div100_earlyexit" => div100_earlyexit::u32_to_digits,
+        "bcd" => bcd::u32_to_digits,
+        "bcd_earlyexit" => bcd_earlyexit::u32_to_digits,
+        "jeaiii" => jeaiii::u32_to_digits
+    } default u32_default;
+
+    pub static U16_TO_DIGITS: fn(u16) -> Digits16 = {
+        "naive" => naive::u16_to_digits,
+        "naive_earlyexit" => naive_earlyexit::u16_to_digits,
+        "div100" => div100::u16_to_digits,
+        "div100_earlyexit" => div100_earlyexit::u16_to_digits,
+        "div100_u32" => div100_u32::u16_to_digits,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u16_to_digits,
+        "jeaiii" => jeaiii::u16_to_digits
+    } default u16_default;
+
+    pub static U8_TO_DIGITS: fn(u8) -> Digits8 = {
+        "naive" => naive::u8_to_digits,
+        "naive_earlyexit" => naive_earlyexit::u8_to_digits,
+        "div100" => div100::u8_to_digits,
+        "div100_earlyexit" => div100_earlyexit::u8_to_digits,
+        "div100_u32" => div100_u32::u8_to_digits,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u8_to_digits,
+        "jeaiii" => jeaiii::u8_to_digits
+    } default u8_default;
+
+    pub static U128_TO_DEC: fn(u128, &mut [u8]) -> usize = {
+        "naive_earlyexit" => naive_earlyexit::u128_to_dec,
+        "div100_earlyexit" => div100_earlyexit::u128_to_dec,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u128_to_dec,
+        "bcd_earlyexit" => bcd_earlyexit::u128_to_dec
+    } default wide_default;
+
+    pub static U64_TO_DEC: fn(u64, &mut [u8]) -> usize = {
+        "naive_earlyexit" => naive_earlyexit::u64_to_dec,
+        "div100_earlyexit" => div100_earlyexit::u64_to_dec,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u64_to_dec,
+        "bcd_earlyexit" => bcd_earlyexit::u64_to_dec
+    } default wide_default;
+
+    pub static U32_TO_DEC: fn(u32, &mut [u8]) -> usize = {
+        "naive_earlyexit" => naive_earlyexit::u32_to_dec,
+        "div100_earlyexit" => div100_earlyexit::u32_to_dec,
+        "bcd_earlyexit" => bcd_earlyexit::u32_to_dec
+    } default u32_dec_default;
+
+    pub static U16_TO_DEC: fn(u16, &mut [u8]) -> usize = {
+        "naive_earlyexit" => naive_earlyexit::u16_to_dec,
+        "div100_earlyexit" => div100_earlyexit::u16_to_dec,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u16_to_dec
+    } default u16_dec_default;
+
+    pub static U8_TO_DEC: fn(u8, &mut [u8]) -> usize = {
+        "naive_earlyexit" => naive_earlyexit::u8_to_dec,
+        "div100_earlyexit" => div100_earlyexit::u8_to_dec,
+        "div100_u32_earlyexit" => div100_u32_earlyexit::u8_to_dec
+    } default u8_dec_default;
+}
+
+// `u128` and `u64` except for `u64_to_digits`
+fn wide_default(_: &Features) -> &'static str {
+    if cfg!(target_arch = "x86") { "bcd_earlyexit" } else { "div100_u32_earlyexit" }
+}
+
+// `simd::u64_to_digits` is scalar unless SSE2 is enabled at the compile time.
+fn u64_default(f: &Features) -> &'static str {
+    if cfg!(all(any(target_arch = "x86", target_arch = "x86_64"),
+                target_feature = "sse2")) && f.sse2 {
+        "simd"
+    } else {
+        wide_default(f)
+    }
+}
+
+fn u32_default(f: &Features) -> &'static str {
+    if cfg!(target_arch = "x86_64") && f.bmi2 { "jeaiii" } else { "div100_earlyexit" }
+}
+
+fn u16_default(f: &Features) -> &'static str {
+    if cfg!(target_arch = "x86") {
+        "div100"
+    } else if cfg!(target_arch = "x86_64") {
+        if f.bmi2 { "jeaiii" } else { "div100_u32" }
+    } else {
+        "naive"
+    }
+}
+
+fn u8_default(_: &Features) -> &'static str {
+    if cfg!(target_arch = "x86") { "div100_earlyexit" } else { "div100_u32" }
+}
+
+fn u32_dec_default(_: &Features) -> &'static str {
+    "div100_earlyexit"
+}
+
+fn u16_dec_default(_: &Features) -> &'static str {
+    if cfg!(target_arch = "x86") { "div100_earlyexit" } else { "naive_earlyexit" }
+}
+
+fn u8_dec_default(_: &Features) -> &'static str {
+    if cfg!(target_arch = "x86") { "div100_earlyexit" } else { "div100_u32_earlyexit" }
+}
+
+#[inline] pub fn u128_to_digits(n: u128) -> Digits128 { (U128_TO_DIGITS.get())(n) }
+#[inline] pub fn u64_to_digits(n: u64) -> Digits64 { (U64_TO_DIGITS.get())(n) }
+#[inline] pub fn u32_to_digits(n: u32) -> Digits32 { (U32_TO_DIGITS.get())(n) }
+#[inline] pub fn u16_to_digits(n: u16) -> Digits16 { (U16_TO_DIGITS.get())(n) }
+#[inline] pub fn u8_to_digits(n: u8) -> Digits8 { (U8_TO_DIGITS.get())(n) }
+
+#[inline] pub fn u128_to_dec(n: u128, buf: &mut [u8]) -> usize { (U128_TO_DEC.get())(n, buf) }
+#[inline] pub fn u64_to_dec(n: u64, buf: &mut [u8]) -> usize { (U64_TO_DEC.get())(n, buf) }
+#[inline] pub fn u32_to_dec(n: u32, buf: &mut [u8]) -> usize { (U32_TO_DEC.get())(n, buf) }
+#[inline] pub fn u16_to_dec(n: u16, buf: &mut [u8]) -> usize { (U16_TO_DEC.get())(n, buf) }
+#[inline] pub fn u8_to_dec(n: u8, buf: &mut [u8]) -> usize { (U8_TO_DEC.get())(n, buf) }
+
+signed_to_digits! {
+    i128_to_digits(i128) => u128_to_digits(u128) -> Digits128;
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+    i32_to_digits(i32) => u32_to_digits(u32) -> Digits32;
+    i16_to_digits(i16) => u16_to_digits(u16) -> Digits16;
+    i8_to_digits(i8) => u8_to_digits(u8) -> Digits8;
+}
+
+// keeps the calibration workload from being optimized out.
+static SINK: AtomicUsize = ATOMIC_USIZE_INIT;
+
+// the number of passes over the inputs per measurement.
+const PASSES: usize = 16;
+
+// the same sets of integers as benchmarks, interleaved: one smallish (starts at 4 and
+// multiplies by 5/4 each time) and one larger (starts at 1 and multiplies by 3 each time).
+// they are truncated to the width of each function.
+fn calibration_inputs() -> [u128; 128] {
+    let mut inputs = [0; 128];
+    let mut small = 4u128;
+    let mut large = 1u128;
+    for i in 0..64 {
+        inputs[i * 2] = small;
+        inputs[i * 2 + 1] = large;
+        small = small * 5 / 4;
+        large = large.wrapping_mul(3);
+    }
+    inputs
+}
+
+/// Times every candidate of every function and chooses the fastest ones.
+///
+/// `clock` should return the current time in any monotonically increasing unit,
+/// preferably with a sub-microsecond resolution. This takes some milliseconds.
+pub fn calibrate<C: FnMut() -> u64>(clock: &mut C) {
+    let inputs = calibration_inputs();
+
+    macro_rules! calibrate_digits {
+        ($($DISPATCH:ident($t:ty) -> $Digits:ty;)*) => ($(
+            $DISPATCH.calibrate(clock, &mut |f: fn($t) -> $Digits| {
+                let mut acc = 0;
+                for _ in 0..PASSES {
+                    for &n in &inputs[..] { acc ^= f(n as $t)[0] as usize; }
+                }
+                SINK.store(acc, Ordering::Relaxed);
+            });
+        )*)
+    }
+
+    macro_rules! calibrate_dec {
+        ($($DISPATCH:ident($t:ty) -> $NDIGITS:expr;)*) => ($(
+            $DISPATCH.calibrate(clock, &mut |f: fn($t, &mut [u8]) -> usize| {
+                let mut acc = 0;
+                let mut buf = [0; $NDIGITS];
+                for _ in 0..PASSES {
+                    for &n in &inputs[..] { acc ^= f(n as $t, &mut buf); }
+                }
+                SINK.store(acc, Ordering::Relaxed);
+            });
+        )*)
+    }
+
+    calibrate_digits! {
+        U128_TO_DIGITS(u128) -> Digits128;
+        U64_TO_DIGITS(u64) -> Digits64;
+        U32_TO_DIGITS(u32) -> Digits32;
+        U16_TO_DIGITS(u16) -> Digits16;
+        U8_TO_DIGITS(u8) -> Digits8;
+    }
+
+    calibrate_dec! {
+        U128_TO_DEC(u128) -> NDIGITS128;
+        U64_TO_DEC(u64) -> NDIGITS64;
+        U32_TO_DEC(u32) -> NDIGITS32;
+        U16_TO_DEC(u16) -> NDIGITS16;
+        U8_TO_DEC(u8) -> NDIGITS8;
+    }
+}
+
+/// Forgets every choice, so that the defaults are chosen again on the next use.
+pub fn reset() {
+    U128_TO_DIGITS.reset(); U64_TO_DIGITS.reset(); U32_TO_DIGITS.reset();
+    U16_TO_DIGITS.reset(); U8_TO_DIGITS.reset();
+    U128_TO_DEC.reset(); U64_TO_DEC.reset(); U32_TO_DEC.reset();
+    U16_TO_DEC.reset(); U8_TO_DEC.reset();
+}
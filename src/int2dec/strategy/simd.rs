@@ -0,0 +1,171 @@
+// the vectorized strategy for `u64`, based on the SSE2 routine by Wojciech Muła.
+//
+// `n` is split into 4 + 8 + 8 digits with the scalar code, and two 8-digit halves are
+// converted in parallel. each half `abcdefgh` is first split into `abcd` and `efgh`
+// (with the multiplication by the reciprocal of 10^4), and each of them is broadcast to
+// four 16-bit lanes which are divided by 10^3, 10^2, 10^1 and 10^0 respectively
+// (again with the multiplication by reciprocals and shifts). subtracting ten times
+// the lane to the left then gives the individual digits.
+//
+// with AVX2 the batch conversion processes two numbers at once, one per 128-bit lane.
+// every vector instruction used here is lane-local, so the code is almost identical.
+// when neither is available at the compile time, the scalar fallback is used.
+
+use num::div_rem;
+
+use int2dec::digits::{Digits64, NDIGITS64};
+use int2dec::digits::{ONES, TENS};
+
+#[cfg(all(target_arch = "x86", target_feature = "sse2"))] use core::arch::x86::*;
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))] use core::arch::x86_64::*;
+
+// returns `(hi, mid, lo)` such that `n = hi * 10^16 + mid * 10^8 + lo` and `hi < 1845`.
+#[inline(always)]
+fn split(n: u64) -> (u32, u32, u32) {
+    let (hi, n) = div_rem(n, 1_0000_0000_0000_0000);
+    let (mid, lo) = div_rem(n, 1_0000_0000);
+    (hi as u32, mid as u32, lo as u32)
+}
+
+#[inline(always)]
+fn write_hi(hi: u32, buf: &mut [u8]) {
+    let (hi, lo) = div_rem(hi, 100);
+    buf[0] = tens!(hi);
+    buf[1] = ones!(hi);
+    buf[2] = tens!(lo);
+    buf[3] = ones!(lo);
+}
+
+// converts `n < 10^8` stored in the lowest 32 bits of each 128-bit lane into
+// eight 16-bit digits in that lane, the most significant digit first.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+#[inline(always)]
+unsafe fn sse2_convert8(abcdefgh: __m128i) -> __m128i {
+    // abcd = abcdefgh / 10^4 = (abcdefgh * ceil(2^45 / 10^4)) >> 45
+    let abcd = _mm_srli_epi64(_mm_mul_epu32(abcdefgh, _mm_set1_epi32(0xd1b71759u32 as i32)), 45);
+    let efgh = _mm_sub_epi32(abcdefgh, _mm_mul_epu32(abcd, _mm_set1_epi32(10000)));
+
+    // [abcd, abcd, abcd, abcd, efgh, efgh, efgh, efgh] (pre-shifted by 2 bits for precision)
+    let v1 = _mm_slli_epi64(_mm_unpacklo_epi16(abcd, efgh), 2);
+    let v2 = _mm_unpacklo_epi16(v1, v1);
+    let v2 = _mm_unpacklo_epi32(v2, v2);
+
+    // [a, ab, abc, abcd, e, ef, efg, efgh]
+    let v3 = _mm_mulhi_epu16(v2, _mm_set_epi16(0x8000u16 as i16, 13108, 5243, 8389,
+                                                0x8000u16 as i16, 13108, 5243, 8389));
+    let v4 = _mm_mulhi_epu16(v3, _mm_set_epi16(0x8000u16 as i16, 1 << 13, 1 << 11, 1 << 7,
+                                                0x8000u16 as i16, 1 << 13, 1 << 11, 1 << 7));
+
+    // [a, b, c, d, e, f, g, h]
+    let v5 = _mm_slli_epi64(_mm_mullo_epi16(v4, _mm_set1_epi16(10)), 16);
+    _mm_sub_epi16(v4, v5)
+}
+
+// writes 16 digits of `mid` and `lo` to `buf[..16]`.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"))]
+#[inline(always)]
+fn write_mid_lo(mid: u32, lo: u32, buf: &mut [u8]) {
+    assert!(buf.len() >= 16);
+    unsafe {
+        let mid = sse2_convert8(_mm_cvtsi32_si128(mid as i32));
+        let lo = sse2_convert8(_mm_cvtsi32_si128(lo as i32));
+        let digits = _mm_add_epi8(_mm_packus_epi16(mid, lo), _mm_set1_epi8(b'0' as i8));
+        _mm_storeu_si128(buf.as_mut_ptr() as *mut __m128i, digits);
+    }
+}
+
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2")))]
+#[inline(always)]
+fn write_mid_lo(mid: u32, lo: u32, buf: &mut [u8]) {
+    assert!(buf.len() >= 16);
+    for &(mut n, i) in &[(mid, 0), (lo, 8)] {
+        let (q, r) = div_rem(n, 100); buf[i+6] = tens!(r); buf[i+7] = ones!(r); n = q;
+        let (q, r) = div_rem(n, 100); buf[i+4] = tens!(r); buf[i+5] = ones!(r); n = q;
+        let (q, r) = div_rem(n, 100); buf[i+2] = tens!(r); buf[i+3] = ones!(r); n = q;
+        buf[i] = tens!(n); buf[i+1] = ones!(n);
+    }
+}
+
+#[inline(always)]
+fn write_u64(n: u64, buf: &mut [u8]) {
+    let (hi, mid, lo) = split(n);
+    write_hi(hi, &mut buf[..4]);
+    write_mid_lo(mid, lo, &mut buf[4..NDIGITS64]);
+}
+
+pub fn u64_to_digits(n: u64) -> Digits64 {
+    let mut buf: Digits64 = [0; NDIGITS64];
+    write_u64(n, &mut buf);
+    buf
+}
+
+// writes two numbers to `buf[..2*NDIGITS64]` at once, and returns the number of
+// converted integers from `ns` (either 0 or a multiple of 2).
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx2"))]
+fn write_u64_pairs(ns: &[u64], out: &mut [u8]) -> usize {
+    let npairs = ns.len() / 2;
+    for i in 0..npairs {
+        let buf = &mut out[i * 2 * NDIGITS64..(i + 1) * 2 * NDIGITS64];
+        let (hi0, mid0, lo0) = split(ns[i * 2]);
+        let (hi1, mid1, lo1) = split(ns[i * 2 + 1]);
+        write_hi(hi0, &mut buf[..4]);
+        write_hi(hi1, &mut buf[NDIGITS64..NDIGITS64 + 4]);
+        unsafe {
+            let mid = avx2_convert8(_mm256_set_epi32(0, 0, 0, mid1 as i32, 0, 0, 0, mid0 as i32));
+            let lo = avx2_convert8(_mm256_set_epi32(0, 0, 0, lo1 as i32, 0, 0, 0, lo0 as i32));
+            let digits = _mm256_add_epi8(_mm256_packus_epi16(mid, lo), _mm256_set1_epi8(b'0' as i8));
+            _mm_storeu_si128(buf[4..].as_mut_ptr() as *mut __m128i,
+                             _mm256_castsi256_si128(digits));
+            _mm_storeu_si128(buf[NDIGITS64 + 4..].as_mut_ptr() as *mut __m128i,
+                             _mm256_extracti128_si256(digits, 1));
+        }
+    }
+    npairs * 2
+}
+
+#[cfg(not(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx2")))]
+#[inline(always)]
+fn write_u64_pairs(_ns: &[u64], _out: &mut [u8]) -> usize {
+    0
+}
+
+// same as `sse2_convert8`, but for two 128-bit lanes.
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "avx2"))]
+#[inline(always)]
+unsafe fn avx2_convert8(abcdefgh: __m256i) -> __m256i {
+    let abcd = _mm256_srli_epi64(_mm256_mul_epu32(abcdefgh, _mm256_set1_epi32(0xd1b71759u32 as i32)),
+                                 45);
+    let efgh = _mm256_sub_epi32(abcdefgh, _mm256_mul_epu32(abcd, _mm256_set1_epi32(10000)));
+
+    let v1 = _mm256_slli_epi64(_mm256_unpacklo_epi16(abcd, efgh), 2);
+    let v2 = _mm256_unpacklo_epi16(v1, v1);
+    let v2 = _mm256_unpacklo_epi32(v2, v2);
+
+    let v3 = _mm256_mulhi_epu16(v2, _mm256_set_epi16(0x8000u16 as i16, 13108, 5243, 8389,
+                                                     0x8000u16 as i16, 13108, 5243, 8389,
+                                                     0x8000u16 as i16, 13108, 5243, 8389,
+                                                     0x8000u16 as i16, 13108, 5243, 8389));
+    let v4 = _mm256_mulhi_epu16(v3, _mm256_set_epi16(0x8000u16 as i16, 1 << 13, 1 << 11, 1 << 7,
+                                                     0x8000u16 as i16, 1 << 13, 1 << 11, 1 << 7,
+                                                     0x8000u16 as i16, 1 << 13, 1 << 11, 1 << 7,
+                                                     0x8000u16 as i16, 1 << 13, 1 << 11, 1 << 7));
+
+    let v5 = _mm256_slli_epi64(_mm256_mullo_epi16(v4, _mm256_set1_epi16(10)), 16);
+    _mm256_sub_epi16(v4, v5)
+}
+
+// writes the fixed-size digits of each integer in `ns` to `out` consecutively,
+// so that `out[i*NDIGITS64..(i+1)*NDIGITS64]` is same to `u64_to_digits(ns[i])`.
+// `out` should be at least `ns.len() * NDIGITS64` bytes long.
+pub fn u64s_to_digits(ns: &[u64], out: &mut [u8]) {
+    assert!(out.len() >= ns.len() * NDIGITS64);
+    let mut i = write_u64_pairs(ns, out);
+    while i < ns.len() {
+        write_u64(ns[i], &mut out[i * NDIGITS64..(i + 1) * NDIGITS64]);
+        i += 1;
+    }
+}
+
+signed_to_digits! {
+    i64_to_digits(i64) => u64_to_digits(u64) -> Digits64;
+}
@@ -1,14 +1,32 @@
 use std::prelude::v1::*;
+use std::{str, fmt, i8, i16, i32, i64, i128};
 use std::ops::{Add, Shr};
 use std::num::One;
 use std::num::wrapping::OverflowingOps;
 use test;
 
-use int2dec::digits::{Digits64, Digits32, Digits16, Digits8};
-use int2dec::UintToDec;
+use int2dec::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+use int2dec::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use int2dec::{UintToDec, IntToDec, GroupedUintToDec, GroupedIntToDec};
+use int2dec::{count_digits_u128, count_digits_u64, count_digits_u32};
+use int2dec::{count_digits_u16, count_digits_u8};
+use grouping::{self, Grouping};
 
 pub use test::Bencher;
 
+pub fn u128_sanity_test<F: FnMut(u128) -> Digits128>(mut f: F) {
+    assert_eq!(&f(0), b"000000000000000000000000000000000000000");
+    assert_eq!(&f(1), b"000000000000000000000000000000000000001");
+    assert_eq!(&f(12), b"000000000000000000000000000000000000012");
+    assert_eq!(&f(1234567), b"000000000000000000000000000000001234567");
+    assert_eq!(&f(18446744073709551615), b"000000000000000000018446744073709551615");
+    assert_eq!(&f(18446744073709551616), b"000000000000000000018446744073709551616");
+    assert_eq!(&f(12345678901234567890123456789012345678),
+               b"012345678901234567890123456789012345678");
+    assert_eq!(&f(340282366920938463463374607431768211455),
+               b"340282366920938463463374607431768211455");
+}
+
 pub fn u64_sanity_test<F: FnMut(u64) -> Digits64>(mut f: F) {
     assert_eq!(&f(                   0), b"00000000000000000000");
     assert_eq!(&f(                   1), b"00000000000000000001");
@@ -44,6 +62,100 @@ pub fn u8_sanity_test<F: FnMut(u8) -> Digits8>(mut f: F) {
     assert_eq!(&f(255), b"255");
 }
 
+pub fn i128_sanity_test<F: FnMut(i128) -> (bool, Digits128)>(mut f: F) {
+    assert_eq!(f(0), (false, *b"000000000000000000000000000000000000000"));
+    assert_eq!(f(1), (false, *b"000000000000000000000000000000000000001"));
+    assert_eq!(f(-1), (true, *b"000000000000000000000000000000000000001"));
+    assert_eq!(f(-18446744073709551616),
+               (true, *b"000000000000000000018446744073709551616"));
+    assert_eq!(f(170141183460469231731687303715884105727),
+               (false, *b"170141183460469231731687303715884105727"));
+    assert_eq!(f(i128::MIN), (true, *b"170141183460469231731687303715884105728"));
+}
+
+pub fn i64_sanity_test<F: FnMut(i64) -> (bool, Digits64)>(mut f: F) {
+    assert_eq!(f(                   0), (false, *b"00000000000000000000"));
+    assert_eq!(f(                   1), (false, *b"00000000000000000001"));
+    assert_eq!(f(                  -1), (true,  *b"00000000000000000001"));
+    assert_eq!(f(            -1234567), (true,  *b"00000000000001234567"));
+    assert_eq!(f( 9223372036854775807), (false, *b"09223372036854775807"));
+    assert_eq!(f(-9223372036854775807), (true,  *b"09223372036854775807"));
+    assert_eq!(f(i64::MIN),             (true,  *b"09223372036854775808"));
+}
+
+pub fn i32_sanity_test<F: FnMut(i32) -> (bool, Digits32)>(mut f: F) {
+    assert_eq!(f(          0), (false, *b"0000000000"));
+    assert_eq!(f(          1), (false, *b"0000000001"));
+    assert_eq!(f(         -1), (true,  *b"0000000001"));
+    assert_eq!(f(   -1234567), (true,  *b"0001234567"));
+    assert_eq!(f( 2147483647), (false, *b"2147483647"));
+    assert_eq!(f(-2147483647), (true,  *b"2147483647"));
+    assert_eq!(f(i32::MIN),    (true,  *b"2147483648"));
+}
+
+pub fn i16_sanity_test<F: FnMut(i16) -> (bool, Digits16)>(mut f: F) {
+    assert_eq!(f(     0), (false, *b"00000"));
+    assert_eq!(f(     1), (false, *b"00001"));
+    assert_eq!(f(    -1), (true,  *b"00001"));
+    assert_eq!(f(  -123), (true,  *b"00123"));
+    assert_eq!(f( 32767), (false, *b"32767"));
+    assert_eq!(f(-32767), (true,  *b"32767"));
+    assert_eq!(f(i16::MIN), (true, *b"32768"));
+}
+
+pub fn i8_sanity_test<F: FnMut(i8) -> (bool, Digits8)>(mut f: F) {
+    assert_eq!(f(   0), (false, *b"000"));
+    assert_eq!(f(   1), (false, *b"001"));
+    assert_eq!(f(  -1), (true,  *b"001"));
+    assert_eq!(f( -12), (true,  *b"012"));
+    assert_eq!(f( 127), (false, *b"127"));
+    assert_eq!(f(-127), (true,  *b"127"));
+    assert_eq!(f(i8::MIN), (true, *b"128"));
+}
+
+fn check_dec<I, F>(f: &mut F, n: I, ndigits: usize)
+        where I: Copy + fmt::Display, F: FnMut(I, &mut [u8]) -> usize {
+    let expected = n.to_string();
+    for &extra in &[0, 1, 7] {
+        let mut buf = vec![b'_'; ndigits + extra];
+        let start = f(n, &mut buf);
+        assert_eq!(str::from_utf8(&buf[start..]).unwrap(), expected);
+        assert!(buf[..start].iter().all(|&c| c == b'_'),
+                "{} wrote outside of significant digits: {:?}", n, buf);
+    }
+}
+
+macro_rules! dec_sanity_test {
+    ($name:ident: $t:ty, $ndigits:expr) => (
+        pub fn $name<F: FnMut($t, &mut [u8]) -> usize>(mut f: F) {
+            // all powers of 10 and their neighbors
+            let mut n: $t = 1;
+            loop {
+                check_dec(&mut f, n - 1, $ndigits);
+                check_dec(&mut f, n, $ndigits);
+                check_dec(&mut f, n + 1, $ndigits);
+                match n.checked_mul(10) {
+                    Some(n_) => { n = n_; }
+                    None => { break; }
+                }
+            }
+
+            let mut n: $t = 1;
+            for _ in 0..(::std::mem::size_of::<$t>() * 8) {
+                check_dec(&mut f, n, $ndigits);
+                check_dec(&mut f, !n, $ndigits);
+                n = n.wrapping_mul(3);
+            }
+        }
+    )
+}
+
+dec_sanity_test!(u128_dec_sanity_test: u128, NDIGITS128);
+dec_sanity_test!(u64_dec_sanity_test: u64, NDIGITS64);
+dec_sanity_test!(u32_dec_sanity_test: u32, NDIGITS32);
+dec_sanity_test!(u16_dec_sanity_test: u16, NDIGITS16);
+dec_sanity_test!(u8_dec_sanity_test: u8, NDIGITS8);
+
 #[inline(always)]
 pub fn rotating_bench<I, T, F>(mut f: F, b: &mut Bencher)
         where I: Copy + One + Add<I,Output=I> + Shr<usize,Output=I> + OverflowingOps,
@@ -70,6 +182,51 @@ pub fn rotating_bench<I, T, F>(mut f: F, b: &mut Bencher)
     });
 }
 
+pub fn u64_batch_sanity_test<F: FnMut(&[u64], &mut [u8])>(mut f: F) {
+    let mut ns = Vec::new();
+    let mut n = 1u64;
+    for _ in 0..64 {
+        ns.push(n);
+        ns.push(!n);
+        n = n.wrapping_mul(3);
+    }
+    ns.push(0);
+    ns.push(18446744073709551615);
+
+    // every prefix, so that both odd and even lengths are checked
+    for len in 0..ns.len() + 1 {
+        let mut out = vec![b'_'; len * NDIGITS64 + 1];
+        f(&ns[..len], &mut out);
+        for (i, &n) in ns[..len].iter().enumerate() {
+            let expected = format!("{:020}", n);
+            assert_eq!(&out[i * NDIGITS64..(i + 1) * NDIGITS64], expected.as_bytes());
+        }
+        assert_eq!(out[len * NDIGITS64], b'_');
+    }
+}
+
+// same as `rotating_bench`, but all integers are converted by a single call
+#[inline(always)]
+pub fn rotating_batch_bench<F>(mut f: F, b: &mut Bencher) where F: FnMut(&[u64], &mut [u8]) {
+    let mut ns = Vec::new();
+    let mut n = 4u64;
+    for _ in 0..64 {
+        ns.push(n);
+        n = n.wrapping_add(n >> 2);
+    }
+    let mut n = 1u64;
+    for _ in 0..64 {
+        ns.push(n);
+        n = n.wrapping_mul(3);
+    }
+
+    let mut out = vec![0; ns.len() * NDIGITS64];
+    b.iter(|| {
+        f(&ns, &mut out);
+        test::black_box(&out);
+    });
+}
+
 // per-strategy tests
 macro_rules! per_strategy {
     ($strategy:ident: $($t:tt)*) => (
@@ -89,6 +246,18 @@ macro_rules! per_strategy_sanity_test {
     (u16 $($t:tt)*) => ({ u16_sanity_test(u16_to_digits); per_strategy_sanity_test!($($t)*) });
     (u32 $($t:tt)*) => ({ u32_sanity_test(u32_to_digits); per_strategy_sanity_test!($($t)*) });
     (u64 $($t:tt)*) => ({ u64_sanity_test(u64_to_digits); per_strategy_sanity_test!($($t)*) });
+    (u128 $($t:tt)*) => ({ u128_sanity_test(u128_to_digits); per_strategy_sanity_test!($($t)*) });
+    (i8 $($t:tt)*) => ({ i8_sanity_test(i8_to_digits); per_strategy_sanity_test!($($t)*) });
+    (i16 $($t:tt)*) => ({ i16_sanity_test(i16_to_digits); per_strategy_sanity_test!($($t)*) });
+    (i32 $($t:tt)*) => ({ i32_sanity_test(i32_to_digits); per_strategy_sanity_test!($($t)*) });
+    (i64 $($t:tt)*) => ({ i64_sanity_test(i64_to_digits); per_strategy_sanity_test!($($t)*) });
+    (i128 $($t:tt)*) => ({ i128_sanity_test(i128_to_digits); per_strategy_sanity_test!($($t)*) });
+    (u8_dec $($t:tt)*) => ({ u8_dec_sanity_test(u8_to_dec); per_strategy_sanity_test!($($t)*) });
+    (u16_dec $($t:tt)*) => ({ u16_dec_sanity_test(u16_to_dec); per_strategy_sanity_test!($($t)*) });
+    (u32_dec $($t:tt)*) => ({ u32_dec_sanity_test(u32_to_dec); per_strategy_sanity_test!($($t)*) });
+    (u64_dec $($t:tt)*) => ({ u64_dec_sanity_test(u64_to_dec); per_strategy_sanity_test!($($t)*) });
+    (u128_dec $($t:tt)*) => ({ u128_dec_sanity_test(u128_to_dec); per_strategy_sanity_test!($($t)*) });
+    (u64_batch $($t:tt)*) => ({ u64_batch_sanity_test(u64s_to_digits); per_strategy_sanity_test!($($t)*) });
 }
 
 macro_rules! per_strategy_bench {
@@ -109,22 +278,162 @@ macro_rules! per_strategy_bench {
         #[bench] fn bench_u64(b: &mut Bencher) { rotating_bench(u64_to_digits, b) }
         per_strategy_bench!($($t)*);
     );
+    (u128 $($t:tt)*) => (
+        #[bench] fn bench_u128(b: &mut Bencher) { rotating_bench(u128_to_digits, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i8 $($t:tt)*) => (
+        #[bench] fn bench_i8(b: &mut Bencher) { rotating_bench(i8_to_digits, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i16 $($t:tt)*) => (
+        #[bench] fn bench_i16(b: &mut Bencher) { rotating_bench(i16_to_digits, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i32 $($t:tt)*) => (
+        #[bench] fn bench_i32(b: &mut Bencher) { rotating_bench(i32_to_digits, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i64 $($t:tt)*) => (
+        #[bench] fn bench_i64(b: &mut Bencher) { rotating_bench(i64_to_digits, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (i128 $($t:tt)*) => (
+        #[bench] fn bench_i128(b: &mut Bencher) { rotating_bench(i128_to_digits, b) }
+        per_strategy_bench!($($t)*);
+    );
+    (u8_dec $($t:tt)*) => (
+        #[bench] fn bench_u8_dec(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2dec::NDIGITS8];
+                                 let start = u8_to_dec(n, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u16_dec $($t:tt)*) => (
+        #[bench] fn bench_u16_dec(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2dec::NDIGITS16];
+                                 let start = u16_to_dec(n, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u32_dec $($t:tt)*) => (
+        #[bench] fn bench_u32_dec(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2dec::NDIGITS32];
+                                 let start = u32_to_dec(n, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u64_dec $($t:tt)*) => (
+        #[bench] fn bench_u64_dec(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2dec::NDIGITS64];
+                                 let start = u64_to_dec(n, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u128_dec $($t:tt)*) => (
+        #[bench] fn bench_u128_dec(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2dec::NDIGITS128];
+                                 let start = u128_to_dec(n, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u64_batch $($t:tt)*) => (
+        #[bench] fn bench_u64_batch(b: &mut Bencher) { rotating_batch_bench(u64s_to_digits, b) }
+        per_strategy_bench!($($t)*);
+    );
 }
 
 mod strategy {
-    per_strategy!(bcd:                  u64 u32       );
-    per_strategy!(bcd_earlyexit:        u64 u32       );
-    per_strategy!(div100:               u64 u32 u16 u8);
-    per_strategy!(div100_earlyexit:     u64 u32 u16 u8);
-    per_strategy!(div100_u32:           u64     u16 u8);
-    per_strategy!(div100_u32_earlyexit: u64     u16 u8);
-    per_strategy!(naive:                u64 u32 u16 u8);
-    per_strategy!(naive_earlyexit:      u64 u32 u16 u8);
+    per_strategy!(bcd:                  u128 u64 u32         i128 i64 i32       );
+    per_strategy!(bcd_earlyexit:        u128 u64 u32         i128 i64 i32
+                                        u128_dec u64_dec u32_dec                  );
+    per_strategy!(div100:               u128 u64 u32 u16 u8  i128 i64 i32 i16 i8);
+    per_strategy!(div100_earlyexit:     u128 u64 u32 u16 u8  i128 i64 i32 i16 i8
+                                        u128_dec u64_dec u32_dec u16_dec u8_dec);
+    per_strategy!(div100_u32:           u128 u64     u16 u8  i128 i64     i16 i8);
+    per_strategy!(div100_u32_earlyexit: u128 u64     u16 u8  i128 i64     i16 i8
+                                        u128_dec u64_dec         u16_dec u8_dec);
+    per_strategy!(jeaiii:                    u64 u32 u16 u8       i64 i32 i16 i8);
+    per_strategy!(simd:                      u64                  i64
+                                        u64_batch                                 );
+    per_strategy!(naive:                u128 u64 u32 u16 u8  i128 i64 i32 i16 i8);
+    per_strategy!(naive_earlyexit:      u128 u64 u32 u16 u8  i128 i64 i32 i16 i8
+                                        u128_dec u64_dec u32_dec u16_dec u8_dec);
 
     mod best {
         use super::super::*;
         use int2dec::strategy::best::*;
-        per_strategy_bench!(u64 u32 u16 u8);
+        #[test] fn sanity_test() {
+            per_strategy_sanity_test!(u128 u64 u32 u16 u8 i128 i64 i32 i16 i8
+                                      u128_dec u64_dec u32_dec u16_dec u8_dec)
+        }
+        per_strategy_bench!(u128 u64 u32 u16 u8 i128 i64 i32 i16 i8
+                            u128_dec u64_dec u32_dec u16_dec u8_dec);
+    }
+
+    mod runtime {
+        use super::super::*;
+        use int2dec::strategy::runtime::*;
+        #[test] fn sanity_test() {
+            per_strategy_sanity_test!(u128 u64 u32 u16 u8 i128 i64 i32 i16 i8
+                                      u128_dec u64_dec u32_dec u16_dec u8_dec)
+        }
+        per_strategy_bench!(u128 u64 u32 u16 u8 i128 i64 i32 i16 i8
+                            u128_dec u64_dec u32_dec u16_dec u8_dec);
+
+        #[test]
+        fn candidates_sanity_test() {
+            // every candidate should be correct for any input, as other tests may select them
+            macro_rules! check {
+                ($($DISPATCH:ident => $test:ident;)*) => ($(
+                    for &(_, f) in $DISPATCH.candidates { $test(f); }
+                )*)
+            }
+            check! {
+                U128_TO_DIGITS => u128_sanity_test; U64_TO_DIGITS => u64_sanity_test;
+                U32_TO_DIGITS => u32_sanity_test; U16_TO_DIGITS => u16_sanity_test;
+                U8_TO_DIGITS => u8_sanity_test;
+                U128_TO_DEC => u128_dec_sanity_test; U64_TO_DEC => u64_dec_sanity_test;
+                U32_TO_DEC => u32_dec_sanity_test; U16_TO_DEC => u16_dec_sanity_test;
+                U8_TO_DEC => u8_dec_sanity_test;
+            }
+        }
+
+        #[test]
+        fn dispatch_test() {
+            use std::sync::atomic::ATOMIC_USIZE_INIT;
+            use dispatch::Dispatch;
+
+            // the global choices are shared with other tests running in parallel,
+            // so this works on private copies of them instead.
+            macro_rules! check {
+                ($($DISPATCH:ident;)*) => ($({
+                    let d = Dispatch { candidates: $DISPATCH.candidates,
+                                       default: $DISPATCH.default, choice: ATOMIC_USIZE_INIT };
+
+                    // every candidate should be selectable
+                    for &(name, f) in d.candidates {
+                        assert!(d.select(name));
+                        assert_eq!(d.name(), name);
+                        assert!(d.get() as usize == f as usize);
+                    }
+                    assert!(!d.select("no such strategy"));
+                    assert_eq!(d.name(), d.candidates.last().unwrap().0);
+
+                    // with a clock that never advances, the first candidate should be chosen
+                    assert_eq!(d.calibrate(&mut || 0, &mut |_| {}), d.candidates[0].0);
+                    assert_eq!(d.name(), d.candidates[0].0);
+
+                    // the default should be always one of candidates
+                    d.reset();
+                    assert!(d.candidates.iter().any(|&(name, _)| name == d.name()));
+                })*)
+            }
+            check! {
+                U128_TO_DIGITS; U64_TO_DIGITS; U32_TO_DIGITS; U16_TO_DIGITS; U8_TO_DIGITS;
+                U128_TO_DEC; U64_TO_DEC; U32_TO_DEC; U16_TO_DEC; U8_TO_DEC;
+            }
+        }
     }
 }
 
@@ -140,8 +449,134 @@ fn uint_to_dec_sanity_test() {
     }
 }
 
+#[test]
+fn uint_to_dec_128_sanity_test() {
+    let mut n = 1u128;
+    for _ in 0..128 {
+        assert_eq!(n.to_string(), UintToDec(n).to_string());
+        assert_eq!((n as i128).to_string(), IntToDec(n as i128).to_string());
+        assert_eq!((-(n as i128)).to_string(), IntToDec(-(n as i128)).to_string());
+        n = n.wrapping_mul(3);
+    }
+    assert_eq!(IntToDec(i128::MIN).to_string(), "-170141183460469231731687303715884105728");
+}
+
+#[test]
+fn int_to_dec_sanity_test() {
+    let mut n = 1u64;
+    for _ in 0..64 {
+        for &m in &[n as i64, -(n as i64)] {
+            assert_eq!((m as i64).to_string(), IntToDec(m as i64).to_string());
+            assert_eq!((m as i32).to_string(), IntToDec(m as i32).to_string());
+            assert_eq!((m as i16).to_string(), IntToDec(m as i16).to_string());
+            assert_eq!((m as i8).to_string(), IntToDec(m as i8).to_string());
+        }
+        n = n.wrapping_mul(3);
+    }
+
+    assert_eq!(format!("{:+}", IntToDec(42i32)), "+42");
+    assert_eq!(format!("{:5}", IntToDec(-42i32)), "  -42");
+    assert_eq!(format!("{:05}", IntToDec(-42i32)), "-0042");
+    assert_eq!(IntToDec(i64::MIN).to_string(), "-9223372036854775808");
+    assert_eq!(IntToDec(i8::MIN).to_string(), "-128");
+}
+
+#[test]
+fn count_digits_exhaustive_test() {
+    for n in 0..256u32 {
+        assert_eq!(count_digits_u8(n as u8), n.to_string().len());
+    }
+    for n in 0..65536u32 {
+        assert_eq!(count_digits_u16(n as u16), n.to_string().len());
+    }
+}
+
+#[test]
+fn count_digits_boundary_test() {
+    assert_eq!(count_digits_u32(0), 1);
+    assert_eq!(count_digits_u64(0), 1);
+    assert_eq!(count_digits_u128(0), 1);
+
+    // 10^k - 1, 10^k and 10^k + 1 for every 10^k in the range
+    let mut p = 1u128;
+    for k in 1..39 {
+        p *= 10;
+        for &n in &[p - 1, p, p + 1] {
+            let expected = if n < p { k } else { k + 1 };
+            assert_eq!(count_digits_u128(n), expected);
+            if n <= !0u64 as u128 { assert_eq!(count_digits_u64(n as u64), expected); }
+            if n <= !0u32 as u128 { assert_eq!(count_digits_u32(n as u32), expected); }
+        }
+    }
+
+    // 2^k - 1 and 2^k, where the estimate from the bit length changes
+    for k in 1..128 {
+        let n = 1u128 << k;
+        assert_eq!(count_digits_u128(n - 1), (n - 1).to_string().len());
+        assert_eq!(count_digits_u128(n), n.to_string().len());
+        if k < 64 { assert_eq!(count_digits_u64(n as u64), n.to_string().len()); }
+        if k < 32 { assert_eq!(count_digits_u32(n as u32), n.to_string().len()); }
+    }
+
+    assert_eq!(count_digits_u32(!0), NDIGITS32);
+    assert_eq!(count_digits_u64(!0), NDIGITS64);
+    assert_eq!(count_digits_u128(!0), NDIGITS128);
+}
+
+#[test]
+fn grouped_to_dec_sanity_test() {
+    const NARROW_NBSP: Grouping<'static> = Grouping { separator: b"\xe2\x80\xaf", ..grouping::SPACE };
+    const BY_ONE: Grouping<'static> = Grouping { groups: &[1], ..grouping::COMMA };
+
+    assert_eq!(GroupedUintToDec(0u32, &grouping::COMMA).to_string(), "0");
+    assert_eq!(GroupedUintToDec(123u32, &grouping::COMMA).to_string(), "123");
+    assert_eq!(GroupedUintToDec(1234u32, &grouping::COMMA).to_string(), "1,234");
+    assert_eq!(GroupedUintToDec(1234567u32, &grouping::COMMA).to_string(), "1,234,567");
+    assert_eq!(GroupedUintToDec(1234567u32, &grouping::SPACE).to_string(), "1 234 567");
+    assert_eq!(GroupedUintToDec(1234567u32, &grouping::INDIAN).to_string(), "12,34,567");
+    assert_eq!(GroupedUintToDec(1234567u32, &NARROW_NBSP).to_string(), "1\u{202f}234\u{202f}567");
+    assert_eq!(GroupedUintToDec(255u8, &BY_ONE).to_string(), "2,5,5");
+    assert_eq!(GroupedUintToDec(18446744073709551615u64, &grouping::COMMA).to_string(),
+               "18,446,744,073,709,551,615");
+    assert_eq!(GroupedUintToDec(!0u128, &grouping::INDIAN).to_string(),
+               "34,02,82,36,69,20,93,84,63,46,33,74,60,74,31,76,82,11,455");
+    assert_eq!(GroupedUintToDec(!0u128, &Grouping { separator: b"\xf0\x9f\x98\x80", ..BY_ONE })
+                   .to_string().len(), 39 + 38 * 4);
+    assert_eq!(format!("{:>12}", GroupedUintToDec(1234567u32, &grouping::COMMA)), "   1,234,567");
+
+    assert_eq!(GroupedIntToDec(-1234567i32, &grouping::COMMA).to_string(), "-1,234,567");
+    assert_eq!(GroupedIntToDec(-123i32, &grouping::COMMA).to_string(), "-123");
+    assert_eq!(format!("{:+}", GroupedIntToDec(1234i16, &grouping::SPACE)), "+1 234");
+    assert_eq!(GroupedIntToDec(i64::MIN, &grouping::COMMA).to_string(),
+               "-9,223,372,036,854,775,808");
+    assert_eq!(GroupedIntToDec(i8::MIN, &grouping::INDIAN).to_string(), "-128");
+
+    const ZERO: Grouping<'static> = Grouping { groups: &[0], ..grouping::COMMA };
+    const THREE_THEN_ZERO: Grouping<'static> = Grouping { groups: &[3, 0], ..grouping::COMMA };
+    assert_eq!(GroupedUintToDec(1234567u32, &ZERO).to_string(), "1234567");
+    assert_eq!(GroupedUintToDec(1234567u32, &THREE_THEN_ZERO).to_string(), "1234,567");
+    assert_eq!(GroupedIntToDec(-1234567i32, &THREE_THEN_ZERO).to_string(), "-1234,567");
+
+    {
+        use std::fmt::Write;
+        const LONG: Grouping<'static> = Grouping { separator: b"<sep>", ..grouping::COMMA };
+        let mut s = String::new();
+        assert!(write!(&mut s, "{}", GroupedUintToDec(1234u32, &LONG)).is_err());
+        assert!(write!(&mut s, "{}", GroupedIntToDec(-1234i32, &LONG)).is_err());
+    }
+
+    let mut n = 1u64;
+    for _ in 0..64 {
+        let plain = n.to_string();
+        let grouped = GroupedUintToDec(n, &grouping::COMMA).to_string();
+        assert_eq!(grouped.replace(",", ""), plain);
+        assert_eq!(grouped.len(), grouping::COMMA.grouped_len(plain.len()));
+        n = n.wrapping_mul(3);
+    }
+}
+
 macro_rules! make_bench {
-    ($t:ty: $system:ident vs $best:ident) => (
+    ($wrap:ident($t:ty): $system:ident vs $best:ident) => (
         #[bench]
         fn $system(b: &mut test::Bencher) {
             b.iter(|| {
@@ -164,7 +599,7 @@ macro_rules! make_bench {
                 let mut buf = [0; 4096];
                 let mut w = Cursor::new(&mut buf[..]);
                 for _ in 0..64 {
-                    let _ = write!(&mut w, "{}", UintToDec(n));
+                    let _ = write!(&mut w, "{}", $wrap(n));
                     n = n.wrapping_mul(3);
                 }
             });
@@ -172,8 +607,14 @@ macro_rules! make_bench {
     )
 }
 
-make_bench!(u64: bench_u64_system vs bench_u64_best);
-make_bench!(u32: bench_u32_system vs bench_u32_best);
-make_bench!(u16: bench_u16_system vs bench_u16_best);
-make_bench!(u8: bench_u8_system vs bench_u8_best);
+make_bench!(UintToDec(u128): bench_u128_system vs bench_u128_best);
+make_bench!(UintToDec(u64): bench_u64_system vs bench_u64_best);
+make_bench!(UintToDec(u32): bench_u32_system vs bench_u32_best);
+make_bench!(UintToDec(u16): bench_u16_system vs bench_u16_best);
+make_bench!(UintToDec(u8): bench_u8_system vs bench_u8_best);
+make_bench!(IntToDec(i128): bench_i128_system vs bench_i128_best);
+make_bench!(IntToDec(i64): bench_i64_system vs bench_i64_best);
+make_bench!(IntToDec(i32): bench_i32_system vs bench_i32_best);
+make_bench!(IntToDec(i16): bench_i16_system vs bench_i16_best);
+make_bench!(IntToDec(i8): bench_i8_system vs bench_i8_best);
 
@@ -0,0 +1,35 @@
+pub const NDIGITS128: usize = 128;
+pub const NDIGITS64: usize = 64;
+pub const NDIGITS32: usize = 32;
+pub const NDIGITS16: usize = 16;
+pub const NDIGITS8: usize = 8;
+
+pub type Digit = u8;
+
+// the fixed-size arrays are large enough for any radix (the worst case being radix 2).
+// as in `int2dec`, `uN_to_digits` zero-pads the array and `uN_to_radix` writes only
+// significant digits to the end of given buffer and returns the starting offset.
+pub type Digits128 = [Digit; NDIGITS128];
+pub type Digits64 = [Digit; NDIGITS64];
+pub type Digits32 = [Digit; NDIGITS32];
+pub type Digits16 = [Digit; NDIGITS16];
+pub type Digits8 = [Digit; NDIGITS8];
+
+pub static LOWER: &'static [u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+pub static UPPER: &'static [u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub const MIN_RADIX: u32 = 2;
+pub const MAX_RADIX: u32 = 36;
+
+// defines `uN_to_digits` functions in terms of `uN_to_radix` ones.
+macro_rules! radix_to_digits {
+    ($($dfn:ident($t:ty) => $rfn:ident -> $Digits:ty, $NDIGITS:expr;)*) => (
+        $(
+            pub fn $dfn(n: $t, radix: u32, upper: bool) -> $Digits {
+                let mut buf: $Digits = [b'0'; $NDIGITS];
+                $rfn(n, radix, upper, &mut buf);
+                buf
+            }
+        )*
+    )
+}
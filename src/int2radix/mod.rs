@@ -0,0 +1,125 @@
+use core::prelude::*;
+use core::{str, fmt};
+
+pub use self::digits::Digit;
+pub use self::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+pub use self::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+pub use self::digits::{MIN_RADIX, MAX_RADIX};
+
+pub use self::strategy::best;
+
+#[macro_use] mod digits;
+
+pub mod strategy {
+    pub mod naive;
+    pub mod shift;
+
+    pub mod best {
+        // unlike `int2dec`, the radix is only known at runtime so we have to dispatch here.
+        use int2radix::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+        use int2radix::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+        use super::{naive, shift};
+
+        pub fn u128_to_radix(n: u128, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+            if radix.is_power_of_two() {
+                shift::u128_to_radix(n, radix, upper, buf)
+            } else {
+                naive::u128_to_radix(n, radix, upper, buf)
+            }
+        }
+
+        pub fn u64_to_radix(n: u64, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+            if radix.is_power_of_two() {
+                shift::u64_to_radix(n, radix, upper, buf)
+            } else {
+                naive::u64_to_radix(n, radix, upper, buf)
+            }
+        }
+
+        pub fn u32_to_radix(n: u32, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+            if radix.is_power_of_two() {
+                shift::u32_to_radix(n, radix, upper, buf)
+            } else {
+                naive::u32_to_radix(n, radix, upper, buf)
+            }
+        }
+
+        pub fn u16_to_radix(n: u16, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+            if radix.is_power_of_two() {
+                shift::u16_to_radix(n, radix, upper, buf)
+            } else {
+                naive::u16_to_radix(n, radix, upper, buf)
+            }
+        }
+
+        pub fn u8_to_radix(n: u8, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+            if radix.is_power_of_two() {
+                shift::u8_to_radix(n, radix, upper, buf)
+            } else {
+                naive::u8_to_radix(n, radix, upper, buf)
+            }
+        }
+
+        radix_to_digits! {
+            u128_to_digits(u128) => u128_to_radix -> Digits128, NDIGITS128;
+            u64_to_digits(u64) => u64_to_radix -> Digits64, NDIGITS64;
+            u32_to_digits(u32) => u32_to_radix -> Digits32, NDIGITS32;
+            u16_to_digits(u16) => u16_to_radix -> Digits16, NDIGITS16;
+            u8_to_digits(u8) => u8_to_radix -> Digits8, NDIGITS8;
+        }
+    }
+}
+
+#[cfg(test)] mod tests;
+
+pub struct UintToRadixFunc<I, T>(pub I, pub u32, pub bool, pub fn(I, u32, bool) -> T);
+#[derive(Debug)] pub struct UintToRadix<I>(pub I, /*radix*/ pub u32, /*upper*/ pub bool);
+
+// the radix is a public field, so it is checked on formatting instead of panicking in `conv`.
+fn is_valid_radix(radix: u32) -> bool {
+    MIN_RADIX <= radix && radix <= MAX_RADIX
+}
+
+// the alternate flag (`{:#}`) prepends the same prefix as `{:#x}`, `{:#o}` and `{:#b}`.
+fn radix_prefix(radix: u32) -> &'static str {
+    match radix {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        _ => "",
+    }
+}
+
+macro_rules! impl_uint_to_radix {
+    ($t:ty, $Digits:ty, $NDIGITS:expr, $default_conv:ident) => (
+        impl fmt::Display for UintToRadixFunc<$t, $Digits> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let UintToRadixFunc(num, radix, upper, conv) = *self;
+                if !is_valid_radix(radix) { return Err(fmt::Error); }
+                let buf = conv(num, radix, upper);
+                let last = buf.len() - 1;
+                let start = buf[..last].iter().position(|&c| c != b'0').unwrap_or(last);
+                f.pad_integral(true, radix_prefix(radix),
+                               unsafe {str::from_utf8_unchecked(&buf[start..])})
+            }
+        }
+
+        impl fmt::Display for UintToRadix<$t> {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let UintToRadix(num, radix, upper) = *self;
+                if !is_valid_radix(radix) { return Err(fmt::Error); }
+                let mut buf = [0; $NDIGITS];
+                let start = best::$default_conv(num, radix, upper, &mut buf);
+                f.pad_integral(true, radix_prefix(radix),
+                               unsafe {str::from_utf8_unchecked(&buf[start..])})
+            }
+        }
+    )
+}
+
+impl_uint_to_radix!(u128, Digits128, NDIGITS128, u128_to_radix);
+impl_uint_to_radix!(u64, Digits64, NDIGITS64, u64_to_radix);
+impl_uint_to_radix!(u32, Digits32, NDIGITS32, u32_to_radix);
+impl_uint_to_radix!(u16, Digits16, NDIGITS16, u16_to_radix);
+impl_uint_to_radix!(u8, Digits8, NDIGITS8, u8_to_radix);
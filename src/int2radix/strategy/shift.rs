@@ -0,0 +1,88 @@
+use core::prelude::*;
+
+use int2radix::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+use int2radix::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use int2radix::digits::{LOWER, UPPER, MIN_RADIX, MAX_RADIX};
+
+pub fn u128_to_radix(mut n: u128, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+    assert!(MIN_RADIX <= radix && radix <= MAX_RADIX && radix.is_power_of_two());
+    assert!(buf.len() >= NDIGITS128);
+    let digits = if upper {UPPER} else {LOWER};
+    let shift = radix.trailing_zeros() as usize;
+    let mask = (radix - 1) as u128;
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = digits[(n & mask) as usize];
+        n >>= shift;
+        if n == 0 { return i; }
+    }
+}
+
+pub fn u64_to_radix(mut n: u64, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+    assert!(MIN_RADIX <= radix && radix <= MAX_RADIX && radix.is_power_of_two());
+    assert!(buf.len() >= NDIGITS64);
+    let digits = if upper {UPPER} else {LOWER};
+    let shift = radix.trailing_zeros() as usize;
+    let mask = (radix - 1) as u64;
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = digits[(n & mask) as usize];
+        n >>= shift;
+        if n == 0 { return i; }
+    }
+}
+
+pub fn u32_to_radix(mut n: u32, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+    assert!(MIN_RADIX <= radix && radix <= MAX_RADIX && radix.is_power_of_two());
+    assert!(buf.len() >= NDIGITS32);
+    let digits = if upper {UPPER} else {LOWER};
+    let shift = radix.trailing_zeros() as usize;
+    let mask = (radix - 1) as u32;
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = digits[(n & mask) as usize];
+        n >>= shift;
+        if n == 0 { return i; }
+    }
+}
+
+pub fn u16_to_radix(mut n: u16, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+    assert!(MIN_RADIX <= radix && radix <= MAX_RADIX && radix.is_power_of_two());
+    assert!(buf.len() >= NDIGITS16);
+    let digits = if upper {UPPER} else {LOWER};
+    let shift = radix.trailing_zeros() as usize;
+    let mask = (radix - 1) as u16;
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = digits[(n & mask) as usize];
+        n >>= shift;
+        if n == 0 { return i; }
+    }
+}
+
+pub fn u8_to_radix(mut n: u8, radix: u32, upper: bool, buf: &mut [u8]) -> usize {
+    assert!(MIN_RADIX <= radix && radix <= MAX_RADIX && radix.is_power_of_two());
+    assert!(buf.len() >= NDIGITS8);
+    let digits = if upper {UPPER} else {LOWER};
+    let shift = radix.trailing_zeros() as usize;
+    let mask = (radix - 1) as u8;
+    let mut i = buf.len();
+    loop {
+        i -= 1;
+        buf[i] = digits[(n & mask) as usize];
+        n >>= shift;
+        if n == 0 { return i; }
+    }
+}
+
+radix_to_digits! {
+    u128_to_digits(u128) => u128_to_radix -> Digits128, NDIGITS128;
+    u64_to_digits(u64) => u64_to_radix -> Digits64, NDIGITS64;
+    u32_to_digits(u32) => u32_to_radix -> Digits32, NDIGITS32;
+    u16_to_digits(u16) => u16_to_radix -> Digits16, NDIGITS16;
+    u8_to_digits(u8) => u8_to_radix -> Digits8, NDIGITS8;
+}
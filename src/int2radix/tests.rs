@@ -0,0 +1,347 @@
+use std::prelude::v1::*;
+use std::{str, fmt};
+use test;
+
+use int2radix::digits::{Digits128, Digits64, Digits32, Digits16, Digits8};
+use int2radix::digits::{NDIGITS128, NDIGITS64, NDIGITS32, NDIGITS16, NDIGITS8};
+use int2radix::{UintToRadix, UintToRadixFunc, MIN_RADIX, MAX_RADIX};
+
+pub use test::Bencher;
+pub use int2dec::tests::rotating_bench;
+
+pub static ALL_RADIXES: &'static [u32] = &[2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17,
+                                           18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30,
+                                           31, 32, 33, 34, 35, 36];
+pub static POW2_RADIXES: &'static [u32] = &[2, 4, 8, 16, 32];
+
+// asserts that `s` matches `n` as formatted by the standard library, for the radixes it
+// supports (and does nothing otherwise), so that we are not testing the implementation
+// against itself.
+fn check_system<I>(s: &str, n: I, radix: u32, upper: bool)
+        where I: fmt::Binary + fmt::Octal + fmt::LowerHex + fmt::UpperHex {
+    let expected = match (radix, upper) {
+        (2, _) => format!("{:b}", n),
+        (8, _) => format!("{:o}", n),
+        (16, false) => format!("{:x}", n),
+        (16, true) => format!("{:X}", n),
+        _ => return,
+    };
+    assert_eq!(s, expected);
+}
+
+fn check_case(s: &str, radix: u32, upper: bool) {
+    for c in s.chars() {
+        let d = c.to_digit(radix).expect("invalid digit");
+        assert!(d < 10 || c.is_uppercase() == upper,
+                "digit {:?} has a wrong case in {:?}", c, s);
+    }
+}
+
+macro_rules! radix_sanity_test {
+    ($digits_test:ident, $radix_test:ident: $t:ident, $Digits:ty, $ndigits:expr) => (
+        fn $digits_test<F: FnMut($t, u32, bool) -> $Digits>(mut f: F, radixes: &[u32]) {
+            let mut check = |n: $t, radix: u32, upper: bool| {
+                let buf = f(n, radix, upper);
+                assert_eq!(buf.len(), $ndigits);
+                let s = str::from_utf8(&buf).unwrap();
+                assert_eq!($t::from_str_radix(s, radix), Ok(n));
+                check_case(s, radix, upper);
+                let trimmed = s.trim_left_matches('0');
+                check_system(if trimmed.is_empty() {"0"} else {trimmed}, n, radix, upper);
+            };
+
+            for &radix in radixes {
+                for &upper in &[false, true] {
+                    // all powers of the radix and their neighbors
+                    let mut n: $t = 1;
+                    loop {
+                        check(n - 1, radix, upper);
+                        check(n, radix, upper);
+                        check(n + 1, radix, upper);
+                        match n.checked_mul(radix as $t) {
+                            Some(n_) => { n = n_; }
+                            None => { break; }
+                        }
+                    }
+
+                    let mut n: $t = 1;
+                    for _ in 0..$ndigits {
+                        check(n, radix, upper);
+                        check(!n, radix, upper);
+                        n = n.wrapping_mul(3);
+                    }
+                }
+            }
+        }
+
+        fn $radix_test<F: FnMut($t, u32, bool, &mut [u8]) -> usize>(mut f: F, radixes: &[u32]) {
+            let mut check = |n: $t, radix: u32, upper: bool| {
+                for &extra in &[0, 1, 7] {
+                    let mut buf = vec![b'_'; $ndigits + extra];
+                    let start = f(n, radix, upper, &mut buf);
+                    let s = str::from_utf8(&buf[start..]).unwrap();
+                    assert!(s.len() == 1 || !s.starts_with("0"),
+                            "{} has leading zeroes in radix {}: {:?}", n, radix, s);
+                    assert_eq!($t::from_str_radix(s, radix), Ok(n));
+                    check_case(s, radix, upper);
+                    check_system(s, n, radix, upper);
+                    assert!(buf[..start].iter().all(|&c| c == b'_'),
+                            "{} wrote outside of significant digits: {:?}", n, buf);
+                }
+            };
+
+            for &radix in radixes {
+                for &upper in &[false, true] {
+                    let mut n: $t = 1;
+                    loop {
+                        check(n - 1, radix, upper);
+                        check(n, radix, upper);
+                        check(n + 1, radix, upper);
+                        match n.checked_mul(radix as $t) {
+                            Some(n_) => { n = n_; }
+                            None => { break; }
+                        }
+                    }
+
+                    let mut n: $t = 1;
+                    for _ in 0..$ndigits {
+                        check(n, radix, upper);
+                        check(!n, radix, upper);
+                        n = n.wrapping_mul(3);
+                    }
+                }
+            }
+        }
+    )
+}
+
+radix_sanity_test!(u128_sanity_test, u128_radix_sanity_test: u128, Digits128, NDIGITS128);
+radix_sanity_test!(u64_sanity_test, u64_radix_sanity_test: u64, Digits64, NDIGITS64);
+radix_sanity_test!(u32_sanity_test, u32_radix_sanity_test: u32, Digits32, NDIGITS32);
+radix_sanity_test!(u16_sanity_test, u16_radix_sanity_test: u16, Digits16, NDIGITS16);
+radix_sanity_test!(u8_sanity_test, u8_radix_sanity_test: u8, Digits8, NDIGITS8);
+
+// per-strategy tests
+macro_rules! per_strategy {
+    ($strategy:ident($radixes:expr): $($t:tt)*) => (
+        mod $strategy {
+            use super::super::*;
+            use int2radix::strategy::$strategy::*;
+
+            #[test] fn sanity_test() { per_strategy_sanity_test!($radixes; $($t)*) }
+            per_strategy_bench!($($t)*);
+        }
+    )
+}
+
+macro_rules! per_strategy_sanity_test {
+    ($radixes:expr;) => ({});
+    ($radixes:expr; u128 $($t:tt)*) => ({
+        u128_sanity_test(u128_to_digits, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u64 $($t:tt)*) => ({
+        u64_sanity_test(u64_to_digits, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u32 $($t:tt)*) => ({
+        u32_sanity_test(u32_to_digits, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u16 $($t:tt)*) => ({
+        u16_sanity_test(u16_to_digits, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u8 $($t:tt)*) => ({
+        u8_sanity_test(u8_to_digits, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u128_radix $($t:tt)*) => ({
+        u128_radix_sanity_test(u128_to_radix, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u64_radix $($t:tt)*) => ({
+        u64_radix_sanity_test(u64_to_radix, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u32_radix $($t:tt)*) => ({
+        u32_radix_sanity_test(u32_to_radix, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u16_radix $($t:tt)*) => ({
+        u16_radix_sanity_test(u16_to_radix, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+    ($radixes:expr; u8_radix $($t:tt)*) => ({
+        u8_radix_sanity_test(u8_to_radix, $radixes); per_strategy_sanity_test!($radixes; $($t)*)
+    });
+}
+
+// benchmarks are done in radix 16, which is supported by every strategy.
+macro_rules! per_strategy_bench {
+    () => ();
+    (u128 $($t:tt)*) => (
+        #[bench] fn bench_u128(b: &mut Bencher) {
+            rotating_bench(|n| u128_to_digits(n, 16, false), b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u64 $($t:tt)*) => (
+        #[bench] fn bench_u64(b: &mut Bencher) {
+            rotating_bench(|n| u64_to_digits(n, 16, false), b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u32 $($t:tt)*) => (
+        #[bench] fn bench_u32(b: &mut Bencher) {
+            rotating_bench(|n| u32_to_digits(n, 16, false), b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u16 $($t:tt)*) => (
+        #[bench] fn bench_u16(b: &mut Bencher) {
+            rotating_bench(|n| u16_to_digits(n, 16, false), b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u8 $($t:tt)*) => (
+        #[bench] fn bench_u8(b: &mut Bencher) {
+            rotating_bench(|n| u8_to_digits(n, 16, false), b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u128_radix $($t:tt)*) => (
+        #[bench] fn bench_u128_radix(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2radix::NDIGITS128];
+                                 let start = u128_to_radix(n, 16, false, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u64_radix $($t:tt)*) => (
+        #[bench] fn bench_u64_radix(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2radix::NDIGITS64];
+                                 let start = u64_to_radix(n, 16, false, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u32_radix $($t:tt)*) => (
+        #[bench] fn bench_u32_radix(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2radix::NDIGITS32];
+                                 let start = u32_to_radix(n, 16, false, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u16_radix $($t:tt)*) => (
+        #[bench] fn bench_u16_radix(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2radix::NDIGITS16];
+                                 let start = u16_to_radix(n, 16, false, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+    (u8_radix $($t:tt)*) => (
+        #[bench] fn bench_u8_radix(b: &mut Bencher) {
+            rotating_bench(|n| { let mut buf = [0; ::int2radix::NDIGITS8];
+                                 let start = u8_to_radix(n, 16, false, &mut buf); (start, buf) }, b)
+        }
+        per_strategy_bench!($($t)*);
+    );
+}
+
+mod strategy {
+    per_strategy!(naive(ALL_RADIXES): u128 u64 u32 u16 u8
+                                      u128_radix u64_radix u32_radix u16_radix u8_radix);
+    per_strategy!(shift(POW2_RADIXES): u128 u64 u32 u16 u8
+                                       u128_radix u64_radix u32_radix u16_radix u8_radix);
+
+    mod best {
+        use super::super::*;
+        use int2radix::strategy::best::*;
+        #[test] fn sanity_test() {
+            per_strategy_sanity_test!(ALL_RADIXES; u128 u64 u32 u16 u8
+                                      u128_radix u64_radix u32_radix u16_radix u8_radix)
+        }
+        per_strategy_bench!(u128 u64 u32 u16 u8
+                            u128_radix u64_radix u32_radix u16_radix u8_radix);
+    }
+}
+
+#[test]
+#[should_panic]
+fn naive_invalid_radix_test() {
+    let mut buf = [0; NDIGITS64];
+    ::int2radix::strategy::naive::u64_to_radix(42, MAX_RADIX + 1, false, &mut buf);
+}
+
+#[test]
+#[should_panic]
+fn shift_non_pow2_radix_test() {
+    let mut buf = [0; NDIGITS64];
+    ::int2radix::strategy::shift::u64_to_radix(42, 10, false, &mut buf);
+}
+
+#[test]
+fn uint_to_radix_sanity_test() {
+    let mut n = 1u128;
+    for _ in 0..128 {
+        assert_eq!(format!("{:x}", n), UintToRadix(n, 16, false).to_string());
+        assert_eq!(format!("{:X}", n as u64), UintToRadix(n as u64, 16, true).to_string());
+        assert_eq!(format!("{:o}", n as u32), UintToRadix(n as u32, 8, false).to_string());
+        assert_eq!(format!("{:b}", n as u16), UintToRadix(n as u16, 2, false).to_string());
+        assert_eq!((n as u8).to_string(), UintToRadix(n as u8, 10, false).to_string());
+        n = n.wrapping_mul(3);
+    }
+
+    assert_eq!(UintToRadix(0u32, MIN_RADIX, false).to_string(), "0");
+    assert_eq!(UintToRadix(35u32, MAX_RADIX, false).to_string(), "z");
+    assert_eq!(UintToRadix(35u32, MAX_RADIX, true).to_string(), "Z");
+    assert_eq!(UintToRadix(!0u64, 36, false).to_string(), "3w5e11264sgsf");
+    assert_eq!(format!("{:#}", UintToRadix(255u8, 16, false)), "0xff");
+    assert_eq!(format!("{:#}", UintToRadix(8u8, 8, false)), "0o10");
+    assert_eq!(format!("{:#}", UintToRadix(5u8, 2, false)), "0b101");
+    assert_eq!(format!("{:#}", UintToRadix(35u8, 36, false)), "z");
+    assert_eq!(format!("{:#06}", UintToRadix(255u8, 16, true)), "0x00FF");
+    assert_eq!(format!("{:>6}", UintToRadix(255u8, 16, true)), "    FF");
+}
+
+#[test]
+fn uint_to_radix_invalid_radix_test() {
+    use std::fmt::Write;
+    use int2radix::strategy::best;
+
+    let mut s = String::new();
+    assert!(write!(&mut s, "{}", UintToRadix(5u32, MAX_RADIX + 1, false)).is_err());
+    assert!(write!(&mut s, "{}", UintToRadix(5u8, MIN_RADIX - 1, false)).is_err());
+    assert!(write!(&mut s, "{}", UintToRadix(5u64, 0, false)).is_err());
+    assert!(write!(&mut s, "{}",
+                   UintToRadixFunc(5u32, MAX_RADIX + 1, false, best::u32_to_digits)).is_err());
+    assert_eq!(s, "");
+}
+
+macro_rules! make_bench {
+    ($t:ty: $system:ident vs $best:ident) => (
+        #[bench]
+        fn $system(b: &mut test::Bencher) {
+            b.iter(|| {
+                use std::io::{Cursor, Write};
+                let mut n: $t = 1;
+                let mut buf = [0; 4096];
+                let mut w = Cursor::new(&mut buf[..]);
+                for _ in 0..64 {
+                    let _ = write!(&mut w, "{:x}", n);
+                    n = n.wrapping_mul(3);
+                }
+            });
+        }
+
+        #[bench]
+        fn $best(b: &mut test::Bencher) {
+            b.iter(|| {
+                use std::io::{Cursor, Write};
+                let mut n: $t = 1;
+                let mut buf = [0; 4096];
+                let mut w = Cursor::new(&mut buf[..]);
+                for _ in 0..64 {
+                    let _ = write!(&mut w, "{}", UintToRadix(n, 16, false));
+                    n = n.wrapping_mul(3);
+                }
+            });
+        }
+    )
+}
+
+make_bench!(u128: bench_u128_hex_system vs bench_u128_hex_best);
+make_bench!(u64: bench_u64_hex_system vs bench_u64_hex_best);
+make_bench!(u32: bench_u32_hex_system vs bench_u32_hex_best);
+make_bench!(u16: bench_u16_hex_system vs bench_u16_hex_best);
+make_bench!(u8: bench_u8_hex_system vs bench_u8_hex_best);
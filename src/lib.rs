@@ -28,6 +28,13 @@
 
 mod num;
 
+#[macro_use] pub mod dispatch;
+pub mod grouping;
+
 pub mod int2dec;
+pub mod int2radix;
+pub mod dec2int;
 pub mod flt2dec;
+pub mod dec2flt;
+pub mod decfloat;
 